@@ -0,0 +1,41 @@
+//! # wasm_preview.rs - 브라우저 미리보기
+//!
+//! 편집기에서 입력한 Markdown을 Block으로 해석해 HTML 문자열로 돌려줍니다.
+//! 사이트 빌드 없이 HTML 계층만 쓰므로 `wasm32-unknown-unknown`에서 동작합니다.
+//! CI가 `cargo check --target wasm32-unknown-unknown --example wasm_preview`로 검사합니다.
+//!
+//! ```text
+//! cargo build --target wasm32-unknown-unknown --example wasm_preview
+//! wasm-bindgen --target web target/wasm32-unknown-unknown/debug/examples/wasm_preview.wasm --out-dir pkg
+//! ```
+//!
+//! ```js
+//! import init, { render_preview } from "./pkg/wasm_preview.js";
+//! await init();
+//! editor.addEventListener("input", () => {
+//!     preview.innerHTML = render_preview(editor.value);
+//! });
+//! ```
+
+use quo::block::context::RenderContext;
+use quo::block::markdown::parse_markdown;
+use quo::html::renderer::{HtmlRenderer, Renderer};
+use quo::metadata::builder::Metadata;
+use wasm_bindgen::prelude::*;
+
+/// Markdown 원문 → 미리보기 HTML.
+#[wasm_bindgen]
+pub fn render_preview(markdown: &str) -> String {
+    let ctx = RenderContext::new(Metadata::new().into_map());
+    parse_markdown(markdown)
+        .iter()
+        .map(|block| {
+            block
+                .render_to_ir(&ctx)
+                .accept(HtmlRenderer::new())
+                .finalize()
+                .as_str()
+                .to_string()
+        })
+        .collect()
+}
@@ -0,0 +1,704 @@
+//! # quo-macros - `html!` 매크로
+//!
+//! ## 목적
+//! JSX와 비슷한 문법을 `quo::html::elements`의 타입 요소 생성자로 펼칩니다. 펼친 코드는 손으로 쓴
+//! 생성자 호출과 같으므로 속성 검사(`Attributes<T>`)와 콘텐츠 모델 검사(`FlowContent`)가 그대로 적용됩니다.
+//!
+//! ## 사용 예시
+//! ```rust
+//! use quo::html;
+//!
+//! let title = "Hello";
+//! let card = html! {
+//!     <div class="card" id="intro">
+//!         <h1>{title}</h1>
+//!         <h2>"부제목"</h2>
+//!         <p>"안녕하세요, " <span class="name">{name_text}</span> "님"</p>
+//!         <img src="/logo.png" alt={alt_text} />
+//!         {footer}                // FlowContent를 구현한 값
+//!     </div>
+//! };
+//! ```
+//!
+//! ## 펼친 결과
+//! ```rust
+//! Div::new(
+//!     AttrBuilder::global().class(classes("card")).id(attr("intro")),
+//!     vec![
+//!         Box::new(H1::new(AttrBuilder::global(), text(&format!("{}", title)))) as Box<dyn FlowContent>,
+//!         ...
+//!     ],
+//! )
+//! ```
+//!
+//! ## 문법
+//! - 요소: `<tag 속성...>자식...</tag>`, 자식이 없는 요소는 `<img ... />`
+//! - 속성값: 문자열 리터럴 또는 `{식}` (`Display`)
+//! - 텍스트: 문자열 리터럴 또는 `{식}`. 따옴표 없는 텍스트는 공백을 알 수 없으므로 받지 않습니다
+//! - 자식 `{식}`: 요소 안에서는 `FlowContent` 값, 구문 요소(`p`, `span`, `a`, `strong`, `em`, `time` 등) 안에서는 `Phrasing` 값,
+//!   제목 안에서는 텍스트. 표에서는 `table` 안에 `TableContent` 값, `thead`/`tbody`/`tfoot` 안에 `Tr`,
+//!   `colgroup` 안에 `Col`, `tr` 안에 `TrContent` 값. 폼에서는 `select` 안에 `SelectContent` 값,
+//!   `fieldset` 안에 `FieldsetContent` 값 (`legend`, `FlowContent`).
+//!   미디어에서는 `video`/`audio` 안에 `MediaContent` 값, `picture` 안에 `Source`들과 마지막 `Img`.
+//!   문서에서는 `html` 안에 `head`와 `body` 하나씩, `head` 안에 `MetadataContent` 값 (`title`, `meta`, `link`).
+//!   `figure` 안에 `FlowContent` 값과 마지막 `figcaption` 하나 (`Figure::caption`으로 펼침).
+//!   `details` 안에 첫 `summary` 하나와 `FlowContent` 값, `summary` 안에 `Phrasing` 값.
+//!   `pre`는 `Phrasing` 값, `code`는 텍스트만. 둘 다 문자열에 타이포그래피 규칙을 적용하지 않습니다 (`verbatim`)
+//! - 불리언 속성(`required`, `disabled`, `controls`, `autoplay`, `loop`, `muted`, `default`, `defer`, `async`, `open`):
+//!   `{true}`, `{식}` (`bool`). `type`, `loop`, `as`, `async`는 빌더의 `r#type`, `r#loop`, `r#as`, `r#async`로 펼칩니다
+//! - `datetime`: 문자열 리터럴 또는 `{식}` (`Datetime`, 문자열). 문자열은 `Datetime::parse`로 검사합니다
+//!
+//! ## 설계 결정
+//! - **검사는 타입 시스템이**: 매크로는 태그와 콘텐츠 종류(텍스트, 요소, 없음)만 압니다. 허용되지 않은
+//!   속성은 빌더 메서드가 없다는 컴파일 오류로, 허용되지 않은 자식은 `FlowContent` 변환 오류로 드러납니다.
+//! - **외부 의존성 없음**: `proc_macro`만으로 토큰을 읽습니다. 사용자 식의 span은 그대로 유지됩니다.
+
+use proc_macro::{Delimiter, Group, Ident, Literal, Punct, Spacing, Span, TokenStream, TokenTree};
+
+/// 요소의 자식 종류.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Model {
+    /// 텍스트 하나 (`Content`)
+    Text,
+    /// `FlowContent` 자식들
+    Flow,
+    /// `Phrasing` 자식들 (문자열 리터럴은 텍스트 노드)
+    Phrasing,
+    /// 코드 텍스트 하나 (`&str`, 타이포그래피 규칙 없음)
+    Code,
+    /// `Phrasing` 자식들, 문자열 리터럴은 코드 텍스트 (pre)
+    Preformatted,
+    /// `TableContent` 자식들 (table)
+    Table,
+    /// 같은 타입 자식들, 상자 없이 `Vec<T>` (thead의 `Tr`, colgroup의 `Col`)
+    Items,
+    /// `TrContent` 자식들 (tr)
+    Row,
+    /// `SelectContent` 자식들 (select)
+    Select,
+    /// `FieldsetContent` 자식들 (fieldset)
+    Fieldset,
+    /// `MediaContent` 자식들 (video, audio)
+    Media,
+    /// `Source`들 + 마지막 `Img` 하나 (picture)
+    Picture,
+    /// `FlowContent` 자식들 + 마지막 `Figcaption` 하나까지 (figure)
+    Figure,
+    /// 첫 `Summary` 하나 + `FlowContent` 자식들 (details)
+    Details,
+    /// `MetadataContent` 자식들 (head)
+    Head,
+    /// `Head` 하나 + `Body` 하나 (html)
+    Document,
+    /// 자식 없음
+    Void,
+}
+
+/// 매크로가 아는 요소.
+struct Spec {
+    tag: &'static str,
+    ty: &'static str,
+    /// `AttrBuilder`의 생성자
+    attrs: &'static str,
+    model: Model,
+}
+
+const ELEMENTS: &[Spec] = &[
+    Spec { tag: "h1", ty: "H1", attrs: "global", model: Model::Text },
+    Spec { tag: "h2", ty: "H2", attrs: "global", model: Model::Text },
+    Spec { tag: "div", ty: "Div", attrs: "global", model: Model::Flow },
+    Spec { tag: "blockquote", ty: "Blockquote", attrs: "quote", model: Model::Flow },
+    Spec { tag: "figure", ty: "Figure", attrs: "global", model: Model::Figure },
+    Spec { tag: "figcaption", ty: "Figcaption", attrs: "global", model: Model::Flow },
+    Spec { tag: "p", ty: "P", attrs: "global", model: Model::Phrasing },
+    Spec { tag: "span", ty: "Span", attrs: "global", model: Model::Phrasing },
+    Spec { tag: "strong", ty: "Strong", attrs: "global", model: Model::Phrasing },
+    Spec { tag: "em", ty: "Em", attrs: "global", model: Model::Phrasing },
+    Spec { tag: "mark", ty: "Mark", attrs: "global", model: Model::Phrasing },
+    Spec { tag: "small", ty: "Small", attrs: "global", model: Model::Phrasing },
+    Spec { tag: "sub", ty: "Sub", attrs: "global", model: Model::Phrasing },
+    Spec { tag: "sup", ty: "Sup", attrs: "global", model: Model::Phrasing },
+    Spec { tag: "abbr", ty: "Abbr", attrs: "global", model: Model::Phrasing },
+    Spec { tag: "time", ty: "Time", attrs: "timestamp", model: Model::Phrasing },
+    Spec { tag: "data", ty: "Data", attrs: "value", model: Model::Phrasing },
+    Spec { tag: "del", ty: "Del", attrs: "edit", model: Model::Phrasing },
+    Spec { tag: "ins", ty: "Ins", attrs: "edit", model: Model::Phrasing },
+    Spec { tag: "a", ty: "A", attrs: "anchor", model: Model::Phrasing },
+    Spec { tag: "pre", ty: "Pre", attrs: "global", model: Model::Preformatted },
+    Spec { tag: "code", ty: "Code", attrs: "global", model: Model::Code },
+    Spec { tag: "img", ty: "Img", attrs: "image", model: Model::Void },
+    Spec { tag: "table", ty: "Table", attrs: "global", model: Model::Table },
+    Spec { tag: "caption", ty: "Caption", attrs: "global", model: Model::Flow },
+    Spec { tag: "colgroup", ty: "Colgroup", attrs: "column", model: Model::Items },
+    Spec { tag: "col", ty: "Col", attrs: "column", model: Model::Void },
+    Spec { tag: "thead", ty: "Thead", attrs: "global", model: Model::Items },
+    Spec { tag: "tbody", ty: "Tbody", attrs: "global", model: Model::Items },
+    Spec { tag: "tfoot", ty: "Tfoot", attrs: "global", model: Model::Items },
+    Spec { tag: "tr", ty: "Tr", attrs: "global", model: Model::Row },
+    Spec { tag: "th", ty: "Th", attrs: "table_cell", model: Model::Flow },
+    Spec { tag: "td", ty: "Td", attrs: "table_cell", model: Model::Flow },
+    Spec { tag: "form", ty: "Form", attrs: "form", model: Model::Flow },
+    Spec { tag: "input", ty: "Input", attrs: "form", model: Model::Void },
+    Spec { tag: "button", ty: "Button", attrs: "form", model: Model::Phrasing },
+    Spec { tag: "label", ty: "Label", attrs: "global", model: Model::Phrasing },
+    Spec { tag: "select", ty: "Select", attrs: "form", model: Model::Select },
+    Spec { tag: "option", ty: "Option", attrs: "form", model: Model::Text },
+    Spec { tag: "textarea", ty: "Textarea", attrs: "form", model: Model::Text },
+    Spec { tag: "fieldset", ty: "Fieldset", attrs: "form", model: Model::Fieldset },
+    Spec { tag: "legend", ty: "Legend", attrs: "global", model: Model::Phrasing },
+    Spec { tag: "video", ty: "Video", attrs: "media", model: Model::Media },
+    Spec { tag: "audio", ty: "Audio", attrs: "media", model: Model::Media },
+    Spec { tag: "source", ty: "Source", attrs: "media", model: Model::Void },
+    Spec { tag: "track", ty: "Track", attrs: "media", model: Model::Void },
+    Spec { tag: "picture", ty: "Picture", attrs: "global", model: Model::Picture },
+    Spec { tag: "details", ty: "Details", attrs: "disclosure", model: Model::Details },
+    Spec { tag: "summary", ty: "Summary", attrs: "global", model: Model::Phrasing },
+    Spec { tag: "dialog", ty: "Dialog", attrs: "disclosure", model: Model::Flow },
+    Spec { tag: "html", ty: "Html", attrs: "global", model: Model::Document },
+    Spec { tag: "head", ty: "Head", attrs: "global", model: Model::Head },
+    Spec { tag: "body", ty: "Body", attrs: "global", model: Model::Flow },
+    Spec { tag: "title", ty: "Title", attrs: "global", model: Model::Text },
+    Spec { tag: "meta", ty: "Meta", attrs: "metadata", model: Model::Void },
+    Spec { tag: "link", ty: "Link", attrs: "metadata", model: Model::Void },
+];
+
+/// JSX와 비슷한 문법으로 타입 HTML 요소 생성. 최상위 요소는 하나입니다.
+#[proc_macro]
+pub fn html(input: TokenStream) -> TokenStream {
+    let tokens: Vec<TokenTree> = input.into_iter().collect();
+    let mut parser = Parser { tokens, pos: 0 };
+    let result = parser.element().and_then(|element| match parser.peek() {
+        None => Ok(element),
+        Some(token) => Err(Error::new(token.span(), "html! expects a single root element")),
+    });
+    match result {
+        Ok(element) => element.expand(),
+        Err(err) => err.to_compile_error(),
+    }
+}
+
+// ============================================================================
+// 구문
+// ============================================================================
+
+struct Error {
+    span: Span,
+    message: String,
+}
+
+impl Error {
+    fn new(span: Span, message: &str) -> Self {
+        Error {
+            span,
+            message: message.to_string(),
+        }
+    }
+
+    /// `compile_error!("...")`, span은 오류 위치.
+    fn to_compile_error(&self) -> TokenStream {
+        let mut message = Literal::string(&self.message);
+        message.set_span(self.span);
+        let mut bang = Punct::new('!', Spacing::Alone);
+        bang.set_span(self.span);
+        let mut args = Group::new(Delimiter::Parenthesis, TokenTree::Literal(message).into());
+        args.set_span(self.span);
+        [
+            TokenTree::Ident(Ident::new("compile_error", self.span)),
+            TokenTree::Punct(bang),
+            TokenTree::Group(args),
+        ]
+        .into_iter()
+        .collect()
+    }
+}
+
+enum AttrValue {
+    Literal(Literal),
+    Expr(Group),
+}
+
+struct Attr {
+    name: Ident,
+    value: AttrValue,
+}
+
+enum Child {
+    Element(Element),
+    Text(Literal),
+    Expr(Group),
+}
+
+struct Element {
+    spec: &'static Spec,
+    span: Span,
+    attrs: Vec<Attr>,
+    childs: Vec<Child>,
+}
+
+struct Parser {
+    tokens: Vec<TokenTree>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&TokenTree> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Option<TokenTree> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    fn last_span(&self) -> Span {
+        self.tokens.last().map_or_else(Span::call_site, |t| t.span())
+    }
+
+    fn is_punct(&self, offset: usize, ch: char) -> bool {
+        matches!(self.tokens.get(self.pos + offset), Some(TokenTree::Punct(p)) if p.as_char() == ch)
+    }
+
+    fn expect_punct(&mut self, ch: char) -> Result<Span, Error> {
+        match self.next() {
+            Some(TokenTree::Punct(p)) if p.as_char() == ch => Ok(p.span()),
+            Some(token) => Err(Error::new(token.span(), &format!("expected `{}`", ch))),
+            None => Err(Error::new(self.last_span(), &format!("expected `{}`", ch))),
+        }
+    }
+
+    fn ident(&mut self) -> Result<Ident, Error> {
+        match self.next() {
+            Some(TokenTree::Ident(ident)) => Ok(ident),
+            Some(token) => Err(Error::new(token.span(), "expected a name")),
+            None => Err(Error::new(self.last_span(), "expected a name")),
+        }
+    }
+
+    /// `<tag attrs>childs</tag>` 또는 `<tag attrs />`.
+    fn element(&mut self) -> Result<Element, Error> {
+        let span = self.expect_punct('<')?;
+        let name = self.ident()?;
+        let tag = name.to_string();
+        let Some(spec) = ELEMENTS.iter().find(|spec| spec.tag == tag) else {
+            let known: Vec<&str> = ELEMENTS.iter().map(|spec| spec.tag).collect();
+            return Err(Error::new(
+                name.span(),
+                &format!("unsupported element <{}> (supported: {})", tag, known.join(", ")),
+            ));
+        };
+
+        let mut attrs = Vec::new();
+        loop {
+            if self.is_punct(0, '/') && self.is_punct(1, '>') {
+                self.pos += 2;
+                return Ok(Element { spec, span, attrs, childs: Vec::new() });
+            }
+            if self.is_punct(0, '>') {
+                self.pos += 1;
+                break;
+            }
+            attrs.push(self.attr()?);
+        }
+
+        if spec.model == Model::Void {
+            // void 요소는 닫는 태그가 없어도 됨
+            let closes = self.is_punct(0, '<')
+                && self.is_punct(1, '/')
+                && matches!(self.tokens.get(self.pos + 2), Some(TokenTree::Ident(i)) if i.to_string() == tag);
+            if closes {
+                self.pos += 4;
+            }
+            return Ok(Element { spec, span, attrs, childs: Vec::new() });
+        }
+
+        let mut childs = Vec::new();
+        loop {
+            if self.is_punct(0, '<') && self.is_punct(1, '/') {
+                self.pos += 2;
+                let close = self.ident()?;
+                if close.to_string() != tag {
+                    return Err(Error::new(
+                        close.span(),
+                        &format!("expected </{}>, found </{}>", tag, close),
+                    ));
+                }
+                self.expect_punct('>')?;
+                break;
+            }
+            match self.peek() {
+                Some(TokenTree::Punct(p)) if p.as_char() == '<' => {
+                    childs.push(Child::Element(self.element()?))
+                }
+                Some(TokenTree::Literal(_)) => {
+                    let Some(TokenTree::Literal(literal)) = self.next() else {
+                        unreachable!()
+                    };
+                    childs.push(Child::Text(literal));
+                }
+                Some(TokenTree::Group(g)) if g.delimiter() == Delimiter::Brace => {
+                    let Some(TokenTree::Group(group)) = self.next() else {
+                        unreachable!()
+                    };
+                    childs.push(Child::Expr(group));
+                }
+                Some(token) => {
+                    return Err(Error::new(
+                        token.span(),
+                        "expected an element, a string literal or a {expression}",
+                    ));
+                }
+                None => {
+                    return Err(Error::new(name.span(), &format!("unclosed element <{}>", tag)));
+                }
+            }
+        }
+
+        let element = Element { spec, span, attrs, childs };
+        element.check()?;
+        Ok(element)
+    }
+
+    /// `name="literal"` 또는 `name={expr}`.
+    fn attr(&mut self) -> Result<Attr, Error> {
+        let name = self.ident()?;
+        if self.is_punct(0, '-') {
+            return Err(Error::new(
+                name.span(),
+                "hyphenated attributes are not supported by the typed attribute builders",
+            ));
+        }
+        self.expect_punct('=')?;
+        let value = match self.next() {
+            Some(TokenTree::Literal(literal)) => AttrValue::Literal(literal),
+            Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Brace => {
+                AttrValue::Expr(group)
+            }
+            Some(token) => {
+                return Err(Error::new(token.span(), "expected a string literal or a {expression}"));
+            }
+            None => return Err(Error::new(name.span(), "missing attribute value")),
+        };
+        Ok(Attr { name, value })
+    }
+}
+
+impl Element {
+    /// 콘텐츠 종류 검사. 요소/FlowContent 구분은 타입 시스템이 합니다.
+    fn check(&self) -> Result<(), Error> {
+        if self.spec.model == Model::Picture && self.childs.is_empty() {
+            return Err(Error::new(
+                self.span,
+                &format!("<{}> needs an <img> as its last child", self.spec.tag),
+            ));
+        }
+        if self.spec.model == Model::Details && self.childs.is_empty() {
+            return Err(Error::new(
+                self.span,
+                &format!("<{}> needs a <summary> as its first child", self.spec.tag),
+            ));
+        }
+        if self.spec.model == Model::Document && self.childs.len() != 2 {
+            return Err(Error::new(
+                self.span,
+                &format!("<{}> needs exactly a <head> and a <body>", self.spec.tag),
+            ));
+        }
+        if self.spec.model == Model::Figure {
+            let last = self.childs.len().saturating_sub(1);
+            for (i, child) in self.childs.iter().enumerate() {
+                if let Child::Element(element) = child
+                    && element.spec.tag == "figcaption"
+                    && i != last
+                {
+                    return Err(Error::new(
+                        element.span,
+                        &format!("<figcaption> must be the last child of <{}>", self.spec.tag),
+                    ));
+                }
+            }
+        }
+        for child in &self.childs {
+            match (self.spec.model, child) {
+                (Model::Text | Model::Code, Child::Element(element)) => {
+                    return Err(Error::new(
+                        element.span,
+                        &format!("<{}> accepts text only", self.spec.tag),
+                    ));
+                }
+                (Model::Flow | Model::Figure | Model::Details, Child::Text(literal)) => {
+                    return Err(Error::new(
+                        literal.span(),
+                        &format!("<{}> accepts elements only; put text in a heading", self.spec.tag),
+                    ));
+                }
+                (Model::Table | Model::Items | Model::Row, Child::Text(literal)) => {
+                    return Err(Error::new(
+                        literal.span(),
+                        &format!("<{}> accepts table elements only", self.spec.tag),
+                    ));
+                }
+                (Model::Select, Child::Text(literal)) => {
+                    return Err(Error::new(
+                        literal.span(),
+                        &format!("<{}> accepts <option> elements only", self.spec.tag),
+                    ));
+                }
+                (Model::Picture, Child::Text(literal)) => {
+                    return Err(Error::new(
+                        literal.span(),
+                        &format!("<{}> accepts <source> elements and one <img> only", self.spec.tag),
+                    ));
+                }
+                (Model::Head | Model::Document, Child::Text(literal)) => {
+                    return Err(Error::new(
+                        literal.span(),
+                        &format!("<{}> accepts elements only", self.spec.tag),
+                    ));
+                }
+                (Model::Fieldset, Child::Text(literal)) => {
+                    return Err(Error::new(
+                        literal.span(),
+                        &format!("<{}> accepts elements only; put text in a <legend>", self.spec.tag),
+                    ));
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    fn expand(&self) -> TokenStream {
+        let mut out = code(&format!("::quo::html::elements::{}::new", self.spec.ty));
+        let mut args = self.expand_attrs();
+        match self.spec.model {
+            Model::Text => {
+                args.extend(code(","));
+                args.extend(self.expand_text());
+            }
+            Model::Flow => {
+                args.extend(code(","));
+                args.extend(self.expand_childs("FlowContent"));
+            }
+            Model::Phrasing | Model::Preformatted => {
+                args.extend(code(","));
+                args.extend(self.expand_childs("Phrasing"));
+            }
+            Model::Code => {
+                args.extend(code(","));
+                args.extend(code("&"));
+                args.extend(self.expand_format());
+            }
+            Model::Table => {
+                args.extend(code(","));
+                args.extend(self.expand_childs("TableContent"));
+            }
+            Model::Items => {
+                args.extend(code(","));
+                args.extend(expand_items(&self.childs));
+            }
+            Model::Row => {
+                args.extend(code(","));
+                args.extend(self.expand_childs("TrContent"));
+            }
+            Model::Select => {
+                args.extend(code(","));
+                args.extend(self.expand_childs("SelectContent"));
+            }
+            Model::Fieldset => {
+                args.extend(code(","));
+                args.extend(self.expand_childs("FieldsetContent"));
+            }
+            Model::Media => {
+                args.extend(code(","));
+                args.extend(self.expand_childs("MediaContent"));
+            }
+            Model::Figure => {
+                args.extend(code(","));
+                match self.caption() {
+                    Some((caption, childs)) => {
+                        args.extend(expand_boxed(childs, "FlowContent", "text"));
+                        out.extend([group(Delimiter::Parenthesis, args)]);
+                        out.extend(code(".caption"));
+                        out.extend([group(Delimiter::Parenthesis, caption.expand())]);
+                        return out;
+                    }
+                    None => args.extend(self.expand_childs("FlowContent")),
+                }
+            }
+            Model::Details => {
+                // check()가 첫 자식(summary)이 있음을 확인
+                let (summary, childs) = self.childs.split_first().expect("checked");
+                args.extend(code(","));
+                args.extend(expand_item(summary));
+                args.extend(code(","));
+                args.extend(expand_boxed(childs, "FlowContent", "text"));
+            }
+            Model::Picture => {
+                // check()가 마지막 자식(img)이 있음을 확인
+                let (img, sources) = self.childs.split_last().expect("checked");
+                args.extend(code(","));
+                args.extend(expand_items(sources));
+                args.extend(code(","));
+                args.extend(expand_item(img));
+            }
+            Model::Head => {
+                args.extend(code(","));
+                args.extend(self.expand_childs("MetadataContent"));
+            }
+            Model::Document => {
+                // check()가 자식이 둘(head, body)임을 확인
+                for child in &self.childs {
+                    args.extend(code(","));
+                    args.extend(expand_item(child));
+                }
+            }
+            Model::Void => {}
+        }
+        out.extend([group(Delimiter::Parenthesis, args)]);
+        out
+    }
+
+    /// `AttrBuilder::global().class(...).id(...)`
+    fn expand_attrs(&self) -> TokenStream {
+        let mut out = code(&format!("::quo::html::attributes::AttrBuilder::{}()", self.spec.attrs));
+        for attr in &self.attrs {
+            let helper = match attr.name.to_string().as_str() {
+                "class" => "classes",
+                "href" | "src" | "action" | "poster" | "cite" => "url",
+                "srcset" => "srcset",
+                "datetime" => "datetime",
+                "required" | "disabled" | "controls" | "autoplay" | "loop" | "muted" | "default" | "defer"
+                | "async" | "open" => "flag",
+                _ => "attr",
+            };
+            // 키워드 속성(`type`, `loop`, `as`, `async`)은 빌더 메서드 `r#type`, `r#loop`, `r#as`, `r#async`
+            let method = match attr.name.to_string().as_str() {
+                name @ ("type" | "loop" | "as" | "async") => Ident::new_raw(name, attr.name.span()),
+                _ => attr.name.clone(),
+            };
+            out.extend(code("."));
+            out.extend([TokenTree::Ident(method)]);
+            let mut call = code(&format!("::quo::html::macros::{}", helper));
+            call.extend([group(Delimiter::Parenthesis, value_ref(&attr.value))]);
+            out.extend([group(Delimiter::Parenthesis, call)]);
+        }
+        out
+    }
+
+    /// `text(&format!("{}{}", a, b))`
+    fn expand_text(&self) -> TokenStream {
+        let mut arg = code("&");
+        arg.extend(self.expand_format());
+        let mut out = code("::quo::html::macros::text");
+        out.extend([group(Delimiter::Parenthesis, arg)]);
+        out
+    }
+
+    /// `format!("{}{}", a, b)`
+    fn expand_format(&self) -> TokenStream {
+        let mut format_args = TokenStream::new();
+        format_args.extend([TokenTree::Literal(Literal::string(&"{}".repeat(self.childs.len())))]);
+        for child in &self.childs {
+            format_args.extend(code(","));
+            match child {
+                Child::Text(literal) => format_args.extend([TokenTree::Literal(literal.clone())]),
+                Child::Expr(expr) => format_args.extend([paren(expr.stream())]),
+                Child::Element(_) => unreachable!("checked"),
+            }
+        }
+        let mut format = code("::std::format!");
+        format.extend([group(Delimiter::Parenthesis, format_args)]);
+        format
+    }
+
+    /// `vec![Box::new(child) as Box<dyn FlowContent>, ...]`. 텍스트는 `text("...")`.
+    fn expand_childs(&self, category: &str) -> TokenStream {
+        let helper = match self.spec.model {
+            Model::Preformatted => "verbatim",
+            _ => "text",
+        };
+        expand_boxed(&self.childs, category, helper)
+    }
+
+    /// 마지막 자식이 `<figcaption>`이면 (캡션, 나머지 자식들).
+    fn caption(&self) -> Option<(&Element, &[Child])> {
+        match self.childs.split_last() {
+            Some((Child::Element(caption), childs)) if caption.spec.tag == "figcaption" => {
+                Some((caption, childs))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// `vec![Box::new(child) as Box<dyn ..>, ...]`. 텍스트는 `helper("...")`.
+fn expand_boxed(childs: &[Child], category: &str, helper: &str) -> TokenStream {
+    let mut items = TokenStream::new();
+    for child in childs {
+        let value = match child {
+            Child::Element(element) => element.expand(),
+            Child::Expr(expr) => TokenStream::from(paren(expr.stream())),
+            Child::Text(literal) => {
+                let mut text = code(&format!("::quo::html::macros::{}", helper));
+                text.extend([paren(TokenTree::Literal(literal.clone()).into())]);
+                text
+            }
+        };
+        let mut boxed = code("::std::boxed::Box::new");
+        boxed.extend([group(Delimiter::Parenthesis, value)]);
+        items.extend(boxed);
+        items.extend(code(&format!(
+            "as ::std::boxed::Box<dyn ::quo::html::node::{}>,",
+            category
+        )));
+    }
+    let mut out = code("::std::vec!");
+    out.extend([group(Delimiter::Bracket, items)]);
+    out
+}
+
+/// `vec![child, ...]`. 자식 타입은 생성자가 정합니다 (`Thead`는 `Tr`만).
+fn expand_items(childs: &[Child]) -> TokenStream {
+    let mut items = TokenStream::new();
+    for child in childs {
+        items.extend(expand_item(child));
+        items.extend(code(","));
+    }
+    let mut out = code("::std::vec!");
+    out.extend([group(Delimiter::Bracket, items)]);
+    out
+}
+
+/// 요소나 `{식}` 자식 하나 (텍스트는 check()가 거름).
+fn expand_item(child: &Child) -> TokenStream {
+    match child {
+        Child::Element(element) => element.expand(),
+        Child::Expr(expr) => TokenStream::from(paren(expr.stream())),
+        Child::Text(_) => unreachable!("checked"),
+    }
+}
+
+/// 속성값 인자: 리터럴은 그대로(`&str`), 식은 `&(식)`.
+fn value_ref(value: &AttrValue) -> TokenStream {
+    match value {
+        AttrValue::Literal(literal) => TokenStream::from(TokenTree::Literal(literal.clone())),
+        AttrValue::Expr(expr) => {
+            let mut out = code("&");
+            out.extend([paren(expr.stream())]);
+            out
+        }
+    }
+}
+
+fn code(source: &str) -> TokenStream {
+    source.parse().expect("valid generated tokens")
+}
+
+fn group(delimiter: Delimiter, stream: TokenStream) -> TokenTree {
+    TokenTree::Group(Group::new(delimiter, stream))
+}
+
+fn paren(stream: TokenStream) -> TokenTree {
+    group(Delimiter::Parenthesis, stream)
+}
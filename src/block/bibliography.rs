@@ -0,0 +1,705 @@
+//! # bibliography.rs - Citation, Bibliography (참고 문헌)
+//!
+//! ## 목적
+//! 사이트에 등록한 참고 문헌(`References`, BibTeX 또는 CSL-JSON)을 본문에서 인용하고(`Citation`),
+//! 페이지 끝에 그 페이지가 인용한 문헌 목록(`Bibliography`)을 렌더링합니다.
+//! 인용 번호는 자동 번호(`Counter`)가 매기므로 페이지 안에서 처음 인용한 순서대로 1, 2, 3...이 됩니다.
+//!
+//! ## 사용 예시
+//! ```rust
+//! // 전역 메타데이터에 문헌 등록
+//! let references = References::load("references.bib")?;
+//! let site = Site::new().metadata(Metadata::new().custom(references).custom(CitationStyle::Numeric));
+//!
+//! // 글 템플릿
+//! HBox::new(vec![Box::new(text), Box::new(Citation::new("knuth1984").locator("p. 97"))]);
+//! Bibliography::new().heading("참고 문헌");
+//! ```
+//!
+//! ## 렌더링 결과
+//! ```html
+//! <span class="citation">[<a href="#ref-knuth1984">1</a>, p. 97]</span>
+//!
+//! <section aria-labelledby="bibliography" class="bibliography">
+//!   <h2 id="bibliography">참고 문헌</h2>
+//!   <ol>
+//!     <li id="ref-knuth1984">Donald E. Knuth (1984). Literate Programming. <em>The Computer Journal</em>,
+//!       27(2), 97-111. <a href="https://doi.org/10.1093/comjnl/27.2.97">https://doi.org/10.1093/comjnl/27.2.97</a></li>
+//!   </ol>
+//! </section>
+//! ```
+//!
+//! ## 구현 상태
+//! - [x] BibTeX (`@string` 매크로 제외), CSL-JSON 읽기
+//! - [x] 번호 인용 / 키 인용 (`CitationStyle`), 여러 문헌 동시 인용, 쪽수 등 위치 (`locator`)
+//! - [x] 페이지별 인용 문헌 목록, 등록된 문헌 전체 목록 (`Bibliography::all`)
+//! - [x] 등록되지 않은 키 경고 (`WarningKind::UnknownCitation`)
+//! - [ ] TODO: 인용 양식 선택 (APA, Chicago 등 CSL 스타일), LaTeX 악센트 명령 변환
+//!
+//! ## 설계 결정
+//! - **문헌은 전역 메타데이터**: 모든 페이지가 같은 문헌 목록을 쓰고, 바뀌면 증분 빌드 지문에 반영됩니다.
+//! - **번호는 페이지 단위**: 각 페이지가 자기 문헌 목록을 가지므로 번호도 페이지마다 1부터 시작합니다.
+//! - **인덱스 없이 렌더링하면 전체 목록**: 분석 단계에서는 인용 번호가 아직 없으므로 등록된 문헌을 모두
+//!   렌더링합니다. 이때 모은 anchor로 인용 링크(`#ref-키`)를 검사하므로 목록이 없는 페이지의 인용만 경고됩니다.
+//! - **형식은 하나**: 저자 (연도). 제목. 게재지, 권(호), 쪽. 출판사. DOI 순서의 단순한 형식만 지원합니다.
+
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::block::block::{Block, class_attrs, element, prose_rule, token_attrs, with_tokens};
+use crate::block::context::RenderContext;
+use crate::html::attributes::SharedAttrs;
+use crate::html::node::{Element, IRNode};
+use crate::html::trust::{Content, SafeString};
+use crate::metadata::builder::Metadata;
+use crate::metadata::types::{CurrentUrl, Numbered, NumberedKeys};
+use crate::metadata::value::MetadataValue;
+
+/// 인용의 자동 번호 종류 (`Numbered`).
+pub const CITATION_KIND: &str = "citation";
+
+/// 참고 문헌 읽기 오류.
+#[derive(Debug)]
+pub enum BibliographyError {
+    /// BibTeX 문법 오류 (줄 번호는 1부터)
+    Parse { line: usize, message: String },
+    /// CSL-JSON 형식 오류
+    Json(String),
+    /// 확장자로 형식을 알 수 없음 (`.bib`, `.json`만 지원)
+    UnknownFormat { path: PathBuf },
+    Io { path: PathBuf, source: io::Error },
+}
+
+impl Display for BibliographyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BibliographyError::Parse { line, message } => write!(f, "BibTeX line {}: {}", line, message),
+            BibliographyError::Json(message) => write!(f, "CSL-JSON: {}", message),
+            BibliographyError::UnknownFormat { path } => {
+                write!(f, "{}: unknown bibliography format (expected .bib or .json)", path.display())
+            }
+            BibliographyError::Io { path, source } => write!(f, "{}: {}", path.display(), source),
+        }
+    }
+}
+
+impl std::error::Error for BibliographyError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            BibliographyError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// 참고 문헌 하나.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Reference {
+    pub key: String,
+    /// 문헌 종류. BibTeX 항목 종류(`article`) 또는 CSL 종류(`article-journal`)
+    pub kind: String,
+    /// 표시 이름 (`이름 성` 순서)
+    pub authors: Vec<String>,
+    pub title: Option<String>,
+    pub year: Option<String>,
+    /// 학술지, 책 제목 등 (BibTeX `journal`/`booktitle`, CSL `container-title`)
+    pub container: Option<String>,
+    pub volume: Option<String>,
+    pub issue: Option<String>,
+    pub pages: Option<String>,
+    pub publisher: Option<String>,
+    pub doi: Option<String>,
+    pub url: Option<String>,
+}
+
+impl Reference {
+    pub fn new(key: &str) -> Self {
+        Reference {
+            key: key.to_string(),
+            ..Reference::default()
+        }
+    }
+
+    /// 저자 목록. `A`, `A and B`, `A, B, and C`
+    pub fn author_list(&self) -> Option<String> {
+        match self.authors.as_slice() {
+            [] => None,
+            [one] => Some(one.clone()),
+            [a, b] => Some(format!("{} and {}", a, b)),
+            [rest @ .., last] => Some(format!("{}, and {}", rest.join(", "), last)),
+        }
+    }
+
+    /// DOI가 있으면 DOI 주소, 없으면 `url`.
+    pub fn link(&self) -> Option<String> {
+        match &self.doi {
+            Some(doi) => Some(format!("https://doi.org/{}", doi.trim_start_matches("https://doi.org/"))),
+            None => self.url.clone(),
+        }
+    }
+
+    /// 목록 항목의 내용. 항목마다 마침표로 끝나고, 마지막 링크만 마침표 없이 붙습니다.
+    pub fn render(&self) -> Vec<Element> {
+        let rule = prose_rule();
+        let text = |s: &str| Element::Text(Content::from_str(s, &rule));
+        let em = |s: &str| Element::Node(element("em", SharedAttrs::new(), vec![text(s)]));
+        let mut sentences: Vec<Vec<Element>> = Vec::new();
+
+        let lead = match (self.author_list(), &self.year) {
+            (Some(authors), Some(year)) => Some(format!("{} ({})", authors, year)),
+            (Some(authors), None) => Some(authors),
+            (None, Some(year)) => Some(format!("({})", year)),
+            (None, None) => None,
+        };
+        sentences.extend(lead.map(|lead| vec![text(lead.trim_end_matches('.'))]));
+
+        // 게재지가 없는 책 등은 제목을 기울임
+        if let Some(title) = &self.title {
+            let title = title.trim_end_matches('.');
+            sentences.push(vec![match self.container {
+                Some(_) => text(title),
+                None => em(title),
+            }]);
+        }
+        if let Some(container) = &self.container {
+            let mut details = Vec::new();
+            match (&self.volume, &self.issue) {
+                (Some(volume), Some(issue)) => details.push(format!("{}({})", volume, issue)),
+                (Some(volume), None) => details.push(volume.clone()),
+                (None, Some(issue)) => details.push(format!("({})", issue)),
+                (None, None) => {}
+            }
+            details.extend(self.pages.clone());
+            let mut sentence = vec![em(container)];
+            if !details.is_empty() {
+                sentence.push(text(&format!(", {}", details.join(", "))));
+            }
+            sentences.push(sentence);
+        }
+        if let Some(publisher) = &self.publisher {
+            sentences.push(vec![text(publisher.trim_end_matches('.'))]);
+        }
+
+        let mut childs = Vec::new();
+        for sentence in sentences {
+            if !childs.is_empty() {
+                childs.push(text(" "));
+            }
+            childs.extend(sentence);
+            childs.push(text("."));
+        }
+        if let Some(link) = self.link() {
+            if !childs.is_empty() {
+                childs.push(text(" "));
+            }
+            childs.push(Element::Node(element("a", token_attrs(&[("href", &link)]), vec![text(&link)])));
+        }
+        childs
+    }
+}
+
+/// 키 → 참고 문헌. 전역 메타데이터로 넣습니다.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct References {
+    entries: BTreeMap<String, Reference>,
+}
+impl MetadataValue for References {}
+
+impl References {
+    pub fn new() -> Self {
+        References::default()
+    }
+
+    /// 파일에서 읽기. `.bib`은 BibTeX, `.json`은 CSL-JSON.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, BibliographyError> {
+        References::new().file(path)
+    }
+
+    /// 파일의 문헌 추가. 형식은 `load`와 같습니다.
+    pub fn file<P: AsRef<Path>>(self, path: P) -> Result<Self, BibliographyError> {
+        let path = path.as_ref();
+        let source = fs::read_to_string(path).map_err(|source| BibliographyError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("bib") | Some("bibtex") => self.bibtex(&source),
+            Some("json") => self.csl_json(&source),
+            _ => Err(BibliographyError::UnknownFormat {
+                path: path.to_path_buf(),
+            }),
+        }
+    }
+
+    /// BibTeX 원문의 문헌 추가. 같은 키는 나중 것이 덮어씁니다.
+    pub fn bibtex(self, source: &str) -> Result<Self, BibliographyError> {
+        Ok(parse_bibtex(source)?.into_iter().fold(self, References::reference))
+    }
+
+    /// CSL-JSON 원문(항목 배열)의 문헌 추가.
+    pub fn csl_json(self, source: &str) -> Result<Self, BibliographyError> {
+        Ok(parse_csl_json(source)?.into_iter().fold(self, References::reference))
+    }
+
+    pub fn reference(mut self, reference: Reference) -> Self {
+        self.entries.insert(reference.key.clone(), reference);
+        self
+    }
+
+    pub fn get(&self, key: &str) -> Option<&Reference> {
+        self.entries.get(key)
+    }
+
+    pub fn contains(&self, key: &str) -> bool {
+        self.entries.contains_key(key)
+    }
+
+    /// 모든 문헌 (키 순).
+    pub fn iter(&self) -> impl Iterator<Item = &Reference> {
+        self.entries.values()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+/// 인용 표시 방식. 전역 메타데이터로 넣습니다.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum CitationStyle {
+    /// `[1]` (페이지 안에서 처음 인용한 순서, 목록도 같은 순서)
+    #[default]
+    Numeric,
+    /// `[knuth1984]` (목록은 키 순)
+    Key,
+}
+impl MetadataValue for CitationStyle {}
+
+/// 문헌 목록 항목의 anchor. 예: `ref-knuth1984`
+pub fn reference_anchor(key: &str) -> String {
+    let slug: String = key
+        .chars()
+        .map(|c| if c.is_alphanumeric() || c == '-' || c == '_' { c } else { '-' })
+        .collect();
+    format!("ref-{}", slug)
+}
+
+/// 본문 인용 Block.
+#[derive(Debug, Clone)]
+pub struct Citation {
+    keys: Vec<String>,
+    locator: Option<String>,
+}
+
+impl Citation {
+    pub fn new(key: &str) -> Self {
+        Citation {
+            keys: vec![key.to_string()],
+            locator: None,
+        }
+    }
+
+    /// 함께 인용할 문헌. `[1, 3]`
+    pub fn key(mut self, key: &str) -> Self {
+        if !self.keys.iter().any(|k| k == key) {
+            self.keys.push(key.to_string());
+        }
+        self
+    }
+
+    /// 쪽수 등 문헌 안의 위치. `[1, p. 97]`
+    pub fn locator(mut self, locator: &str) -> Self {
+        self.locator = Some(locator.to_string());
+        self
+    }
+
+    pub fn keys(&self) -> &[String] {
+        &self.keys
+    }
+}
+
+impl Block for Citation {
+    fn metadata(&self) -> Metadata {
+        Metadata::new()
+            .custom(Numbered(CITATION_KIND.to_string()))
+            .custom(NumberedKeys(self.keys.clone()))
+    }
+
+    fn render_to_ir(&self, ctx: &RenderContext) -> IRNode {
+        let rule = prose_rule();
+        let text = |s: &str| Element::Text(Content::from_str(s, &rule));
+        let style = ctx.get::<CitationStyle>().copied().unwrap_or_default();
+        let url = ctx.get::<CurrentUrl>().map(|u| u.0.as_str()).unwrap_or("/");
+
+        let mut childs = vec![text("[")];
+        for (i, key) in self.keys.iter().enumerate() {
+            if i > 0 {
+                childs.push(text(", "));
+            }
+            let label = match style {
+                CitationStyle::Key => key.clone(),
+                CitationStyle::Numeric => ctx
+                    .index()
+                    .and_then(|index| index.key_number(url, CITATION_KIND, key))
+                    .map(|n| n.to_string())
+                    .unwrap_or_else(|| "?".to_string()),
+            };
+            let href = format!("#{}", reference_anchor(key));
+            childs.push(Element::Node(element("a", token_attrs(&[("href", &href)]), vec![text(&label)])));
+        }
+        if let Some(locator) = &self.locator {
+            childs.push(text(&format!(", {}", locator)));
+        }
+        childs.push(text("]"));
+        element("span", class_attrs(&["citation"]), childs)
+    }
+}
+
+/// 참고 문헌 목록 Block.
+#[derive(Debug, Clone, Default)]
+pub struct Bibliography {
+    heading: Option<String>,
+    all: bool,
+}
+
+impl Bibliography {
+    pub fn new() -> Self {
+        Bibliography::default()
+    }
+
+    /// 목록 제목 (`<h2>`). 없으면 제목 없이 `aria-label="References"`.
+    pub fn heading(mut self, heading: &str) -> Self {
+        self.heading = Some(heading.to_string());
+        self
+    }
+
+    /// 인용하지 않은 문헌까지 등록된 문헌 전체를 나열 (인용한 문헌 뒤에 키 순).
+    pub fn all(mut self) -> Self {
+        self.all = true;
+        self
+    }
+}
+
+impl Block for Bibliography {
+    fn render_to_ir(&self, ctx: &RenderContext) -> IRNode {
+        let style = ctx.get::<CitationStyle>().copied().unwrap_or_default();
+        let url = ctx.get::<CurrentUrl>().map(|u| u.0.as_str()).unwrap_or("/");
+        let references = ctx.get::<References>();
+
+        let mut keys: Vec<&str> = ctx
+            .index()
+            .map(|index| index.numbered_keys(url, CITATION_KIND))
+            .unwrap_or_default();
+        if style == CitationStyle::Key {
+            keys.sort();
+        }
+        // 인덱스가 없으면(분석 단계, 미리보기) 전체 목록: 인용 링크의 anchor가 모두 있어야 함
+        if self.all || ctx.index().is_none() {
+            let cited = keys.clone();
+            let rest = references.iter().flat_map(|r| r.iter()).map(|r| r.key.as_str());
+            keys.extend(rest.filter(|key| !cited.contains(key)));
+        }
+
+        let rule = prose_rule();
+        let items = keys
+            .iter()
+            .map(|key| {
+                let mut childs = Vec::new();
+                if style == CitationStyle::Key {
+                    childs.push(Element::Node(element(
+                        "span",
+                        class_attrs(&["reference-key"]),
+                        vec![Element::Text(Content::from_str(&format!("[{}]", key), &rule))],
+                    )));
+                    childs.push(Element::Text(Content::from_str(" ", &rule)));
+                }
+                match references.and_then(|r| r.get(key)) {
+                    Some(reference) => childs.extend(reference.render()),
+                    None => childs.push(Element::Text(Content::from_str(key, &rule))),
+                }
+                let id = reference_anchor(key);
+                Element::Node(element("li", token_attrs(&[("id", &id)]), childs))
+            })
+            .collect();
+        let list = match style {
+            CitationStyle::Numeric => element("ol", SharedAttrs::new(), items),
+            CitationStyle::Key => element("ul", SharedAttrs::new(), items),
+        };
+
+        match &self.heading {
+            Some(heading) => {
+                let title = element(
+                    "h2",
+                    token_attrs(&[("id", "bibliography")]),
+                    vec![Element::Text(Content::from_str(heading, &rule))],
+                );
+                element(
+                    "section",
+                    with_tokens(class_attrs(&["bibliography"]), &[("aria-labelledby", "bibliography")]),
+                    vec![Element::Node(title), Element::Node(list)],
+                )
+            }
+            None => element(
+                "section",
+                with_tokens(class_attrs(&["bibliography"]), &[("aria-label", "References")]),
+                vec![Element::Node(list)],
+            ),
+        }
+    }
+}
+
+// ============================================================================
+// BibTeX
+// ============================================================================
+
+/// BibTeX 원문 파싱. `@comment`, `@preamble`, `@string`은 건너뜁니다.
+pub fn parse_bibtex(source: &str) -> Result<Vec<Reference>, BibliographyError> {
+    let chars: Vec<char> = source.chars().collect();
+    let mut references = Vec::new();
+    let mut i = 0;
+
+    while let Some(at) = (i..chars.len()).find(|&j| chars[j] == '@') {
+        let mut p = at + 1;
+        let kind: String = take_while(&chars, &mut p, |c| c.is_alphanumeric() || c == '_').to_lowercase();
+        skip_whitespace(&chars, &mut p);
+        let close = match chars.get(p) {
+            Some('{') => '}',
+            Some('(') => ')',
+            _ => return Err(parse_error(&chars, p, &format!("expected '{{' after @{}", kind))),
+        };
+        if matches!(kind.as_str(), "comment" | "preamble" | "string") {
+            i = skip_group(&chars, p).ok_or_else(|| parse_error(&chars, at, "unclosed entry"))?;
+            continue;
+        }
+        p += 1;
+
+        skip_whitespace(&chars, &mut p);
+        let key = take_while(&chars, &mut p, |c| c != ',' && c != close && !c.is_whitespace());
+        if key.is_empty() {
+            return Err(parse_error(&chars, p, "missing citation key"));
+        }
+        let mut reference = Reference::new(&key);
+        reference.kind = kind;
+
+        loop {
+            skip_whitespace(&chars, &mut p);
+            match chars.get(p) {
+                Some(',') => {
+                    p += 1;
+                    continue;
+                }
+                Some(c) if *c == close => {
+                    p += 1;
+                    break;
+                }
+                None => return Err(parse_error(&chars, at, "unclosed entry")),
+                _ => {}
+            }
+            let name = take_while(&chars, &mut p, |c| c != '=' && c != ',' && c != close && !c.is_whitespace())
+                .to_lowercase();
+            skip_whitespace(&chars, &mut p);
+            if chars.get(p) != Some(&'=') {
+                return Err(parse_error(&chars, p, &format!("expected '=' after field '{}'", name)));
+            }
+            p += 1;
+            let value = field_value(&chars, &mut p, close)?;
+            set_field(&mut reference, &name, &value);
+        }
+        references.push(reference);
+        i = p;
+    }
+    Ok(references)
+}
+
+/// 필드 값 하나 (`{...}`, `"..."`, 숫자/매크로 이름, `#`로 이어 붙이기).
+fn field_value(chars: &[char], p: &mut usize, close: char) -> Result<String, BibliographyError> {
+    let mut value = String::new();
+    loop {
+        skip_whitespace(chars, p);
+        match chars.get(*p) {
+            Some('{') => {
+                let end = skip_group(chars, *p).ok_or_else(|| parse_error(chars, *p, "unclosed '{'"))?;
+                value.extend(&chars[*p + 1..end - 1]);
+                *p = end;
+            }
+            Some('"') => {
+                let mut depth = 0;
+                let start = *p + 1;
+                let end = (start..chars.len())
+                    .find(|&j| {
+                        match chars[j] {
+                            '{' => depth += 1,
+                            '}' => depth -= 1,
+                            '"' if depth == 0 => return true,
+                            _ => {}
+                        }
+                        false
+                    })
+                    .ok_or_else(|| parse_error(chars, *p, "unclosed '\"'"))?;
+                value.extend(&chars[start..end]);
+                *p = end + 1;
+            }
+            Some(_) => {
+                let word = take_while(chars, p, |c| c != ',' && c != close && c != '#' && !c.is_whitespace());
+                if word.is_empty() {
+                    return Err(parse_error(chars, *p, "missing field value"));
+                }
+                value.push_str(&word);
+            }
+            None => return Err(parse_error(chars, *p, "unexpected end of input")),
+        }
+        skip_whitespace(chars, p);
+        if chars.get(*p) == Some(&'#') {
+            *p += 1;
+            continue;
+        }
+        return Ok(clean_latex(&value));
+    }
+}
+
+fn set_field(reference: &mut Reference, name: &str, value: &str) {
+    let value = Some(value.to_string()).filter(|v| !v.is_empty());
+    match name {
+        "author" => {
+            reference.authors = value
+                .iter()
+                .flat_map(|v| v.split(" and "))
+                .map(|name| match name.split_once(',') {
+                    Some((family, given)) => format!("{} {}", given.trim(), family.trim()),
+                    None => name.trim().to_string(),
+                })
+                .collect()
+        }
+        "title" => reference.title = value,
+        "year" => reference.year = value,
+        "date" if reference.year.is_none() => reference.year = value.map(|d| d.chars().take(4).collect()),
+        "journal" | "journaltitle" | "booktitle" => reference.container = value,
+        "volume" => reference.volume = value,
+        "number" | "issue" => reference.issue = value,
+        "pages" => reference.pages = value,
+        "publisher" | "institution" | "school" | "organization" => reference.publisher = value,
+        "doi" => reference.doi = value,
+        "url" => reference.url = value,
+        _ => {}
+    }
+}
+
+/// 중괄호와 흔한 이스케이프 제거, 공백 정리. `--`는 en dash.
+fn clean_latex(value: &str) -> String {
+    let value = value
+        .replace(['{', '}'], "")
+        .replace("\\&", "&")
+        .replace("\\%", "%")
+        .replace("\\_", "_")
+        .replace("---", "\u{2014}")
+        .replace("--", "\u{2013}");
+    value.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// `open` 위치의 괄호와 짝이 맞는 닫는 괄호 다음 위치.
+fn skip_group(chars: &[char], open: usize) -> Option<usize> {
+    let (o, c) = match chars[open] {
+        '(' => ('(', ')'),
+        _ => ('{', '}'),
+    };
+    let mut depth = 0;
+    for (j, &ch) in chars.iter().enumerate().skip(open) {
+        if ch == o {
+            depth += 1;
+        } else if ch == c {
+            depth -= 1;
+            if depth == 0 {
+                return Some(j + 1);
+            }
+        }
+    }
+    None
+}
+
+fn take_while(chars: &[char], p: &mut usize, f: impl Fn(char) -> bool) -> String {
+    let start = *p;
+    while *p < chars.len() && f(chars[*p]) {
+        *p += 1;
+    }
+    chars[start..*p].iter().collect()
+}
+
+fn skip_whitespace(chars: &[char], p: &mut usize) {
+    while *p < chars.len() && chars[*p].is_whitespace() {
+        *p += 1;
+    }
+}
+
+fn parse_error(chars: &[char], at: usize, message: &str) -> BibliographyError {
+    BibliographyError::Parse {
+        line: chars[..at.min(chars.len())].iter().filter(|&&c| c == '\n').count() + 1,
+        message: message.to_string(),
+    }
+}
+
+// ============================================================================
+// CSL-JSON
+// ============================================================================
+
+/// CSL-JSON 항목 배열 파싱.
+pub fn parse_csl_json(source: &str) -> Result<Vec<Reference>, BibliographyError> {
+    let json: serde_json::Value =
+        serde_json::from_str(source).map_err(|err| BibliographyError::Json(err.to_string()))?;
+    let items = json
+        .as_array()
+        .ok_or_else(|| BibliographyError::Json("expected an array of items".to_string()))?;
+
+    items
+        .iter()
+        .enumerate()
+        .map(|(i, item)| {
+            let key = scalar(item.get("id")).ok_or_else(|| BibliographyError::Json(format!("item {} has no id", i)))?;
+            let mut reference = Reference::new(&key);
+            reference.kind = scalar(item.get("type")).unwrap_or_default();
+            reference.authors = item
+                .get("author")
+                .and_then(|a| a.as_array())
+                .map(|authors| authors.iter().filter_map(csl_name).collect())
+                .unwrap_or_default();
+            reference.title = scalar(item.get("title"));
+            reference.year = item.get("issued").and_then(|issued| {
+                let parts = issued.get("date-parts").and_then(|p| p.get(0)).and_then(|p| p.get(0));
+                scalar(parts).or_else(|| scalar(issued.get("literal"))).or_else(|| scalar(issued.get("raw")))
+            });
+            reference.container = scalar(item.get("container-title"));
+            reference.volume = scalar(item.get("volume"));
+            reference.issue = scalar(item.get("issue"));
+            reference.pages = scalar(item.get("page"));
+            reference.publisher = scalar(item.get("publisher"));
+            reference.doi = scalar(item.get("DOI"));
+            reference.url = scalar(item.get("URL"));
+            Ok(reference)
+        })
+        .collect()
+}
+
+/// 문자열 또는 숫자 값.
+fn scalar(value: Option<&serde_json::Value>) -> Option<String> {
+    match value? {
+        serde_json::Value::String(s) if !s.is_empty() => Some(s.clone()),
+        serde_json::Value::Number(n) => Some(n.to_string()),
+        _ => None,
+    }
+}
+
+/// CSL 이름 (`given family` 또는 `literal`).
+fn csl_name(name: &serde_json::Value) -> Option<String> {
+    if let Some(literal) = scalar(name.get("literal")) {
+        return Some(literal);
+    }
+    let parts: Vec<String> = [scalar(name.get("given")), scalar(name.get("family"))].into_iter().flatten().collect();
+    (!parts.is_empty()).then(|| parts.join(" "))
+}
@@ -1,11 +1,214 @@
-/*  
-* 의미론적 계층
-* html element를 조합해 code,math 등 큰 단위 element를작성한다.
-* 모든 block은 page에 속한다.
-*/
-pub trait Block {
-    fn get_attr(&self);
-    fn get_chids(&self);
-    fn accept(&self);
-    fn build(&self);
+//! # block.rs - Block 트레이트
+//!
+//! 의미론적 계층.
+//! html element를 조합해 code, math 등 큰 단위 element를 작성한다.
+//! 모든 block은 page에 속한다.
+
+use std::fmt::Display;
+use std::hash::{Hash, Hasher};
+
+use crate::block::context::RenderContext;
+use crate::block::css::ScopedCss;
+use crate::cite::config::{BlockErrorMode, SiteConfig};
+use crate::html::attributes::{AttrValues, SharedAttrs};
+use crate::html::node::{Element, IRNode};
+use crate::html::rules::{self, RuleList};
+use crate::html::trust::{
+    AttrKey, AttrValue, Content, HtmlBlock, SafeString, TagName, unescape_html_chars,
+};
+use crate::metadata::builder::Metadata;
+
+/// 의미론적 콘텐츠 단위. 자신을 IRNode로 변환하고 메타데이터를 제공합니다.
+pub trait Block: Send + Sync {
+    /// 블록을 IRNode로 변환. 렌더링의 핵심 메서드.
+    fn render_to_ir(&self, ctx: &RenderContext) -> IRNode;
+
+    /// 블록의 메타데이터 반환. Cite 계층에서 수집.
+    fn metadata(&self) -> Metadata {
+        Metadata::new()
+    }
+
+    /// 블록의 고유 ID. 없으면 자동 생성.
+    fn id(&self) -> Option<BlockId> {
+        None
+    }
+
+    /// 자식 Block 목록. Cite 계층이 Block 트리를 순회할 때 사용.
+    fn children(&self) -> Vec<&dyn Block> {
+        Vec::new()
+    }
+
+    /// 구성 값 검증. 빌드 시 호출되며, 오류는 `BuildError::InvalidAttribute`로 보고됩니다.
+    fn check(&self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        Ok(())
+    }
+
+    /// 의존 CSS. Cite 계층이 사이트 전체에서 모아 스타일시트 하나로 기록하고 사용하는 페이지에 연결합니다.
+    fn css(&self) -> Option<ScopedCss> {
+        None
+    }
+
+    /// 렌더링하지 않고 증분 빌드 지문에 넣을 구성 값 (텍스트, 설정).
+    /// 종류, id, 메타데이터, 자식은 Site가 넣으므로 자신이 직접 가진 값만 추가합니다.
+    /// 기본: 없음 (코드에만 있는 값은 `template_version`으로 무효화)
+    fn fingerprint(&self, _state: &mut dyn Hasher) {}
+
+    /// Block 종류 (타입 경로). `SiteConfig::block_errors_for`가 종류별 오류 처리를 찾을 때 씁니다.
+    fn kind(&self) -> &'static str {
+        std::any::type_name::<Self>()
+    }
+}
+
+/// `Hash` 값을 `Block::fingerprint`의 해셔에 추가.
+pub(crate) fn hash_into<T: Hash + ?Sized>(value: &T, mut state: &mut dyn Hasher) {
+    value.hash(&mut state);
+}
+
+/// 실패한 Block이 남기는 주석의 앞부분.
+pub(crate) const BLOCK_ERROR_MARKER: &str = "<!-- block error: ";
+
+/// Block 렌더링 (오류 경계). 페이지와 컨테이너 Block은 자식을 이 함수로 렌더링합니다.
+///
+/// `SiteConfig::block_errors`가 `Lenient`인 Block이 `check`에 실패하면 Block 대신 오류 표시
+/// (`div.block-error`)를 렌더링합니다. Site는 표시에 남은 주석을 `WarningKind::BlockFailed`로 옮깁니다.
+/// `Strict`면 그대로 렌더링하고, 실패는 Site의 검사 단계가 빌드 오류로 보고합니다.
+pub fn render_block(block: &dyn Block, ctx: &RenderContext) -> IRNode {
+    let lenient = ctx
+        .get::<SiteConfig>()
+        .is_some_and(|config| config.block_errors.mode_for(block) == BlockErrorMode::Lenient);
+    match block.check() {
+        Err(err) if lenient => block_error(block.kind(), &err.to_string()),
+        _ => block.render_to_ir(ctx),
+    }
+}
+
+/// 실패한 Block 자리의 오류 표시.
+fn block_error(kind: &str, message: &str) -> IRNode {
+    let name = short_kind(kind);
+    element(
+        "div",
+        with_tokens(class_attrs(&["block-error"]), &[("role", "alert")]),
+        vec![
+            Element::Raw(HtmlBlock::from_str(&format!(
+                "{}{}: {} -->",
+                BLOCK_ERROR_MARKER,
+                kind,
+                message.replace("--", "")
+            ))),
+            Element::Node(element(
+                "strong",
+                SharedAttrs::new(),
+                vec![Element::Text(Content::from_str(name, &verbatim_rule()))],
+            )),
+            Element::Text(Content::from_str(&format!(": {}", message), &verbatim_rule())),
+        ],
+    )
+}
+
+/// 실패한 Block 주석이면 (종류, 오류 메시지).
+pub(crate) fn block_error_marker(raw: &str) -> Option<(&str, &str)> {
+    raw.strip_prefix(BLOCK_ERROR_MARKER)?
+        .strip_suffix(" -->")?
+        .split_once(": ")
+}
+
+/// 타입 경로의 마지막 이름 (`quo::page::hbox::HBox` → `HBox`).
+pub(crate) fn short_kind(kind: &str) -> &str {
+    let path = kind.split('<').next().unwrap_or(kind);
+    path.rsplit("::").next().unwrap_or(path)
+}
+
+/// 블록 식별자.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct BlockId(String);
+
+impl BlockId {
+    pub fn new(id: &str) -> Self {
+        BlockId(id.to_string())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for BlockId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+// ============================================================================
+// Block 구현용 내부 헬퍼
+// ============================================================================
+
+/// 본문 텍스트용 규칙: 모든 타이포그래피 정규화 적용.
+pub(crate) fn prose_rule() -> rules::Default {
+    rules::Default {
+        rules: vec![RuleList::All],
+    }
+}
+
+/// 코드/URL용 규칙: 이스케이프만 적용 (스마트 쿼트 등으로 내용이 바뀌면 안 됨).
+pub(crate) fn verbatim_rule() -> rules::Default {
+    rules::Default { rules: vec![] }
+}
+
+/// 자식을 가지는 IRNode 생성.
+pub(crate) fn element(tag: &str, attrs: SharedAttrs, childs: Vec<Element>) -> IRNode {
+    IRNode::new(TagName::from_str(tag), attrs, childs)
+}
+
+/// Void IRNode 생성.
+pub(crate) fn void_element(tag: &str, attrs: SharedAttrs) -> IRNode {
+    IRNode::void(TagName::from_str(tag), attrs)
+}
+
+/// class 속성만 가진 SharedAttrs 생성.
+pub(crate) fn class_attrs(classes: &[&str]) -> SharedAttrs {
+    let rule = verbatim_rule();
+    SharedAttrs::new().with_added(
+        AttrKey::from_str("class"),
+        AttrValues::Set(
+            classes
+                .iter()
+                .map(|c| AttrValue::from_str(c, &rule))
+                .collect(),
+        ),
+    )
+}
+
+/// 단일 값(Token) 속성들로 SharedAttrs 생성. 값에는 이스케이프만 적용.
+pub(crate) fn token_attrs(pairs: &[(&str, &str)]) -> SharedAttrs {
+    with_tokens(SharedAttrs::new(), pairs)
+}
+
+/// 기존 SharedAttrs에 단일 값(Token) 속성들을 추가.
+/// `href`, `src`, `action`은 `AttrValue::url`, `srcset`은 `AttrValue::srcset`으로 URL 인코딩도 적용.
+pub(crate) fn with_tokens(attrs: SharedAttrs, pairs: &[(&str, &str)]) -> SharedAttrs {
+    let rule = verbatim_rule();
+    pairs.iter().fold(attrs, |attrs, (k, v)| {
+        let value = match *k {
+            "href" | "src" | "action" => AttrValue::url(v),
+            "srcset" => AttrValue::srcset(v),
+            _ => AttrValue::from_str(v, &rule),
+        };
+        attrs.with_added(AttrKey::from_str(k), AttrValues::Token(value))
+    })
+}
+
+/// 인라인 `style` 속성 추가. 값에는 이스케이프만 적용.
+pub(crate) fn with_style(attrs: SharedAttrs, style: &str) -> SharedAttrs {
+    attrs.with_added(
+        AttrKey::from_str("style"),
+        AttrValues::Token(AttrValue::from_str(style, &verbatim_rule())),
+    )
+}
+
+/// 단일 값 속성 읽기 (이스케이프 전 원래 값). 없거나 Token이 아니면 `None`.
+pub(crate) fn attr_token(node: &IRNode, key: &str) -> Option<String> {
+    match node.get_attrs().get().get(&AttrKey::from_str(key))? {
+        AttrValues::Token(value) => Some(unescape_html_chars(&value.to_string())),
+        _ => None,
+    }
 }
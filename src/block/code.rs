@@ -0,0 +1,104 @@
+//! # code.rs - CodeBlock
+//!
+//! ## 목적
+//! 소스 코드를 원문 그대로 보여주는 Block입니다.
+//!
+//! ## 렌더링 결과
+//! ```html
+//! <pre class="code-block"><code class="language-rust">fn main() {}</code></pre>
+//! ```
+//!
+//! ## 구현 상태
+//! - [x] 언어 class, 줄 번호 class
+//! - [x] 타이포그래피 규칙 미적용 (이스케이프만)
+//! - [x] 테마 CSS asset 선언 (`theme_stylesheet`)
+//! - [ ] TODO: 구문 강조 (외부 도구 출력을 HtmlBlock으로 주입)
+
+use std::hash::Hasher;
+
+use crate::block::block::{class_attrs, element, hash_into, verbatim_rule, Block};
+use crate::block::context::RenderContext;
+use crate::html::attributes::SharedAttrs;
+use crate::html::node::{Element, IRNode};
+use crate::html::trust::{Content, SafeString};
+use crate::metadata::builder::Metadata;
+use crate::metadata::types::Assets;
+
+/// 코드 블록.
+#[derive(Debug, Clone, Default, Hash)]
+pub struct CodeBlock {
+    language: Option<String>,
+    content: String,
+    show_line_numbers: bool,
+    theme_stylesheet: Option<String>,
+}
+
+impl CodeBlock {
+    pub fn new() -> Self {
+        CodeBlock::default()
+    }
+
+    pub fn language(mut self, language: &str) -> Self {
+        self.language = Some(language.to_string()).filter(|l| !l.is_empty());
+        self
+    }
+
+    pub fn content(mut self, content: &str) -> Self {
+        self.content = content.to_string();
+        self
+    }
+
+    pub fn show_line_numbers(mut self, show: bool) -> Self {
+        self.show_line_numbers = show;
+        self
+    }
+
+    /// 구문 강조 테마 CSS. 메타데이터 `Assets`로 선언되어 페이지 head에 주입됩니다.
+    pub fn theme_stylesheet(mut self, href: &str) -> Self {
+        self.theme_stylesheet = Some(href.to_string());
+        self
+    }
+
+    pub fn get_language(&self) -> Option<&str> {
+        self.language.as_deref()
+    }
+
+    pub fn get_content(&self) -> &str {
+        &self.content
+    }
+}
+
+impl Block for CodeBlock {
+    fn metadata(&self) -> Metadata {
+        match &self.theme_stylesheet {
+            Some(href) => Metadata::new().assets(Assets::new().stylesheet(href)),
+            None => Metadata::new(),
+        }
+    }
+
+    fn fingerprint(&self, state: &mut dyn Hasher) {
+        hash_into(self, state);
+    }
+
+    fn render_to_ir(&self, _ctx: &RenderContext) -> IRNode {
+        let mut pre_classes = vec!["code-block"];
+        if self.show_line_numbers {
+            pre_classes.push("line-numbers");
+        }
+
+        let code_attrs = match &self.language {
+            Some(lang) => class_attrs(&[&format!("language-{}", lang)]),
+            None => SharedAttrs::new(),
+        };
+
+        element(
+            "pre",
+            class_attrs(&pre_classes),
+            vec![Element::Node(element(
+                "code",
+                code_attrs,
+                vec![Element::Text(Content::from_str(&self.content, &verbatim_rule()))],
+            ))],
+        )
+    }
+}
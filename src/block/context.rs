@@ -0,0 +1,67 @@
+//! # context.rs - 렌더링 컨텍스트
+//!
+//! ## 목적
+//! Cite 계층에서 수집한 정보를 Block 렌더링 시점에 전달합니다.
+//!
+//! ## 구현 상태
+//! - [x] 병합된 메타데이터 (`metadata`)
+//! - [x] 렌더링 대상 (`target`)
+//! - [x] 글 방향 (`direction`: `TextDirection` 또는 문서 언어)
+//! - [x] 사이트 인덱스 (`index`: 블록 ID 맵, 페이지 링크, 카운터, 컬렉션)
+//! - [x] 공유 입력 의존성 기록 (`depends_on`, `Site::rebuild_affected`)
+
+use crate::cite::dependency::{DependencyRecorder, Input};
+use crate::cite::index::SiteIndex;
+use crate::metadata::builder::Metadata;
+use crate::metadata::map::ResolvedMetadata;
+use crate::metadata::types::{Language, RenderTarget, TextDirection};
+use crate::metadata::value::MetadataValue;
+
+/// Block 렌더링에 필요한 정보. `render_to_ir(&self, ctx)`로 전달됩니다.
+#[derive(Clone, Default)]
+pub struct RenderContext {
+    /// 계층적으로 병합된 메타데이터 (Site → Page → Block)
+    pub metadata: ResolvedMetadata,
+}
+
+impl RenderContext {
+    pub fn new(metadata: ResolvedMetadata) -> Self {
+        RenderContext { metadata }
+    }
+
+    /// 메타데이터 타입 안전 접근 (`ctx.metadata.get::<T>()`의 축약).
+    pub fn get<T: MetadataValue>(&self) -> Option<&T> {
+        self.metadata.get::<T>()
+    }
+
+    /// Site 분석 결과. Site 빌드로 렌더링할 때만 있습니다.
+    pub fn index(&self) -> Option<&SiteIndex> {
+        self.get::<SiteIndex>()
+    }
+
+    /// 렌더링 결과가 공유 입력에 의존함을 기록. 입력이 바뀌면 `Site::rebuild_affected`가
+    /// 이 페이지를 다시 렌더링합니다. 의존성을 기록하지 않는 렌더링에서는 무시됩니다.
+    pub fn depends_on(&self, input: Input) {
+        if let Some(recorder) = self.get::<DependencyRecorder>() {
+            recorder.record(input);
+        }
+    }
+
+    /// 렌더링 대상. 주입되지 않았으면 `RenderTarget::Screen`.
+    pub fn target(&self) -> RenderTarget {
+        self.get::<RenderTarget>().cloned().unwrap_or_default()
+    }
+
+    /// 글 방향. 메타데이터의 `TextDirection`, 없으면 `Language`로 정하고, 둘 다 없으면 LTR.
+    pub fn direction(&self) -> TextDirection {
+        match self.get::<TextDirection>() {
+            Some(direction) => *direction,
+            None => self.get::<Language>().map(Language::direction).unwrap_or_default(),
+        }
+    }
+
+    /// 글 방향만 바꾼 컨텍스트. 문서와 방향이 다른 영역의 자식을 렌더링할 때 씁니다.
+    pub fn with_direction(&self, direction: TextDirection) -> RenderContext {
+        RenderContext::new(self.metadata.merge(Metadata::new().direction(direction).map()))
+    }
+}
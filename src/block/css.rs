@@ -0,0 +1,77 @@
+//! # css.rs - Block 범위 CSS
+//!
+//! ## 목적
+//! Block이 자신이 의존하는 CSS를 직접 제공합니다 (`Block::css`). 규칙은 생성된 class 하나 아래로
+//! 범위가 묶이므로 다른 Block이나 사용자 CSS와 이름이 겹치지 않습니다. Cite 계층(`StyleBundler`)이
+//! 사이트 전체에서 모아 중복을 제거하고 지문이 붙은 스타일시트 하나로 기록한 뒤, 사용하는 페이지의
+//! `<head>`에 연결합니다.
+//!
+//! ## 사용 예시
+//! ```rust
+//! impl Block for Callout {
+//!     fn css(&self) -> Option<ScopedCss> {
+//!         Some(ScopedCss::new("callout", "&{border-left:4px solid}& > p{margin:0}"))
+//!     }
+//!
+//!     fn render_to_ir(&self, ctx: &RenderContext) -> IRNode {
+//!         let scope = self.css().unwrap().class();
+//!         element("aside", class_attrs(&["callout", &scope]), children)
+//!     }
+//! }
+//! ```
+//!
+//! ## 출력 결과
+//! ```css
+//! /* assets/blocks.3f9a1c0d5e7b2a64.css */
+//! .callout-5e7b2a64{border-left:4px solid}.callout-5e7b2a64 > p{margin:0}
+//! ```
+//!
+//! ## 설계 결정
+//! - **`&`가 범위 class**: 중첩 CSS와 같은 표기입니다. 규칙 안의 모든 `&`가 `.class`로 바뀌므로
+//!   문자열 값에는 `&`를 쓰지 않습니다.
+//! - **class는 내용에서 결정**: 이름 + 규칙의 FNV 해시 8자리입니다. 같은 규칙은 같은 class가 되어
+//!   한 번만 기록되고, 규칙이 바뀌면 class도 바뀌어 이전 캐시와 섞이지 않습니다.
+
+use crate::cite::incremental::FingerprintHasher;
+use crate::metadata::value::MetadataValue;
+
+/// 생성된 class 아래로 범위가 묶인 CSS 규칙.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ScopedCss {
+    name: String,
+    rules: String,
+}
+
+impl ScopedCss {
+    /// `name`은 class 접두사, `rules`의 `&`는 범위 class 선택자.
+    pub fn new(name: &str, rules: &str) -> Self {
+        ScopedCss {
+            name: name.to_string(),
+            rules: rules.to_string(),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// 범위 class. 예: `vbox-1a2b3c4d`
+    pub fn class(&self) -> String {
+        let hash = FingerprintHasher::new()
+            .with(&self.name)
+            .with(&self.rules)
+            .fingerprint()
+            .to_string();
+        format!("{}-{}", self.name, &hash[hash.len() - 8..])
+    }
+
+    /// `&`를 범위 class 선택자로 바꾼 CSS.
+    pub fn css(&self) -> String {
+        self.rules.replace('&', &format!(".{}", self.class()))
+    }
+}
+
+/// 페이지에 연결할 Block 스타일시트 URL. Cite 계층이 범위 CSS를 쓰는 페이지에 넣습니다.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BlockStylesheet(pub String);
+impl MetadataValue for BlockStylesheet {}
@@ -0,0 +1,340 @@
+//! # icon.rs - Icon (SVG 아이콘)
+//!
+//! ## 목적
+//! 사용자가 등록한 SVG 파일(`IconSet`)을 이름으로 불러 쓰는 아이콘 Block입니다.
+//! 기본은 스프라이트 참조(`<use href>`)이고, 빌드가 사용된 아이콘만 모아 스프라이트 시트(`icons.svg`)를
+//! 기록합니다. 스프라이트를 받을 수 없는 곳(메일, 외부 삽입)에는 `inline()`으로 SVG를 그대로 넣습니다.
+//!
+//! ## 사용 예시
+//! ```rust
+//! // 전역 메타데이터에 아이콘 등록 (icons/github.svg → "github")
+//! let icons = IconSet::load_dir("icons")?.icon("logo", include_str!("logo.svg"))?;
+//! let site = Site::new().metadata(Metadata::new().custom(icons));
+//!
+//! Icon::new("github");                      // 장식용 (aria-hidden)
+//! Icon::new("github").label("GitHub");      // 의미 있는 아이콘 (role="img")
+//! Icon::new("logo").inline().size("2rem");  // SVG 내용을 직접 삽입
+//! ```
+//!
+//! ## 렌더링 결과
+//! ```html
+//! <svg aria-hidden="true" class="icon icon-059aefe2 icon-github" focusable="false">
+//!   <use href="/icons.svg#icon-github"></use>
+//! </svg>
+//!
+//! <!-- inline() -->
+//! <svg aria-label="Logo" class="icon icon-059aefe2 icon-logo" role="img" style="width:2rem;height:2rem"
+//!      viewBox="0 0 24 24"><path d="..."/></svg>
+//! ```
+//! ```xml
+//! <!-- dist/icons.svg -->
+//! <svg xmlns="http://www.w3.org/2000/svg"><symbol id="icon-github" viewBox="0 0 24 24">...</symbol></svg>
+//! ```
+//!
+//! ## 구현 상태
+//! - [x] 아이콘 등록 (`IconSet::icon`, `IconSet::load_dir`)
+//! - [x] 스프라이트 참조 / 인라인 삽입
+//! - [x] 사용된 아이콘만 스프라이트 시트로 기록 (`IconSpriteGenerator`)
+//! - [x] 등록되지 않은 아이콘 경고 (`WarningKind::MissingIcon`)
+//! - [ ] TODO: 아이콘 SVG 최적화 (불필요한 속성, 주석 제거)
+//!
+//! ## 설계 결정
+//! - **크기는 글자에 맞춤**: 기본 `1em`, `fill: currentColor`이므로 주변 텍스트의 크기와 색을 따릅니다.
+//! - **SVG는 신뢰된 입력**: 사용자가 등록한 파일이므로 내용은 이스케이프하지 않고 그대로 씁니다.
+//!   루트 `<svg>`의 `viewBox`만 읽고 나머지 루트 속성(`width`, `xmlns` 등)은 버립니다.
+//! - **스프라이트는 외부 파일**: 모든 페이지가 같은 파일을 캐시로 씁니다. 같은 출처의 외부
+//!   `<use href>`는 모든 현대 브라우저가 지원합니다.
+
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::block::block::{Block, class_attrs, element, token_attrs, with_style, with_tokens};
+use crate::block::context::RenderContext;
+use crate::block::css::ScopedCss;
+use crate::html::node::{Element, IRNode};
+use crate::html::trust::HtmlBlock;
+use crate::metadata::builder::Metadata;
+use crate::metadata::value::MetadataValue;
+
+/// 스프라이트 시트 기본 출력 경로.
+pub const ICON_SPRITE_PATH: &str = "icons.svg";
+
+/// 아이콘 등록 오류.
+#[derive(Debug)]
+pub enum IconError {
+    /// 루트 `<svg>` 요소를 찾지 못함
+    InvalidSvg { name: String },
+    Io { path: PathBuf, source: io::Error },
+}
+
+impl Display for IconError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IconError::InvalidSvg { name } => write!(f, "icon '{}' is not an SVG document", name),
+            IconError::Io { path, source } => write!(f, "{}: {}", path.display(), source),
+        }
+    }
+}
+
+impl std::error::Error for IconError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            IconError::Io { source, .. } => Some(source),
+            IconError::InvalidSvg { .. } => None,
+        }
+    }
+}
+
+/// 등록된 SVG 하나 (루트 `viewBox`와 내용).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SvgIcon {
+    pub view_box: Option<String>,
+    pub body: String,
+}
+
+impl SvgIcon {
+    /// SVG 문서에서 루트 `viewBox`와 내용을 읽습니다. 루트 `<svg>`가 없으면 `None`.
+    pub fn parse(source: &str) -> Option<Self> {
+        let start = source.find("<svg")?;
+        let open_end = start + source[start..].find('>')?;
+        let open = &source[start..open_end];
+        if open.ends_with('/') {
+            return Some(SvgIcon {
+                view_box: attr_value(open, "viewBox"),
+                body: String::new(),
+            });
+        }
+        let close = source.rfind("</svg>")?;
+        (close > open_end).then(|| SvgIcon {
+            view_box: attr_value(open, "viewBox"),
+            body: source[open_end + 1..close].trim().to_string(),
+        })
+    }
+
+    /// 스프라이트의 `<symbol>`.
+    pub fn symbol(&self, id: &str) -> String {
+        match &self.view_box {
+            Some(view_box) => format!("<symbol id=\"{}\" viewBox=\"{}\">{}</symbol>", id, view_box, self.body),
+            None => format!("<symbol id=\"{}\">{}</symbol>", id, self.body),
+        }
+    }
+}
+
+/// 이름 → SVG. 전역 메타데이터로 넣습니다.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IconSet {
+    icons: BTreeMap<String, SvgIcon>,
+    sprite_path: String,
+}
+impl MetadataValue for IconSet {}
+
+impl Default for IconSet {
+    fn default() -> Self {
+        IconSet {
+            icons: BTreeMap::new(),
+            sprite_path: ICON_SPRITE_PATH.to_string(),
+        }
+    }
+}
+
+impl IconSet {
+    pub fn new() -> Self {
+        IconSet::default()
+    }
+
+    /// SVG 문서를 이름으로 등록. 같은 이름은 덮어씁니다.
+    pub fn icon(mut self, name: &str, svg: &str) -> Result<Self, IconError> {
+        let icon = SvgIcon::parse(svg).ok_or_else(|| IconError::InvalidSvg {
+            name: name.to_string(),
+        })?;
+        self.icons.insert(name.to_string(), icon);
+        Ok(self)
+    }
+
+    /// 디렉토리의 `*.svg`를 파일 이름(확장자 제외)으로 등록. 하위 디렉토리는 읽지 않습니다.
+    pub fn load_dir<P: AsRef<Path>>(dir: P) -> Result<Self, IconError> {
+        let dir = dir.as_ref();
+        let io_error = |source| IconError::Io {
+            path: dir.to_path_buf(),
+            source,
+        };
+        let mut paths: Vec<PathBuf> = fs::read_dir(dir)
+            .map_err(io_error)?
+            .filter_map(|entry| entry.ok().map(|e| e.path()))
+            .filter(|path| path.extension().is_some_and(|e| e == "svg"))
+            .collect();
+        paths.sort();
+
+        paths.into_iter().try_fold(IconSet::new(), |set, path| {
+            let name = path.file_stem().unwrap_or_default().to_string_lossy().to_string();
+            let svg = fs::read_to_string(&path).map_err(|source| IconError::Io {
+                path: path.clone(),
+                source,
+            })?;
+            set.icon(&name, &svg)
+        })
+    }
+
+    /// 스프라이트 시트 출력 경로 (출력 디렉토리 기준). 기본: `icons.svg`
+    pub fn sprite_path(mut self, path: &str) -> Self {
+        self.sprite_path = path.trim_start_matches('/').to_string();
+        self
+    }
+
+    pub fn get(&self, name: &str) -> Option<&SvgIcon> {
+        self.icons.get(name)
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.icons.contains_key(name)
+    }
+
+    pub fn sprite_file(&self) -> &str {
+        &self.sprite_path
+    }
+
+    /// 아이콘 하나의 스프라이트 참조 URL. 예: `/icons.svg#icon-github`
+    pub fn sprite_href(&self, name: &str) -> String {
+        format!("/{}#{}", self.sprite_path, symbol_id(name))
+    }
+
+    /// `names` 아이콘만 담은 스프라이트 시트. 등록되지 않은 이름은 건너뜁니다.
+    pub fn sprite<'a>(&self, names: impl IntoIterator<Item = &'a str>) -> String {
+        let symbols: String = names
+            .into_iter()
+            .filter_map(|name| Some(self.get(name)?.symbol(&symbol_id(name))))
+            .collect();
+        format!("<svg xmlns=\"http://www.w3.org/2000/svg\">{}</svg>\n", symbols)
+    }
+}
+
+/// 스프라이트 안의 `<symbol>` id.
+pub fn symbol_id(name: &str) -> String {
+    format!("icon-{}", name)
+}
+
+/// Block이 참조하는 아이콘. 방문자가 스프라이트에 넣을 아이콘을 찾는 데 씁니다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IconRef {
+    pub name: String,
+    /// 스프라이트 참조 여부 (`inline()`이면 false)
+    pub sprite: bool,
+}
+impl MetadataValue for IconRef {}
+
+/// SVG 아이콘 Block.
+#[derive(Debug, Clone)]
+pub struct Icon {
+    name: String,
+    label: Option<String>,
+    size: Option<String>,
+    inline: bool,
+}
+
+impl Icon {
+    pub fn new(name: &str) -> Self {
+        Icon {
+            name: name.to_string(),
+            label: None,
+            size: None,
+            inline: false,
+        }
+    }
+
+    /// 보조 기술에 읽힐 이름. 없으면 장식용으로 숨깁니다.
+    pub fn label(mut self, label: &str) -> Self {
+        self.label = Some(label.to_string());
+        self
+    }
+
+    /// 가로세로 크기 (CSS 길이). 기본: `1em`
+    pub fn size(mut self, size: &str) -> Self {
+        self.size = Some(size.to_string());
+        self
+    }
+
+    /// 스프라이트 참조 대신 SVG 내용을 직접 삽입.
+    pub fn inline(mut self) -> Self {
+        self.inline = true;
+        self
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl Block for Icon {
+    fn metadata(&self) -> Metadata {
+        Metadata::new().custom(IconRef {
+            name: self.name.clone(),
+            sprite: !self.inline,
+        })
+    }
+
+    fn css(&self) -> Option<ScopedCss> {
+        Some(ScopedCss::new(
+            "icon",
+            "&{width:1em;height:1em;fill:currentColor;vertical-align:-0.125em;flex-shrink:0}",
+        ))
+    }
+
+    fn render_to_ir(&self, ctx: &RenderContext) -> IRNode {
+        let default = IconSet::new();
+        let icons = ctx.get::<IconSet>().unwrap_or(&default);
+
+        let scope = self.css().map(|css| css.class()).unwrap_or_default();
+        let name_class = format!("icon-{}", self.name);
+        let mut attrs = class_attrs(&["icon", &scope, &name_class]);
+        attrs = match &self.label {
+            Some(label) => with_tokens(attrs, &[("role", "img"), ("aria-label", label)]),
+            None => with_tokens(attrs, &[("aria-hidden", "true"), ("focusable", "false")]),
+        };
+        if let Some(size) = &self.size {
+            attrs = with_style(attrs, &format!("width:{};height:{}", size, size));
+        }
+
+        if !self.inline {
+            let href = icons.sprite_href(&self.name);
+            let reference = element("use", token_attrs(&[("href", &href)]), vec![]);
+            return element("svg", attrs, vec![Element::Node(reference)]);
+        }
+        match icons.get(&self.name) {
+            Some(icon) => {
+                if let Some(view_box) = &icon.view_box {
+                    attrs = with_tokens(attrs, &[("viewBox", view_box)]);
+                }
+                element("svg", attrs, vec![Element::Raw(HtmlBlock::from_str(&icon.body))])
+            }
+            None => element("svg", attrs, vec![]),
+        }
+    }
+}
+
+/// 여는 태그에서 속성 값 읽기 (`"` 또는 `'`).
+fn attr_value(tag: &str, name: &str) -> Option<String> {
+    let mut rest = tag;
+    while let Some(pos) = rest.find(name) {
+        let before = rest[..pos].chars().last();
+        let after = rest[pos + name.len()..].trim_start();
+        rest = &rest[pos + name.len()..];
+        if !before.is_some_and(char::is_whitespace) {
+            continue;
+        }
+        let Some(value) = after.strip_prefix('=') else {
+            continue;
+        };
+        let value = value.trim_start();
+        let quote = value.chars().next()?;
+        if quote != '"' && quote != '\'' {
+            continue;
+        }
+        let end = value[1..].find(quote)?;
+        return Some(value[1..1 + end].to_string());
+    }
+    None
+}
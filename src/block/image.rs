@@ -0,0 +1,182 @@
+//! # image.rs - ImageBlock
+//!
+//! ## 목적
+//! 캡션을 가질 수 있는 단일 이미지 Block입니다.
+//! 빌드에 `ImageProcessor`가 등록되어 있으면 `SiteIndex`의 `ImageVariants`를 읽어
+//! 크기별 변형과 최신 형식(AVIF, WebP)을 `srcset`/`<source>`로 자동 연결합니다.
+//!
+//! ## 사용 예시
+//! ```rust
+//! ImageBlock::new("/images/photo.jpg", "바닷가 사진")
+//!     .caption("2024년 여름")
+//!     .sizes("(max-width: 768px) 100vw, 768px");
+//! ```
+//!
+//! ## 렌더링 결과
+//! ```html
+//! <!-- 변형 없음 -->
+//! <figure class="image-block"><img alt="바닷가 사진" loading="lazy" src="/images/photo.jpg"></figure>
+//!
+//! <!-- ImageProcessor 실행 후 -->
+//! <figure class="image-block">
+//!   <picture>
+//!     <source sizes="..." srcset="/images/photo-480w.avif 480w, ..." type="image/avif">
+//!     <source sizes="..." srcset="/images/photo-480w.webp 480w, ..." type="image/webp">
+//!     <img alt="바닷가 사진" height="1200" loading="lazy" sizes="..."
+//!          src="/images/photo.jpg" srcset="/images/photo-480w.jpg 480w, ..." width="1600">
+//!   </picture>
+//!   <figcaption>2024년 여름</figcaption>
+//! </figure>
+//! ```
+//!
+//! ## 설계 결정
+//! - **원본 `src` 유지**: `srcset`을 지원하지 않는 환경은 원본을 그대로 받습니다.
+//! - **원본 크기를 `width`/`height`로**: 변형이 있으면 원본 크기를 알 수 있으므로
+//!   레이아웃 이동(CLS)을 막기 위해 함께 출력합니다.
+
+use std::hash::Hasher;
+
+use crate::block::block::{Block, class_attrs, element, hash_into, token_attrs, void_element};
+use crate::block::context::RenderContext;
+use crate::block::inline::{Inline, parse_inline, render_inline};
+use crate::block::math::math_assets;
+use crate::cite::dependency::Input;
+use crate::cite::image::ImageVariants;
+use crate::html::attributes::SharedAttrs;
+use crate::html::node::{Element, IRNode};
+use crate::metadata::builder::Metadata;
+use crate::metadata::value::MetadataValue;
+
+/// `srcset`이 있고 `sizes`를 지정하지 않았을 때의 기본값.
+const DEFAULT_SIZES: &str = "100vw";
+
+/// Block이 참조하는 이미지 원본 (`src` 그대로). 방문자가 처리할 이미지를 찾는 데 씁니다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImageSource(pub String);
+impl MetadataValue for ImageSource {}
+
+/// 단일 이미지 Block.
+#[derive(Debug, Clone)]
+pub struct ImageBlock {
+    src: String,
+    alt: String,
+    caption: Option<Vec<Inline>>,
+    sizes: Option<String>,
+    lazy: bool,
+}
+
+impl ImageBlock {
+    /// 대체 텍스트는 필수입니다. 장식용 이미지는 빈 문자열을 넘깁니다.
+    pub fn new(src: &str, alt: &str) -> Self {
+        ImageBlock {
+            src: src.to_string(),
+            alt: alt.to_string(),
+            caption: None,
+            sizes: None,
+            lazy: true,
+        }
+    }
+
+    /// 인라인 마크업이 포함된 캡션 (`<figcaption>`).
+    pub fn caption(mut self, caption: &str) -> Self {
+        self.caption = Some(parse_inline(caption));
+        self
+    }
+
+    /// `sizes` 속성. 변형이 있을 때만 출력됩니다. 기본: `100vw`
+    pub fn sizes(mut self, sizes: &str) -> Self {
+        self.sizes = Some(sizes.to_string());
+        self
+    }
+
+    /// `loading="lazy"` 여부. 첫 화면의 큰 이미지는 끕니다. 기본: true
+    pub fn lazy(mut self, lazy: bool) -> Self {
+        self.lazy = lazy;
+        self
+    }
+
+    pub fn src(&self) -> &str {
+        &self.src
+    }
+
+    pub fn alt(&self) -> &str {
+        &self.alt
+    }
+}
+
+impl Block for ImageBlock {
+    fn metadata(&self) -> Metadata {
+        let metadata = Metadata::new().custom(ImageSource(self.src.clone()));
+        match &self.caption {
+            Some(caption) if Inline::has_math(caption) => metadata.assets(math_assets()),
+            _ => metadata,
+        }
+    }
+
+    fn fingerprint(&self, state: &mut dyn Hasher) {
+        hash_into(&(&self.src, &self.alt, &self.caption, &self.sizes, self.lazy), state);
+    }
+
+    fn render_to_ir(&self, ctx: &RenderContext) -> IRNode {
+        ctx.depends_on(Input::asset(&self.src));
+        let processed = ctx
+            .index()
+            .and_then(|index| index.get::<ImageVariants>())
+            .and_then(|variants| variants.get(&self.src));
+
+        let mut pairs = vec![("alt", self.alt.clone()), ("src", self.src.clone())];
+        if self.lazy {
+            pairs.push(("loading", "lazy".to_string()));
+        }
+
+        let image = match processed {
+            None => void_element("img", attrs(&pairs)),
+            Some(processed) => {
+                let sizes = self.sizes.as_deref().unwrap_or(DEFAULT_SIZES).to_string();
+                let mut sources: Vec<Element> = processed
+                    .modern_formats()
+                    .into_iter()
+                    .filter_map(|format| {
+                        let srcset = processed.srcset(format)?;
+                        let source = attrs(&[
+                            ("type", format.mime_type().to_string()),
+                            ("srcset", srcset),
+                            ("sizes", sizes.clone()),
+                        ]);
+                        Some(Element::Node(void_element("source", source)))
+                    })
+                    .collect();
+
+                pairs.push(("width", processed.width.to_string()));
+                pairs.push(("height", processed.height.to_string()));
+                if let Some(srcset) = processed.srcset(processed.format) {
+                    pairs.push(("srcset", srcset));
+                    pairs.push(("sizes", sizes));
+                }
+                let img = void_element("img", attrs(&pairs));
+                if sources.is_empty() {
+                    img
+                } else {
+                    sources.push(Element::Node(img));
+                    element("picture", SharedAttrs::new(), sources)
+                }
+            }
+        };
+
+        let mut childs = vec![Element::Node(image)];
+        if let Some(caption) = &self.caption {
+            childs.push(Element::Node(element(
+                "figcaption",
+                SharedAttrs::new(),
+                render_inline(caption, ctx),
+            )));
+        }
+        element("figure", class_attrs(&["image-block"]), childs)
+    }
+}
+
+/// 값이 `String`인 단일 값 속성들.
+fn attrs(pairs: &[(&str, String)]) -> SharedAttrs {
+    let pairs: Vec<(&str, &str)> = pairs.iter().map(|(k, v)| (*k, v.as_str())).collect();
+    token_attrs(&pairs)
+}
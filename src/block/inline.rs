@@ -0,0 +1,337 @@
+//! # inline.rs - 인라인 마크업
+//!
+//! ## 목적
+//! 문단, 제목, 목록 항목 안의 텍스트 수준 마크업을 표현하고 IRNode 자식으로 변환합니다.
+//!
+//! ## 지원 문법 (Markdown 부분집합)
+//! ```text
+//! **strong**, __strong__   → <strong>
+//! *em*, _em_               → <em>
+//! `code`                   → <code> (타이포그래피 규칙 미적용)
+//! [text](href)             → <a href>
+//! ![alt](src)              → <img>
+//! $tex$                   → <span class="math"> (구분자는 `MathDelimiters`)
+//! 줄 끝 공백 두 개 / '\'    → <br>
+//! \*                       → 문자 그대로
+//! ```
+//!
+//! ## 사용 예시
+//! ```rust
+//! let inlines = parse_inline("**Quo** is `fast`");
+//! let childs = render_inline(&inlines, ctx);  // Vec<Element>
+//! ```
+//!
+//! ## 설계 결정
+//! - **코드, URL, 수식은 verbatim 규칙**: 스마트 쿼트가 코드나 URL, TeX 원문을 바꾸지 않도록
+//!   이스케이프만 적용합니다.
+//! - **실패 시 문자 그대로**: 닫히지 않은 구분자는 텍스트로 남깁니다 (문서 보존).
+
+use crate::block::block::{element, prose_rule, verbatim_rule, void_element};
+use crate::block::context::RenderContext;
+use crate::block::math::{MathDelimiters, render_math};
+use crate::html::attributes::{AttrValues, SharedAttrs};
+use crate::html::node::Element;
+use crate::html::trust::{AttrKey, AttrValue, Content, SafeString};
+use crate::page::vars::render_var;
+
+/// 인라인 마크업 트리.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Inline {
+    Text(String),
+    Strong(Vec<Inline>),
+    Emphasis(Vec<Inline>),
+    Code(String),
+    Link { href: String, children: Vec<Inline> },
+    Image { src: String, alt: String },
+    /// TeX 원문 (구분자 제외)
+    Math(String),
+    /// 사이트 변수 이름 (`SiteVars`, `var!`)
+    Var(String),
+    LineBreak,
+}
+
+impl Inline {
+    /// 마크업을 제거한 순수 텍스트.
+    pub fn plain_text(inlines: &[Inline]) -> String {
+        let mut text = String::new();
+        for inline in inlines {
+            match inline {
+                Inline::Text(s) | Inline::Code(s) | Inline::Math(s) | Inline::Var(s) => text.push_str(s),
+                Inline::Strong(children)
+                | Inline::Emphasis(children)
+                | Inline::Link { children, .. } => text.push_str(&Inline::plain_text(children)),
+                Inline::Image { alt, .. } => text.push_str(alt),
+                Inline::LineBreak => text.push(' '),
+            }
+        }
+        text
+    }
+
+    /// 수식이 들어 있는지 (수식 asset 선언용).
+    pub fn has_math(inlines: &[Inline]) -> bool {
+        inlines.iter().any(|inline| match inline {
+            Inline::Math(_) => true,
+            Inline::Strong(children)
+            | Inline::Emphasis(children)
+            | Inline::Link { children, .. } => Inline::has_math(children),
+            _ => false,
+        })
+    }
+}
+
+/// 인라인 마크업 파싱. 수식은 기본 구분자(`MathDelimiters::default`)로 찾습니다.
+pub fn parse_inline(src: &str) -> Vec<Inline> {
+    parse_inline_with(src, &MathDelimiters::default())
+}
+
+/// 수식 구분자를 지정한 인라인 마크업 파싱.
+pub fn parse_inline_with(src: &str, math: &MathDelimiters) -> Vec<Inline> {
+    let chars: Vec<char> = src.chars().collect();
+    parse_range(&chars, 0, chars.len(), math)
+}
+
+/// 인라인 트리를 IRNode 자식 목록으로 변환. 변수(`Inline::Var`)는 `ctx`의 `SiteVars`로 치환합니다.
+pub fn render_inline(inlines: &[Inline], ctx: &RenderContext) -> Vec<Element> {
+    let prose = prose_rule();
+    let verbatim = verbatim_rule();
+
+    inlines
+        .iter()
+        .map(|inline| match inline {
+            Inline::Text(text) => Element::Text(Content::from_str(text, &prose)),
+            Inline::Strong(children) => Element::Node(element(
+                "strong",
+                SharedAttrs::new(),
+                render_inline(children, ctx),
+            )),
+            Inline::Emphasis(children) => Element::Node(element(
+                "em",
+                SharedAttrs::new(),
+                render_inline(children, ctx),
+            )),
+            Inline::Code(code) => Element::Node(element(
+                "code",
+                SharedAttrs::new(),
+                vec![Element::Text(Content::from_str(code, &verbatim))],
+            )),
+            Inline::Link { href, children } => Element::Node(element(
+                "a",
+                SharedAttrs::new().with_added(
+                    AttrKey::from_str("href"),
+                    AttrValues::Token(AttrValue::url(href)),
+                ),
+                render_inline(children, ctx),
+            )),
+            Inline::Image { src, alt } => Element::Node(void_element(
+                "img",
+                SharedAttrs::new()
+                    .with_added(
+                        AttrKey::from_str("src"),
+                        AttrValues::Token(AttrValue::url(src)),
+                    )
+                    .with_added(
+                        AttrKey::from_str("alt"),
+                        AttrValues::Token(AttrValue::from_str(alt, &prose)),
+                    ),
+            )),
+            Inline::Math(tex) => Element::Node(render_math(tex, false)),
+            Inline::Var(name) => render_var(name, ctx),
+            Inline::LineBreak => Element::Node(void_element("br", SharedAttrs::new())),
+        })
+        .collect()
+}
+
+fn parse_range(chars: &[char], start: usize, end: usize, math: &MathDelimiters) -> Vec<Inline> {
+    let mut result = Vec::new();
+    let mut text = String::new();
+    let mut i = start;
+
+    while i < end {
+        // 수식 구분자가 '\(' 같은 이스케이프보다 먼저
+        if let Some((tex, next)) = parse_math(chars, i, end, math) {
+            flush_text(&mut text, &mut result);
+            result.push(Inline::Math(tex));
+            i = next;
+            continue;
+        }
+        let c = chars[i];
+        match c {
+            '\\' if i + 1 < end && chars[i + 1] == '\n' => {
+                flush_text(&mut text, &mut result);
+                result.push(Inline::LineBreak);
+                i += 2;
+            }
+            '\\' if i + 1 < end && chars[i + 1].is_ascii_punctuation() => {
+                text.push(chars[i + 1]);
+                i += 2;
+            }
+            '\n' if text.ends_with("  ") => {
+                let trimmed = text.trim_end_matches(' ').len();
+                text.truncate(trimmed);
+                flush_text(&mut text, &mut result);
+                result.push(Inline::LineBreak);
+                i += 1;
+            }
+            '`' => {
+                let run = run_length(chars, i, end, '`');
+                let close = find_sequence(chars, i + run, end, &vec!['`'; run]);
+                match close {
+                    Some(close) => {
+                        flush_text(&mut text, &mut result);
+                        let code: String = chars[i + run..close].iter().collect();
+                        result.push(Inline::Code(code.trim().to_string()));
+                        i = close + run;
+                    }
+                    None => {
+                        text.extend(&chars[i..i + run]);
+                        i += run;
+                    }
+                }
+            }
+            '!' if i + 1 < end && chars[i + 1] == '[' => match parse_link(chars, i + 1, end) {
+                Some((label, href, next)) => {
+                    flush_text(&mut text, &mut result);
+                    result.push(Inline::Image {
+                        src: href,
+                        alt: chars[label.0..label.1].iter().collect(),
+                    });
+                    i = next;
+                }
+                None => {
+                    text.push(c);
+                    i += 1;
+                }
+            },
+            '[' => match parse_link(chars, i, end) {
+                Some((label, href, next)) => {
+                    flush_text(&mut text, &mut result);
+                    result.push(Inline::Link {
+                        href,
+                        children: parse_range(chars, label.0, label.1, math),
+                    });
+                    i = next;
+                }
+                None => {
+                    text.push(c);
+                    i += 1;
+                }
+            },
+            '*' | '_' => {
+                // '_'는 단어 중간(snake_case)에서는 구분자가 아님
+                let intraword = c == '_' && i > start && chars[i - 1].is_alphanumeric();
+                let run = run_length(chars, i, end, c);
+                let parsed = if intraword {
+                    None
+                } else if run >= 2 {
+                    find_closing(chars, i + 2, end, &[c, c])
+                        .map(|close| (Inline::Strong(parse_range(chars, i + 2, close, math)), close + 2))
+                } else {
+                    find_closing(chars, i + 1, end, &[c])
+                        .map(|close| (Inline::Emphasis(parse_range(chars, i + 1, close, math)), close + 1))
+                };
+                match parsed {
+                    Some((inline, next)) => {
+                        flush_text(&mut text, &mut result);
+                        result.push(inline);
+                        i = next;
+                    }
+                    None => {
+                        text.extend(&chars[i..i + run]);
+                        i += run;
+                    }
+                }
+            }
+            _ => {
+                text.push(c);
+                i += 1;
+            }
+        }
+    }
+
+    flush_text(&mut text, &mut result);
+    result
+}
+
+fn flush_text(text: &mut String, result: &mut Vec<Inline>) {
+    if !text.is_empty() {
+        result.push(Inline::Text(std::mem::take(text)));
+    }
+}
+
+fn run_length(chars: &[char], start: usize, end: usize, c: char) -> usize {
+    chars[start..end].iter().take_while(|&&x| x == c).count()
+}
+
+fn find_sequence(chars: &[char], from: usize, end: usize, seq: &[char]) -> Option<usize> {
+    (from..end.saturating_sub(seq.len() - 1)).find(|&i| chars[i..i + seq.len()] == *seq)
+}
+
+/// 강조 닫는 구분자 탐색. 내용이 비어 있거나 공백으로 끝나면 구분자로 보지 않습니다.
+fn find_closing(chars: &[char], from: usize, end: usize, delim: &[char]) -> Option<usize> {
+    if from >= end || chars[from].is_whitespace() {
+        return None;
+    }
+    let mut i = from;
+    while let Some(close) = find_sequence(chars, i, end, delim) {
+        if close > from && !chars[close - 1].is_whitespace() {
+            return Some(close);
+        }
+        i = close + 1;
+    }
+    None
+}
+
+/// 인라인 수식 파싱. (TeX 원문, 다음 위치) 반환.
+/// 여는 구분자와 닫는 구분자가 같으면(`$`) 안쪽 양 끝에 공백이 없어야 하고 닫은 뒤 숫자가 오면 안 됩니다.
+fn parse_math(chars: &[char], i: usize, end: usize, math: &MathDelimiters) -> Option<(String, usize)> {
+    math.inline.iter().find_map(|(open, close)| {
+        let open: Vec<char> = open.chars().collect();
+        let close: Vec<char> = close.chars().collect();
+        let from = i + open.len();
+        if from > end || chars[i..from] != *open {
+            return None;
+        }
+        // '$'로 여는 경우 '$$'는 인라인 수식이 아님
+        let doubled = (i > 0 && chars.get(i - 1) == open.last()) || chars.get(from) == open.first();
+        if open == close && doubled {
+            return None;
+        }
+        let at = find_sequence(chars, from, end, &close)?;
+        let tex: String = chars[from..at].iter().collect();
+        let next = at + close.len();
+        let symmetric = open == close
+            && (tex.starts_with(char::is_whitespace)
+                || tex.ends_with(char::is_whitespace)
+                || chars[..end].get(next).is_some_and(|c| c.is_ascii_digit() || Some(c) == close.first()));
+        if tex.trim().is_empty() || symmetric {
+            return None;
+        }
+        Some((tex.trim().to_string(), next))
+    })
+}
+
+/// `[label](href)` 파싱. (label 범위, href, 다음 위치) 반환.
+fn parse_link(chars: &[char], open: usize, end: usize) -> Option<((usize, usize), String, usize)> {
+    let mut depth = 0;
+    let mut close = None;
+    for (i, &c) in chars.iter().enumerate().take(end).skip(open) {
+        match c {
+            '[' => depth += 1,
+            ']' => {
+                depth -= 1;
+                if depth == 0 {
+                    close = Some(i);
+                    break;
+                }
+            }
+            _ => (),
+        }
+    }
+    let close = close?;
+    if close + 1 >= end || chars[close + 1] != '(' {
+        return None;
+    }
+    let href_end = (close + 2..end).find(|&i| chars[i] == ')')?;
+    let href: String = chars[close + 2..href_end].iter().collect();
+    Some(((open + 1, close), href.trim().to_string(), href_end + 1))
+}
@@ -0,0 +1,221 @@
+//! # markdown.rs - Markdown → Block 변환
+//!
+//! ## 목적
+//! Markdown 원문을 Block 목록으로 변환합니다. 콘텐츠 디렉토리 로더
+//! (`page::loader`)가 파일 본문을 Block으로 바꿀 때 사용합니다.
+//!
+//! ## 지원 문법 (CommonMark 부분집합)
+//! ```text
+//! # 제목 ~ ###### 제목     → Heading
+//! ``` / ~~~ 코드 펜스      → CodeBlock (언어 지정 가능)
+//! > 인용                   → QuoteBlock (내부 재귀 파싱)
+//! - / * / + 항목           → ListBlock (unordered)
+//! 1. / 1) 항목             → ListBlock (ordered)
+//! --- / *** / ___          → Divider
+//! $$ 수식 $$ (여러 줄 가능)  → MathBlock (구분자는 `MathDelimiters`)
+//! 그 외                    → Paragraph (빈 줄로 구분)
+//! ```
+//! 인라인 문법은 `inline.rs` 참고.
+//!
+//! ## 사용 예시
+//! ```rust
+//! let blocks = parse_markdown("# Hello\n\nQuo로 만든 **첫 글**");
+//! assert_eq!(blocks.len(), 2);
+//! ```
+//!
+//! ## 구현 상태
+//! - [x] 제목, 문단, 코드 펜스, 인용, 목록, 구분선
+//! - [x] 수식 (별도 줄 `MathBlock`, 인라인 `Inline::Math`)
+//! - [ ] TODO: 중첩 목록, 표, 각주
+
+use crate::block::block::Block;
+use crate::block::code::CodeBlock;
+use crate::block::inline::parse_inline_with;
+use crate::block::math::{MathBlock, MathDelimiters};
+use crate::block::prose::{Divider, Heading, ListBlock, Paragraph, QuoteBlock};
+
+/// Markdown 원문을 Block 목록으로 변환. 수식은 기본 구분자로 찾습니다.
+pub fn parse_markdown(src: &str) -> Vec<Box<dyn Block>> {
+    parse_markdown_with(src, &MathDelimiters::default())
+}
+
+/// 수식 구분자를 지정한 Markdown 변환.
+pub fn parse_markdown_with(src: &str, math: &MathDelimiters) -> Vec<Box<dyn Block>> {
+    let lines: Vec<&str> = src.lines().collect();
+    let mut blocks: Vec<Box<dyn Block>> = Vec::new();
+    let mut i = 0;
+
+    while i < lines.len() {
+        let line = lines[i];
+        let trimmed = line.trim();
+
+        if trimmed.is_empty() {
+            i += 1;
+            continue;
+        }
+
+        if let Some((fence, lang)) = fence_open(trimmed) {
+            let mut code = Vec::new();
+            i += 1;
+            while i < lines.len() && !lines[i].trim().starts_with(fence) {
+                code.push(lines[i]);
+                i += 1;
+            }
+            i += 1; // 닫는 펜스
+            blocks.push(Box::new(
+                CodeBlock::new().language(lang).content(&code.join("\n")),
+            ));
+            continue;
+        }
+
+        if let Some((open, close)) = math.display_open(trimmed) {
+            let mut tex = Vec::new();
+            let mut rest = &trimmed[open.len()..];
+            i += 1;
+            loop {
+                if let Some(inner) = rest.trim_end().strip_suffix(close) {
+                    tex.push(inner);
+                    break;
+                }
+                tex.push(rest);
+                let Some(next) = lines.get(i) else {
+                    break;
+                };
+                rest = next;
+                i += 1;
+            }
+            blocks.push(Box::new(MathBlock::new(&tex.join("\n"))));
+            continue;
+        }
+
+        if let Some((level, text)) = heading(line) {
+            blocks.push(Box::new(Heading::new(level, parse_inline_with(text, math))));
+            i += 1;
+            continue;
+        }
+
+        if is_thematic_break(trimmed) {
+            blocks.push(Box::new(Divider::new()));
+            i += 1;
+            continue;
+        }
+
+        if trimmed.starts_with('>') {
+            let mut inner = Vec::new();
+            while i < lines.len() && lines[i].trim_start().starts_with('>') {
+                let rest = &lines[i].trim_start()[1..];
+                inner.push(rest.strip_prefix(' ').unwrap_or(rest));
+                i += 1;
+            }
+            blocks.push(Box::new(QuoteBlock::new(parse_markdown_with(&inner.join("\n"), math))));
+            continue;
+        }
+
+        if let Some((ordered, _)) = list_marker(line) {
+            let mut items: Vec<String> = Vec::new();
+            while i < lines.len() {
+                let current = lines[i];
+                match list_marker(current) {
+                    Some((o, text)) if o == ordered => items.push(text.to_string()),
+                    Some(_) => break,
+                    None if !current.trim().is_empty() && current.starts_with([' ', '\t']) => {
+                        let Some(item) = items.last_mut() else {
+                            break;
+                        };
+                        item.push('\n');
+                        item.push_str(current.trim());
+                    }
+                    None => break,
+                }
+                i += 1;
+            }
+            blocks.push(Box::new(ListBlock::new(
+                ordered,
+                items.iter().map(|item| parse_inline_with(item, math)).collect(),
+            )));
+            continue;
+        }
+
+        let mut paragraph = Vec::new();
+        while i < lines.len() && !lines[i].trim().is_empty() && !starts_block(lines[i], math) {
+            paragraph.push(lines[i].trim_start());
+            i += 1;
+        }
+        if paragraph.is_empty() {
+            // starts_block과 위 분기의 판단이 어긋나는 경우 무한 루프 방지
+            paragraph.push(trimmed);
+            i += 1;
+        }
+        let text = paragraph.join("\n");
+        blocks.push(Box::new(Paragraph::from_inlines(parse_inline_with(text.trim_end(), math))));
+    }
+
+    blocks
+}
+
+/// 새 Block을 시작하는 줄인지 (문단 종료 조건).
+fn starts_block(line: &str, math: &MathDelimiters) -> bool {
+    let trimmed = line.trim();
+    fence_open(trimmed).is_some()
+        || math.display_open(trimmed).is_some()
+        || heading(line).is_some()
+        || is_thematic_break(trimmed)
+        || trimmed.starts_with('>')
+        || list_marker(line).is_some()
+}
+
+/// 코드 펜스 시작이면 (펜스 문자열, 언어) 반환.
+fn fence_open(trimmed: &str) -> Option<(&'static str, &str)> {
+    [("```", '`'), ("~~~", '~')]
+        .into_iter()
+        .find(|(fence, _)| trimmed.starts_with(fence))
+        .map(|(fence, c)| (fence, trimmed.trim_start_matches(c).trim()))
+}
+
+/// ATX 제목이면 (레벨, 텍스트) 반환.
+fn heading(line: &str) -> Option<(u8, &str)> {
+    let trimmed = line.trim_start();
+    if line.len() - trimmed.len() > 3 {
+        return None;
+    }
+    let level = trimmed.chars().take_while(|&c| c == '#').count();
+    if !(1..=6).contains(&level) {
+        return None;
+    }
+    let rest = &trimmed[level..];
+    if !rest.is_empty() && !rest.starts_with([' ', '\t']) {
+        return None;
+    }
+    // 닫는 '#'들은 제거: "## 제목 ##"
+    let text = rest.trim().trim_end_matches('#').trim_end();
+    Some((level as u8, text))
+}
+
+fn is_thematic_break(trimmed: &str) -> bool {
+    let compact: String = trimmed.chars().filter(|c| !c.is_whitespace()).collect();
+    compact.len() >= 3
+        && ['-', '*', '_']
+            .iter()
+            .any(|&m| compact.chars().all(|c| c == m))
+}
+
+/// 목록 항목이면 (ordered 여부, 항목 텍스트) 반환.
+fn list_marker(line: &str) -> Option<(bool, &str)> {
+    let trimmed = line.trim_start();
+    if line.len() - trimmed.len() > 3 {
+        return None;
+    }
+    for marker in ["- ", "* ", "+ "] {
+        if let Some(text) = trimmed.strip_prefix(marker) {
+            return Some((false, text.trim()));
+        }
+    }
+    let digits = trimmed.chars().take_while(|c| c.is_ascii_digit()).count();
+    if (1..=9).contains(&digits) {
+        let rest = &trimmed[digits..];
+        if let Some(text) = rest.strip_prefix(". ").or_else(|| rest.strip_prefix(") ")) {
+            return Some((true, text.trim()));
+        }
+    }
+    None
+}
@@ -0,0 +1,171 @@
+//! # math.rs - 수식 (MathBlock, 인라인 수식)
+//!
+//! ## 목적
+//! TeX 수식을 렌더링합니다. 문단 안의 인라인 수식(`$...$`, `Inline::Math`)과 별도 줄의 수식
+//! (`$$...$$`, `MathBlock`)이 같은 방식(`render_math`)으로 출력되고, 페이지에서 KaTeX가 조판합니다.
+//! KaTeX는 HTML과 함께 MathML을 출력하므로 화면 낭독기도 수식을 읽습니다.
+//!
+//! ## 사용 예시
+//! ```rust
+//! Paragraph::new("넓이는 $\\pi r^2$ 입니다.");
+//! MathBlock::new("\\int_0^1 x^2\\,dx = \\frac{1}{3}");
+//!
+//! // 구분자 바꾸기: `$`를 통화 기호로 쓰는 사이트
+//! let delimiters = MathDelimiters::new().inline("\\(", "\\)").display("\\[", "\\]");
+//! let site = Site::new().metadata(Metadata::new().custom(delimiters.clone()));
+//! ContentLoader::new("content").math_delimiters(delimiters).load(SimplePage::from_content)?;
+//! ```
+//!
+//! ## 렌더링 결과
+//! ```html
+//! <p>넓이는 <span class="math math-inline">\pi r^2</span> 입니다.</p>
+//! <div class="math math-display">\int_0^1 x^2\,dx = \frac{1}{3}</div>
+//! <!-- head: katex.min.css, katex.min.js, /assets/math.js (수식이 있는 페이지만) -->
+//! ```
+//!
+//! ## 구현 상태
+//! - [x] 인라인 수식 (`Inline::Math`), 별도 줄 수식 (`MathBlock`, Markdown `$$` 줄)
+//! - [x] 사이트별 구분자 (`MathDelimiters`)
+//! - [x] KaTeX asset 자동 주입 (수식을 쓰는 Block의 `Assets`), 초기화 스크립트 기록 (`MathScriptGenerator`)
+//! - [ ] TODO: 빌드 시 조판 (외부 도구 출력을 HtmlBlock으로 주입), KaTeX 자체 호스팅
+//!
+//! ## 설계 결정
+//! - **구분자는 파싱 시점 설정**: 인라인 문법은 Block을 만들 때 파싱되므로 `MathDelimiters`는
+//!   렌더링 컨텍스트가 아니라 파서(`parse_inline_with`, `ContentLoader::math_delimiters`)에 넘깁니다.
+//!   사이트 메타데이터에 함께 두면 설정을 한 곳에서 관리할 수 있습니다.
+//! - **`$` 규칙**: 여는 `$` 뒤와 닫는 `$` 앞에 공백이 없어야 하고, 닫는 `$` 바로 뒤에 숫자가 오면
+//!   수식으로 보지 않습니다 (`$5 and $10`은 문자 그대로).
+//! - **`\(`, `\[`는 기본에서 제외**: 기존 문서의 `\(`는 괄호 이스케이프이므로 필요한 사이트만 추가합니다.
+//! - **원문 보존**: 스크립트 없이도 TeX 원문이 보이도록 요소 안에는 수식 원문만 둡니다.
+
+use std::hash::Hasher;
+
+use crate::block::block::{Block, class_attrs, element, hash_into, verbatim_rule};
+use crate::block::context::RenderContext;
+use crate::html::node::{Element, IRNode};
+use crate::html::trust::{Content, SafeString};
+use crate::metadata::builder::Metadata;
+use crate::metadata::types::Assets;
+use crate::metadata::value::MetadataValue;
+
+/// KaTeX 스타일시트.
+pub const KATEX_STYLESHEET: &str = "https://cdn.jsdelivr.net/npm/katex@0.16.11/dist/katex.min.css";
+
+/// KaTeX 스크립트.
+pub const KATEX_SCRIPT: &str = "https://cdn.jsdelivr.net/npm/katex@0.16.11/dist/katex.min.js";
+
+/// 수식 초기화 스크립트 출력 경로 (출력 디렉토리 기준).
+pub const MATH_SCRIPT_PATH: &str = "assets/math.js";
+
+/// 수식 초기화 스크립트 URL (`Assets`로 주입).
+pub const MATH_SCRIPT_URL: &str = "/assets/math.js";
+
+/// 수식 초기화 스크립트. `.math` 요소마다 원문을 KaTeX로 조판합니다.
+pub const MATH_SCRIPT: &str = r#"(()=>{
+if(!window.katex)return;
+for(const el of document.querySelectorAll(".math")){
+  katex.render(el.textContent,el,{displayMode:el.classList.contains("math-display"),throwOnError:false});
+}
+})();
+"#;
+
+/// 수식 구분자. 앞에 둔 쌍을 먼저 찾습니다.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MathDelimiters {
+    /// 문단 안의 수식. 기본: `$...$`
+    pub inline: Vec<(String, String)>,
+    /// 별도 줄의 수식 (Markdown). 기본: `$$...$$`
+    pub display: Vec<(String, String)>,
+}
+impl MetadataValue for MathDelimiters {}
+
+impl Default for MathDelimiters {
+    fn default() -> Self {
+        MathDelimiters::new().inline("$", "$").display("$$", "$$")
+    }
+}
+
+impl MathDelimiters {
+    /// 구분자가 하나도 없는 설정 (수식 인식 안 함).
+    pub fn new() -> Self {
+        MathDelimiters {
+            inline: Vec::new(),
+            display: Vec::new(),
+        }
+    }
+
+    pub fn inline(mut self, open: &str, close: &str) -> Self {
+        if !open.is_empty() && !close.is_empty() {
+            self.inline.push((open.to_string(), close.to_string()));
+        }
+        self
+    }
+
+    pub fn display(mut self, open: &str, close: &str) -> Self {
+        if !open.is_empty() && !close.is_empty() {
+            self.display.push((open.to_string(), close.to_string()));
+        }
+        self
+    }
+
+    /// 별도 줄 수식을 여는 줄이면 닫는 구분자 반환.
+    pub(crate) fn display_open<'a>(&'a self, trimmed: &str) -> Option<(&'a str, &'a str)> {
+        self.display
+            .iter()
+            .find(|(open, _)| trimmed.starts_with(open.as_str()))
+            .map(|(open, close)| (open.as_str(), close.as_str()))
+    }
+}
+
+/// 수식을 쓰는 Block이 선언하는 asset.
+pub fn math_assets() -> Assets {
+    Assets::new()
+        .stylesheet(KATEX_STYLESHEET)
+        .script(KATEX_SCRIPT)
+        .script(MATH_SCRIPT_URL)
+}
+
+/// 수식 요소. 인라인은 `span`, 별도 줄은 `div`.
+pub fn render_math(tex: &str, display: bool) -> IRNode {
+    let (tag, class) = match display {
+        true => ("div", "math-display"),
+        false => ("span", "math-inline"),
+    };
+    element(
+        tag,
+        class_attrs(&["math", class]),
+        vec![Element::Text(Content::from_str(tex, &verbatim_rule()))],
+    )
+}
+
+/// 별도 줄 수식 Block.
+#[derive(Debug, Clone)]
+pub struct MathBlock {
+    tex: String,
+}
+
+impl MathBlock {
+    pub fn new(tex: &str) -> Self {
+        MathBlock {
+            tex: tex.trim().to_string(),
+        }
+    }
+
+    pub fn tex(&self) -> &str {
+        &self.tex
+    }
+}
+
+impl Block for MathBlock {
+    fn metadata(&self) -> Metadata {
+        Metadata::new().assets(math_assets())
+    }
+
+    fn fingerprint(&self, state: &mut dyn Hasher) {
+        hash_into(&self.tex, state);
+    }
+
+    fn render_to_ir(&self, _ctx: &RenderContext) -> IRNode {
+        render_math(&self.tex, true)
+    }
+}
@@ -108,7 +108,7 @@
 //!
 //! ### Block 트레이트
 //! ```rust
-//! pub trait Block {
+//! pub trait Block: Send + Sync {
 //!     /// 블록을 IRNode로 변환. 렌더링의 핵심 메서드.
 //!     fn render_to_ir(&self, ctx: &RenderContext) -> IRNode;
 //!     
@@ -315,9 +315,9 @@
 //!             );
 //!         }
 //!         
-//!         // 자식 Block들 렌더링
+//!         // 자식 Block들 렌더링 (오류 경계: 실패한 자식은 설정에 따라 오류 표시로)
 //!         for child in &self.children {
-//!             elements.push(child.render_to_ir(ctx));
+//!             elements.push(render_block(child.as_ref(), ctx));
 //!         }
 //!         
 //!         // 전체 래핑
@@ -335,14 +335,18 @@
 //! ## 구현해야 할 Block 목록
 //!
 //! ### 우선순위: 높음 (기본 콘텐츠)
-//! - [ ] `Paragraph`: 일반 문단
+//! - [x] `Paragraph`: 일반 문단
+//! - [x] `Heading`: 제목 (h1~h6)
+//! - [x] `ListBlock`: 목록
 //! - [ ] `CodeBlock`: 코드 블록 (구문 강조)
-//! - [ ] `MathBlock`: 수식 (KaTeX/MathJax)
-//! - [ ] `ImageBlock`: 단일 이미지 (캡션 포함)
-//! - [ ] `QuoteBlock`: 인용문
+//!   - [x] 원문 그대로 출력, 언어 class
+//! - [x] `MathBlock`: 수식 (KaTeX, 문단 안의 인라인 수식 포함)
+//! - [x] `ImageBlock`: 단일 이미지 (캡션 포함, `ImageProcessor` 변형의 srcset 자동 연결)
+//! - [x] `QuoteBlock`: 인용문
 //!
 //! ### 우선순위: 중간 (향상된 콘텐츠)
 //! - [ ] `CalloutBlock`: Note, Warning, Tip, Info
+//! - [x] `Citation`, `Bibliography`: 인용과 페이지별 참고 문헌 목록 (BibTeX, CSL-JSON)
 //! - [ ] `DiagramBlock`: Mermaid, Graphviz
 //! - [ ] `TableBlock`: 마크다운 스타일 테이블
 //! - [ ] `ImageGallery`: 이미지 갤러리
@@ -360,7 +364,7 @@
 //! - [ ] `VBox`: 수직 배치
 //! - [ ] `Grid`: 그리드 레이아웃
 //! - [ ] `Spacer`: 공백
-//! - [ ] `Divider`: 구분선
+//! - [x] `Divider`: 구분선
 //!
 //! ## 설계 결정
 //!
@@ -447,6 +451,17 @@
 //! - 소유권 이동으로 명확한 책임
 //! - Page가 모든 IRNode를 수집하여 트리 구성
 //!
+//! ### 왜 실패는 `check()`로 알리는가? (오류 경계)
+//! `render_to_ir`는 실패하지 않는 함수로 두고, 외부 도구가 없거나 구성 값이 잘못된 경우는
+//! `check()`가 알립니다. 기본(`BlockErrorMode::Strict`)은 빌드 오류이고, `SiteConfig::block_errors`를
+//! `Lenient`로 두면(사이트 전체 또는 `block_errors_for::<B>()`로 Block 종류별) 그 Block 자리에
+//! 오류 표시를 렌더링하고 경고만 남깁니다. 페이지와 컨테이너는 자식을 `render_block`으로 렌더링하므로
+//! 실패한 Block 하나가 빌드 전체를 멈추지 않습니다.
+//!
+//! ```html
+//! <div class="block-error" role="alert"><!-- block error: my_site::Diagram: dot not found --><strong>Diagram</strong>: dot not found</div>
+//! ```
+//!
 //! ## 사용 예시
 //!
 //! ### 간단한 Block 사용
@@ -504,4 +519,15 @@
 //! - [MDX 컴포넌트](https://mdxjs.com/)
 //! - [Notion 블록 시스템](https://developers.notion.com/reference/block)
 
+#[allow(clippy::module_inception)]
 pub mod block;
+pub mod context;
+pub mod css;
+pub mod bibliography;
+pub mod inline;
+pub mod prose;
+pub mod code;
+pub mod image;
+pub mod math;
+pub mod icon;
+pub mod markdown;
@@ -0,0 +1,212 @@
+//! # prose.rs - 본문 Block
+//!
+//! ## 목적
+//! 일반 문서 본문을 구성하는 기본 Block들을 제공합니다.
+//!
+//! ## 제공 Block
+//! - `Heading`: 제목 (h1~h6)
+//! - `Paragraph`: 문단
+//! - `QuoteBlock`: 인용문 (다른 Block 포함 가능)
+//! - `ListBlock`: 순서 있는/없는 목록
+//! - `Divider`: 구분선
+//!
+//! ## 사용 예시
+//! ```rust
+//! let blocks: Vec<Box<dyn Block>> = vec![
+//!     Box::new(Heading::text(1, "Quo 소개")),
+//!     Box::new(Paragraph::new("**타입 안전** 정적 사이트 생성기")),
+//!     Box::new(ListBlock::unordered(vec!["빠름", "안전함"])),
+//!     Box::new(Divider::new()),
+//! ];
+//! ```
+
+use std::hash::Hasher;
+
+use crate::block::block::{element, hash_into, render_block, void_element, Block};
+use crate::block::context::RenderContext;
+use crate::block::inline::{parse_inline, render_inline, Inline};
+use crate::block::math::math_assets;
+use crate::html::attributes::SharedAttrs;
+use crate::html::node::{Element, IRNode};
+use crate::metadata::builder::Metadata;
+
+/// 제목 Block. `level`은 1~6으로 제한됩니다.
+#[derive(Debug, Clone)]
+pub struct Heading {
+    level: u8,
+    content: Vec<Inline>,
+}
+
+impl Heading {
+    pub fn new(level: u8, content: Vec<Inline>) -> Self {
+        Heading {
+            level: level.clamp(1, 6),
+            content,
+        }
+    }
+
+    /// 인라인 마크업이 포함된 텍스트로 생성.
+    pub fn text(level: u8, text: &str) -> Self {
+        Heading::new(level, parse_inline(text))
+    }
+
+    pub fn level(&self) -> u8 {
+        self.level
+    }
+
+    pub fn content(&self) -> &[Inline] {
+        &self.content
+    }
+}
+
+impl Block for Heading {
+    fn metadata(&self) -> Metadata {
+        inline_metadata(&[&self.content])
+    }
+
+    fn fingerprint(&self, state: &mut dyn Hasher) {
+        hash_into(&(self.level, &self.content), state);
+    }
+
+    fn render_to_ir(&self, ctx: &RenderContext) -> IRNode {
+        element(
+            &format!("h{}", self.level),
+            SharedAttrs::new(),
+            render_inline(&self.content, ctx),
+        )
+    }
+}
+
+/// 문단 Block.
+#[derive(Debug, Clone)]
+pub struct Paragraph {
+    content: Vec<Inline>,
+}
+
+impl Paragraph {
+    /// 인라인 마크업이 포함된 텍스트로 생성.
+    pub fn new(text: &str) -> Self {
+        Paragraph {
+            content: parse_inline(text),
+        }
+    }
+
+    pub fn from_inlines(content: Vec<Inline>) -> Self {
+        Paragraph { content }
+    }
+
+    pub fn content(&self) -> &[Inline] {
+        &self.content
+    }
+}
+
+impl Block for Paragraph {
+    fn metadata(&self) -> Metadata {
+        inline_metadata(&[&self.content])
+    }
+
+    fn fingerprint(&self, state: &mut dyn Hasher) {
+        hash_into(&self.content, state);
+    }
+
+    fn render_to_ir(&self, ctx: &RenderContext) -> IRNode {
+        element("p", SharedAttrs::new(), render_inline(&self.content, ctx))
+    }
+}
+
+/// 인용문 Block. 다른 Block들을 자식으로 가집니다.
+pub struct QuoteBlock {
+    children: Vec<Box<dyn Block>>,
+}
+
+impl QuoteBlock {
+    pub fn new(children: Vec<Box<dyn Block>>) -> Self {
+        QuoteBlock { children }
+    }
+}
+
+impl Block for QuoteBlock {
+    fn children(&self) -> Vec<&dyn Block> {
+        self.children.iter().map(|c| c.as_ref()).collect()
+    }
+
+    fn render_to_ir(&self, ctx: &RenderContext) -> IRNode {
+        element(
+            "blockquote",
+            SharedAttrs::new(),
+            self.children
+                .iter()
+                .map(|child| Element::Node(render_block(child.as_ref(), ctx)))
+                .collect(),
+        )
+    }
+}
+
+/// 목록 Block. 각 항목은 인라인 콘텐츠입니다.
+#[derive(Debug, Clone)]
+pub struct ListBlock {
+    ordered: bool,
+    items: Vec<Vec<Inline>>,
+}
+
+impl ListBlock {
+    pub fn new(ordered: bool, items: Vec<Vec<Inline>>) -> Self {
+        ListBlock { ordered, items }
+    }
+
+    /// 순서 없는 목록 (`<ul>`).
+    pub fn unordered(items: Vec<&str>) -> Self {
+        ListBlock::new(false, items.into_iter().map(parse_inline).collect())
+    }
+
+    /// 순서 있는 목록 (`<ol>`).
+    pub fn ordered(items: Vec<&str>) -> Self {
+        ListBlock::new(true, items.into_iter().map(parse_inline).collect())
+    }
+}
+
+impl Block for ListBlock {
+    fn metadata(&self) -> Metadata {
+        let items: Vec<&[Inline]> = self.items.iter().map(Vec::as_slice).collect();
+        inline_metadata(&items)
+    }
+
+    fn fingerprint(&self, state: &mut dyn Hasher) {
+        hash_into(&(self.ordered, &self.items), state);
+    }
+
+    fn render_to_ir(&self, ctx: &RenderContext) -> IRNode {
+        element(
+            if self.ordered { "ol" } else { "ul" },
+            SharedAttrs::new(),
+            self.items
+                .iter()
+                .map(|item| Element::Node(element("li", SharedAttrs::new(), render_inline(item, ctx))))
+                .collect(),
+        )
+    }
+}
+
+/// 구분선 Block (`<hr>`).
+#[derive(Debug, Clone, Default)]
+pub struct Divider;
+
+impl Divider {
+    pub fn new() -> Self {
+        Divider
+    }
+}
+
+impl Block for Divider {
+    fn render_to_ir(&self, _ctx: &RenderContext) -> IRNode {
+        void_element("hr", SharedAttrs::new())
+    }
+}
+
+/// 인라인 콘텐츠에 수식이 있으면 수식 asset을 선언하는 메타데이터.
+fn inline_metadata(contents: &[&[Inline]]) -> Metadata {
+    match contents.iter().any(|content| Inline::has_math(content)) {
+        true => Metadata::new().assets(math_assets()),
+        false => Metadata::new(),
+    }
+}
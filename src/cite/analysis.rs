@@ -0,0 +1,292 @@
+//! # analysis.rs - 기본 분석 방문자
+//!
+//! ## 목적
+//! Site가 기본으로 실행하는 분석 단계들입니다. 결과는 `SiteIndex`의 질의 API로 읽습니다.
+//! - `IdGenerator`: Block ID → 위치(페이지 URL, anchor) 맵, 중복 ID 감지, 제목 anchor (`SiteConfig::heading_anchors`)
+//! - `Counter`: `Numbered`를 선언한 Block(또는 `NumberedKeys`의 키)에 페이지별·종류별 번호 부여
+//! - `LinkResolver`: 페이지 간 링크와 역방향 링크(백링크)
+//!
+//! ## 설계 결정
+//! - **링크/ID는 렌더링 결과에서 수집**: 링크는 Paragraph 안의 인라인 등 Block 내부 여러 곳에
+//!   있으므로, 페이지 최상위 Block을 IR로 렌더링하여 `<a href>`와 `id` 속성을 모읍니다.
+//! - **URL 정규화**: 쿼리/fragment, 끝의 `index.html`과 `/`를 떼어 비교합니다.
+//!   (`/blog/`, `/blog/index.html`, `/blog`는 같은 페이지)
+
+use std::collections::{BTreeMap, BTreeSet, btree_map::Entry};
+
+use crate::block::block::{Block, BlockId, attr_token};
+use crate::block::context::RenderContext;
+use crate::cite::visitor::{PageContext, SiteContext, Visitor, VisitorResult};
+use crate::html::node::{Element, IRNode};
+use crate::html::trust::decode_url;
+use crate::metadata::builder::Metadata;
+use crate::metadata::types::{Numbered, NumberedKeys, Redirect};
+use crate::metadata::value::MetadataValue;
+use crate::page::anchors::anchor_headings;
+use crate::page::page::{Page, page_context};
+
+// ============================================================================
+// IdGenerator
+// ============================================================================
+
+/// Block의 위치.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlockLocation {
+    /// Block이 속한 페이지 URL
+    pub page_url: String,
+    /// 페이지 안의 anchor (`id` 속성)
+    pub anchor: String,
+}
+
+impl BlockLocation {
+    /// `/page.html#anchor` 형태의 링크 대상.
+    pub fn href(&self) -> String {
+        format!("{}#{}", self.page_url, self.anchor)
+    }
+}
+
+/// Block ID 맵.
+#[derive(Debug, Clone, Default)]
+pub struct BlockIds {
+    pub blocks: BTreeMap<BlockId, BlockLocation>,
+    /// 두 번 이상 선언된 ID (먼저 선언된 위치가 유지됨)
+    pub duplicates: Vec<(BlockId, BlockLocation)>,
+    /// 페이지 URL → 렌더링 결과에 있는 모든 `id` 속성
+    pub anchors: BTreeMap<String, BTreeSet<String>>,
+}
+impl MetadataValue for BlockIds {}
+
+/// `BlockIds`를 만드는 방문자.
+#[derive(Debug, Clone, Default)]
+pub struct IdGenerator {
+    ids: BlockIds,
+}
+
+impl Visitor for IdGenerator {
+    fn visit_page(&mut self, page: &dyn Page, ctx: &SiteContext) {
+        let anchors = self.ids.anchors.entry(ctx.url.clone()).or_default();
+        // 제목 anchor는 문서 조립 단계가 본문 전체에 붙이므로 같은 본문으로 다시 계산
+        let Some(style) = ctx.site.site_config().heading_anchors else {
+            return;
+        };
+        if ctx.metadata.contains::<Redirect>() {
+            return;
+        }
+        let base = ctx.metadata.merge(Metadata::new().custom(ctx.index.clone()).map());
+        let content = anchor_headings(page.layout(&page_context(page, &base)), style);
+        collect_attr(&content, "id", &mut |id| {
+            anchors.insert(id);
+        });
+    }
+
+    fn visit_block(&mut self, block: &dyn Block, ctx: &PageContext) {
+        if let Some(id) = block.id() {
+            let location = BlockLocation {
+                page_url: ctx.url.to_string(),
+                anchor: id.as_str().to_string(),
+            };
+            match self.ids.blocks.entry(id) {
+                Entry::Occupied(entry) => {
+                    self.ids.duplicates.push((entry.key().clone(), location))
+                }
+                Entry::Vacant(entry) => {
+                    entry.insert(location);
+                }
+            }
+        }
+
+        if ctx.depth == 0 {
+            let ir = block.render_to_ir(&RenderContext::new(ctx.metadata.clone()));
+            let anchors = self.ids.anchors.entry(ctx.url.to_string()).or_default();
+            collect_attr(&ir, "id", &mut |id| {
+                anchors.insert(id);
+            });
+        }
+    }
+
+    fn finalize(&self) -> VisitorResult {
+        VisitorResult::new().custom(self.ids.clone())
+    }
+}
+
+// ============================================================================
+// Counter
+// ============================================================================
+
+/// 자동 번호. (정규화된 페이지 URL, Block ID) → 번호 (1부터)
+#[derive(Debug, Clone, Default)]
+pub struct Counters {
+    pub numbers: BTreeMap<(String, BlockId), usize>,
+    /// (페이지 URL, 종류) → 개수
+    pub totals: BTreeMap<(String, String), usize>,
+    /// (정규화된 페이지 URL, 종류, 키) → 번호 (`NumberedKeys`)
+    pub keys: BTreeMap<(String, String, String), usize>,
+}
+impl MetadataValue for Counters {}
+
+/// `Counters`를 만드는 방문자.
+#[derive(Debug, Clone, Default)]
+pub struct Counter {
+    counters: Counters,
+}
+
+impl Visitor for Counter {
+    fn visit_block(&mut self, block: &dyn Block, ctx: &PageContext) {
+        let metadata = block.metadata();
+        let Some(kind) = metadata.get::<Numbered>() else {
+            return;
+        };
+        let url = normalize_url(ctx.url);
+        let total = self.counters.totals.entry((url.clone(), kind.0.clone())).or_insert(0);
+
+        if let Some(keys) = metadata.get::<NumberedKeys>() {
+            for key in &keys.0 {
+                if let Entry::Vacant(entry) = self.counters.keys.entry((url.clone(), kind.0.clone(), key.clone())) {
+                    *total += 1;
+                    entry.insert(*total);
+                }
+            }
+            return;
+        }
+        let Some(id) = block.id() else {
+            return;
+        };
+        *total += 1;
+        self.counters.numbers.insert((url, id), *total);
+    }
+
+    fn finalize(&self) -> VisitorResult {
+        VisitorResult::new().custom(self.counters.clone())
+    }
+}
+
+// ============================================================================
+// LinkResolver
+// ============================================================================
+
+/// 페이지 간 링크 그래프. 키와 값은 모두 정규화된 URL.
+#[derive(Debug, Clone, Default)]
+pub struct LinkGraph {
+    /// 페이지 → 그 페이지가 링크하는 사이트 내부 URL (중복 제거, 등장 순서)
+    pub links: BTreeMap<String, Vec<String>>,
+    /// 대상 URL → 그 URL을 링크하는 페이지 (URL 순)
+    pub backlinks: BTreeMap<String, BTreeSet<String>>,
+    /// 페이지 → 외부 링크 (중복 제거, 등장 순서)
+    pub external: BTreeMap<String, Vec<String>>,
+}
+impl MetadataValue for LinkGraph {}
+
+/// `LinkGraph`를 만드는 방문자.
+#[derive(Debug, Clone, Default)]
+pub struct LinkResolver {
+    graph: LinkGraph,
+}
+
+impl Visitor for LinkResolver {
+    fn visit_page(&mut self, _page: &dyn Page, ctx: &SiteContext) {
+        let url = normalize_url(&ctx.url);
+        self.graph.links.entry(url.clone()).or_default();
+        self.graph.external.entry(url).or_default();
+    }
+
+    fn visit_block(&mut self, block: &dyn Block, ctx: &PageContext) {
+        if ctx.depth != 0 {
+            return;
+        }
+        let page_url = normalize_url(ctx.url);
+        let ir = block.render_to_ir(&RenderContext::new(ctx.metadata.clone()));
+        let graph = &mut self.graph;
+        collect_attr(&ir, "href", &mut |href| {
+            if is_external(&href) {
+                let external = graph.external.entry(page_url.clone()).or_default();
+                if !external.contains(&href) {
+                    external.push(href);
+                }
+                return;
+            }
+            let Some(target) = resolve_href(ctx.url, &href) else {
+                return;
+            };
+            if target == page_url {
+                return;
+            }
+            let links = graph.links.entry(page_url.clone()).or_default();
+            if !links.contains(&target) {
+                links.push(target.clone());
+            }
+            graph.backlinks.entry(target).or_default().insert(page_url.clone());
+        });
+    }
+
+    fn finalize(&self) -> VisitorResult {
+        VisitorResult::new().custom(self.graph.clone())
+    }
+}
+
+// ============================================================================
+// 헬퍼
+// ============================================================================
+
+/// IR 트리의 모든 `key` 속성 값을 문서 순서로 전달.
+pub(crate) fn collect_attr(node: &IRNode, key: &str, f: &mut dyn FnMut(String)) {
+    if let Some(value) = attr_token(node, key) {
+        f(value);
+    }
+    for child in node.get_childs() {
+        if let Element::Node(child) = child {
+            collect_attr(child, key, f);
+        }
+    }
+}
+
+/// `scheme:` 또는 `//`로 시작하는 링크.
+pub(crate) fn is_external(href: &str) -> bool {
+    href.starts_with("//")
+        || href
+            .split_once(':')
+            .is_some_and(|(scheme, _)| !scheme.is_empty() && !scheme.contains('/'))
+}
+
+/// 쿼리/fragment, 끝의 `index.html`과 `/`를 뗀 URL. 루트는 `/`.
+pub(crate) fn normalize_url(url: &str) -> String {
+    let url = decode_url(url.split(['?', '#']).next().unwrap_or(""));
+    let url = url.as_str();
+    let url = match url.strip_suffix("index.html") {
+        Some(dir) if dir.ends_with('/') => dir,
+        _ => url,
+    };
+    let url = url.trim_end_matches('/');
+    if url.is_empty() {
+        "/".to_string()
+    } else {
+        url.to_string()
+    }
+}
+
+/// 페이지 URL 기준으로 내부 링크를 정규화된 절대 경로로 해석. fragment만 있는 링크는 `None`.
+pub(crate) fn resolve_href(page_url: &str, href: &str) -> Option<String> {
+    let path = href.split(['?', '#']).next().unwrap_or("");
+    if path.is_empty() {
+        return None;
+    }
+    if path.starts_with('/') {
+        return Some(normalize_url(path));
+    }
+
+    let base_dir = match page_url.rfind('/') {
+        Some(i) => &page_url[..=i],
+        None => "/",
+    };
+    let mut segments: Vec<&str> = base_dir.split('/').filter(|s| !s.is_empty()).collect();
+    for segment in path.split('/') {
+        match segment {
+            "" | "." => {}
+            ".." => {
+                segments.pop();
+            }
+            other => segments.push(other),
+        }
+    }
+    let trailing = if path.ends_with('/') { "/" } else { "" };
+    Some(normalize_url(&format!("/{}{}", segments.join("/"), trailing)))
+}
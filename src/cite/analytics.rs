@@ -0,0 +1,158 @@
+//! # analytics.rs - 방문 통계 스크립트 삽입
+//!
+//! ## 목적
+//! 개인정보를 수집하지 않는 방문 통계(Plausible, umami) 또는 직접 지정한 조각을 모든 페이지에 넣는
+//! 내장 플러그인입니다. 페이지마다 `head()`에 스크립트를 추가하지 않고 Site에 한 번 등록합니다.
+//!
+//! ## 사용 예시
+//! ```rust
+//! let site = Site::new().plugin(Analytics::plausible("blog.example.com"));
+//!
+//! // 직접 운영하는 umami
+//! Site::new().plugin(Analytics::umami("94db1cb1-74f4-4a40-ad6c-962362670409")
+//!     .script_src("https://stats.example.com/script.js"));
+//!
+//! // 그 밖의 서비스: 본문 끝에 삽입
+//! Site::new().plugin(Analytics::custom("<script src=\"/stats.js\" defer></script>")
+//!     .position(SnippetPosition::BodyEnd));
+//! ```
+//!
+//! ## 출력 결과
+//! ```html
+//! <head>...<script data-domain="blog.example.com" defer src="https://plausible.io/js/script.js"></script></head>
+//! ```
+//!
+//! ## 구현 상태
+//! - [x] Plausible, umami, 직접 지정한 조각
+//! - [x] head 끝 / body 끝 삽입
+//! - [x] 발행되지 않은 페이지(초안 미리보기, 예약)와 제외 경로는 건너뜀
+//! - [ ] TODO: 대체 렌더링 대상(인쇄용 등) 문서 제외
+//!
+//! ## 설계 결정
+//! - **플러그인 훅으로 구현**: 렌더링이 끝난 문서에 `after_page_render`로 넣으므로 Page와 Layout이
+//!   통계 서비스를 몰라도 됩니다. 다른 플러그인과 같은 순서 규칙(등록 순)을 따릅니다.
+//! - **조각은 신뢰된 입력**: `custom`의 HTML은 그대로 넣습니다. Plausible/umami의 값은 이스케이프합니다.
+//! - **증분 빌드**: 설정은 지문에 들어가지 않습니다 (`Plugin` 규칙). 설정을 바꾸면 `Site::template_version`을 올립니다.
+
+use crate::cite::cite::RenderedPage;
+use crate::cite::plugin::{PageHook, Plugin};
+use crate::html::trust::{HtmlBlock, escape_html_chars};
+use crate::metadata::types::PageStatus;
+
+/// 조각을 넣을 위치.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SnippetPosition {
+    /// `</head>` 바로 앞 (기본)
+    #[default]
+    Head,
+    /// `</body>` 바로 앞
+    BodyEnd,
+}
+
+/// 통계 서비스.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AnalyticsProvider {
+    Plausible { domain: String },
+    Umami { website_id: String },
+    Custom { html: String },
+}
+
+/// 방문 통계 플러그인.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Analytics {
+    provider: AnalyticsProvider,
+    script_src: Option<String>,
+    position: SnippetPosition,
+    exclude: Vec<String>,
+}
+
+impl Analytics {
+    fn new(provider: AnalyticsProvider) -> Self {
+        Analytics {
+            provider,
+            script_src: None,
+            position: SnippetPosition::default(),
+            exclude: Vec::new(),
+        }
+    }
+
+    /// Plausible. `domain`은 Plausible에 등록한 사이트 도메인.
+    pub fn plausible(domain: &str) -> Self {
+        Analytics::new(AnalyticsProvider::Plausible {
+            domain: domain.to_string(),
+        })
+    }
+
+    /// umami. 기본 스크립트는 umami cloud.
+    pub fn umami(website_id: &str) -> Self {
+        Analytics::new(AnalyticsProvider::Umami {
+            website_id: website_id.to_string(),
+        })
+    }
+
+    /// 그 밖의 서비스. `html`을 그대로 넣습니다.
+    pub fn custom(html: &str) -> Self {
+        Analytics::new(AnalyticsProvider::Custom { html: html.to_string() })
+    }
+
+    /// 스크립트 주소 (직접 운영하는 서버, 확장 스크립트 등). `custom`에는 쓰이지 않습니다.
+    pub fn script_src(mut self, src: &str) -> Self {
+        self.script_src = Some(src.to_string());
+        self
+    }
+
+    pub fn position(mut self, position: SnippetPosition) -> Self {
+        self.position = position;
+        self
+    }
+
+    /// 출력 경로가 `prefix`로 시작하는 문서는 건너뜀. 예: `"admin/"`
+    pub fn exclude(mut self, prefix: &str) -> Self {
+        self.exclude.push(prefix.trim_start_matches('/').to_string());
+        self
+    }
+
+    /// 삽입할 HTML.
+    pub fn snippet(&self) -> String {
+        match &self.provider {
+            AnalyticsProvider::Plausible { domain } => format!(
+                "<script data-domain=\"{}\" defer src=\"{}\"></script>",
+                escape_html_chars(domain),
+                escape_html_chars(self.script_src.as_deref().unwrap_or("https://plausible.io/js/script.js"))
+            ),
+            AnalyticsProvider::Umami { website_id } => format!(
+                "<script data-website-id=\"{}\" defer src=\"{}\"></script>",
+                escape_html_chars(website_id),
+                escape_html_chars(self.script_src.as_deref().unwrap_or("https://cloud.umami.is/script.js"))
+            ),
+            AnalyticsProvider::Custom { html } => html.clone(),
+        }
+    }
+}
+
+impl Plugin for Analytics {
+    fn name(&self) -> &str {
+        "analytics"
+    }
+
+    fn after_page_render(&self, document: &mut RenderedPage, hook: &mut PageHook) {
+        let published = matches!(hook.metadata.get::<PageStatus>(), None | Some(PageStatus::Published));
+        let path = document.path.to_string_lossy().replace('\\', "/");
+        if !published || self.exclude.iter().any(|prefix| path.starts_with(prefix.as_str())) {
+            return;
+        }
+
+        let html = document.html.as_str();
+        let (tag, at) = match self.position {
+            SnippetPosition::Head => ("</head>", html.find("</head>")),
+            SnippetPosition::BodyEnd => ("</body>", html.rfind("</body>")),
+        };
+        match at {
+            Some(at) => {
+                let html = format!("{}{}{}", &html[..at], self.snippet(), &html[at..]);
+                document.html = HtmlBlock::from_str(&html);
+            }
+            None => hook.warn(&format!("document has no {}; analytics snippet not inserted", tag)),
+        }
+    }
+}
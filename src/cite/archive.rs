@@ -0,0 +1,132 @@
+//! # archive.rs - 날짜별 아카이브 목록
+//!
+//! ## 목적
+//! 발행일이 있는 페이지를 연월별로 묶어 보여 줍니다. 태그 목록 페이지(`TaxonomyList`)와 나란히
+//! 시간순 아카이브나 변경 기록 페이지를 만들 수 있습니다. 항목은 `SiteIndex::pages_by_year_month`에서 읽습니다.
+//!
+//! ## 사용 예시
+//! ```rust
+//! site.register_page(Box::new(
+//!     SimplePage::new("Archive", "blog/archive.html").add_block(Box::new(ArchiveList::new())),
+//! ));
+//!
+//! // 연도별 페이지
+//! SimplePage::new("2024", "blog/2024/index.html").add_block(Box::new(ArchiveList::new().year(2024)))
+//!
+//! // 달 안의 순서 (기본: 최신순)
+//! ArchiveList::new().order(SortOrder::by(SortKey::PublishDate).then(SortKey::Title))
+//! ```
+//!
+//! ## 렌더링 결과
+//! ```html
+//! <div class="archive">
+//!   <section class="archive-month">
+//!     <h2><time datetime="2024-03">2024-03</time></h2>
+//!     <ul class="archive-list">
+//!       <li><a href="/blog/second.html">Second Post</a> <time datetime="2024-03-02">2024-03-02</time></li>
+//!       <li><a href="/blog/first.html">First Post</a> <time datetime="2024-03-01">2024-03-01</time></li>
+//!     </ul>
+//!   </section>
+//! </div>
+//! ```
+//! 언어가 있는 페이지는 표시 텍스트만 `Locale` 형식으로 바뀝니다 (ko: `2024년 3월`, `2024년 3월 2일`).
+//!
+//! ## 설계 결정
+//! - **발행일 없는 페이지 제외**: 날짜가 없으면 어느 달에도 속하지 않으므로 목록에 넣지 않습니다.
+//! - **의존성 기록**: 사이트 전체 질의이므로 목록에 보인 페이지를 `Input::Page`로 기록합니다.
+
+use crate::block::block::{Block, class_attrs, element, prose_rule, token_attrs};
+use crate::block::context::RenderContext;
+use crate::cite::collection::SortOrder;
+use crate::cite::dependency::Input;
+use crate::cite::taxonomy::entry_item;
+use crate::html::attributes::SharedAttrs;
+use crate::html::node::{Element, IRNode};
+use crate::html::trust::{Content, SafeString};
+use crate::metadata::format::Locale;
+
+/// 연월별 아카이브 Block.
+#[derive(Debug, Clone)]
+pub struct ArchiveList {
+    year: Option<i32>,
+    show_dates: bool,
+    order: Option<SortOrder>,
+}
+
+impl Default for ArchiveList {
+    fn default() -> Self {
+        ArchiveList {
+            year: None,
+            show_dates: true,
+            order: None,
+        }
+    }
+}
+
+impl ArchiveList {
+    pub fn new() -> Self {
+        ArchiveList::default()
+    }
+
+    /// 한 해만 표시. 기본: 전체
+    pub fn year(mut self, year: i32) -> Self {
+        self.year = Some(year);
+        self
+    }
+
+    /// 항목의 발행일 표시 여부. 기본: true
+    pub fn show_dates(mut self, show: bool) -> Self {
+        self.show_dates = show;
+        self
+    }
+
+    /// 달 안의 항목 순서. 기본: 발행일 최신순
+    pub fn order(mut self, order: SortOrder) -> Self {
+        self.order = Some(order);
+        self
+    }
+}
+
+impl Block for ArchiveList {
+    fn render_to_ir(&self, ctx: &RenderContext) -> IRNode {
+        let groups = ctx.index().map(|index| index.pages_by_year_month()).unwrap_or_default();
+        let rule = prose_rule();
+        let locale = Locale::from_metadata(&ctx.metadata);
+        let sections = groups
+            .into_iter()
+            .filter(|((year, _), _)| self.year.is_none_or(|y| y == *year))
+            .map(|((year, month), pages)| {
+                let datetime = format!("{:04}-{:02}", year, month);
+                let heading = element(
+                    "h2",
+                    SharedAttrs::new(),
+                    vec![Element::Node(element(
+                        "time",
+                        token_attrs(&[("datetime", &datetime)]),
+                        vec![Element::Text(Content::from_str(&locale.month(year, month), &rule))],
+                    ))],
+                );
+                let pages = match &self.order {
+                    Some(order) => order.sort(pages),
+                    None => pages,
+                };
+                let items = pages
+                    .into_iter()
+                    .map(|entry| {
+                        ctx.depends_on(Input::page(&entry.output_path));
+                        entry_item(entry, self.show_dates.then_some(&locale))
+                    })
+                    .collect();
+                Element::Node(element(
+                    "section",
+                    class_attrs(&["archive-month"]),
+                    vec![
+                        Element::Node(heading),
+                        Element::Node(element("ul", class_attrs(&["archive-list"]), items)),
+                    ],
+                ))
+            })
+            .collect();
+        element("div", class_attrs(&["archive"]), sections)
+    }
+}
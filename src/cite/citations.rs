@@ -0,0 +1,66 @@
+//! # citations.rs - CitationChecker
+//!
+//! ## 목적
+//! 인용(`Citation`)의 키가 사이트에 등록된 참고 문헌(`References`)에 있는지 확인합니다.
+//! 없는 키는 `UnknownCitations`로 `SiteIndex`에 남고, Site가 페이지 검사 단계에서
+//! 경고(`WarningKind::UnknownCitation`)로 옮깁니다. 번호는 `Counter`가 매깁니다.
+//!
+//! ## 설계 결정
+//! - **기본 방문자**: 인용이 없는 사이트에서는 아무것도 기록하지 않으므로 항상 실행합니다.
+//! - **문헌 목록은 전역 메타데이터**: 렌더링하는 `Citation`, `Bibliography`와 같은 `References`를 읽습니다.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::block::bibliography::{CITATION_KIND, References};
+use crate::block::block::Block;
+use crate::cite::cite::Site;
+use crate::cite::visitor::{PageContext, Visitor, VisitorResult};
+use crate::metadata::types::{Numbered, NumberedKeys};
+use crate::metadata::value::MetadataValue;
+
+/// 페이지 URL(사이트 루트 기준) → 등록되지 않은 인용 키. 빠진 키가 없는 페이지는 담지 않습니다.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UnknownCitations(pub BTreeMap<String, BTreeSet<String>>);
+impl MetadataValue for UnknownCitations {}
+
+impl UnknownCitations {
+    pub fn get(&self, url: &str) -> Option<&BTreeSet<String>> {
+        self.0.get(url)
+    }
+}
+
+/// 인용 키를 확인하는 방문자.
+#[derive(Debug, Clone, Default)]
+pub struct CitationChecker {
+    references: References,
+    unknown: UnknownCitations,
+}
+
+impl CitationChecker {
+    pub fn new() -> Self {
+        CitationChecker::default()
+    }
+}
+
+impl Visitor for CitationChecker {
+    fn visit_site(&mut self, site: &Site) {
+        self.references = site.global_metadata().get::<References>().cloned().unwrap_or_default();
+    }
+
+    fn visit_block(&mut self, block: &dyn Block, ctx: &PageContext) {
+        let metadata = block.metadata();
+        if metadata.get::<Numbered>().is_none_or(|kind| kind.0 != CITATION_KIND) {
+            return;
+        }
+        let Some(keys) = metadata.get::<NumberedKeys>() else {
+            return;
+        };
+        for key in keys.0.iter().filter(|key| !self.references.contains(key)) {
+            self.unknown.0.entry(ctx.url.to_string()).or_default().insert(key.clone());
+        }
+    }
+
+    fn finalize(&self) -> VisitorResult {
+        VisitorResult::new().custom(self.unknown.clone())
+    }
+}
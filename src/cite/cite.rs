@@ -1,9 +1,1888 @@
-/*
-* page들을 등록하고 page 레이아웃을 방문자들을 통해 스캔하면서 메타데이터 수집 빌드 진행
-*/
-pub trait cite {
-    fn build(&self);
-    fn get_attr(&self);
-    fn visitor(&self);
+//! # cite.rs - Site
+//!
+//! page들을 등록하고 page 레이아웃을 방문자들을 통해 스캔하면서 메타데이터 수집 빌드 진행.
+//!
+//! ## 사용 예시
+//! ```rust
+//! let mut site = Site::new().config(SiteConfig::new("My Blog", "https://example.com").language("ko"));
+//! site.register_page(Box::new(SimplePage::new("About", "about.html")));
+//! let report = site.build_output()?;  // 설정 검증 후 dist/에 기록
+//! ```
+//!
+//! ## 구현 상태
+//! - [x] 페이지 등록, 사이트 전역 메타데이터
+//! - [x] 페이지별 asset 수집 + 사이트 단위 중복 제거/순서 통일
+//! - [x] 렌더링 및 파일 출력
+//! - [x] pretty URL 출력 경로 (`SiteConfig::pretty_urls`)
+//! - [x] 오류 페이지 생성 (`generate_not_found`, `generate_server_error`)
+//! - [x] 리다이렉트 매핑 수집 (`redirects`)
+//! - [x] 재사용 조각 등록 (`register_partial`)
+//! - [x] 발행 상태 필터 (초안 제외, 미래 날짜 제외, `include_drafts`, 미리보기 `preview`)
+//! - [x] 네비게이션 링크 트리 자동 생성 (`nav_links`)
+//! - [x] 테마 스타일시트 기록 및 연결 (`Theme`)
+//! - [x] Block 범위 CSS 묶음 기록 및 사용 페이지에 연결 (`Block::css`, `StyleBundler`)
+//! - [x] 쿼리 문자열 캐시 무효화 (`SiteConfig::cache_busting`, `AssetVersions`)
+//! - [x] 대체 렌더링 대상 출력 (인쇄용 등)
+//! - [x] 빌드 설정 (`SiteConfig`, 렌더링 시 메타데이터로 주입)
+//! - [x] 방문자 파이프라인 (`register_visitor`, `index`)
+//! - [x] 태그/카테고리 컬렉션 (`CollectionBuilder`)
+//! - [x] 연월별 아카이브 (`pages_by_year_month`, `ArchiveList`)
+//! - [x] SiteIndex (ID 맵, 링크/백링크, 자동 번호, 컬렉션 질의)
+//! - [x] 페이지 ID/타입으로 링크 (`Link::to`, `link!`, `PageLinker`)
+//! - [x] 원자적 파일 기록, 이전 빌드 파일 정리, 기록 목록 반환 (`BuildManifest`, 쓴 바이트 수)
+//! - [x] 방문자가 만든 전역 파일 기록 (`GeneratedFiles`: sitemap.xml 등)
+//! - [x] 구독 피드 (RSS/Atom/JSON Feed, `SiteConfig::feed`)
+//! - [x] 호스트별 리다이렉트 파일 (`SiteConfig::redirect_file`)
+//! - [x] 증분 빌드 (입력 지문이 같은 페이지 건너뜀, `incremental`)
+//! - [x] 재현 가능한 빌드 (정렬된 출력, 주입 가능한 시계 `clock`, `SOURCE_DATE_EPOCH`)
+//! - [x] 의존성 그래프와 선택적 재빌드 (`dependency_graph`, `rebuild_affected`)
+//! - [x] 병렬 렌더링/기록 (`jobs`, 빌드마다 rayon 스레드 풀 하나를 검사와 렌더링이 공유, 오류는 페이지 순서로 모아 반환)
+//! - [x] 출력 경로 중복 거부 (`BuildError::DuplicateOutput`, 렌더링 전에 검사)
+//! - [x] 다국어 (언어별 URL 트리, hreflang 대체 링크, 메시지 카탈로그 `t!`)
+//! - [x] 태그/카테고리 목록 페이지 자동 생성 (`taxonomy`, 페이지네이션 포함)
+//! - [x] 컬렉션 페이지네이션 (`paginate`: `blog/page/2/index.html`, `Paging` 주입)
+//! - [x] 빌드 보고서 (페이지/바이트 수, 단계별 시간, 경고: alt 누락, 큰 페이지)
+//! - [x] 빌드 단위 문자열 공유 (`InternPool`, 통계는 `BuildReport::interning`)
+//! - [x] 공유 조각 캐시 (header, footer, nav를 한 번만 렌더링, `FragmentCache`, 통계는 `BuildReport::fragments`)
+//! - [x] 구조화된 빌드 오류 (`BuildError`: 규칙 데이터, 잘못된 속성, 깨진 링크, 입출력)
+//! - [x] 미리 압축한 출력 (`precompress`: `.gz`, `.br`)
+//! - [x] 렌더링 결과 구조 검사 (`validate_html`, 위반은 빌드 경고)
+//! - [x] 출력 형식 (`html_format`: 들여쓰기, 공백 축약. 공백이 내용인 요소는 보존)
+//! - [x] 검사 전용 빌드 (`check`: 파일을 쓰지 않고 깨진 링크, 중복 출력 경로/ID 보고)
+//! - [x] 빌드 단계 훅 (`plugin`: 분석 전, 문서 렌더링 후, 기록 후)
+//! - [x] 웹 프레임워크 응답 (`axum`, `actix` 기능: `HtmlBlock`, `RenderedPage`)
+//! - [x] Tailwind class 목록 파일 (`tailwind` 기능: `TailwindManifest`)
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    io,
+    path::{Path, PathBuf},
+    sync::Arc,
+    thread,
+};
+
+use rayon::prelude::*;
+
+use crate::block::block::{Block, attr_token, block_error_marker, render_block};
+use crate::block::context::RenderContext;
+use crate::block::css::BlockStylesheet;
+use crate::cite::analysis::{
+    Counter, IdGenerator, LinkResolver, collect_attr, is_external, normalize_url, resolve_href,
+};
+use crate::cite::bundle::ArchiveFormat;
+use crate::cite::citations::{CitationChecker, UnknownCitations};
+use crate::cite::formats::OutputFormatGenerator;
+use crate::cite::glossary::GlossaryLinker;
+use crate::cite::clock::{Clock, FixedClock, SystemClock};
+use crate::cite::lastmod::{FileMtime, SourceDates};
+use crate::cite::collection::CollectionBuilder;
+use crate::cite::compress::Compressor;
+use crate::cite::config::{AnchorPolicy, BlockErrorMode, BlockErrors, CacheBusting, SiteConfig};
+use crate::cite::dependency::{DependencyGraph, DependencyRecorder, Input, PageDependencies};
+use crate::cite::error::{BlockPath, BuildError};
+use crate::cite::feed::FeedGenerator;
+use crate::cite::i18n::{TranslationGroups, TranslationLinker};
+use crate::cite::icons::{IconSpriteGenerator, MissingIcons};
+use crate::cite::incremental::{
+    Fingerprint, FingerprintHasher, Fingerprints, LIBRARY_VERSION, hash_block, hash_metadata, hash_sources,
+};
+use crate::cite::index::SiteIndex;
+use crate::cite::link::{PageLinker, PageRefs};
+#[cfg(feature = "link-check")]
+use crate::cite::link_check::DeadLinks;
+use crate::cite::heading_nav::HeadingNavGenerator;
+use crate::cite::math::MathScriptGenerator;
+use crate::cite::og_image::SocialImages;
+use crate::cite::outline::HeadingOutlines;
+use crate::cite::plugin::{PageHook, Plugin, WriteHook};
+use crate::cite::redirects::RedirectFileGenerator;
+use crate::cite::report::{BuildReport, BuildTimings, BuildWarning, ContentAudit, Stopwatch, WarningKind};
+use crate::cite::styles::{BlockStyles, StyleBundler};
+use crate::cite::taxonomy::{Taxonomy, TaxonomyKind, TaxonomyListing};
+use crate::cite::visitor::{Visitor, VisitorResult, run_pipeline};
+use crate::cite::vfs::{DiskFs, MemoryFs, SiteFs};
+use crate::cite::writer::{BuildManifest, GeneratedFiles, OutputWriter};
+use crate::html::fragment::FragmentCache;
+use crate::html::intern::InternPool;
+use crate::html::node::{Element, IRNode};
+use crate::html::renderer::{HtmlFormat, HtmlRenderer, Renderer};
+use crate::html::rules::check_rule_data;
+use crate::html::validate::HtmlValidator;
+use crate::html::trust::HtmlBlock;
+use crate::metadata::builder::Metadata;
+use crate::metadata::map::ResolvedMetadata;
+use crate::metadata::types::{
+    Assets, Categories, CurrentUrl, Date, Language, LastModified, PageStatus, PublishDate, Redirect,
+    RenderTarget, Series, SourceFiles, Tags, Title, Weight,
+};
+use crate::page::assets::{AssetVersions, collect_assets};
+use crate::page::error::ErrorPage;
+use crate::page::heading_nav::heading_nav_assets;
+use crate::page::i18n::{TranslationKey, Translations};
+use crate::page::layout::Layout;
+use crate::page::link::unresolved_target;
+use crate::page::nav::{NavLink, nest_links};
+use crate::page::page::{Page, document_html_cached, document_ir, page_context};
+use crate::page::partial::{Partial, PartialParams, PartialRegistry};
+use crate::page::preview::PreviewStatus;
+use crate::page::slug::{is_error_page_path, pretty_path, url_for_path};
+use crate::page::social::SocialImage;
+use crate::page::target::target_path;
+use crate::page::theme::Theme;
+use crate::page::vars::undefined_var;
+
+/// 큰 페이지 경고 기준 기본값 (바이트).
+const DEFAULT_PAGE_SIZE_LIMIT: u64 = 1024 * 1024;
+
+/// `Site::audit`가 asset으로 보는 방문자 생성 파일의 확장자.
+pub const AUDIT_ASSET_EXTENSIONS: &[&str] = &[
+    "css", "js", "mjs", "png", "jpg", "jpeg", "gif", "webp", "avif", "svg", "ico", "woff", "woff2", "ttf", "otf",
+    "mp4", "webm", "mp3", "ogg", "pdf",
+];
+
+/// 렌더링된 페이지 하나.
+pub struct RenderedPage {
+    /// 출력 디렉토리 기준 상대 경로
+    pub path: PathBuf,
+    pub html: HtmlBlock,
+}
+
+/// 사이트 전체. 모든 Page를 소유하고 빌드를 수행합니다.
+#[derive(Default)]
+pub struct Site {
+    pages: Vec<Box<dyn Page>>,
+    metadata: Metadata,
+    include_drafts: bool,
+    preview: bool,
+    clock: Option<Arc<dyn Clock>>,
+    source_dates: Option<Arc<dyn SourceDates>>,
+    config: SiteConfig,
+    default_layout: Option<Arc<dyn Layout>>,
+    generate_not_found: bool,
+    generate_server_error: bool,
+    partials: PartialRegistry,
+    visitors: Vec<Box<dyn Fn() -> Box<dyn Visitor> + Send + Sync>>,
+    incremental: bool,
+    template_version: String,
+    jobs: Option<usize>,
+    taxonomies: Vec<Taxonomy>,
+    deny_broken_links: bool,
+    page_size_limit: Option<u64>,
+    validate_html: bool,
+    html_format: HtmlFormat,
+    compressors: Vec<Arc<dyn Compressor>>,
+    plugins: Vec<Arc<dyn Plugin>>,
+}
+
+/// 페이지 하나의 렌더링 결과.
+struct PageRender {
+    documents: Vec<RenderedPage>,
+    warnings: Vec<BuildWarning>,
+    /// 렌더링 중 기록된 입력 (`RenderContext::depends_on`)
+    inputs: BTreeSet<Input>,
 }
 
+/// 페이지 기록에 필요한 빌드 상태.
+struct BuildState<'a> {
+    index: &'a SiteIndex,
+    /// 증분 빌드의 사이트 지문
+    site: Option<FingerprintHasher>,
+    previous: Fingerprints,
+    /// 이전 빌드의 의존성 그래프. 있으면 이번 빌드의 그래프도 기록
+    graph: Option<&'a DependencyGraph>,
+    /// 다시 렌더링할 페이지 (`rebuild_affected`). 없으면 모든 페이지
+    affected: Option<&'a BTreeSet<PathBuf>>,
+    writer: OutputWriter,
+    /// 페이지들이 나눠 쓰는 조각 캐시
+    fragments: Arc<FragmentCache>,
+}
+
+/// 렌더링 전에 정한 페이지 상태.
+#[derive(Debug, Clone, Copy)]
+struct PageState {
+    /// 증분 빌드의 이번 지문 (유지한 페이지는 이전 빌드 값)
+    fingerprint: Option<Fingerprint>,
+    /// 이전 빌드 파일을 그대로 유지함
+    unchanged: bool,
+}
+
+/// 페이지 하나의 기록 결과.
+struct PageBuild {
+    /// 증분 빌드의 이번 지문
+    fingerprint: Option<Fingerprint>,
+    /// 이전 빌드 파일을 그대로 유지함
+    unchanged: bool,
+    warnings: Vec<BuildWarning>,
+    /// 의존성 (유지한 페이지는 이전 빌드 값). 모르면 `None`
+    dependencies: Option<PageDependencies>,
+}
+
+impl Site {
+    pub fn new() -> Self {
+        Site::default()
+    }
+
+    /// 사이트 전역 메타데이터. 모든 페이지 메타데이터의 기반이 됩니다.
+    pub fn metadata(mut self, metadata: Metadata) -> Self {
+        self.metadata = metadata;
+        self
+    }
+
+    /// 초안과 예약 페이지도 출력할지 (`--drafts`). 기본: false
+    pub fn include_drafts(mut self, include: bool) -> Self {
+        self.include_drafts = include;
+        self
+    }
+
+    /// 초안 미리보기 빌드 (`--preview`). 초안과 예약 페이지도 출력하되 `noindex`와 초안 배너를 붙이고
+    /// sitemap, 피드에서는 뺍니다 (`PreviewStatus`). 기본: false
+    pub fn preview(mut self, preview: bool) -> Self {
+        self.preview = preview;
+        self
+    }
+
+    /// 발행 판정 기준 날짜. 기본: 오늘 (UTC) (`clock(FixedClock(date))`의 축약)
+    pub fn build_date(mut self, date: Date) -> Self {
+        self.clock = Some(Arc::new(FixedClock(date)));
+        self
+    }
+
+    /// 빌드 기준 날짜를 읽는 시계 (발행 판정, 날짜 없는 피드의 갱신일).
+    /// 기본: `SystemClock` (`SOURCE_DATE_EPOCH`가 있으면 그 시각)
+    pub fn clock<C: Clock + 'static>(mut self, clock: C) -> Self {
+        self.clock = Some(Arc::new(clock));
+        self
+    }
+
+    /// 원본 파일(`SourceFiles`)의 수정일 출처. 기본: `FileMtime` (파일 시스템 수정 시각)
+    pub fn source_dates<S: SourceDates + 'static>(mut self, dates: S) -> Self {
+        self.source_dates = Some(Arc::new(dates));
+        self
+    }
+
+    /// 빌드 설정. 렌더링 시 메타데이터로 주입됩니다.
+    pub fn config(mut self, config: SiteConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// `blog/post.html` 대신 `blog/post/index.html`로 출력. 기본: false
+    /// (`SiteConfig::pretty_urls`의 축약)
+    pub fn pretty_urls(mut self, pretty: bool) -> Self {
+        self.config.pretty_urls = pretty;
+        self
+    }
+
+    /// Site가 생성하는 페이지(오류 페이지 등)에 적용할 공유 Layout.
+    pub fn default_layout(mut self, layout: Arc<dyn Layout>) -> Self {
+        self.default_layout = Some(layout);
+        self
+    }
+
+    /// `404.html` 생성. 같은 경로의 페이지를 직접 등록했다면 그 페이지를 사용합니다.
+    pub fn generate_not_found(mut self, generate: bool) -> Self {
+        self.generate_not_found = generate;
+        self
+    }
+
+    /// `50x.html` 생성. 같은 경로의 페이지를 직접 등록했다면 그 페이지를 사용합니다.
+    pub fn generate_server_error(mut self, generate: bool) -> Self {
+        self.generate_server_error = generate;
+        self
+    }
+
+    /// 이전 빌드와 입력 지문이 같은 페이지는 렌더링과 기록을 건너뜀. 기본: false
+    pub fn incremental(mut self, incremental: bool) -> Self {
+        self.incremental = incremental;
+        self
+    }
+
+    /// 증분 빌드 지문에 들어가는 템플릿 버전. Page/Layout 코드를 바꾸면 올립니다.
+    pub fn template_version(mut self, version: &str) -> Self {
+        self.template_version = version.to_string();
+        self
+    }
+
+    /// 태그/카테고리마다 목록 페이지 생성. 직접 등록한 페이지와 경로가 같으면 직접 등록한 페이지를 씁니다.
+    pub fn taxonomy(mut self, taxonomy: Taxonomy) -> Self {
+        self.taxonomies.push(taxonomy);
+        self
+    }
+
+    /// 페이지 렌더링/기록에 쓸 스레드 수. 1이면 현재 스레드에서 순서대로 처리합니다.
+    /// 기본: `std::thread::available_parallelism`
+    pub fn jobs(mut self, jobs: usize) -> Self {
+        self.jobs = Some(jobs.max(1));
+        self
+    }
+
+    /// 출력 대상이 없는 내부 링크를 빌드 오류(`BuildError::BrokenLink`)로 처리. 기본: false
+    /// 페이지, 방문자가 만든 파일, 테마 스타일시트를 출력 대상으로 봅니다.
+    /// 직접 복사하는 정적 파일로의 링크도 깨진 링크가 되므로 정적 파일이 없는 사이트에서 켭니다.
+    pub fn deny_broken_links(mut self, deny: bool) -> Self {
+        self.deny_broken_links = deny;
+        self
+    }
+
+    /// 렌더링 결과가 이보다 큰 문서는 `WarningKind::LargePage` 경고. 기본: 1 MB
+    pub fn page_size_limit(mut self, bytes: u64) -> Self {
+        self.page_size_limit = Some(bytes);
+        self
+    }
+
+    /// HTML/CSS/JS 출력 옆에 미리 압축한 파일 기록 (`Gzip`, `brotli` 기능의 `Brotli`, 또는 직접 연결한 압축기).
+    /// 여러 번 호출하면 압축기마다 파일이 하나씩 생깁니다.
+    pub fn precompress<C: Compressor + 'static>(mut self, compressor: C) -> Self {
+        self.compressors.push(Arc::new(compressor));
+        self
+    }
+
+    /// 빌드 단계 훅 등록 (`Plugin`). 훅은 등록 순서대로 실행됩니다.
+    pub fn plugin<P: Plugin + 'static>(mut self, plugin: P) -> Self {
+        self.plugins.push(Arc::new(plugin));
+        self
+    }
+
+    /// 렌더링한 모든 문서의 구조(중첩, 중복 ID, 랜드마크)를 `HtmlValidator`로 검사해
+    /// 위반을 `WarningKind::InvalidHtml` 경고로 `BuildReport`에 모음. 기본: false
+    pub fn validate_html(mut self, validate: bool) -> Self {
+        self.validate_html = validate;
+        self
+    }
+
+    /// 문서 HTML 출력 형식. `Pretty`는 들여쓰기, `Minified`는 공백 축약이며
+    /// 둘 다 `pre`, `code`, `textarea`, `script` 안의 공백은 바꾸지 않습니다. 기본: `Compact`
+    pub fn html_format(mut self, format: HtmlFormat) -> Self {
+        self.html_format = format;
+        self
+    }
+
+    /// 페이지의 실제 출력 경로 (언어 디렉토리, pretty URL 옵션 적용).
+    /// 페이지 언어가 `SiteConfig::language`와 다르면 `<언어>/` 아래에 출력합니다.
+    /// 리다이렉트 문서는 옛 경로를 그대로 지켜야 하므로 변환하지 않습니다.
+    pub fn output_path(&self, page: &dyn Page) -> PathBuf {
+        let metadata = page.metadata();
+        if metadata.map().contains::<Redirect>() {
+            return page.path();
+        }
+        let path = match metadata.get::<Language>() {
+            Some(lang) if self.config.language.as_ref() != Some(&lang.0) => {
+                locale_path(&page.path(), &lang.0)
+            }
+            _ => page.path(),
+        };
+        if self.config.pretty_urls {
+            pretty_path(&path)
+        } else {
+            path
+        }
+    }
+
+    /// 페이지의 사이트 루트 기준 URL (`SiteConfig::url_for` 적용).
+    pub fn page_url(&self, page: &dyn Page) -> String {
+        self.config.url_for(&self.output_path(page))
+    }
+
+    /// 이름으로 참조할 재사용 조각 등록. 같은 이름은 교체됩니다.
+    pub fn register_partial<P: Partial + 'static>(&mut self, name: &str, partial: P) {
+        self.partials.register(name, partial);
+    }
+
+    pub fn partials(&self) -> &PartialRegistry {
+        &self.partials
+    }
+
+    /// 분석 방문자 등록. 빌드마다 복제본이 등록 순서대로 실행됩니다.
+    pub fn register_visitor<V: Visitor + Clone + Send + Sync + 'static>(&mut self, visitor: V) {
+        self.visitors
+            .push(Box::new(move || Box::new(visitor.clone()) as Box<dyn Visitor>));
+    }
+
+    /// 방문자 파이프라인을 실행하여 사이트 인덱스 생성.
+    /// 기본 방문자(`CollectionBuilder`, `IdGenerator`, `Counter`, `LinkResolver`, `TranslationLinker`,
+    /// `PageLinker`, `StyleBundler`, `IconSpriteGenerator`, `MathScriptGenerator`, `CitationChecker`,
+    /// `GlossaryLinker`, `OutputFormatGenerator`)가 먼저, 등록한 방문자가 뒤에 실행됩니다.
+    /// `SiteConfig::heading_nav`가 켜져 있으면 `HeadingNavGenerator`가, `SiteConfig::feeds`가 있으면 `FeedGenerator`가,
+    /// `SiteConfig::redirect_hosts`가 있으면 `RedirectFileGenerator`가 마지막에 실행됩니다.
+    pub fn index(&self) -> SiteIndex {
+        let mut visitors: Vec<Box<dyn Visitor>> = vec![
+            Box::new(CollectionBuilder::new()),
+            Box::new(IdGenerator::default()),
+            Box::new(Counter::default()),
+            Box::new(LinkResolver::default()),
+            Box::new(TranslationLinker::default()),
+            Box::new(PageLinker::default()),
+            Box::new(StyleBundler::new()),
+            Box::new(IconSpriteGenerator::new()),
+            Box::new(MathScriptGenerator::new()),
+            Box::new(CitationChecker::new()),
+            Box::new(GlossaryLinker::new()),
+            Box::new(OutputFormatGenerator::new()),
+        ];
+        visitors.extend(self.visitors.iter().map(|factory| factory()));
+        if self.config.heading_nav {
+            visitors.push(Box::new(HeadingNavGenerator::new()));
+        }
+        if !self.config.feeds.is_empty() {
+            visitors.push(Box::new(FeedGenerator::new()));
+        }
+        if !self.config.redirect_hosts.is_empty() {
+            visitors.push(Box::new(RedirectFileGenerator::new()));
+        }
+        let index = run_pipeline(self, visitors);
+        match self.config.cache_busting {
+            CacheBusting::Query => {
+                let versions = self.asset_versions(&index);
+                index.absorb(VisitorResult::new().custom(versions))
+            }
+            CacheBusting::Filename => index,
+        }
+    }
+
+    /// 사이트가 기록할 asset의 URL별 내용 지문 (`CacheBusting::Query`).
+    /// 방문자가 만든 전역 파일, 테마 스타일시트, `SiteConfig::asset_dirs`의 파일 순으로 찾습니다.
+    fn asset_versions(&self, index: &SiteIndex) -> AssetVersions {
+        let mut files: Vec<(PathBuf, Vec<u8>)> = index
+            .get::<GeneratedFiles>()
+            .map(|files| files.0.clone().into_iter().collect())
+            .unwrap_or_default();
+        if let Some(theme) = self.metadata.get::<Theme>() {
+            files.push((theme.stylesheet_path(), theme.stylesheet().into_bytes()));
+        }
+        for dir in &self.config.asset_dirs {
+            // 읽을 수 없는 디렉토리는 버전 없이 둠 (복사 단계가 오류를 보고)
+            let _ = collect_asset_files(dir, dir, &mut files);
+        }
+        files.into_iter().fold(AssetVersions::new(), |versions, (path, bytes)| {
+            let url = url_for_path(&path);
+            if versions.get(&url).is_some() {
+                return versions;
+            }
+            let version = FingerprintHasher::new().with(&bytes[..]).fingerprint();
+            versions.version(&url, &version.to_string())
+        })
+    }
+
+    pub fn register_page(&mut self, page: Box<dyn Page>) {
+        self.pages.push(page);
+    }
+
+    pub fn pages(&self) -> &[Box<dyn Page>] {
+        &self.pages
+    }
+
+    pub fn global_metadata(&self) -> &Metadata {
+        &self.metadata
+    }
+
+    pub fn site_config(&self) -> &SiteConfig {
+        &self.config
+    }
+
+    /// 렌더링 기반 메타데이터: `SiteConfig`(와 그 언어) 위에 전역 메타데이터를 덮어씀.
+    fn base_metadata(&self) -> Metadata {
+        let defaults = match &self.config.language {
+            Some(lang) => Metadata::new().custom(Language(lang.clone())),
+            None => Metadata::new(),
+        };
+        defaults
+            .custom(self.config.clone())
+            .merge(&self.metadata)
+            .custom(self.partials.clone())
+    }
+
+    /// 빌드 기준 날짜 (`clock`).
+    pub fn today(&self) -> Date {
+        match &self.clock {
+            Some(clock) => clock.today(),
+            None => SystemClock.today(),
+        }
+    }
+
+    /// 페이지 렌더링 기반 메타데이터 (Page 메타데이터 병합 전).
+    fn page_base(&self, page: &dyn Page) -> Metadata {
+        let status = self.page_status(page);
+        let base = self
+            .base_metadata()
+            .custom(status)
+            .custom(CurrentUrl(url_for_path(&self.output_path(page))));
+        let base = match self.preview && !status.is_published_on(self.today()) {
+            true => base.custom(PreviewStatus(status)),
+            false => base,
+        };
+        match self.last_modified(page) {
+            Some(date) => base.custom(LastModified(date)),
+            None => base,
+        }
+    }
+
+    /// 페이지의 마지막 수정일. 페이지가 `LastModified`를 지정하지 않았으면
+    /// `SourceFiles` 중 가장 늦은 수정일 (`source_dates`).
+    pub fn last_modified(&self, page: &dyn Page) -> Option<Date> {
+        let metadata = page.metadata();
+        if let Some(date) = metadata.get::<LastModified>() {
+            return Some(date.0);
+        }
+        let sources = metadata.get::<SourceFiles>()?;
+        let dates: &dyn SourceDates = self.source_dates.as_deref().unwrap_or(&FileMtime);
+        sources.0.iter().filter_map(|path| dates.modified(path)).max()
+    }
+
+    /// Site → Page 병합 메타데이터 (실제 `PageStatus`, `CurrentUrl`, `SiteConfig` 포함).
+    pub fn page_metadata(&self, page: &dyn Page) -> ResolvedMetadata {
+        self.page_base(page).into_map().merge(page.metadata().map())
+    }
+
+    /// 페이지의 실제 발행 상태.
+    /// 명시한 상태가 없고 발행일이 미래이면 `Scheduled(발행일)`로 간주합니다.
+    pub fn page_status(&self, page: &dyn Page) -> PageStatus {
+        let metadata = page.metadata();
+        let date = metadata.get::<PublishDate>().map(|d| d.0);
+        match (metadata.get::<PageStatus>().copied(), date) {
+            (Some(status), _) => status,
+            (None, Some(date)) if date > self.today() => PageStatus::Scheduled(date),
+            (None, _) => PageStatus::Published,
+        }
+    }
+
+    /// 이번 빌드에서 출력되는 페이지인지.
+    pub fn is_visible(&self, page: &dyn Page) -> bool {
+        self.include_drafts || self.preview || self.page_status(page).is_published_on(self.today())
+    }
+
+    /// 출력 대상 페이지 (등록 순서). 컬렉션, 피드도 이 목록을 사용합니다.
+    pub fn visible_pages(&self) -> Vec<&dyn Page> {
+        self.pages
+            .iter()
+            .map(|p| p.as_ref())
+            .filter(|p| self.is_visible(*p))
+            .collect()
+    }
+
+    /// 출력 대상 페이지가 선언한 리다이렉트 매핑 (`from` 순 정렬).
+    /// 호스트별 리다이렉트 파일 생성에 사용합니다.
+    pub fn redirects(&self) -> Vec<Redirect> {
+        let mut redirects: Vec<Redirect> = self
+            .visible_pages()
+            .into_iter()
+            .filter_map(|page| page.metadata().get::<Redirect>().cloned())
+            .collect();
+        redirects.sort();
+        redirects
+    }
+
+    /// 출력 대상 페이지로 만든 네비게이션 링크 트리 (디렉토리별 중첩). 같은 단계의 링크는 `Weight` 순,
+    /// 가중치가 없거나 같으면 URL 순입니다. `Title`이 없는 페이지, 리다이렉트, 오류 페이지는 제외합니다.
+    pub fn nav_links(&self) -> Vec<NavLink> {
+        let mut weights = BTreeMap::new();
+        let mut links: Vec<NavLink> = self
+            .visible_pages()
+            .into_iter()
+            .filter(|page| !is_error_page_path(&page.path()))
+            .filter_map(|page| {
+                let metadata = page.metadata();
+                if metadata.map().contains::<Redirect>() {
+                    return None;
+                }
+                let title = metadata.get::<Title>()?;
+                let href = self.page_url(page);
+                if let Some(weight) = metadata.get::<Weight>() {
+                    weights.insert(href.clone(), weight.0);
+                }
+                Some(NavLink::new(&title.0, &href))
+            })
+            .collect();
+        links.sort_by(|a, b| a.href.cmp(&b.href));
+        let mut links = nest_links(links);
+        sort_by_weight(&mut links, &weights);
+        links
+    }
+
+    /// 옵션에 따라 Site가 생성하는 오류 페이지와 분류 목록 페이지 (직접 등록한 경로는 제외).
+    fn generated_pages(&self) -> Vec<Box<dyn Page>> {
+        let mut candidates = Vec::new();
+        if self.generate_not_found {
+            candidates.push(ErrorPage::not_found());
+        }
+        if self.generate_server_error {
+            candidates.push(ErrorPage::server_error());
+        }
+        let mut generated: Vec<Box<dyn Page>> = candidates
+            .into_iter()
+            .map(|error| {
+                let page = match &self.default_layout {
+                    Some(layout) => error.with_layout(layout.clone()),
+                    None => error,
+                };
+                Box::new(page) as Box<dyn Page>
+            })
+            .collect();
+
+        if !self.taxonomies.is_empty() {
+            let metadata: Vec<ResolvedMetadata> = self
+                .visible_pages()
+                .into_iter()
+                .map(|page| self.page_metadata(page))
+                .collect();
+            for taxonomy in &self.taxonomies {
+                generated.extend(taxonomy.pages(&metadata, &self.config));
+            }
+        }
+
+        generated.retain(|page| !self.pages.iter().any(|p| p.path() == page.path()));
+        generated
+    }
+
+    /// 출력 대상 페이지별 asset 목록 (`visible_pages()` 순서). 사이트 전역 Assets가 앞에 오고,
+    /// 모든 페이지에서 같은 asset은 같은 상대 순서로 나타납니다 (사이트 단위 순서 통일).
+    pub fn page_assets(&self) -> Vec<Assets> {
+        let per_page = self.collect_page_assets();
+        let site_order = union_all(&per_page);
+        per_page
+            .iter()
+            .map(|needed| Assets {
+                stylesheets: site_order
+                    .stylesheets
+                    .iter()
+                    .filter(|s| needed.stylesheets.contains(s))
+                    .cloned()
+                    .collect(),
+                scripts: site_order
+                    .scripts
+                    .iter()
+                    .filter(|s| needed.scripts.contains(s))
+                    .cloned()
+                    .collect(),
+            })
+            .collect()
+    }
+
+    /// 사이트 전체에서 사용되는 asset 합집합 (복사/번들링 대상).
+    pub fn site_assets(&self) -> Assets {
+        union_all(&self.collect_page_assets())
+    }
+
+    /// 모든 페이지 앞에 오는 asset (테마 스타일시트 + 전역 `Assets` + `SiteConfig::heading_nav`).
+    fn global_assets(&self) -> Assets {
+        let theme = self.metadata.get::<Theme>().map(Theme::assets).unwrap_or_default();
+        let assets = theme.union(self.metadata.get::<Assets>().unwrap_or(&Assets::new()));
+        match self.config.heading_nav {
+            true => assets.union(&heading_nav_assets()),
+            false => assets,
+        }
+    }
+
+    fn collect_page_assets(&self) -> Vec<Assets> {
+        let global = self.global_assets();
+        self.visible_pages()
+            .into_iter()
+            .map(|page| global.union(&collect_assets(page)))
+            .collect()
+    }
+
+    /// 출력 대상 페이지를 HTML 문서로 렌더링 (등록 순서).
+    /// 각 페이지의 실제 `PageStatus`, `CurrentUrl`과 `SiteIndex`가 메타데이터로 전달됩니다.
+    /// 대체 대상(`alternate_targets`)이 있으면 대상별 문서가 화면용 문서 바로 뒤에 옵니다.
+    pub fn render(&self) -> Vec<RenderedPage> {
+        self.render_with(&self.index())
+    }
+
+    fn render_with(&self, index: &SiteIndex) -> Vec<RenderedPage> {
+        let generated = self.generated_pages();
+        let fragments = Arc::new(FragmentCache::new());
+        self.output_pages(&generated)
+            .into_iter()
+            .flat_map(|(page, assets)| self.render_page(page, &assets, index, &fragments).documents)
+            .collect()
+    }
+
+    /// 출력 대상 페이지와 Site가 생성한 페이지, 각각의 asset.
+    fn output_pages<'a>(&'a self, generated: &'a [Box<dyn Page>]) -> Vec<(&'a dyn Page, Assets)> {
+        let global = self.global_assets();
+        let generated_assets = generated
+            .iter()
+            .map(|page| global.union(&collect_assets(page.as_ref())));
+
+        self.visible_pages()
+            .into_iter()
+            .zip(self.page_assets())
+            .chain(generated.iter().map(|p| p.as_ref()).zip(generated_assets))
+            .collect()
+    }
+
+    /// 페이지의 렌더링 대상 (화면용 먼저).
+    fn targets(page: &dyn Page) -> Vec<RenderTarget> {
+        let mut targets = vec![RenderTarget::Screen];
+        targets.extend(page.alternate_targets());
+        targets
+    }
+
+    /// 페이지가 속한 번역 그룹의 번역들 (`TranslationKey`가 있고 번역이 둘 이상일 때).
+    fn translations(&self, page: &dyn Page, index: &SiteIndex) -> Option<Translations> {
+        let metadata = self.page_metadata(page);
+        let key = metadata.get::<TranslationKey>()?;
+        index
+            .get::<TranslationGroups>()?
+            .translations(key, self.config.language.as_deref())
+    }
+
+    /// 렌더링 기반 메타데이터에 `SiteIndex`와 번역 목록, 공유 이미지(`OgImageGenerator`) 추가.
+    fn render_base(&self, page: &dyn Page, index: &SiteIndex) -> Metadata {
+        let base = self.page_base(page).custom(index.clone());
+        let base = match self.translations(page, index) {
+            Some(translations) => base.custom(translations),
+            None => base,
+        };
+        let image = index
+            .get::<SocialImages>()
+            .and_then(|images| images.get(&self.page_url(page)));
+        let base = match image {
+            Some(image) => base.custom(image.clone()),
+            None => base,
+        };
+        let styles = index
+            .get::<BlockStyles>()
+            .and_then(|styles| styles.href_for(&self.page_url(page)));
+        let base = match styles {
+            Some(href) => base.custom(BlockStylesheet(href.to_string())),
+            None => base,
+        };
+        match index.get::<AssetVersions>() {
+            Some(versions) => base.custom(versions.clone()),
+            None => base,
+        }
+    }
+
+    /// 대상별 문서. `validate_html`이면 문서마다 구조를 검사해 위반을 경고로 함께 반환합니다.
+    /// 문서마다 플러그인의 `after_page_render`를 실행하고, 플러그인 경고도 함께 반환합니다.
+    /// 렌더링 중 Block이 기록한 의존 입력도 함께 반환합니다.
+    /// 공유 조각(header, footer, nav)은 `fragments`에서 찾아 이어 붙입니다.
+    fn render_page(
+        &self,
+        page: &dyn Page,
+        assets: &Assets,
+        index: &SiteIndex,
+        fragments: &Arc<FragmentCache>,
+    ) -> PageRender {
+        let output_path = self.output_path(page);
+        let recorder = DependencyRecorder::new();
+        let base = self.render_base(page, index).custom(recorder.clone());
+        let mut warnings = Vec::new();
+        let metadata = self.page_metadata(page);
+        let documents = Self::targets(page)
+            .into_iter()
+            .map(|target| {
+                let path = target_path(&output_path, &target);
+                let base = base.clone().custom(target).into_map();
+                let document = document_ir(page, &base, assets);
+                if self.validate_html {
+                    warnings.extend(HtmlValidator::new().validate(&document).into_iter().map(
+                        |violation| BuildWarning {
+                            page: path.clone(),
+                            kind: WarningKind::InvalidHtml(violation),
+                        },
+                    ));
+                }
+                let mut rendered = RenderedPage {
+                    path,
+                    html: document_html_cached(&document, self.html_format, fragments.clone()),
+                };
+                for plugin in &self.plugins {
+                    let mut hook = PageHook::new(index, &metadata);
+                    plugin.after_page_render(&mut rendered, &mut hook);
+                    warnings.extend(hook.warnings.into_iter().map(|message| BuildWarning {
+                        page: rendered.path.clone(),
+                        kind: WarningKind::Plugin {
+                            plugin: plugin.name().to_string(),
+                            message,
+                        },
+                    }));
+                }
+                rendered
+            })
+            .collect();
+        PageRender {
+            documents,
+            warnings,
+            inputs: recorder.inputs(),
+        }
+    }
+
+    /// 페이지의 의존성: 렌더링 중 기록된 입력에 정적으로 알 수 있는 입력을 더함.
+    /// - 사이트에서 물려받은 메타데이터 타입 (페이지가 덮어쓴 타입, Partial 목록 제외)
+    /// - 페이지 asset
+    /// - 목록 페이지의 태그/카테고리
+    fn page_dependencies(
+        &self,
+        page: &dyn Page,
+        assets: &Assets,
+        recorded: BTreeSet<Input>,
+    ) -> PageDependencies {
+        let own = page.metadata().map().type_names();
+        let mut inputs = recorded;
+        inputs.extend(
+            self.base_metadata()
+                .into_map()
+                .type_names()
+                .into_iter()
+                .filter(|name| !own.contains(name) && *name != std::any::type_name::<PartialRegistry>())
+                .map(|name| Input::Metadata(name.to_string())),
+        );
+        inputs.extend(assets.stylesheets.iter().chain(&assets.scripts).map(|url| Input::asset(url)));
+        if let Some(listing) = self.page_metadata(page).get::<TaxonomyListing>() {
+            inputs.insert(match listing.kind {
+                TaxonomyKind::Tag => Input::tag(&listing.term),
+                TaxonomyKind::Category => Input::category(&listing.term),
+            });
+        }
+        PageDependencies {
+            inputs,
+            provides: self.provided_inputs(page),
+        }
+    }
+
+    /// 페이지가 제공하는 입력 (태그, 카테고리 컬렉션).
+    fn provided_inputs(&self, page: &dyn Page) -> BTreeSet<Input> {
+        let metadata = self.page_metadata(page);
+        let tags = metadata.get::<Tags>().into_iter().flat_map(|t| &t.0).map(|t| Input::tag(t));
+        let categories = metadata
+            .get::<Categories>()
+            .into_iter()
+            .flat_map(|c| &c.0)
+            .map(|c| Input::category(c));
+        tags.chain(categories).collect()
+    }
+
+    /// 모든 출력 페이지를 렌더링해 의존성 그래프 생성 (파일을 쓰지 않음).
+    /// 작업 스레드 풀을 만들 수 없으면 `BuildError::ThreadPool`을 반환합니다.
+    pub fn dependency_graph(&self) -> Result<DependencyGraph, BuildError> {
+        let interning = Arc::new(InternPool::new());
+        let _scope = interning.enter();
+        let index = self.index();
+        let generated = self.generated_pages();
+        let pages = self.output_pages(&generated);
+        let workers = self.workers(pages.len(), &interning)?;
+        let fragments = Arc::new(FragmentCache::new());
+        let dependencies = workers.for_each_page(&pages, |page, assets| {
+            let inputs = self.render_page(page, assets, &index, &fragments).inputs;
+            (self.output_path(page), self.page_dependencies(page, assets, inputs))
+        });
+        Ok(DependencyGraph {
+            pages: dependencies.into_iter().collect(),
+        })
+    }
+
+    /// 모든 페이지가 공유하는 렌더링 입력의 지문.
+    fn site_fingerprint(&self) -> FingerprintHasher {
+        let base = self.base_metadata().into_map();
+        let ctx = RenderContext::new(base.clone());
+        let partials = self.partials.names().fold(FingerprintHasher::new(), |hasher, name| {
+            let rendered = self
+                .partials
+                .get(name)
+                .map(|partial| render_block_html(partial.render(&PartialParams::new(), &ctx).as_ref(), &ctx));
+            hasher.with(name).with(&rendered)
+        });
+
+        let hasher = FingerprintHasher::new()
+            .with(LIBRARY_VERSION)
+            .with(&self.template_version)
+            .with(&partials.fingerprint().0)
+            .with_debug(&self.nav_links())
+            .with(&self.metadata.get::<Theme>().map(Theme::stylesheet))
+            .with(&self.include_drafts)
+            .with(&self.preview)
+            .with(&self.html_format)
+            .with(&self.default_layout.is_some())
+            .with_debug(&self.plugins.iter().map(|p| p.name()).collect::<Vec<_>>());
+        hash_metadata(hasher, &base)
+    }
+
+    /// 페이지 렌더링 입력의 지문 (사이트 지문 포함).
+    pub fn page_fingerprint(&self, page: &dyn Page, index: &SiteIndex) -> Fingerprint {
+        let generated = self.generated_pages();
+        let site = self.pages_fingerprint(self.site_fingerprint(), &self.output_pages(&generated));
+        self.fingerprint_with(site, page, &collect_assets(page), index)
+    }
+
+    /// 모든 페이지의 출력 경로와 메타데이터. `SiteIndex`를 읽는 Block(목록, 백링크)을 무효화합니다.
+    fn pages_fingerprint(&self, hasher: FingerprintHasher, pages: &[(&dyn Page, Assets)]) -> FingerprintHasher {
+        pages.iter().fold(hasher.with(&pages.len()), |hasher, (page, _)| {
+            hash_metadata(hasher.with(&self.output_path(*page)), &self.page_metadata(*page))
+        })
+    }
+
+    fn fingerprint_with(
+        &self,
+        site: FingerprintHasher,
+        page: &dyn Page,
+        assets: &Assets,
+        index: &SiteIndex,
+    ) -> Fingerprint {
+        let base = self.render_base(page, index).into_map();
+        let blocks = page.blocks();
+        let hasher = site
+            .with(&self.output_path(page))
+            .with_debug(&Self::targets(page))
+            .with_debug(assets)
+            .with(&blocks.len());
+        let hasher = blocks
+            .into_iter()
+            .fold(hasher, hash_block)
+            .with(&base.get::<Translations>())
+            .with(&base.get::<SocialImage>())
+            .with(&base.get::<BlockStylesheet>())
+            .with(&base.get::<AssetVersions>());
+        let metadata = self.page_metadata(page);
+        hash_sources(hash_metadata(hasher, &metadata), &metadata).fingerprint()
+    }
+
+    /// 설정을 검증한 뒤 `SiteConfig::output_dir`에 빌드.
+    /// 검증 오류는 `BuildError::Config`로 반환합니다.
+    pub fn build_output(&self) -> Result<BuildReport, BuildError> {
+        self.config.validate()?;
+        self.build(&self.config.output_dir)
+    }
+
+    /// 렌더링 결과를 출력 디렉토리에 원자적으로 기록하고, 이전 빌드에만 있던 파일을 정리.
+    /// 방문자가 만든 전역 파일(`GeneratedFiles`)과, 전역 메타데이터에 `Theme`이 있으면
+    /// 테마 스타일시트도 기록합니다.
+    /// `incremental`이면 입력 지문이 이전 빌드와 같은 페이지는 렌더링하지 않고 기존 파일을 유지합니다.
+    ///
+    /// 기록 전에 모든 페이지의 head와 Block 구성(`Block::check`), `deny_broken_links`이면 내부 링크를
+    /// 검사합니다. 검사 오류가 하나라도 있으면 아무 파일도 쓰지 않습니다.
+    ///
+    /// 두 페이지(또는 페이지와 생성 파일)가 같은 경로로 출력되면 어느 쪽이 남을지 정할 수 없으므로
+    /// 렌더링 전에 `BuildError::DuplicateOutput`으로 실패합니다.
+    ///
+    /// 분석(방문자 파이프라인)이 끝난 뒤에는 `SiteIndex`가 바뀌지 않으므로 페이지 검사, 렌더링과 기록은
+    /// `jobs`개의 스레드에서 나눠 처리합니다. 실패한 페이지가 있어도 나머지 페이지는 끝까지 처리하고,
+    /// 오류는 스레드 실행 순서와 무관하게 페이지 등록 순서로 모아 반환합니다 (`BuildError::Multiple`).
+    /// 페이지 오류가 있으면 이전 빌드 파일 정리와 지문 저장은 하지 않습니다.
+    ///
+    /// 출력은 결정적입니다. 같은 입력과 시계(`clock`)면 `jobs`, 실행 기계, 빌드 시각과 무관하게
+    /// 같은 바이트를 기록합니다 (속성, 컬렉션, 인덱스는 정렬된 맵, 생성 파일은 경로 순, gzip 시각은 0).
+    ///
+    /// 플러그인(`plugin`)의 훅은 분석 전, 문서 렌더링 직후, 모든 파일 기록 뒤(이전 빌드 파일 정리 전)에
+    /// 실행됩니다. 유지한 페이지는 렌더링하지 않으므로 `after_page_render`도 실행하지 않습니다.
+    /// `SiteConfig::deploy`가 있으면(`deploy` 기능) 플러그인 훅 뒤에 모든 기록 파일로 배포 메타데이터를 만듭니다.
+    ///
+    /// 성공하면 기록한 파일과 통계, 경고를 담은 `BuildReport`를 반환합니다.
+    /// `incremental`이면 다음 `rebuild_affected`를 위해 의존성 그래프도 저장합니다.
+    pub fn build(&self, output_dir: &Path) -> Result<BuildReport, BuildError> {
+        self.build_to(Arc::new(DiskFs::new(output_dir)))
+    }
+
+    /// `build`와 같지만 디스크 대신 출력 파일 시스템 `fs`에 기록 (`MemoryFs`로 테스트, 압축 파일로 배포).
+    /// 증분 빌드 상태(지문, 의존성 그래프)도 `fs`에서 읽고 씁니다.
+    pub fn build_to(&self, fs: Arc<dyn SiteFs>) -> Result<BuildReport, BuildError> {
+        let graph = match self.incremental {
+            true => Some(read_graph(fs.as_ref())?.unwrap_or_default()),
+            false => None,
+        };
+        self.build_pages(fs, graph.as_ref(), None)
+    }
+
+    /// 사이트 전체를 압축 파일 하나로 빌드. 형식은 확장자로 정합니다 (`.zip`, `.tar.gz`, `.tgz`).
+    /// 메모리에 빌드한 뒤 빌드 상태 파일(`.quo-*`)을 뺀 출력을 묶어 `path`에 원자적으로 기록하고,
+    /// 보고서의 `manifest.output_dir`은 `path`입니다. 알 수 없는 확장자는 `InvalidInput` 입출력 오류입니다.
+    pub fn build_to_archive(&self, path: &Path) -> Result<BuildReport, BuildError> {
+        let format = ArchiveFormat::from_path(path).ok_or_else(|| {
+            let message = "unknown archive format (expected .zip, .tar.gz or .tgz)";
+            BuildError::io(path, io::Error::new(io::ErrorKind::InvalidInput, message))
+        })?;
+        let fs = Arc::new(MemoryFs::new());
+        let mut report = self.build_to(fs.clone())?;
+        let mut files = fs.files();
+        files.retain(|file, _| !file.to_string_lossy().starts_with(".quo-"));
+        let bytes = format.encode(&files).map_err(|err| BuildError::io(path, err))?;
+        let parent = path.parent().filter(|p| !p.as_os_str().is_empty());
+        let name = path.file_name().map(PathBuf::from).unwrap_or_default();
+        DiskFs::new(parent.unwrap_or(Path::new(".")))
+            .write(&name, &bytes)
+            .map_err(|err| BuildError::io(path, err))?;
+        report.manifest.output_dir = path.to_path_buf();
+        Ok(report)
+    }
+
+    /// 바뀐 입력에 의존하는 페이지만 다시 렌더링해 `SiteConfig::output_dir`에 기록 (편집기, 감시 모드).
+    /// 이전 빌드의 의존성 그래프로 대상을 정하고(`DependencyGraph::affected`) 나머지 페이지의 파일은
+    /// 유지합니다. 새 페이지와 출력 파일이 없는 페이지는 항상 렌더링합니다.
+    ///
+    /// 분석, 검사, 전역 파일 기록과 이전 빌드 파일 정리는 `build`와 같습니다. 그래프가 없으면
+    /// (첫 빌드, `incremental` 없이 빌드한 출력) 전체를 빌드합니다. 그래프는 `incremental`과 무관하게 갱신합니다.
+    pub fn rebuild_affected(&self, changed: &[Input]) -> Result<BuildReport, BuildError> {
+        self.config.validate()?;
+        let fs: Arc<dyn SiteFs> = Arc::new(DiskFs::new(&self.config.output_dir));
+        let Some(graph) = read_graph(fs.as_ref())? else {
+            return self.build_pages(fs, Some(&DependencyGraph::new()), None);
+        };
+
+        // 바뀐 페이지의 현재 태그/카테고리 (이전 값은 그래프에 있음)
+        let mut changed = changed.to_vec();
+        let generated = self.generated_pages();
+        for (page, _) in self.output_pages(&generated) {
+            if changed.contains(&Input::Page(self.output_path(page))) {
+                changed.extend(self.provided_inputs(page));
+            }
+        }
+        let affected = graph.affected(&changed);
+        self.build_pages(fs, Some(&graph), Some(&affected))
+    }
+
+    /// `build`와 `rebuild_affected`의 공통 과정. `graph`가 있으면 이번 빌드의 의존성 그래프를 저장하고,
+    /// `affected`가 있으면 그 밖의 페이지는 이전 빌드 파일을 유지합니다.
+    fn build_pages(
+        &self,
+        fs: Arc<dyn SiteFs>,
+        graph: Option<&DependencyGraph>,
+        affected: Option<&BTreeSet<PathBuf>>,
+    ) -> Result<BuildReport, BuildError> {
+        check_rule_data().map_err(|err| BuildError::RuleData(err.clone()))?;
+        let interning = Arc::new(InternPool::new());
+        let _scope = interning.enter();
+        let mut timings = BuildTimings::default();
+        let started = Stopwatch::start();
+        self.before_analysis()?;
+        let index = self.index();
+        timings.analysis = started.elapsed();
+
+        let previous = if self.incremental {
+            Fingerprints::read(fs.as_ref()).map_err(|err| BuildError::io(fs.root(), err))?
+        } else {
+            Fingerprints::new()
+        };
+        let generated = self.generated_pages();
+        let pages = self.output_pages(&generated);
+        let workers = self.workers(pages.len(), &interning)?;
+        let state = BuildState {
+            index: &index,
+            site: self.incremental.then(|| self.pages_fingerprint(self.site_fingerprint(), &pages)),
+            previous,
+            graph,
+            affected,
+            writer: OutputWriter::with_fs(fs.clone()).precompress(self.compressors.clone()),
+            fragments: Arc::new(FragmentCache::new()),
+        };
+
+        if let Some(err) = BuildError::from_errors(self.duplicate_outputs(&pages, &index)) {
+            return Err(err);
+        }
+        let started = Stopwatch::start();
+        let known = self.output_urls(&pages, &index);
+        let mut problems = Vec::new();
+        let mut warnings = Vec::new();
+        let mut page_states = BTreeMap::new();
+        let checked = workers.for_each_page(&pages, |page, assets| {
+            let page_state = self.page_state(page, assets, &state);
+            if page_state.unchanged {
+                return (page_state, Vec::new(), Vec::new());
+            }
+            let (errors, warnings) = self.check_page(page, &index, &known, self.deny_broken_links);
+            (page_state, errors, warnings)
+        });
+        for ((page, _), (page_state, errors, page_warnings)) in pages.iter().zip(checked) {
+            page_states.insert(self.output_path(*page), page_state);
+            problems.extend(errors);
+            warnings.extend(page_warnings);
+        }
+        if let Some(err) = BuildError::from_errors(problems) {
+            return Err(err);
+        }
+        timings.check = started.elapsed();
+
+        let started = Stopwatch::start();
+        let results = workers.for_each_page(&pages, |page, assets| {
+            self.build_page(page, assets, page_states[&self.output_path(page)], &state)
+        });
+
+        let mut fingerprints = Fingerprints::new();
+        let mut dependencies = DependencyGraph::new();
+        let mut errors = Vec::new();
+        let mut unchanged_pages = 0;
+        for ((page, _), result) in pages.iter().zip(results) {
+            match result {
+                Ok(built) => {
+                    if let Some(fingerprint) = built.fingerprint {
+                        fingerprints.insert(self.output_path(*page), fingerprint);
+                    }
+                    if let Some(page_dependencies) = built.dependencies {
+                        dependencies.insert(self.output_path(*page), page_dependencies);
+                    }
+                    unchanged_pages += usize::from(built.unchanged);
+                    warnings.extend(built.warnings);
+                }
+                Err(err) => errors.push(err),
+            }
+        }
+        if let Some(err) = BuildError::from_errors(errors) {
+            return Err(err);
+        }
+        timings.render = started.elapsed();
+
+        let started = Stopwatch::start();
+        let writer = &state.writer;
+        if let Some(files) = index.get::<GeneratedFiles>() {
+            for (path, contents) in &files.0 {
+                writer.write(path, contents).map_err(|err| BuildError::io(path, err))?;
+            }
+        }
+        if let Some(theme) = self.metadata.get::<Theme>() {
+            let path = theme.stylesheet_path();
+            writer
+                .write(&path, theme.stylesheet().as_bytes())
+                .map_err(|err| BuildError::io(&path, err))?;
+        }
+        for plugin in &self.plugins {
+            let mut hook = WriteHook::new(&index, writer);
+            plugin.after_write(&mut hook).map_err(|source| BuildError::Plugin {
+                plugin: plugin.name().to_string(),
+                source,
+            })?;
+            warnings.extend(hook.warnings.into_iter().map(|(page, message)| BuildWarning {
+                page,
+                kind: WarningKind::Plugin {
+                    plugin: plugin.name().to_string(),
+                    message,
+                },
+            }));
+        }
+        #[cfg(feature = "deploy")]
+        if let Some(deploy) = &self.config.deploy {
+            for (path, contents) in &deploy.files(&self.config, &writer.files()).0 {
+                writer.write(path, contents).map_err(|err| BuildError::io(path, err))?;
+            }
+        }
+        let manifest = state.writer.finish().map_err(|err| BuildError::io(fs.root(), err))?;
+        if self.incremental {
+            fingerprints
+                .write(fs.as_ref())
+                .map_err(|err| BuildError::io(fs.root(), err))?;
+        }
+        if graph.is_some() {
+            dependencies
+                .write(fs.as_ref())
+                .map_err(|err| BuildError::io(fs.root(), err))?;
+        }
+        timings.finish = started.elapsed();
+
+        Ok(BuildReport {
+            manifest,
+            pages: pages.len(),
+            unchanged_pages,
+            timings,
+            interning: interning.stats(),
+            fragments: state.fragments.stats(),
+            warnings,
+        })
+    }
+
+    /// 파일을 쓰지 않는 빌드. 설정 검증, 분석, 모든 페이지의 검사와 렌더링을 수행하고
+    /// `build`가 실패할 모든 경우와 함께 다음을 오류로 보고합니다 (CI에서 내용 변경 검사용).
+    /// - 깨진 내부 링크 (`deny_broken_links`와 무관하게 항상)
+    /// - 두 번 이상 출력되는 경로 (`BuildError::DuplicateOutput`)
+    /// - 두 번 이상 선언된 Block ID (`BuildError::DuplicateId`), 페이지 ID (`BuildError::DuplicatePageId`)
+    ///
+    /// 플러그인의 `before_analysis`, `after_page_render`는 실행하고 `after_write`는 실행하지 않습니다.
+    ///
+    /// 성공하면 `manifest`는 기록했을 파일 목록과 바이트 수입니다 (`removed`, `unchanged`는 비어 있음).
+    pub fn check(&self) -> Result<BuildReport, BuildError> {
+        check_rule_data().map_err(|err| BuildError::RuleData(err.clone()))?;
+        self.config.validate_urls()?;
+        let interning = Arc::new(InternPool::new());
+        let _scope = interning.enter();
+        let mut timings = BuildTimings::default();
+        let started = Stopwatch::start();
+        self.before_analysis()?;
+        let index = self.index();
+        timings.analysis = started.elapsed();
+
+        let started = Stopwatch::start();
+        let generated = self.generated_pages();
+        let pages = self.output_pages(&generated);
+        let workers = self.workers(pages.len(), &interning)?;
+        let known = self.output_urls(&pages, &index);
+        let mut errors = Vec::new();
+        let mut warnings = Vec::new();
+        for (page_errors, page_warnings) in
+            workers.for_each_page(&pages, |page, _| self.check_page(page, &index, &known, true))
+        {
+            errors.extend(page_errors);
+            warnings.extend(page_warnings);
+        }
+        for (id, duplicate) in index.duplicate_ids() {
+            errors.push(BuildError::DuplicateId {
+                id: id.clone(),
+                first: index.block_href(id).unwrap_or_default(),
+                duplicate: duplicate.href(),
+            });
+        }
+        if let Some(refs) = index.get::<PageRefs>() {
+            for (id, duplicate) in &refs.duplicates {
+                errors.push(BuildError::DuplicatePageId {
+                    id: id.clone(),
+                    first: refs.ids.get(id).map(|t| t.output_path.clone()).unwrap_or_default(),
+                    duplicate: duplicate.output_path.clone(),
+                });
+            }
+        }
+        timings.check = started.elapsed();
+
+        let started = Stopwatch::start();
+        let limit = self.page_size_limit.unwrap_or(DEFAULT_PAGE_SIZE_LIMIT);
+        let fragments = Arc::new(FragmentCache::new());
+        let rendered =
+            workers.for_each_page(&pages, |page, assets| self.render_page(page, assets, &index, &fragments));
+        let mut outputs: Vec<(PathBuf, u64)> = Vec::new();
+        let mut documents = Vec::new();
+        for render in rendered {
+            documents.extend(render.documents);
+            warnings.extend(render.warnings);
+        }
+        for rendered in documents {
+            let bytes = rendered.html.as_str().len() as u64;
+            if bytes > limit {
+                warnings.push(BuildWarning {
+                    page: rendered.path.clone(),
+                    kind: WarningKind::LargePage { bytes, limit },
+                });
+            }
+            outputs.push((rendered.path, bytes));
+        }
+        if let Some(files) = index.get::<GeneratedFiles>() {
+            outputs.extend(files.0.iter().map(|(path, contents)| (path.clone(), contents.len() as u64)));
+        }
+        if let Some(theme) = self.metadata.get::<Theme>() {
+            outputs.push((theme.stylesheet_path(), theme.stylesheet().len() as u64));
+        }
+        timings.render = started.elapsed();
+
+        let files: BTreeSet<PathBuf> = outputs.iter().map(|(path, _)| path.clone()).collect();
+        errors.extend(self.duplicate_outputs(&pages, &index));
+        if let Some(err) = BuildError::from_errors(errors) {
+            return Err(err);
+        }
+
+        Ok(BuildReport {
+            manifest: BuildManifest {
+                output_dir: self.config.output_dir.clone(),
+                files: files.into_iter().collect(),
+                unchanged: Vec::new(),
+                removed: Vec::new(),
+                bytes_written: outputs.iter().map(|(_, bytes)| bytes).sum(),
+            },
+            pages: pages.len(),
+            unchanged_pages: 0,
+            timings,
+            interning: interning.stats(),
+            fragments: fragments.stats(),
+            warnings,
+        })
+    }
+
+    /// 큰 사이트 정리용 점검 (파일을 쓰지 않음). 모든 출력 문서(Layout의 내비게이션, 분류 목록 페이지 포함)를
+    /// 렌더링해 참조를 모은 뒤 다음을 찾습니다.
+    /// - 다른 문서에서 링크하지 않고 컬렉션(태그, 카테고리, 연재)에도 속하지 않는 페이지.
+    ///   사이트 루트, 오류 페이지, 리다이렉트는 제외합니다.
+    /// - `SiteConfig::asset_dirs`의 파일과 방문자가 만든 asset 파일(`AUDIT_ASSET_EXTENSIONS`) 중
+    ///   어느 문서나 스타일시트도 참조하지 않는 파일.
+    pub fn audit(&self) -> ContentAudit {
+        let index = self.index();
+        let generated = self.generated_pages();
+        let base_url = self.config.base_url.trim_end_matches('/');
+        let mut linked = BTreeSet::new();
+        let mut referenced = BTreeSet::new();
+        for (page, assets) in self.output_pages(&generated) {
+            let page_url = self.page_url(page);
+            let own = normalize_url(&page_url);
+            let base = self.render_base(page, &index).into_map();
+            let document = document_ir(page, &base, &assets);
+            collect_references(&document, base_url, &mut |reference| {
+                let Some(target) = resolve_href(&page_url, &reference) else {
+                    return;
+                };
+                if target != own {
+                    linked.insert(target.clone());
+                }
+                referenced.insert(target);
+            });
+        }
+
+        let mut candidates: Vec<(PathBuf, Vec<u8>)> = index
+            .get::<GeneratedFiles>()
+            .map(|files| files.0.clone().into_iter().collect())
+            .unwrap_or_default();
+        candidates.retain(|(path, _)| {
+            path.extension()
+                .and_then(|e| e.to_str())
+                .is_some_and(|e| AUDIT_ASSET_EXTENSIONS.contains(&e))
+        });
+        for dir in &self.config.asset_dirs {
+            // 읽을 수 없는 디렉토리는 건너뜀 (복사 단계가 오류를 보고)
+            let _ = collect_asset_files(dir, dir, &mut candidates);
+        }
+        for (path, contents) in &candidates {
+            if path.extension().is_some_and(|e| e == "css") {
+                let url = self.config.url_for(path);
+                for reference in css_urls(&String::from_utf8_lossy(contents)) {
+                    referenced.extend(resolve_href(&url, &reference));
+                }
+            }
+        }
+        let unused_assets: BTreeSet<PathBuf> = candidates
+            .into_iter()
+            .map(|(path, _)| path)
+            .filter(|path| !referenced.contains(&normalize_url(&self.config.url_for(path))))
+            .collect();
+
+        let orphan_pages = self
+            .visible_pages()
+            .into_iter()
+            .filter(|page| {
+                let metadata = self.page_metadata(*page);
+                let url = normalize_url(&self.page_url(*page));
+                let in_collection = metadata.get::<Tags>().is_some_and(|t| !t.0.is_empty())
+                    || metadata.get::<Categories>().is_some_and(|c| !c.0.is_empty())
+                    || metadata.contains::<Series>();
+                url != "/"
+                    && !is_error_page_path(&page.path())
+                    && !metadata.contains::<Redirect>()
+                    && !in_collection
+                    && !linked.contains(&url)
+            })
+            .map(|page| self.output_path(page))
+            .collect();
+        ContentAudit {
+            orphan_pages,
+            unused_assets: unused_assets.into_iter().collect(),
+        }
+    }
+
+    /// 빌드가 기록할 모든 파일의 정규화된 URL (페이지와 대체 대상, 방문자가 만든 파일, 테마 스타일시트).
+    fn output_urls(&self, pages: &[(&dyn Page, Assets)], index: &SiteIndex) -> BTreeSet<String> {
+        self.output_files(pages, index)
+            .iter()
+            .map(|path| normalize_url(&self.config.url_for(path)))
+            .collect()
+    }
+
+    /// 기록할 파일 경로 (페이지의 대상별 문서, 방문자가 만든 파일, 테마 스타일시트). 중복을 포함합니다.
+    fn output_files(&self, pages: &[(&dyn Page, Assets)], index: &SiteIndex) -> Vec<PathBuf> {
+        let page_paths = pages.iter().flat_map(|(page, _)| {
+            let output_path = self.output_path(*page);
+            Self::targets(*page)
+                .into_iter()
+                .map(move |target| target_path(&output_path, &target))
+        });
+        let generated = index
+            .get::<GeneratedFiles>()
+            .into_iter()
+            .flat_map(|files| files.0.keys().cloned());
+        let theme = self.metadata.get::<Theme>().map(Theme::stylesheet_path);
+        page_paths.chain(generated).chain(theme).collect()
+    }
+
+    /// 두 번 이상 기록될 경로마다 `BuildError::DuplicateOutput` (경로순).
+    fn duplicate_outputs(&self, pages: &[(&dyn Page, Assets)], index: &SiteIndex) -> Vec<BuildError> {
+        let mut files = BTreeSet::new();
+        let mut duplicates = BTreeSet::new();
+        for path in self.output_files(pages, index) {
+            if files.contains(&path) {
+                duplicates.insert(path);
+            } else {
+                files.insert(path);
+            }
+        }
+        duplicates
+            .into_iter()
+            .map(|path| BuildError::DuplicateOutput { path })
+            .collect()
+    }
+
+    /// 페이지 하나의 head 값, Block 구성, (`links`이면) 내부 링크 검사.
+    /// 본문 Block의 `alt` 없는 이미지와 찾지 못한 페이지 링크(`Link`), 제목 구조 위반(`OutlineValidator`),
+    /// 등록되지 않은 아이콘(`Icon`), 정의하지 않은 사이트 변수(`var!`), 오류 표시로 바뀐 Block(`render_block`),
+    /// 깨진 외부 링크(`ExternalLinkChecker`)는 경고로 반환합니다.
+    /// 대상 페이지에 없는 `id`를 가리키는 fragment 링크는 `SiteConfig::anchors`에 따라 경고 또는 오류입니다.
+    fn check_page(
+        &self,
+        page: &dyn Page,
+        index: &SiteIndex,
+        known: &BTreeSet<String>,
+        links: bool,
+    ) -> (Vec<BuildError>, Vec<BuildWarning>) {
+        let output_path = self.output_path(page);
+        let base = self.render_base(page, index).into_map();
+        let ctx = page_context(page, &base);
+        let mut errors = Vec::new();
+
+        if let Err(err) = page.head(&ctx).validate() {
+            errors.push(BuildError::InvalidAttribute {
+                page: output_path.clone(),
+                block: None,
+                source: Box::new(err),
+            });
+        }
+        let mut warnings = Vec::new();
+        let page_url = self.page_url(page);
+        for (i, block) in page.blocks().into_iter().enumerate() {
+            check_block(block, vec![i], &output_path, &self.config.block_errors, &mut errors);
+
+            let ir = render_block(block, &ctx);
+            collect_missing_alt(&ir, &mut |src| {
+                warnings.push(BuildWarning {
+                    page: output_path.clone(),
+                    kind: WarningKind::MissingAltText { src },
+                });
+            });
+            collect_unresolved_links(&ir, &mut |target| {
+                warnings.push(BuildWarning {
+                    page: output_path.clone(),
+                    kind: WarningKind::UnresolvedLink { target },
+                });
+            });
+            collect_undefined_vars(&ir, &mut |name| {
+                warnings.push(BuildWarning {
+                    page: output_path.clone(),
+                    kind: WarningKind::UndefinedVar { name },
+                });
+            });
+            collect_block_errors(&ir, &mut |block, message| {
+                warnings.push(BuildWarning {
+                    page: output_path.clone(),
+                    kind: WarningKind::BlockFailed { block, message },
+                });
+            });
+            if self.config.anchors != AnchorPolicy::Ignore {
+                collect_attr(&ir, "href", &mut |href| {
+                    if !is_broken_anchor(index, &page_url, &href) {
+                        return;
+                    }
+                    let block = Some(BlockPath {
+                        indices: vec![i],
+                        id: block.id(),
+                    });
+                    match self.config.anchors {
+                        AnchorPolicy::Deny => errors.push(BuildError::BrokenAnchor {
+                            page: output_path.clone(),
+                            href,
+                            block,
+                        }),
+                        _ => warnings.push(BuildWarning {
+                            page: output_path.clone(),
+                            kind: WarningKind::BrokenAnchor { href },
+                        }),
+                    }
+                });
+            }
+            if !links {
+                continue;
+            }
+            collect_attr(&ir, "href", &mut |href| {
+                if is_external(&href) {
+                    return;
+                }
+                let Some(target) = resolve_href(&page_url, &href) else {
+                    return;
+                };
+                if !known.contains(&target) {
+                    errors.push(BuildError::BrokenLink {
+                        page: output_path.clone(),
+                        href,
+                        block: Some(BlockPath {
+                            indices: vec![i],
+                            id: block.id(),
+                        }),
+                    });
+                }
+            });
+        }
+        if let Some(violations) = index.get::<HeadingOutlines>().and_then(|o| o.get(&page_url)) {
+            warnings.extend(violations.iter().map(|violation| BuildWarning {
+                page: output_path.clone(),
+                kind: WarningKind::HeadingOutline(violation.clone()),
+            }));
+        }
+        if let Some(icons) = index.get::<MissingIcons>().and_then(|m| m.get(&page_url)) {
+            warnings.extend(icons.iter().map(|name| BuildWarning {
+                page: output_path.clone(),
+                kind: WarningKind::MissingIcon { name: name.clone() },
+            }));
+        }
+        if let Some(keys) = index.get::<UnknownCitations>().and_then(|u| u.get(&page_url)) {
+            warnings.extend(keys.iter().map(|key| BuildWarning {
+                page: output_path.clone(),
+                kind: WarningKind::UnknownCitation { key: key.clone() },
+            }));
+        }
+        #[cfg(feature = "link-check")]
+        if let Some(dead) = index.get::<DeadLinks>().and_then(|d| d.get(&page_url)) {
+            warnings.extend(dead.iter().map(|link| BuildWarning {
+                page: output_path.clone(),
+                kind: WarningKind::DeadLink {
+                    url: link.url.clone(),
+                    reason: link.reason.clone(),
+                },
+            }));
+        }
+        (errors, warnings)
+    }
+
+    /// 플러그인의 `before_analysis`를 등록 순서대로 실행. 첫 오류에서 멈춥니다.
+    fn before_analysis(&self) -> Result<(), BuildError> {
+        for plugin in &self.plugins {
+            plugin.before_analysis(self).map_err(|source| BuildError::Plugin {
+                plugin: plugin.name().to_string(),
+                source,
+            })?;
+        }
+        Ok(())
+    }
+
+    /// `rebuild_affected`에서 다시 렌더링하지 않는 페이지인지.
+    /// 대상 밖이고, 이전 빌드의 의존성과 출력 파일이 모두 남아 있어야 합니다.
+    fn is_kept(&self, page: &dyn Page, state: &BuildState) -> bool {
+        let Some(affected) = state.affected else {
+            return false;
+        };
+        let output_path = self.output_path(page);
+        !affected.contains(&output_path)
+            && state.graph.is_some_and(|graph| graph.get(&output_path).is_some())
+            && self.output_files_exist(page, &state.writer)
+    }
+
+    fn output_files_exist(&self, page: &dyn Page, writer: &OutputWriter) -> bool {
+        let output_path = self.output_path(page);
+        Self::targets(page)
+            .iter()
+            .all(|target| writer.fs().is_file(&target_path(&output_path, target)))
+    }
+
+    /// 페이지를 렌더링하지 않고 유지할지 정합니다. `rebuild_affected`의 대상 밖이거나,
+    /// 지문이 이전 빌드와 같고 파일이 남아 있으면 유지합니다.
+    fn page_state(&self, page: &dyn Page, assets: &Assets, state: &BuildState) -> PageState {
+        let output_path = self.output_path(page);
+        if self.is_kept(page, state) {
+            return PageState {
+                fingerprint: state.previous.get(&output_path),
+                unchanged: true,
+            };
+        }
+        let Some(site) = &state.site else {
+            return PageState {
+                fingerprint: None,
+                unchanged: false,
+            };
+        };
+        let current = self.fingerprint_with(site.clone(), page, assets, state.index);
+        PageState {
+            fingerprint: Some(current),
+            unchanged: state.previous.get(&output_path) == Some(current)
+                && self.output_files_exist(page, &state.writer),
+        }
+    }
+
+    /// 페이지 하나를 렌더링해 기록. 유지할 페이지(`page_state`)는 기존 파일만 남깁니다.
+    /// 렌더링 결과가 `page_size_limit`보다 큰 문서는 경고로 반환합니다.
+    fn build_page(
+        &self,
+        page: &dyn Page,
+        assets: &Assets,
+        page_state: PageState,
+        state: &BuildState,
+    ) -> Result<PageBuild, BuildError> {
+        let output_path = self.output_path(page);
+        let previous_dependencies = state.graph.and_then(|graph| graph.get(&output_path)).cloned();
+        let PageState { fingerprint, unchanged } = page_state;
+        if unchanged {
+            for target in Self::targets(page) {
+                let path = target_path(&output_path, &target);
+                state.writer.keep(&path).map_err(|err| BuildError::io(&path, err))?;
+            }
+            return Ok(PageBuild {
+                fingerprint,
+                unchanged: true,
+                warnings: Vec::new(),
+                dependencies: previous_dependencies,
+            });
+        }
+
+        let limit = self.page_size_limit.unwrap_or(DEFAULT_PAGE_SIZE_LIMIT);
+        let render = self.render_page(page, assets, state.index, &state.fragments);
+        let mut warnings = render.warnings;
+        for rendered in render.documents {
+            let html = rendered.html.as_str().as_bytes();
+            state
+                .writer
+                .write(&rendered.path, html)
+                .map_err(|err| BuildError::io(&rendered.path, err))?;
+            if html.len() as u64 > limit {
+                warnings.push(BuildWarning {
+                    page: rendered.path,
+                    kind: WarningKind::LargePage {
+                        bytes: html.len() as u64,
+                        limit,
+                    },
+                });
+            }
+        }
+        Ok(PageBuild {
+            fingerprint,
+            unchanged: false,
+            warnings,
+            dependencies: state
+                .graph
+                .map(|_| self.page_dependencies(page, assets, render.inputs)),
+        })
+    }
+
+    /// 페이지 작업 스레드 풀. `jobs`개(페이지 수 이하) 스레드로 한 번 만들어 빌드의 모든 단계가 함께 씁니다.
+    /// 작업 스레드는 시작할 때 빌드의 문자열 풀(`interning`)을 활성화합니다.
+    fn workers(&self, pages: usize, interning: &Arc<InternPool>) -> Result<Workers, BuildError> {
+        // wasm32-unknown-unknown에는 스레드가 없음
+        if cfg!(target_arch = "wasm32") {
+            return Ok(Workers(None));
+        }
+        let jobs = self
+            .jobs
+            .unwrap_or_else(|| thread::available_parallelism().map_or(1, |n| n.get()))
+            .min(pages);
+        match jobs {
+            0 | 1 => Ok(Workers(None)),
+            jobs => {
+                let interning = interning.clone();
+                rayon::ThreadPoolBuilder::new()
+                    .num_threads(jobs)
+                    .start_handler(move |_| interning.enter_thread())
+                    .build()
+                    .map(|pool| Workers(Some(pool)))
+                    .map_err(BuildError::ThreadPool)
+            }
+        }
+    }
+}
+
+/// 페이지 작업을 나눠 실행하는 rayon 풀. 없으면 (`jobs(1)`, 페이지 하나 이하) 현재 스레드에서 처리합니다.
+struct Workers(Option<rayon::ThreadPool>);
+
+impl Workers {
+    /// 페이지마다 `work`를 실행하고 결과를 페이지 순서로 반환.
+    /// 작업 훔치기로 나누므로 오래 걸리는 페이지가 있어도 스레드가 고르게 쓰입니다.
+    fn for_each_page<T, F>(&self, pages: &[(&dyn Page, Assets)], work: F) -> Vec<T>
+    where
+        T: Send,
+        F: Fn(&dyn Page, &Assets) -> T + Sync,
+    {
+        match &self.0 {
+            Some(pool) => pool.install(|| {
+                pages
+                    .par_iter()
+                    .map(|(page, assets)| work(*page, assets))
+                    .collect()
+            }),
+            None => pages.iter().map(|(page, assets)| work(*page, assets)).collect(),
+        }
+    }
+}
+
+/// 출력 디렉토리의 이전 빌드 의존성 그래프.
+fn read_graph(fs: &dyn SiteFs) -> Result<Option<DependencyGraph>, BuildError> {
+    DependencyGraph::read(fs).map_err(|err| BuildError::io(fs.root(), err))
+}
+
+/// Block과 자식들의 `Block::check` (깊이 우선). `BlockErrorMode::Lenient`인 Block의 실패는
+/// 렌더링 결과의 오류 표시로 남으므로 오류로 보고하지 않고, 렌더링되지 않는 자식도 검사하지 않습니다.
+fn check_block(
+    block: &dyn Block,
+    indices: Vec<usize>,
+    page: &Path,
+    policy: &BlockErrors,
+    errors: &mut Vec<BuildError>,
+) {
+    match block.check() {
+        Err(_) if policy.mode_for(block) == BlockErrorMode::Lenient => return,
+        Err(source) => errors.push(BuildError::InvalidAttribute {
+            page: page.to_path_buf(),
+            block: Some(BlockPath {
+                indices: indices.clone(),
+                id: block.id(),
+            }),
+            source,
+        }),
+        Ok(()) => {}
+    }
+    for (i, child) in block.children().into_iter().enumerate() {
+        let mut child_indices = indices.clone();
+        child_indices.push(i);
+        check_block(child, child_indices, page, policy, errors);
+    }
+}
+
+/// `alt` 속성이 없는 `<img>`의 `src` (문서 순서).
+fn collect_missing_alt(node: &IRNode, f: &mut dyn FnMut(String)) {
+    if node.get_tag().as_str() == "img" && attr_token(node, "alt").is_none() {
+        f(attr_token(node, "src").unwrap_or_default());
+    }
+    for child in node.get_childs() {
+        if let Element::Node(child) = child {
+            collect_missing_alt(child, f);
+        }
+    }
+}
+
+/// 찾지 못한 `Link`의 대상 이름 (문서 순서).
+fn collect_unresolved_links(node: &IRNode, f: &mut dyn FnMut(String)) {
+    for child in node.get_childs() {
+        match child {
+            Element::Node(child) => collect_unresolved_links(child, f),
+            Element::Raw(raw) => {
+                if let Some(target) = unresolved_target(raw.as_str()) {
+                    f(target.to_string());
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// 정의하지 않은 사이트 변수 이름 (문서 순서).
+fn collect_undefined_vars(node: &IRNode, f: &mut dyn FnMut(String)) {
+    for child in node.get_childs() {
+        match child {
+            Element::Node(child) => collect_undefined_vars(child, f),
+            Element::Raw(raw) => {
+                if let Some(name) = undefined_var(raw.as_str()) {
+                    f(name.to_string());
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// 오류 표시로 바뀐 Block의 (종류, 오류 메시지) (문서 순서).
+fn collect_block_errors(node: &IRNode, f: &mut dyn FnMut(String, String)) {
+    for child in node.get_childs() {
+        match child {
+            Element::Node(child) => collect_block_errors(child, f),
+            Element::Raw(raw) => {
+                if let Some((kind, message)) = block_error_marker(raw.as_str()) {
+                    f(kind.to_string(), message.to_string());
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// 문서가 참조하는 사이트 내부 URL (`href`, `src`, `srcset`, `poster`, `data`, URL 형태의 `content`).
+/// 기본 URL이 붙은 절대 URL은 사이트 루트 기준으로 바꾸고, 외부 URL은 건너뜁니다.
+fn collect_references(node: &IRNode, base_url: &str, f: &mut dyn FnMut(String)) {
+    let mut emit = |value: &str| {
+        let value = match value.strip_prefix(base_url) {
+            Some(rest) if !base_url.is_empty() => format!("/{}", rest.trim_start_matches('/')),
+            _ => value.to_string(),
+        };
+        if !is_external(&value) {
+            f(value);
+        }
+    };
+    for key in ["href", "src", "poster", "data"] {
+        collect_attr(node, key, &mut |value| emit(&value));
+    }
+    collect_attr(node, "srcset", &mut |srcset| {
+        for candidate in srcset.split(',') {
+            if let Some(url) = candidate.split_whitespace().next() {
+                emit(url);
+            }
+        }
+    });
+    collect_attr(node, "content", &mut |content| {
+        if content.starts_with('/') || (!base_url.is_empty() && content.starts_with(base_url)) {
+            emit(&content);
+        }
+    });
+}
+
+/// 스타일시트의 `url(...)` 값 (따옴표 제거, `data:` 제외).
+fn css_urls(css: &str) -> Vec<String> {
+    css.split("url(")
+        .skip(1)
+        .filter_map(|rest| {
+            let url = rest.split(')').next()?.trim().trim_matches(['"', '\'']);
+            (!url.is_empty() && !url.starts_with("data:") && !url.starts_with('#')).then(|| url.to_string())
+        })
+        .collect()
+}
+
+/// 내부 링크의 fragment가 대상 페이지에 없는 `id`를 가리키는지.
+/// `#`만 있거나 `#top`(문서 맨 위)인 링크와 분석하지 않은 대상(생성 페이지, 정적 파일)은 검사하지 않습니다.
+fn is_broken_anchor(index: &SiteIndex, page_url: &str, href: &str) -> bool {
+    let Some((path, fragment)) = href.split_once('#') else {
+        return false;
+    };
+    if is_external(href) || fragment.is_empty() || fragment == "top" {
+        return false;
+    }
+    let target = match path.split('?').next().unwrap_or("") {
+        "" => page_url.to_string(),
+        _ => match resolve_href(page_url, href) {
+            Some(target) => target,
+            None => return false,
+        },
+    };
+    index
+        .anchors(&target)
+        .is_some_and(|anchors| !anchors.contains(fragment))
+}
+
+/// Block 하나를 HTML 문자열로 렌더링.
+fn render_block_html(block: &dyn Block, ctx: &RenderContext) -> String {
+    block
+        .render_to_ir(ctx)
+        .accept(HtmlRenderer::new())
+        .finalize()
+        .as_str()
+        .to_string()
+}
+
+/// 언어 디렉토리 아래 경로. 이미 `<언어>/`로 시작하면 그대로.
+fn locale_path(path: &Path, language: &str) -> PathBuf {
+    if path.starts_with(language) {
+        path.to_path_buf()
+    } else {
+        Path::new(language).join(path)
+    }
+}
+
+/// 단계마다 `Weight` 순으로 정렬 (안정 정렬, 가중치 없는 링크는 뒤로).
+fn sort_by_weight(links: &mut [NavLink], weights: &BTreeMap<String, i32>) {
+    links.sort_by_key(|link| weights.get(&link.href).map_or((true, 0), |w| (false, *w)));
+    for link in links {
+        sort_by_weight(&mut link.children, weights);
+    }
+}
+
+fn union_all(assets: &[Assets]) -> Assets {
+    assets.iter().fold(Assets::new(), |acc, a| acc.union(a))
+}
+
+/// 디렉토리의 파일과 내용 (디렉토리 기준 상대 경로). 숨김 파일은 건너뜁니다 (`StaticFiles`와 같음).
+fn collect_asset_files(root: &Path, dir: &Path, files: &mut Vec<(PathBuf, Vec<u8>)>) -> io::Result<()> {
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        let hidden = path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .is_some_and(|n| n.starts_with('.'));
+        if hidden {
+            continue;
+        }
+        if path.is_dir() {
+            collect_asset_files(root, &path, files)?;
+        } else if let Ok(relative) = path.strip_prefix(root) {
+            files.push((relative.to_path_buf(), std::fs::read(&path)?));
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,62 @@
+//! # clock.rs - 빌드 시계
+//!
+//! ## 목적
+//! 빌드가 읽는 "오늘"을 한 곳에서 주입합니다. 발행 판정(예약 글), 날짜가 없는 피드의 갱신일처럼
+//! 시각에 따라 바뀌는 출력은 모두 `Site::clock`을 거치므로, 시계를 고정하면 같은 입력은
+//! 어느 기계에서 언제 빌드해도 같은 바이트를 만듭니다.
+//!
+//! ## 사용 예시
+//! ```rust
+//! // 고정 날짜 (테스트, 재현 빌드)
+//! let site = Site::new().clock(FixedClock(Date::new(2024, 3, 1).unwrap()));
+//!
+//! // 기본: 시스템 시계. SOURCE_DATE_EPOCH가 있으면 그 값
+//! // $ SOURCE_DATE_EPOCH=$(git log -1 --format=%ct) cargo run --release
+//! ```
+//!
+//! ## 설계 결정
+//! - **`SOURCE_DATE_EPOCH` 존중**: 재현 가능한 빌드(reproducible-builds.org)의 표준 환경 변수를 따르므로
+//!   배포 파이프라인은 코드를 바꾸지 않고 시계를 고정할 수 있습니다.
+//! - **날짜 단위**: 출력에 쓰는 시각은 모두 날짜(`Date`)이므로 시계도 날짜만 돌려줍니다.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::metadata::types::Date;
+
+/// 재현 빌드용 기준 시각 환경 변수 (유닉스 초).
+pub const SOURCE_DATE_EPOCH: &str = "SOURCE_DATE_EPOCH";
+
+/// 빌드 기준 날짜를 돌려주는 시계.
+pub trait Clock: Send + Sync {
+    fn today(&self) -> Date;
+}
+
+/// 시스템 시계 (UTC). `SOURCE_DATE_EPOCH`가 있으면 그 시각을 씁니다.
+/// `wasm32-unknown-unknown`에는 시스템 시계가 없으므로 `FixedClock`을 넘깁니다.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn today(&self) -> Date {
+        let secs = std::env::var(SOURCE_DATE_EPOCH)
+            .ok()
+            .and_then(|epoch| epoch.trim().parse::<i64>().ok())
+            .unwrap_or_else(|| {
+                SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .map(|d| d.as_secs() as i64)
+                    .unwrap_or(0)
+            });
+        Date::from_unix_days(secs.div_euclid(86_400))
+    }
+}
+
+/// 고정 날짜 시계.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FixedClock(pub Date);
+
+impl Clock for FixedClock {
+    fn today(&self) -> Date {
+        self.0
+    }
+}
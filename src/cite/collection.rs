@@ -0,0 +1,245 @@
+//! # collection.rs - CollectionBuilder
+//!
+//! ## 목적
+//! 페이지 메타데이터의 `Tags`, `Categories`, `Series`, `PublishDate`로 컬렉션을 만듭니다.
+//! 결과(`Collections`)는 `SiteIndex`에 저장되어 아카이브 페이지, 관련 글, 피드가 사용합니다.
+//!
+//! ## 사용 예시
+//! ```rust
+//! let index = site.index();  // CollectionBuilder는 기본 실행
+//!
+//! index.pages_by_tag("rust");        // 발행일 최신순
+//! index.pages_by_category("tutorial");
+//! index.recent(5);
+//! index.pages_in_series("Rust 입문");  // 연재 순서
+//! index.related("/blog/first-post.html", 3);
+//!
+//! // 정렬 기준을 직접 지정 (여러 키, 마지막은 항상 URL 순)
+//! let order = SortOrder::by(SortKey::Weight).then_desc(SortKey::PublishDate);
+//! order.sort(index.pages_by_category("guide"));
+//! index.pages_sorted(&order);
+//! SortOrder::by(SortKey::custom(|p| p.metadata.get::<Stars>().map(|s| SortValue::Number(s.0))));
+//! ```
+//!
+//! ## 설계 결정
+//! - **정렬 기준**: 발행일 최신순, 발행일이 없는 페이지는 뒤로, 같으면 URL 순 (결정적 빌드).
+//! - **연재는 읽는 순서**: `Series::part`, 발행일 오래된 순, URL 순. 연재 소속은 페이지 메타데이터
+//!   (머리말 `series`)에만 선언하고 목록을 따로 관리하지 않습니다.
+//! - **명시적 정렬(`SortOrder`)**: 키마다 방향을 정하고, 값이 없는 페이지는 방향과 무관하게 뒤로 갑니다.
+//!   모든 키가 같으면 URL 순이므로 등록 순서와 무관하게 같은 결과가 나옵니다. 기본 컬렉션의 순서는
+//!   바꾸지 않고 질의 결과를 다시 정렬합니다.
+//! - **태그는 원문 그대로**: 대소문자나 공백을 바꾸지 않습니다. 표기는 콘텐츠 작성자가 통일합니다.
+
+use std::{cmp::Ordering, collections::BTreeMap, fmt::Debug, sync::Arc};
+
+use crate::cite::index::PageEntry;
+use crate::cite::visitor::{SiteContext, Visitor, VisitorResult};
+use crate::metadata::types::{Categories, Date, Description, PublishDate, Series, Tags, Title, Weight};
+use crate::metadata::value::MetadataValue;
+use crate::page::page::Page;
+
+/// 태그/카테고리 컬렉션.
+#[derive(Debug, Clone, Default)]
+pub struct Collections {
+    /// 출력 대상 페이지 (발행일 최신순)
+    pub pages: Vec<PageEntry>,
+    /// 태그 → `pages` 인덱스 (발행일 최신순)
+    pub tags: BTreeMap<String, Vec<usize>>,
+    /// 카테고리 → `pages` 인덱스 (발행일 최신순)
+    pub categories: BTreeMap<String, Vec<usize>>,
+    /// 연재 이름 → `pages` 인덱스 (연재 순서)
+    pub series: BTreeMap<String, Vec<usize>>,
+}
+impl MetadataValue for Collections {}
+
+/// `Collections`를 만드는 방문자. Site가 기본으로 맨 앞에 실행합니다.
+#[derive(Debug, Clone, Default)]
+pub struct CollectionBuilder {
+    pages: Vec<PageEntry>,
+}
+
+impl CollectionBuilder {
+    pub fn new() -> Self {
+        CollectionBuilder::default()
+    }
+}
+
+impl Visitor for CollectionBuilder {
+    fn visit_page(&mut self, _page: &dyn Page, ctx: &SiteContext) {
+        let metadata = &ctx.metadata;
+        self.pages.push(PageEntry {
+            url: ctx.url.clone(),
+            output_path: ctx.output_path.clone(),
+            title: metadata.get::<Title>().map(|t| t.0.clone()),
+            description: metadata.get::<Description>().map(|d| d.0.clone()),
+            date: metadata.get::<PublishDate>().map(|d| d.0),
+            tags: metadata.get::<Tags>().map(|t| t.0.clone()).unwrap_or_default(),
+            categories: metadata.get::<Categories>().map(|c| c.0.clone()).unwrap_or_default(),
+            series: metadata.get::<Series>().cloned(),
+            weight: metadata.get::<Weight>().map(|w| w.0),
+            metadata: metadata.clone(),
+        });
+    }
+
+    fn finalize(&self) -> VisitorResult {
+        let mut pages = self.pages.clone();
+        pages.sort_by(|a, b| b.date.cmp(&a.date).then_with(|| a.url.cmp(&b.url)));
+
+        let mut tags: BTreeMap<String, Vec<usize>> = BTreeMap::new();
+        let mut categories: BTreeMap<String, Vec<usize>> = BTreeMap::new();
+        let mut series: BTreeMap<String, Vec<usize>> = BTreeMap::new();
+        for (i, page) in pages.iter().enumerate() {
+            for tag in &page.tags {
+                tags.entry(tag.clone()).or_default().push(i);
+            }
+            for category in &page.categories {
+                categories.entry(category.clone()).or_default().push(i);
+            }
+            if let Some(s) = &page.series {
+                series.entry(s.name.clone()).or_default().push(i);
+            }
+        }
+        for parts in series.values_mut() {
+            parts.sort_by(|&a, &b| {
+                let (a, b) = (&pages[a], &pages[b]);
+                let part = |p: &PageEntry| p.series.as_ref().and_then(|s| s.part).unwrap_or(usize::MAX);
+                part(a)
+                    .cmp(&part(b))
+                    .then_with(|| a.date.is_none().cmp(&b.date.is_none()))
+                    .then_with(|| a.date.cmp(&b.date))
+                    .then_with(|| a.url.cmp(&b.url))
+            });
+        }
+
+        VisitorResult::new().custom(Collections {
+            pages,
+            tags,
+            categories,
+            series,
+        })
+    }
+}
+
+// ============================================================================
+// 정렬
+// ============================================================================
+
+/// 사용자 정의 정렬 키가 돌려주는 값. 종류가 다르면 `Number` < `Date` < `Text` 순입니다.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SortValue {
+    Number(i64),
+    Date(Date),
+    Text(String),
+}
+
+type Extractor = Arc<dyn Fn(&PageEntry) -> Option<SortValue> + Send + Sync>;
+
+/// 정렬 키. 값이 없는 페이지(발행일 없음, 제목 없음 등)는 방향과 무관하게 뒤로 갑니다.
+#[derive(Clone)]
+pub enum SortKey {
+    /// 발행일 (`PublishDate`)
+    PublishDate,
+    /// 제목 (`Title`, 문자열 순)
+    Title,
+    /// 순서 가중치 (`Weight`, 작을수록 앞)
+    Weight,
+    /// URL
+    Url,
+    /// 페이지에서 값을 읽는 함수
+    Custom(Extractor),
+}
+
+impl SortKey {
+    /// 사용자 정의 키.
+    pub fn custom<F>(extract: F) -> Self
+    where
+        F: Fn(&PageEntry) -> Option<SortValue> + Send + Sync + 'static,
+    {
+        SortKey::Custom(Arc::new(extract))
+    }
+
+    fn value(&self, page: &PageEntry) -> Option<SortValue> {
+        match self {
+            SortKey::PublishDate => page.date.map(SortValue::Date),
+            SortKey::Title => page.title.clone().map(SortValue::Text),
+            SortKey::Weight => page.weight.map(|w| SortValue::Number(w.into())),
+            SortKey::Url => Some(SortValue::Text(page.url.clone())),
+            SortKey::Custom(extract) => extract(page),
+        }
+    }
+}
+
+impl Debug for SortKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SortKey::PublishDate => f.write_str("PublishDate"),
+            SortKey::Title => f.write_str("Title"),
+            SortKey::Weight => f.write_str("Weight"),
+            SortKey::Url => f.write_str("Url"),
+            SortKey::Custom(_) => f.write_str("Custom"),
+        }
+    }
+}
+
+/// 정렬 방향.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum SortDirection {
+    #[default]
+    Ascending,
+    Descending,
+}
+
+/// 여러 키로 정하는 페이지 순서. 앞 키가 같으면 다음 키로, 모두 같으면 URL 순으로 비교합니다.
+///
+/// # Example
+/// ```rust
+/// // 가중치 순, 같으면 최신순
+/// let order = SortOrder::by(SortKey::Weight).then_desc(SortKey::PublishDate);
+/// let pages = order.sort(index.pages_by_tag("guide"));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct SortOrder {
+    keys: Vec<(SortKey, SortDirection)>,
+}
+
+impl SortOrder {
+    /// 오름차순 첫 키.
+    pub fn by(key: SortKey) -> Self {
+        SortOrder::default().then(key)
+    }
+
+    /// 내림차순 첫 키.
+    pub fn by_desc(key: SortKey) -> Self {
+        SortOrder::default().then_desc(key)
+    }
+
+    /// 오름차순 다음 키.
+    pub fn then(mut self, key: SortKey) -> Self {
+        self.keys.push((key, SortDirection::Ascending));
+        self
+    }
+
+    /// 내림차순 다음 키.
+    pub fn then_desc(mut self, key: SortKey) -> Self {
+        self.keys.push((key, SortDirection::Descending));
+        self
+    }
+
+    /// 두 페이지 비교.
+    pub fn compare(&self, a: &PageEntry, b: &PageEntry) -> Ordering {
+        self.keys
+            .iter()
+            .map(|(key, direction)| match (key.value(a), key.value(b)) {
+                (Some(a), Some(b)) if *direction == SortDirection::Descending => b.cmp(&a),
+                (a, b) => a.is_none().cmp(&b.is_none()).then_with(|| a.cmp(&b)),
+            })
+            .find(|ordering| ordering.is_ne())
+            .unwrap_or_else(|| a.url.cmp(&b.url))
+    }
+
+    /// 질의 결과를 이 순서로 정렬.
+    pub fn sort<'a>(&self, mut pages: Vec<&'a PageEntry>) -> Vec<&'a PageEntry> {
+        pages.sort_by(|a, b| self.compare(a, b));
+        pages
+    }
+}
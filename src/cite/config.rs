@@ -0,0 +1,386 @@
+//! # config.rs - SiteConfig
+//!
+//! ## 목적
+//! 사이트 이름, 기본 URL, 출력 디렉토리, 언어, URL 형식 등 빌드 설정을 한 곳에 모읍니다.
+//! Site가 렌더링 시 메타데이터로 주입하므로 Page/Block은 `ctx.get::<SiteConfig>()`로 읽습니다.
+//!
+//! ## 사용 예시
+//! ```rust
+//! let config = SiteConfig::new("My Blog", "https://example.com")
+//!     .output_dir("public")
+//!     .language("ko")
+//!     .pretty_urls(true)
+//!     .feed(FeedConfig::new().tag("post"))
+//!     .redirect_file(RedirectHost::Netlify)
+//!     .anchors(AnchorPolicy::Deny)
+//!     .cache_busting(CacheBusting::Query)   // 이름을 바꿀 수 없는 호스트: /assets/blocks.css?v=3f9a1c0d5e7b2a64
+//!     .asset_dir("static")
+//!     .heading_anchors(SlugStyle::Unicode)   // <h2 id="설치">
+//!     .heading_nav(true)                     // 목차 스크롤 추적 + 제목 고정 링크 스크립트
+//!     .block_errors(BlockErrorMode::Lenient)  // 실패한 Block은 오류 표시로 바꾸고 경고
+//!     .block_errors_for::<HBox>(BlockErrorMode::Strict)
+//!     .deploy(DeployConfig::new().target(DeployTarget::Netlify));  // `deploy` 기능
+//! config.validate()?;
+//!
+//! let site = Site::new().config(config);
+//!
+//! // Block에서
+//! let config = ctx.get::<SiteConfig>().unwrap();
+//! config.absolute_url("/blog/")  // "https://example.com/blog/"
+//! ```
+//!
+//! ## 구현 상태
+//! - [x] 이름, 기본 URL, 출력 디렉토리, 언어, 끝 슬래시, pretty URL
+//! - [x] 검증 (절대 기본 URL, 언어 태그, 쓰기 가능한 출력 디렉토리)
+//! - [x] RenderContext로 전달
+//! - [x] URL 생성 (`url_for`, `absolute_url`)
+//! - [x] 모음별 구독 피드 (`feed`)
+//! - [x] 호스트별 리다이렉트 파일 (`redirect_file`)
+//! - [x] 호스트별 배포 메타데이터 (`deploy`, `deploy` 기능)
+//! - [x] fragment 링크 검사 수준 (`anchors`)
+//! - [x] asset 캐시 무효화 방식 (`cache_busting`: 파일 이름 지문 / `?v=` 쿼리)
+//! - [x] 제목 anchor (`heading_anchors`: 유니코드 유지 / ASCII 음역)
+//! - [x] 목차, 고정 링크 스크립트 (`heading_nav`, 기본 꺼짐)
+//! - [x] Block 오류 처리 (`block_errors`: 빌드 중단 / 오류 표시 + 경고, Block 종류별 지정)
+//!
+//! ## 설계 결정
+//! - **기본 URL은 끝 `/` 없이 저장**: 경로는 항상 `/`로 시작하므로 이어 붙이기만 하면 됩니다.
+//! - **출력 디렉토리 검증은 실제 쓰기로**: 권한 비트만으로는 읽기 전용 마운트 등을 알 수 없으므로
+//!   임시 파일을 써 보고 지웁니다.
+
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use serde::Deserialize;
+
+#[cfg(feature = "deploy")]
+use crate::cite::deploy::{DeployConfig, DeployTarget};
+use crate::block::block::Block;
+use crate::cite::feed::FeedConfig;
+use crate::cite::redirects::RedirectHost;
+use crate::metadata::value::MetadataValue;
+use crate::page::slug::{SlugStyle, url_for_path};
+
+/// 사이트 빌드 설정.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SiteConfig {
+    /// 사이트 이름
+    pub name: String,
+    /// 기본 URL (끝 `/` 제외). 예: `https://example.com`, `https://example.com/blog`
+    pub base_url: String,
+    /// 출력 디렉토리. 기본: `dist`
+    pub output_dir: PathBuf,
+    /// 기본 언어 (BCP 47). 예: "ko"
+    pub language: Option<String>,
+    /// 디렉토리 URL 끝에 `/`를 붙일지. 기본: true (`/blog/`)
+    pub trailing_slash: bool,
+    /// `blog/post.html` 대신 `blog/post/index.html`로 출력. 기본: false
+    pub pretty_urls: bool,
+    /// 모음별 구독 피드 (RSS/Atom/JSON Feed). 있으면 Site가 `FeedGenerator`를 실행
+    pub feeds: Vec<FeedConfig>,
+    /// 리다이렉트 매핑을 기록할 호스트별 파일. 있으면 Site가 `RedirectFileGenerator`를 실행
+    pub redirect_hosts: Vec<RedirectHost>,
+    /// 배포 대상별 메타데이터. 있으면 Site가 모든 파일을 기록한 뒤 생성
+    #[cfg(feature = "deploy")]
+    pub deploy: Option<DeployConfig>,
+    /// `page#fragment` 링크의 대상 `id` 검사. 기본: `AnchorPolicy::Warn`
+    pub anchors: AnchorPolicy,
+    /// asset URL의 캐시 무효화 방식. 기본: `CacheBusting::Filename`
+    pub cache_busting: CacheBusting,
+    /// `CacheBusting::Query`에서 버전을 붙일 정적 파일 디렉토리 (사이트 루트에 복사되는 디렉토리)
+    pub asset_dirs: Vec<PathBuf>,
+    /// 본문 제목에 붙이는 `id`의 slug 문자. 없으면 붙이지 않음. 기본: `None`
+    pub heading_anchors: Option<SlugStyle>,
+    /// 목차 스크롤 추적, 제목 고정 링크 스크립트를 모든 페이지에 주입. 기본: false
+    pub heading_nav: bool,
+    /// `Block::check`에 실패한 Block의 처리. 기본: 모든 Block `BlockErrorMode::Strict`
+    pub block_errors: BlockErrors,
+}
+impl MetadataValue for SiteConfig {}
+
+/// 내용이 바뀐 asset을 브라우저가 다시 받게 하는 방식.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CacheBusting {
+    /// 내용 지문을 파일 이름에 넣음 (`/assets/blocks.3f9a1c0d5e7b2a64.css`)
+    #[default]
+    Filename,
+    /// 파일 이름은 그대로 두고 `asset_url`이 `?v=<지문>`을 붙임 (`/assets/blocks.css?v=3f9a1c0d5e7b2a64`).
+    /// 배포 도구가 파일 이름을 고정해야 하는 호스트용
+    Query,
+}
+
+/// `Block::check`에 실패한 Block의 처리.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BlockErrorMode {
+    /// 빌드 오류 (`BuildError::InvalidAttribute`)
+    #[default]
+    Strict,
+    /// 그 자리에 오류 표시(`render_block`)를 렌더링하고 경고 (`WarningKind::BlockFailed`)
+    Lenient,
+}
+
+/// 사이트 기본 `BlockErrorMode`와 Block 종류별 예외.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct BlockErrors {
+    pub default: BlockErrorMode,
+    /// `Block::kind` → 처리
+    pub kinds: BTreeMap<&'static str, BlockErrorMode>,
+}
+
+impl BlockErrors {
+    /// Block 종류에 적용할 처리.
+    pub fn mode_for(&self, block: &dyn Block) -> BlockErrorMode {
+        self.kinds.get(block.kind()).copied().unwrap_or(self.default)
+    }
+}
+
+/// 내부 링크의 fragment가 대상 페이지에 없는 `id`를 가리킬 때의 처리.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AnchorPolicy {
+    /// 검사하지 않음
+    Ignore,
+    /// 경고 (`WarningKind::BrokenAnchor`)
+    #[default]
+    Warn,
+    /// 빌드 오류 (`BuildError::BrokenAnchor`)
+    Deny,
+}
+
+impl Default for SiteConfig {
+    fn default() -> Self {
+        SiteConfig {
+            name: String::new(),
+            base_url: String::new(),
+            output_dir: PathBuf::from("dist"),
+            language: None,
+            trailing_slash: true,
+            pretty_urls: false,
+            feeds: Vec::new(),
+            redirect_hosts: Vec::new(),
+            #[cfg(feature = "deploy")]
+            deploy: None,
+            anchors: AnchorPolicy::default(),
+            cache_busting: CacheBusting::default(),
+            asset_dirs: Vec::new(),
+            heading_anchors: None,
+            heading_nav: false,
+            block_errors: BlockErrors::default(),
+        }
+    }
+}
+
+/// 설정 검증 오류.
+#[derive(Debug)]
+pub enum SiteConfigError {
+    /// 기본 URL이 `http(s)://host` 형식이 아님
+    InvalidBaseUrl(String),
+    /// 언어 태그가 BCP 47 형식이 아님
+    InvalidLanguage(String),
+    /// 출력 디렉토리를 만들거나 쓸 수 없음
+    OutputDirNotWritable(PathBuf, io::Error),
+}
+
+impl Display for SiteConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SiteConfigError::InvalidBaseUrl(url) => {
+                write!(f, "base URL must be absolute (http:// or https://): {}", url)
+            }
+            SiteConfigError::InvalidLanguage(lang) => {
+                write!(f, "invalid language tag (expected BCP 47, e.g. \"ko\", \"en-US\"): {}", lang)
+            }
+            SiteConfigError::OutputDirNotWritable(path, err) => {
+                write!(f, "output directory is not writable: {}: {}", path.display(), err)
+            }
+        }
+    }
+}
+
+impl std::error::Error for SiteConfigError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            SiteConfigError::OutputDirNotWritable(_, err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl SiteConfig {
+    pub fn new(name: &str, base_url: &str) -> Self {
+        SiteConfig {
+            name: name.to_string(),
+            base_url: base_url.trim_end_matches('/').to_string(),
+            ..SiteConfig::default()
+        }
+    }
+
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    pub fn base_url(mut self, base_url: &str) -> Self {
+        self.base_url = base_url.trim_end_matches('/').to_string();
+        self
+    }
+
+    pub fn output_dir<P: AsRef<Path>>(mut self, dir: P) -> Self {
+        self.output_dir = dir.as_ref().to_path_buf();
+        self
+    }
+
+    pub fn language(mut self, language: &str) -> Self {
+        self.language = Some(language.to_string());
+        self
+    }
+
+    pub fn trailing_slash(mut self, trailing_slash: bool) -> Self {
+        self.trailing_slash = trailing_slash;
+        self
+    }
+
+    pub fn pretty_urls(mut self, pretty: bool) -> Self {
+        self.pretty_urls = pretty;
+        self
+    }
+
+    /// 구독 피드 추가.
+    pub fn feed(mut self, feed: FeedConfig) -> Self {
+        self.feeds.push(feed);
+        self
+    }
+
+    /// 호스트별 리다이렉트 파일 추가 (`_redirects`, `vercel.json`, nginx `map`). 같은 호스트는 한 번만.
+    pub fn redirect_file(mut self, host: RedirectHost) -> Self {
+        if !self.redirect_hosts.contains(&host) {
+            self.redirect_hosts.push(host);
+        }
+        self
+    }
+
+    /// 배포 메타데이터 설정. Netlify 대상이면 `_redirects`도 기록합니다.
+    #[cfg(feature = "deploy")]
+    pub fn deploy(mut self, deploy: DeployConfig) -> Self {
+        if deploy.targets.contains(&DeployTarget::Netlify) {
+            self = self.redirect_file(RedirectHost::Netlify);
+        }
+        self.deploy = Some(deploy);
+        self
+    }
+
+    /// `page#fragment` 링크 검사 수준.
+    pub fn anchors(mut self, policy: AnchorPolicy) -> Self {
+        self.anchors = policy;
+        self
+    }
+
+    /// asset URL의 캐시 무효화 방식.
+    pub fn cache_busting(mut self, mode: CacheBusting) -> Self {
+        self.cache_busting = mode;
+        self
+    }
+
+    /// `CacheBusting::Query`에서 버전을 붙일 정적 파일 디렉토리 추가.
+    /// 디렉토리 안의 `css/site.css`는 `/css/site.css`로 참조된다고 봅니다.
+    /// `Site::audit`는 이 디렉토리의 파일 중 참조되지 않는 파일을 보고합니다.
+    pub fn asset_dir<P: AsRef<Path>>(mut self, dir: P) -> Self {
+        self.asset_dirs.push(dir.as_ref().to_path_buf());
+        self
+    }
+
+    /// 본문 제목(h1~h6)에 제목 텍스트로 만든 `id` 붙이기. 같은 제목은 `-1`, `-2`로 구분합니다.
+    pub fn heading_anchors(mut self, style: SlugStyle) -> Self {
+        self.heading_anchors = Some(style);
+        self
+    }
+
+    /// 목차 스크롤 추적과 제목 고정 링크 스크립트, 스타일시트(`page::heading_nav`)를 기록하고 모든 페이지에 연결.
+    pub fn heading_nav(mut self, enabled: bool) -> Self {
+        self.heading_nav = enabled;
+        self
+    }
+
+    /// 실패한 Block의 기본 처리.
+    pub fn block_errors(mut self, mode: BlockErrorMode) -> Self {
+        self.block_errors.default = mode;
+        self
+    }
+
+    /// Block 종류 `B`의 처리. 기본 처리보다 우선합니다.
+    pub fn block_errors_for<B: Block>(mut self, mode: BlockErrorMode) -> Self {
+        self.block_errors.kinds.insert(std::any::type_name::<B>(), mode);
+        self
+    }
+
+    /// 기본 URL과 언어 형식 검증 (파일 시스템 접근 없음).
+    pub fn validate_urls(&self) -> Result<(), SiteConfigError> {
+        check_base_url(&self.base_url)?;
+        match &self.language {
+            Some(lang) if !is_language_tag(lang) => {
+                Err(SiteConfigError::InvalidLanguage(lang.clone()))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// 출력 디렉토리를 만들고 임시 파일을 써 보아 쓰기 가능한지 확인.
+    pub fn validate_output_dir(&self) -> Result<(), SiteConfigError> {
+        let not_writable = |err| SiteConfigError::OutputDirNotWritable(self.output_dir.clone(), err);
+        fs::create_dir_all(&self.output_dir).map_err(not_writable)?;
+        let probe = self.output_dir.join(".quo-write-test");
+        fs::write(&probe, b"").map_err(not_writable)?;
+        fs::remove_file(&probe).map_err(not_writable)
+    }
+
+    /// 모든 설정 검증.
+    pub fn validate(&self) -> Result<(), SiteConfigError> {
+        self.validate_urls()?;
+        self.validate_output_dir()
+    }
+
+    /// 출력 경로의 사이트 루트 기준 URL. `trailing_slash`가 false면 디렉토리 URL의 끝 `/`를 뗍니다.
+    pub fn url_for(&self, path: &Path) -> String {
+        let url = url_for_path(path);
+        if self.trailing_slash || url == "/" {
+            url
+        } else {
+            url.trim_end_matches('/').to_string()
+        }
+    }
+
+    /// 사이트 루트 기준 경로 또는 URL을 기본 URL이 붙은 절대 URL로 변환. 이미 절대 URL이면 그대로.
+    pub fn absolute_url(&self, url: &str) -> String {
+        if url.starts_with("http://") || url.starts_with("https://") {
+            return url.to_string();
+        }
+        format!("{}/{}", self.base_url, url.trim_start_matches('/'))
+    }
+}
+
+fn check_base_url(url: &str) -> Result<(), SiteConfigError> {
+    let invalid = || SiteConfigError::InvalidBaseUrl(url.to_string());
+    let rest = url
+        .strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"))
+        .ok_or_else(invalid)?;
+    let host = rest.split('/').next().unwrap_or("");
+    if host.is_empty() || rest.contains(['?', '#', ' ']) {
+        return Err(invalid());
+    }
+    Ok(())
+}
+
+/// 간단한 BCP 47 형식 검사: 2~3자 언어 + `-`로 구분된 1~8자 영숫자 하위 태그.
+fn is_language_tag(tag: &str) -> bool {
+    let mut parts = tag.split('-');
+    let language = parts.next().unwrap_or("");
+    (2..=3).contains(&language.len())
+        && language.chars().all(|c| c.is_ascii_alphabetic())
+        && parts.all(|p| (1..=8).contains(&p.len()) && p.chars().all(|c| c.is_ascii_alphanumeric()))
+}
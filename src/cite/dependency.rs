@@ -0,0 +1,189 @@
+//! # dependency.rs - 페이지 의존성 그래프
+//!
+//! ## 목적
+//! 페이지마다 렌더링에 쓴 공유 입력(Partial, 태그/카테고리 컬렉션, asset, 사이트 메타데이터)을 기록합니다.
+//! 편집기나 감시 모드는 바뀐 입력만 알려 주고(`Site::rebuild_affected`), Site는 그 입력에 의존하는
+//! 페이지만 다시 렌더링합니다. 나머지 페이지의 파일은 그대로 유지합니다.
+//!
+//! ## 사용 예시
+//! ```rust
+//! site.build_output()?;  // 첫 빌드: dist/.quo-dependencies.json 기록 (`incremental`일 때)
+//!
+//! // 파일 감시 중 Partial 하나와 글 하나가 바뀜
+//! let report = site.rebuild_affected(&[
+//!     Input::partial("newsletter"),
+//!     Input::page("blog/post.html"),
+//! ])?;
+//!
+//! // 의존 관계 확인 (파일을 쓰지 않음)
+//! let graph = site.dependency_graph()?;
+//! graph.affected(&[Input::tag("rust")]);  // {"tags/rust/index.html", ...}
+//! ```
+//!
+//! ## 기록 방식
+//! - 정적: 사이트에서 물려받은 메타데이터 타입, 페이지 asset, 목록 페이지의 태그/카테고리
+//! - 렌더링 중: `Include`(Partial 이름), `ImageBlock`(이미지 URL), 사용자 Block의 `RenderContext::depends_on`
+//! - 페이지가 제공하는 입력: 페이지의 태그/카테고리 (글이 바뀌면 그 컬렉션을 쓰는 페이지도 다시 렌더링)
+//!
+//! ## 설계 결정
+//! - **페이지는 출력 경로로**: 지문(`incremental.rs`)과 같은 키를 씁니다 (`Site::output_path`).
+//! - **모르는 페이지는 다시 렌더링**: 그래프에 없는 페이지(새 페이지)나 출력 파일이 없는 페이지는
+//!   바뀐 입력과 무관하게 렌더링합니다. 사라진 페이지의 파일은 이전 빌드 파일 정리로 지워집니다.
+//! - **전역 파일은 항상 다시 생성**: sitemap, 피드처럼 방문자가 만든 파일은 분석 결과로 매번 만들고,
+//!   내용이 같으면 기록하지 않습니다.
+//! - **사이트 전체 질의는 직접 기록**: `recent`, `related`처럼 여러 페이지를 읽는 Block은 읽은 페이지를
+//!   `Input::Page`로 기록해야 합니다. 기록하지 않은 의존성은 `build`의 증분 빌드(지문)가 잡습니다.
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    io,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::cite::vfs::SiteFs;
+use crate::metadata::value::MetadataValue;
+
+/// 출력 디렉토리에 저장되는 이전 빌드의 의존성 그래프.
+pub const DEPENDENCY_FILE: &str = ".quo-dependencies.json";
+
+/// 페이지가 의존하는 공유 입력.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(tag = "kind", content = "name", rename_all = "snake_case")]
+pub enum Input {
+    /// 등록한 페이지 (출력 디렉토리 기준 출력 경로)
+    Page(PathBuf),
+    /// `Site::register_partial`로 등록한 조각 이름
+    Partial(String),
+    /// 태그 컬렉션
+    Tag(String),
+    /// 카테고리 컬렉션
+    Category(String),
+    /// 스타일시트, 스크립트, 이미지 URL
+    Asset(String),
+    /// 사이트 전역 메타데이터 타입 이름 (`std::any::type_name`)
+    Metadata(String),
+}
+
+impl Input {
+    pub fn page<P: AsRef<Path>>(path: P) -> Self {
+        Input::Page(path.as_ref().to_path_buf())
+    }
+
+    pub fn partial(name: &str) -> Self {
+        Input::Partial(name.to_string())
+    }
+
+    pub fn tag(tag: &str) -> Self {
+        Input::Tag(tag.to_string())
+    }
+
+    pub fn category(category: &str) -> Self {
+        Input::Category(category.to_string())
+    }
+
+    pub fn asset(url: &str) -> Self {
+        Input::Asset(url.to_string())
+    }
+
+    /// 사이트 전역 메타데이터 `T`.
+    pub fn metadata<T: MetadataValue>() -> Self {
+        Input::Metadata(std::any::type_name::<T>().to_string())
+    }
+}
+
+/// 렌더링 중 읽은 입력을 모으는 기록기. 페이지 렌더링 메타데이터로 주입됩니다.
+#[derive(Debug, Clone, Default)]
+pub struct DependencyRecorder(Arc<Mutex<BTreeSet<Input>>>);
+impl MetadataValue for DependencyRecorder {}
+
+impl DependencyRecorder {
+    pub fn new() -> Self {
+        DependencyRecorder::default()
+    }
+
+    pub fn record(&self, input: Input) {
+        self.0.lock().unwrap_or_else(|e| e.into_inner()).insert(input);
+    }
+
+    /// 지금까지 기록된 입력.
+    pub fn inputs(&self) -> BTreeSet<Input> {
+        self.0.lock().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+}
+
+/// 페이지 하나의 의존성.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PageDependencies {
+    /// 렌더링에 쓴 입력
+    pub inputs: BTreeSet<Input>,
+    /// 페이지가 제공하는 입력 (태그, 카테고리). 페이지가 바뀌면 함께 바뀐 것으로 봅니다
+    pub provides: BTreeSet<Input>,
+}
+
+/// 출력 경로 → 의존성.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DependencyGraph {
+    pub pages: BTreeMap<PathBuf, PageDependencies>,
+}
+
+impl DependencyGraph {
+    pub fn new() -> Self {
+        DependencyGraph::default()
+    }
+
+    pub fn get(&self, path: &Path) -> Option<&PageDependencies> {
+        self.pages.get(path)
+    }
+
+    pub fn insert(&mut self, path: PathBuf, dependencies: PageDependencies) {
+        self.pages.insert(path, dependencies);
+    }
+
+    /// 입력 중 하나라도 바뀌면 다시 렌더링해야 하는 페이지.
+    /// 바뀐 페이지 자신과, 그 페이지가 제공하던 태그/카테고리에 의존하는 페이지를 포함합니다.
+    pub fn affected(&self, changed: &[Input]) -> BTreeSet<PathBuf> {
+        let mut changed: BTreeSet<Input> = changed.iter().cloned().collect();
+        let mut affected = BTreeSet::new();
+        for input in changed.clone() {
+            if let Input::Page(path) = input {
+                if let Some(page) = self.pages.get(&path) {
+                    changed.extend(page.provides.iter().cloned());
+                }
+                affected.insert(path);
+            }
+        }
+        for (path, page) in &self.pages {
+            if page.inputs.iter().any(|input| changed.contains(input)) {
+                affected.insert(path.clone());
+            }
+        }
+        affected
+    }
+
+    /// `input`에 의존하는 페이지 (출력 경로 순).
+    pub fn dependents(&self, input: &Input) -> Vec<&Path> {
+        self.pages
+            .iter()
+            .filter(|(_, page)| page.inputs.contains(input))
+            .map(|(path, _)| path.as_path())
+            .collect()
+    }
+
+    /// 출력 파일 시스템의 이전 빌드 그래프. 파일이 없거나 해석할 수 없으면 `None`.
+    pub fn read(fs: &dyn SiteFs) -> io::Result<Option<Self>> {
+        match fs.read(Path::new(DEPENDENCY_FILE)) {
+            Ok(json) => Ok(serde_json::from_slice(&json).ok()),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// 출력 파일 시스템에 저장.
+    pub fn write(&self, fs: &dyn SiteFs) -> io::Result<()> {
+        let json = serde_json::to_string(self).map_err(io::Error::other)?;
+        fs.write(Path::new(DEPENDENCY_FILE), json.as_bytes())
+    }
+}
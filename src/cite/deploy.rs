@@ -0,0 +1,256 @@
+//! # deploy.rs - 호스트별 배포 메타데이터 (`deploy` 기능)
+//!
+//! ## 목적
+//! 정적 호스트가 읽는 설정 파일을 빌드 결과와 함께 만듭니다. 배포 대상과 캐시 정책은 `SiteConfig::deploy`로
+//! 정하고, Site는 모든 파일(플러그인이 쓴 파일 포함)을 기록한 뒤 그 목록으로 메타데이터를 만듭니다.
+//!
+//! ## 사용 예시
+//! ```rust
+//! let config = SiteConfig::new("My Blog", "https://blog.example.com").deploy(
+//!     DeployConfig::new()
+//!         .target(DeployTarget::Netlify)
+//!         .target(DeployTarget::GitHubPages)
+//!         .target(DeployTarget::S3)
+//!         .cache("assets/", "public, max-age=31536000, immutable"),
+//! );
+//! Site::new().config(config).build_output()?;
+//! // dist/_headers, dist/_redirects, dist/.nojekyll, dist/CNAME, dist/s3-manifest.json
+//! ```
+//!
+//! ## 출력 결과
+//! ```text
+//! # _headers (Netlify)
+//! /assets/app.js
+//!   Cache-Control: public, max-age=31536000, immutable
+//! /blog/hello/
+//!   Cache-Control: public, max-age=0, must-revalidate
+//!
+//! # CNAME (GitHub Pages, 기본 URL이 *.github.io가 아닐 때)
+//! blog.example.com
+//! ```
+//! ```json
+//! // s3-manifest.json: aws s3 cp --content-type --cache-control --content-encoding 인자
+//! {"files":[{"key":"index.html","content_type":"text/html; charset=utf-8",
+//!            "cache_control":"public, max-age=0, must-revalidate"},
+//!           {"key":"index.html.gz","content_type":"text/html; charset=utf-8",
+//!            "cache_control":"public, max-age=0, must-revalidate","content_encoding":"gzip"}]}
+//! ```
+//!
+//! ## 캐시 정책
+//! 1. `cache(접두사, 값)`로 지정한 규칙 중 가장 긴 접두사
+//! 2. 문서(HTML, XML, JSON, 텍스트): `document_cache` (기본: 매번 다시 확인)
+//! 3. 그 밖의 파일: `asset_cache` (기본: 하루)
+//!
+//! ## 설계 결정
+//! - **파일마다 명시**: Netlify는 겹치는 헤더 규칙을 합치므로 와일드카드 대신 출력 파일마다 한 항목을 씁니다.
+//!   HTML은 요청 URL(`SiteConfig::url_for`) 기준입니다.
+//! - **Netlify는 리다이렉트 파일 포함**: `DeployTarget::Netlify`를 고르면 `RedirectHost::Netlify`도 켭니다.
+//! - **미리 압축한 파일**: `_headers`에서는 빼고(호스트가 압축), S3 매니페스트에는 원본의 Content-Type과
+//!   `content_encoding`으로 넣습니다.
+//! - **메타데이터 파일은 목록에서 제외**: `_headers`, `s3-manifest.json` 등과 숨김 파일은 서로의 목록에 넣지 않습니다.
+
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+
+use crate::cite::compress::Encoding;
+use crate::cite::config::SiteConfig;
+use crate::cite::mime::{content_type, is_document};
+use crate::cite::writer::GeneratedFiles;
+
+/// Netlify 헤더 파일.
+pub const NETLIFY_HEADERS_FILE: &str = "_headers";
+/// S3 동기화 매니페스트.
+pub const S3_MANIFEST_FILE: &str = "s3-manifest.json";
+
+/// 배포 대상 호스트.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DeployTarget {
+    /// `_headers` (+ `_redirects`)
+    Netlify,
+    /// `.nojekyll`, 사용자 도메인이면 `CNAME`
+    GitHubPages,
+    /// `s3-manifest.json` (키별 Content-Type, Cache-Control)
+    S3,
+}
+
+/// 배포 설정.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeployConfig {
+    pub targets: Vec<DeployTarget>,
+    /// 문서의 Cache-Control. 기본: `public, max-age=0, must-revalidate`
+    pub document_cache: String,
+    /// 그 밖의 파일의 Cache-Control. 기본: `public, max-age=86400`
+    pub asset_cache: String,
+    /// (출력 경로 접두사, Cache-Control). 가장 긴 접두사가 우선
+    pub cache_rules: Vec<(String, String)>,
+}
+
+impl Default for DeployConfig {
+    fn default() -> Self {
+        DeployConfig {
+            targets: Vec::new(),
+            document_cache: "public, max-age=0, must-revalidate".to_string(),
+            asset_cache: "public, max-age=86400".to_string(),
+            cache_rules: Vec::new(),
+        }
+    }
+}
+
+/// S3 매니페스트 항목.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct S3Object {
+    pub key: String,
+    pub content_type: &'static str,
+    pub cache_control: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_encoding: Option<&'static str>,
+}
+
+#[derive(Serialize)]
+struct S3Manifest<'a> {
+    files: &'a [S3Object],
+}
+
+impl DeployConfig {
+    pub fn new() -> Self {
+        DeployConfig::default()
+    }
+
+    /// 배포 대상 추가. 같은 대상은 한 번만.
+    pub fn target(mut self, target: DeployTarget) -> Self {
+        if !self.targets.contains(&target) {
+            self.targets.push(target);
+        }
+        self
+    }
+
+    pub fn document_cache(mut self, value: &str) -> Self {
+        self.document_cache = value.to_string();
+        self
+    }
+
+    pub fn asset_cache(mut self, value: &str) -> Self {
+        self.asset_cache = value.to_string();
+        self
+    }
+
+    /// 출력 경로가 `prefix`로 시작하는 파일의 Cache-Control. 예: `("assets/", "public, max-age=31536000, immutable")`
+    pub fn cache(mut self, prefix: &str, value: &str) -> Self {
+        let prefix = prefix.trim_start_matches('/').to_string();
+        self.cache_rules.retain(|(existing, _)| *existing != prefix);
+        self.cache_rules.push((prefix, value.to_string()));
+        self
+    }
+
+    /// 출력 파일 하나의 Cache-Control.
+    pub fn cache_control(&self, path: &Path) -> &str {
+        let key = key(path);
+        self.cache_rules
+            .iter()
+            .filter(|(prefix, _)| key.starts_with(prefix.as_str()))
+            .max_by_key(|(prefix, _)| prefix.len())
+            .map(|(_, value)| value.as_str())
+            .unwrap_or(if is_document(path) {
+                &self.document_cache
+            } else {
+                &self.asset_cache
+            })
+    }
+
+    /// 기록한 파일 목록(출력 디렉토리 기준)으로 배포 메타데이터 생성.
+    pub fn files(&self, config: &SiteConfig, written: &[PathBuf]) -> GeneratedFiles {
+        let written: Vec<&PathBuf> = written.iter().filter(|p| is_listed(p)).collect();
+        let mut files = GeneratedFiles::new();
+        for target in &self.targets {
+            files = match target {
+                DeployTarget::Netlify => {
+                    files.file(NETLIFY_HEADERS_FILE, self.netlify_headers(config, &written))
+                }
+                DeployTarget::GitHubPages => {
+                    let files = files.file(".nojekyll", String::new());
+                    match custom_domain(&config.base_url) {
+                        Some(host) => files.file("CNAME", format!("{}\n", host)),
+                        None => files,
+                    }
+                }
+                DeployTarget::S3 => {
+                    let objects = self.s3_objects(&written);
+                    let json = serde_json::to_string(&S3Manifest { files: &objects }).unwrap_or_default();
+                    files.file(S3_MANIFEST_FILE, json + "\n")
+                }
+            };
+        }
+        files
+    }
+
+    fn netlify_headers(&self, config: &SiteConfig, written: &[&PathBuf]) -> String {
+        written
+            .iter()
+            .filter(|path| encoding_of(path).is_none())
+            .map(|path| {
+                let url = match path.extension().is_some_and(|e| e == "html") {
+                    true => config.url_for(path),
+                    false => format!("/{}", key(path)),
+                };
+                format!("{}\n  Cache-Control: {}\n", url, self.cache_control(path))
+            })
+            .collect()
+    }
+
+    /// S3 매니페스트 항목 (키 순).
+    pub fn s3_objects(&self, written: &[&PathBuf]) -> Vec<S3Object> {
+        written
+            .iter()
+            .map(|path| {
+                let encoding = encoding_of(path);
+                // index.html.gz → index.html의 형식
+                let original = match encoding {
+                    Some(_) => path.with_extension(""),
+                    None => path.to_path_buf(),
+                };
+                S3Object {
+                    key: key(path),
+                    content_type: content_type(&original),
+                    cache_control: self.cache_control(&original).to_string(),
+                    content_encoding: encoding.map(|e| match e {
+                        Encoding::Gzip => "gzip",
+                        Encoding::Brotli => "br",
+                    }),
+                }
+            })
+            .collect()
+    }
+}
+
+/// `/`로 구분한 출력 경로.
+fn key(path: &Path) -> String {
+    path.components()
+        .map(|c| c.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// 미리 압축한 파일의 형식.
+fn encoding_of(path: &Path) -> Option<Encoding> {
+    match path.extension()?.to_str()? {
+        "gz" => Some(Encoding::Gzip),
+        "br" => Some(Encoding::Brotli),
+        _ => None,
+    }
+}
+
+/// 배포 메타데이터 파일과 숨김 파일은 목록에서 제외.
+fn is_listed(path: &Path) -> bool {
+    let name = key(path);
+    let metadata = [NETLIFY_HEADERS_FILE, S3_MANIFEST_FILE, "_redirects", "CNAME"].contains(&name.as_str());
+    !metadata && !path.iter().any(|part| part.to_string_lossy().starts_with('.'))
+}
+
+/// 기본 URL의 호스트. GitHub 기본 도메인(`*.github.io`)이면 `None`.
+fn custom_domain(base_url: &str) -> Option<&str> {
+    let rest = base_url.split_once("://").map(|(_, rest)| rest).unwrap_or(base_url);
+    let host = rest.split('/').next().unwrap_or("");
+    let host = host.rsplit_once('@').map(|(_, host)| host).unwrap_or(host);
+    (!host.is_empty() && !host.ends_with(".github.io")).then_some(host)
+}
@@ -0,0 +1,246 @@
+//! # error.rs - 빌드 오류
+//!
+//! ## 목적
+//! `Site::build`가 실패하는 모든 경우를 하나의 타입(`BuildError`)으로 돌려줍니다.
+//! 라이브러리 사용자는 메시지를 해석하지 않고 종류와 위치(페이지, Block)로 분기할 수 있습니다.
+//!
+//! ## 사용 예시
+//! ```rust
+//! match site.build_output() {
+//!     Ok(report) => println!("{} files", report.manifest.files.len()),
+//!     Err(err) => {
+//!         for err in err.errors() {
+//!             if let BuildError::BrokenLink { page, href, .. } = err {
+//!                 eprintln!("{}: broken link {}", page.display(), href);
+//!             }
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! ## 오류 종류
+//! - `RuleData`: 타이포그래피 규칙 데이터(`rules.rs`)를 해석할 수 없음
+//! - `Config`: `SiteConfig` 검증 실패 (`build_output`)
+//! - `InvalidAttribute`: 페이지 head 또는 Block 구성 값이 유효하지 않음 (Open Graph, HBox 너비 등)
+//! - `BrokenLink`: 출력 대상이 없는 내부 링크 (`Site::deny_broken_links`)
+//! - `BrokenAnchor`: 대상 페이지에 없는 `id`를 가리키는 fragment 링크 (`AnchorPolicy::Deny`)
+//! - `DuplicateOutput`: 같은 출력 경로가 둘 이상 (`Site::build`는 렌더링 전에, `Site::check`)
+//! - `DuplicateId`, `DuplicatePageId`: 같은 Block ID/페이지 ID가 둘 이상 (`Site::check`)
+//! - `Plugin`: 플러그인 훅(`before_analysis`, `after_write`) 실패
+//! - `Io`: 파일 읽기/쓰기 실패
+//! - `ThreadPool`: 페이지 작업 스레드 풀(`Site::jobs`)을 만들 수 없음
+//! - `Multiple`: 여러 페이지의 오류 (페이지 등록 순서)
+//!
+//! ## 설계 결정
+//! - **위치는 출력 경로로**: 페이지는 출력 경로(출력 디렉토리 기준)로, Block은 페이지 안의
+//!   위치(`BlockPath`)로 가리킵니다. 두 값 모두 빌드마다 같습니다.
+//! - **페이지 오류는 모아서**: 한 페이지가 실패해도 나머지 페이지를 끝까지 검사/기록하고
+//!   한 번에 보고합니다. 오류가 하나면 `Multiple`로 감싸지 않습니다.
+
+use std::{
+    fmt::Display,
+    io,
+    path::{Path, PathBuf},
+};
+
+use crate::block::block::BlockId;
+use crate::cite::config::SiteConfigError;
+use crate::html::rules::RuleDataError;
+use crate::page::link::PageId;
+
+/// Block의 페이지 안 위치. 최상위 Block부터의 자식 순번과, 있으면 Block ID.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlockPath {
+    /// 예: `[2, 0]` = 세 번째 최상위 Block의 첫 자식
+    pub indices: Vec<usize>,
+    pub id: Option<BlockId>,
+}
+
+impl Display for BlockPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let indices: Vec<String> = self.indices.iter().map(usize::to_string).collect();
+        write!(f, "block {}", indices.join("."))?;
+        if let Some(id) = &self.id {
+            write!(f, " (#{})", id)?;
+        }
+        Ok(())
+    }
+}
+
+/// 빌드 실패.
+#[derive(Debug)]
+pub enum BuildError {
+    /// 타이포그래피 규칙 데이터를 해석할 수 없음
+    RuleData(RuleDataError),
+    /// 설정 검증 실패
+    Config(SiteConfigError),
+    /// 페이지 head 또는 Block의 값이 유효하지 않음. `block`이 없으면 페이지 head
+    InvalidAttribute {
+        /// 출력 경로 (출력 디렉토리 기준)
+        page: PathBuf,
+        block: Option<BlockPath>,
+        source: Box<dyn std::error::Error + Send + Sync>,
+    },
+    /// 출력 대상이 없는 내부 링크
+    BrokenLink {
+        /// 출력 경로 (출력 디렉토리 기준)
+        page: PathBuf,
+        href: String,
+        /// 링크를 담은 최상위 Block
+        block: Option<BlockPath>,
+    },
+    /// 대상 페이지에 없는 `id`를 가리키는 fragment 링크 (`page#fragment`, `#fragment`)
+    BrokenAnchor {
+        /// 출력 경로 (출력 디렉토리 기준)
+        page: PathBuf,
+        href: String,
+        /// 링크를 담은 최상위 Block
+        block: Option<BlockPath>,
+    },
+    /// 두 번 이상 출력되는 경로 (출력 디렉토리 기준). 병렬 기록에서 어느 쪽이 남을지 정할 수 없으므로 빌드하지 않음
+    DuplicateOutput { path: PathBuf },
+    /// 두 번 이상 선언된 Block ID. 링크는 `first`로 연결됨
+    DuplicateId {
+        id: BlockId,
+        /// 먼저 선언된 위치 (`/page.html#anchor`)
+        first: String,
+        duplicate: String,
+    },
+    /// 두 번 이상 선언된 `PageId`. `Link`는 `first`로 연결됨
+    DuplicatePageId {
+        id: PageId,
+        /// 먼저 선언한 페이지의 출력 경로
+        first: PathBuf,
+        duplicate: PathBuf,
+    },
+    /// 플러그인 훅 실패
+    Plugin {
+        /// `Plugin::name`
+        plugin: String,
+        source: Box<dyn std::error::Error + Send + Sync>,
+    },
+    /// 파일 입출력 실패
+    Io {
+        /// 대상 파일 (출력 디렉토리 기준) 또는 디렉토리
+        path: PathBuf,
+        source: io::Error,
+    },
+    /// 페이지 작업 스레드 풀을 만들 수 없음
+    ThreadPool(rayon::ThreadPoolBuildError),
+    /// 여러 오류 (페이지 등록 순서)
+    Multiple(Vec<BuildError>),
+}
+
+impl BuildError {
+    pub(crate) fn io(path: &Path, source: io::Error) -> Self {
+        BuildError::Io {
+            path: path.to_path_buf(),
+            source,
+        }
+    }
+
+    /// 오류 목록을 하나로. 비어 있으면 `None`, 하나면 그대로.
+    pub fn from_errors(mut errors: Vec<BuildError>) -> Option<Self> {
+        match errors.len() {
+            0 => None,
+            1 => errors.pop(),
+            _ => Some(BuildError::Multiple(errors)),
+        }
+    }
+
+    /// 개별 오류들 (`Multiple`을 펼침).
+    pub fn errors(&self) -> Vec<&BuildError> {
+        match self {
+            BuildError::Multiple(errors) => errors.iter().flat_map(BuildError::errors).collect(),
+            err => vec![err],
+        }
+    }
+
+    /// 오류가 난 페이지의 출력 경로.
+    pub fn page(&self) -> Option<&Path> {
+        match self {
+            BuildError::InvalidAttribute { page, .. }
+            | BuildError::BrokenLink { page, .. }
+            | BuildError::BrokenAnchor { page, .. } => Some(page),
+            BuildError::DuplicateOutput { path } => Some(path),
+            _ => None,
+        }
+    }
+}
+
+impl Display for BuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let location = |f: &mut std::fmt::Formatter<'_>, page: &Path, block: &Option<BlockPath>| {
+            write!(f, "{}", page.display())?;
+            match block {
+                Some(block) => write!(f, ", {}", block),
+                None => Ok(()),
+            }
+        };
+        match self {
+            BuildError::RuleData(err) => write!(f, "{}", err),
+            BuildError::Config(err) => write!(f, "invalid site config: {}", err),
+            BuildError::InvalidAttribute { page, block, source } => {
+                location(f, page, block)?;
+                write!(f, ": {}", source)
+            }
+            BuildError::BrokenLink { page, href, block } => {
+                location(f, page, block)?;
+                write!(f, ": broken link: {}", href)
+            }
+            BuildError::BrokenAnchor { page, href, block } => {
+                location(f, page, block)?;
+                write!(f, ": broken anchor: {}", href)
+            }
+            BuildError::DuplicateOutput { path } => {
+                write!(f, "{}: written by more than one page", path.display())
+            }
+            BuildError::DuplicateId { id, first, duplicate } => {
+                write!(f, "duplicate block id #{}: {} and {}", id, first, duplicate)
+            }
+            BuildError::DuplicatePageId { id, first, duplicate } => write!(
+                f,
+                "duplicate page id {}: {} and {}",
+                id.0,
+                first.display(),
+                duplicate.display()
+            ),
+            BuildError::Plugin { plugin, source } => write!(f, "plugin {}: {}", plugin, source),
+            BuildError::Io { path, source } => write!(f, "{}: {}", path.display(), source),
+            BuildError::ThreadPool(err) => write!(f, "cannot start build threads: {}", err),
+            BuildError::Multiple(errors) => {
+                write!(f, "{} errors:", errors.len())?;
+                for err in errors {
+                    write!(f, "\n{}", err)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for BuildError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            BuildError::RuleData(err) => Some(err),
+            BuildError::Config(err) => Some(err),
+            BuildError::InvalidAttribute { source, .. } | BuildError::Plugin { source, .. } => {
+                Some(source.as_ref())
+            }
+            BuildError::Io { source, .. } => Some(source),
+            BuildError::ThreadPool(err) => Some(err),
+            BuildError::BrokenLink { .. }
+            | BuildError::BrokenAnchor { .. }
+            | BuildError::DuplicateOutput { .. }
+            | BuildError::DuplicateId { .. }
+            | BuildError::DuplicatePageId { .. }
+            | BuildError::Multiple(_) => None,
+        }
+    }
+}
+
+impl From<SiteConfigError> for BuildError {
+    fn from(err: SiteConfigError) -> Self {
+        BuildError::Config(err)
+    }
+}
@@ -0,0 +1,556 @@
+//! # feed.rs - 구독 피드
+//!
+//! ## 목적
+//! 발행일이 있는 페이지 모음으로 구독 피드를 만듭니다. 세 형식이 같은 피드 모델(`FeedConfig`, 항목)을 씁니다.
+//! - RSS 2.0 (`feed.xml`)
+//! - Atom 1.0 (`atom.xml`)
+//! - JSON Feed 1.1 (`feed.json`)
+//!
+//! ## 사용 예시
+//! ```rust
+//! // SiteConfig에 모음별로 지정하면 Site가 자동으로 FeedGenerator를 실행
+//! let config = SiteConfig::new("My Blog", "https://example.com")
+//!     .language("ko")
+//!     .feed(
+//!         FeedConfig::new()
+//!             .tag("post")                                   // "post" 태그가 붙은 페이지만
+//!             .formats(&[FeedFormat::Rss, FeedFormat::Atom, FeedFormat::JsonFeed])
+//!             .full_content(true),                           // 본문 HTML 포함
+//!     )
+//!     .feed(FeedConfig::new().category("tutorial").dir("tutorial"));
+//!
+//! let site = Site::new().config(config);
+//! site.build_output()?;  // dist/feed.xml, dist/atom.xml, dist/feed.json, dist/tutorial/feed.xml
+//! ```
+//!
+//! ## 렌더링 결과 (RSS)
+//! ```xml
+//! <rss version="2.0" xmlns:atom="http://www.w3.org/2005/Atom" xmlns:content="http://purl.org/rss/1.0/modules/content/">
+//!   <channel>
+//!     <title>My Blog</title>
+//!     <link>https://example.com/</link>
+//!     ...
+//!     <item>
+//!       <title>First Post</title>
+//!       <link>https://example.com/blog/first-post.html</link>
+//!       <guid isPermaLink="true">https://example.com/blog/first-post.html</guid>
+//!       <pubDate>Fri, 01 Mar 2024 00:00:00 +0000</pubDate>
+//!       <description><![CDATA[본문 첫 부분...]]></description>
+//!     </item>
+//!   </channel>
+//! </rss>
+//! ```
+//!
+//! ## 설계 결정
+//! - **요약은 일반 텍스트**: `Description` 메타데이터가 있으면 그대로, 없으면 본문을
+//!   `TextRenderer`로 렌더링한 앞부분을 씁니다. 본문 전체는 `HtmlRenderer` 결과를 씁니다.
+//! - **CDATA 이스케이프**: 내용에 `]]>`가 있으면 CDATA 구간을 나누어 이어 붙입니다.
+//! - **발행일 필수**: 최신순 정렬과 날짜 필드를 위해 발행일이 없는 페이지는 제외합니다.
+//!   미리보기 빌드의 초안(`PreviewStatus`)도 구독자에게 보내지 않도록 제외합니다.
+//!   `Unlisted`로 피드에서 뺀 페이지는 모든 피드(모음별 피드 포함)에서 빠집니다.
+//! - **피드 갱신일은 항목 날짜로**: 빌드 시각을 쓰면 내용이 같아도 파일이 바뀌므로
+//!   결정적 빌드를 위해 항목 갱신일 중 가장 늦은 날짜를 씁니다. 항목이 없으면 `Site::clock`의 날짜를 씁니다.
+//! - **항목 갱신일은 마지막 수정일**: `LastModified`(원본 파일 수정일, `lastmod.rs`)가 발행일보다 늦으면
+//!   Atom `updated`, JSON Feed `date_modified`에 씁니다. RSS 항목은 갱신일 필드가 없습니다.
+//! - **날짜는 언어와 무관**: 피드 날짜는 기계가 읽으므로 `SiteConfig::language`와 관계없이 RFC 형식
+//!   (`format::rfc822`, `format::rfc3339`)을 씁니다. 언어는 피드의 `language` 필드로만 알립니다.
+//! - **Atom 저자는 사이트 이름**: Atom은 저자가 필수이므로 피드 단위로 사이트 이름을 넣습니다.
+
+use std::path::{Path, PathBuf};
+
+use serde_json::{Value, json};
+
+use crate::block::block::render_block;
+use crate::cite::cite::Site;
+use crate::cite::clock::{Clock, SystemClock};
+use crate::cite::config::SiteConfig;
+use crate::cite::visitor::{SiteContext, Visitor, VisitorResult};
+use crate::cite::writer::GeneratedFiles;
+use crate::html::renderer::{HtmlRenderer, Renderer, TextRenderer};
+use crate::html::trust::escape_html_chars;
+use crate::metadata::builder::Metadata;
+use crate::metadata::format::{rfc822, rfc3339};
+use crate::metadata::types::{
+    Categories, Date, Description, LastModified, Listing, PublishDate, Tags, Title, Unlisted,
+};
+use crate::page::page::{Page, page_context};
+use crate::page::preview::PreviewStatus;
+
+/// 요약 최대 길이 (문자 수).
+const SUMMARY_LENGTH: usize = 300;
+
+/// 피드에 넣을 페이지 모음.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum FeedSource {
+    /// 발행일이 있는 모든 페이지
+    #[default]
+    All,
+    /// 태그가 붙은 페이지
+    Tag(String),
+    /// 카테고리에 속한 페이지
+    Category(String),
+}
+
+impl FeedSource {
+    fn matches(&self, tags: &[String], categories: &[String]) -> bool {
+        match self {
+            FeedSource::All => true,
+            FeedSource::Tag(tag) => tags.contains(tag),
+            FeedSource::Category(category) => categories.contains(category),
+        }
+    }
+}
+
+/// 피드 형식.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum FeedFormat {
+    /// RSS 2.0
+    Rss,
+    /// Atom 1.0
+    Atom,
+    /// JSON Feed 1.1
+    JsonFeed,
+}
+
+impl FeedFormat {
+    /// 피드 디렉토리 안의 파일 이름.
+    pub fn file_name(&self) -> &'static str {
+        match self {
+            FeedFormat::Rss => "feed.xml",
+            FeedFormat::Atom => "atom.xml",
+            FeedFormat::JsonFeed => "feed.json",
+        }
+    }
+
+    /// `<link rel="alternate">`에 쓰는 MIME 타입.
+    pub fn mime_type(&self) -> &'static str {
+        match self {
+            FeedFormat::Rss => "application/rss+xml",
+            FeedFormat::Atom => "application/atom+xml",
+            FeedFormat::JsonFeed => "application/feed+json",
+        }
+    }
+}
+
+/// 피드 하나의 설정. 같은 모음을 여러 형식으로 출력할 수 있습니다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FeedConfig {
+    /// 출력 디렉토리 기준 피드 디렉토리. 기본: 루트
+    pub dir: PathBuf,
+    /// 피드 제목. 기본: 사이트 이름
+    pub title: Option<String>,
+    /// 피드 설명. 기본: 사이트 이름
+    pub description: Option<String>,
+    pub source: FeedSource,
+    /// 최대 항목 수 (최신순). 기본: 20
+    pub limit: usize,
+    /// 본문 HTML 포함. 기본: false
+    pub full_content: bool,
+    /// 출력 형식. 기본: RSS
+    pub formats: Vec<FeedFormat>,
+}
+
+impl Default for FeedConfig {
+    fn default() -> Self {
+        FeedConfig {
+            dir: PathBuf::new(),
+            title: None,
+            description: None,
+            source: FeedSource::All,
+            limit: 20,
+            full_content: false,
+            formats: vec![FeedFormat::Rss],
+        }
+    }
+}
+
+impl FeedConfig {
+    pub fn new() -> Self {
+        FeedConfig::default()
+    }
+
+    pub fn dir<P: AsRef<Path>>(mut self, dir: P) -> Self {
+        self.dir = dir.as_ref().to_path_buf();
+        self
+    }
+
+    pub fn title(mut self, title: &str) -> Self {
+        self.title = Some(title.to_string());
+        self
+    }
+
+    pub fn description(mut self, description: &str) -> Self {
+        self.description = Some(description.to_string());
+        self
+    }
+
+    pub fn source(mut self, source: FeedSource) -> Self {
+        self.source = source;
+        self
+    }
+
+    /// 태그가 붙은 페이지만 포함.
+    pub fn tag(self, tag: &str) -> Self {
+        self.source(FeedSource::Tag(tag.to_string()))
+    }
+
+    /// 카테고리에 속한 페이지만 포함.
+    pub fn category(self, category: &str) -> Self {
+        self.source(FeedSource::Category(category.to_string()))
+    }
+
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    pub fn full_content(mut self, full: bool) -> Self {
+        self.full_content = full;
+        self
+    }
+
+    pub fn formats(mut self, formats: &[FeedFormat]) -> Self {
+        self.formats = formats.to_vec();
+        self
+    }
+
+    /// 형식별 출력 경로 (출력 디렉토리 기준).
+    pub fn path(&self, format: FeedFormat) -> PathBuf {
+        self.dir.join(format.file_name())
+    }
+}
+
+/// 피드 항목 하나.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct FeedItem {
+    /// 절대 URL
+    pub url: String,
+    pub title: Option<String>,
+    pub date: Date,
+    /// 마지막 수정일 (발행일보다 이르지 않음)
+    pub updated: Date,
+    /// 일반 텍스트 요약
+    pub summary: String,
+    /// 본문 HTML (`full_content`일 때만)
+    pub content: Option<String>,
+    pub categories: Vec<String>,
+}
+
+/// 피드 파일들을 만드는 방문자.
+/// 피드를 직접 주지 않으면 `SiteConfig::feeds`를 사용하며, 이 경우 Site가 자동으로 실행합니다.
+#[derive(Debug, Clone, Default)]
+pub struct FeedGenerator {
+    feeds: Option<Vec<FeedConfig>>,
+    config: SiteConfig,
+    /// 빌드 기준 날짜 (`Site::today`)
+    today: Option<Date>,
+    items: Vec<Vec<FeedItem>>,
+}
+
+impl FeedGenerator {
+    /// `SiteConfig::feeds`의 피드들.
+    pub fn new() -> Self {
+        FeedGenerator::default()
+    }
+
+    /// 지정한 피드들 (`SiteConfig::feeds` 무시).
+    pub fn with_feeds(feeds: Vec<FeedConfig>) -> Self {
+        FeedGenerator {
+            feeds: Some(feeds),
+            ..FeedGenerator::default()
+        }
+    }
+
+    fn feeds(&self) -> &[FeedConfig] {
+        self.feeds.as_deref().unwrap_or(&self.config.feeds)
+    }
+
+    fn render(&self, feed: &FeedConfig, format: FeedFormat, items: &[FeedItem]) -> String {
+        let channel = Channel {
+            title: feed.title.as_ref().unwrap_or(&self.config.name),
+            description: feed.description.as_ref().unwrap_or(&self.config.name),
+            home_url: self.config.absolute_url("/"),
+            feed_url: self.config.absolute_url(&url_path(&feed.path(format))),
+            config: &self.config,
+            today: self.today.unwrap_or_else(|| SystemClock.today()),
+        };
+        match format {
+            FeedFormat::Rss => rss(&channel, items),
+            FeedFormat::Atom => atom(&channel, items),
+            FeedFormat::JsonFeed => json_feed(&channel, items),
+        }
+    }
+}
+
+impl Visitor for FeedGenerator {
+    fn visit_site(&mut self, site: &Site) {
+        self.config = site.site_config().clone();
+        self.today = Some(site.today());
+        self.items = vec![Vec::new(); self.feeds().len()];
+    }
+
+    fn visit_page(&mut self, page: &dyn Page, ctx: &SiteContext) {
+        let items: Vec<Option<FeedItem>> = self
+            .feeds()
+            .iter()
+            .map(|feed| feed_item(page, ctx, &feed.source, &self.config, feed.full_content))
+            .collect();
+        for (feed_items, item) in self.items.iter_mut().zip(items) {
+            feed_items.extend(item);
+        }
+    }
+
+    fn finalize(&self) -> VisitorResult {
+        let mut files = GeneratedFiles::new();
+        for (feed, items) in self.feeds().iter().zip(&self.items) {
+            let items = latest(items, feed.limit);
+            for &format in &feed.formats {
+                files = files.file(feed.path(format), self.render(feed, format, &items));
+            }
+        }
+        VisitorResult::new().mergeable(files)
+    }
+}
+
+// ============================================================================
+// 형식별 출력
+// ============================================================================
+
+/// 형식과 무관한 피드 정보.
+struct Channel<'a> {
+    title: &'a str,
+    description: &'a str,
+    home_url: String,
+    feed_url: String,
+    config: &'a SiteConfig,
+    /// 항목이 없을 때의 갱신일
+    today: Date,
+}
+
+fn rss(channel: &Channel, items: &[FeedItem]) -> String {
+    let mut xml = String::from(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <rss version=\"2.0\" xmlns:atom=\"http://www.w3.org/2005/Atom\" \
+         xmlns:content=\"http://purl.org/rss/1.0/modules/content/\">\n  <channel>\n",
+    );
+    xml.push_str(&element(4, "title", channel.title));
+    xml.push_str(&element(4, "link", &channel.home_url));
+    xml.push_str(&element(4, "description", channel.description));
+    if let Some(language) = &channel.config.language {
+        xml.push_str(&element(4, "language", language));
+    }
+    if let Some(updated) = items.iter().map(|i| i.updated).max() {
+        xml.push_str(&element(4, "lastBuildDate", &rfc822(updated)));
+    }
+    xml.push_str(&format!(
+        "    <atom:link href=\"{}\" rel=\"self\" type=\"{}\"/>\n",
+        escape_html_chars(&channel.feed_url),
+        FeedFormat::Rss.mime_type()
+    ));
+
+    for item in items {
+        xml.push_str("    <item>\n");
+        if let Some(title) = &item.title {
+            xml.push_str(&element(6, "title", title));
+        }
+        xml.push_str(&element(6, "link", &item.url));
+        xml.push_str(&format!(
+            "      <guid isPermaLink=\"true\">{}</guid>\n",
+            escape_html_chars(&item.url)
+        ));
+        xml.push_str(&element(6, "pubDate", &rfc822(item.date)));
+        for category in &item.categories {
+            xml.push_str(&element(6, "category", category));
+        }
+        xml.push_str(&format!("      <description>{}</description>\n", cdata(&item.summary)));
+        if let Some(content) = &item.content {
+            xml.push_str(&format!("      <content:encoded>{}</content:encoded>\n", cdata(content)));
+        }
+        xml.push_str("    </item>\n");
+    }
+    xml.push_str("  </channel>\n</rss>\n");
+    xml
+}
+
+fn atom(channel: &Channel, items: &[FeedItem]) -> String {
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    match &channel.config.language {
+        Some(language) => xml.push_str(&format!(
+            "<feed xmlns=\"http://www.w3.org/2005/Atom\" xml:lang=\"{}\">\n",
+            escape_html_chars(language)
+        )),
+        None => xml.push_str("<feed xmlns=\"http://www.w3.org/2005/Atom\">\n"),
+    }
+    xml.push_str(&element(2, "title", channel.title));
+    xml.push_str(&element(2, "subtitle", channel.description));
+    xml.push_str(&format!(
+        "  <link href=\"{}\" rel=\"self\" type=\"{}\"/>\n",
+        escape_html_chars(&channel.feed_url),
+        FeedFormat::Atom.mime_type()
+    ));
+    xml.push_str(&format!("  <link href=\"{}\"/>\n", escape_html_chars(&channel.home_url)));
+    xml.push_str(&element(2, "id", &channel.feed_url));
+    let updated = items.iter().map(|i| i.updated).max().unwrap_or(channel.today);
+    xml.push_str(&element(2, "updated", &rfc3339(updated)));
+    xml.push_str(&format!("  <author>\n{}  </author>\n", element(4, "name", &channel.config.name)));
+
+    for item in items {
+        xml.push_str("  <entry>\n");
+        xml.push_str(&element(4, "title", item.title.as_deref().unwrap_or(&item.url)));
+        xml.push_str(&format!("    <link href=\"{}\"/>\n", escape_html_chars(&item.url)));
+        xml.push_str(&element(4, "id", &item.url));
+        xml.push_str(&element(4, "updated", &rfc3339(item.updated)));
+        xml.push_str(&element(4, "published", &rfc3339(item.date)));
+        for category in &item.categories {
+            xml.push_str(&format!("    <category term=\"{}\"/>\n", escape_html_chars(category)));
+        }
+        xml.push_str(&format!(
+            "    <summary type=\"text\">{}</summary>\n",
+            escape_html_chars(&item.summary)
+        ));
+        if let Some(content) = &item.content {
+            xml.push_str(&format!("    <content type=\"html\">{}</content>\n", cdata(content)));
+        }
+        xml.push_str("  </entry>\n");
+    }
+    xml.push_str("</feed>\n");
+    xml
+}
+
+fn json_feed(channel: &Channel, items: &[FeedItem]) -> String {
+    let items: Vec<Value> = items
+        .iter()
+        .map(|item| {
+            let mut entry = json!({
+                "id": item.url,
+                "url": item.url,
+                "summary": item.summary,
+                "date_published": rfc3339(item.date),
+            });
+            if let Some(title) = &item.title {
+                entry["title"] = json!(title);
+            }
+            if item.updated > item.date {
+                entry["date_modified"] = json!(rfc3339(item.updated));
+            }
+            match &item.content {
+                Some(html) => entry["content_html"] = json!(html),
+                None => entry["content_text"] = json!(item.summary),
+            }
+            if !item.categories.is_empty() {
+                entry["tags"] = json!(item.categories);
+            }
+            entry
+        })
+        .collect();
+
+    let mut feed = json!({
+        "version": "https://jsonfeed.org/version/1.1",
+        "title": channel.title,
+        "description": channel.description,
+        "home_page_url": channel.home_url,
+        "feed_url": channel.feed_url,
+        "authors": [{ "name": channel.config.name }],
+        "items": items,
+    });
+    if let Some(language) = &channel.config.language {
+        feed["language"] = json!(language);
+    }
+    let mut out = serde_json::to_string_pretty(&feed).unwrap_or_default();
+    out.push('\n');
+    out
+}
+
+// ============================================================================
+// 헬퍼
+// ============================================================================
+
+/// 모음에 속하고 발행일이 있는 페이지의 피드 항목.
+pub(crate) fn feed_item(
+    page: &dyn Page,
+    ctx: &SiteContext,
+    source: &FeedSource,
+    config: &SiteConfig,
+    full_content: bool,
+) -> Option<FeedItem> {
+    let metadata = &ctx.metadata;
+    if metadata.contains::<PreviewStatus>()
+        || metadata.get::<Unlisted>().is_some_and(|u| u.excludes(Listing::Feeds))
+    {
+        return None;
+    }
+    let date = metadata.get::<PublishDate>()?.0;
+    let tags = metadata.get::<Tags>().map(|t| t.0.clone()).unwrap_or_default();
+    let categories = metadata.get::<Categories>().map(|c| c.0.clone()).unwrap_or_default();
+    if !source.matches(&tags, &categories) {
+        return None;
+    }
+
+    let base = metadata.merge(Metadata::new().custom(ctx.index.clone()).map());
+    let render_ctx = page_context(page, &base);
+    let (text, html) = page.blocks().into_iter().fold(
+        (String::new(), String::new()),
+        |(mut text, mut html), block| {
+            let ir = render_block(block, &render_ctx);
+            text.push_str(ir.accept(TextRenderer::new()).finalize());
+            text.push('\n');
+            html.push_str(ir.accept(HtmlRenderer::new()).finalize().as_str());
+            (text, html)
+        },
+    );
+    let summary = match metadata.get::<Description>() {
+        Some(description) => description.0.clone(),
+        None => excerpt(&text, SUMMARY_LENGTH),
+    };
+
+    Some(FeedItem {
+        url: config.absolute_url(&ctx.url),
+        title: metadata.get::<Title>().map(|t| t.0.clone()),
+        date,
+        updated: metadata.get::<LastModified>().map_or(date, |d| d.0.max(date)),
+        summary,
+        content: full_content.then_some(html),
+        categories: tags.into_iter().chain(categories).collect(),
+    })
+}
+
+/// 발행일 최신순 (같으면 URL 순) 앞 `limit`개.
+pub(crate) fn latest(items: &[FeedItem], limit: usize) -> Vec<FeedItem> {
+    let mut items = items.to_vec();
+    items.sort_by(|a, b| b.date.cmp(&a.date).then_with(|| a.url.cmp(&b.url)));
+    items.truncate(limit);
+    items
+}
+
+/// 공백을 하나로 줄이고 `max`자를 넘으면 단어 경계에서 잘라 `…`를 붙임.
+pub(crate) fn excerpt(text: &str, max: usize) -> String {
+    let collapsed = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    if collapsed.chars().count() <= max {
+        return collapsed;
+    }
+    let cut: String = collapsed.chars().take(max).collect();
+    let cut = match cut.rfind(' ') {
+        Some(i) if i > 0 => &cut[..i],
+        _ => cut.as_str(),
+    };
+    format!("{}…", cut)
+}
+
+/// CDATA 구간. `]]>`는 구간을 나누어 표현합니다.
+fn cdata(text: &str) -> String {
+    format!("<![CDATA[{}]]>", text.replace("]]>", "]]]]><![CDATA[>"))
+}
+
+/// 이스케이프한 텍스트 요소 한 줄.
+fn element(indent: usize, tag: &str, text: &str) -> String {
+    format!("{:indent$}<{tag}>{}</{tag}>\n", "", escape_html_chars(text))
+}
+
+/// 출력 경로를 `/`로 구분한 URL 경로로.
+fn url_path(path: &Path) -> String {
+    path.components()
+        .map(|c| c.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
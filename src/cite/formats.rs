@@ -0,0 +1,150 @@
+//! # formats.rs - OutputFormatGenerator
+//!
+//! ## 목적
+//! 추가 출력 형식(`OutputFormats`)을 선언한 페이지마다 본문을 다른 렌더러로 다시 렌더링하여
+//! HTML 옆에 `page.json`, `page.txt`, `page.md`를 씁니다.
+//!
+//! ## 렌더링 결과 (`blog/post.json`)
+//! ```json
+//! {
+//!   "url": "/blog/post.html",
+//!   "title": "첫 글",
+//!   "date": "2024-03-01",
+//!   "tags": ["rust"],
+//!   "content": [{ "tag": "p", "attrs": {}, "children": ["본문 ", { "tag": "strong", ... }] }]
+//! }
+//! ```
+//!
+//! ## 설계 결정
+//! - **기본 방문자**: 선언한 페이지가 없으면 아무 파일도 쓰지 않으므로 항상 실행합니다.
+//!   다른 기본 방문자 뒤에 실행되어 링크, 번호, 용어집 결과가 반영된 본문을 렌더링합니다.
+//! - **대상 표시**: 렌더링할 때 `RenderTarget::Alternate`(확장자)를 주입하므로 Block이
+//!   `ctx.target()`으로 형식별 출력을 조정할 수 있습니다.
+//! - **Markdown 제목**: 본문이 `# 제목`으로 시작하지 않으면 `Title`을 첫 줄 제목으로 붙입니다.
+
+use serde::Serialize;
+
+use crate::block::block::render_block;
+use crate::cite::visitor::{SiteContext, Visitor, VisitorResult};
+use crate::cite::writer::GeneratedFiles;
+use crate::html::renderer::{JsonRenderer, MarkdownRenderer, Renderer, TextRenderer};
+use crate::metadata::builder::Metadata;
+use crate::metadata::types::{
+    Categories, Description, LastModified, PublishDate, Redirect, RenderTarget, Tags, Title,
+};
+use crate::page::formats::{OutputFormat, OutputFormats, format_path};
+use crate::page::page::{Page, page_context};
+use crate::page::preview::PreviewStatus;
+use crate::page::slug::is_error_page_path;
+
+/// `page.json` 문서.
+#[derive(Debug, Clone, Serialize)]
+struct PageDocument {
+    url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    updated: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    tags: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    categories: Vec<String>,
+    content: Vec<serde_json::Value>,
+}
+
+/// 추가 출력 형식 파일을 쓰는 방문자.
+#[derive(Debug, Clone, Default)]
+pub struct OutputFormatGenerator {
+    files: GeneratedFiles,
+}
+
+impl OutputFormatGenerator {
+    pub fn new() -> Self {
+        OutputFormatGenerator::default()
+    }
+
+    fn render(page: &dyn Page, ctx: &SiteContext, format: OutputFormat) -> String {
+        let metadata = &ctx.metadata;
+        let base = metadata.merge(
+            Metadata::new()
+                .custom(ctx.index.clone())
+                .custom(RenderTarget::Alternate(format.extension().to_string()))
+                .map(),
+        );
+        let render_ctx = page_context(page, &base);
+        let blocks: Vec<_> = page.blocks().into_iter().map(|block| render_block(block, &render_ctx)).collect();
+
+        match format {
+            OutputFormat::Json => {
+                let content = blocks
+                    .iter()
+                    .flat_map(|ir| match ir.accept(JsonRenderer::new()).finalize() {
+                        serde_json::Value::Array(nodes) => nodes.clone(),
+                        other => vec![other.clone()],
+                    })
+                    .collect();
+                let document = PageDocument {
+                    url: ctx.url.clone(),
+                    title: metadata.get::<Title>().map(|t| t.0.clone()),
+                    description: metadata.get::<Description>().map(|d| d.0.clone()),
+                    date: metadata.get::<PublishDate>().map(|d| d.0.to_string()),
+                    updated: metadata.get::<LastModified>().map(|d| d.0.to_string()),
+                    tags: metadata.get::<Tags>().map(|t| t.0.clone()).unwrap_or_default(),
+                    categories: metadata.get::<Categories>().map(|c| c.0.clone()).unwrap_or_default(),
+                    content,
+                };
+                let mut json = serde_json::to_string_pretty(&document).unwrap_or_default();
+                json.push('\n');
+                json
+            }
+            OutputFormat::Text => Self::join(
+                blocks.iter().map(|ir| ir.accept(TextRenderer::new()).finalize().trim().to_string()),
+                "\n",
+            ),
+            OutputFormat::Markdown => {
+                let body = Self::join(
+                    blocks.iter().map(|ir| ir.accept(MarkdownRenderer::new()).finalize().trim().to_string()),
+                    "\n\n",
+                );
+                match metadata.get::<Title>() {
+                    Some(title) if !body.starts_with("# ") => format!("# {}\n\n{}", title.0, body),
+                    _ => body,
+                }
+            }
+        }
+    }
+
+    /// 빈 조각을 뺀 뒤 구분자로 연결하고 마지막 줄바꿈 추가.
+    fn join(parts: impl Iterator<Item = String>, separator: &str) -> String {
+        let mut joined = parts.filter(|part| !part.is_empty()).collect::<Vec<_>>().join(separator);
+        joined.push('\n');
+        joined
+    }
+}
+
+impl Visitor for OutputFormatGenerator {
+    fn visit_page(&mut self, page: &dyn Page, ctx: &SiteContext) {
+        let metadata = &ctx.metadata;
+        let Some(formats) = metadata.get::<OutputFormats>() else {
+            return;
+        };
+        if is_error_page_path(&page.path())
+            || metadata.contains::<Redirect>()
+            || metadata.contains::<PreviewStatus>()
+        {
+            return;
+        }
+        for format in formats.iter() {
+            let content = Self::render(page, ctx, *format);
+            self.files = std::mem::take(&mut self.files).file(format_path(&ctx.output_path, *format), content);
+        }
+    }
+
+    fn finalize(&self) -> VisitorResult {
+        VisitorResult::new().mergeable(self.files.clone())
+    }
+}
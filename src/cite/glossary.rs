@@ -0,0 +1,53 @@
+//! # glossary.rs - GlossaryLinker
+//!
+//! ## 목적
+//! 용어집(`Glossary`)의 용어가 페이지마다 어떤 순서로 처음 나오는지 찾고, `GlossaryBlock`이 있는
+//! 용어집 페이지를 기록합니다(`GlossaryLinks`). 링크는 문서 조립 단계가 이 결과를 읽어 넣습니다.
+//!
+//! ## 설계 결정
+//! - **기본 방문자**: `Glossary::auto_link`가 꺼져 있으면 용어집 페이지만 찾으므로 항상 실행합니다.
+//! - **최상위 Block만 렌더링**: `IdGenerator`처럼 깊이 0의 Block을 렌더링해 자식까지 한 번에 봅니다.
+
+use crate::block::block::Block;
+use crate::block::context::RenderContext;
+use crate::cite::analysis::normalize_url;
+use crate::cite::cite::Site;
+use crate::cite::visitor::{PageContext, Visitor, VisitorResult};
+use crate::page::glossary::{Glossary, GlossaryLinks, GlossaryListing, collect_terms};
+
+/// `GlossaryLinks`를 만드는 방문자.
+#[derive(Debug, Clone, Default)]
+pub struct GlossaryLinker {
+    glossary: Option<Glossary>,
+    links: GlossaryLinks,
+}
+
+impl GlossaryLinker {
+    pub fn new() -> Self {
+        GlossaryLinker::default()
+    }
+}
+
+impl Visitor for GlossaryLinker {
+    fn visit_site(&mut self, site: &Site) {
+        self.glossary = site.global_metadata().get::<Glossary>().filter(|g| g.is_auto_link()).cloned();
+    }
+
+    fn visit_block(&mut self, block: &dyn Block, ctx: &PageContext) {
+        if self.links.page.is_none() && block.metadata().get::<GlossaryListing>().is_some() {
+            self.links.page = Some(ctx.url.to_string());
+        }
+        let Some(glossary) = &self.glossary else {
+            return;
+        };
+        if ctx.depth == 0 {
+            let ir = block.render_to_ir(&RenderContext::new(ctx.metadata.clone()));
+            let found = self.links.terms.entry(normalize_url(ctx.url)).or_default();
+            collect_terms(&ir, glossary, found);
+        }
+    }
+
+    fn finalize(&self) -> VisitorResult {
+        VisitorResult::new().custom(self.links.clone())
+    }
+}
@@ -0,0 +1,36 @@
+//! # heading_nav.rs - HeadingNavGenerator
+//!
+//! ## 목적
+//! `SiteConfig::heading_nav`가 켜져 있으면 목차 스크롤 추적, 제목 고정 링크 스크립트와 스타일시트
+//! (`assets/heading-nav.js`, `assets/heading-nav.css`)를 기록합니다. 페이지에는 Site가 전역 `Assets`로
+//! 이미 연결합니다.
+//!
+//! ## 설계 결정
+//! - **설정이 켜졌을 때만 실행**: Site가 `SiteConfig::heading_nav`를 보고 방문자를 추가하므로
+//!   방문자는 항상 두 파일을 씁니다.
+
+use crate::cite::visitor::{Visitor, VisitorResult};
+use crate::cite::writer::GeneratedFiles;
+use crate::page::heading_nav::{
+    HEADING_NAV_CSS, HEADING_NAV_SCRIPT, HEADING_NAV_SCRIPT_PATH, HEADING_NAV_STYLESHEET_PATH,
+};
+
+/// 제목 탐색 스크립트와 스타일시트를 기록하는 방문자.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HeadingNavGenerator;
+
+impl HeadingNavGenerator {
+    pub fn new() -> Self {
+        HeadingNavGenerator
+    }
+}
+
+impl Visitor for HeadingNavGenerator {
+    fn finalize(&self) -> VisitorResult {
+        VisitorResult::new().mergeable(
+            GeneratedFiles::new()
+                .file(HEADING_NAV_SCRIPT_PATH, HEADING_NAV_SCRIPT.to_string())
+                .file(HEADING_NAV_STYLESHEET_PATH, HEADING_NAV_CSS.to_string()),
+        )
+    }
+}
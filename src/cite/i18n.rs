@@ -0,0 +1,94 @@
+//! # i18n.rs - 번역 그룹 수집
+//!
+//! ## 목적
+//! 같은 `TranslationKey`를 가진 페이지들을 언어별로 모아 `TranslationGroups`로 `SiteIndex`에 남깁니다.
+//! Site는 렌더링 시 페이지마다 자신의 그룹을 `Translations`로 주입해 hreflang 대체 링크를 출력합니다.
+//!
+//! ## 사용 예시
+//! ```rust
+//! let index = site.index();
+//! let groups = index.get::<TranslationGroups>().unwrap();
+//! groups.translations(&TranslationKey::new("about"), Some("ko"));
+//! // [en → https://example.com/en/about.html, ko → .../about.html, x-default → .../about.html]
+//! ```
+//!
+//! ## 설계 결정
+//! - **절대 URL**: 검색 엔진은 hreflang에 절대 URL을 요구하므로 `SiteConfig::absolute_url`을 씁니다.
+//! - **같은 언어가 둘이면 먼저 등록한 페이지**: 대체 링크는 언어마다 하나여야 합니다.
+//! - **번역이 하나뿐이면 생략**: 자기 자신만 가리키는 대체 링크는 의미가 없습니다.
+
+use std::collections::BTreeMap;
+
+use crate::cite::cite::Site;
+use crate::cite::config::SiteConfig;
+use crate::cite::visitor::{SiteContext, Visitor, VisitorResult};
+use crate::metadata::types::{Language, Redirect};
+use crate::metadata::value::MetadataValue;
+use crate::page::i18n::{Alternate, TranslationKey, Translations};
+use crate::page::page::Page;
+use crate::page::slug::is_error_page_path;
+
+/// 번역 그룹 → 언어별 절대 URL (언어순).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TranslationGroups(pub BTreeMap<TranslationKey, BTreeMap<String, String>>);
+impl MetadataValue for TranslationGroups {}
+
+impl TranslationGroups {
+    /// 그룹의 번역 목록. `default_language`의 번역이 있으면 `x-default`로 한 번 더 넣습니다.
+    /// 번역이 둘 미만이면 `None`.
+    pub fn translations(
+        &self,
+        key: &TranslationKey,
+        default_language: Option<&str>,
+    ) -> Option<Translations> {
+        let group = self.0.get(key).filter(|group| group.len() > 1)?;
+        let mut alternates: Vec<Alternate> = group
+            .iter()
+            .map(|(language, href)| Alternate {
+                language: language.clone(),
+                href: href.clone(),
+            })
+            .collect();
+        if let Some(href) = default_language.and_then(|l| group.get(l)) {
+            alternates.push(Alternate {
+                language: "x-default".to_string(),
+                href: href.clone(),
+            });
+        }
+        Some(Translations(alternates))
+    }
+}
+
+/// 번역 그룹을 모으는 방문자. Site의 기본 방문자입니다.
+#[derive(Debug, Clone, Default)]
+pub struct TranslationLinker {
+    config: SiteConfig,
+    groups: TranslationGroups,
+}
+
+impl Visitor for TranslationLinker {
+    fn visit_site(&mut self, site: &Site) {
+        self.config = site.site_config().clone();
+    }
+
+    fn visit_page(&mut self, page: &dyn Page, ctx: &SiteContext) {
+        let metadata = &ctx.metadata;
+        if is_error_page_path(&page.path()) || metadata.contains::<Redirect>() {
+            return;
+        }
+        let (Some(key), Some(language)) = (metadata.get::<TranslationKey>(), metadata.get::<Language>())
+        else {
+            return;
+        };
+        self.groups
+            .0
+            .entry(key.clone())
+            .or_default()
+            .entry(language.0.clone())
+            .or_insert_with(|| self.config.absolute_url(&ctx.url));
+    }
+
+    fn finalize(&self) -> VisitorResult {
+        VisitorResult::new().custom(self.groups.clone())
+    }
+}
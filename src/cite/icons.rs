@@ -0,0 +1,70 @@
+//! # icons.rs - IconSpriteGenerator
+//!
+//! ## 목적
+//! 페이지가 스프라이트로 참조하는 아이콘(`Icon`)만 모아 스프라이트 시트(`IconSet::sprite_file`)를 기록합니다.
+//! 등록되지 않은 아이콘 이름은 `MissingIcons`로 `SiteIndex`에 남고, Site가 페이지 검사 단계에서
+//! 경고(`WarningKind::MissingIcon`)로 옮깁니다.
+//!
+//! ## 설계 결정
+//! - **기본 방문자**: 아이콘을 쓰지 않는 사이트에서는 아무 파일도 만들지 않으므로 항상 실행합니다.
+//! - **아이콘 목록은 전역 메타데이터**: 렌더링하는 `Icon`과 같은 `IconSet`을 읽습니다.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::block::block::Block;
+use crate::block::icon::{IconRef, IconSet};
+use crate::cite::cite::Site;
+use crate::cite::visitor::{PageContext, Visitor, VisitorResult};
+use crate::cite::writer::GeneratedFiles;
+use crate::metadata::value::MetadataValue;
+
+/// 페이지 URL(사이트 루트 기준) → 등록되지 않은 아이콘 이름. 빠진 아이콘이 없는 페이지는 담지 않습니다.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MissingIcons(pub BTreeMap<String, BTreeSet<String>>);
+impl MetadataValue for MissingIcons {}
+
+impl MissingIcons {
+    pub fn get(&self, url: &str) -> Option<&BTreeSet<String>> {
+        self.0.get(url)
+    }
+}
+
+/// 스프라이트 시트를 만드는 방문자.
+#[derive(Debug, Clone, Default)]
+pub struct IconSpriteGenerator {
+    icons: IconSet,
+    sprite: BTreeSet<String>,
+    missing: MissingIcons,
+}
+
+impl IconSpriteGenerator {
+    pub fn new() -> Self {
+        IconSpriteGenerator::default()
+    }
+}
+
+impl Visitor for IconSpriteGenerator {
+    fn visit_site(&mut self, site: &Site) {
+        self.icons = site.global_metadata().get::<IconSet>().cloned().unwrap_or_default();
+    }
+
+    fn visit_block(&mut self, block: &dyn Block, ctx: &PageContext) {
+        let Some(icon) = block.metadata().map().get::<IconRef>().cloned() else {
+            return;
+        };
+        if !self.icons.contains(&icon.name) {
+            self.missing.0.entry(ctx.url.to_string()).or_default().insert(icon.name);
+        } else if icon.sprite {
+            self.sprite.insert(icon.name);
+        }
+    }
+
+    fn finalize(&self) -> VisitorResult {
+        let result = VisitorResult::new().custom(self.missing.clone());
+        if self.sprite.is_empty() {
+            return result;
+        }
+        let sprite = self.icons.sprite(self.sprite.iter().map(String::as_str));
+        result.mergeable(GeneratedFiles::new().file(self.icons.sprite_file(), sprite))
+    }
+}
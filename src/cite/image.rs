@@ -0,0 +1,393 @@
+//! # image.rs - ImageProcessor
+//!
+//! ## 목적
+//! `ImageBlock`이 참조하는 이미지마다 크기별 변형과 최신 형식(AVIF, WebP)을 만들어 출력 파일로 내보내고,
+//! 결과(`ImageVariants`)를 `SiteIndex`에 남깁니다. 렌더링 단계의 `ImageBlock`이 이를 읽어
+//! `srcset`/`sizes`와 `<picture>`의 `<source>`를 자동으로 채웁니다.
+//!
+//! ## 사용 예시
+//! ```rust
+//! // `image` 기능: image 크레이트 기반 기본 인코더
+//! site.register_visitor(ImageProcessor::new("static", DefaultImageEncoder::new()));
+//!
+//! // 다른 라이브러리 연결
+//! struct MyEncoder;
+//! impl ImageEncoder for MyEncoder {
+//!     fn decode(&self, source: &[u8]) -> io::Result<Box<dyn DecodedImage>> { /* ... */ }
+//! }
+//! struct MyImage { /* 해석한 픽셀 */ }
+//! impl DecodedImage for MyImage {
+//!     fn dimensions(&self) -> (u32, u32) { /* ... */ }
+//!     fn encode(&self, width: u32, format: ImageFormat) -> io::Result<Vec<u8>> { /* ... */ }
+//! }
+//!
+//! site.register_visitor(
+//!     ImageProcessor::new("static", MyEncoder)
+//!         .widths(&[480, 960, 1440])
+//!         .formats(&[ImageFormat::Avif, ImageFormat::WebP]),
+//! );
+//! // static/images/photo.jpg (1600px) →
+//! //   dist/images/photo-480w.jpg, photo-960w.jpg, photo-1440w.jpg
+//! //   dist/images/photo-480w.avif, ..., photo-1600w.avif
+//! //   dist/images/photo-480w.webp, ..., photo-1600w.webp
+//! ```
+//!
+//! ## 구현 상태
+//! - [x] 참조 이미지 수집 (`ImageSource` 메타데이터)
+//! - [x] 크기별 변형 (원본보다 큰 너비는 만들지 않음), 최신 형식 변형
+//! - [x] `srcset`/`sizes`/`<source>` 자동 연결 (`ImageBlock`)
+//! - [x] `image` 크레이트 기반 기본 인코더 (`image` 기능: `DefaultImageEncoder`, JPEG/PNG/GIF/WebP/AVIF)
+//!
+//! ## 설계 결정
+//! - **인코더는 트레이트로**: 픽셀 처리는 무거운 외부 의존성이므로 라이브러리 핵심과 분리합니다.
+//!   방문자는 어떤 변형이 필요한지와 파일 경로, URL만 결정합니다. 기본 인코더는 `image` 기능으로만 켭니다.
+//! - **원본은 한 번만 해석**: 변형(너비 × 형식)마다 원본을 다시 해석하지 않도록 `decode`가 해석한
+//!   이미지(`DecodedImage`)를 돌려주고, 변형은 모두 그 이미지에서 만듭니다.
+//! - **WebP는 무손실**: image 크레이트의 WebP 인코더는 무손실만 지원합니다. 손실 압축이 필요하면
+//!   `ImageEncoder`로 다른 인코더를 연결합니다.
+//! - **사이트 루트 기준 경로만 처리**: `/`로 시작하는 `src`만 `source_dir` 아래에서 찾습니다.
+//!   외부 URL과 페이지 기준 상대 경로는 그대로 둡니다.
+//! - **실패는 원본으로**: 원본을 읽지 못하거나 인코딩에 실패한 변형은 건너뜁니다.
+//!   변형이 하나도 없으면 `ImageBlock`은 원본 `src`만 출력합니다.
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs, io,
+    path::{Component, Path, PathBuf},
+    sync::Arc,
+};
+
+use crate::block::block::Block;
+use crate::block::image::ImageSource;
+use crate::cite::visitor::{PageContext, Visitor, VisitorResult};
+use crate::cite::writer::GeneratedFiles;
+use crate::metadata::merge::MergeableMetadata;
+use crate::metadata::value::MetadataValue;
+
+/// 기본 변형 너비 (px).
+const DEFAULT_WIDTHS: &[u32] = &[480, 960, 1440];
+
+/// 이미지 형식.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ImageFormat {
+    Jpeg,
+    Png,
+    Gif,
+    WebP,
+    Avif,
+}
+
+impl ImageFormat {
+    /// 파일 확장자로 형식 판별 (대소문자 무시).
+    pub fn from_extension(extension: &str) -> Option<Self> {
+        match extension.to_ascii_lowercase().as_str() {
+            "jpg" | "jpeg" => Some(ImageFormat::Jpeg),
+            "png" => Some(ImageFormat::Png),
+            "gif" => Some(ImageFormat::Gif),
+            "webp" => Some(ImageFormat::WebP),
+            "avif" => Some(ImageFormat::Avif),
+            _ => None,
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ImageFormat::Jpeg => "jpg",
+            ImageFormat::Png => "png",
+            ImageFormat::Gif => "gif",
+            ImageFormat::WebP => "webp",
+            ImageFormat::Avif => "avif",
+        }
+    }
+
+    pub fn mime_type(&self) -> &'static str {
+        match self {
+            ImageFormat::Jpeg => "image/jpeg",
+            ImageFormat::Png => "image/png",
+            ImageFormat::Gif => "image/gif",
+            ImageFormat::WebP => "image/webp",
+            ImageFormat::Avif => "image/avif",
+        }
+    }
+}
+
+/// 이미지 해석. 픽셀 처리 라이브러리를 연결하는 지점입니다.
+pub trait ImageEncoder: Send + Sync {
+    /// 원본 해석. 원본 하나당 한 번 호출되며, 변형은 모두 반환된 이미지에서 만듭니다.
+    fn decode(&self, source: &[u8]) -> io::Result<Box<dyn DecodedImage>>;
+}
+
+/// 해석한 원본 이미지.
+pub trait DecodedImage {
+    /// 원본의 (너비, 높이).
+    fn dimensions(&self) -> (u32, u32);
+
+    /// 비율을 유지해 너비 `width`로 줄인 뒤 `format`으로 인코딩.
+    fn encode(&self, width: u32, format: ImageFormat) -> io::Result<Vec<u8>>;
+}
+
+/// image 크레이트 기반 기본 인코더 (`image` 기능). 축소는 Lanczos3 필터입니다.
+#[cfg(feature = "image")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DefaultImageEncoder {
+    quality: u8,
+    speed: u8,
+}
+
+#[cfg(feature = "image")]
+impl Default for DefaultImageEncoder {
+    fn default() -> Self {
+        DefaultImageEncoder {
+            quality: 80,
+            speed: 6,
+        }
+    }
+}
+
+#[cfg(feature = "image")]
+impl DefaultImageEncoder {
+    pub fn new() -> Self {
+        DefaultImageEncoder::default()
+    }
+
+    /// JPEG, AVIF 품질 (1~100). 기본: 80
+    pub fn quality(mut self, quality: u8) -> Self {
+        self.quality = quality.clamp(1, 100);
+        self
+    }
+
+    /// AVIF 인코딩 속도 (1~10, 클수록 빠르고 파일이 큼). 기본: 6
+    pub fn speed(mut self, speed: u8) -> Self {
+        self.speed = speed.clamp(1, 10);
+        self
+    }
+}
+
+#[cfg(feature = "image")]
+impl ImageEncoder for DefaultImageEncoder {
+    fn decode(&self, source: &[u8]) -> io::Result<Box<dyn DecodedImage>> {
+        let image = image::load_from_memory(source).map_err(io::Error::other)?;
+        Ok(Box::new(DefaultDecodedImage {
+            image,
+            encoder: *self,
+        }))
+    }
+}
+
+/// `DefaultImageEncoder`가 해석한 이미지.
+#[cfg(feature = "image")]
+struct DefaultDecodedImage {
+    image: image::DynamicImage,
+    encoder: DefaultImageEncoder,
+}
+
+#[cfg(feature = "image")]
+impl DecodedImage for DefaultDecodedImage {
+    fn dimensions(&self) -> (u32, u32) {
+        (self.image.width(), self.image.height())
+    }
+
+    fn encode(&self, width: u32, format: ImageFormat) -> io::Result<Vec<u8>> {
+        use image::codecs::{avif::AvifEncoder, jpeg::JpegEncoder};
+        use image::imageops::FilterType;
+
+        let image = &self.image;
+        let height = (u64::from(image.height()) * u64::from(width) / u64::from(image.width().max(1))).max(1);
+        let resized = image.resize_exact(width, height as u32, FilterType::Lanczos3);
+        let DefaultImageEncoder { quality, speed } = self.encoder;
+
+        let mut bytes = Vec::new();
+        let result = match format {
+            // JPEG은 알파 채널이 없음
+            ImageFormat::Jpeg => image::DynamicImage::ImageRgb8(resized.to_rgb8())
+                .write_with_encoder(JpegEncoder::new_with_quality(&mut bytes, quality)),
+            ImageFormat::Avif => {
+                resized.write_with_encoder(AvifEncoder::new_with_speed_quality(&mut bytes, speed, quality))
+            }
+            ImageFormat::Png => resized.write_to(io::Cursor::new(&mut bytes), image::ImageFormat::Png),
+            ImageFormat::Gif => resized.write_to(io::Cursor::new(&mut bytes), image::ImageFormat::Gif),
+            ImageFormat::WebP => resized.write_to(io::Cursor::new(&mut bytes), image::ImageFormat::WebP),
+        };
+        result.map_err(io::Error::other)?;
+        Ok(bytes)
+    }
+}
+
+/// 변형 하나.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImageVariant {
+    /// 사이트 루트 기준 URL
+    pub url: String,
+    pub width: u32,
+    pub format: ImageFormat,
+}
+
+/// 처리된 원본 하나와 그 변형들.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProcessedImage {
+    /// 원본 형식
+    pub format: ImageFormat,
+    /// 원본 너비
+    pub width: u32,
+    /// 원본 높이
+    pub height: u32,
+    /// 형식별, 너비순
+    pub variants: Vec<ImageVariant>,
+}
+
+impl ProcessedImage {
+    /// 한 형식의 `srcset` 값 (`url 480w, url 960w`). 변형이 없으면 `None`.
+    pub fn srcset(&self, format: ImageFormat) -> Option<String> {
+        let entries: Vec<String> = self
+            .variants
+            .iter()
+            .filter(|v| v.format == format)
+            .map(|v| format!("{} {}w", v.url, v.width))
+            .collect();
+        (!entries.is_empty()).then(|| entries.join(", "))
+    }
+
+    /// 원본과 다른 형식들 (`<source>`로 출력할 순서).
+    pub fn modern_formats(&self) -> Vec<ImageFormat> {
+        let mut formats = Vec::new();
+        for variant in &self.variants {
+            if variant.format != self.format && !formats.contains(&variant.format) {
+                formats.push(variant.format);
+            }
+        }
+        formats
+    }
+}
+
+/// 원본 `src` → 처리 결과. `SiteIndex`에 저장됩니다.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ImageVariants(pub BTreeMap<String, ProcessedImage>);
+impl MetadataValue for ImageVariants {}
+
+impl MergeableMetadata for ImageVariants {
+    fn merge_with(&self, base: &Self) -> Self {
+        let mut images = base.0.clone();
+        images.extend(self.0.clone());
+        ImageVariants(images)
+    }
+}
+
+impl ImageVariants {
+    pub fn get(&self, src: &str) -> Option<&ProcessedImage> {
+        self.0.get(src)
+    }
+}
+
+/// 이미지 변형을 만드는 방문자.
+#[derive(Clone)]
+pub struct ImageProcessor {
+    source_dir: PathBuf,
+    encoder: Arc<dyn ImageEncoder>,
+    widths: Vec<u32>,
+    formats: Vec<ImageFormat>,
+    sources: BTreeSet<String>,
+}
+
+impl ImageProcessor {
+    /// `source_dir`: 사이트 루트 기준 `src`를 찾을 디렉토리 (예: `/images/a.jpg` → `static/images/a.jpg`)
+    pub fn new<P: AsRef<Path>, E: ImageEncoder + 'static>(source_dir: P, encoder: E) -> Self {
+        ImageProcessor {
+            source_dir: source_dir.as_ref().to_path_buf(),
+            encoder: Arc::new(encoder),
+            widths: DEFAULT_WIDTHS.to_vec(),
+            formats: vec![ImageFormat::Avif, ImageFormat::WebP],
+            sources: BTreeSet::new(),
+        }
+    }
+
+    /// 변형 너비 (px). 원본보다 큰 너비는 건너뜁니다. 기본: 480, 960, 1440
+    pub fn widths(mut self, widths: &[u32]) -> Self {
+        let widths: BTreeSet<u32> = widths.iter().copied().filter(|w| *w > 0).collect();
+        self.widths = widths.into_iter().collect();
+        self
+    }
+
+    /// 원본 형식 외에 만들 형식 (`<source>` 순서). 기본: AVIF, WebP
+    pub fn formats(mut self, formats: &[ImageFormat]) -> Self {
+        self.formats = formats.to_vec();
+        self
+    }
+
+    /// 원본 하나 처리. 읽거나 해석할 수 없으면 `None`.
+    fn process(&self, src: &str, files: &mut GeneratedFiles) -> Option<ProcessedImage> {
+        let relative = Path::new(src.trim_start_matches('/'));
+        if !relative.components().all(|c| matches!(c, Component::Normal(_))) {
+            return None;
+        }
+        let format = ImageFormat::from_extension(relative.extension()?.to_str()?)?;
+        let source = fs::read(self.source_dir.join(relative)).ok()?;
+        let image = self.encoder.decode(&source).ok()?;
+        let (width, height) = image.dimensions();
+
+        let stem = relative.file_stem()?.to_str()?;
+        let parent = relative.parent().unwrap_or(Path::new(""));
+        let mut widths: Vec<u32> = self.widths.iter().copied().filter(|w| *w < width).collect();
+        widths.push(width);
+
+        let mut variants = Vec::new();
+        let mut formats = vec![format];
+        formats.extend(self.formats.iter().filter(|f| **f != format));
+        for target in formats {
+            for w in &widths {
+                // 원본 형식의 원본 너비는 원본 파일 그대로
+                if target == format && *w == width {
+                    variants.push(ImageVariant { url: src.to_string(), width, format });
+                    continue;
+                }
+                let Ok(encoded) = image.encode(*w, target) else {
+                    continue;
+                };
+                let path = parent.join(format!("{}-{}w.{}", stem, w, target.extension()));
+                variants.push(ImageVariant {
+                    url: format!("/{}", url_path(&path)),
+                    width: *w,
+                    format: target,
+                });
+                files.0.insert(path, encoded);
+            }
+        }
+
+        Some(ProcessedImage {
+            format,
+            width,
+            height,
+            variants,
+        })
+    }
+}
+
+impl Visitor for ImageProcessor {
+    fn visit_block(&mut self, block: &dyn Block, _ctx: &PageContext) {
+        if let Some(ImageSource(src)) = block.metadata().map().get::<ImageSource>()
+            && src.starts_with('/')
+            && !src.starts_with("//")
+        {
+            self.sources.insert(src.clone());
+        }
+    }
+
+    fn finalize(&self) -> VisitorResult {
+        let mut files = GeneratedFiles::new();
+        let images = self
+            .sources
+            .iter()
+            .filter_map(|src| Some((src.clone(), self.process(src, &mut files)?)))
+            .collect();
+        VisitorResult::new()
+            .mergeable(ImageVariants(images))
+            .mergeable(files)
+    }
+}
+
+/// `/`로 구분한 경로.
+fn url_path(path: &Path) -> String {
+    path.components()
+        .map(|c| c.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
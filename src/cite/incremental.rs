@@ -0,0 +1,222 @@
+//! # incremental.rs - 증분 빌드 지문
+//!
+//! ## 목적
+//! 페이지마다 렌더링 입력의 지문(fingerprint)을 빌드 사이에 저장합니다. 다음 빌드에서 지문이 같고
+//! 출력 파일이 남아 있으면 Site가 페이지 검사, 문서 렌더링, 기록을 건너뜁니다.
+//!
+//! ## 지문 구성
+//! ```text
+//! 사이트 지문 = 라이브러리 버전 + template_version + SiteConfig + 전역 메타데이터
+//!             + 테마 스타일시트 + 네비게이션 링크 + 공유 조각(Partial) 렌더링 결과
+//!             + 모든 페이지의 출력 경로와 메타데이터 (SiteIndex의 원천)
+//! 페이지 지문 = 사이트 지문 + 출력 경로 + 렌더링 대상 + asset + 병합 메타데이터
+//!             + 원본 파일(SourceFiles) 내용 + 본문 Block 트리 (종류, id, 메타데이터, 구성 값)
+//! ```
+//!
+//! ## 사용 예시
+//! ```rust
+//! let site = Site::new()
+//!     .incremental(true)
+//!     .template_version("2");  // Page/Layout 코드를 바꾸면 올림
+//! let report = site.build_output()?;
+//! report.manifest.unchanged;  // 건너뛴 파일
+//! ```
+//!
+//! ## 설계 결정
+//! - **렌더링하지 않고 입력만**: 지문을 계산하려고 Block을 렌더링하면 건너뛸 페이지도 렌더링 비용을
+//!   치르므로, 입력만 해시합니다. 디렉토리에서 읽은 페이지는 원본 파일 내용으로, 코드로 만든 Block은
+//!   `Block::fingerprint`가 내놓는 구성 값(텍스트, 설정)으로 바뀜을 알아냅니다.
+//! - **다른 페이지 참조는 사이트 지문으로**: 목록, 백링크 Block은 `SiteIndex`를 읽으므로,
+//!   인덱스의 원천인 모든 페이지의 경로와 메타데이터를 사이트 지문에 넣습니다. 본문만 고치면 그 페이지만,
+//!   제목이나 태그를 고치면 모든 페이지가 다시 렌더링됩니다.
+//! - **메타데이터는 라이브러리 타입만**: 타입 맵의 값은 임의 타입이라 해시할 수 없으므로
+//!   라이브러리 표준 타입의 값과 모든 타입 이름을 지문에 넣습니다.
+//!   사용자 타입만 바뀌는 경우나 Page/Layout 코드 변경은 `template_version`으로 무효화합니다.
+//! - **공유 입력은 사이트 지문으로**: Partial, 전역 메타데이터, 설정이 바뀌면 모든 페이지가 다시 렌더링됩니다.
+//! - **FNV-1a**: 표준 `DefaultHasher`는 버전 간 안정성을 보장하지 않으므로 직접 구현한 해시를 씁니다.
+
+use std::{
+    collections::BTreeMap,
+    fmt::{Debug, Display},
+    hash::{Hash, Hasher},
+    io,
+    path::{Path, PathBuf},
+};
+
+use crate::block::bibliography::{CitationStyle, References};
+use crate::block::block::Block;
+use crate::block::icon::IconSet;
+use crate::cite::config::SiteConfig;
+use crate::cite::vfs::SiteFs;
+use crate::metadata::map::MetadataMap;
+use crate::metadata::format::DateStyle;
+use crate::metadata::types::{
+    Assets, Categories, CriticalCss, CurrentUrl, Description, Language, LastModified, PageStatus, Paging,
+    PublishDate, Redirect, RenderTarget, Series, SourceFiles, Tags, Title,
+};
+use crate::metadata::value::MetadataValue;
+use crate::page::accessibility::Accessibility;
+use crate::page::comments::CommentSystem;
+use crate::page::frontmatter::Frontmatter;
+use crate::page::formats::OutputFormats;
+use crate::page::glossary::Glossary;
+use crate::page::theme::Theme;
+
+/// 출력 디렉토리에 저장되는 이전 빌드 지문 목록.
+pub const FINGERPRINT_FILE: &str = ".quo-fingerprints";
+
+/// 지문에 들어가는 라이브러리 버전. 라이브러리를 올리면 모든 페이지가 다시 렌더링됩니다.
+pub const LIBRARY_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// 렌더링 입력의 지문.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Fingerprint(pub u64);
+
+impl Display for Fingerprint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:016x}", self.0)
+    }
+}
+
+/// FNV-1a 64비트 해시.
+#[derive(Debug, Clone)]
+pub struct FingerprintHasher(u64);
+
+impl Default for FingerprintHasher {
+    fn default() -> Self {
+        FingerprintHasher(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Hasher for FingerprintHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+impl FingerprintHasher {
+    pub fn new() -> Self {
+        FingerprintHasher::default()
+    }
+
+    /// 값 추가 (빌더 형태).
+    pub fn with<T: Hash + ?Sized>(mut self, value: &T) -> Self {
+        value.hash(&mut self);
+        self
+    }
+
+    /// `Debug` 출력으로 값 추가. `Hash`가 없는 설정 타입용.
+    pub fn with_debug<T: Debug + ?Sized>(self, value: &T) -> Self {
+        self.with(&format!("{:?}", value))
+    }
+
+    pub fn fingerprint(&self) -> Fingerprint {
+        Fingerprint(self.finish())
+    }
+}
+
+/// 라이브러리 표준 메타데이터 값과 모든 타입 이름을 지문에 추가.
+pub(crate) fn hash_metadata(hasher: FingerprintHasher, map: &MetadataMap) -> FingerprintHasher {
+    fn value<T: MetadataValue + Debug>(hasher: FingerprintHasher, map: &MetadataMap) -> FingerprintHasher {
+        hasher.with_debug(&map.get::<T>())
+    }
+
+    let hasher = hasher.with(&map.type_names());
+    let hasher = value::<Title>(hasher, map);
+    let hasher = value::<Description>(hasher, map);
+    let hasher = value::<Tags>(hasher, map);
+    let hasher = value::<Categories>(hasher, map);
+    let hasher = value::<PublishDate>(hasher, map);
+    let hasher = value::<LastModified>(hasher, map);
+    let hasher = value::<Language>(hasher, map);
+    let hasher = value::<DateStyle>(hasher, map);
+    let hasher = value::<Paging>(hasher, map);
+    let hasher = value::<Assets>(hasher, map);
+    let hasher = value::<CriticalCss>(hasher, map);
+    let hasher = value::<PageStatus>(hasher, map);
+    let hasher = value::<Redirect>(hasher, map);
+    let hasher = value::<CurrentUrl>(hasher, map);
+    let hasher = value::<RenderTarget>(hasher, map);
+    let hasher = value::<Accessibility>(hasher, map);
+    let hasher = value::<Frontmatter>(hasher, map);
+    let hasher = value::<Theme>(hasher, map);
+    let hasher = value::<IconSet>(hasher, map);
+    let hasher = value::<CommentSystem>(hasher, map);
+    let hasher = value::<References>(hasher, map);
+    let hasher = value::<CitationStyle>(hasher, map);
+    let hasher = value::<Glossary>(hasher, map);
+    let hasher = value::<Series>(hasher, map);
+    let hasher = value::<OutputFormats>(hasher, map);
+    value::<SiteConfig>(hasher, map)
+}
+
+/// Block 트리를 렌더링하지 않고 지문에 추가: 종류, id, 메타데이터, 구성 값(`Block::fingerprint`), 자식.
+pub(crate) fn hash_block(hasher: FingerprintHasher, block: &dyn Block) -> FingerprintHasher {
+    let hasher = hasher.with(block.kind()).with(&block.id().map(|id| id.to_string()));
+    let mut hasher = hash_metadata(hasher, &block.metadata().into_map());
+    block.fingerprint(&mut hasher);
+    let children = block.children();
+    let hasher = hasher.with(&children.len());
+    children.into_iter().fold(hasher, hash_block)
+}
+
+/// 원본 파일(`SourceFiles`) 내용을 지문에 추가. 읽을 수 없는 파일은 없는 것으로 넣습니다.
+pub(crate) fn hash_sources(hasher: FingerprintHasher, map: &MetadataMap) -> FingerprintHasher {
+    map.get::<SourceFiles>()
+        .into_iter()
+        .flat_map(|sources| &sources.0)
+        .fold(hasher, |hasher, path| hasher.with(path).with(&std::fs::read(path).ok()))
+}
+
+/// 출력 경로(페이지의 화면용 경로) → 지문.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Fingerprints(pub BTreeMap<PathBuf, Fingerprint>);
+
+impl Fingerprints {
+    pub fn new() -> Self {
+        Fingerprints::default()
+    }
+
+    pub fn get(&self, path: &Path) -> Option<Fingerprint> {
+        self.0.get(path).copied()
+    }
+
+    pub fn insert(&mut self, path: PathBuf, fingerprint: Fingerprint) {
+        self.0.insert(path, fingerprint);
+    }
+
+    /// 출력 파일 시스템의 이전 빌드 지문. 없거나 읽을 수 없는 줄은 무시합니다.
+    pub fn read(fs: &dyn SiteFs) -> io::Result<Self> {
+        let listing = match fs.read(Path::new(FINGERPRINT_FILE)) {
+            Ok(listing) => String::from_utf8_lossy(&listing).into_owned(),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Fingerprints::new()),
+            Err(err) => return Err(err),
+        };
+        let entries = listing
+            .lines()
+            .filter_map(|line| {
+                let (hash, path) = line.split_once('\t')?;
+                let hash = u64::from_str_radix(hash, 16).ok()?;
+                Some((PathBuf::from(path), Fingerprint(hash)))
+            })
+            .collect();
+        Ok(Fingerprints(entries))
+    }
+
+    /// 출력 파일 시스템에 저장 (`지문<TAB>경로` 한 줄씩).
+    pub fn write(&self, fs: &dyn SiteFs) -> io::Result<()> {
+        let listing: String = self
+            .0
+            .iter()
+            .map(|(path, fingerprint)| format!("{}\t{}\n", fingerprint, path.to_string_lossy()))
+            .collect();
+        fs.write(Path::new(FINGERPRINT_FILE), listing.as_bytes())
+    }
+}
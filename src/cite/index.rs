@@ -0,0 +1,334 @@
+//! # index.rs - SiteIndex
+//!
+//! ## 목적
+//! 방문자 파이프라인의 결과를 하나로 모아 렌더링 단계에 전달합니다.
+//! 렌더링 시 메타데이터로 주입되므로 Page/Block은 `ctx.index()`로 읽습니다.
+//!
+//! ## 포함 정보
+//! - 페이지별 병합 메타데이터 (`metadata`)
+//! - Block ID 맵 (`block`, `block_href`, `has_anchor`)
+//! - 링크/백링크 (`links_from`, `backlinks`, `external_links`)
+//! - 컬렉션 (`pages_by_tag`, `pages_by_category`, `pages_in_series`, `pages_by_year_month`, `recent`, `related`)
+//! - 지정한 순서의 페이지 목록 (`pages_sorted`, 다른 질의 결과는 `SortOrder::sort`)
+//! - 자동 번호 (`number`, `count`, `key_number`, `numbered_keys`)
+//! - 사용자 방문자 결과 (`get::<T>()`)
+//!
+//! ## 사용 예시
+//! ```rust
+//! let index = site.index();
+//! let count = index.get::<WordCount>();
+//!
+//! // 컬렉션 질의 (CollectionBuilder 결과)
+//! for post in index.pages_by_tag("rust") {
+//!     println!("{} {}", post.url, post.title.as_deref().unwrap_or(""));
+//! }
+//!
+//! // Block 렌더링 중
+//! fn render_to_ir(&self, ctx: &RenderContext) -> IRNode {
+//!     let index = ctx.index().unwrap();
+//!     let n = index.number(&ctx.get::<CurrentUrl>().unwrap().0, &self.id);  // "Figure 2"
+//!     let target = index.block_href(&BlockId::new("intro"));        // "/guide.html#intro"
+//! }
+//! ```
+//!
+//! ## 구현 상태
+//! - [x] 방문자 결과 저장 (타입별)
+//! - [x] 컬렉션 질의 (`pages_by_tag`, `pages_by_category`, `pages_in_series`, `recent`, `related`)
+//! - [x] 날짜별 묶음 (`pages_by_year_month`, `ArchiveList`)
+//! - [x] 페이지 메타데이터, ID 맵, 링크/백링크, 카운터
+//!
+//! ## 설계 결정
+//! - **읽기 전용**: 렌더링 시점에는 분석이 끝났으므로 질의 메서드만 공개합니다.
+//! - **URL 인자는 정규화 후 비교**: `/blog/`, `/blog/index.html`, `/blog`를 같게 봅니다.
+
+use std::{collections::BTreeSet, path::PathBuf};
+
+use crate::block::block::BlockId;
+use crate::cite::analysis::{BlockIds, BlockLocation, Counters, LinkGraph, normalize_url};
+use crate::cite::collection::{Collections, SortOrder};
+use crate::cite::visitor::VisitorResult;
+use crate::metadata::map::{MetadataMap, ResolvedMetadata};
+use crate::metadata::types::{Date, Series};
+use crate::metadata::value::MetadataValue;
+
+/// 인덱스에 기록된 페이지 하나의 요약.
+#[derive(Debug, Clone)]
+pub struct PageEntry {
+    /// 사이트 루트 기준 URL
+    pub url: String,
+    /// 출력 디렉토리 기준 경로
+    pub output_path: PathBuf,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub date: Option<Date>,
+    pub tags: Vec<String>,
+    pub categories: Vec<String>,
+    pub series: Option<Series>,
+    pub weight: Option<i32>,
+    /// Site → Page 병합 메타데이터
+    pub metadata: ResolvedMetadata,
+}
+
+/// 사이트 분석 결과. 읽기 전용.
+#[derive(Clone, Default)]
+pub struct SiteIndex {
+    results: MetadataMap,
+}
+impl MetadataValue for SiteIndex {}
+
+impl SiteIndex {
+    pub fn new() -> Self {
+        SiteIndex::default()
+    }
+
+    /// 방문자 결과를 위에 덮어 병합한 새 인덱스.
+    pub(crate) fn absorb(self, result: VisitorResult) -> Self {
+        SiteIndex {
+            results: self.results.merge(result.map()),
+        }
+    }
+
+    /// 방문자 결과 타입 안전 접근.
+    pub fn get<T: MetadataValue>(&self) -> Option<&T> {
+        self.results.get::<T>()
+    }
+
+    fn collections(&self) -> Option<&Collections> {
+        self.get::<Collections>()
+    }
+
+    fn entries(&self, indices: Option<&Vec<usize>>) -> Vec<&PageEntry> {
+        match (self.collections(), indices) {
+            (Some(c), Some(indices)) => indices.iter().map(|&i| &c.pages[i]).collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// 출력 대상 페이지 전체 (발행일 최신순).
+    pub fn pages_by_date(&self) -> Vec<&PageEntry> {
+        self.collections()
+            .map(|c| c.pages.iter().collect())
+            .unwrap_or_default()
+    }
+
+    /// 출력 대상 페이지 전체 (`order` 순).
+    pub fn pages_sorted(&self, order: &SortOrder) -> Vec<&PageEntry> {
+        order.sort(self.pages_by_date())
+    }
+
+    /// URL로 페이지 찾기.
+    pub fn page(&self, url: &str) -> Option<&PageEntry> {
+        let url = normalize_url(url);
+        self.collections()?
+            .pages
+            .iter()
+            .find(|p| normalize_url(&p.url) == url)
+    }
+
+    /// 사이트 내부 URL이 출력 대상 페이지를 가리키는지.
+    pub fn has_page(&self, url: &str) -> bool {
+        self.page(url).is_some()
+    }
+
+    /// 페이지의 Site → Page 병합 메타데이터.
+    pub fn metadata(&self, url: &str) -> Option<&ResolvedMetadata> {
+        self.page(url).map(|p| &p.metadata)
+    }
+
+    // ------------------------------------------------------------------------
+    // ID 맵
+    // ------------------------------------------------------------------------
+
+    /// Block ID의 위치.
+    pub fn block(&self, id: &BlockId) -> Option<&BlockLocation> {
+        self.get::<BlockIds>()?.blocks.get(id)
+    }
+
+    /// Block ID로의 링크 (`/page.html#id`).
+    pub fn block_href(&self, id: &BlockId) -> Option<String> {
+        self.block(id).map(BlockLocation::href)
+    }
+
+    /// 같은 ID가 두 번 이상 선언된 Block들 (뒤에 선언된 위치).
+    pub fn duplicate_ids(&self) -> &[(BlockId, BlockLocation)] {
+        self.get::<BlockIds>()
+            .map(|ids| ids.duplicates.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// 페이지 렌더링 결과의 모든 `id` 속성. 분석하지 않은 페이지(생성 페이지, 정적 파일)는 `None`.
+    pub fn anchors(&self, url: &str) -> Option<&BTreeSet<String>> {
+        let url = normalize_url(url);
+        self.get::<BlockIds>()?
+            .anchors
+            .iter()
+            .find(|(page, _)| normalize_url(page) == url)
+            .map(|(_, anchors)| anchors)
+    }
+
+    /// 페이지 렌더링 결과에 `id="anchor"` 요소가 있는지.
+    pub fn has_anchor(&self, url: &str, anchor: &str) -> bool {
+        let url = normalize_url(url);
+        self.get::<BlockIds>().is_some_and(|ids| {
+            ids.anchors
+                .iter()
+                .any(|(page, anchors)| normalize_url(page) == url && anchors.contains(anchor))
+        })
+    }
+
+    // ------------------------------------------------------------------------
+    // 링크
+    // ------------------------------------------------------------------------
+
+    /// 페이지가 링크하는 사이트 내부 URL (정규화됨, 등장 순서).
+    pub fn links_from(&self, url: &str) -> &[String] {
+        self.get::<LinkGraph>()
+            .and_then(|graph| graph.links.get(&normalize_url(url)))
+            .map(|links| links.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// 페이지를 링크하는 페이지 URL (정규화됨, URL 순).
+    pub fn backlinks(&self, url: &str) -> Vec<&str> {
+        self.get::<LinkGraph>()
+            .and_then(|graph| graph.backlinks.get(&normalize_url(url)))
+            .map(|pages| pages.iter().map(|p| p.as_str()).collect())
+            .unwrap_or_default()
+    }
+
+    /// 페이지의 외부 링크 (등장 순서).
+    pub fn external_links(&self, url: &str) -> &[String] {
+        self.get::<LinkGraph>()
+            .and_then(|graph| graph.external.get(&normalize_url(url)))
+            .map(|links| links.as_slice())
+            .unwrap_or_default()
+    }
+
+    // ------------------------------------------------------------------------
+    // 자동 번호
+    // ------------------------------------------------------------------------
+
+    /// 페이지 안에서 Block의 번호 (종류별, 1부터).
+    pub fn number(&self, url: &str, id: &BlockId) -> Option<usize> {
+        self.get::<Counters>()?
+            .numbers
+            .get(&(normalize_url(url), id.clone()))
+            .copied()
+    }
+
+    /// 페이지 안에서 키의 번호 (`NumberedKeys`, 종류별, 1부터).
+    pub fn key_number(&self, url: &str, kind: &str, key: &str) -> Option<usize> {
+        self.get::<Counters>()?
+            .keys
+            .get(&(normalize_url(url), kind.to_string(), key.to_string()))
+            .copied()
+    }
+
+    /// 페이지에서 번호를 받은 키 (번호순).
+    pub fn numbered_keys(&self, url: &str, kind: &str) -> Vec<&str> {
+        let Some(counters) = self.get::<Counters>() else {
+            return Vec::new();
+        };
+        let url = normalize_url(url);
+        let mut keys: Vec<(usize, &str)> = counters
+            .keys
+            .iter()
+            .filter(|((page, k, _), _)| *page == url && k == kind)
+            .map(|((_, _, key), number)| (*number, key.as_str()))
+            .collect();
+        keys.sort();
+        keys.into_iter().map(|(_, key)| key).collect()
+    }
+
+    /// 페이지 안의 종류별 번호 대상 개수.
+    pub fn count(&self, url: &str, kind: &str) -> usize {
+        self.get::<Counters>()
+            .and_then(|c| c.totals.get(&(normalize_url(url), kind.to_string())))
+            .copied()
+            .unwrap_or(0)
+    }
+
+    // ------------------------------------------------------------------------
+    // 컬렉션
+    // ------------------------------------------------------------------------
+
+    /// 태그가 붙은 페이지 (발행일 최신순).
+    pub fn pages_by_tag(&self, tag: &str) -> Vec<&PageEntry> {
+        self.entries(self.collections().and_then(|c| c.tags.get(tag)))
+    }
+
+    /// 카테고리에 속한 페이지 (발행일 최신순).
+    pub fn pages_by_category(&self, category: &str) -> Vec<&PageEntry> {
+        self.entries(self.collections().and_then(|c| c.categories.get(category)))
+    }
+
+    /// 연재에 속한 페이지 (연재 순서).
+    pub fn pages_in_series(&self, name: &str) -> Vec<&PageEntry> {
+        self.entries(self.collections().and_then(|c| c.series.get(name)))
+    }
+
+    /// 모든 태그와 페이지 수 (이름순).
+    pub fn tags(&self) -> Vec<(&str, usize)> {
+        self.collections()
+            .map(|c| c.tags.iter().map(|(t, pages)| (t.as_str(), pages.len())).collect())
+            .unwrap_or_default()
+    }
+
+    /// 모든 카테고리와 페이지 수 (이름순).
+    pub fn categories(&self) -> Vec<(&str, usize)> {
+        self.collections()
+            .map(|c| {
+                c.categories
+                    .iter()
+                    .map(|(name, pages)| (name.as_str(), pages.len()))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// 발행일이 있는 페이지를 발행 연월별로 묶은 목록 (최신 달부터, 달 안은 최신순).
+    pub fn pages_by_year_month(&self) -> Vec<((i32, u8), Vec<&PageEntry>)> {
+        let mut groups: Vec<((i32, u8), Vec<&PageEntry>)> = Vec::new();
+        for page in self.pages_by_date() {
+            let Some(date) = page.date else {
+                continue;
+            };
+            let key = (date.year, date.month);
+            match groups.last_mut() {
+                Some((last, pages)) if *last == key => pages.push(page),
+                _ => groups.push((key, vec![page])),
+            }
+        }
+        groups
+    }
+
+    /// 발행일이 있는 최근 페이지 `n`개.
+    pub fn recent(&self, n: usize) -> Vec<&PageEntry> {
+        self.pages_by_date()
+            .into_iter()
+            .filter(|p| p.date.is_some())
+            .take(n)
+            .collect()
+    }
+
+    /// `url` 페이지와 태그/카테고리를 많이 공유하는 페이지 `n`개 (공유 개수 → 최신순).
+    pub fn related(&self, url: &str, n: usize) -> Vec<&PageEntry> {
+        let Some(base) = self.page(url) else {
+            return Vec::new();
+        };
+        let shared = |p: &PageEntry| {
+            p.tags.iter().filter(|t| base.tags.contains(t)).count()
+                + p.categories.iter().filter(|c| base.categories.contains(c)).count()
+        };
+        let mut related: Vec<(usize, &PageEntry)> = self
+            .pages_by_date()
+            .into_iter()
+            .filter(|p| p.url != url)
+            .map(|p| (shared(p), p))
+            .filter(|(count, _)| *count > 0)
+            .collect();
+        // 안정 정렬이므로 같은 공유 개수 안에서는 최신순이 유지됨
+        related.sort_by_key(|(count, _)| std::cmp::Reverse(*count));
+        related.into_iter().take(n).map(|(_, p)| p).collect()
+    }
+}
@@ -0,0 +1,120 @@
+//! # lastmod.rs - 원본 파일 수정일
+//!
+//! ## 목적
+//! 페이지가 선언한 원본 파일(`SourceFiles`)의 수정일로 페이지의 마지막 수정일(`LastModified`)을 정합니다.
+//! Site는 이 날짜를 페이지 메타데이터로 주입하고, sitemap `<lastmod>`, 피드의 갱신일(Atom `updated`,
+//! JSON Feed `date_modified`), 페이지의 "마지막 수정" 표시가 같은 값을 읽습니다.
+//!
+//! ## 사용 예시
+//! ```rust
+//! // ContentLoader가 읽은 페이지는 원본 파일이 자동으로 선언됨
+//! // 직접 만든 페이지
+//! Metadata::new().custom(SourceFiles::new().file("content/about.md").file("data/team.json"));
+//!
+//! // 기본: 파일 시스템 수정 시각
+//! let site = Site::new();
+//!
+//! // VCS 날짜 주입: 체크아웃하면 수정 시각이 바뀌므로 CI에서는 커밋 날짜를 씁니다
+//! // $ git log --format=@%ct --name-only > lastmod.txt
+//! let dates = InjectedDates::from_git_log(&fs::read_to_string("lastmod.txt")?);
+//! let site = Site::new().source_dates(dates);
+//!
+//! // 머리말로 직접 지정하면 그 날짜가 우선
+//! // updated: 2024-05-01
+//! ```
+//!
+//! ## 설계 결정
+//! - **여러 원본은 가장 늦은 날짜**: 본문과 데이터 파일 중 하나만 바뀌어도 페이지가 바뀐 것입니다.
+//! - **명시한 날짜 우선**: 페이지 메타데이터의 `LastModified`(머리말 `updated`, `lastmod`)가 있으면
+//!   원본 파일을 읽지 않습니다.
+//! - **재현 빌드**: 파일 수정 시각은 체크아웃마다 다르므로 결정적 출력이 필요하면 `InjectedDates`를 씁니다.
+//! - **날짜 단위**: 다른 빌드 시각(`Clock`)과 같이 UTC 날짜만 씁니다.
+
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Component, Path, PathBuf},
+    time::UNIX_EPOCH,
+};
+
+use crate::metadata::types::Date;
+
+/// 원본 파일의 수정일을 돌려주는 출처.
+pub trait SourceDates: Send + Sync {
+    /// 모르는 파일이면 `None`.
+    fn modified(&self, path: &Path) -> Option<Date>;
+}
+
+/// 파일 시스템의 수정 시각 (UTC 날짜).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FileMtime;
+
+impl SourceDates for FileMtime {
+    fn modified(&self, path: &Path) -> Option<Date> {
+        let modified = fs::metadata(path).and_then(|m| m.modified()).ok()?;
+        let secs = modified.duration_since(UNIX_EPOCH).ok()?.as_secs() as i64;
+        Some(Date::from_unix_days(secs.div_euclid(86_400)))
+    }
+}
+
+/// 미리 알려 준 파일별 수정일 (VCS 커밋 날짜 등). 경로의 `./`는 무시합니다.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct InjectedDates {
+    dates: BTreeMap<PathBuf, Date>,
+}
+
+impl InjectedDates {
+    pub fn new() -> Self {
+        InjectedDates::default()
+    }
+
+    pub fn date<P: AsRef<Path>>(mut self, path: P, date: Date) -> Self {
+        self.dates.insert(clean(path.as_ref()), date);
+        self
+    }
+
+    /// `git log --format=@%ct --name-only` 출력 파싱. 파일마다 가장 최근 커밋 날짜를 씁니다.
+    /// 경로는 저장소 루트 기준이므로 저장소 루트에서 빌드할 때 `ContentLoader`의 경로와 일치합니다.
+    pub fn from_git_log(log: &str) -> Self {
+        let mut dates = InjectedDates::new();
+        let mut current = None;
+        for line in log.lines().map(str::trim).filter(|line| !line.is_empty()) {
+            match line.strip_prefix('@') {
+                Some(epoch) => {
+                    current = epoch
+                        .parse::<i64>()
+                        .ok()
+                        .map(|secs| Date::from_unix_days(secs.div_euclid(86_400)));
+                }
+                None => {
+                    if let Some(date) = current {
+                        // 최신 커밋이 먼저 나옴
+                        dates.dates.entry(clean(Path::new(line))).or_insert(date);
+                    }
+                }
+            }
+        }
+        dates
+    }
+
+    pub fn len(&self) -> usize {
+        self.dates.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.dates.is_empty()
+    }
+}
+
+impl SourceDates for InjectedDates {
+    fn modified(&self, path: &Path) -> Option<Date> {
+        self.dates.get(&clean(path)).copied()
+    }
+}
+
+/// `./` 구성 요소를 뺀 경로.
+fn clean(path: &Path) -> PathBuf {
+    path.components()
+        .filter(|c| !matches!(c, Component::CurDir))
+        .collect()
+}
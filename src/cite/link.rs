@@ -0,0 +1,91 @@
+//! # link.rs - 페이지 참조 수집
+//!
+//! ## 목적
+//! 출력 대상 페이지를 `PageId`와 Page 구현 타입으로 찾을 수 있도록 `PageRefs`로 `SiteIndex`에 남깁니다.
+//! `Link` Block(`page/link.rs`)은 렌더링 시점에 여기서 대상 페이지의 URL을 찾습니다.
+//!
+//! ## 사용 예시
+//! ```rust
+//! let index = site.index();
+//! let refs = index.get::<PageRefs>().unwrap();
+//! refs.resolve(&PageRef::id("install"));      // Some(PageTarget { url: "/docs/install.html", .. })
+//! refs.resolve(&PageRef::of::<AboutPage>());
+//! ```
+//!
+//! ## 설계 결정
+//! - **같은 ID는 먼저 등록한 페이지**: 나중 페이지는 `duplicates`에 남고 `Site::check`가 오류로 보고합니다.
+//! - **타입은 유일할 때만**: 같은 타입의 페이지(`SimplePage` 등)가 여럿이면 어느 것인지 알 수 없으므로
+//!   찾지 못한 것으로 봅니다. 여러 개인 타입은 `PageId`로 가리킵니다.
+
+use std::{
+    any::{Any, TypeId},
+    collections::{BTreeMap, btree_map::Entry},
+    path::PathBuf,
+};
+
+use crate::cite::visitor::{SiteContext, Visitor, VisitorResult};
+use crate::metadata::value::MetadataValue;
+use crate::page::link::{PageId, PageRef};
+use crate::page::page::Page;
+
+/// 찾은 링크 대상.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PageTarget {
+    /// 사이트 루트 기준 URL
+    pub url: String,
+    /// 출력 경로 (출력 디렉토리 기준)
+    pub output_path: PathBuf,
+}
+
+/// 페이지 ID, 페이지 타입 → 대상.
+#[derive(Debug, Clone, Default)]
+pub struct PageRefs {
+    pub ids: BTreeMap<PageId, PageTarget>,
+    /// 타입별 페이지 (등록 순서)
+    pub types: BTreeMap<TypeId, Vec<PageTarget>>,
+    /// 두 번 이상 선언된 ID의 나중 페이지
+    pub duplicates: Vec<(PageId, PageTarget)>,
+}
+impl MetadataValue for PageRefs {}
+
+impl PageRefs {
+    /// 링크 대상 찾기. 타입 참조는 그 타입의 페이지가 하나일 때만 찾습니다.
+    pub fn resolve(&self, target: &PageRef) -> Option<&PageTarget> {
+        match target {
+            PageRef::Id(id) => self.ids.get(&PageId::new(id)),
+            PageRef::Type { id, .. } => match self.types.get(id)?.as_slice() {
+                [page] => Some(page),
+                _ => None,
+            },
+        }
+    }
+}
+
+/// `PageRefs`를 만드는 방문자. Site의 기본 방문자입니다.
+#[derive(Debug, Clone, Default)]
+pub struct PageLinker {
+    refs: PageRefs,
+}
+
+impl Visitor for PageLinker {
+    fn visit_page(&mut self, page: &dyn Page, ctx: &SiteContext) {
+        let target = PageTarget {
+            url: ctx.url.clone(),
+            output_path: ctx.output_path.clone(),
+        };
+        let any: &dyn Any = page;
+        self.refs.types.entry(any.type_id()).or_default().push(target.clone());
+        if let Some(id) = ctx.metadata.get::<PageId>() {
+            match self.refs.ids.entry(id.clone()) {
+                Entry::Occupied(entry) => self.refs.duplicates.push((entry.key().clone(), target)),
+                Entry::Vacant(entry) => {
+                    entry.insert(target);
+                }
+            }
+        }
+    }
+
+    fn finalize(&self) -> VisitorResult {
+        VisitorResult::new().custom(self.refs.clone())
+    }
+}
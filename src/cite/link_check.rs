@@ -0,0 +1,360 @@
+//! # link_check.rs - ExternalLinkChecker (`link-check` 기능)
+//!
+//! ## 목적
+//! 페이지의 외부 링크(`SiteIndex::external_links`)가 실제로 응답하는지 확인합니다. 결과(`DeadLinks`)는
+//! `SiteIndex`에 남고, Site가 페이지 검사 단계에서 `BuildReport`의 경고(`WarningKind::DeadLink`)로 옮깁니다.
+//! 네트워크를 쓰므로 기능 플래그로 분리하고, 방문자를 등록한 빌드에서만 실행합니다.
+//!
+//! ## 사용 예시
+//! ```rust
+//! site.register_visitor(
+//!     ExternalLinkChecker::new()
+//!         .concurrency(16)
+//!         .timeout(Duration::from_secs(5))
+//!         .ignore("localhost")                     // 호스트 (하위 도메인 포함)
+//!         .ignore("https://example.com/private/")  // URL 접두사
+//!         .cache_file(".quo-link-cache.json"),
+//! );
+//! let report = site.check()?;
+//! // blog/post.html: dead external link: https://old.example.org/page (HTTP 404)
+//!
+//! // HTTP 클라이언트 교체 (기본: curl 실행 파일)
+//! struct Ureq;
+//! impl HttpClient for Ureq {
+//!     fn status(&self, url: &str, timeout: Duration) -> io::Result<u16> { /* ... */ }
+//! }
+//! ExternalLinkChecker::new().client(Ureq);
+//! ```
+//!
+//! ## 구현 상태
+//! - [x] `http(s)://`, `//` 링크 확인 (fragment 제외, URL당 한 번)
+//! - [x] 동시 요청 수 제한, 요청별 시간 제한
+//! - [x] 확인 대상(`allow`) / 제외(`ignore`) 목록: URL 접두사 또는 호스트
+//! - [x] 캐시: 같은 프로세스의 빌드끼리 공유, `cache_file`이면 살아 있는 링크를 `cache_ttl` 동안 파일에 보관
+//! - [ ] TODO: 호스트별 동시 요청 제한, `Retry-After`
+//!
+//! ## 설계 결정
+//! - **HTTP 클라이언트는 트레이트로**: TLS와 HTTP 구현은 무거운 외부 의존성이므로 `ImageEncoder`처럼
+//!   분리합니다. 기본 구현 `CurlClient`는 시스템의 `curl`을 실행합니다.
+//! - **2xx/3xx만 정상**: 리다이렉트는 따라간 뒤의 상태로 판단합니다. 429(요청 과다)는 서버가 살아 있으므로
+//!   깨진 링크로 보지 않습니다.
+//! - **죽은 링크는 캐시하지 않음**: 일시적인 장애일 수 있으므로 다음 빌드에서 다시 확인합니다.
+//! - **경고로 보고**: 외부 사이트 상태는 빌드 입력이 아니므로 빌드를 멈추지 않습니다.
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs, io,
+    path::{Path, PathBuf},
+    process::Command,
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicUsize, Ordering},
+    },
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use serde::Serialize;
+
+use crate::cite::visitor::{SiteContext, Visitor, VisitorResult};
+use crate::metadata::value::MetadataValue;
+use crate::page::page::Page;
+
+/// 기본 동시 요청 수.
+const DEFAULT_CONCURRENCY: usize = 8;
+/// 기본 요청 시간 제한.
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
+/// 캐시 파일의 기본 유효 기간 (하루).
+const DEFAULT_CACHE_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// URL의 HTTP 상태를 알아내는 클라이언트.
+pub trait HttpClient: Send + Sync {
+    /// 리다이렉트를 따라간 뒤의 상태 코드. 연결 실패, 시간 초과 등은 오류.
+    /// `HEAD`를 거부하는 서버(405, 501)는 `GET`으로 다시 확인하는 것이 좋습니다.
+    fn status(&self, url: &str, timeout: Duration) -> io::Result<u16>;
+}
+
+/// 시스템의 `curl` 실행 파일을 쓰는 클라이언트.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CurlClient;
+
+impl CurlClient {
+    fn request(url: &str, timeout: Duration, head: bool) -> io::Result<u16> {
+        let mut command = Command::new("curl");
+        command
+            .args(["--silent", "--show-error", "--location", "--output"])
+            .arg(if cfg!(windows) { "NUL" } else { "/dev/null" })
+            .args(["--write-out", "%{http_code}", "--user-agent", "quo-link-check"])
+            .arg("--max-time")
+            .arg(timeout.as_secs().max(1).to_string());
+        if head {
+            command.arg("--head");
+        }
+        let output = command.arg("--").arg(url).output()?;
+        let code = String::from_utf8_lossy(&output.stdout);
+        match code.trim().parse::<u16>() {
+            Ok(code) if code != 0 => Ok(code),
+            _ => {
+                // "curl: (7) Failed to connect ..." → "Failed to connect ..."
+                let stderr = String::from_utf8_lossy(&output.stderr);
+                let message = stderr.trim().trim_start_matches("curl: ");
+                let message = match message.split_once(") ") {
+                    Some((code, rest)) if code.starts_with('(') => rest,
+                    _ => message,
+                };
+                Err(io::Error::other(message.to_string()))
+            }
+        }
+    }
+}
+
+impl HttpClient for CurlClient {
+    fn status(&self, url: &str, timeout: Duration) -> io::Result<u16> {
+        match CurlClient::request(url, timeout, true)? {
+            405 | 501 => CurlClient::request(url, timeout, false),
+            code => Ok(code),
+        }
+    }
+}
+
+/// 깨진 외부 링크 하나.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DeadLink {
+    /// 페이지에 적힌 그대로의 URL
+    pub url: String,
+    /// 예: `HTTP 404`, `connection refused`
+    pub reason: String,
+}
+
+/// 페이지 URL(사이트 루트 기준) → 깨진 외부 링크 (등장 순서). 깨진 링크가 없는 페이지는 담지 않습니다.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DeadLinks(pub BTreeMap<String, Vec<DeadLink>>);
+impl MetadataValue for DeadLinks {}
+
+impl DeadLinks {
+    pub fn get(&self, url: &str) -> Option<&[DeadLink]> {
+        self.0.get(url).map(Vec::as_slice)
+    }
+}
+
+/// 확인 결과. `None`이면 정상.
+type Outcome = Option<String>;
+
+/// 외부 링크를 확인하는 방문자.
+#[derive(Clone)]
+pub struct ExternalLinkChecker {
+    client: Arc<dyn HttpClient>,
+    concurrency: usize,
+    timeout: Duration,
+    allow: Vec<String>,
+    ignore: Vec<String>,
+    cache_file: Option<PathBuf>,
+    cache_ttl: Duration,
+    /// 확인한 URL → 결과. 복제본(빌드마다 하나)끼리 공유
+    results: Arc<Mutex<BTreeMap<String, Outcome>>>,
+    /// 페이지 URL → 외부 링크
+    pages: BTreeMap<String, Vec<String>>,
+}
+
+impl Default for ExternalLinkChecker {
+    fn default() -> Self {
+        ExternalLinkChecker {
+            client: Arc::new(CurlClient),
+            concurrency: DEFAULT_CONCURRENCY,
+            timeout: DEFAULT_TIMEOUT,
+            allow: Vec::new(),
+            ignore: Vec::new(),
+            cache_file: None,
+            cache_ttl: DEFAULT_CACHE_TTL,
+            results: Arc::default(),
+            pages: BTreeMap::new(),
+        }
+    }
+}
+
+impl ExternalLinkChecker {
+    pub fn new() -> Self {
+        ExternalLinkChecker::default()
+    }
+
+    pub fn client<C: HttpClient + 'static>(mut self, client: C) -> Self {
+        self.client = Arc::new(client);
+        self
+    }
+
+    /// 동시 요청 수. 기본: 8
+    pub fn concurrency(mut self, n: usize) -> Self {
+        self.concurrency = n.max(1);
+        self
+    }
+
+    /// 요청 하나의 시간 제한. 기본: 10초
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// 확인 대상 추가. 하나라도 있으면 일치하는 링크만 확인합니다.
+    /// `://`가 있으면 URL 접두사, 없으면 호스트(하위 도메인 포함)입니다.
+    pub fn allow(mut self, pattern: &str) -> Self {
+        self.allow.push(pattern.to_string());
+        self
+    }
+
+    /// 확인하지 않을 링크 (`allow`와 같은 형식). `allow`보다 우선합니다.
+    pub fn ignore(mut self, pattern: &str) -> Self {
+        self.ignore.push(pattern.to_string());
+        self
+    }
+
+    /// 살아 있는 링크를 기록해 다음 빌드에서 다시 확인하지 않을 파일 (JSON).
+    pub fn cache_file<P: AsRef<Path>>(mut self, path: P) -> Self {
+        self.cache_file = Some(path.as_ref().to_path_buf());
+        self
+    }
+
+    /// 캐시 파일 항목의 유효 기간. 기본: 하루
+    pub fn cache_ttl(mut self, ttl: Duration) -> Self {
+        self.cache_ttl = ttl;
+        self
+    }
+
+    /// 확인할 링크인지 (`http(s)://`, `//`이고 목록 조건을 만족).
+    fn is_checked(&self, url: &str) -> bool {
+        let Some(host) = host(url) else {
+            return false;
+        };
+        let matches = |pattern: &String| match pattern.contains("://") {
+            true => url.starts_with(pattern.as_str()),
+            false => host == pattern.as_str() || host.ends_with(&format!(".{}", pattern)),
+        };
+        !self.ignore.iter().any(matches) && (self.allow.is_empty() || self.allow.iter().any(matches))
+    }
+
+    /// 캐시 파일의 유효한 항목 (URL → 확인 시각, 유닉스 초).
+    fn read_cache(&self, now: u64) -> BTreeMap<String, u64> {
+        let Some(path) = &self.cache_file else {
+            return BTreeMap::new();
+        };
+        let cache: BTreeMap<String, u64> = fs::read(path)
+            .ok()
+            .and_then(|bytes| serde_json::from_slice(&bytes).ok())
+            .unwrap_or_default();
+        cache
+            .into_iter()
+            .filter(|(_, checked)| now.saturating_sub(*checked) < self.cache_ttl.as_secs())
+            .collect()
+    }
+
+    /// 확인이 필요한 URL들을 동시에 확인해 `results`에 기록.
+    fn check_all(&self, urls: Vec<String>) {
+        let next = AtomicUsize::new(0);
+        thread::scope(|scope| {
+            for _ in 0..self.concurrency.min(urls.len()) {
+                scope.spawn(|| {
+                    while let Some(url) = urls.get(next.fetch_add(1, Ordering::Relaxed)) {
+                        let outcome = match self.client.status(&request_url(url), self.timeout) {
+                            Ok(code) if (200..400).contains(&code) || code == 429 => None,
+                            Ok(code) => Some(format!("HTTP {}", code)),
+                            Err(err) => Some(err.to_string()),
+                        };
+                        self.lock().insert(url.clone(), outcome);
+                    }
+                });
+            }
+        });
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, BTreeMap<String, Outcome>> {
+        self.results.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Visitor for ExternalLinkChecker {
+    fn visit_page(&mut self, _page: &dyn Page, ctx: &SiteContext) {
+        let links: Vec<String> = ctx
+            .index
+            .external_links(&ctx.url)
+            .iter()
+            .filter(|url| self.is_checked(url))
+            .cloned()
+            .collect();
+        if !links.is_empty() {
+            self.pages.insert(ctx.url.clone(), links);
+        }
+    }
+
+    fn finalize(&self) -> VisitorResult {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or_default();
+        let mut cache = self.read_cache(now);
+
+        let urls: BTreeSet<String> = self.pages.values().flatten().map(|url| without_fragment(url)).collect();
+        let pending: Vec<String> = {
+            let results = self.lock();
+            urls.iter()
+                .filter(|url| !results.contains_key(*url) && !cache.contains_key(*url))
+                .cloned()
+                .collect()
+        };
+        self.check_all(pending);
+
+        let results = self.lock();
+        let mut dead = DeadLinks::default();
+        for (page, links) in &self.pages {
+            let broken: Vec<DeadLink> = links
+                .iter()
+                .filter_map(|url| {
+                    let reason = results.get(&without_fragment(url))?.clone()?;
+                    Some(DeadLink {
+                        url: url.clone(),
+                        reason,
+                    })
+                })
+                .collect();
+            if !broken.is_empty() {
+                dead.0.insert(page.clone(), broken);
+            }
+        }
+
+        if let Some(path) = &self.cache_file {
+            for url in &urls {
+                if let Some(None) = results.get(url) {
+                    cache.entry(url.clone()).or_insert(now);
+                }
+            }
+            // 캐시 기록 실패는 다음 빌드에서 다시 확인할 뿐이므로 무시
+            let _ = fs::write(path, serde_json::to_string_pretty(&cache).unwrap_or_default());
+        }
+        VisitorResult::new().custom(dead)
+    }
+}
+
+/// `http(s)://`, `//` 링크의 호스트 (소문자, 포트 제외). 그 밖의 링크는 `None`.
+fn host(url: &str) -> Option<String> {
+    let lower = url.to_ascii_lowercase();
+    let rest = ["https://", "http://", "//"]
+        .iter()
+        .find_map(|scheme| lower.strip_prefix(scheme))?;
+    let authority = rest.split(['/', '?', '#']).next().unwrap_or("");
+    let host = authority.rsplit_once('@').map(|(_, h)| h).unwrap_or(authority);
+    let host = match host.strip_prefix('[') {
+        Some(ipv6) => ipv6.split(']').next().unwrap_or(""),
+        None => host.split(':').next().unwrap_or(""),
+    };
+    (!host.is_empty()).then(|| host.to_string())
+}
+
+fn without_fragment(url: &str) -> String {
+    url.split('#').next().unwrap_or(url).to_string()
+}
+
+/// 요청할 URL. 프로토콜 상대 링크(`//host/...`)는 HTTPS로.
+fn request_url(url: &str) -> String {
+    match url.starts_with("//") {
+        true => format!("https:{}", url),
+        false => url.to_string(),
+    }
+}
@@ -0,0 +1,40 @@
+//! # math.rs - MathScriptGenerator
+//!
+//! ## 목적
+//! 수식(`MathBlock`, 인라인 수식)을 쓰는 페이지가 있으면 KaTeX 초기화 스크립트(`assets/math.js`)를 기록합니다.
+//! 페이지에는 수식 Block이 선언한 `Assets`로 이미 연결되어 있습니다.
+//!
+//! ## 설계 결정
+//! - **기본 방문자**: 수식이 없는 사이트에서는 아무 파일도 만들지 않으므로 항상 실행합니다.
+
+use crate::block::math::{MATH_SCRIPT, MATH_SCRIPT_PATH, MATH_SCRIPT_URL};
+use crate::cite::visitor::{SiteContext, Visitor, VisitorResult};
+use crate::cite::writer::GeneratedFiles;
+use crate::page::assets::collect_assets;
+use crate::page::page::Page;
+
+/// 수식 초기화 스크립트를 기록하는 방문자.
+#[derive(Debug, Clone, Default)]
+pub struct MathScriptGenerator {
+    used: bool,
+}
+
+impl MathScriptGenerator {
+    pub fn new() -> Self {
+        MathScriptGenerator::default()
+    }
+}
+
+impl Visitor for MathScriptGenerator {
+    fn visit_page(&mut self, page: &dyn Page, _ctx: &SiteContext) {
+        self.used |= collect_assets(page).scripts.iter().any(|src| src == MATH_SCRIPT_URL);
+    }
+
+    fn finalize(&self) -> VisitorResult {
+        match self.used {
+            true => VisitorResult::new()
+                .mergeable(GeneratedFiles::new().file(MATH_SCRIPT_PATH, MATH_SCRIPT.to_string())),
+            false => VisitorResult::new(),
+        }
+    }
+}
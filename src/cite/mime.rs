@@ -0,0 +1,50 @@
+//! # mime.rs - 출력 파일의 Content-Type
+//!
+//! ## 목적
+//! 출력 파일 확장자로 HTTP `Content-Type`을 정합니다. 로컬 서버(`quo serve`)와 배포 메타데이터
+//! (S3 매니페스트)가 같은 표를 씁니다.
+//!
+//! ## 설계 결정
+//! - **텍스트는 UTF-8 명시**: 렌더링 결과는 항상 UTF-8이므로 `charset=utf-8`을 붙입니다.
+//! - **모르는 확장자는 `application/octet-stream`**: 브라우저가 추측하지 않고 내려받게 합니다.
+
+use std::path::Path;
+
+/// 확장자(대소문자 무시)로 정한 Content-Type.
+pub fn content_type(path: &Path) -> &'static str {
+    let extension = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or("")
+        .to_ascii_lowercase();
+    match extension.as_str() {
+        "html" | "htm" => "text/html; charset=utf-8",
+        "css" => "text/css; charset=utf-8",
+        "js" | "mjs" => "text/javascript; charset=utf-8",
+        "json" => "application/json",
+        "xml" => "application/xml",
+        "txt" => "text/plain; charset=utf-8",
+        "svg" => "image/svg+xml",
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "webp" => "image/webp",
+        "avif" => "image/avif",
+        "ico" => "image/x-icon",
+        "woff" => "font/woff",
+        "woff2" => "font/woff2",
+        "pdf" => "application/pdf",
+        "wasm" => "application/wasm",
+        "webmanifest" => "application/manifest+json",
+        _ => "application/octet-stream",
+    }
+}
+
+/// 문서로 보고 항상 다시 확인해야 하는 파일인지 (HTML, XML, JSON, 텍스트).
+pub fn is_document(path: &Path) -> bool {
+    let content_type = content_type(path);
+    content_type.starts_with("text/html")
+        || content_type.starts_with("text/plain")
+        || content_type == "application/xml"
+        || content_type == "application/json"
+}
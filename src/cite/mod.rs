@@ -26,7 +26,7 @@
 //! **렌더링 단계 (Rendering Pass):**
 //! 7. `HtmlRenderer`: 최종 HTML 파일 생성
 //! 8. `SitemapGenerator`: sitemap.xml 생성
-//! 9. `RssGenerator`: RSS 피드 생성
+//! 9. `FeedGenerator`: RSS/Atom/JSON Feed 생성
 //! 10. `SearchIndexGenerator`: 검색 인덱스 생성
 //!
 //! ### 3. 사이트 인덱스 (Site Index)
@@ -71,6 +71,7 @@
 //! - `base_url`: 기본 URL (링크 생성용)
 //! - `output_dir`: 출력 디렉토리
 //! - `language`: 기본 언어
+//! - `trailing_slash`, `pretty_urls`: URL/출력 경로 형식
 //!
 //! ### SiteIndex
 //! - `resolved_metadata`: 병합된 메타데이터 맵
@@ -107,6 +108,10 @@
 //!      ↓
 //!    3.5. 전역 파일 생성
 //!         sitemap.xml, feed.xml, search.json 등
+//!      ↓
+//!    3.6. 파일 기록 (OutputWriter → SiteFs: 디스크, 메모리)
+//!         임시 파일 + 이름 변경, 이전 빌드 파일 정리, BuildReport 반환
+//!         (실패는 BuildError: 규칙 데이터, 잘못된 속성, 깨진 링크, 입출력)
 //! ```
 //!
 //! ## Visitor 트레이트
@@ -123,17 +128,32 @@
 //! - `MetadataCollector`: Site → Page → Block 메타데이터 병합
 //! - `IdGenerator`: 경로/사용자 지정 기반 고유 ID 생성
 //! - `Counter`: 페이지별/사이트별 자동 번호 부여
+//! - `CitationChecker`: 등록되지 않은 인용 키 감지 (`References`)
+//! - `GlossaryLinker`: 페이지마다 처음 나온 용어집 용어 (`Glossary`)
 //! - `LinkResolver`: 링크 대상 검증 및 해결
 //! - `BacklinkGenerator`: 역방향 링크 맵 생성
 //! - `CollectionBuilder`: 태그/카테고리별 페이지 그룹화
+//! - `TranslationLinker`: 번역 그룹 수집 (hreflang 대체 링크)
+//! - `PageLinker`: 페이지 ID/타입 → URL (`Link::to`, `link!`)
+//! - `OutlineValidator`: 제목 구조 검사 (h1 하나, 단계 건너뛰지 않기, 빈 제목)
+//! - `ExternalLinkChecker`: 외부 링크 응답 확인 (`link-check` 기능)
 //!
 //! **렌더링 방문자:**
 //! - `HtmlRenderer`: IRNode → HTML 파일
 //!
 //! **전역 파일 방문자:**
 //! - `SitemapGenerator`: sitemap.xml (SEO)
-//! - `RssGenerator`: feed.xml (구독)
+//! - `FeedGenerator`: feed.xml, atom.xml, feed.json (구독)
 //! - `SearchIndexGenerator`: search.json (검색)
+//! - `OutputFormatGenerator`: 페이지별 page.json, page.txt, page.md (`OutputFormats`)
+//! - `RedirectFileGenerator`: _redirects, vercel.json, nginx map (리다이렉트)
+//! - `ImageProcessor`: 이미지 크기별 변형, AVIF/WebP (`ImageBlock`의 srcset, 기본 인코더는 `image` 기능)
+//! - `OgImageGenerator`: 페이지별 공유 미리보기 이미지 (`og:image`)
+//! - `StyleBundler`: Block 범위 CSS 묶음 (`assets/blocks.<지문>.css`)
+//! - `IconSpriteGenerator`: 사용된 아이콘의 스프라이트 시트 (`icons.svg`)
+//! - `MathScriptGenerator`: 수식 초기화 스크립트 (`assets/math.js`)
+//! - `HeadingNavGenerator`: 목차 스크롤 추적, 제목 고정 링크 (`assets/heading-nav.js`, `SiteConfig::heading_nav`)
+//! - `TailwindManifest`: tailwind-classes.txt (Tailwind JIT 입력, `tailwind` 기능)
 //!
 //! ## 전역 기능
 //!
@@ -156,4 +176,50 @@
 //!
 
 
+pub mod analysis;
+pub mod analytics;
+pub mod archive;
+pub mod bundle;
+pub mod citations;
+#[allow(clippy::module_inception)]
 pub mod cite;
+pub mod clock;
+pub mod collection;
+pub mod compress;
+pub mod config;
+#[cfg(feature = "deploy")]
+pub mod deploy;
+pub mod dependency;
+pub mod error;
+pub mod feed;
+pub mod formats;
+pub mod glossary;
+pub mod heading_nav;
+pub mod i18n;
+pub mod icons;
+pub mod image;
+pub mod incremental;
+pub mod index;
+pub mod lastmod;
+pub mod link;
+#[cfg(feature = "link-check")]
+pub mod link_check;
+pub mod math;
+pub mod mime;
+pub mod og_image;
+pub mod outline;
+pub mod paginate;
+pub mod plugin;
+pub mod redirects;
+pub mod report;
+#[cfg(any(feature = "axum", feature = "actix"))]
+pub mod response;
+pub mod search;
+pub mod sitemap;
+pub mod styles;
+#[cfg(feature = "tailwind")]
+pub mod tailwind;
+pub mod taxonomy;
+pub mod vfs;
+pub mod visitor;
+pub mod writer;
@@ -0,0 +1,180 @@
+//! # outline.rs - OutlineValidator
+//!
+//! ## 목적
+//! 페이지마다 제목(h1~h6) 구조를 검사합니다. `Heading`의 단계는 타입으로 1~6에 묶이지만,
+//! 문서 전체의 개요 규칙(h1 하나, 단계 건너뛰지 않기)은 페이지를 다 그려 봐야 알 수 있습니다.
+//! 결과(`HeadingOutlines`)는 `SiteIndex`에 남고, Site가 페이지 검사 단계에서 `BuildReport`의 경고
+//! (`WarningKind::HeadingOutline`)로 옮깁니다.
+//!
+//! ## 사용 예시
+//! ```rust
+//! site.register_visitor(OutlineValidator::new());
+//! let report = site.build_output()?;
+//! // blog/post.html: heading outline: skipped_level: h4 "Details" follows h2 (expected h3 or higher)
+//! ```
+//!
+//! ## 검사 규칙
+//! - `missing_h1`: h1이 없음
+//! - `multiple_h1`: h1이 둘 이상
+//! - `skipped_level`: 앞 제목보다 두 단계 이상 깊음 (h2 다음 h4). 얕아지는 것은 허용
+//! - `empty_heading`: 텍스트가 없는 제목
+//!
+//! ## 설계 결정
+//! - **페이지 본문(`Page::layout`) 기준**: 페이지가 직접 그리는 제목만 검사합니다. 공유 Layout의
+//!   사이트 머리글은 모든 페이지에 같으므로 페이지마다 경고하지 않습니다.
+//! - **경고로 보고**: 개요 위반은 접근성 품질 문제이고 출력은 정상이므로 빌드를 멈추지 않습니다.
+//! - **오류 페이지와 리다이렉트는 제외**: 검색 색인과 보조 기술의 탐색 대상이 아닙니다.
+
+use std::{collections::BTreeMap, fmt::Display};
+
+use serde::Serialize;
+
+use crate::cite::visitor::{SiteContext, Visitor, VisitorResult};
+use crate::html::node::{Element, IRNode};
+use crate::html::renderer::{Renderer, TextRenderer};
+use crate::metadata::builder::Metadata;
+use crate::metadata::types::Redirect;
+use crate::metadata::value::MetadataValue;
+use crate::page::page::{Page, page_context};
+use crate::page::slug::is_error_page_path;
+
+/// 개요 규칙 종류.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OutlineRule {
+    MissingH1,
+    MultipleH1,
+    SkippedLevel,
+    EmptyHeading,
+}
+
+impl OutlineRule {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OutlineRule::MissingH1 => "missing_h1",
+            OutlineRule::MultipleH1 => "multiple_h1",
+            OutlineRule::SkippedLevel => "skipped_level",
+            OutlineRule::EmptyHeading => "empty_heading",
+        }
+    }
+}
+
+/// 위반 하나.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct OutlineViolation {
+    pub rule: OutlineRule,
+    pub message: String,
+}
+
+impl Display for OutlineViolation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.rule.as_str(), self.message)
+    }
+}
+
+/// 페이지 URL(사이트 루트 기준) → 위반 (문서 순서). 위반이 없는 페이지는 담지 않습니다.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HeadingOutlines(pub BTreeMap<String, Vec<OutlineViolation>>);
+impl MetadataValue for HeadingOutlines {}
+
+impl HeadingOutlines {
+    pub fn get(&self, url: &str) -> Option<&[OutlineViolation]> {
+        self.0.get(url).map(Vec::as_slice)
+    }
+}
+
+/// 제목 구조를 검사하는 방문자.
+#[derive(Debug, Clone, Default)]
+pub struct OutlineValidator {
+    outlines: HeadingOutlines,
+}
+
+impl OutlineValidator {
+    pub fn new() -> Self {
+        OutlineValidator::default()
+    }
+}
+
+impl Visitor for OutlineValidator {
+    fn visit_page(&mut self, page: &dyn Page, ctx: &SiteContext) {
+        if is_error_page_path(&page.path()) || ctx.metadata.contains::<Redirect>() {
+            return;
+        }
+        let base = ctx.metadata.merge(Metadata::new().custom(ctx.index.clone()).map());
+        let render_ctx = page_context(page, &base);
+        let mut headings = Vec::new();
+        collect_headings(&page.layout(&render_ctx), &mut headings);
+
+        let violations = check_outline(&headings);
+        if !violations.is_empty() {
+            self.outlines.0.insert(ctx.url.clone(), violations);
+        }
+    }
+
+    fn finalize(&self) -> VisitorResult {
+        VisitorResult::new().custom(self.outlines.clone())
+    }
+}
+
+/// 제목 목록 (단계, 공백을 하나로 줄인 텍스트)의 위반.
+pub fn check_outline(headings: &[(u8, String)]) -> Vec<OutlineViolation> {
+    let mut violations = Vec::new();
+    let h1s = headings.iter().filter(|(level, _)| *level == 1).count();
+    match h1s {
+        0 => violations.push(OutlineViolation {
+            rule: OutlineRule::MissingH1,
+            message: "page has no h1".to_string(),
+        }),
+        1 => {}
+        n => violations.push(OutlineViolation {
+            rule: OutlineRule::MultipleH1,
+            message: format!("page has {} h1 headings (expected 1)", n),
+        }),
+    }
+
+    let mut previous = 1;
+    for (level, text) in headings {
+        if text.is_empty() {
+            violations.push(OutlineViolation {
+                rule: OutlineRule::EmptyHeading,
+                message: format!("h{} has no text", level),
+            });
+        }
+        if *level > previous + 1 {
+            violations.push(OutlineViolation {
+                rule: OutlineRule::SkippedLevel,
+                message: format!(
+                    "h{} \"{}\" follows h{} (expected h{} or higher)",
+                    level,
+                    text,
+                    previous,
+                    previous + 1
+                ),
+            });
+        }
+        previous = *level;
+    }
+    violations
+}
+
+/// IR 트리의 h1~h6을 문서 순서로 수집.
+fn collect_headings(node: &IRNode, headings: &mut Vec<(u8, String)>) {
+    let level = match node.get_tag().as_str() {
+        "h1" => 1,
+        "h2" => 2,
+        "h3" => 3,
+        "h4" => 4,
+        "h5" => 5,
+        "h6" => 6,
+        _ => {
+            for child in node.get_childs() {
+                if let Element::Node(child) = child {
+                    collect_headings(child, headings);
+                }
+            }
+            return;
+        }
+    };
+    let text = node.accept(TextRenderer::new()).finalize().to_string();
+    headings.push((level, text.split_whitespace().collect::<Vec<_>>().join(" ")));
+}
@@ -0,0 +1,195 @@
+//! # paginate.rs - 컬렉션 페이지네이션
+//!
+//! ## 목적
+//! 임의의 항목 목록(글 목록, 프로젝트 목록 등)을 `per_page`개씩 나누고, 묶음마다
+//! 출력 경로와 `Paging`을 계산합니다. 묶음으로 만든 페이지에는 `Paging`이 주입되므로
+//! `Pagination` Block과 `HeadElements::paging`이 그대로 동작합니다.
+//!
+//! ## 사용 예시
+//! ```rust
+//! let posts: Vec<PostSummary> = load_posts();
+//! let chunks = paginate(&posts, 10).dir("blog").chunks(site.site_config());
+//! for chunk in chunks {
+//!     let page = SimplePage::new("Blog", &chunk.path)
+//!         .add_block(Box::new(PostList::new(chunk.items.clone())))
+//!         .add_block(Box::new(Pagination::new()));
+//!     site.register_page(chunk.page(Box::new(page)));
+//! }
+//! // blog/index.html, blog/page/2/index.html, blog/page/3/index.html, ...
+//! ```
+//!
+//! ## 설계 결정
+//! - **경로 규칙은 한 곳에서**: 1페이지는 `<dir>/index.html`, 이후는 `<dir>/page/N/index.html`.
+//!   분류 목록 페이지(`taxonomy`)도 같은 규칙(`page_path`)을 씁니다.
+//! - **빈 목록도 한 페이지**: 항목이 없어도 목록 페이지 자체는 있어야 하므로
+//!   항목 없는 1페이지를 만듭니다.
+//! - **경로는 묶음이 결정**: `PageChunk::page`로 감싼 페이지는 안쪽 페이지의 `path()` 대신
+//!   묶음의 경로를 씁니다 (`TaxonomyPage`와 같은 방식).
+
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use crate::block::block::Block;
+use crate::block::context::RenderContext;
+use crate::cite::config::SiteConfig;
+use crate::html::node::IRNode;
+use crate::metadata::builder::Metadata;
+use crate::metadata::types::{Paging, RenderTarget};
+use crate::page::head::HeadElements;
+use crate::page::layout::Layout;
+use crate::page::page::Page;
+
+/// 목록의 n페이지(1부터) 출력 경로 (출력 디렉토리 기준).
+pub fn page_path(dir: &Path, page: usize) -> PathBuf {
+    if page <= 1 {
+        dir.join("index.html")
+    } else {
+        dir.join("page").join(page.to_string()).join("index.html")
+    }
+}
+
+/// `collection`을 `per_page`개씩 나누는 페이지네이터. 기본 출력 디렉토리는 사이트 루트.
+pub fn paginate<T: Clone>(collection: &[T], per_page: usize) -> Paginator<T> {
+    Paginator {
+        items: collection.to_vec(),
+        per_page: per_page.max(1),
+        dir: String::new(),
+    }
+}
+
+/// 컬렉션 페이지네이션 설정.
+#[derive(Debug, Clone)]
+pub struct Paginator<T> {
+    items: Vec<T>,
+    per_page: usize,
+    dir: String,
+}
+
+impl<T: Clone> Paginator<T> {
+    /// 출력 디렉토리 (출력 디렉토리 기준). 예: `blog`
+    pub fn dir(mut self, dir: &str) -> Self {
+        self.dir = dir.trim_matches('/').to_string();
+        self
+    }
+
+    /// 전체 페이지 수. 빈 목록도 1.
+    pub fn total_pages(&self) -> usize {
+        self.items.len().div_ceil(self.per_page).max(1)
+    }
+
+    /// n페이지(1부터)의 출력 경로.
+    pub fn path(&self, page: usize) -> PathBuf {
+        page_path(Path::new(&self.dir), page)
+    }
+
+    /// 페이지별 묶음. URL은 `config`의 형식(`trailing_slash`)을 따릅니다.
+    pub fn chunks(&self, config: &SiteConfig) -> Vec<PageChunk<T>> {
+        let total_pages = self.total_pages();
+        let urls: Vec<String> = (1..=total_pages)
+            .map(|n| config.url_for(&self.path(n)))
+            .collect();
+        (1..=total_pages)
+            .map(|n| PageChunk {
+                items: self
+                    .items
+                    .iter()
+                    .skip((n - 1) * self.per_page)
+                    .take(self.per_page)
+                    .cloned()
+                    .collect(),
+                page: n,
+                total_pages,
+                path: self.path(n),
+                paging: Paging::new(n, urls.clone()),
+            })
+            .collect()
+    }
+
+    /// 묶음마다 `template`으로 페이지를 만들고 경로와 `Paging`을 입힙니다.
+    pub fn pages<F>(&self, config: &SiteConfig, template: F) -> Vec<Box<dyn Page>>
+    where
+        F: Fn(&PageChunk<T>) -> Box<dyn Page>,
+    {
+        self.chunks(config)
+            .into_iter()
+            .map(|chunk| {
+                let inner = template(&chunk);
+                chunk.page(inner)
+            })
+            .collect()
+    }
+}
+
+/// 목록 페이지 하나에 들어갈 항목과 위치.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PageChunk<T> {
+    pub items: Vec<T>,
+    /// 현재 페이지 번호 (1부터)
+    pub page: usize,
+    pub total_pages: usize,
+    /// 출력 경로 (출력 디렉토리 기준)
+    pub path: PathBuf,
+    pub paging: Option<Paging>,
+}
+
+impl<T> PageChunk<T> {
+    pub fn is_first(&self) -> bool {
+        self.page == 1
+    }
+
+    pub fn is_last(&self) -> bool {
+        self.page == self.total_pages
+    }
+
+    /// `inner`에 묶음의 경로와 `Paging` 메타데이터를 입힌 페이지.
+    pub fn page(&self, inner: Box<dyn Page>) -> Box<dyn Page> {
+        Box::new(PaginatedPage {
+            inner,
+            path: self.path.clone(),
+            paging: self.paging.clone(),
+        })
+    }
+}
+
+/// 페이지네이션 묶음으로 만든 페이지.
+struct PaginatedPage {
+    inner: Box<dyn Page>,
+    path: PathBuf,
+    paging: Option<Paging>,
+}
+
+impl Page for PaginatedPage {
+    fn layout(&self, ctx: &RenderContext) -> IRNode {
+        self.inner.layout(ctx)
+    }
+
+    fn metadata(&self) -> Metadata {
+        let base = match &self.paging {
+            Some(paging) => Metadata::new().custom(paging.clone()),
+            None => Metadata::new(),
+        };
+        base.merge(&self.inner.metadata())
+    }
+
+    fn path(&self) -> PathBuf {
+        self.path.clone()
+    }
+
+    fn head(&self, ctx: &RenderContext) -> HeadElements {
+        self.inner.head(ctx).paging(self.paging.as_ref())
+    }
+
+    fn layout_template(&self) -> Option<Arc<dyn Layout>> {
+        self.inner.layout_template()
+    }
+
+    fn blocks(&self) -> Vec<&dyn Block> {
+        self.inner.blocks()
+    }
+
+    fn alternate_targets(&self) -> Vec<RenderTarget> {
+        self.inner.alternate_targets()
+    }
+}
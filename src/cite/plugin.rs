@@ -0,0 +1,134 @@
+//! # plugin.rs - 빌드 단계 훅
+//!
+//! ## 목적
+//! `Visitor`를 구현하지 않고도 빌드의 정해진 지점에 끼어드는 확장 지점입니다.
+//! 분석 스크립트 삽입, 출력 링크 검사, CSS 후처리처럼 렌더링 결과나 기록한 파일을 다루는
+//! 기능을 별도 crate로 만들어 `Site::plugin`으로 연결합니다.
+//! 내장 플러그인: `Analytics` (방문 통계 스크립트 삽입, `analytics.rs`)
+//!
+//! ## 사용 예시
+//! ```rust
+//! struct StatsScript { id: String }
+//!
+//! impl Plugin for StatsScript {
+//!     fn name(&self) -> &str { "stats" }
+//!
+//!     fn after_page_render(&self, document: &mut RenderedPage, _hook: &mut PageHook) {
+//!         let script = format!("<script async src=\"https://stats.example.com/{}.js\"></script>", self.id);
+//!         let html = document.html.as_str().replacen("</head>", &format!("{}</head>", script), 1);
+//!         document.html = HtmlBlock::from_str(&html);
+//!     }
+//! }
+//!
+//! let site = Site::new().plugin(StatsScript { id: "abc".into() });
+//! ```
+//!
+//! ## 훅 (실행 순서)
+//! - `before_analysis`: 방문자 파이프라인 전에 한 번. 오류를 반환하면 빌드가 멈춥니다
+//! - `after_page_render`: 문서(대체 대상 포함)마다 한 번. HTML을 고치거나 경고를 남깁니다
+//! - `after_write`: 모든 파일을 기록한 뒤, 이전 빌드 파일 정리 전에 한 번 (`build`만)
+//!
+//! ## 설계 결정
+//! - **모든 훅은 선택**: 기본 구현은 아무것도 하지 않으므로 필요한 훅만 구현합니다.
+//! - **기록은 기록기로**: `after_write`가 `WriteHook::write`로 고친 파일도 미리 압축, 목록, 정리 대상에
+//!   그대로 들어갑니다. 출력 디렉토리에 직접 쓴 파일은 Site가 모릅니다.
+//! - **병렬 호출**: `after_page_render`는 렌더링 스레드에서 동시에 불리므로 플러그인은 `Send + Sync`입니다.
+//! - **증분 빌드**: 플러그인 이름은 지문에 들어가지만 설정은 들어가지 않습니다. 플러그인 설정을 바꾸면
+//!   `Site::template_version`을 올립니다.
+
+use std::{
+    error::Error,
+    io,
+    path::{Path, PathBuf},
+};
+
+use crate::cite::cite::{RenderedPage, Site};
+use crate::cite::index::SiteIndex;
+use crate::cite::writer::OutputWriter;
+use crate::metadata::map::ResolvedMetadata;
+
+/// 훅이 반환하는 오류. `BuildError::Plugin`으로 감싸집니다.
+pub type PluginError = Box<dyn Error + Send + Sync>;
+
+/// 빌드 단계 훅 묶음.
+pub trait Plugin: Send + Sync {
+    /// 오류와 경고에 표시할 이름.
+    fn name(&self) -> &str;
+
+    /// 분석 전. `build`와 `check`에서 한 번 호출됩니다.
+    fn before_analysis(&self, _site: &Site) -> Result<(), PluginError> {
+        Ok(())
+    }
+
+    /// 문서 하나를 렌더링한 직후. `document.html`을 바꾸면 바뀐 HTML이 기록됩니다.
+    fn after_page_render(&self, _document: &mut RenderedPage, _hook: &mut PageHook) {}
+
+    /// 모든 파일을 기록한 뒤.
+    fn after_write(&self, _hook: &mut WriteHook) -> Result<(), PluginError> {
+        Ok(())
+    }
+}
+
+/// `after_page_render`의 입력.
+pub struct PageHook<'a> {
+    pub index: &'a SiteIndex,
+    /// 페이지의 Site → Page 병합 메타데이터
+    pub metadata: &'a ResolvedMetadata,
+    pub(crate) warnings: Vec<String>,
+}
+
+impl<'a> PageHook<'a> {
+    pub(crate) fn new(index: &'a SiteIndex, metadata: &'a ResolvedMetadata) -> Self {
+        PageHook {
+            index,
+            metadata,
+            warnings: Vec::new(),
+        }
+    }
+
+    /// 문서에 대한 경고 (`WarningKind::Plugin`).
+    pub fn warn(&mut self, message: &str) {
+        self.warnings.push(message.to_string());
+    }
+}
+
+/// `after_write`의 입력.
+pub struct WriteHook<'a> {
+    pub index: &'a SiteIndex,
+    writer: &'a OutputWriter,
+    pub(crate) warnings: Vec<(PathBuf, String)>,
+}
+
+impl<'a> WriteHook<'a> {
+    pub(crate) fn new(index: &'a SiteIndex, writer: &'a OutputWriter) -> Self {
+        WriteHook {
+            index,
+            writer,
+            warnings: Vec::new(),
+        }
+    }
+
+    pub fn output_dir(&self) -> &Path {
+        self.writer.output_dir()
+    }
+
+    /// 이번 빌드가 기록하거나 유지한 파일 (출력 디렉토리 기준, 경로순). 압축 파일 포함.
+    pub fn files(&self) -> Vec<PathBuf> {
+        self.writer.files()
+    }
+
+    /// 출력 파일 읽기 (출력 디렉토리 기준 경로).
+    pub fn read(&self, path: &Path) -> io::Result<Vec<u8>> {
+        self.writer.fs().read(path)
+    }
+
+    /// 출력 파일 기록 (새 파일 또는 덮어쓰기). 압축 대상이면 압축 파일도 다시 만듭니다.
+    pub fn write(&self, path: &Path, contents: &[u8]) -> io::Result<()> {
+        self.writer.write(path, contents)
+    }
+
+    /// 파일에 대한 경고 (`WarningKind::Plugin`).
+    pub fn warn(&mut self, path: &Path, message: &str) {
+        self.warnings.push((path.to_path_buf(), message.to_string()));
+    }
+}
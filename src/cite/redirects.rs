@@ -0,0 +1,179 @@
+//! # redirects.rs - 호스트별 리다이렉트 파일
+//!
+//! ## 목적
+//! 등록된 리다이렉트 매핑(`Redirect`, 보통 `RedirectPage`)으로 호스팅 플랫폼이 읽는 설정 파일을 만듭니다.
+//! meta refresh 문서와 함께 출력되므로 서버가 파일을 지원하면 HTTP 301/302로, 아니면 HTML로 이동합니다.
+//!
+//! ## 사용 예시
+//! ```rust
+//! let config = SiteConfig::new("My Blog", "https://example.com")
+//!     .redirect_file(RedirectHost::Netlify)
+//!     .redirect_file(RedirectHost::Nginx);
+//! let mut site = Site::new().config(config);
+//! site.register_page(Box::new(RedirectPage::new("/old-post/", "/blog/new-post/")));
+//! site.build_output()?;  // dist/_redirects, dist/redirects.nginx.conf
+//! ```
+//!
+//! ## 렌더링 결과
+//! ```text
+//! # _redirects (Netlify, Cloudflare Pages)
+//! /old-post/  /blog/new-post/  301
+//! ```
+//! ```json
+//! // vercel.json
+//! {"redirects":[{"source":"/old-post/","destination":"/blog/new-post/","permanent":true}]}
+//! ```
+//! ```nginx
+//! # redirects.nginx.conf
+//! # Include in the http block. In the server block:
+//! #   if ($quo_redirect_permanent) { return 301 $quo_redirect_permanent; }
+//! #   if ($quo_redirect_temporary) { return 302 $quo_redirect_temporary; }
+//! map $uri $quo_redirect_permanent {
+//!     "/old-post/" "/blog/new-post/";
+//! }
+//! map $uri $quo_redirect_temporary {
+//! }
+//! ```
+//!
+//! ## 설계 결정
+//! - **SiteConfig로 선택**: 배포 대상은 사이트 설정이므로 피드처럼 `SiteConfig`에 두고,
+//!   Site가 `RedirectFileGenerator`를 자동으로 실행합니다.
+//! - **`from` 순 정렬**: 등록 순서와 무관하게 같은 사이트는 같은 파일을 만듭니다.
+//! - **출력 디렉토리에 기록**: nginx 설정도 다른 결과물과 함께 배포되도록 같은 곳에 둡니다.
+//!   공개되면 안 되는 경우 서버 설정에서 경로를 막습니다.
+
+use std::path::PathBuf;
+
+use serde::Serialize;
+
+use crate::cite::cite::Site;
+use crate::cite::visitor::{SiteContext, Visitor, VisitorResult};
+use crate::cite::writer::GeneratedFiles;
+use crate::metadata::types::Redirect;
+use crate::page::page::Page;
+
+/// 리다이렉트 파일을 읽는 호스팅 플랫폼.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RedirectHost {
+    /// `_redirects` (Netlify, Cloudflare Pages)
+    Netlify,
+    /// `vercel.json`의 `redirects`
+    Vercel,
+    /// nginx `map` 조각
+    Nginx,
+}
+
+impl RedirectHost {
+    /// 출력 파일 이름 (출력 디렉토리 기준).
+    pub fn file_name(&self) -> &'static str {
+        match self {
+            RedirectHost::Netlify => "_redirects",
+            RedirectHost::Vercel => "vercel.json",
+            RedirectHost::Nginx => "redirects.nginx.conf",
+        }
+    }
+
+    /// 매핑 목록으로 파일 내용 생성.
+    pub fn render(&self, redirects: &[Redirect]) -> String {
+        match self {
+            RedirectHost::Netlify => netlify(redirects),
+            RedirectHost::Vercel => vercel(redirects),
+            RedirectHost::Nginx => nginx(redirects),
+        }
+    }
+}
+
+/// `SiteConfig::redirect_hosts`의 파일을 만드는 방문자. 설정이 있으면 Site가 자동으로 실행합니다.
+#[derive(Debug, Clone, Default)]
+pub struct RedirectFileGenerator {
+    hosts: Vec<RedirectHost>,
+    redirects: Vec<Redirect>,
+}
+
+impl RedirectFileGenerator {
+    pub fn new() -> Self {
+        RedirectFileGenerator::default()
+    }
+}
+
+impl Visitor for RedirectFileGenerator {
+    fn visit_site(&mut self, site: &Site) {
+        self.hosts = site.site_config().redirect_hosts.clone();
+    }
+
+    fn visit_page(&mut self, _page: &dyn Page, ctx: &SiteContext) {
+        if let Some(redirect) = ctx.metadata.get::<Redirect>() {
+            self.redirects.push(redirect.clone());
+        }
+    }
+
+    fn finalize(&self) -> VisitorResult {
+        let mut redirects = self.redirects.clone();
+        redirects.sort();
+        redirects.dedup_by(|a, b| a.from == b.from);
+
+        let files = self.hosts.iter().fold(GeneratedFiles::new(), |files, host| {
+            files.file(PathBuf::from(host.file_name()), host.render(&redirects))
+        });
+        VisitorResult::new().mergeable(files)
+    }
+}
+
+fn status(redirect: &Redirect) -> u16 {
+    if redirect.permanent { 301 } else { 302 }
+}
+
+fn netlify(redirects: &[Redirect]) -> String {
+    // 공백은 필드 구분자이므로 인코딩
+    let encode = |s: &str| s.replace(' ', "%20");
+    redirects
+        .iter()
+        .map(|r| format!("{}  {}  {}\n", encode(&r.from), encode(&r.to), status(r)))
+        .collect()
+}
+
+#[derive(Serialize)]
+struct VercelRedirect<'a> {
+    source: &'a str,
+    destination: &'a str,
+    permanent: bool,
+}
+
+#[derive(Serialize)]
+struct VercelConfig<'a> {
+    redirects: Vec<VercelRedirect<'a>>,
+}
+
+fn vercel(redirects: &[Redirect]) -> String {
+    let config = VercelConfig {
+        redirects: redirects
+            .iter()
+            .map(|r| VercelRedirect {
+                source: &r.from,
+                destination: &r.to,
+                permanent: r.permanent,
+            })
+            .collect(),
+    };
+    serde_json::to_string(&config).unwrap_or_default() + "\n"
+}
+
+fn nginx(redirects: &[Redirect]) -> String {
+    let quote = |s: &str| format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""));
+    let map = |name: &str, permanent: bool| {
+        let entries: String = redirects
+            .iter()
+            .filter(|r| r.permanent == permanent)
+            .map(|r| format!("    {} {};\n", quote(&r.from), quote(&r.to)))
+            .collect();
+        format!("map $uri ${} {{\n{}}}\n", name, entries)
+    };
+    format!(
+        "# Include in the http block. In the server block:\n\
+         #   if ($quo_redirect_permanent) {{ return 301 $quo_redirect_permanent; }}\n\
+         #   if ($quo_redirect_temporary) {{ return 302 $quo_redirect_temporary; }}\n\
+         {}{}",
+        map("quo_redirect_permanent", true),
+        map("quo_redirect_temporary", false)
+    )
+}
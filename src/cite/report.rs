@@ -0,0 +1,326 @@
+//! # report.rs - 빌드 결과
+//!
+//! ## 목적
+//! `Site::build`가 성공했을 때 돌려주는 값입니다. 기록한 파일 목록(`BuildManifest`)과 통계
+//! (페이지 수, 쓴 바이트 수, 단계별 소요 시간, 문자열 공유), 빌드를 멈추지는 않지만 고쳐야 할 경고를 담습니다.
+//! 사람이 읽는 요약(`Display`)과 CI가 읽는 JSON(`to_json`, serde)을 모두 제공합니다.
+//!
+//! ## 사용 예시
+//! ```rust
+//! let report = site.build_output()?;
+//! println!("{}", report);  // 요약
+//! fs::write("build-report.json", report.to_json())?;
+//! if !report.warnings.is_empty() { /* CI 실패 처리 */ }
+//! ```
+//!
+//! ## 출력 결과
+//! ```text
+//! Built 12 pages (3 unchanged) into dist: 18 files, 240.5 KB written, 1 removed in 41 ms
+//!   analysis 12 ms, check 6 ms, render 20 ms, finish 3 ms
+//!   interned 84210 strings (1312 unique), 1.1 MB saved
+//! 2 warnings:
+//!   blog/post.html: image without alt text: /images/chart.png
+//!   archive.html: page is 1.4 MB (limit 1.0 MB)
+//! ```
+//!
+//! ## 경고 종류
+//! - `MissingAltText`: `alt` 속성이 없는 `<img>` (장식 이미지는 `alt=""`로 표시)
+//! - `LargePage`: 렌더링 결과가 `Site::page_size_limit`보다 큰 페이지
+//! - `InvalidHtml`: 문서 구조 위반 (`Site::validate_html`, `HtmlValidator`)
+//! - `UnresolvedLink`: 대상 페이지를 찾지 못한 `Link` (없는 `PageId`, 같은 타입의 페이지가 여럿)
+//! - `BrokenAnchor`: 대상 페이지에 없는 `id`를 가리키는 fragment 링크 (`AnchorPolicy::Warn`)
+//! - `HeadingOutline`: 제목 구조 위반 (`OutlineValidator`)
+//! - `UndefinedVar`: `SiteVars`에 정의되지 않은 변수 (`var!`)
+//! - `BlockFailed`: 검사에 실패해 오류 표시로 바뀐 Block (`BlockErrorMode::Lenient`)
+//! - `DeadLink`: 응답하지 않는 외부 링크 (`ExternalLinkChecker`, `link-check` 기능)
+//! - `Plugin`: 플러그인 훅이 남긴 경고 (`PageHook::warn`, `WriteHook::warn`)
+//!
+//! ## 사이트 정리 점검
+//! `Site::audit`(`quo check --audit`)는 경고와 별도로 `ContentAudit`을 돌려줍니다. 들어오는 링크가 없고
+//! 컬렉션에도 속하지 않는 페이지(`orphan_pages`)와 어느 문서도 참조하지 않는 asset(`unused_assets`)입니다.
+//! ```text
+//! 1 orphan page:
+//!   drafts/old-launch.html
+//! 2 unused assets:
+//!   fonts/legacy.woff
+//!   images/banner-2019.png
+//! ```
+//!
+//! ## 설계 결정
+//! - **경고는 오류와 분리**: 빌드를 실패시키는 문제는 `BuildError`, 결과물은 나오지만 품질 문제인 것은
+//!   경고입니다. 경고를 실패로 볼지는 사용자(CI)가 정합니다.
+//! - **시간은 밀리초로 직렬화**: `Duration`의 기본 직렬화(`secs`, `nanos`)보다 다루기 쉽습니다.
+//! - **경고 순서**: 페이지 등록 순서, 페이지 안에서는 발견 순서입니다.
+
+use std::{fmt::Display, path::PathBuf, time::Duration};
+
+use serde::{Serialize, Serializer};
+
+use crate::cite::outline::OutlineViolation;
+use crate::cite::writer::BuildManifest;
+use crate::html::fragment::FragmentStats;
+use crate::html::intern::InternStats;
+use crate::html::validate::Violation;
+use crate::metadata::format::Locale;
+
+/// 성공한 빌드의 결과.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct BuildReport {
+    /// 기록/유지/삭제한 파일
+    pub manifest: BuildManifest,
+    /// 출력한 페이지 수 (생성 페이지 포함)
+    pub pages: usize,
+    /// 그중 입력이 같아 렌더링을 건너뛴 페이지 수 (증분 빌드)
+    pub unchanged_pages: usize,
+    pub timings: BuildTimings,
+    /// 빌드 동안 `Content`/`AttrValue` 문자열 공유 통계
+    pub interning: InternStats,
+    /// 공유 조각(header, footer, nav) 캐시 통계
+    pub fragments: FragmentStats,
+    pub warnings: Vec<BuildWarning>,
+}
+
+/// 단계별 소요 시간.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct BuildTimings {
+    /// 방문자 파이프라인 (`SiteIndex` 생성)
+    #[serde(serialize_with = "millis")]
+    pub analysis: Duration,
+    /// 페이지 검사 (head, Block 구성, 링크)
+    #[serde(serialize_with = "millis")]
+    pub check: Duration,
+    /// 페이지 렌더링과 기록
+    #[serde(serialize_with = "millis")]
+    pub render: Duration,
+    /// 전역 파일 기록, 이전 빌드 파일 정리, 지문 저장
+    #[serde(serialize_with = "millis")]
+    pub finish: Duration,
+}
+
+impl BuildTimings {
+    pub fn total(&self) -> Duration {
+        self.analysis + self.check + self.render + self.finish
+    }
+}
+
+/// 단계 시간 측정. `wasm32-unknown-unknown`에는 시계가 없어 (`Instant::now`가 패닉) 항상 0입니다.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Stopwatch {
+    #[cfg(not(target_arch = "wasm32"))]
+    started: std::time::Instant,
+}
+
+impl Stopwatch {
+    pub(crate) fn start() -> Self {
+        Stopwatch {
+            #[cfg(not(target_arch = "wasm32"))]
+            started: std::time::Instant::now(),
+        }
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub(crate) fn elapsed(&self) -> Duration {
+        self.started.elapsed()
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    pub(crate) fn elapsed(&self) -> Duration {
+        Duration::ZERO
+    }
+}
+
+fn millis<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_f64(duration.as_secs_f64() * 1000.0)
+}
+
+/// 빌드 경고 하나.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct BuildWarning {
+    /// 출력 경로 (출력 디렉토리 기준)
+    pub page: PathBuf,
+    #[serde(flatten)]
+    pub kind: WarningKind,
+}
+
+/// 경고 종류.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum WarningKind {
+    /// `alt` 속성이 없는 이미지
+    MissingAltText { src: String },
+    /// 렌더링 결과가 기준보다 큰 페이지 (바이트)
+    LargePage { bytes: u64, limit: u64 },
+    /// 문서 구조 위반
+    InvalidHtml(Violation),
+    /// 대상 페이지를 찾지 못한 `Link` (`PageRef::label`)
+    UnresolvedLink { target: String },
+    /// 대상 페이지에 없는 `id`를 가리키는 fragment 링크
+    BrokenAnchor { href: String },
+    /// 제목 구조 위반
+    HeadingOutline(OutlineViolation),
+    /// `IconSet`에 등록되지 않은 아이콘
+    MissingIcon { name: String },
+    /// `References`에 등록되지 않은 인용 키
+    UnknownCitation { key: String },
+    /// `SiteVars`에 정의되지 않은 변수
+    UndefinedVar { name: String },
+    /// 검사에 실패해 오류 표시로 렌더링한 Block (`BlockErrorMode::Lenient`)
+    BlockFailed { block: String, message: String },
+    /// 응답하지 않는 외부 링크
+    #[cfg(feature = "link-check")]
+    DeadLink { url: String, reason: String },
+    /// 플러그인 경고
+    Plugin { plugin: String, message: String },
+}
+
+impl Display for BuildWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: ", self.page.display())?;
+        match &self.kind {
+            WarningKind::MissingAltText { src } => write!(f, "image without alt text: {}", src),
+            WarningKind::LargePage { bytes, limit } => {
+                write!(f, "page is {} (limit {})", file_size(*bytes), file_size(*limit))
+            }
+            WarningKind::InvalidHtml(violation) => write!(f, "{}", violation),
+            WarningKind::UnresolvedLink { target } => write!(f, "unresolved page link: {}", target),
+            WarningKind::BrokenAnchor { href } => write!(f, "broken anchor: {}", href),
+            WarningKind::HeadingOutline(violation) => write!(f, "heading outline: {}", violation),
+            WarningKind::MissingIcon { name } => write!(f, "unknown icon: {}", name),
+            WarningKind::UnknownCitation { key } => write!(f, "unknown citation key: {}", key),
+            WarningKind::UndefinedVar { name } => write!(f, "undefined site var: {}", name),
+            WarningKind::BlockFailed { block, message } => {
+                write!(f, "block failed, rendered error placeholder: {}: {}", block, message)
+            }
+            #[cfg(feature = "link-check")]
+            WarningKind::DeadLink { url, reason } => write!(f, "dead external link: {} ({})", url, reason),
+            WarningKind::Plugin { plugin, message } => write!(f, "{}: {}", plugin, message),
+        }
+    }
+}
+
+impl BuildReport {
+    /// 기계가 읽는 JSON.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap_or_default()
+    }
+}
+
+impl Display for BuildReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let manifest = &self.manifest;
+        write!(f, "Built {}", plural(self.pages, "page"))?;
+        if self.unchanged_pages > 0 {
+            write!(f, " ({} unchanged)", self.unchanged_pages)?;
+        }
+        write!(
+            f,
+            " into {}: {}, {} written",
+            manifest.output_dir.display(),
+            plural(manifest.files.len(), "file"),
+            file_size(manifest.bytes_written)
+        )?;
+        if !manifest.removed.is_empty() {
+            write!(f, ", {} removed", manifest.removed.len())?;
+        }
+        let t = &self.timings;
+        writeln!(f, " in {}", format_duration(t.total()))?;
+        write!(
+            f,
+            "  analysis {}, check {}, render {}, finish {}",
+            format_duration(t.analysis),
+            format_duration(t.check),
+            format_duration(t.render),
+            format_duration(t.finish)
+        )?;
+        let interning = &self.interning;
+        if interning.lookups > 0 {
+            write!(
+                f,
+                "\n  interned {} strings ({} unique), {} saved",
+                interning.lookups,
+                interning.unique,
+                file_size(interning.bytes_saved)
+            )?;
+        }
+        let fragments = &self.fragments;
+        if fragments.hits > 0 {
+            write!(
+                f,
+                "\n  reused {} of {} fragments, {} not re-rendered",
+                fragments.hits,
+                fragments.lookups,
+                file_size(fragments.bytes_reused)
+            )?;
+        }
+        if !self.warnings.is_empty() {
+            write!(f, "\n{}:", plural(self.warnings.len(), "warning"))?;
+            for warning in &self.warnings {
+                write!(f, "\n  {}", warning)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// 사이트 정리용 점검 결과 (`Site::audit`).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct ContentAudit {
+    /// 들어오는 링크가 없고 컬렉션에도 속하지 않는 페이지 (출력 경로, 등록 순서)
+    pub orphan_pages: Vec<PathBuf>,
+    /// 어느 문서도 참조하지 않는 asset (출력 경로순)
+    pub unused_assets: Vec<PathBuf>,
+}
+
+impl ContentAudit {
+    pub fn is_empty(&self) -> bool {
+        self.orphan_pages.is_empty() && self.unused_assets.is_empty()
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap_or_default()
+    }
+}
+
+impl Display for ContentAudit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_empty() {
+            return write!(f, "No orphan pages or unused assets");
+        }
+        let sections = [
+            (&self.orphan_pages, "orphan page"),
+            (&self.unused_assets, "unused asset"),
+        ];
+        let mut first = true;
+        for (paths, word) in sections {
+            if paths.is_empty() {
+                continue;
+            }
+            if !first {
+                writeln!(f)?;
+            }
+            first = false;
+            write!(f, "{}:", plural(paths.len(), word))?;
+            for path in paths {
+                write!(f, "\n  {}", path.display())?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn plural(n: usize, word: &str) -> String {
+    if n == 1 {
+        format!("1 {}", word)
+    } else {
+        format!("{} {}s", n, word)
+    }
+}
+
+/// `512 B`, `240.5 KB`, `1.4 MB`. 콘솔 출력이므로 사이트 언어와 관계없이 기본 형식.
+fn file_size(bytes: u64) -> String {
+    Locale::default().file_size(bytes)
+}
+
+fn format_duration(duration: Duration) -> String {
+    format!("{} ms", duration.as_millis())
+}
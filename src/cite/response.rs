@@ -0,0 +1,83 @@
+//! # response.rs - 웹 프레임워크 응답
+//!
+//! ## 목적
+//! 렌더링 결과(`HtmlBlock`, `RenderedPage`)를 axum/actix-web 핸들러에서 바로 반환합니다.
+//! 정적 생성기와 같은 Page/Block을 서버 쪽 템플릿으로도 쓸 수 있습니다.
+//!
+//! ## 사용 예시
+//! ```toml
+//! quo = { version = "0.1", features = ["axum"] }   # 또는 "actix"
+//! ```
+//!
+//! ```rust
+//! // axum
+//! async fn about() -> HtmlBlock {
+//!     render_document(&AboutPage, &site.page_metadata(&AboutPage))
+//! }
+//!
+//! // actix-web
+//! #[get("/about")]
+//! async fn about(site: web::Data<Site>) -> HtmlBlock { /* ... */ }
+//! ```
+//!
+//! ## 응답
+//! - 상태: `200 OK`
+//! - `Content-Type: text/html; charset=utf-8` (`HTML_CONTENT_TYPE`)
+//!
+//! ## 설계 결정
+//! - **기능 플래그**: 웹 프레임워크는 정적 빌드에 필요 없으므로 `axum`, `actix` 기능을 켤 때만 의존합니다.
+//!   axum은 `axum-core`만 의존하므로 axum 버전을 고정하지 않습니다.
+//! - **문서 타입은 HTML**: 인쇄용 등 대체 대상 문서도 HTML이므로 `RenderedPage`는 경로와 무관하게 HTML로 응답합니다.
+
+use crate::cite::cite::RenderedPage;
+use crate::html::trust::HtmlBlock;
+
+/// HTML 응답의 `Content-Type`.
+pub const HTML_CONTENT_TYPE: &str = "text/html; charset=utf-8";
+
+// ============================================================================
+// axum
+// ============================================================================
+
+#[cfg(feature = "axum")]
+impl axum_core::response::IntoResponse for HtmlBlock {
+    fn into_response(self) -> axum_core::response::Response {
+        let mut response = self.as_str().to_string().into_response();
+        response.headers_mut().insert(
+            http::header::CONTENT_TYPE,
+            http::HeaderValue::from_static(HTML_CONTENT_TYPE),
+        );
+        response
+    }
+}
+
+#[cfg(feature = "axum")]
+impl axum_core::response::IntoResponse for RenderedPage {
+    fn into_response(self) -> axum_core::response::Response {
+        self.html.into_response()
+    }
+}
+
+// ============================================================================
+// actix-web
+// ============================================================================
+
+#[cfg(feature = "actix")]
+impl actix_web::Responder for HtmlBlock {
+    type Body = actix_web::body::BoxBody;
+
+    fn respond_to(self, _req: &actix_web::HttpRequest) -> actix_web::HttpResponse<Self::Body> {
+        actix_web::HttpResponse::Ok()
+            .content_type(HTML_CONTENT_TYPE)
+            .body(self.as_str().to_string())
+    }
+}
+
+#[cfg(feature = "actix")]
+impl actix_web::Responder for RenderedPage {
+    type Body = actix_web::body::BoxBody;
+
+    fn respond_to(self, req: &actix_web::HttpRequest) -> actix_web::HttpResponse<Self::Body> {
+        self.html.respond_to(req)
+    }
+}
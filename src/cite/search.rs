@@ -0,0 +1,283 @@
+//! # search.rs - SearchIndexGenerator
+//!
+//! ## 목적
+//! 클라이언트 쪽 검색(lunr, Fuse.js 등)에 쓸 `search.json`을 만듭니다.
+//! 페이지마다 제목, 소제목(h1~h6), 본문 텍스트(`TextRenderer`), 태그를 담습니다.
+//!
+//! 검색창(`SearchBox`)을 쓰는 페이지가 있으면 그 검색 스크립트(`assets/search.js`)도 함께 기록합니다.
+//!
+//! ## 사용 예시
+//! ```rust
+//! site.register_visitor(
+//!     SearchIndexGenerator::new()
+//!         .weight(SearchField::Title, 10.0)
+//!         .weight(SearchField::Tags, 0.0)      // 0이면 필드 제외
+//!         .stop_words(&["the", "a", "그리고"]),
+//! );
+//! ```
+//!
+//! ```js
+//! const { fields, documents } = await (await fetch("/search.json")).json();
+//! // Fuse.js
+//! const fuse = new Fuse(documents, { keys: fields });
+//! // lunr
+//! const idx = lunr(function () {
+//!   this.ref("id");
+//!   fields.forEach(f => this.field(f.name, { boost: f.weight }));
+//!   documents.forEach(d => this.add(d));
+//! });
+//! ```
+//!
+//! ## 렌더링 결과
+//! ```json
+//! {
+//!   "fields": [{ "name": "title", "weight": 10.0 }, { "name": "headings", "weight": 5.0 }, ...],
+//!   "documents": [
+//!     { "id": "/blog/first-post.html", "url": "/blog/first-post.html", "title": "First Post",
+//!       "headings": ["Intro", "Setup"], "body": "quo static site generator ...", "tags": ["rust"] }
+//!   ]
+//! }
+//! ```
+//!
+//! ## 설계 결정
+//! - **필드 가중치를 파일에 포함**: Fuse의 `keys`, lunr의 `boost`에 그대로 넘길 수 있어
+//!   클라이언트 코드가 설정을 중복하지 않습니다.
+//! - **불용어는 본문에서만 제거**: 제목과 소제목은 검색 결과 표시에 쓰이므로 원문을 유지합니다.
+//! - **사이트 루트 기준 URL**: 검색 페이지와 같은 사이트에서 쓰므로 절대 URL이 필요 없습니다.
+//! - **제외 대상**: 오류 페이지, 리다이렉트 페이지, `Unlisted`로 검색에서 뺀 페이지는 색인하지 않습니다.
+//! - **URL 순 정렬**: 등록 순서와 무관하게 같은 사이트는 같은 파일을 만듭니다.
+
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+
+use crate::block::block::render_block;
+use crate::cite::visitor::{SiteContext, Visitor, VisitorResult};
+use crate::cite::writer::GeneratedFiles;
+use crate::html::node::{Element, IRNode};
+use crate::html::renderer::{Renderer, TextRenderer};
+use crate::metadata::builder::Metadata;
+use crate::metadata::types::{Listing, Redirect, Tags, Title, Unlisted};
+use crate::page::assets::collect_assets;
+use crate::page::page::{Page, page_context};
+use crate::page::search::{SEARCH_SCRIPT, SEARCH_SCRIPT_PATH, SEARCH_SCRIPT_URL};
+use crate::page::slug::is_error_page_path;
+
+/// 기본 불용어 (영어).
+const DEFAULT_STOP_WORDS: &[&str] = &[
+    "a", "an", "and", "are", "as", "at", "be", "but", "by", "for", "if", "in", "into", "is", "it",
+    "no", "not", "of", "on", "or", "such", "that", "the", "their", "then", "there", "these",
+    "they", "this", "to", "was", "will", "with",
+];
+
+/// 검색 필드.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchField {
+    Title,
+    Headings,
+    Body,
+    Tags,
+}
+
+impl SearchField {
+    const ALL: [SearchField; 4] = [
+        SearchField::Title,
+        SearchField::Headings,
+        SearchField::Body,
+        SearchField::Tags,
+    ];
+
+    fn name(&self) -> &'static str {
+        match self {
+            SearchField::Title => "title",
+            SearchField::Headings => "headings",
+            SearchField::Body => "body",
+            SearchField::Tags => "tags",
+        }
+    }
+
+    fn default_weight(&self) -> f32 {
+        match self {
+            SearchField::Title => 10.0,
+            SearchField::Headings => 5.0,
+            SearchField::Tags => 3.0,
+            SearchField::Body => 1.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct FieldWeight {
+    name: &'static str,
+    weight: f32,
+}
+
+/// 검색 문서 하나. 가중치가 0인 필드는 출력하지 않습니다.
+#[derive(Debug, Clone, Serialize)]
+struct SearchDocument {
+    id: String,
+    url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    headings: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    body: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tags: Option<Vec<String>>,
+}
+
+#[derive(Serialize)]
+struct SearchIndexFile<'a> {
+    fields: Vec<FieldWeight>,
+    documents: &'a [SearchDocument],
+}
+
+/// `search.json`을 만드는 방문자.
+#[derive(Debug, Clone)]
+pub struct SearchIndexGenerator {
+    path: PathBuf,
+    weights: Vec<(SearchField, f32)>,
+    stop_words: BTreeSet<String>,
+    documents: Vec<SearchDocument>,
+    /// `SearchBox`를 쓰는 페이지가 있는지
+    search_box: bool,
+}
+
+impl Default for SearchIndexGenerator {
+    fn default() -> Self {
+        SearchIndexGenerator {
+            path: PathBuf::from("search.json"),
+            weights: SearchField::ALL.iter().map(|f| (*f, f.default_weight())).collect(),
+            stop_words: DEFAULT_STOP_WORDS.iter().map(|w| w.to_string()).collect(),
+            documents: Vec::new(),
+            search_box: false,
+        }
+    }
+}
+
+impl SearchIndexGenerator {
+    pub fn new() -> Self {
+        SearchIndexGenerator::default()
+    }
+
+    /// 출력 경로 (출력 디렉토리 기준). 기본: `search.json`
+    pub fn path<P: AsRef<Path>>(mut self, path: P) -> Self {
+        self.path = path.as_ref().to_path_buf();
+        self
+    }
+
+    /// 필드 가중치. 0 이하면 필드를 색인에서 제외합니다.
+    /// 기본: 제목 10, 소제목 5, 태그 3, 본문 1
+    pub fn weight(mut self, field: SearchField, weight: f32) -> Self {
+        for (f, w) in self.weights.iter_mut() {
+            if *f == field {
+                *w = weight;
+            }
+        }
+        self
+    }
+
+    /// 본문에서 제거할 불용어 (기본 영어 목록을 대체). 대소문자 무시.
+    pub fn stop_words(mut self, words: &[&str]) -> Self {
+        self.stop_words = words.iter().map(|w| w.to_lowercase()).collect();
+        self
+    }
+
+    fn enabled(&self, field: SearchField) -> bool {
+        self.weights.iter().any(|(f, w)| *f == field && *w > 0.0)
+    }
+
+    /// 불용어를 뺀 본문. 공백은 하나로 줄입니다.
+    fn filter_stop_words(&self, text: &str) -> String {
+        text.split_whitespace()
+            .filter(|word| {
+                let bare = word.trim_matches(|c: char| !c.is_alphanumeric()).to_lowercase();
+                !bare.is_empty() && !self.stop_words.contains(&bare)
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+impl Visitor for SearchIndexGenerator {
+    fn visit_page(&mut self, page: &dyn Page, ctx: &SiteContext) {
+        self.search_box |= collect_assets(page).scripts.iter().any(|src| src == SEARCH_SCRIPT_URL);
+        let metadata = &ctx.metadata;
+        if is_error_page_path(&page.path())
+            || metadata.contains::<Redirect>()
+            || metadata.get::<Unlisted>().is_some_and(|u| u.excludes(Listing::Search))
+        {
+            return;
+        }
+
+        let base = metadata.merge(Metadata::new().custom(ctx.index.clone()).map());
+        let render_ctx = page_context(page, &base);
+        let mut headings = Vec::new();
+        let mut body = String::new();
+        for block in page.blocks() {
+            let ir = render_block(block, &render_ctx);
+            collect_headings(&ir, &mut headings);
+            body.push_str(ir.accept(TextRenderer::new()).finalize());
+            body.push('\n');
+        }
+
+        self.documents.push(SearchDocument {
+            id: ctx.url.clone(),
+            url: ctx.url.clone(),
+            title: self
+                .enabled(SearchField::Title)
+                .then(|| metadata.get::<Title>().map(|t| t.0.clone()))
+                .flatten(),
+            headings: self.enabled(SearchField::Headings).then_some(headings),
+            body: self
+                .enabled(SearchField::Body)
+                .then(|| self.filter_stop_words(&body)),
+            tags: self
+                .enabled(SearchField::Tags)
+                .then(|| metadata.get::<Tags>().map(|t| t.0.clone()).unwrap_or_default()),
+        });
+    }
+
+    fn finalize(&self) -> VisitorResult {
+        let mut documents = self.documents.clone();
+        documents.sort_by(|a, b| a.url.cmp(&b.url));
+        let file = SearchIndexFile {
+            fields: self
+                .weights
+                .iter()
+                .filter(|(_, weight)| *weight > 0.0)
+                .map(|(field, weight)| FieldWeight {
+                    name: field.name(),
+                    weight: *weight,
+                })
+                .collect(),
+            documents: &documents,
+        };
+        let json = serde_json::to_string(&file).unwrap_or_default();
+        let files = GeneratedFiles::new().file(&self.path, json);
+        let files = match self.search_box {
+            true => files.file(SEARCH_SCRIPT_PATH, SEARCH_SCRIPT.to_string()),
+            false => files,
+        };
+        VisitorResult::new().mergeable(files)
+    }
+}
+
+/// IR 트리의 h1~h6 텍스트를 문서 순서로 수집.
+fn collect_headings(node: &IRNode, headings: &mut Vec<String>) {
+    let tag = node.get_tag().as_str();
+    if matches!(tag, "h1" | "h2" | "h3" | "h4" | "h5" | "h6") {
+        let text = node.accept(TextRenderer::new()).finalize().trim().to_string();
+        if !text.is_empty() {
+            headings.push(text.split_whitespace().collect::<Vec<_>>().join(" "));
+        }
+        return;
+    }
+    for child in node.get_childs() {
+        if let Element::Node(child) = child {
+            collect_headings(child, headings);
+        }
+    }
+}
@@ -0,0 +1,233 @@
+//! # sitemap.rs - SitemapGenerator
+//!
+//! ## 목적
+//! 출력 대상 페이지로 [sitemaps.org](https://www.sitemaps.org/protocol.html) 규격의 `sitemap.xml`을 만듭니다.
+//! URL이 파일당 한도(50,000개)를 넘으면 `sitemap-1.xml`, `sitemap-2.xml`, ...로 나누고
+//! `sitemap.xml`은 이 파일들을 가리키는 사이트맵 인덱스가 됩니다.
+//!
+//! ## 사용 예시
+//! ```rust
+//! let mut site = Site::new().config(SiteConfig::new("My Blog", "https://example.com"));
+//! site.register_visitor(SitemapGenerator::new().changefreq(ChangeFreq::Weekly));
+//!
+//! // 페이지별 지정
+//! let meta = Metadata::new()
+//!     .custom(ChangeFreq::Daily)
+//!     .custom(SitemapPriority(0.8));
+//!
+//! site.build_output()?;  // dist/sitemap.xml
+//! ```
+//!
+//! ## 렌더링 결과
+//! ```xml
+//! <?xml version="1.0" encoding="UTF-8"?>
+//! <urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+//!   <url>
+//!     <loc>https://example.com/blog/first-post.html</loc>
+//!     <lastmod>2024-03-01</lastmod>
+//!     <changefreq>daily</changefreq>
+//!     <priority>0.8</priority>
+//!   </url>
+//! </urlset>
+//! ```
+//!
+//! ## 설계 결정
+//! - **절대 URL**: 규격상 `<loc>`은 절대 URL이어야 하므로 `SiteConfig::absolute_url`을 사용합니다.
+//! - **`lastmod`는 마지막 수정일**: 페이지 메타데이터의 `LastModified`(원본 파일 수정일, `lastmod.rs`)와
+//!   `PublishDate` 중 늦은 날짜를 씁니다. 둘 다 없으면 생략합니다.
+//! - **제외 대상**: 오류 페이지, 리다이렉트 페이지, 미리보기 빌드의 초안(`PreviewStatus`)은
+//!   색인 대상이 아니므로 넣지 않습니다. `Unlisted`로 sitemap에서 뺀 페이지도 넣지 않습니다.
+//! - **URL 순 정렬**: 등록 순서와 무관하게 같은 사이트는 같은 파일을 만듭니다.
+
+use std::fmt::Display;
+
+use crate::cite::config::SiteConfig;
+use crate::cite::cite::Site;
+use crate::cite::visitor::{SiteContext, Visitor, VisitorResult};
+use crate::cite::writer::GeneratedFiles;
+use crate::html::trust::escape_html_chars;
+use crate::metadata::types::{Date, LastModified, Listing, PublishDate, Redirect, Unlisted};
+use crate::metadata::value::MetadataValue;
+use crate::page::page::Page;
+use crate::page::preview::PreviewStatus;
+use crate::page::slug::is_error_page_path;
+
+/// 사이트맵 파일 하나에 넣을 수 있는 최대 URL 수 (규격 한도).
+pub const MAX_URLS_PER_SITEMAP: usize = 50_000;
+
+/// 페이지 변경 빈도 힌트 (`<changefreq>`). 페이지 메타데이터로 지정하면 기본값보다 우선합니다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeFreq {
+    Always,
+    Hourly,
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+    Never,
+}
+impl MetadataValue for ChangeFreq {}
+
+impl Display for ChangeFreq {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let value = match self {
+            ChangeFreq::Always => "always",
+            ChangeFreq::Hourly => "hourly",
+            ChangeFreq::Daily => "daily",
+            ChangeFreq::Weekly => "weekly",
+            ChangeFreq::Monthly => "monthly",
+            ChangeFreq::Yearly => "yearly",
+            ChangeFreq::Never => "never",
+        };
+        write!(f, "{}", value)
+    }
+}
+
+/// 사이트 안에서의 상대 우선순위 (`<priority>`, 0.0 ~ 1.0). 범위 밖의 값은 잘라냅니다.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SitemapPriority(pub f32);
+impl MetadataValue for SitemapPriority {}
+
+/// 사이트맵 항목 하나.
+#[derive(Debug, Clone, PartialEq)]
+struct SitemapEntry {
+    loc: String,
+    lastmod: Option<Date>,
+    changefreq: Option<ChangeFreq>,
+    priority: Option<f32>,
+}
+
+/// `sitemap.xml`을 만드는 방문자.
+#[derive(Debug, Clone)]
+pub struct SitemapGenerator {
+    changefreq: Option<ChangeFreq>,
+    priority: Option<f32>,
+    max_urls: usize,
+    config: SiteConfig,
+    entries: Vec<SitemapEntry>,
+}
+
+impl Default for SitemapGenerator {
+    fn default() -> Self {
+        SitemapGenerator {
+            changefreq: None,
+            priority: None,
+            max_urls: MAX_URLS_PER_SITEMAP,
+            config: SiteConfig::default(),
+            entries: Vec::new(),
+        }
+    }
+}
+
+impl SitemapGenerator {
+    pub fn new() -> Self {
+        SitemapGenerator::default()
+    }
+
+    /// 페이지가 지정하지 않았을 때의 변경 빈도.
+    pub fn changefreq(mut self, changefreq: ChangeFreq) -> Self {
+        self.changefreq = Some(changefreq);
+        self
+    }
+
+    /// 페이지가 지정하지 않았을 때의 우선순위.
+    pub fn priority(mut self, priority: f32) -> Self {
+        self.priority = Some(priority);
+        self
+    }
+
+    /// 파일당 최대 URL 수. 규격 한도(50,000)보다 크게 지정할 수 없습니다.
+    pub fn max_urls(mut self, max_urls: usize) -> Self {
+        self.max_urls = max_urls.clamp(1, MAX_URLS_PER_SITEMAP);
+        self
+    }
+
+    fn urlset(&self, entries: &[SitemapEntry]) -> String {
+        let mut xml = String::from(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+             <urlset xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\">\n",
+        );
+        for entry in entries {
+            xml.push_str("  <url>\n");
+            xml.push_str(&format!("    <loc>{}</loc>\n", escape_html_chars(&entry.loc)));
+            if let Some(date) = entry.lastmod {
+                xml.push_str(&format!("    <lastmod>{}</lastmod>\n", date));
+            }
+            if let Some(changefreq) = entry.changefreq {
+                xml.push_str(&format!("    <changefreq>{}</changefreq>\n", changefreq));
+            }
+            if let Some(priority) = entry.priority {
+                xml.push_str(&format!("    <priority>{:.1}</priority>\n", priority.clamp(0.0, 1.0)));
+            }
+            xml.push_str("  </url>\n");
+        }
+        xml.push_str("</urlset>\n");
+        xml
+    }
+
+    fn sitemap_index(&self, files: &[(String, Option<Date>)]) -> String {
+        let mut xml = String::from(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+             <sitemapindex xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\">\n",
+        );
+        for (file, lastmod) in files {
+            xml.push_str("  <sitemap>\n");
+            xml.push_str(&format!(
+                "    <loc>{}</loc>\n",
+                escape_html_chars(&self.config.absolute_url(file))
+            ));
+            if let Some(date) = lastmod {
+                xml.push_str(&format!("    <lastmod>{}</lastmod>\n", date));
+            }
+            xml.push_str("  </sitemap>\n");
+        }
+        xml.push_str("</sitemapindex>\n");
+        xml
+    }
+}
+
+impl Visitor for SitemapGenerator {
+    fn visit_site(&mut self, site: &Site) {
+        self.config = site.site_config().clone();
+    }
+
+    fn visit_page(&mut self, page: &dyn Page, ctx: &SiteContext) {
+        let metadata = &ctx.metadata;
+        if is_error_page_path(&page.path())
+            || metadata.contains::<Redirect>()
+            || metadata.contains::<PreviewStatus>()
+            || metadata.get::<Unlisted>().is_some_and(|u| u.excludes(Listing::Sitemap))
+        {
+            return;
+        }
+        self.entries.push(SitemapEntry {
+            loc: self.config.absolute_url(&ctx.url),
+            lastmod: metadata
+                .get::<LastModified>()
+                .map(|d| d.0)
+                .max(metadata.get::<PublishDate>().map(|d| d.0)),
+            changefreq: metadata.get::<ChangeFreq>().copied().or(self.changefreq),
+            priority: metadata.get::<SitemapPriority>().map(|p| p.0).or(self.priority),
+        });
+    }
+
+    fn finalize(&self) -> VisitorResult {
+        let mut entries = self.entries.clone();
+        entries.sort_by(|a, b| a.loc.cmp(&b.loc));
+
+        let files = if entries.len() <= self.max_urls {
+            GeneratedFiles::new().file("sitemap.xml", self.urlset(&entries))
+        } else {
+            let mut files = GeneratedFiles::new();
+            let mut index = Vec::new();
+            for (i, chunk) in entries.chunks(self.max_urls).enumerate() {
+                let name = format!("sitemap-{}.xml", i + 1);
+                let lastmod = chunk.iter().filter_map(|e| e.lastmod).max();
+                files = files.file(&name, self.urlset(chunk));
+                index.push((name, lastmod));
+            }
+            files.file("sitemap.xml", self.sitemap_index(&index))
+        };
+        VisitorResult::new().mergeable(files)
+    }
+}
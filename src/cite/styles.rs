@@ -0,0 +1,135 @@
+//! # styles.rs - StyleBundler
+//!
+//! ## 목적
+//! Block이 제공한 범위 CSS(`Block::css`)를 사이트 전체에서 모아 스타일시트 하나로 기록합니다.
+//! 내장 Block(VBox, HBox 등)의 CSS를 손으로 관리하지 않아도 되고, 사용하는 Block만 포함됩니다.
+//! 결과(`BlockStyles`)는 `SiteIndex`에 남고, Site가 그 Block을 쓰는 페이지의 `<head>`에 연결합니다.
+//!
+//! ## 출력 결과
+//! ```text
+//! dist/assets/blocks.3f9a1c0d5e7b2a64.css
+//! ```
+//! ```html
+//! <link rel="stylesheet" href="/assets/blocks.3f9a1c0d5e7b2a64.css">
+//! ```
+//!
+//! ## 구현 상태
+//! - [x] 페이지 본문과 Layout 체인의 Block 수집 (자식 포함)
+//! - [x] 범위 class 기준 중복 제거, class 순 정렬
+//! - [x] 내용 지문이 붙은 파일 이름 (`assets/blocks.<지문>.css`)
+//! - [x] `CacheBusting::Query`면 고정 이름 (`assets/blocks.css?v=<지문>`)
+//! - [ ] TODO: 페이지별 분할 (첫 화면에 필요한 규칙만 critical CSS로)
+//!
+//! ## 설계 결정
+//! - **기본 방문자**: 범위 CSS가 없으면 파일도 link도 만들지 않으므로 항상 실행합니다.
+//! - **사이트 전체에 한 파일**: 페이지마다 나누면 페이지를 옮길 때마다 다시 받아야 합니다. 한 파일은
+//!   한 번 받으면 모든 페이지에서 캐시를 씁니다. 내용이 바뀌면 파일 이름이 바뀝니다.
+//! - **사용하는 페이지에만 연결**: 범위 CSS를 가진 Block이 없는 페이지(리다이렉트 등)는 link를 넣지 않습니다.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::block::block::Block;
+use crate::block::css::ScopedCss;
+use crate::cite::cite::Site;
+use crate::cite::config::CacheBusting;
+use crate::cite::incremental::FingerprintHasher;
+use crate::cite::visitor::{PageContext, SiteContext, Visitor, VisitorResult};
+use crate::cite::writer::GeneratedFiles;
+use crate::metadata::value::MetadataValue;
+use crate::page::layout::layout_chain;
+use crate::page::page::Page;
+
+/// 묶은 스타일시트와 그것을 쓰는 페이지.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BlockStyles {
+    /// 스타일시트 URL (사이트 루트 기준). 예: `/assets/blocks.3f9a1c0d5e7b2a64.css`
+    pub href: String,
+    /// 범위 CSS를 가진 Block을 쓰는 페이지 URL
+    pub pages: BTreeSet<String>,
+}
+impl MetadataValue for BlockStyles {}
+
+impl BlockStyles {
+    /// 페이지에 연결할 스타일시트 URL.
+    pub fn href_for(&self, url: &str) -> Option<&str> {
+        self.pages.contains(url).then_some(self.href.as_str())
+    }
+}
+
+/// Block 범위 CSS를 모으는 방문자.
+#[derive(Debug, Clone, Default)]
+pub struct StyleBundler {
+    /// 범위 class → CSS
+    rules: BTreeMap<String, String>,
+    pages: BTreeSet<String>,
+    /// 파일 이름에 지문을 넣을지 (`SiteConfig::cache_busting`)
+    cache_busting: CacheBusting,
+}
+
+impl StyleBundler {
+    pub fn new() -> Self {
+        StyleBundler::default()
+    }
+
+    fn add(&mut self, url: &str, css: ScopedCss) {
+        self.rules.entry(css.class()).or_insert_with(|| css.css());
+        self.pages.insert(url.to_string());
+    }
+
+    /// Layout의 Block은 방문자 파이프라인이 순회하지 않으므로 직접 내려갑니다.
+    fn add_tree(&mut self, url: &str, block: &dyn Block) {
+        if let Some(css) = block.css() {
+            self.add(url, css);
+        }
+        for child in block.children() {
+            self.add_tree(url, child);
+        }
+    }
+
+    /// 묶은 스타일시트 (class 순).
+    pub fn stylesheet(&self) -> String {
+        self.rules.values().map(|css| format!("{}\n", css)).collect()
+    }
+}
+
+impl Visitor for StyleBundler {
+    fn visit_site(&mut self, site: &Site) {
+        self.cache_busting = site.site_config().cache_busting;
+    }
+
+    fn visit_page(&mut self, page: &dyn Page, ctx: &SiteContext) {
+        for layout in page.layout_template().map(layout_chain).unwrap_or_default() {
+            for block in layout.blocks() {
+                self.add_tree(&ctx.url, block);
+            }
+        }
+    }
+
+    fn visit_block(&mut self, block: &dyn Block, ctx: &PageContext) {
+        if let Some(css) = block.css() {
+            self.add(ctx.url, css);
+        }
+    }
+
+    fn finalize(&self) -> VisitorResult {
+        if self.rules.is_empty() {
+            return VisitorResult::new();
+        }
+        let stylesheet = self.stylesheet();
+        let path = match self.cache_busting {
+            CacheBusting::Filename => format!(
+                "assets/blocks.{}.css",
+                FingerprintHasher::new().with(&stylesheet).fingerprint()
+            ),
+            // 버전은 `asset_url`이 쿼리로 붙임
+            CacheBusting::Query => "assets/blocks.css".to_string(),
+        };
+        let styles = BlockStyles {
+            href: format!("/{}", path),
+            pages: self.pages.clone(),
+        };
+        VisitorResult::new()
+            .custom(styles)
+            .mergeable(GeneratedFiles::new().file(path, stylesheet))
+    }
+}
@@ -0,0 +1,154 @@
+//! # tailwind.rs - TailwindManifest
+//!
+//! ## 목적
+//! 출력 대상 페이지가 실제로 쓰는 class를 모아 `tailwind-classes.txt`로 기록합니다.
+//! Tailwind JIT는 소스 파일을 훑어 class를 찾는데, quo 사이트의 class는 Rust 코드와
+//! Markdown에서 조합되므로 정적 훑기로는 빠지는 것이 생깁니다. 이 파일을 입력으로 주면
+//! 빌드 결과와 같은 class 목록으로 CSS를 만듭니다.
+//!
+//! ## 사용 예시
+//! ```rust
+//! site.register_visitor(TailwindManifest::new().safelist(&["prose", "dark"]));
+//! site.build_output()?;  // dist/tailwind-classes.txt
+//!
+//! let report = site.index().get::<TailwindClasses>();  // 잘못된 유틸리티 class
+//! ```
+//!
+//! ```css
+//! /* Tailwind v4 */
+//! @source "../dist/tailwind-classes.txt";
+//! ```
+//!
+//! ## 출력 결과
+//! ```text
+//! bg-slate-100
+//! card
+//! hover:bg-slate-200
+//! md:p-8
+//! ```
+//!
+//! ## 설계 결정
+//! - **렌더링 결과에서 수집**: 링크 수집(`LinkResolver`)과 같은 방식입니다. 페이지 본문과
+//!   페이지의 Layout을 렌더링하여 `class` 속성을 모읍니다. `Site::default_layout`은
+//!   방문자에게 보이지 않으므로 그 class는 `safelist`로 넣습니다.
+//! - **한 줄에 class 하나, 이름순**: 같은 사이트는 같은 파일을 만듭니다.
+//! - **검사는 경고 수준**: `check_class`에 걸린 class도 목록에는 넣고 `TailwindClasses::invalid`에
+//!   페이지와 함께 모읍니다. 빌드를 멈출지는 사용자가 정합니다.
+
+use std::collections::BTreeSet;
+
+use crate::block::context::RenderContext;
+use crate::cite::visitor::{SiteContext, Visitor, VisitorResult};
+use crate::cite::writer::GeneratedFiles;
+use crate::html::attributes::AttrValues;
+use crate::html::node::{Element, IRNode};
+use crate::html::tailwind::{TailwindError, check_class};
+use crate::html::trust::{AttrKey, SafeString, unescape_html_chars};
+use crate::metadata::value::MetadataValue;
+use crate::page::page::Page;
+
+/// 기본 출력 파일 (출력 디렉토리 기준).
+pub const DEFAULT_MANIFEST_PATH: &str = "tailwind-classes.txt";
+
+/// 사이트에서 쓴 class와 잘못된 유틸리티 class.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TailwindClasses {
+    pub classes: BTreeSet<String>,
+    /// (페이지 URL, 오류). 페이지 등록 순서, 페이지 안에서는 이름순
+    pub invalid: Vec<(String, TailwindError)>,
+}
+impl MetadataValue for TailwindClasses {}
+
+/// class 목록 파일을 만드는 방문자.
+#[derive(Debug, Clone)]
+pub struct TailwindManifest {
+    path: String,
+    result: TailwindClasses,
+}
+
+impl Default for TailwindManifest {
+    fn default() -> Self {
+        TailwindManifest {
+            path: DEFAULT_MANIFEST_PATH.to_string(),
+            result: TailwindClasses::default(),
+        }
+    }
+}
+
+impl TailwindManifest {
+    pub fn new() -> Self {
+        TailwindManifest::default()
+    }
+
+    /// 출력 파일 경로 (출력 디렉토리 기준). 기본: `tailwind-classes.txt`
+    pub fn path(mut self, path: &str) -> Self {
+        self.path = path.trim_start_matches('/').to_string();
+        self
+    }
+
+    /// 렌더링 결과와 무관하게 항상 넣을 class (공유 Layout, 스크립트가 붙이는 class 등).
+    pub fn safelist(mut self, classes: &[&str]) -> Self {
+        self.result
+            .classes
+            .extend(classes.iter().map(|c| c.to_string()));
+        self
+    }
+}
+
+impl Visitor for TailwindManifest {
+    fn visit_page(&mut self, page: &dyn Page, ctx: &SiteContext) {
+        let render_ctx = RenderContext::new(ctx.metadata.clone());
+        let mut ir = page.layout(&render_ctx);
+        let mut layout = page.layout_template();
+        while let Some(current) = layout {
+            ir = current.wrap(ir, &render_ctx);
+            layout = current.parent();
+        }
+
+        let mut classes = BTreeSet::new();
+        collect_classes(&ir, &mut classes);
+        for class in &classes {
+            if let Err(err) = check_class(class) {
+                self.result.invalid.push((ctx.url.clone(), err));
+            }
+        }
+        self.result.classes.extend(classes);
+    }
+
+    fn finalize(&self) -> VisitorResult {
+        let contents: String = self
+            .result
+            .classes
+            .iter()
+            .map(|class| format!("{}\n", class))
+            .collect();
+        VisitorResult::new()
+            .custom(self.result.clone())
+            .mergeable(GeneratedFiles::new().file(&self.path, contents))
+    }
+}
+
+/// IR 트리의 모든 `class` 값 (이스케이프 전 원래 값).
+fn collect_classes(node: &IRNode, classes: &mut BTreeSet<String>) {
+    match node.get_attrs().get().get(&AttrKey::from_str("class")) {
+        Some(AttrValues::Set(set)) => {
+            classes.extend(set.iter().map(|c| unescape_html_chars(c.as_str())));
+        }
+        Some(AttrValues::List(list)) => {
+            classes.extend(list.iter().map(|c| unescape_html_chars(c.as_str())));
+        }
+        Some(AttrValues::Token(token)) => {
+            classes.extend(
+                unescape_html_chars(token.as_str())
+                    .split_whitespace()
+                    .map(str::to_string),
+            );
+        }
+        _ => {}
+    }
+    for child in node.get_childs() {
+        if let Element::Node(child) = child {
+            collect_classes(child, classes);
+        }
+    }
+}
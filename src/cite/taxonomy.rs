@@ -0,0 +1,333 @@
+//! # taxonomy.rs - 태그/카테고리 목록 페이지 자동 생성
+//!
+//! ## 목적
+//! 페이지 템플릿 하나로 태그(또는 카테고리)마다 목록 페이지를 만듭니다.
+//! 페이지 수가 `per_page`를 넘으면 여러 페이지로 나누고 `Paging`을 주입하므로
+//! `Pagination` Block이 그대로 동작합니다. 아카이브 페이지를 손으로 등록할 필요가 없습니다.
+//!
+//! ## 사용 예시
+//! ```rust
+//! let site = Site::new().taxonomy(
+//!     Taxonomy::tags(|listing: &TaxonomyListing| {
+//!         Box::new(
+//!             SimplePage::new(&format!("#{}", listing.term), &listing.path)
+//!                 .add_block(Box::new(TaxonomyList::new()))
+//!                 .add_block(Box::new(Pagination::new())),
+//!         ) as Box<dyn Page>
+//!     })
+//!     .dir("tags")
+//!     .per_page(10),
+//! );
+//! // tags/rust/index.html, tags/rust/page/2/index.html, tags/웹/index.html, ...
+//! ```
+//!
+//! ## 렌더링 결과 (`TaxonomyList`)
+//! ```html
+//! <ul class="taxonomy-list">
+//!   <li><a href="/blog/second.html">Second Post</a> <time datetime="2024-03-02">2024-03-02</time></li>
+//!   <li><a href="/blog/first.html">First Post</a> <time datetime="2024-03-01">2024-03-01</time></li>
+//! </ul>
+//! ```
+//! 언어가 있는 페이지의 날짜 텍스트는 `Locale` 형식입니다 (en: `March 2, 2024`).
+//!
+//! ## 설계 결정
+//! - **경로는 Site가 결정**: 템플릿이 돌려준 페이지의 `path()` 대신 `listing.path`를 씁니다.
+//!   템플릿은 내용만 책임지고, 페이지 번호별 경로 규칙(`page/N/`)은 한 곳에서 관리합니다.
+//! - **목록은 렌더링 시점에**: 페이지 생성은 분석 전에 메타데이터만으로 하고, 실제 항목은
+//!   `SiteIndex`의 컬렉션(발행일 최신순)에서 읽습니다 (`TaxonomyListing::entries`).
+//! - **오류 페이지처럼 분석 대상 아님**: 생성 페이지는 방문자 파이프라인을 거치지 않습니다.
+//! - **slug 충돌**: 두 태그의 slug가 같으면(`Rust`, `rust`) 뒤 태그에 `-2`, `-3`을 붙입니다.
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use crate::block::block::{Block, class_attrs, element, prose_rule, token_attrs};
+use crate::block::context::RenderContext;
+use crate::cite::config::SiteConfig;
+use crate::cite::index::{PageEntry, SiteIndex};
+use crate::cite::paginate::page_path;
+use crate::html::attributes::SharedAttrs;
+use crate::html::node::{Element, IRNode};
+use crate::html::trust::{Content, SafeString};
+use crate::metadata::builder::Metadata;
+use crate::metadata::format::Locale;
+use crate::metadata::map::ResolvedMetadata;
+use crate::metadata::types::{Categories, Paging, RenderTarget, Tags};
+use crate::metadata::value::MetadataValue;
+use crate::page::head::HeadElements;
+use crate::page::layout::Layout;
+use crate::page::page::Page;
+use crate::page::slug::Slug;
+
+/// 목록을 만들 분류.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TaxonomyKind {
+    Tag,
+    Category,
+}
+
+impl TaxonomyKind {
+    fn default_dir(&self) -> &'static str {
+        match self {
+            TaxonomyKind::Tag => "tags",
+            TaxonomyKind::Category => "categories",
+        }
+    }
+
+    fn terms(&self, metadata: &ResolvedMetadata) -> Vec<String> {
+        match self {
+            TaxonomyKind::Tag => metadata.get::<Tags>().map(|t| t.0.clone()),
+            TaxonomyKind::Category => metadata.get::<Categories>().map(|c| c.0.clone()),
+        }
+        .unwrap_or_default()
+    }
+}
+
+/// 목록 페이지 하나의 정보. 생성된 페이지의 메타데이터로 주입됩니다.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TaxonomyListing {
+    pub kind: TaxonomyKind,
+    /// 태그/카테고리 원문
+    pub term: String,
+    pub slug: Slug,
+    /// 현재 페이지 번호 (1부터)
+    pub page: usize,
+    pub total_pages: usize,
+    pub per_page: usize,
+    /// 출력 경로 (출력 디렉토리 기준)
+    pub path: PathBuf,
+}
+impl MetadataValue for TaxonomyListing {}
+
+impl TaxonomyListing {
+    /// 이 페이지에 보일 항목 (발행일 최신순).
+    pub fn entries<'a>(&self, index: &'a SiteIndex) -> Vec<&'a PageEntry> {
+        let all = match self.kind {
+            TaxonomyKind::Tag => index.pages_by_tag(&self.term),
+            TaxonomyKind::Category => index.pages_by_category(&self.term),
+        };
+        all.into_iter()
+            .skip((self.page - 1) * self.per_page)
+            .take(self.per_page)
+            .collect()
+    }
+}
+
+type Template = Arc<dyn Fn(&TaxonomyListing) -> Box<dyn Page> + Send + Sync>;
+
+/// 분류 목록 페이지 설정.
+#[derive(Clone)]
+pub struct Taxonomy {
+    kind: TaxonomyKind,
+    dir: String,
+    per_page: usize,
+    template: Template,
+}
+
+impl Taxonomy {
+    pub fn new<F>(kind: TaxonomyKind, template: F) -> Self
+    where
+        F: Fn(&TaxonomyListing) -> Box<dyn Page> + Send + Sync + 'static,
+    {
+        Taxonomy {
+            kind,
+            dir: kind.default_dir().to_string(),
+            per_page: 10,
+            template: Arc::new(template),
+        }
+    }
+
+    /// 태그마다 목록 페이지.
+    pub fn tags<F>(template: F) -> Self
+    where
+        F: Fn(&TaxonomyListing) -> Box<dyn Page> + Send + Sync + 'static,
+    {
+        Taxonomy::new(TaxonomyKind::Tag, template)
+    }
+
+    /// 카테고리마다 목록 페이지.
+    pub fn categories<F>(template: F) -> Self
+    where
+        F: Fn(&TaxonomyListing) -> Box<dyn Page> + Send + Sync + 'static,
+    {
+        Taxonomy::new(TaxonomyKind::Category, template)
+    }
+
+    /// 출력 디렉토리 (출력 디렉토리 기준). 기본: `tags`, `categories`
+    pub fn dir(mut self, dir: &str) -> Self {
+        self.dir = dir.trim_matches('/').to_string();
+        self
+    }
+
+    /// 목록 페이지 하나에 보일 항목 수. 기본: 10
+    pub fn per_page(mut self, per_page: usize) -> Self {
+        self.per_page = per_page.max(1);
+        self
+    }
+
+    fn path(&self, slug: &Slug, page: usize) -> PathBuf {
+        page_path(&Path::new(&self.dir).join(slug.as_str()), page)
+    }
+
+    /// 출력 대상 페이지들의 병합 메타데이터로 목록 페이지 생성 (분류 이름순).
+    pub(crate) fn pages(&self, metadata: &[ResolvedMetadata], config: &SiteConfig) -> Vec<Box<dyn Page>> {
+        let mut counts: BTreeMap<String, usize> = BTreeMap::new();
+        for page in metadata {
+            // 한 페이지에 같은 태그가 두 번 있어도 한 번만
+            let terms: BTreeSet<String> = self.kind.terms(page).into_iter().collect();
+            for term in terms {
+                *counts.entry(term).or_default() += 1;
+            }
+        }
+
+        let mut used = BTreeSet::new();
+        let mut pages = Vec::new();
+        for (term, count) in counts {
+            let slug = unique_slug(&term, &mut used);
+            let total_pages = count.div_ceil(self.per_page);
+            let urls: Vec<String> = (1..=total_pages)
+                .map(|n| config.url_for(&self.path(&slug, n)))
+                .collect();
+            for n in 1..=total_pages {
+                let listing = TaxonomyListing {
+                    kind: self.kind,
+                    term: term.clone(),
+                    slug: slug.clone(),
+                    page: n,
+                    total_pages,
+                    per_page: self.per_page,
+                    path: self.path(&slug, n),
+                };
+                let inner = (self.template)(&listing);
+                let paging = Paging::new(n, urls.clone());
+                pages.push(Box::new(TaxonomyPage {
+                    inner,
+                    listing,
+                    paging,
+                }) as Box<dyn Page>);
+            }
+        }
+        pages
+    }
+}
+
+fn unique_slug(term: &str, used: &mut BTreeSet<Slug>) -> Slug {
+    let base = Slug::new(term);
+    let base = if base.as_str().is_empty() {
+        Slug::from_raw("term")
+    } else {
+        base
+    };
+    let mut slug = base.clone();
+    let mut n = 2;
+    while used.contains(&slug) {
+        slug = Slug::from_raw(&format!("{}-{}", base, n));
+        n += 1;
+    }
+    used.insert(slug.clone());
+    slug
+}
+
+/// 템플릿이 만든 페이지에 목록 경로와 메타데이터를 입힌 페이지.
+struct TaxonomyPage {
+    inner: Box<dyn Page>,
+    listing: TaxonomyListing,
+    paging: Option<Paging>,
+}
+
+impl Page for TaxonomyPage {
+    fn layout(&self, ctx: &RenderContext) -> IRNode {
+        self.inner.layout(ctx)
+    }
+
+    fn metadata(&self) -> Metadata {
+        let base = Metadata::new().custom(self.listing.clone());
+        let base = match &self.paging {
+            Some(paging) => base.custom(paging.clone()),
+            None => base,
+        };
+        base.merge(&self.inner.metadata())
+    }
+
+    fn path(&self) -> PathBuf {
+        self.listing.path.clone()
+    }
+
+    fn head(&self, ctx: &RenderContext) -> HeadElements {
+        self.inner.head(ctx).paging(self.paging.as_ref())
+    }
+
+    fn layout_template(&self) -> Option<Arc<dyn Layout>> {
+        self.inner.layout_template()
+    }
+
+    fn blocks(&self) -> Vec<&dyn Block> {
+        self.inner.blocks()
+    }
+
+    fn alternate_targets(&self) -> Vec<RenderTarget> {
+        self.inner.alternate_targets()
+    }
+}
+
+/// 현재 목록 페이지의 항목 Block. 목록 페이지가 아니면 빈 목록입니다.
+#[derive(Debug, Clone)]
+pub struct TaxonomyList {
+    show_dates: bool,
+}
+
+impl Default for TaxonomyList {
+    fn default() -> Self {
+        TaxonomyList { show_dates: true }
+    }
+}
+
+impl TaxonomyList {
+    pub fn new() -> Self {
+        TaxonomyList::default()
+    }
+
+    /// 발행일 표시 여부. 기본: true
+    pub fn show_dates(mut self, show: bool) -> Self {
+        self.show_dates = show;
+        self
+    }
+}
+
+impl Block for TaxonomyList {
+    fn render_to_ir(&self, ctx: &RenderContext) -> IRNode {
+        let locale = Locale::from_metadata(&ctx.metadata);
+        let entries = match (ctx.get::<TaxonomyListing>(), ctx.index()) {
+            (Some(listing), Some(index)) => listing.entries(index),
+            _ => Vec::new(),
+        };
+        let items = entries
+            .into_iter()
+            .map(|entry| entry_item(entry, self.show_dates.then_some(&locale)))
+            .collect();
+        element("ul", class_attrs(&["taxonomy-list"]), items)
+    }
+}
+
+/// 목록 항목 하나: 제목 링크와 (있으면) 발행일. `locale`이 없으면 날짜를 표시하지 않습니다.
+pub(crate) fn entry_item(entry: &PageEntry, locale: Option<&Locale>) -> Element {
+    let rule = prose_rule();
+    let title = entry.title.clone().unwrap_or_else(|| entry.url.clone());
+    let mut childs = vec![Element::Node(element(
+        "a",
+        token_attrs(&[("href", &entry.url)]),
+        vec![Element::Text(Content::from_str(&title, &rule))],
+    ))];
+    if let (Some(date), Some(locale)) = (entry.date, locale) {
+        childs.push(Element::Text(Content::from_str(" ", &rule)));
+        childs.push(Element::Node(element(
+            "time",
+            token_attrs(&[("datetime", &date.to_string())]),
+            vec![Element::Text(Content::from_str(&locale.date(date), &rule))],
+        )));
+    }
+    Element::Node(element("li", SharedAttrs::new(), childs))
+}
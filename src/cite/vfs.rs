@@ -0,0 +1,184 @@
+//! # vfs.rs - 출력 파일 시스템
+//!
+//! ## 목적
+//! 빌드가 출력 파일을 읽고 쓰는 대상을 `SiteFs` 트레이트로 추상화합니다. 기록기(`OutputWriter`),
+//! 증분 빌드 상태(지문, 의존성 그래프), 플러그인 훅이 모두 이 트레이트만 사용하므로 같은 빌드를
+//! 디스크, 메모리, 압축 파일 등 어디에든 기록할 수 있습니다.
+//!
+//! ## 사용 예시
+//! ```rust
+//! // 디스크 (Site::build와 같음)
+//! site.build_to(Arc::new(DiskFs::new("dist")))?;
+//!
+//! // 메모리: 테스트에서 디스크를 건드리지 않음
+//! let fs = Arc::new(MemoryFs::new());
+//! let report = site.build_to(fs.clone())?;
+//! let html = fs.get(Path::new("index.html")).unwrap();
+//! ```
+//!
+//! ## 구현 상태
+//! - [x] `DiskFs`: 원자적 기록, 삭제 후 빈 디렉토리 정리
+//! - [x] `MemoryFs`: 경로 → 내용 맵
+//! - [x] zip/tar: `Site::build_to_archive`가 `MemoryFs`에 빌드한 뒤 묶음 (`bundle.rs`)
+//!
+//! ## 설계 결정
+//! - **경로는 출력 루트 기준 상대 경로**: 루트 밖을 가리키는 경로 검사는 호출하는 쪽(`OutputWriter`)이 합니다.
+//! - **`root`는 표시용**: `BuildManifest::output_dir`, `WriteHook::output_dir`, 오류 메시지에 쓰입니다.
+//!   디스크가 아닌 구현은 실제 경로가 아니어도 됩니다.
+//! - **공유 참조로 기록**: 렌더링 스레드가 동시에 기록하므로 메서드는 `&self`이고 구현은 `Send + Sync`입니다.
+
+use std::{
+    collections::BTreeMap,
+    fmt::Debug,
+    fs, io,
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+/// 출력 파일 시스템.
+pub trait SiteFs: Send + Sync + Debug {
+    /// 표시용 루트 경로.
+    fn root(&self) -> &Path;
+
+    /// 파일 내용. 없으면 `NotFound` 오류.
+    fn read(&self, path: &Path) -> io::Result<Vec<u8>>;
+
+    /// 파일 기록 (새 파일 또는 덮어쓰기). 필요한 디렉토리는 만들고, 실패하면 기존 내용을 유지합니다.
+    fn write(&self, path: &Path, contents: &[u8]) -> io::Result<()>;
+
+    /// 파일 삭제. 없으면 `NotFound` 오류.
+    fn remove(&self, path: &Path) -> io::Result<()>;
+
+    fn is_file(&self, path: &Path) -> bool;
+}
+
+/// 디스크의 디렉토리.
+#[derive(Debug, Clone)]
+pub struct DiskFs {
+    root: PathBuf,
+}
+
+impl DiskFs {
+    pub fn new<P: AsRef<Path>>(root: P) -> Self {
+        DiskFs {
+            root: root.as_ref().to_path_buf(),
+        }
+    }
+}
+
+impl SiteFs for DiskFs {
+    fn root(&self) -> &Path {
+        &self.root
+    }
+
+    fn read(&self, path: &Path) -> io::Result<Vec<u8>> {
+        fs::read(self.root.join(path))
+    }
+
+    /// 같은 디렉토리의 임시 파일에 쓴 뒤 이름 변경.
+    fn write(&self, path: &Path, contents: &[u8]) -> io::Result<()> {
+        write_atomic(&self.root.join(path), contents)
+    }
+
+    /// 삭제 후 비게 된 상위 디렉토리를 루트 직전까지 정리합니다.
+    fn remove(&self, path: &Path) -> io::Result<()> {
+        let full = self.root.join(path);
+        fs::remove_file(&full)?;
+        remove_empty_parents(&self.root, &full);
+        Ok(())
+    }
+
+    fn is_file(&self, path: &Path) -> bool {
+        self.root.join(path).is_file()
+    }
+}
+
+/// 메모리의 파일 맵.
+#[derive(Debug)]
+pub struct MemoryFs {
+    root: PathBuf,
+    files: Mutex<BTreeMap<PathBuf, Vec<u8>>>,
+}
+
+impl MemoryFs {
+    /// 표시용 루트는 `<memory>`.
+    pub fn new() -> Self {
+        MemoryFs {
+            root: PathBuf::from("<memory>"),
+            files: Mutex::default(),
+        }
+    }
+
+    pub fn get(&self, path: &Path) -> Option<Vec<u8>> {
+        self.lock().get(path).cloned()
+    }
+
+    /// 모든 파일 (경로순).
+    pub fn files(&self) -> BTreeMap<PathBuf, Vec<u8>> {
+        self.lock().clone()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, BTreeMap<PathBuf, Vec<u8>>> {
+        self.files.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Default for MemoryFs {
+    fn default() -> Self {
+        MemoryFs::new()
+    }
+}
+
+impl SiteFs for MemoryFs {
+    fn root(&self) -> &Path {
+        &self.root
+    }
+
+    fn read(&self, path: &Path) -> io::Result<Vec<u8>> {
+        self.get(path).ok_or_else(|| not_found(path))
+    }
+
+    fn write(&self, path: &Path, contents: &[u8]) -> io::Result<()> {
+        self.lock().insert(path.to_path_buf(), contents.to_vec());
+        Ok(())
+    }
+
+    fn remove(&self, path: &Path) -> io::Result<()> {
+        self.lock().remove(path).map(|_| ()).ok_or_else(|| not_found(path))
+    }
+
+    fn is_file(&self, path: &Path) -> bool {
+        self.lock().contains_key(path)
+    }
+}
+
+fn not_found(path: &Path) -> io::Error {
+    io::Error::new(io::ErrorKind::NotFound, format!("no such file: {}", path.display()))
+}
+
+/// 같은 디렉토리의 임시 파일에 쓴 뒤 이름 변경.
+fn write_atomic(path: &Path, contents: &[u8]) -> io::Result<()> {
+    let parent = path.parent().unwrap_or(Path::new("."));
+    fs::create_dir_all(parent)?;
+    let file_name = path.file_name().and_then(|n| n.to_str()).unwrap_or("output");
+    let temp = parent.join(format!(".{}.quo-tmp", file_name));
+    fs::write(&temp, contents)?;
+    fs::rename(&temp, path).inspect_err(|_| {
+        let _ = fs::remove_file(&temp);
+    })
+}
+
+/// 파일을 지운 뒤 비게 된 상위 디렉토리를 루트 직전까지 삭제.
+fn remove_empty_parents(root: &Path, path: &Path) {
+    let mut dir = path.parent();
+    while let Some(current) = dir {
+        if current == root || !current.starts_with(root) {
+            break;
+        }
+        // 비어 있지 않으면 실패하므로 그대로 멈춤
+        if fs::remove_dir(current).is_err() {
+            break;
+        }
+        dir = current.parent();
+    }
+}
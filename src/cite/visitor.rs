@@ -0,0 +1,150 @@
+//! # visitor.rs - Visitor 트레이트와 파이프라인
+//!
+//! ## 목적
+//! 빌드 분석 과정을 독립적인 방문자 단계로 나누어 순서대로 실행합니다.
+//! 각 방문자는 사이트 → 페이지 → Block 트리를 한 번 순회하고 `finalize()`로 결과를 내며,
+//! 결과는 타입별로 `SiteIndex`에 모입니다.
+//!
+//! ## 흐름
+//! ```text
+//! for visitor in 등록 순서:
+//!     visitor.visit_site(site)
+//!     for page in 출력 대상 페이지:
+//!         visitor.visit_page(page, &SiteContext)
+//!         for block in page.blocks() (깊이 우선):
+//!             visitor.visit_block(block, &PageContext)
+//!     index.absorb(visitor.finalize())
+//! ```
+//!
+//! ## 사용 예시
+//! ```rust
+//! #[derive(Clone, Default)]
+//! struct WordCounter { words: usize }
+//!
+//! #[derive(Clone)]
+//! struct WordCount(usize);
+//! impl MetadataValue for WordCount {}
+//!
+//! impl Visitor for WordCounter {
+//!     fn visit_block(&mut self, block: &dyn Block, _ctx: &PageContext) { /* ... */ }
+//!     fn finalize(&self) -> VisitorResult {
+//!         VisitorResult::new().custom(WordCount(self.words))
+//!     }
+//! }
+//!
+//! site.register_visitor(WordCounter::default());
+//! let index = site.index();
+//! index.get::<WordCount>();
+//! ```
+//!
+//! ## 설계 결정
+//! - **방문자별 전체 순회**: 한 방문자가 사이트 전체를 끝낸 뒤 다음 방문자가 시작합니다.
+//!   뒤 단계는 `ctx.index`로 앞 단계 결과를 읽을 수 있습니다 (예: 링크 검사가 ID 맵 사용).
+//! - **결과는 메타데이터**: 방문자마다 결과 타입이 다르므로 `Metadata`와 같은 타입 맵으로 반환합니다.
+//!   같은 타입을 낸 방문자가 여럿이면 뒤 결과가 덮어씁니다 (`mergeable`이면 병합).
+//! - **빌드마다 새 방문자**: 등록한 방문자를 복제하여 실행하므로 상태가 다음 빌드로 새지 않습니다.
+//! - **Block 순회는 페이지 본문만**: 공유 Layout의 Block(네비게이션 등)은 모든 페이지에 같으므로
+//!   분석 대상에서 제외합니다.
+
+use std::path::PathBuf;
+
+use crate::block::block::Block;
+use crate::cite::cite::Site;
+use crate::cite::index::SiteIndex;
+use crate::metadata::builder::Metadata;
+use crate::metadata::map::ResolvedMetadata;
+use crate::page::page::Page;
+
+/// 방문자가 `finalize()`에서 반환하는 결과. 타입별로 `SiteIndex`에 저장됩니다.
+pub type VisitorResult = Metadata;
+
+/// 페이지 방문 시 전달되는 정보.
+pub struct SiteContext<'a> {
+    pub site: &'a Site,
+    /// 앞 단계 방문자들의 결과
+    pub index: &'a SiteIndex,
+    /// 페이지 URL (사이트 루트 기준)
+    pub url: String,
+    /// 출력 경로 (출력 디렉토리 기준)
+    pub output_path: PathBuf,
+    /// Site → Page 병합 메타데이터
+    pub metadata: ResolvedMetadata,
+}
+
+/// Block 방문 시 전달되는 정보.
+pub struct PageContext<'a> {
+    pub page: &'a dyn Page,
+    pub index: &'a SiteIndex,
+    /// 페이지 URL (사이트 루트 기준)
+    pub url: &'a str,
+    /// Site → Page → (상위 Block들) → Block 병합 메타데이터
+    pub metadata: ResolvedMetadata,
+    /// 트리 깊이. 페이지 최상위 Block이 0
+    pub depth: usize,
+}
+
+/// 빌드 분석 단계 하나.
+pub trait Visitor {
+    /// 사이트 방문 시작.
+    fn visit_site(&mut self, _site: &Site) {}
+
+    /// 출력 대상 페이지 하나 방문 (등록 순서).
+    fn visit_page(&mut self, _page: &dyn Page, _ctx: &SiteContext) {}
+
+    /// 페이지의 Block 하나 방문 (깊이 우선, 부모 먼저).
+    fn visit_block(&mut self, _block: &dyn Block, _ctx: &PageContext) {}
+
+    /// 방문 완료 후 결과 반환.
+    fn finalize(&self) -> VisitorResult;
+}
+
+/// 방문자들을 순서대로 실행하여 `SiteIndex`를 만듭니다.
+pub fn run_pipeline(site: &Site, visitors: Vec<Box<dyn Visitor>>) -> SiteIndex {
+    visitors.into_iter().fold(SiteIndex::new(), |index, mut visitor| {
+        run_visitor(site, visitor.as_mut(), &index);
+        index.absorb(visitor.finalize())
+    })
+}
+
+fn run_visitor(site: &Site, visitor: &mut dyn Visitor, index: &SiteIndex) {
+    visitor.visit_site(site);
+    for page in site.visible_pages() {
+        let site_ctx = SiteContext {
+            site,
+            index,
+            url: site.page_url(page),
+            output_path: site.output_path(page),
+            metadata: site.page_metadata(page),
+        };
+        visitor.visit_page(page, &site_ctx);
+
+        for block in page.blocks() {
+            let ctx = PageContext {
+                page,
+                index,
+                url: &site_ctx.url,
+                metadata: site_ctx.metadata.clone(),
+                depth: 0,
+            };
+            visit_tree(visitor, block, ctx);
+        }
+    }
+}
+
+fn visit_tree(visitor: &mut dyn Visitor, block: &dyn Block, parent: PageContext) {
+    let ctx = PageContext {
+        metadata: parent.metadata.merge(block.metadata().map()),
+        ..parent
+    };
+    visitor.visit_block(block, &ctx);
+    for child in block.children() {
+        let child_ctx = PageContext {
+            page: ctx.page,
+            index: ctx.index,
+            url: ctx.url,
+            metadata: ctx.metadata.clone(),
+            depth: ctx.depth + 1,
+        };
+        visit_tree(visitor, child, child_ctx);
+    }
+}
@@ -0,0 +1,292 @@
+//! # writer.rs - 출력 파일 기록
+//!
+//! ## 목적
+//! 빌드의 마지막 단계로 렌더링 결과를 출력 파일 시스템(`SiteFs`, 기본은 `DiskFs`)의 `<path>`에 기록합니다.
+//! - 필요한 디렉토리 생성, 원자적 기록 (`DiskFs`: 같은 디렉토리의 임시 파일에 쓴 뒤 이름 변경)
+//! - 이전 빌드가 남긴 파일 정리 (이번 빌드에 없는 파일 삭제)
+//! - 기록한 파일 목록(`BuildManifest`) 반환
+//! - 내용이 같은 파일은 다시 쓰지 않음 (수정 시각 유지)
+//! - 설정하면 HTML/CSS/JS 옆에 미리 압축한 파일 기록 (`precompress`, `compress.rs`)
+//!
+//! ## 사용 예시
+//! ```rust
+//! let writer = OutputWriter::new("dist");
+//! writer.write(Path::new("index.html"), html.as_bytes())?;
+//! writer.write(Path::new("blog/post.html"), post.as_bytes())?;
+//! let manifest = writer.finish()?;  // 이전 빌드의 남은 파일 삭제, 목록 저장
+//!
+//! let memory = Arc::new(MemoryFs::new());
+//! let writer = OutputWriter::with_fs(memory.clone());  // 디스크 대신 메모리에
+//! ```
+//!
+//! ## 설계 결정
+//! - **임시 파일은 같은 디렉토리에**: 이름 변경이 같은 파일 시스템 안에서 일어나야 원자적입니다.
+//!   빌드가 중간에 실패해도 기존 파일은 이전 내용 그대로 남습니다.
+//! - **정리는 목록 기준**: 출력 디렉토리를 통째로 지우지 않고, 이전 빌드 목록(`.quo-manifest`)에
+//!   있던 파일만 지웁니다. 사용자가 직접 넣은 파일(CNAME 등)은 건드리지 않습니다.
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    io,
+    path::{Component, Path, PathBuf},
+    sync::{Arc, Mutex},
+};
+
+use serde::Serialize;
+
+use crate::cite::compress::{Compressor, Encoding, is_compressible};
+use crate::cite::vfs::{DiskFs, SiteFs};
+use crate::metadata::merge::MergeableMetadata;
+use crate::metadata::value::MetadataValue;
+
+/// 출력 디렉토리에 저장되는 이전 빌드 파일 목록.
+pub const MANIFEST_FILE: &str = ".quo-manifest";
+
+/// 한 번의 빌드가 기록한 결과.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct BuildManifest {
+    /// 출력 디렉토리
+    pub output_dir: PathBuf,
+    /// 기록한 파일 (출력 디렉토리 기준, 경로순)
+    pub files: Vec<PathBuf>,
+    /// `files` 중 이전 빌드와 같아 다시 쓰지 않은 파일 (경로순)
+    pub unchanged: Vec<PathBuf>,
+    /// 이전 빌드에 있었지만 이번 빌드에 없어 삭제한 파일 (출력 디렉토리 기준, 경로순)
+    pub removed: Vec<PathBuf>,
+    /// 실제로 쓴 바이트 수 (`unchanged` 제외)
+    pub bytes_written: u64,
+}
+
+impl BuildManifest {
+    /// 기록한 파일의 전체 경로.
+    pub fn paths(&self) -> Vec<PathBuf> {
+        self.files.iter().map(|f| self.output_dir.join(f)).collect()
+    }
+}
+
+/// 방문자가 만든 전역 파일 (sitemap.xml, feed.xml, 이미지 변형 등). 출력 디렉토리 기준 경로 → 내용.
+/// `VisitorResult::mergeable`로 반환하면 여러 방문자의 파일이 합쳐지고, Site 빌드가 페이지와 함께 기록합니다.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GeneratedFiles(pub BTreeMap<PathBuf, Vec<u8>>);
+impl MetadataValue for GeneratedFiles {}
+
+impl MergeableMetadata for GeneratedFiles {
+    fn merge_with(&self, base: &Self) -> Self {
+        let mut files = base.0.clone();
+        files.extend(self.0.clone());
+        GeneratedFiles(files)
+    }
+}
+
+impl GeneratedFiles {
+    pub fn new() -> Self {
+        GeneratedFiles::default()
+    }
+
+    pub fn file<P: AsRef<Path>>(mut self, path: P, contents: String) -> Self {
+        self.0.insert(path.as_ref().to_path_buf(), contents.into_bytes());
+        self
+    }
+
+    /// 이진 파일 (이미지 등).
+    pub fn bytes<P: AsRef<Path>>(mut self, path: P, contents: Vec<u8>) -> Self {
+        self.0.insert(path.as_ref().to_path_buf(), contents);
+        self
+    }
+}
+
+/// 기록 상태. 여러 스레드가 같은 기록기를 쓰므로 잠금 안에 둡니다.
+#[derive(Debug, Default)]
+struct Written {
+    files: BTreeSet<PathBuf>,
+    unchanged: BTreeSet<PathBuf>,
+    bytes: u64,
+}
+
+/// 출력 디렉토리 기록기. 서로 다른 경로라면 여러 스레드에서 동시에 기록할 수 있습니다.
+pub struct OutputWriter {
+    fs: Arc<dyn SiteFs>,
+    written: Mutex<Written>,
+    compressors: Vec<Arc<dyn Compressor>>,
+}
+
+impl OutputWriter {
+    /// 디스크의 `output_dir`에 기록.
+    pub fn new<P: AsRef<Path>>(output_dir: P) -> Self {
+        OutputWriter::with_fs(Arc::new(DiskFs::new(output_dir)))
+    }
+
+    /// 임의의 출력 파일 시스템에 기록.
+    pub fn with_fs(fs: Arc<dyn SiteFs>) -> Self {
+        OutputWriter {
+            fs,
+            written: Mutex::new(Written::default()),
+            compressors: Vec::new(),
+        }
+    }
+
+    /// 압축 대상 파일(`is_compressible`)을 기록할 때 압축기마다 `<파일>.gz` 등도 함께 기록.
+    /// 압축 파일도 목록에 들어가므로 설정을 끄면 다음 빌드에서 정리됩니다.
+    pub fn precompress(mut self, compressors: Vec<Arc<dyn Compressor>>) -> Self {
+        self.compressors = compressors;
+        self
+    }
+
+    pub fn output_dir(&self) -> &Path {
+        self.fs.root()
+    }
+
+    pub fn fs(&self) -> &dyn SiteFs {
+        self.fs.as_ref()
+    }
+
+    /// 출력 디렉토리 기준 경로에 원자적으로 기록. 기존 파일과 내용이 같으면 쓰지 않습니다.
+    /// 출력 디렉토리 밖을 가리키는 경로(절대 경로, `..`)는 `InvalidInput` 오류입니다.
+    pub fn write(&self, path: &Path, contents: &[u8]) -> io::Result<()> {
+        check_inside(path)?;
+        let unchanged = self.fs.read(path).is_ok_and(|existing| existing == contents);
+        if !unchanged {
+            self.fs.write(path, contents)?;
+        }
+        self.record(path, unchanged, if unchanged { 0 } else { contents.len() as u64 });
+        self.write_compressed(path, contents, unchanged)
+    }
+
+    /// 이전 빌드의 파일을 그대로 유지 (증분 빌드에서 렌더링을 건너뛴 페이지).
+    /// 파일이 없으면 `NotFound` 오류입니다.
+    pub fn keep(&self, path: &Path) -> io::Result<()> {
+        check_inside(path)?;
+        if !self.fs.is_file(path) {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("kept output file does not exist: {}", path.display()),
+            ));
+        }
+        self.record(path, true, 0);
+        if self.compressors.is_empty() || !is_compressible(path) {
+            return Ok(());
+        }
+        let contents = self.fs.read(path)?;
+        self.write_compressed(path, &contents, true)
+    }
+
+    /// 압축 파일 기록. 원본이 그대로이고 압축 파일이 남아 있으면 다시 압축하지 않습니다.
+    fn write_compressed(&self, path: &Path, contents: &[u8], unchanged: bool) -> io::Result<()> {
+        if !is_compressible(path) {
+            return Ok(());
+        }
+        for compressor in &self.compressors {
+            let compressed_path = compressed_path(path, compressor.encoding());
+            if unchanged && self.fs.is_file(&compressed_path) {
+                self.record(&compressed_path, true, 0);
+                continue;
+            }
+            let compressed = compressor.compress(contents)?;
+            if compressed.len() >= contents.len() {
+                continue;
+            }
+            let same = self
+                .fs
+                .read(&compressed_path)
+                .is_ok_and(|existing| existing == compressed);
+            if !same {
+                self.fs.write(&compressed_path, &compressed)?;
+            }
+            self.record(&compressed_path, same, if same { 0 } else { compressed.len() as u64 });
+        }
+        Ok(())
+    }
+
+    fn record(&self, path: &Path, unchanged: bool, bytes: u64) {
+        // 다른 스레드가 기록 중 패닉해도 목록 자체는 온전하므로 잠금 오염을 무시
+        let mut written = self.written.lock().unwrap_or_else(|e| e.into_inner());
+        written.files.insert(path.to_path_buf());
+        if unchanged {
+            written.unchanged.insert(path.to_path_buf());
+        } else {
+            // 같은 빌드에서 다시 기록한 파일 (플러그인 후처리)
+            written.unchanged.remove(path);
+        }
+        written.bytes += bytes;
+    }
+
+    /// 지금까지 기록하거나 유지한 파일 (출력 디렉토리 기준, 경로순).
+    pub fn files(&self) -> Vec<PathBuf> {
+        let written = self.written.lock().unwrap_or_else(|e| e.into_inner());
+        written.files.iter().cloned().collect()
+    }
+
+    /// 이번 빌드에 없는 이전 빌드 파일을 지우고 목록을 저장.
+    pub fn finish(self) -> io::Result<BuildManifest> {
+        let written = self.written.into_inner().unwrap_or_else(|e| e.into_inner());
+        let previous = read_manifest(self.fs.as_ref())?;
+        let mut removed = Vec::new();
+        for stale in previous.difference(&written.files) {
+            match self.fs.remove(stale) {
+                Ok(()) => removed.push(stale.clone()),
+                Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+                Err(err) => return Err(err),
+            }
+        }
+
+        let listing: String = written
+            .files
+            .iter()
+            .map(|f| format!("{}\n", manifest_line(f)))
+            .collect();
+        self.fs.write(Path::new(MANIFEST_FILE), listing.as_bytes())?;
+
+        Ok(BuildManifest {
+            output_dir: self.fs.root().to_path_buf(),
+            files: written.files.into_iter().collect(),
+            unchanged: written.unchanged.into_iter().collect(),
+            removed,
+            bytes_written: written.bytes,
+        })
+    }
+}
+
+/// `index.html` → `index.html.gz`
+fn compressed_path(path: &Path, encoding: Encoding) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(".");
+    name.push(encoding.extension());
+    path.with_file_name(name)
+}
+
+fn check_inside(path: &Path) -> io::Result<()> {
+    if is_relative_inside(path) {
+        Ok(())
+    } else {
+        Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("output path escapes output directory: {}", path.display()),
+        ))
+    }
+}
+
+/// 이전 빌드 목록. 없으면 빈 목록.
+fn read_manifest(fs: &dyn SiteFs) -> io::Result<BTreeSet<PathBuf>> {
+    match fs.read(Path::new(MANIFEST_FILE)) {
+        Ok(listing) => Ok(String::from_utf8_lossy(&listing)
+            .lines()
+            .map(PathBuf::from)
+            .filter(|p| !p.as_os_str().is_empty() && is_relative_inside(p))
+            .collect()),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(BTreeSet::new()),
+        Err(err) => Err(err),
+    }
+}
+
+/// 플랫폼과 무관하게 `/`로 구분한 목록 한 줄.
+fn manifest_line(path: &Path) -> String {
+    path.components()
+        .map(|c| c.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// 상대 경로이고 `..`로 올라가지 않는지.
+fn is_relative_inside(path: &Path) -> bool {
+    path.components().all(|c| matches!(c, Component::Normal(_) | Component::CurDir))
+}
@@ -0,0 +1,341 @@
+//! # cli - 명령줄 도구
+//!
+//! ## 목적
+//! 라이브러리를 직접 다루지 않는 사용자를 위한 작업 흐름입니다. `quo` 바이너리가 이 모듈을 실행합니다.
+//!
+//! ```text
+//! quo new <dir> [--name <이름>] [--rust]          새 사이트 프로젝트 생성
+//! quo build [--drafts] [--preview] [--out <dir>]  dist/에 빌드 (--preview: 초안에 noindex + 배너)
+//! quo check [--drafts] [--audit]                  파일을 쓰지 않고 검사 (CI, --audit: 고아 페이지와 안 쓰는 asset)
+//! quo serve [--port <n>] [--no-watch]             빌드 후 로컬 서버, 바뀌면 다시 빌드
+//! ```
+//! 모든 명령은 `--config <파일>`(기본: `quo.json`)로 프로젝트 설정을 지정합니다.
+//!
+//! ## 두 가지 프로젝트
+//! - **설정 파일 프로젝트** (`quo new`): `quo.json` + `content/*.md` + `static/`.
+//!   Markdown 파일마다 `SimplePage`를 만들고 `static/`을 그대로 복사합니다.
+//! - **Rust 프로젝트** (`quo new --rust`): `site.rs`가 `Site`를 만들고 `main_with`로 같은 명령을 제공합니다.
+//!   `quo` 바이너리는 설정 파일 옆에 `site.rs`와 `Cargo.toml`이 있으면 `cargo run --bin site -- <명령>`으로 넘깁니다.
+//!
+//! ```rust
+//! // site.rs
+//! fn main() -> ExitCode {
+//!     quo::cli::main_with(|project| {
+//!         let mut site = project.site()?;        // quo.json 설정과 content/ 페이지
+//!         site.register_visitor(SitemapGenerator::new());
+//!         Ok(site)
+//!     })
+//! }
+//! ```
+//!
+//! ## 모듈 구조
+//! ```text
+//! cli/
+//! ├─ project.rs   - quo.json (ProjectConfig), 콘텐츠 로드, static/ 복사 플러그인
+//! ├─ scaffold.rs  - quo new
+//! ├─ serve.rs     - 로컬 HTTP 서버와 변경 감시
+//! └─ mod.rs       - 인자 해석과 명령 실행 (이 파일)
+//! ```
+//!
+//! ## 설계 결정
+//! - **외부 의존성 없음**: 인자 해석, HTTP 서버, 변경 감시를 표준 라이브러리로 구현합니다.
+//!   서버는 미리보기 전용이며 배포용이 아닙니다.
+//! - **감시는 폴링**: 운영체제별 알림 API 대신 주기적으로 수정 시각을 비교합니다.
+//!   콘텐츠 수천 개 규모에서는 충분히 빠릅니다.
+//! - **코드 변경은 다시 시작**: Rust 프로젝트의 `site.rs`가 바뀌면 다시 컴파일해야 하므로
+//!   감시 대상은 콘텐츠, static, 설정 파일입니다.
+
+pub mod project;
+pub mod scaffold;
+pub mod serve;
+
+use std::{
+    ffi::OsString,
+    fmt::Display,
+    io,
+    path::{Path, PathBuf},
+    process::{self, ExitCode},
+};
+
+use crate::cite::cite::Site;
+use crate::cite::error::BuildError;
+use crate::cli::project::{DEFAULT_CONFIG_FILE, ProjectConfig};
+
+const USAGE: &str = "\
+Usage: quo <command> [options]
+
+Commands:
+  new <dir>    Create a new site project (--name <name>, --rust)
+  build        Build the site into the output directory (--drafts, --preview, --out <dir>)
+  check        Check the site without writing files (--drafts, --audit)
+  serve        Build, serve locally and rebuild on change (--port <n>, --no-watch)
+  help         Show this message
+
+Options:
+  --config <file>    Project configuration (default: quo.json)";
+
+/// 명령줄 명령.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    New {
+        dir: PathBuf,
+        name: Option<String>,
+        rust: bool,
+    },
+    Build {
+        drafts: bool,
+        /// 초안 미리보기 빌드 (`Site::preview`)
+        preview: bool,
+        out: Option<PathBuf>,
+    },
+    Check {
+        drafts: bool,
+        /// 고아 페이지와 안 쓰는 asset 목록 (`Site::audit`)
+        audit: bool,
+    },
+    Serve {
+        port: u16,
+        watch: bool,
+    },
+    Help,
+}
+
+/// 해석한 명령줄.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Args {
+    pub command: Command,
+    /// 프로젝트 설정 파일. 기본: `quo.json`
+    pub config: PathBuf,
+}
+
+/// 명령 실패.
+#[derive(Debug)]
+pub enum CliError {
+    /// 잘못된 인자
+    Usage(String),
+    /// 프로젝트 설정 파일을 읽거나 해석할 수 없음
+    Config { path: PathBuf, message: String },
+    /// 파일 입출력 실패
+    Io { path: PathBuf, source: io::Error },
+    /// 빌드 실패
+    Build(BuildError),
+    /// 위임한 `cargo run`이 실패
+    Delegate(Option<i32>),
+}
+
+impl Display for CliError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CliError::Usage(message) => write!(f, "{}\n\n{}", message, USAGE),
+            CliError::Config { path, message } => {
+                write!(f, "invalid project config {}: {}", path.display(), message)
+            }
+            CliError::Io { path, source } => write!(f, "{}: {}", path.display(), source),
+            CliError::Build(err) => write!(f, "{}", err),
+            CliError::Delegate(Some(code)) => write!(f, "site binary exited with status {}", code),
+            CliError::Delegate(None) => write!(f, "site binary was terminated"),
+        }
+    }
+}
+
+impl std::error::Error for CliError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            CliError::Io { source, .. } => Some(source),
+            CliError::Build(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<BuildError> for CliError {
+    fn from(err: BuildError) -> Self {
+        CliError::Build(err)
+    }
+}
+
+impl CliError {
+    pub(crate) fn io(path: &Path, source: io::Error) -> Self {
+        CliError::Io {
+            path: path.to_path_buf(),
+            source,
+        }
+    }
+}
+
+impl Args {
+    /// 프로그램 이름을 뺀 인자 해석.
+    pub fn parse<I, S>(args: I) -> Result<Args, CliError>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<OsString>,
+    {
+        let mut args = args
+            .into_iter()
+            .map(|arg| arg.into().to_string_lossy().into_owned());
+        let mut config = PathBuf::from(DEFAULT_CONFIG_FILE);
+        let mut positional = Vec::new();
+        let mut name = None;
+        let mut out = None;
+        let mut port = serve::DEFAULT_PORT;
+        let (mut rust, mut drafts, mut preview, mut watch, mut help) = (false, false, false, true, false);
+        let mut audit = false;
+
+        while let Some(arg) = args.next() {
+            let mut value = |flag: &str| {
+                args.next()
+                    .ok_or_else(|| CliError::Usage(format!("missing value for {}", flag)))
+            };
+            match arg.as_str() {
+                "--config" => config = PathBuf::from(value("--config")?),
+                "--name" => name = Some(value("--name")?),
+                "--out" => out = Some(PathBuf::from(value("--out")?)),
+                "--port" => {
+                    let raw = value("--port")?;
+                    port = raw
+                        .parse()
+                        .map_err(|_| CliError::Usage(format!("invalid port: {}", raw)))?;
+                }
+                "--rust" => rust = true,
+                "--drafts" => drafts = true,
+                "--preview" => preview = true,
+                "--audit" => audit = true,
+                "--no-watch" => watch = false,
+                "-h" | "--help" => help = true,
+                flag if flag.starts_with('-') => {
+                    return Err(CliError::Usage(format!("unknown option: {}", flag)));
+                }
+                _ => positional.push(arg),
+            }
+        }
+
+        let mut positional = positional.into_iter();
+        let command = match (help, positional.next().as_deref()) {
+            (true, _) | (false, None | Some("help")) => Command::Help,
+            (false, Some("new")) => Command::New {
+                dir: positional
+                    .next()
+                    .map(PathBuf::from)
+                    .ok_or_else(|| CliError::Usage("quo new needs a directory".to_string()))?,
+                name,
+                rust,
+            },
+            (false, Some("build")) => Command::Build { drafts, preview, out },
+            (false, Some("check")) => Command::Check { drafts, audit },
+            (false, Some("serve")) => Command::Serve { port, watch },
+            (false, Some(other)) => {
+                return Err(CliError::Usage(format!("unknown command: {}", other)));
+            }
+        };
+        if let Some(extra) = positional.next() {
+            return Err(CliError::Usage(format!("unexpected argument: {}", extra)));
+        }
+        Ok(Args { command, config })
+    }
+}
+
+/// `quo` 바이너리 진입점. Rust 프로젝트면 `site` 바이너리로 위임하고,
+/// 아니면 설정 파일의 콘텐츠로 사이트를 만듭니다.
+pub fn run<I, S>(args: I) -> ExitCode
+where
+    I: IntoIterator<Item = S>,
+    S: Into<OsString>,
+{
+    let args: Vec<OsString> = args.into_iter().map(Into::into).collect();
+    report(Args::parse(args.clone()).and_then(|parsed| {
+        let root = parsed
+            .config
+            .parent()
+            .filter(|p| !p.as_os_str().is_empty())
+            .unwrap_or(Path::new("."));
+        let delegate = !matches!(parsed.command, Command::New { .. } | Command::Help)
+            && root.join(scaffold::SITE_FILE).is_file()
+            && root.join("Cargo.toml").is_file();
+        if delegate {
+            delegate_to_site(root, &args)
+        } else {
+            execute(&parsed, &|project: &ProjectConfig| project.site())
+        }
+    }))
+}
+
+/// `site.rs` 진입점. `site`가 프로젝트 설정으로 `Site`를 만들고, 명령은 `quo`와 같습니다.
+/// `serve`는 바뀔 때마다 `site`를 다시 호출합니다.
+pub fn main_with<F>(site: F) -> ExitCode
+where
+    F: Fn(&ProjectConfig) -> Result<Site, CliError>,
+{
+    report(Args::parse(std::env::args_os().skip(1)).and_then(|args| execute(&args, &site)))
+}
+
+fn report(result: Result<(), CliError>) -> ExitCode {
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// 해석한 명령 실행.
+pub fn execute(
+    args: &Args,
+    site: &dyn Fn(&ProjectConfig) -> Result<Site, CliError>,
+) -> Result<(), CliError> {
+    match &args.command {
+        Command::Help => {
+            println!("{}", USAGE);
+            Ok(())
+        }
+        Command::New { dir, name, rust } => {
+            let files = scaffold::create(dir, name.as_deref(), *rust)?;
+            println!("Created {} ({} files)", dir.display(), files.len());
+            let build = if *rust { "cargo run --bin site -- build" } else { "quo build" };
+            println!("  cd {}\n  {}", dir.display(), build);
+            Ok(())
+        }
+        Command::Build { drafts, preview, out } => {
+            let mut project = ProjectConfig::read(&args.config)?;
+            if let Some(out) = out {
+                // 설정 파일이 아닌 현재 디렉토리 기준
+                let cwd = std::env::current_dir().map_err(|err| CliError::io(out, err))?;
+                project.output_dir = cwd.join(out);
+            }
+            let report = site(&project)?
+                .include_drafts(*drafts)
+                .preview(*preview)
+                .build_output()?;
+            println!("{}", report);
+            Ok(())
+        }
+        Command::Check { drafts, audit } => {
+            let project = ProjectConfig::read(&args.config)?;
+            let site = site(&project)?.include_drafts(*drafts);
+            let report = site.check()?;
+            println!("{}", report);
+            if *audit {
+                println!("{}", site.audit());
+            }
+            Ok(())
+        }
+        Command::Serve { port, watch } => serve::serve(&args.config, *port, *watch, site),
+    }
+}
+
+/// `cargo run --quiet --manifest-path <root>/Cargo.toml --bin site -- <args>`.
+/// 작업 디렉토리는 그대로 두므로 `--config` 등 상대 경로가 같은 뜻입니다.
+fn delegate_to_site(root: &Path, args: &[OsString]) -> Result<(), CliError> {
+    let status = process::Command::new("cargo")
+        .args(["run", "--quiet", "--manifest-path"])
+        .arg(root.join("Cargo.toml"))
+        .args(["--bin", "site", "--"])
+        .args(args)
+        .status()
+        .map_err(|err| CliError::io(root, err))?;
+    if status.success() {
+        Ok(())
+    } else {
+        Err(CliError::Delegate(status.code()))
+    }
+}
@@ -0,0 +1,221 @@
+//! # project.rs - 프로젝트 설정 (`quo.json`)
+//!
+//! ## 목적
+//! 명령줄 도구가 읽는 프로젝트 설정입니다. `SiteConfig` 항목과 함께 콘텐츠/정적 파일 위치를 담고,
+//! 설정 파일만으로 사이트를 만들 수 있게 합니다 (`ProjectConfig::site`).
+//!
+//! ## 형식
+//! ```json
+//! {
+//!   "name": "My Blog",
+//!   "base_url": "https://example.com",
+//!   "language": "ko",
+//!   "output_dir": "dist",
+//!   "content_dir": "content",
+//!   "static_dir": "static",
+//!   "pretty_urls": true,
+//!   "cache_busting": "query",
+//!   "block_errors": "lenient"
+//! }
+//! ```
+//! `name`과 `base_url`만 필수입니다. 경로는 설정 파일이 있는 디렉토리 기준입니다.
+//!
+//! ## 설계 결정
+//! - **JSON**: 이미 의존하는 `serde_json`으로 읽습니다. 알 수 없는 키는 오타일 가능성이 높으므로 오류입니다.
+//! - **static/은 플러그인으로 복사**: `WriteHook::write`로 기록하므로 미리 압축, 기록 목록,
+//!   이전 빌드 파일 정리에 페이지와 똑같이 포함됩니다.
+
+use std::{
+    fs,
+    path::{Component, Path, PathBuf},
+};
+
+use serde::Deserialize;
+
+use crate::cite::cite::Site;
+use crate::cite::config::{BlockErrorMode, CacheBusting, SiteConfig};
+use crate::cite::plugin::{Plugin, PluginError, WriteHook};
+use crate::cli::CliError;
+use crate::page::loader::ContentLoader;
+use crate::page::page::Page;
+use crate::page::simple::SimplePage;
+
+/// 기본 설정 파일 이름.
+pub const DEFAULT_CONFIG_FILE: &str = "quo.json";
+
+/// `quo.json` 내용.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ProjectConfig {
+    pub name: String,
+    pub base_url: String,
+    #[serde(default)]
+    pub language: Option<String>,
+    #[serde(default = "default_output_dir")]
+    pub output_dir: PathBuf,
+    #[serde(default = "default_content_dir")]
+    pub content_dir: PathBuf,
+    #[serde(default = "default_static_dir")]
+    pub static_dir: PathBuf,
+    #[serde(default)]
+    pub pretty_urls: bool,
+    #[serde(default = "default_trailing_slash")]
+    pub trailing_slash: bool,
+    /// `"filename"`(기본) 또는 `"query"`. `query`면 정적 파일에도 `?v=<지문>`을 붙임
+    #[serde(default)]
+    pub cache_busting: CacheBusting,
+    /// `"strict"`(기본) 또는 `"lenient"`. `lenient`면 검사에 실패한 Block을 오류 표시로 바꾸고 경고
+    #[serde(default)]
+    pub block_errors: BlockErrorMode,
+    /// 설정 파일이 있는 디렉토리. 상대 경로의 기준
+    #[serde(skip)]
+    pub root: PathBuf,
+}
+
+fn default_output_dir() -> PathBuf {
+    PathBuf::from("dist")
+}
+
+fn default_content_dir() -> PathBuf {
+    PathBuf::from("content")
+}
+
+fn default_static_dir() -> PathBuf {
+    PathBuf::from("static")
+}
+
+fn default_trailing_slash() -> bool {
+    true
+}
+
+impl ProjectConfig {
+    /// 설정 파일 읽기.
+    pub fn read(path: &Path) -> Result<Self, CliError> {
+        let source = fs::read_to_string(path).map_err(|err| CliError::io(path, err))?;
+        let mut config: ProjectConfig =
+            serde_json::from_str(&source).map_err(|err| CliError::Config {
+                path: path.to_path_buf(),
+                message: err.to_string(),
+            })?;
+        config.root = path
+            .parent()
+            .filter(|p| !p.as_os_str().is_empty())
+            .unwrap_or(Path::new("."))
+            .to_path_buf();
+        Ok(config)
+    }
+
+    fn resolve(&self, path: &Path) -> PathBuf {
+        self.root.join(path)
+    }
+
+    pub fn output_path(&self) -> PathBuf {
+        self.resolve(&self.output_dir)
+    }
+
+    pub fn content_path(&self) -> PathBuf {
+        self.resolve(&self.content_dir)
+    }
+
+    pub fn static_path(&self) -> PathBuf {
+        self.resolve(&self.static_dir)
+    }
+
+    /// 빌드 설정. 출력 디렉토리는 설정 파일 기준으로 해석한 경로입니다.
+    pub fn site_config(&self) -> SiteConfig {
+        let config = SiteConfig::new(&self.name, &self.base_url)
+            .output_dir(self.output_path())
+            .pretty_urls(self.pretty_urls)
+            .trailing_slash(self.trailing_slash)
+            .cache_busting(self.cache_busting)
+            .block_errors(self.block_errors)
+            .asset_dir(self.static_path());
+        match &self.language {
+            Some(language) => config.language(language),
+            None => config,
+        }
+    }
+
+    /// 콘텐츠 디렉토리의 Markdown 파일마다 `SimplePage`를 등록한 사이트.
+    /// 정적 파일 디렉토리가 있으면 출력에 그대로 복사합니다.
+    pub fn site(&self) -> Result<Site, CliError> {
+        let content = self.content_path();
+        let mut site = Site::new().config(self.site_config());
+        if content.is_dir() {
+            let pages = ContentLoader::new(&content)
+                .load(SimplePage::from_content)
+                .map_err(|err| CliError::io(&content, err))?;
+            for page in pages {
+                site.register_page(Box::new(page) as Box<dyn Page>);
+            }
+        }
+        let static_dir = self.static_path();
+        if static_dir.is_dir() {
+            site = site.plugin(StaticFiles::new(static_dir));
+        }
+        Ok(site)
+    }
+}
+
+/// 디렉토리의 파일을 출력 디렉토리에 같은 상대 경로로 복사하는 플러그인.
+/// 숨김 파일(`.`로 시작)은 건너뜁니다.
+#[derive(Debug, Clone)]
+pub struct StaticFiles {
+    dir: PathBuf,
+}
+
+impl StaticFiles {
+    pub fn new<P: AsRef<Path>>(dir: P) -> Self {
+        StaticFiles {
+            dir: dir.as_ref().to_path_buf(),
+        }
+    }
+
+    /// 복사할 파일 (디렉토리 기준 상대 경로, 경로순).
+    pub fn files(&self) -> std::io::Result<Vec<PathBuf>> {
+        let mut files = Vec::new();
+        collect_files(&self.dir, &self.dir, &mut files)?;
+        files.sort();
+        Ok(files)
+    }
+}
+
+impl Plugin for StaticFiles {
+    fn name(&self) -> &str {
+        "static-files"
+    }
+
+    fn after_write(&self, hook: &mut WriteHook) -> Result<(), PluginError> {
+        let written = hook.files();
+        for relative in self.files()? {
+            if written.contains(&relative) {
+                hook.warn(&relative, "static file conflicts with a generated file; kept generated");
+                continue;
+            }
+            let contents = fs::read(self.dir.join(&relative))?;
+            hook.write(&relative, &contents)?;
+        }
+        Ok(())
+    }
+}
+
+fn collect_files(root: &Path, dir: &Path, files: &mut Vec<PathBuf>) -> std::io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        let hidden = path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .is_some_and(|n| n.starts_with('.'));
+        if hidden {
+            continue;
+        }
+        if path.is_dir() {
+            collect_files(root, &path, files)?;
+        } else if let Ok(relative) = path.strip_prefix(root)
+            && relative.components().all(|c| matches!(c, Component::Normal(_)))
+        {
+            files.push(relative.to_path_buf());
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,148 @@
+//! # scaffold.rs - `quo new`
+//!
+//! ## 목적
+//! 바로 빌드할 수 있는 새 사이트 프로젝트를 만듭니다.
+//!
+//! ## 생성 파일
+//! ```text
+//! my-site/
+//! ├─ quo.json              프로젝트 설정
+//! ├─ content/index.md      첫 페이지
+//! ├─ content/blog/hello.md 예시 글
+//! ├─ static/style.css      정적 파일 (그대로 복사)
+//! ├─ .gitignore            dist/ 제외
+//! │  (--rust)
+//! ├─ Cargo.toml            quo 의존성, site 바이너리
+//! └─ site.rs               Site 정의 (quo::cli::main_with)
+//! ```
+//!
+//! ## 설계 결정
+//! - **덮어쓰지 않음**: 대상 디렉토리가 비어 있지 않으면 아무것도 쓰지 않고 실패합니다.
+//! - **사이트 이름 기본값은 디렉토리 이름**: `--name`으로 바꿀 수 있습니다.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::cli::CliError;
+use crate::cli::project::DEFAULT_CONFIG_FILE;
+
+/// Rust 프로젝트의 Site 정의 파일.
+pub const SITE_FILE: &str = "site.rs";
+
+/// `dir`에 새 프로젝트 생성. 만든 파일 목록(`dir` 기준)을 반환합니다.
+pub fn create(dir: &Path, name: Option<&str>, rust: bool) -> Result<Vec<PathBuf>, CliError> {
+    let not_empty = fs::read_dir(dir).is_ok_and(|mut entries| entries.next().is_some());
+    if not_empty {
+        return Err(CliError::Usage(format!(
+            "directory is not empty: {}",
+            dir.display()
+        )));
+    }
+
+    let name = match name {
+        Some(name) => name.to_string(),
+        None => dir
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_else(|| "My Site".to_string()),
+    };
+    let files = project_files(&name, rust);
+    for (path, contents) in &files {
+        let target = dir.join(path);
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent).map_err(|err| CliError::io(parent, err))?;
+        }
+        fs::write(&target, contents).map_err(|err| CliError::io(&target, err))?;
+    }
+    Ok(files.into_iter().map(|(path, _)| path).collect())
+}
+
+/// 만들 파일 (상대 경로, 내용).
+fn project_files(name: &str, rust: bool) -> Vec<(PathBuf, String)> {
+    let mut files = vec![
+        (
+            PathBuf::from(DEFAULT_CONFIG_FILE),
+            format!(
+                r#"{{
+  "name": {},
+  "base_url": "https://example.com",
+  "language": "en",
+  "output_dir": "dist",
+  "content_dir": "content",
+  "static_dir": "static",
+  "pretty_urls": true
+}}
+"#,
+                serde_json::Value::String(name.to_string())
+            ),
+        ),
+        (
+            PathBuf::from("content/index.md"),
+            format!(
+                "---\ntitle: {}\n---\nWelcome to your new site. Edit `content/index.md` to change this page.\n\n\
+                 Read the [first post](/blog/hello/).\n",
+                name
+            ),
+        ),
+        (
+            PathBuf::from("content/blog/hello.md"),
+            "---\ntitle: Hello, world\ndate: 2024-01-01\ntags: [welcome]\n---\n\
+             This is your first post. Add more Markdown files under `content/` and run `quo serve`.\n"
+                .to_string(),
+        ),
+        (
+            PathBuf::from("static/style.css"),
+            "body {\n  max-width: 42rem;\n  margin: 0 auto;\n  padding: 1rem;\n  font-family: system-ui, sans-serif;\n}\n"
+                .to_string(),
+        ),
+        (PathBuf::from(".gitignore"), "/dist\n/target\n".to_string()),
+    ];
+    if rust {
+        files.push((
+            PathBuf::from("Cargo.toml"),
+            format!(
+                "[package]\nname = \"{}\"\nversion = \"0.1.0\"\nedition = \"2024\"\npublish = false\n\n\
+                 [[bin]]\nname = \"site\"\npath = \"{}\"\n\n\
+                 [dependencies]\nquo = \"{}\"\n",
+                package_name(name),
+                SITE_FILE,
+                env!("CARGO_PKG_VERSION")
+            ),
+        ));
+        files.push((
+            PathBuf::from(SITE_FILE),
+            "use std::process::ExitCode;\n\n\
+             use quo::cite::sitemap::SitemapGenerator;\n\n\
+             fn main() -> ExitCode {\n\
+             \x20   quo::cli::main_with(|project| {\n\
+             \x20       // quo.json 설정과 content/의 Markdown 페이지\n\
+             \x20       let mut site = project.site()?;\n\
+             \x20       site.register_visitor(SitemapGenerator::new());\n\
+             \x20       Ok(site)\n\
+             \x20   })\n\
+             }\n"
+                .to_string(),
+        ));
+    }
+    files
+}
+
+/// Cargo 패키지 이름 (ASCII 소문자, 숫자, `-`).
+fn package_name(name: &str) -> String {
+    let mut package = String::new();
+    for c in name.chars() {
+        if c.is_ascii_alphanumeric() {
+            package.push(c.to_ascii_lowercase());
+        } else if !package.is_empty() && !package.ends_with('-') {
+            package.push('-');
+        }
+    }
+    let package = package.trim_end_matches('-');
+    match package.chars().next() {
+        None => "site".to_string(),
+        Some(c) if c.is_ascii_digit() => format!("site-{}", package),
+        Some(_) => package.to_string(),
+    }
+}
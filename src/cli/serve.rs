@@ -0,0 +1,258 @@
+//! # serve.rs - `quo serve`
+//!
+//! ## 목적
+//! 사이트를 빌드하고 출력 디렉토리를 로컬 HTTP 서버로 보여 줍니다. 감시 모드에서는 콘텐츠,
+//! 정적 파일, 설정 파일이 바뀔 때마다 사이트를 다시 만들어 빌드합니다.
+//!
+//! ## 동작
+//! ```text
+//! quo serve --port 8000
+//! Built 3 pages into dist: ...
+//! Serving dist at http://127.0.0.1:8000/ (watching for changes, Ctrl+C to stop)
+//! content/blog/hello.md changed, rebuilding
+//! Built 3 pages (2 unchanged) into dist: ...
+//! ```
+//!
+//! ## 응답 규칙
+//! - `GET`/`HEAD`만 처리합니다. 다른 메서드는 `405`.
+//! - `/blog/` → `blog/index.html`, `/about` → `about`, 없으면 `about.html`
+//! - 디렉토리를 `/` 없이 요청하면 `301`로 `/`를 붙입니다.
+//! - 없는 파일은 출력 디렉토리의 `404.html`(있으면)로 `404` 응답.
+//! - `..` 등 출력 디렉토리 밖을 가리키는 경로는 `404`.
+//!
+//! ## 설계 결정
+//! - **미리보기 전용**: `127.0.0.1`에만 연결하고 연결마다 스레드 하나로 처리합니다.
+//! - **감시 중 빌드는 증분**: `Site::incremental`로 바뀐 페이지만 다시 렌더링합니다.
+//! - **빌드 실패는 서버를 멈추지 않음**: 오류를 출력하고 이전 출력을 계속 보여 줍니다.
+
+use std::{
+    collections::BTreeMap,
+    fs,
+    io::{BufRead, BufReader, Write},
+    net::{TcpListener, TcpStream},
+    path::{Component, Path, PathBuf},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use crate::cite::cite::Site;
+use crate::cite::mime::content_type;
+use crate::cli::CliError;
+use crate::cli::project::ProjectConfig;
+use crate::html::trust::decode_url;
+
+/// 기본 포트.
+pub const DEFAULT_PORT: u16 = 8000;
+
+/// 변경 확인 주기.
+pub const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// 빌드 후 서버 실행. 반환하지 않으며, 시작 전 오류(설정, 첫 빌드, 포트)만 돌려줍니다.
+pub fn serve(
+    config: &Path,
+    port: u16,
+    watch: bool,
+    site: &dyn Fn(&ProjectConfig) -> Result<Site, CliError>,
+) -> Result<(), CliError> {
+    let project = ProjectConfig::read(config)?;
+    let report = site(&project)?.incremental(true).build_output()?;
+    println!("{}", report);
+
+    let output_dir = project.output_path();
+    let listener = TcpListener::bind(("127.0.0.1", port))
+        .map_err(|err| CliError::io(Path::new(&format!("127.0.0.1:{}", port)), err))?;
+    let root = output_dir.clone();
+    thread::spawn(move || {
+        for stream in listener.incoming().flatten() {
+            let root = root.clone();
+            thread::spawn(move || handle(stream, &root));
+        }
+    });
+    println!(
+        "Serving {} at http://127.0.0.1:{}/ ({}Ctrl+C to stop)",
+        output_dir.display(),
+        port,
+        if watch { "watching for changes, " } else { "" }
+    );
+
+    if !watch {
+        loop {
+            thread::park();
+        }
+    }
+    let mut snapshot = Snapshot::take(config, &project);
+    loop {
+        thread::sleep(POLL_INTERVAL);
+        let current = Snapshot::take(config, &project);
+        let changed = snapshot.changed(&current);
+        if changed.is_empty() {
+            continue;
+        }
+        snapshot = current;
+        match changed.as_slice() {
+            [path] => println!("{} changed, rebuilding", path.display()),
+            paths => println!("{} files changed, rebuilding", paths.len()),
+        }
+        // 설정 파일이 바뀌었을 수 있으므로 매번 다시 읽음
+        let result = ProjectConfig::read(config)
+            .and_then(|project| Ok(site(&project)?.incremental(true).build_output()?));
+        match result {
+            Ok(report) => println!("{}", report),
+            Err(err) => eprintln!("error: {}", err),
+        }
+    }
+}
+
+/// 감시 대상 파일의 수정 시각.
+#[derive(Debug, Default, PartialEq, Eq)]
+struct Snapshot(BTreeMap<PathBuf, SystemTime>);
+
+impl Snapshot {
+    fn take(config: &Path, project: &ProjectConfig) -> Self {
+        let mut files = BTreeMap::new();
+        record(config, &mut files);
+        for dir in [project.content_path(), project.static_path()] {
+            walk(&dir, &mut files);
+        }
+        Snapshot(files)
+    }
+
+    /// 추가, 삭제, 수정된 파일 (경로순).
+    fn changed(&self, current: &Snapshot) -> Vec<PathBuf> {
+        let mut changed: Vec<PathBuf> = current
+            .0
+            .iter()
+            .filter(|(path, time)| self.0.get(*path) != Some(*time))
+            .map(|(path, _)| path.clone())
+            .collect();
+        changed.extend(self.0.keys().filter(|p| !current.0.contains_key(*p)).cloned());
+        changed.sort();
+        changed
+    }
+}
+
+fn record(path: &Path, files: &mut BTreeMap<PathBuf, SystemTime>) {
+    if let Ok(modified) = fs::metadata(path).and_then(|m| m.modified()) {
+        files.insert(path.to_path_buf(), modified);
+    }
+}
+
+fn walk(dir: &Path, files: &mut BTreeMap<PathBuf, SystemTime>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            walk(&path, files);
+        } else {
+            record(&path, files);
+        }
+    }
+}
+
+// ============================================================================
+// HTTP
+// ============================================================================
+
+fn handle(mut stream: TcpStream, root: &Path) {
+    let mut line = String::new();
+    if BufReader::new(&stream).read_line(&mut line).is_err() {
+        return;
+    }
+    let mut parts = line.split_whitespace();
+    let (Some(method), Some(target)) = (parts.next(), parts.next()) else {
+        return;
+    };
+    let head_only = method == "HEAD";
+    let response = match method {
+        "GET" | "HEAD" => respond(root, target),
+        _ => Response::text(405, "Method Not Allowed"),
+    };
+    let _ = response.write_to(&mut stream, head_only);
+}
+
+struct Response {
+    status: u16,
+    content_type: &'static str,
+    location: Option<String>,
+    body: Vec<u8>,
+}
+
+impl Response {
+    fn text(status: u16, body: &str) -> Self {
+        Response {
+            status,
+            content_type: "text/plain; charset=utf-8",
+            location: None,
+            body: body.as_bytes().to_vec(),
+        }
+    }
+
+    fn write_to(&self, stream: &mut TcpStream, head_only: bool) -> std::io::Result<()> {
+        let reason = match self.status {
+            200 => "OK",
+            301 => "Moved Permanently",
+            404 => "Not Found",
+            405 => "Method Not Allowed",
+            _ => "Error",
+        };
+        let mut head = format!(
+            "HTTP/1.1 {} {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nCache-Control: no-store\r\nConnection: close\r\n",
+            self.status,
+            reason,
+            self.content_type,
+            self.body.len()
+        );
+        if let Some(location) = &self.location {
+            head.push_str(&format!("Location: {}\r\n", location));
+        }
+        head.push_str("\r\n");
+        stream.write_all(head.as_bytes())?;
+        if !head_only {
+            stream.write_all(&self.body)?;
+        }
+        stream.flush()
+    }
+}
+
+/// 요청 경로 → 응답.
+fn respond(root: &Path, target: &str) -> Response {
+    let path = target.split(['?', '#']).next().unwrap_or("/");
+    let decoded = decode_url(path);
+    let relative = PathBuf::from(decoded.trim_start_matches('/'));
+    if !relative.components().all(|c| matches!(c, Component::Normal(_))) {
+        return not_found(root);
+    }
+
+    let file = root.join(&relative);
+    if file.is_dir() {
+        if !path.ends_with('/') {
+            return Response {
+                location: Some(format!("{}/", path)),
+                ..Response::text(301, "Moved Permanently")
+            };
+        }
+        return file_response(200, &file.join("index.html")).unwrap_or_else(|| not_found(root));
+    }
+    file_response(200, &file)
+        .or_else(|| file_response(200, &file.with_extension("html")))
+        .unwrap_or_else(|| not_found(root))
+}
+
+fn not_found(root: &Path) -> Response {
+    file_response(404, &root.join("404.html")).unwrap_or_else(|| Response::text(404, "Not Found"))
+}
+
+fn file_response(status: u16, path: &Path) -> Option<Response> {
+    if !path.is_file() {
+        return None;
+    }
+    let body = fs::read(path).ok()?;
+    Some(Response {
+        status,
+        content_type: content_type(path),
+        location: None,
+        body,
+    })
+}
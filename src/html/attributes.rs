@@ -42,10 +42,10 @@
 //! ## 데이터 구조
 //!
 //! ### AttrHashMap
-//! 실제 속성 데이터를 저장하는 HashMap 래퍼.
+//! 실제 속성 데이터를 저장하는 맵 래퍼. 키 순서로 순회하므로 같은 속성은 항상 같은 순서로 출력됩니다.
 //! ```rust
 //! pub struct AttrHashMap {
-//!     table: HashMap<AttrKey, AttrValues>,
+//!     table: BTreeMap<AttrKey, AttrValues>,
 //! }
 //! ```
 //!
@@ -55,7 +55,7 @@
 //! pub enum AttrValues {
 //!     Token(AttrValue),           // 단일 값: id="main"
 //!     Bool(bool),                 // 불린 속성: disabled
-//!     Set(HashSet<AttrValue>),    // 집합: class="btn primary"
+//!     Set(BTreeSet<AttrValue>),   // 집합: class="btn primary" (정렬)
 //!     List(Vec<AttrValue>),       // 순서 있는 목록 (향후 사용)
 //! }
 //! ```
@@ -90,12 +90,21 @@
 //! - [x] AttrValues enum (Token, Bool, Set, List)
 //! - [x] SharedAttrs (Arc 기반 공유)
 //! - [x] PhantomData 타입 제약
-//! - [x] Global 속성 (id, class, title)
+//! - [x] Global 속성 (id, class, title, lang)
 //! - [x] Image 속성 (src, alt)
+//! - [x] Anchor 속성 (href, target, rel, download)
+//! - [x] TableCell 속성 (colspan, rowspan), Column 속성 (span)
+//! - [x] Form 속성 (name, value, type, required, disabled, placeholder, action, method)
+//! - [x] Media 속성 (controls, autoplay, loop, muted, preload, poster, source/track 속성)
+//! - [x] Metadata 속성 (charset, content, http-equiv, property, hreflang, as, crossorigin, integrity, nonce, defer, async)
+//! - [x] Quote 속성 (cite)
+//! - [x] Disclosure 속성 (open, details의 name)
+//! - [x] Timestamp 속성 (datetime: `Datetime`), Value 속성 (value)
+//! - [x] Edit 속성 (del, ins의 cite, datetime)
+//! - [x] 공유 속성 (src: ForSrc, type: ForType, href: ForHref, rel: ForRel, name: ForName, media: ForMediaQuery, value: ForValue)
 //! - [x] MergeMode (Keep, Force)
 //! - [x] class 속성 병합 로직
 //! - [ ] TODO: 더 많은 Global 속성 (data-*, aria-*, style 등)
-//! - [ ] TODO: 다른 요소별 속성 그룹 (Form, Table, Media 등)
 //! - [ ] TODO: 속성값 검증 (URL 형식, 숫자 범위 등)
 //!
 //! ## 핵심 타입
@@ -121,7 +130,7 @@
 //! 중복 없는 값 집합 (주로 class 속성).
 //! ```rust
 //! // class="btn primary large"
-//! AttrValues::Set(HashSet::from([
+//! AttrValues::Set(BTreeSet::from([
 //!     AttrValue::from_str("btn", &rule),
 //!     AttrValue::from_str("primary", &rule),
 //!     AttrValue::from_str("large", &rule),
@@ -236,6 +245,12 @@
 //! - 메모리 오버헤드 없음
 //! - IDE 자동완성 지원
 //!
+//! ### 여러 그룹에 있는 속성은?
+//! `src`(img, video, source, script)와 `type`(input, source, link)처럼 여러 그룹에 있는 속성은 공유 트레이트
+//! (`ForSrc`, `ForType`, `ForHref`, `ForRel`, `ForName`, `ForMediaQuery`)에 한 번만 정의하고, 필요한 그룹 타입이 함께 구현합니다.
+//! 같은 이름의 메서드를 `impl<T: ForImage>`와 `impl<T: ForMedia>`에 따로 두면 한 타입이 두 트레이트를
+//! 모두 구현할 수 있으므로 중복 정의 오류가 납니다.
+//!
 //! ### 왜 Arc<AttrHashMap>인가?
 //! **이유:**
 //! - IRNode는 불변 구조
@@ -289,12 +304,12 @@
 //!   - [ ] aria-* 속성 지원
 //!   - [ ] style 속성 (인라인 CSS)
 //!   - [ ] role 속성
-//! - [ ] Form 속성 그룹 (name, value, type, required 등)
+//! - [x] Form 속성 그룹 (name, value, type, required 등)
 //! - [ ] 속성값 검증 (URL, 숫자, 열거형)
 //!
 //! ### 우선순위: 중간
-//! - [ ] Table 속성 (colspan, rowspan)
-//! - [ ] Media 속성 (controls, autoplay, loop)
+//! - [x] Table 속성 (colspan, rowspan)
+//! - [x] Media 속성 (controls, autoplay, loop)
 //! - [ ] 이벤트 핸들러 속성 (onclick 등) - 사용 여부 검토
 //!
 //! ### 우선순위: 낮음
@@ -349,7 +364,7 @@
 //! ```
 
 use std::{
-    collections::{HashMap, HashSet},
+    collections::{BTreeMap, BTreeSet},
     fmt::Write,
     marker::PhantomData, 
     sync::Arc,
@@ -365,22 +380,20 @@ use crate::html::{
 pub enum AttrValues {
     Token(trust::AttrValue),           // 단일 값: id="main"
     Bool(bool),                        // 불린 속성: disabled
-    Set(HashSet<trust::AttrValue>),    // 집합: class="btn primary"
+    Set(BTreeSet<trust::AttrValue>),   // 집합: class="btn primary"
     List(Vec<trust::AttrValue>),       // 순서 있는 목록 (향후 사용)
 }
 
 impl AttrValues {
-    /// Vec<String>을 HashSet<AttrValue>로 변환.
+    /// Vec<String>을 BTreeSet<AttrValue>로 변환.
     /// class 속성 등에서 사용.
-    pub fn build_set<T>(list: Vec<String>, rule: &T) -> HashSet<AttrValue>
+    pub fn build_set<T>(list: Vec<String>, rule: &T) -> BTreeSet<AttrValue>
     where
         T: rules::Rules,
     {
-        let set = list
-            .into_iter()
+        list.into_iter()
             .map(|s| AttrValue::from_str(&s, rule))
-            .collect();
-        set
+            .collect()
     }
 }
 
@@ -393,7 +406,7 @@ pub enum MergeMode {
 
 /// Arc로 감싼 불변 속성 맵. IRNode에서 사용.
 /// clone()은 참조 카운트만 증가 (cheap).
-#[derive(Clone)]
+#[derive(Clone, Default)]
 pub struct SharedAttrs(Arc<AttrHashMap>);
 
 impl SharedAttrs {
@@ -422,16 +435,16 @@ impl SharedAttrs {
     }
 }
 
-/// 실제 속성 데이터를 저장하는 HashMap 래퍼.
-#[derive(Clone)]
+/// 실제 속성 데이터를 저장하는 맵 래퍼 (키 순서로 순회).
+#[derive(Clone, Default)]
 pub struct AttrHashMap {
-    table: HashMap<trust::AttrKey, AttrValues>,
+    table: BTreeMap<trust::AttrKey, AttrValues>,
 }
 
 impl AttrHashMap {
     pub fn new() -> Self {
         AttrHashMap {
-            table: HashMap::new(),
+            table: BTreeMap::new(),
         }
     }
 
@@ -469,7 +482,7 @@ impl AttrHashMap {
                 }
             }
             MergeMode::Force => {
-                table.extend(map.table.into_iter());
+                table.extend(map.table);
             }
         }
 
@@ -487,10 +500,7 @@ impl AttrHashMap {
     /// - Set: ` key="val1 val2"` (정렬됨)
     pub fn into_string(&self) -> String {
         let mut result = String::new();
-        let mut sorted_attrs: Vec<_> = self.table.iter().collect();
-        sorted_attrs.sort_by_key(|(k, _)| *k);
-        
-        for (k, v) in sorted_attrs {
+        for (k, v) in &self.table {
             match v {
                 AttrValues::Token(val) => {
                     let _ = write!(result, r#" {}="{}""#, k.as_str(), val.as_str());
@@ -502,9 +512,7 @@ impl AttrHashMap {
                     if classes.is_empty() {
                         continue;
                     }
-                    let mut sorted_classes: Vec<_> = classes.iter().collect();
-                    sorted_classes.sort();
-                    let class_string = sorted_classes
+                    let class_string = classes
                         .iter()
                         .map(|c| c.as_str())
                         .collect::<Vec<_>>()
@@ -530,6 +538,54 @@ pub mod attr_types {
     
     /// Image 속성: img 요소 전용 + Global 속성.
     pub trait ForImage: ForGlobal {}
+
+    /// Anchor 속성: a 요소 전용 + Global 속성.
+    pub trait ForAnchor: ForGlobal {}
+
+    /// TableCell 속성: th, td 요소 전용 + Global 속성.
+    pub trait ForTableCell: ForGlobal {}
+
+    /// Column 속성: col, colgroup 요소 전용 + Global 속성.
+    pub trait ForColumn: ForGlobal {}
+
+    /// Form 속성: 폼과 폼 컨트롤(input, button, select 등) + Global 속성.
+    pub trait ForForm: ForGlobal {}
+
+    /// Media 속성: video, audio와 그 안의 source, track + Global 속성.
+    pub trait ForMedia: ForGlobal {}
+
+    /// Metadata 속성: 문서 머리의 meta, link, script, style + Global 속성.
+    pub trait ForMetadata: ForGlobal {}
+
+    /// Quote 속성: 인용 출처가 있는 blockquote, del, ins 요소 + Global 속성.
+    pub trait ForQuote: ForGlobal {}
+
+    /// Disclosure 속성: 열고 닫는 details, dialog 요소 + Global 속성.
+    pub trait ForDisclosure: ForGlobal {}
+
+    /// Timestamp 속성: 날짜, 시각을 담는 time, del, ins 요소 + Global 속성.
+    pub trait ForDatetime: ForGlobal {}
+
+    /// src 속성 (공유): 외부 리소스를 가리키는 요소 (img, video, audio, source, track, script).
+    pub trait ForSrc: ForGlobal {}
+
+    /// type 속성 (공유): 폼 컨트롤 종류, source, link, script의 MIME 타입.
+    pub trait ForType: ForGlobal {}
+
+    /// href 속성 (공유): a, link.
+    pub trait ForHref: ForGlobal {}
+
+    /// rel 속성 (공유): a, link.
+    pub trait ForRel: ForGlobal {}
+
+    /// name 속성 (공유): 폼 컨트롤, meta, details.
+    pub trait ForName: ForGlobal {}
+
+    /// media 속성 (공유): source, link, style의 미디어 쿼리.
+    pub trait ForMediaQuery: ForGlobal {}
+
+    /// value 속성 (공유): 폼 컨트롤(input, button, option)과 data.
+    pub trait ForValue: ForGlobal {}
 }
 
 /// Global 속성 타입.
@@ -542,6 +598,89 @@ impl attr_types::ForGlobal for Global {}
 pub struct Image;
 impl attr_types::ForGlobal for Image {}
 impl attr_types::ForImage for Image {}
+impl attr_types::ForSrc for Image {}
+
+/// Anchor 속성 타입.
+#[derive(Clone)]
+pub struct Anchor;
+impl attr_types::ForGlobal for Anchor {}
+impl attr_types::ForAnchor for Anchor {}
+impl attr_types::ForHref for Anchor {}
+impl attr_types::ForRel for Anchor {}
+
+/// TableCell 속성 타입.
+#[derive(Clone)]
+pub struct TableCell;
+impl attr_types::ForGlobal for TableCell {}
+impl attr_types::ForTableCell for TableCell {}
+
+/// Column 속성 타입.
+#[derive(Clone)]
+pub struct Column;
+impl attr_types::ForGlobal for Column {}
+impl attr_types::ForColumn for Column {}
+
+/// Form 속성 타입.
+#[derive(Clone)]
+pub struct Form;
+impl attr_types::ForGlobal for Form {}
+impl attr_types::ForForm for Form {}
+impl attr_types::ForType for Form {}
+impl attr_types::ForName for Form {}
+impl attr_types::ForValue for Form {}
+
+/// Media 속성 타입.
+#[derive(Clone)]
+pub struct Media;
+impl attr_types::ForGlobal for Media {}
+impl attr_types::ForMedia for Media {}
+impl attr_types::ForSrc for Media {}
+impl attr_types::ForType for Media {}
+impl attr_types::ForMediaQuery for Media {}
+
+/// Metadata 속성 타입.
+#[derive(Clone)]
+pub struct Metadata;
+impl attr_types::ForGlobal for Metadata {}
+impl attr_types::ForMetadata for Metadata {}
+impl attr_types::ForSrc for Metadata {}
+impl attr_types::ForType for Metadata {}
+impl attr_types::ForHref for Metadata {}
+impl attr_types::ForRel for Metadata {}
+impl attr_types::ForName for Metadata {}
+impl attr_types::ForMediaQuery for Metadata {}
+
+/// Quote 속성 타입.
+#[derive(Clone)]
+pub struct Quote;
+impl attr_types::ForGlobal for Quote {}
+impl attr_types::ForQuote for Quote {}
+
+/// Disclosure 속성 타입.
+#[derive(Clone)]
+pub struct Disclosure;
+impl attr_types::ForGlobal for Disclosure {}
+impl attr_types::ForDisclosure for Disclosure {}
+impl attr_types::ForName for Disclosure {}
+
+/// Timestamp 속성 타입.
+#[derive(Clone)]
+pub struct Timestamp;
+impl attr_types::ForGlobal for Timestamp {}
+impl attr_types::ForDatetime for Timestamp {}
+
+/// Edit 속성 타입 (del, ins: cite + datetime).
+#[derive(Clone)]
+pub struct Edit;
+impl attr_types::ForGlobal for Edit {}
+impl attr_types::ForQuote for Edit {}
+impl attr_types::ForDatetime for Edit {}
+
+/// Value 속성 타입.
+#[derive(Clone)]
+pub struct Value;
+impl attr_types::ForGlobal for Value {}
+impl attr_types::ForValue for Value {}
 
 // ============================================================================
 // Attributes 구조체 (PhantomData 타입 제약)
@@ -573,6 +712,94 @@ impl AttrBuilder {
             _marker: PhantomData,
         }
     }
+
+    /// Anchor 속성 사용 가능한 빌더 생성 (Global 포함).
+    pub fn anchor() -> Attributes<Anchor> {
+        Attributes {
+            table: AttrHashMap::new(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// TableCell 속성 사용 가능한 빌더 생성 (Global 포함).
+    pub fn table_cell() -> Attributes<TableCell> {
+        Attributes {
+            table: AttrHashMap::new(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Column 속성 사용 가능한 빌더 생성 (Global 포함).
+    pub fn column() -> Attributes<Column> {
+        Attributes {
+            table: AttrHashMap::new(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Form 속성 사용 가능한 빌더 생성 (Global 포함).
+    pub fn form() -> Attributes<Form> {
+        Attributes {
+            table: AttrHashMap::new(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Media 속성 사용 가능한 빌더 생성 (Global 포함).
+    pub fn media() -> Attributes<Media> {
+        Attributes {
+            table: AttrHashMap::new(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Metadata 속성 사용 가능한 빌더 생성 (Global 포함).
+    pub fn metadata() -> Attributes<Metadata> {
+        Attributes {
+            table: AttrHashMap::new(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Quote 속성 사용 가능한 빌더 생성 (Global 포함).
+    pub fn quote() -> Attributes<Quote> {
+        Attributes {
+            table: AttrHashMap::new(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Disclosure 속성 사용 가능한 빌더 생성 (Global 포함).
+    pub fn disclosure() -> Attributes<Disclosure> {
+        Attributes {
+            table: AttrHashMap::new(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Timestamp 속성 사용 가능한 빌더 생성 (Global 포함).
+    pub fn timestamp() -> Attributes<Timestamp> {
+        Attributes {
+            table: AttrHashMap::new(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Edit 속성 사용 가능한 빌더 생성 (Global 포함).
+    pub fn edit() -> Attributes<Edit> {
+        Attributes {
+            table: AttrHashMap::new(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Value 속성 사용 가능한 빌더 생성 (Global 포함).
+    pub fn value() -> Attributes<Value> {
+        Attributes {
+            table: AttrHashMap::new(),
+            _marker: PhantomData,
+        }
+    }
 }
 
 // ============================================================================
@@ -597,7 +824,7 @@ impl<T: attr_types::ForGlobal> Attributes<T> {
     /// - 기존 class와 병합 (덮어쓰기 아님)
     /// - 중복 자동 제거
     /// - 출력 시 알파벳 순 정렬
-    pub fn class(self, classes: HashSet<trust::AttrValue>) -> Self {
+    pub fn class(self, classes: BTreeSet<trust::AttrValue>) -> Self {
         let class_key = trust::AttrKey::from_str("class");
         let mut classes = classes;
 
@@ -636,6 +863,17 @@ impl<T: attr_types::ForGlobal> Attributes<T> {
             _marker: self._marker,
         }
     }
+
+    /// lang 속성 설정. 요소 내용의 언어 (BCP 47, `ko`, `en-US`).
+    pub fn lang(self, lang: trust::AttrValue) -> Self {
+        let table = self
+            .table
+            .add(trust::AttrKey::from_str("lang"), AttrValues::Token(lang));
+        Attributes {
+            table,
+            _marker: self._marker,
+        }
+    }
 }
 
 // ============================================================================
@@ -643,32 +881,721 @@ impl<T: attr_types::ForGlobal> Attributes<T> {
 // ============================================================================
 
 impl<T: attr_types::ForImage> Attributes<T> {
-    /// src 속성 설정. 이미지 URL (필수).
-    pub fn src(self, src: trust::AttrValue) -> Self {
+    /// alt 속성 설정. 대체 텍스트 (필수).
+    pub fn alt(self, alt: trust::AttrValue) -> Self {
         let table = self
             .table
-            .add(trust::AttrKey::from_str("src"), AttrValues::Token(src));
+            .add(trust::AttrKey::from_str("alt"), AttrValues::Token(alt));
         Attributes {
             table,
             _marker: self._marker,
         }
     }
-    
-    /// alt 속성 설정. 대체 텍스트 (필수).
-    pub fn alt(self, alt: trust::AttrValue) -> Self {
+}
+
+// ============================================================================
+// Anchor 속성 구현 (a 요소)
+// ============================================================================
+
+impl<T: attr_types::ForAnchor> Attributes<T> {
+    /// target 속성 설정. 링크를 열 브라우징 컨텍스트 (`_blank` 등).
+    pub fn target(self, target: trust::AttrValue) -> Self {
         let table = self
             .table
-            .add(trust::AttrKey::from_str("alt"), AttrValues::Token(alt));
+            .add(trust::AttrKey::from_str("target"), AttrValues::Token(target));
+        Attributes {
+            table,
+            _marker: self._marker,
+        }
+    }
+
+    /// download 속성 설정. 저장할 파일 이름 (빈 값이면 URL의 이름).
+    pub fn download(self, filename: trust::AttrValue) -> Self {
+        let table = self
+            .table
+            .add(trust::AttrKey::from_str("download"), AttrValues::Token(filename));
+        Attributes {
+            table,
+            _marker: self._marker,
+        }
+    }
+}
+
+// ============================================================================
+// TableCell 속성 구현 (th, td 요소)
+// ============================================================================
+
+impl<T: attr_types::ForTableCell> Attributes<T> {
+    /// colspan 속성 설정. 셀이 차지하는 열 수 (기본 1).
+    pub fn colspan(self, colspan: trust::AttrValue) -> Self {
+        let table = self
+            .table
+            .add(trust::AttrKey::from_str("colspan"), AttrValues::Token(colspan));
+        Attributes {
+            table,
+            _marker: self._marker,
+        }
+    }
+
+    /// rowspan 속성 설정. 셀이 차지하는 행 수 (기본 1, 0이면 행 그룹 끝까지).
+    pub fn rowspan(self, rowspan: trust::AttrValue) -> Self {
+        let table = self
+            .table
+            .add(trust::AttrKey::from_str("rowspan"), AttrValues::Token(rowspan));
+        Attributes {
+            table,
+            _marker: self._marker,
+        }
+    }
+}
+
+// ============================================================================
+// Column 속성 구현 (col, colgroup 요소)
+// ============================================================================
+
+impl<T: attr_types::ForColumn> Attributes<T> {
+    /// span 속성 설정. 요소가 나타내는 열 수 (기본 1).
+    pub fn span(self, span: trust::AttrValue) -> Self {
+        let table = self
+            .table
+            .add(trust::AttrKey::from_str("span"), AttrValues::Token(span));
+        Attributes {
+            table,
+            _marker: self._marker,
+        }
+    }
+}
+
+// ============================================================================
+// Form 속성 구현 (form, input, button, select, option, textarea, fieldset)
+// ============================================================================
+
+impl<T: attr_types::ForForm> Attributes<T> {
+    /// required 속성 설정. 값이 없으면 제출 안 됨.
+    pub fn required(self, required: bool) -> Self {
+        let table = self
+            .table
+            .add(trust::AttrKey::from_str("required"), AttrValues::Bool(required));
+        Attributes {
+            table,
+            _marker: self._marker,
+        }
+    }
+
+    /// disabled 속성 설정. 조작할 수 없고 제출되지 않음.
+    pub fn disabled(self, disabled: bool) -> Self {
+        let table = self
+            .table
+            .add(trust::AttrKey::from_str("disabled"), AttrValues::Bool(disabled));
+        Attributes {
+            table,
+            _marker: self._marker,
+        }
+    }
+
+    /// placeholder 속성 설정. 값이 비었을 때 보이는 힌트 (input, textarea).
+    pub fn placeholder(self, placeholder: trust::AttrValue) -> Self {
+        let table = self
+            .table
+            .add(trust::AttrKey::from_str("placeholder"), AttrValues::Token(placeholder));
+        Attributes {
+            table,
+            _marker: self._marker,
+        }
+    }
+
+    /// action 속성 설정. 제출할 URL (form).
+    pub fn action(self, action: trust::AttrValue) -> Self {
+        let table = self
+            .table
+            .add(trust::AttrKey::from_str("action"), AttrValues::Token(action));
+        Attributes {
+            table,
+            _marker: self._marker,
+        }
+    }
+
+    /// method 속성 설정. `get` 또는 `post` (form).
+    pub fn method(self, method: trust::AttrValue) -> Self {
+        let table = self
+            .table
+            .add(trust::AttrKey::from_str("method"), AttrValues::Token(method));
+        Attributes {
+            table,
+            _marker: self._marker,
+        }
+    }
+}
+
+// ============================================================================
+// Media 속성 구현 (video, audio, source, track)
+// ============================================================================
+
+impl<T: attr_types::ForMedia> Attributes<T> {
+    /// controls 속성 설정. 브라우저 기본 재생 컨트롤 표시.
+    pub fn controls(self, controls: bool) -> Self {
+        let table = self
+            .table
+            .add(trust::AttrKey::from_str("controls"), AttrValues::Bool(controls));
+        Attributes {
+            table,
+            _marker: self._marker,
+        }
+    }
+
+    /// autoplay 속성 설정. 자동 재생 (대부분 브라우저는 `muted`일 때만 허용).
+    pub fn autoplay(self, autoplay: bool) -> Self {
+        let table = self
+            .table
+            .add(trust::AttrKey::from_str("autoplay"), AttrValues::Bool(autoplay));
+        Attributes {
+            table,
+            _marker: self._marker,
+        }
+    }
+
+    /// loop 속성 설정. 끝나면 처음부터 다시 재생.
+    pub fn r#loop(self, looping: bool) -> Self {
+        let table = self
+            .table
+            .add(trust::AttrKey::from_str("loop"), AttrValues::Bool(looping));
+        Attributes {
+            table,
+            _marker: self._marker,
+        }
+    }
+
+    /// muted 속성 설정. 소리 끔.
+    pub fn muted(self, muted: bool) -> Self {
+        let table = self
+            .table
+            .add(trust::AttrKey::from_str("muted"), AttrValues::Bool(muted));
         Attributes {
             table,
             _marker: self._marker,
         }
     }
+
+    /// preload 속성 설정. `none`, `metadata`, `auto`.
+    pub fn preload(self, preload: trust::AttrValue) -> Self {
+        let table = self
+            .table
+            .add(trust::AttrKey::from_str("preload"), AttrValues::Token(preload));
+        Attributes {
+            table,
+            _marker: self._marker,
+        }
+    }
+
+    /// poster 속성 설정. 재생 전 보이는 이미지 URL (video).
+    pub fn poster(self, poster: trust::AttrValue) -> Self {
+        let table = self
+            .table
+            .add(trust::AttrKey::from_str("poster"), AttrValues::Token(poster));
+        Attributes {
+            table,
+            _marker: self._marker,
+        }
+    }
+
+    /// srcset 속성 설정. 후보 이미지 URL 목록 (picture 안의 source).
+    pub fn srcset(self, srcset: trust::AttrValue) -> Self {
+        let table = self
+            .table
+            .add(trust::AttrKey::from_str("srcset"), AttrValues::Token(srcset));
+        Attributes {
+            table,
+            _marker: self._marker,
+        }
+    }
+
+    /// sizes 속성 설정. 후보 이미지의 표시 너비 (picture 안의 source).
+    pub fn sizes(self, sizes: trust::AttrValue) -> Self {
+        let table = self
+            .table
+            .add(trust::AttrKey::from_str("sizes"), AttrValues::Token(sizes));
+        Attributes {
+            table,
+            _marker: self._marker,
+        }
+    }
+
+    /// kind 속성 설정. `subtitles`, `captions`, `chapters` 등 (track).
+    pub fn kind(self, kind: trust::AttrValue) -> Self {
+        let table = self
+            .table
+            .add(trust::AttrKey::from_str("kind"), AttrValues::Token(kind));
+        Attributes {
+            table,
+            _marker: self._marker,
+        }
+    }
+
+    /// srclang 속성 설정. 자막 언어 (track).
+    pub fn srclang(self, srclang: trust::AttrValue) -> Self {
+        let table = self
+            .table
+            .add(trust::AttrKey::from_str("srclang"), AttrValues::Token(srclang));
+        Attributes {
+            table,
+            _marker: self._marker,
+        }
+    }
+
+    /// label 속성 설정. 자막 선택 메뉴에 보이는 이름 (track).
+    pub fn label(self, label: trust::AttrValue) -> Self {
+        let table = self
+            .table
+            .add(trust::AttrKey::from_str("label"), AttrValues::Token(label));
+        Attributes {
+            table,
+            _marker: self._marker,
+        }
+    }
+
+    /// default 속성 설정. 기본으로 켜는 자막 (track).
+    pub fn default(self, default: bool) -> Self {
+        let table = self
+            .table
+            .add(trust::AttrKey::from_str("default"), AttrValues::Bool(default));
+        Attributes {
+            table,
+            _marker: self._marker,
+        }
+    }
+}
+
+// ============================================================================
+// Metadata 속성 구현 (meta, link, script, style)
+// ============================================================================
+
+impl<T: attr_types::ForMetadata> Attributes<T> {
+    /// charset 속성 설정. 문서 문자 인코딩 (meta, 보통 `UTF-8`).
+    pub fn charset(self, charset: trust::AttrValue) -> Self {
+        let table = self
+            .table
+            .add(trust::AttrKey::from_str("charset"), AttrValues::Token(charset));
+        Attributes {
+            table,
+            _marker: self._marker,
+        }
+    }
+
+    /// content 속성 설정. `name`, `property`, `http-equiv`의 값 (meta).
+    pub fn content(self, content: trust::AttrValue) -> Self {
+        let table = self
+            .table
+            .add(trust::AttrKey::from_str("content"), AttrValues::Token(content));
+        Attributes {
+            table,
+            _marker: self._marker,
+        }
+    }
+
+    /// http-equiv 속성 설정. HTTP 헤더 대신 쓰는 지시어 (`refresh`, `content-security-policy` 등, meta).
+    pub fn http_equiv(self, equiv: trust::AttrValue) -> Self {
+        let table = self
+            .table
+            .add(trust::AttrKey::from_str("http-equiv"), AttrValues::Token(equiv));
+        Attributes {
+            table,
+            _marker: self._marker,
+        }
+    }
+
+    /// property 속성 설정. RDFa 속성 이름 (Open Graph `og:title` 등, meta).
+    pub fn property(self, property: trust::AttrValue) -> Self {
+        let table = self
+            .table
+            .add(trust::AttrKey::from_str("property"), AttrValues::Token(property));
+        Attributes {
+            table,
+            _marker: self._marker,
+        }
+    }
+
+    /// hreflang 속성 설정. 연결한 문서의 언어 (link `rel="alternate"`).
+    pub fn hreflang(self, hreflang: trust::AttrValue) -> Self {
+        let table = self
+            .table
+            .add(trust::AttrKey::from_str("hreflang"), AttrValues::Token(hreflang));
+        Attributes {
+            table,
+            _marker: self._marker,
+        }
+    }
+
+    /// as 속성 설정. 미리 받을 리소스 종류 (`style`, `script`, `font` 등, link `rel="preload"`).
+    pub fn r#as(self, destination: trust::AttrValue) -> Self {
+        let table = self
+            .table
+            .add(trust::AttrKey::from_str("as"), AttrValues::Token(destination));
+        Attributes {
+            table,
+            _marker: self._marker,
+        }
+    }
+
+    /// crossorigin 속성 설정. `anonymous` 또는 `use-credentials` (link, script).
+    pub fn crossorigin(self, crossorigin: trust::AttrValue) -> Self {
+        let table = self
+            .table
+            .add(trust::AttrKey::from_str("crossorigin"), AttrValues::Token(crossorigin));
+        Attributes {
+            table,
+            _marker: self._marker,
+        }
+    }
+
+    /// integrity 속성 설정. 하위 리소스 무결성 해시 (`sha384-...`, link, script).
+    pub fn integrity(self, integrity: trust::AttrValue) -> Self {
+        let table = self
+            .table
+            .add(trust::AttrKey::from_str("integrity"), AttrValues::Token(integrity));
+        Attributes {
+            table,
+            _marker: self._marker,
+        }
+    }
+
+    /// nonce 속성 설정. CSP가 허용하는 일회용 값 (script, style).
+    pub fn nonce(self, nonce: trust::AttrValue) -> Self {
+        let table = self
+            .table
+            .add(trust::AttrKey::from_str("nonce"), AttrValues::Token(nonce));
+        Attributes {
+            table,
+            _marker: self._marker,
+        }
+    }
+
+    /// defer 속성 설정. 문서를 다 읽은 뒤 실행 (외부 script).
+    pub fn defer(self, defer: bool) -> Self {
+        let table = self
+            .table
+            .add(trust::AttrKey::from_str("defer"), AttrValues::Bool(defer));
+        Attributes {
+            table,
+            _marker: self._marker,
+        }
+    }
+
+    /// async 속성 설정. 받는 대로 실행 (외부 script).
+    pub fn r#async(self, run_async: bool) -> Self {
+        let table = self
+            .table
+            .add(trust::AttrKey::from_str("async"), AttrValues::Bool(run_async));
+        Attributes {
+            table,
+            _marker: self._marker,
+        }
+    }
+}
+
+// ============================================================================
+// Quote 속성 구현 (blockquote, del, ins 요소)
+// ============================================================================
+
+impl<T: attr_types::ForQuote> Attributes<T> {
+    /// cite 속성 설정. 인용 출처나 변경 이유를 설명하는 URL (화면에 표시되지 않음).
+    pub fn cite(self, cite: trust::AttrValue) -> Self {
+        let table = self
+            .table
+            .add(trust::AttrKey::from_str("cite"), AttrValues::Token(cite));
+        Attributes {
+            table,
+            _marker: self._marker,
+        }
+    }
+}
+
+// ============================================================================
+// Disclosure 속성 구현 (details, dialog 요소)
+// ============================================================================
+
+impl<T: attr_types::ForDisclosure> Attributes<T> {
+    /// open 속성 설정. 처음부터 펼친 상태 (details), 모달 없이 열린 상태 (dialog).
+    pub fn open(self, open: bool) -> Self {
+        let table = self
+            .table
+            .add(trust::AttrKey::from_str("open"), AttrValues::Bool(open));
+        Attributes {
+            table,
+            _marker: self._marker,
+        }
+    }
+}
+
+// ============================================================================
+// Timestamp 속성 구현 (time, del, ins 요소)
+// ============================================================================
+
+impl<T: attr_types::ForDatetime> Attributes<T> {
+    /// datetime 속성 설정. 기계가 읽는 날짜, 시각 (`Datetime`이 형식을 보장).
+    pub fn datetime(self, datetime: Datetime) -> Self {
+        let value = AttrValue::from_str(datetime.as_str(), &rules::Default { rules: vec![] });
+        let table = self
+            .table
+            .add(trust::AttrKey::from_str("datetime"), AttrValues::Token(value));
+        Attributes {
+            table,
+            _marker: self._marker,
+        }
+    }
+}
+
+// ============================================================================
+// 공유 속성 구현 (여러 그룹에 있는 속성)
+// ============================================================================
+
+impl<T: attr_types::ForSrc> Attributes<T> {
+    /// src 속성 설정. 리소스 URL (img는 필수).
+    pub fn src(self, src: trust::AttrValue) -> Self {
+        let table = self
+            .table
+            .add(trust::AttrKey::from_str("src"), AttrValues::Token(src));
+        Attributes {
+            table,
+            _marker: self._marker,
+        }
+    }
+}
+
+impl<T: attr_types::ForType> Attributes<T> {
+    /// type 속성 설정. input 종류(`email` 등), button 동작(`submit`), source의 MIME 타입(`video/webm`).
+    pub fn r#type(self, kind: trust::AttrValue) -> Self {
+        let table = self
+            .table
+            .add(trust::AttrKey::from_str("type"), AttrValues::Token(kind));
+        Attributes {
+            table,
+            _marker: self._marker,
+        }
+    }
+}
+
+impl<T: attr_types::ForHref> Attributes<T> {
+    /// href 속성 설정. 링크 대상 URL (a), 연결할 리소스 URL (link).
+    pub fn href(self, href: trust::AttrValue) -> Self {
+        let table = self
+            .table
+            .add(trust::AttrKey::from_str("href"), AttrValues::Token(href));
+        Attributes {
+            table,
+            _marker: self._marker,
+        }
+    }
+}
+
+impl<T: attr_types::ForRel> Attributes<T> {
+    /// rel 속성 설정. 공백으로 구분한 링크 관계 (`noopener noreferrer`, `stylesheet`, `icon` 등).
+    pub fn rel(self, rel: trust::AttrValue) -> Self {
+        let table = self
+            .table
+            .add(trust::AttrKey::from_str("rel"), AttrValues::Token(rel));
+        Attributes {
+            table,
+            _marker: self._marker,
+        }
+    }
+}
+
+impl<T: attr_types::ForName> Attributes<T> {
+    /// name 속성 설정. 제출되는 값의 이름 (폼 컨트롤), 메타데이터 이름 (meta), 함께 하나만 열리는 묶음 (details).
+    pub fn name(self, name: trust::AttrValue) -> Self {
+        let table = self
+            .table
+            .add(trust::AttrKey::from_str("name"), AttrValues::Token(name));
+        Attributes {
+            table,
+            _marker: self._marker,
+        }
+    }
+}
+
+impl<T: attr_types::ForValue> Attributes<T> {
+    /// value 속성 설정. 컨트롤의 값 (input, button, option), 기계가 읽는 값 (data).
+    pub fn value(self, value: trust::AttrValue) -> Self {
+        let table = self
+            .table
+            .add(trust::AttrKey::from_str("value"), AttrValues::Token(value));
+        Attributes {
+            table,
+            _marker: self._marker,
+        }
+    }
+}
+
+impl<T: attr_types::ForMediaQuery> Attributes<T> {
+    /// media 속성 설정. 미디어 쿼리 (picture 안의 source, link, style).
+    pub fn media(self, media: trust::AttrValue) -> Self {
+        let table = self
+            .table
+            .add(trust::AttrKey::from_str("media"), AttrValues::Token(media));
+        Attributes {
+            table,
+            _marker: self._marker,
+        }
+    }
+}
+
+// ============================================================================
+// Datetime (datetime 속성값)
+// ============================================================================
+
+/// `datetime` 속성값. HTML이 정한 형식(연, 연-월, 날짜, 시각, 날짜+시각과 시간대)만 만들 수 있습니다.
+///
+/// # Example
+/// ```rust
+/// Datetime::date(2024, 5, 1)                                  // 2024-05-01
+/// Datetime::date(2024, 5, 1).and_then(|d| d.at(14, 30))       // 2024-05-01T14:30
+/// Datetime::parse("2024-05-01T14:30+09:00")                   // Some
+/// Datetime::parse("2024-02-30")                               // None (없는 날짜)
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Datetime(String);
+
+impl Datetime {
+    /// 연도 (`2024`).
+    pub fn year(year: i32) -> std::option::Option<Self> {
+        (year >= 1).then(|| Datetime(format!("{:04}", year)))
+    }
+
+    /// 연-월 (`2024-05`).
+    pub fn month(year: i32, month: u8) -> std::option::Option<Self> {
+        (year >= 1 && (1..=12).contains(&month))
+            .then(|| Datetime(format!("{:04}-{:02}", year, month)))
+    }
+
+    /// 날짜 (`2024-05-01`).
+    pub fn date(year: i32, month: u8, day: u8) -> std::option::Option<Self> {
+        (year >= 1 && (1..=12).contains(&month) && day >= 1 && day <= days_in_month(year, month))
+            .then(|| Datetime(format!("{:04}-{:02}-{:02}", year, month, day)))
+    }
+
+    /// 시각 (`14:30`).
+    pub fn time(hour: u8, minute: u8) -> std::option::Option<Self> {
+        (hour < 24 && minute < 60).then(|| Datetime(format!("{:02}:{:02}", hour, minute)))
+    }
+
+    /// 날짜에 시각 붙이기 (`2024-05-01T14:30`). 날짜가 아니면 `None`.
+    pub fn at(self, hour: u8, minute: u8) -> std::option::Option<Self> {
+        let time = Datetime::time(hour, minute)?;
+        is_date(&self.0).then(|| Datetime(format!("{}T{}", self.0, time.0)))
+    }
+
+    /// 시간대 붙이기. 0이면 `Z`, 아니면 `+09:00` 형태 (분 단위). 시간대 없는 날짜+시각이 아니면 `None`.
+    pub fn offset(self, minutes: i16) -> std::option::Option<Self> {
+        let (date, time) = self.0.split_once('T')?;
+        if !is_date(date) || !is_time(time) || minutes.unsigned_abs() >= 24 * 60 {
+            return None;
+        }
+        let zone = match minutes {
+            0 => "Z".to_string(),
+            _ => format!(
+                "{}{:02}:{:02}",
+                if minutes < 0 { '-' } else { '+' },
+                minutes.unsigned_abs() / 60,
+                minutes.unsigned_abs() % 60
+            ),
+        };
+        Some(Datetime(format!("{}{}", self.0, zone)))
+    }
+
+    /// 문자열 검사. 날짜와 시각 사이는 `T` 또는 공백.
+    pub fn parse(s: &str) -> std::option::Option<Self> {
+        let valid = match s.split_once(['T', ' ']) {
+            Some((date, rest)) => is_date(date) && is_time_with_zone(rest),
+            None => is_year(s) || is_month(s) || is_date(s) || is_time(s),
+        };
+        valid.then(|| Datetime(s.to_string()))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for Datetime {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+fn digits(s: &str, min: usize, max: usize) -> std::option::Option<u32> {
+    if s.len() < min || s.len() > max || !s.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    s.parse().ok()
+}
+
+fn is_year(s: &str) -> bool {
+    digits(s, 4, 6).is_some_and(|year| year >= 1)
+}
+
+fn is_month(s: &str) -> bool {
+    match s.rsplit_once('-') {
+        Some((year, month)) => {
+            is_year(year) && digits(month, 2, 2).is_some_and(|m| (1..=12).contains(&m))
+        }
+        None => false,
+    }
+}
+
+fn is_date(s: &str) -> bool {
+    let Some((month, day)) = s.rsplit_once('-') else {
+        return false;
+    };
+    let (Some((year, m)), Some(day)) = (month.rsplit_once('-'), digits(day, 2, 2)) else {
+        return false;
+    };
+    is_month(month)
+        && day >= 1
+        && digits(year, 4, 6)
+            .zip(digits(m, 2, 2))
+            .is_some_and(|(year, m)| day <= u32::from(days_in_month(year as i32, m as u8)))
+}
+
+/// `HH:MM`, `HH:MM:SS`, `HH:MM:SS.sss`.
+fn is_time(s: &str) -> bool {
+    let mut parts = s.splitn(3, ':');
+    let hour = parts.next().and_then(|h| digits(h, 2, 2));
+    let minute = parts.next().and_then(|m| digits(m, 2, 2));
+    let second = match parts.next() {
+        None => true,
+        Some(second) => {
+            let (whole, fraction) = second.split_once('.').unwrap_or((second, "0"));
+            digits(whole, 2, 2).is_some_and(|s| s < 60) && digits(fraction, 1, 3).is_some()
+        }
+    };
+    hour.is_some_and(|h| h < 24) && minute.is_some_and(|m| m < 60) && second
+}
+
+/// 시각 뒤에 선택적 시간대 (`Z`, `+09:00`, `-0500`).
+fn is_time_with_zone(s: &str) -> bool {
+    if let Some(time) = s.strip_suffix('Z') {
+        return is_time(time);
+    }
+    match s.rfind(['+', '-']) {
+        Some(i) => {
+            let zone = s[i + 1..].replace(':', "");
+            is_time(&s[..i])
+                && digits(&zone, 4, 4).is_some_and(|z| z / 100 < 24 && z % 100 < 60)
+        }
+        None => is_time(s),
+    }
+}
+
+fn days_in_month(year: i32, month: u8) -> u8 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if (year % 4 == 0 && year % 100 != 0) || year % 400 == 0 => 29,
+        2 => 28,
+        _ => 0,
+    }
 }
 
 // TODO: 추가 속성 그룹
-// - ForForm: name, value, type, required, disabled 등
-// - ForTable: colspan, rowspan 등
-// - ForMedia: controls, autoplay, loop 등
 // - data-* 속성 지원
 // - aria-* 속성 지원
@@ -11,6 +11,7 @@
 //! pub struct H1 { /* ... */ }
 //! pub struct H2 { /* ... */ }
 //! pub struct Div { /* ... */ }
+//! pub struct P { /* ... */ }
 //! ```
 //!
 //! ### 2. Content Category로 타입 제약
@@ -23,6 +24,7 @@
 //!
 //! ### 3. Node 트레이트로 IRNode 변환
 //! 모든 요소는 `to_irnode()`를 통해 중간 표현으로 변환됩니다.
+//! 부모는 자식을 `to_element()`로 넣으므로 텍스트(`Content`)는 텍스트 노드가 됩니다.
 //!
 //! ## 요소 구현 패턴
 //!
@@ -51,13 +53,20 @@
 //! ## 구현 상태
 //! - [x] H1, H2 (제목 요소)
 //! - [x] Div (컨테이너)
+//! - [x] Blockquote, Figure, Figcaption (인용, 캡션 있는 콘텐츠)
 //! - [x] Img (이미지)
-//! - [ ] TODO: 텍스트 요소 (p, span, a, strong, em, code)
+//! - [x] P, Span (텍스트), A (링크)
+//! - [x] Script, Style (인라인 코드, 전용 이스케이프)
+//! - [x] Pre, Code (공백 보존, 타이포그래피 규칙 없음)
+//! - [x] 텍스트 요소 (strong, em, mark, small, sub, sup, abbr, time, data)
+//! - [x] 편집 기록 (del, ins: cite, datetime)
 //! - [ ] TODO: 리스트 (ul, ol, li)
 //! - [ ] TODO: 의미론적 요소 (article, section, nav, header, footer, aside)
-//! - [ ] TODO: 테이블 (table, thead, tbody, tr, th, td)
-//! - [ ] TODO: 폼 (form, input, button, label, textarea, select)
-//! - [ ] TODO: 미디어 (video, audio, picture, source)
+//! - [x] 표 (table, caption, colgroup, col, thead, tbody, tfoot, tr, th, td)
+//! - [x] 폼 (form, input, button, label, select, option, textarea, fieldset, legend)
+//! - [x] 미디어 (video, audio, source, track, picture)
+//! - [x] 대화형 요소 (details, summary, dialog)
+//! - [x] 문서 골격 (html, head, body, title, meta, link + `<!DOCTYPE html>`)
 //!
 //! ## 구현된 요소
 //!
@@ -87,12 +96,139 @@
 //! )
 //! ```
 //!
+//! ### Blockquote, Figure, Figcaption (그룹 콘텐츠)
+//! **특징:**
+//! - 셋 다 FlowContent 자식들을 가짐. blockquote 속성은 `AttrBuilder::quote()` (cite)
+//! - figure의 캡션은 `Figure::caption(Figcaption)`으로 하나만, 마지막 자식으로 출력
+//!
+//! **제약:**
+//! - Figcaption은 어떤 카테고리도 구현하지 않으므로 Figure 밖에 넣을 수 없음
+//!
 //! ### Img (이미지)
 //! **특징:**
 //! - Void 요소 (자식 없음)
 //! - src, alt 속성 필수
 //! - FlowContent 구현
 //!
+//! ### Video, Audio, Picture (미디어)
+//! **특징:**
+//! - 속성은 `AttrBuilder::media()` (controls, autoplay, loop, muted, preload, poster, src, type 등)
+//! - video, audio 자식은 `MediaContent`: `Source`, `Track`, 대체 `Phrasing` 콘텐츠
+//! - picture는 `Picture::new(attrs, sources, img)`로 "source 여러 개 + img 하나" 순서를 강제
+//!
+//! **제약:**
+//! - source, track이 대체 콘텐츠보다 앞에 와야 한다는 순서는 타입으로 막지 않음
+//! - `loop`, `type`은 Rust 키워드이므로 빌더 메서드는 `r#loop`, `r#type`
+//!
+//! ### Details, Summary, Dialog (대화형 요소)
+//! **특징:**
+//! - 속성은 `AttrBuilder::disclosure()` (open, details의 name)
+//! - `Details::new(attrs, summary, childs)`로 "summary 하나 + Flow content" 순서를 강제
+//! - 스크립트 없이 접고 펼 수 있으므로 아코디언, FAQ 같은 Block이 원시 HTML 없이 만들 수 있음
+//!
+//! **제약:**
+//! - Summary는 어떤 카테고리도 구현하지 않으므로 Details 밖에 넣을 수 없음
+//! - summary 안에 제목 하나만 넣는 형태는 타입으로 표현하지 않음 (Phrasing만)
+//!
+//! ### Strong, Em, Mark, Small, Sub, Sup, Abbr, Time, Data (텍스트 수준 의미 요소)
+//! **특징:**
+//! - 모두 Phrasing 자식을 가지는 Phrasing 요소. 글 본문을 원시 HTML 없이 요소 API로 표현
+//! - `Abbr::title(&str)`: 약어의 전체 이름. 문자열을 받아 이스케이프하므로 `AttrValue`를 만들 필요 없음
+//! - `Time`의 속성은 `AttrBuilder::timestamp().datetime(Datetime)`. `Datetime`은 HTML이 정한 형식만 만들 수 있음
+//! - `Data`의 속성은 `AttrBuilder::value().value(..)` (기계가 읽는 값)
+//!
+//! **사용:**
+//! ```rust
+//! Time::new(
+//!     AttrBuilder::timestamp().datetime(Datetime::date(2024, 5, 1).unwrap()),
+//!     vec![Box::new(text("5월 1일"))],
+//! )
+//! // → <time datetime="2024-05-01">5월 1일</time>
+//!
+//! Abbr::new(AttrBuilder::global(), vec![Box::new(text("HTML"))]).title("HyperText Markup Language")
+//! // → <abbr title="HyperText Markup Language">HTML</abbr>
+//! ```
+//!
+//! **제약:**
+//! - 중첩 규칙(`time` 안의 `time` 등)은 타입으로 막지 않음
+//!
+//! ### Del, Ins (편집 기록)
+//! **특징:**
+//! - 속성은 `AttrBuilder::edit()` (cite: `ForQuote`, datetime: `ForDatetime`). 변경 기록 페이지에서 바뀐 부분 표시
+//! - `Transparentcontent` 구현
+//!
+//! **제약:**
+//! - 콘텐츠 모델은 Transparent(부모를 따름)이지만, `A`처럼 Phrasing 자식으로 제한
+//!
+//! ### Pre, Code (코드)
+//! **특징:**
+//! - 공백과 줄바꿈은 `HtmlFormat`과 무관하게 보존 (`Whitespace::Preserve`)
+//! - `Code::new(attrs, &str)`, `Pre::text(attrs, &str)`는 이스케이프만 하고 타이포그래피 규칙을 적용하지 않음
+//!   (`"`가 `“`로, `--`가 `–`로 바뀌면 코드가 깨짐)
+//! - `html!`의 `<pre>`, `<code>` 안 문자열도 같은 규칙 (`macros::verbatim`)
+//!
+//! **제약:**
+//! - `Pre::new`의 자식으로 `text()`(타이포그래피 규칙 적용)로 만든 `Content`를 넣으면 규칙이 적용된 채로 들어감.
+//!   코드는 `Pre::text`, `Code`, `verbatim()`으로 넣습니다
+//!
+//! ### Script, Style (인라인 코드)
+//! **특징:**
+//! - 내용은 HTML 이스케이프 대신 요소 전용 규칙으로 이스케이프 (`&`를 `&amp;`로 바꾸면 코드가 깨짐)
+//! - 요소를 닫는 `</script`, `</style`과 스크립트 상태를 바꾸는 `<!--`는 `<\/script`, `<\!--`로 끊음
+//! - `cdata(true)`: XHTML로도 해석되도록 `//<![CDATA[ ... //]]>`로 감싸고, 내부의 `]]>`는 `]]\>`로 끊음
+//!
+//! **사용:**
+//! ```rust
+//! Script::inline("if (a </script/.test(s)) {}");
+//! // → <script>if (a <\/script/.test(s)) {}</script>
+//! Style::inline("a::after{content:'</style>'}").cdata(true);
+//! // → <style>/*<![CDATA[*/
+//! //   a::after{content:'<\/style>'}
+//! //   /*]]>*/</style>
+//! ```
+//!
+//! **제약:**
+//! - 끊는 위치에 넣는 `\`는 문자열, 정규식 리터럴 안에서 의미가 바뀌지 않는 이스케이프입니다.
+//!   세 시퀀스가 리터럴 밖에 나오는 코드(`a[b[0]]>c` 등)는 그대로 두면 안전하지 않으므로 CDATA를 쓰지 않거나 공백을 넣습니다.
+//!
+//! ### Table (표)
+//! **특징:**
+//! - table 자식은 `TableContent`(caption, colgroup, thead, tbody, tfoot, tr)만
+//! - 행 묶음(thead, tbody, tfoot)은 `Tr`만, 행은 `TrContent`(th, td)만
+//! - 셀 병합은 `AttrBuilder::table_cell().colspan(..).rowspan(..)`
+//!
+//! **제약:**
+//! - 셀을 행 밖에, 행 묶음을 행 안에 넣으면 컴파일 오류
+//! - 자식 순서(caption이 맨 앞, tfoot이 맨 뒤)는 타입으로 막지 않음
+//!
+//! ### Form (폼)
+//! **특징:**
+//! - 컨트롤 속성은 `AttrBuilder::form()` (name, value, type, required, disabled, placeholder, action, method)
+//! - select 자식은 `SelectContent`(option)만, fieldset 자식은 `FieldsetContent`(legend + Flow content)
+//! - option, textarea 내용은 텍스트 하나 (`Content`)
+//!
+//! **제약:**
+//! - `type`은 Rust 키워드이므로 빌더 메서드는 `r#type` (`html!`에서는 `type="email"`)
+//! - `Option`은 `std::option::Option`과 이름이 같으므로 glob import 대신 경로로 씁니다
+//!
+//! ### Html, Head, Body (문서 골격)
+//! **특징:**
+//! - `Html::new(attrs, head, body)`로 "head 하나 + body 하나" 순서를 강제
+//! - head 자식은 `MetadataContent`만: `Title`, `Meta`, `Link`, `Style`, `Script`
+//! - meta, link, 외부 script 속성은 `AttrBuilder::metadata()` (charset, name, content, property, rel, href, src, defer 등)
+//! - `HtmlRenderer`가 문서 맨 앞의 `html` 앞에 `<!DOCTYPE html>`을 붙이므로 문자열을 이어 붙이지 않아도 완전한 문서가 됩니다
+//!
+//! **사용:**
+//! ```rust
+//! let page = Html::new(AttrBuilder::global().lang(attr("ko")), head, body);
+//! let html = page.to_irnode().accept(HtmlRenderer::new()).finalize().clone();
+//! // → <!DOCTYPE html><html lang="ko">...</html>
+//! ```
+//!
+//! **제약:**
+//! - title이 하나인지, charset meta가 맨 앞인지는 타입으로 막지 않음
+//! - 외부 스크립트는 `Script::external(attrs)`, 인라인 스크립트는 `Script::inline(code)`
+//!
 //! ## 새 요소 추가 가이드
 //!
 //! ### 1단계: 요소 정의
@@ -125,7 +261,6 @@
 //!         IRNode::new(
 //!             TagName::from_str("p"),
 //!             self.attrs.clone(),
-//!             ElementType::Normal,
 //!             self.content.iter()
 //!                 .map(|c| Element::Node(c.to_irnode()))
 //!                 .collect()
@@ -193,7 +328,7 @@
 //! | 요소 | 허용 자식 | 구현 |
 //! |------|----------|------|
 //! | div | FlowContent | `Vec<Box<dyn FlowContent>>` |
-//! | p | PhrasingContent | `Vec<Box<dyn PhrasingContent>>` |
+//! | p, span | Phrasing | `Vec<Box<dyn Phrasing>>` (텍스트는 `Content`) |
 //! | h1~h6 | PhrasingContent | `Content` (단순화) |
 //! | ul | li | `Vec<Li>` |
 //! | img | (없음) | Void 요소 |
@@ -207,20 +342,23 @@
 //!
 //! impl Node for Img {
 //!     fn to_irnode(&self) -> IRNode {
-//!         IRNode::new(
-//!             TagName::from_str("img"),
-//!             self.attrs.clone(),
-//!             ElementType::Void,  // Void 명시
-//!             vec![],             // 자식 없음
-//!         )
+//!         IRNode::void(TagName::from_str("img"), self.attrs.clone())  // 자식 인자 없음
 //!     }
 //! }
 //! ```
 //!
 
-use crate::html::attributes::{Attributes, Global, Image, SharedAttrs};
-use crate::html::node::{Element, ElementType, FlowContent, Heading, IRNode, Node};
-use crate::html::trust::{self, Content, TagName};
+use crate::html::attributes::{
+    self, Anchor, AttrValues, Attributes, Column, Disclosure, Edit, Global, Image, Media, Quote,
+    SharedAttrs, TableCell, Timestamp, Value,
+};
+use crate::html::node::{
+    self, Element, Embedded, FieldsetContent, FlowContent, Formassociated, Heading, IRNode,
+    Interactive, MediaContent, MetadataContent, Node, Phrasing, SelectContent, TableContent,
+    TrContent, Transparentcontent,
+};
+use crate::html::rules;
+use crate::html::trust::{self, Content, HtmlBlock, SafeString, TagName};
 
 // ============================================================================
 // 제목 요소 (Heading Elements)
@@ -241,7 +379,7 @@ impl H1 {
     pub fn new(attrs: Attributes<Global>, content: Content) -> Self {
         H1 {
             attrs: SharedAttrs::from_map(attrs.table),
-            content: content,
+            content,
         }
     }
 }
@@ -251,7 +389,6 @@ impl Node for H1 {
         IRNode::new(
             TagName::from_str("h1"),
             self.attrs.clone(),
-            ElementType::Normal,
             vec![Element::Text(self.content.clone())],
         )
     }
@@ -285,7 +422,6 @@ impl Node for H2 {
         IRNode::new(
             TagName::from_str("h2"),
             self.attrs.clone(),
-            ElementType::Normal,
             vec![Element::Text(self.content.clone())],
         )
     }
@@ -322,10 +458,7 @@ impl Div {
     pub fn new(attrs: Attributes<Global>, childs: Vec<Box<dyn FlowContent>>) -> Self {
         Div {
             attrs: SharedAttrs::from_map(attrs.table),
-            childs: childs
-                .iter()
-                .map(|c| Element::Node(c.to_irnode()))
-                .collect(),
+            childs: childs.iter().map(|c| c.to_element()).collect(),
         }
     }
 }
@@ -335,7 +468,6 @@ impl Node for Div {
         IRNode::new(
             TagName::from_str("div"),
             self.attrs.clone(),
-            ElementType::Normal,
             self.childs.clone(),
         )
     }
@@ -343,65 +475,2042 @@ impl Node for Div {
 
 impl FlowContent for Div {}
 
+/// Blockquote 요소. 다른 출처에서 인용한 블록.
+///
+/// # HTML5 명세
+/// - Content model: Flow content
+/// - Categories: Flow content, Sectioning root, Palpable content
+///
+/// # 특징
+/// - `cite` 속성은 출처 URL (`AttrBuilder::quote().cite(...)`), 화면에 보이는 출처는 자식으로 넣음
+///
+/// # Example
+/// ```rust
+/// Blockquote::new(
+///     AttrBuilder::quote().cite(url("https://example.com/talk")),
+///     vec![Box::new(P::new(AttrBuilder::global(), vec![Box::new(text("인용문"))]))],
+/// );
+/// ```
+#[derive(Clone)]
+pub struct Blockquote {
+    attrs: SharedAttrs,
+    childs: Vec<Element>,
+}
+
+impl Blockquote {
+    pub fn new(attrs: Attributes<Quote>, childs: Vec<Box<dyn FlowContent>>) -> Self {
+        Blockquote {
+            attrs: SharedAttrs::from_map(attrs.table),
+            childs: childs.iter().map(|c| c.to_element()).collect(),
+        }
+    }
+}
+
+impl Node for Blockquote {
+    fn to_irnode(&self) -> IRNode {
+        IRNode::new(TagName::from_str("blockquote"), self.attrs.clone(), self.childs.clone())
+    }
+}
+
+impl FlowContent for Blockquote {}
+
+/// Figure 요소. 본문에서 참조하는 독립된 콘텐츠 (이미지, 코드, 표 등)와 선택적 캡션.
+///
+/// # HTML5 명세
+/// - Content model: figcaption 하나 (처음 또는 마지막) + Flow content
+/// - Categories: Flow content, Sectioning root, Palpable content
+///
+/// # 특징
+/// - 캡션은 `caption()`으로 하나만 넣을 수 있고 항상 마지막 자식으로 출력
+///
+/// # Example
+/// ```rust
+/// Figure::new(AttrBuilder::global(), vec![Box::new(Img::new(/* ... */))])
+///     .caption(Figcaption::new(AttrBuilder::global(), vec![Box::new(text("2024년 여름"))]));
+/// // → <figure><img ...><figcaption>2024년 여름</figcaption></figure>
+/// ```
+#[derive(Clone)]
+pub struct Figure {
+    attrs: SharedAttrs,
+    childs: Vec<Element>,
+    caption: std::option::Option<Figcaption>,
+}
+
+impl Figure {
+    pub fn new(attrs: Attributes<Global>, childs: Vec<Box<dyn FlowContent>>) -> Self {
+        Figure {
+            attrs: SharedAttrs::from_map(attrs.table),
+            childs: childs.iter().map(|c| c.to_element()).collect(),
+            caption: None,
+        }
+    }
+
+    /// 캡션. 다시 부르면 바뀝니다.
+    pub fn caption(mut self, caption: Figcaption) -> Self {
+        self.caption = Some(caption);
+        self
+    }
+}
+
+impl Node for Figure {
+    fn to_irnode(&self) -> IRNode {
+        let mut childs = self.childs.clone();
+        if let Some(caption) = &self.caption {
+            childs.push(caption.to_element());
+        }
+        IRNode::new(TagName::from_str("figure"), self.attrs.clone(), childs)
+    }
+}
+
+impl FlowContent for Figure {}
+
+/// Figcaption 요소. Figure의 캡션. `Figure::caption`으로만 넣습니다.
+///
+/// # HTML5 명세
+/// - Content model: Flow content
+/// - Categories: 없음 (figure의 첫째 또는 마지막 자식)
+#[derive(Clone)]
+pub struct Figcaption {
+    attrs: SharedAttrs,
+    childs: Vec<Element>,
+}
+
+impl Figcaption {
+    pub fn new(attrs: Attributes<Global>, childs: Vec<Box<dyn FlowContent>>) -> Self {
+        Figcaption {
+            attrs: SharedAttrs::from_map(attrs.table),
+            childs: childs.iter().map(|c| c.to_element()).collect(),
+        }
+    }
+}
+
+impl Node for Figcaption {
+    fn to_irnode(&self) -> IRNode {
+        IRNode::new(TagName::from_str("figcaption"), self.attrs.clone(), self.childs.clone())
+    }
+}
+
 // ============================================================================
-// 임베디드 콘텐츠 (Embedded Content)
+// 텍스트 요소 (Text-level Elements)
 // ============================================================================
 
-/// Img 요소. 이미지 삽입.
+/// 텍스트 노드. 구문 요소(`P`, `Span`)의 자식으로 다른 요소와 섞어 씁니다.
+/// 부모 없이 `to_irnode()`로 쓰면 `<span>`으로 감쌉니다.
+impl Node for Content {
+    fn to_irnode(&self) -> IRNode {
+        IRNode::new(TagName::from_str("span"), SharedAttrs::new(), vec![self.to_element()])
+    }
+
+    fn to_element(&self) -> Element {
+        Element::Text(self.clone())
+    }
+}
+
+impl Phrasing for Content {}
+
+/// P 요소. 문단.
 ///
 /// # HTML5 명세
-/// - Content model: (없음 - Void 요소)
-/// - Categories: Flow content, Phrasing content, Embedded content,
-///               Interactive content (usemap 속성 있을 때),
-///               Palpable content
+/// - Content model: Phrasing content
+/// - Categories: Flow content, Palpable content
 ///
-/// # 필수 속성
-/// - `src`: 이미지 URL
-/// - `alt`: 대체 텍스트
-pub struct Img {
+/// # Example
+/// ```rust
+/// let p = P::new(
+///     AttrBuilder::global(),
+///     vec![
+///         Box::new(text("Hello ")),
+///         Box::new(Span::new(AttrBuilder::global().class(classes("name")), vec![Box::new(text("World"))])),
+///     ],
+/// );
+/// // → <p>Hello <span class="name">World</span></p>
+/// ```
+#[derive(Clone)]
+pub struct P {
     attrs: SharedAttrs,
+    childs: Vec<Element>,
 }
 
-impl Img {
-    /// 새 Img 생성
-    ///
-    /// # Arguments
-    /// - `attrs`: Image 타입 속성 (src, alt 포함)
-    ///
-    /// # Example
-    /// ```rust
-    /// let img = Img::new(
-    ///     AttrBuilder::image()
-    ///         .src(AttrValue::from_str("/logo.png", &rule))
-    ///         .alt(AttrValue::from_str("Company Logo", &rule))
-    /// );
-    /// ```
-    pub fn new(attrs: Attributes<Image>) -> Self {
-        Img {
+impl P {
+    pub fn new(attrs: Attributes<Global>, childs: Vec<Box<dyn Phrasing>>) -> Self {
+        P {
             attrs: SharedAttrs::from_map(attrs.table),
+            childs: childs.iter().map(|c| c.to_element()).collect(),
         }
     }
 }
 
-impl Node for Img {
+impl Node for P {
     fn to_irnode(&self) -> IRNode {
-        IRNode::new(
-            TagName::from_str("img"),
-            self.attrs.clone(),
-            ElementType::Void,  // Void: 자식 없음
-            vec![],
-        )
+        IRNode::new(TagName::from_str("p"), self.attrs.clone(), self.childs.clone())
     }
 }
 
-impl FlowContent for Img {}
+impl FlowContent for P {}
+
+/// Span 요소. 의미 없는 인라인 컨테이너.
+///
+/// # HTML5 명세
+/// - Content model: Phrasing content
+/// - Categories: Flow content, Phrasing content, Palpable content
+#[derive(Clone)]
+pub struct Span {
+    attrs: SharedAttrs,
+    childs: Vec<Element>,
+}
+
+impl Span {
+    pub fn new(attrs: Attributes<Global>, childs: Vec<Box<dyn Phrasing>>) -> Self {
+        Span {
+            attrs: SharedAttrs::from_map(attrs.table),
+            childs: childs.iter().map(|c| c.to_element()).collect(),
+        }
+    }
+}
+
+impl Node for Span {
+    fn to_irnode(&self) -> IRNode {
+        IRNode::new(TagName::from_str("span"), self.attrs.clone(), self.childs.clone())
+    }
+}
+
+impl FlowContent for Span {}
+impl Phrasing for Span {}
+
+/// A 요소. 하이퍼링크.
+///
+/// # HTML5 명세
+/// - Content model: Transparent (여기서는 Phrasing으로 제한)
+/// - Categories: Flow content, Phrasing content, Interactive content (href 있을 때), Palpable content
+///
+/// # Example
+/// ```rust
+/// let link = A::new(
+///     AttrBuilder::anchor().href(attr("https://example.com")).rel(attr("noopener")),
+///     vec![Box::new(text("예시"))],
+/// );
+/// // → <a href="https://example.com" rel="noopener">예시</a>
+/// ```
+#[derive(Clone)]
+pub struct A {
+    attrs: SharedAttrs,
+    childs: Vec<Element>,
+}
+
+impl A {
+    pub fn new(attrs: Attributes<Anchor>, childs: Vec<Box<dyn Phrasing>>) -> Self {
+        A {
+            attrs: SharedAttrs::from_map(attrs.table),
+            childs: childs.iter().map(|c| c.to_element()).collect(),
+        }
+    }
+}
+
+impl Node for A {
+    fn to_irnode(&self) -> IRNode {
+        IRNode::new(TagName::from_str("a"), self.attrs.clone(), self.childs.clone())
+    }
+}
+
+impl FlowContent for A {}
+impl Phrasing for A {}
+impl Interactive for A {}
+
+/// Strong 요소. 강한 중요성 (경고, 핵심 문장).
+///
+/// # HTML5 명세
+/// - Content model: Phrasing content
+/// - Categories: Flow content, Phrasing content, Palpable content
+#[derive(Clone)]
+pub struct Strong {
+    attrs: SharedAttrs,
+    childs: Vec<Element>,
+}
+
+impl Strong {
+    pub fn new(attrs: Attributes<Global>, childs: Vec<Box<dyn Phrasing>>) -> Self {
+        Strong {
+            attrs: SharedAttrs::from_map(attrs.table),
+            childs: childs.iter().map(|c| c.to_element()).collect(),
+        }
+    }
+}
+
+impl Node for Strong {
+    fn to_irnode(&self) -> IRNode {
+        IRNode::new(TagName::from_str("strong"), self.attrs.clone(), self.childs.clone())
+    }
+}
+
+impl FlowContent for Strong {}
+impl Phrasing for Strong {}
+
+/// Em 요소. 강세 (읽을 때 힘을 주는 부분).
+///
+/// # HTML5 명세
+/// - Content model: Phrasing content
+/// - Categories: Flow content, Phrasing content, Palpable content
+#[derive(Clone)]
+pub struct Em {
+    attrs: SharedAttrs,
+    childs: Vec<Element>,
+}
+
+impl Em {
+    pub fn new(attrs: Attributes<Global>, childs: Vec<Box<dyn Phrasing>>) -> Self {
+        Em {
+            attrs: SharedAttrs::from_map(attrs.table),
+            childs: childs.iter().map(|c| c.to_element()).collect(),
+        }
+    }
+}
+
+impl Node for Em {
+    fn to_irnode(&self) -> IRNode {
+        IRNode::new(TagName::from_str("em"), self.attrs.clone(), self.childs.clone())
+    }
+}
+
+impl FlowContent for Em {}
+impl Phrasing for Em {}
+
+/// Mark 요소. 표시 (검색어 강조처럼 현재 맥락에서 관련 있는 부분).
+///
+/// # HTML5 명세
+/// - Content model: Phrasing content
+/// - Categories: Flow content, Phrasing content, Palpable content
+#[derive(Clone)]
+pub struct Mark {
+    attrs: SharedAttrs,
+    childs: Vec<Element>,
+}
+
+impl Mark {
+    pub fn new(attrs: Attributes<Global>, childs: Vec<Box<dyn Phrasing>>) -> Self {
+        Mark {
+            attrs: SharedAttrs::from_map(attrs.table),
+            childs: childs.iter().map(|c| c.to_element()).collect(),
+        }
+    }
+}
+
+impl Node for Mark {
+    fn to_irnode(&self) -> IRNode {
+        IRNode::new(TagName::from_str("mark"), self.attrs.clone(), self.childs.clone())
+    }
+}
+
+impl FlowContent for Mark {}
+impl Phrasing for Mark {}
+
+/// Small 요소. 부가 설명 (저작권, 법적 고지 같은 작은 글씨).
+///
+/// # HTML5 명세
+/// - Content model: Phrasing content
+/// - Categories: Flow content, Phrasing content, Palpable content
+#[derive(Clone)]
+pub struct Small {
+    attrs: SharedAttrs,
+    childs: Vec<Element>,
+}
+
+impl Small {
+    pub fn new(attrs: Attributes<Global>, childs: Vec<Box<dyn Phrasing>>) -> Self {
+        Small {
+            attrs: SharedAttrs::from_map(attrs.table),
+            childs: childs.iter().map(|c| c.to_element()).collect(),
+        }
+    }
+}
+
+impl Node for Small {
+    fn to_irnode(&self) -> IRNode {
+        IRNode::new(TagName::from_str("small"), self.attrs.clone(), self.childs.clone())
+    }
+}
+
+impl FlowContent for Small {}
+impl Phrasing for Small {}
+
+/// Sub 요소. 아래 첨자 (H₂O).
+///
+/// # HTML5 명세
+/// - Content model: Phrasing content
+/// - Categories: Flow content, Phrasing content, Palpable content
+#[derive(Clone)]
+pub struct Sub {
+    attrs: SharedAttrs,
+    childs: Vec<Element>,
+}
+
+impl Sub {
+    pub fn new(attrs: Attributes<Global>, childs: Vec<Box<dyn Phrasing>>) -> Self {
+        Sub {
+            attrs: SharedAttrs::from_map(attrs.table),
+            childs: childs.iter().map(|c| c.to_element()).collect(),
+        }
+    }
+}
+
+impl Node for Sub {
+    fn to_irnode(&self) -> IRNode {
+        IRNode::new(TagName::from_str("sub"), self.attrs.clone(), self.childs.clone())
+    }
+}
+
+impl FlowContent for Sub {}
+impl Phrasing for Sub {}
+
+/// Sup 요소. 위 첨자 (x², 각주 번호).
+///
+/// # HTML5 명세
+/// - Content model: Phrasing content
+/// - Categories: Flow content, Phrasing content, Palpable content
+#[derive(Clone)]
+pub struct Sup {
+    attrs: SharedAttrs,
+    childs: Vec<Element>,
+}
+
+impl Sup {
+    pub fn new(attrs: Attributes<Global>, childs: Vec<Box<dyn Phrasing>>) -> Self {
+        Sup {
+            attrs: SharedAttrs::from_map(attrs.table),
+            childs: childs.iter().map(|c| c.to_element()).collect(),
+        }
+    }
+}
+
+impl Node for Sup {
+    fn to_irnode(&self) -> IRNode {
+        IRNode::new(TagName::from_str("sup"), self.attrs.clone(), self.childs.clone())
+    }
+}
+
+impl FlowContent for Sup {}
+impl Phrasing for Sup {}
+
+/// Abbr 요소. 약어.
+///
+/// # HTML5 명세
+/// - Content model: Phrasing content
+/// - Categories: Flow content, Phrasing content, Palpable content
+///
+/// 전체 이름은 `title(&str)`로 넣습니다. Global의 `title`보다 우선합니다.
+///
+/// # Example
+/// ```rust
+/// let abbr = Abbr::new(AttrBuilder::global(), vec![Box::new(text("CSS"))])
+///     .title("Cascading Style Sheets");
+/// // → <abbr title="Cascading Style Sheets">CSS</abbr>
+/// ```
+#[derive(Clone)]
+pub struct Abbr {
+    attrs: SharedAttrs,
+    title: std::option::Option<trust::AttrValue>,
+    childs: Vec<Element>,
+}
+
+impl Abbr {
+    pub fn new(attrs: Attributes<Global>, childs: Vec<Box<dyn Phrasing>>) -> Self {
+        Abbr {
+            attrs: SharedAttrs::from_map(attrs.table),
+            title: None,
+            childs: childs.iter().map(|c| c.to_element()).collect(),
+        }
+    }
+
+    /// 약어의 전체 이름 (title 속성). 이스케이프만 합니다.
+    pub fn title(mut self, expansion: &str) -> Self {
+        self.title = Some(trust::AttrValue::from_str(
+            expansion,
+            &rules::Default { rules: vec![] },
+        ));
+        self
+    }
+}
+
+impl Node for Abbr {
+    fn to_irnode(&self) -> IRNode {
+        let attrs = match &self.title {
+            Some(title) => SharedAttrs::from_map(self.attrs.get().clone().add(
+                trust::AttrKey::from_str("title"),
+                AttrValues::Token(title.clone()),
+            )),
+            None => self.attrs.clone(),
+        };
+        IRNode::new(TagName::from_str("abbr"), attrs, self.childs.clone())
+    }
+}
+
+impl FlowContent for Abbr {}
+impl Phrasing for Abbr {}
+
+/// Time 요소. 날짜, 시각.
+///
+/// # HTML5 명세
+/// - Content model: Phrasing content
+/// - Categories: Flow content, Phrasing content, Palpable content
+///
+/// 기계가 읽는 값은 `AttrBuilder::timestamp().datetime(Datetime)`으로 넣습니다.
+///
+/// # Example
+/// ```rust
+/// let time = Time::new(
+///     AttrBuilder::timestamp().datetime(Datetime::date(2024, 5, 1).unwrap()),
+///     vec![Box::new(text("2024년 5월 1일"))],
+/// );
+/// // → <time datetime="2024-05-01">2024년 5월 1일</time>
+/// ```
+#[derive(Clone)]
+pub struct Time {
+    attrs: SharedAttrs,
+    childs: Vec<Element>,
+}
+
+impl Time {
+    pub fn new(attrs: Attributes<Timestamp>, childs: Vec<Box<dyn Phrasing>>) -> Self {
+        Time {
+            attrs: SharedAttrs::from_map(attrs.table),
+            childs: childs.iter().map(|c| c.to_element()).collect(),
+        }
+    }
+}
+
+impl Node for Time {
+    fn to_irnode(&self) -> IRNode {
+        IRNode::new(TagName::from_str("time"), self.attrs.clone(), self.childs.clone())
+    }
+}
+
+impl FlowContent for Time {}
+impl Phrasing for Time {}
+
+/// Data 요소. 사람이 읽는 내용과 기계가 읽는 값(value)을 함께 표시.
+///
+/// # HTML5 명세
+/// - Content model: Phrasing content
+/// - Categories: Flow content, Phrasing content, Palpable content
+///
+/// # Example
+/// ```rust
+/// let data = Data::new(AttrBuilder::value().value(attr("978-0-13-468599-1")), vec![Box::new(text("Rust 책"))]);
+/// // → <data value="978-0-13-468599-1">Rust 책</data>
+/// ```
+#[derive(Clone)]
+pub struct Data {
+    attrs: SharedAttrs,
+    childs: Vec<Element>,
+}
+
+impl Data {
+    pub fn new(attrs: Attributes<Value>, childs: Vec<Box<dyn Phrasing>>) -> Self {
+        Data {
+            attrs: SharedAttrs::from_map(attrs.table),
+            childs: childs.iter().map(|c| c.to_element()).collect(),
+        }
+    }
+}
+
+impl Node for Data {
+    fn to_irnode(&self) -> IRNode {
+        IRNode::new(TagName::from_str("data"), self.attrs.clone(), self.childs.clone())
+    }
+}
+
+impl FlowContent for Data {}
+impl Phrasing for Data {}
+
+/// Del 요소. 삭제된 내용 (변경 기록).
+///
+/// # HTML5 명세
+/// - Content model: Transparent (여기서는 Phrasing으로 제한)
+/// - Categories: Flow content, Phrasing content
+///
+/// 변경 이유는 `cite`(URL), 변경 시각은 `datetime`(`Datetime`)으로 넣습니다 (`AttrBuilder::edit()`).
+///
+/// # Example
+/// ```rust
+/// let del = Del::new(
+///     AttrBuilder::edit()
+///         .cite(url("/changelog.html#v2"))
+///         .datetime(Datetime::date(2024, 5, 1).unwrap()),
+///     vec![Box::new(text("v1 API"))],
+/// );
+/// // → <del cite="/changelog.html#v2" datetime="2024-05-01">v1 API</del>
+/// ```
+#[derive(Clone)]
+pub struct Del {
+    attrs: SharedAttrs,
+    childs: Vec<Element>,
+}
+
+impl Del {
+    pub fn new(attrs: Attributes<Edit>, childs: Vec<Box<dyn Phrasing>>) -> Self {
+        Del {
+            attrs: SharedAttrs::from_map(attrs.table),
+            childs: childs.iter().map(|c| c.to_element()).collect(),
+        }
+    }
+}
+
+impl Node for Del {
+    fn to_irnode(&self) -> IRNode {
+        IRNode::new(TagName::from_str("del"), self.attrs.clone(), self.childs.clone())
+    }
+}
+
+impl FlowContent for Del {}
+impl Phrasing for Del {}
+impl Transparentcontent for Del {}
+
+/// Ins 요소. 추가된 내용 (변경 기록).
+///
+/// # HTML5 명세
+/// - Content model: Transparent (여기서는 Phrasing으로 제한)
+/// - Categories: Flow content, Phrasing content
+///
+/// 변경 이유는 `cite`(URL), 변경 시각은 `datetime`(`Datetime`)으로 넣습니다 (`AttrBuilder::edit()`).
+///
+/// # Example
+/// ```rust
+/// let ins = Ins::new(
+///     AttrBuilder::edit().datetime(Datetime::date(2024, 5, 1).unwrap()),
+///     vec![Box::new(text("v2 API"))],
+/// );
+/// // → <ins datetime="2024-05-01">v2 API</ins>
+/// ```
+#[derive(Clone)]
+pub struct Ins {
+    attrs: SharedAttrs,
+    childs: Vec<Element>,
+}
+
+impl Ins {
+    pub fn new(attrs: Attributes<Edit>, childs: Vec<Box<dyn Phrasing>>) -> Self {
+        Ins {
+            attrs: SharedAttrs::from_map(attrs.table),
+            childs: childs.iter().map(|c| c.to_element()).collect(),
+        }
+    }
+}
+
+impl Node for Ins {
+    fn to_irnode(&self) -> IRNode {
+        IRNode::new(TagName::from_str("ins"), self.attrs.clone(), self.childs.clone())
+    }
+}
+
+impl FlowContent for Ins {}
+impl Phrasing for Ins {}
+impl Transparentcontent for Ins {}
+
+/// Code 요소. 코드 조각.
+///
+/// # HTML5 명세
+/// - Content model: Phrasing content
+/// - Categories: Flow content, Phrasing content, Palpable content
+///
+/// 내용은 문자열로 받아 이스케이프만 합니다. 타이포그래피 규칙(스마트 쿼트, 말줄임표 등)을 적용하면
+/// 코드가 바뀌므로 `Content`를 받지 않습니다. 공백은 렌더링 형식과 무관하게 보존됩니다.
+///
+/// # Example
+/// ```rust
+/// let code = Code::new(AttrBuilder::global(), "let s = \"a -- b\";");
+/// // → <code>let s = "a -- b";</code>   (“a – b”로 바뀌지 않음)
+/// ```
+#[derive(Clone)]
+pub struct Code {
+    attrs: SharedAttrs,
+    content: Content,
+}
+
+impl Code {
+    pub fn new(attrs: Attributes<Global>, code: &str) -> Self {
+        Code {
+            attrs: SharedAttrs::from_map(attrs.table),
+            content: verbatim(code),
+        }
+    }
+}
+
+impl Node for Code {
+    fn to_irnode(&self) -> IRNode {
+        IRNode::new(
+            TagName::from_str("code"),
+            self.attrs.clone(),
+            vec![Element::Text(self.content.clone())],
+        )
+    }
+}
+
+impl FlowContent for Code {}
+impl Phrasing for Code {}
+
+/// Pre 요소. 서식을 유지하는 텍스트 (코드 블록, 시).
+///
+/// # HTML5 명세
+/// - Content model: Phrasing content
+/// - Categories: Flow content, Palpable content
+///
+/// 공백과 줄바꿈은 렌더링 형식(`HtmlFormat::Pretty`, `Minified`)과 무관하게 보존됩니다.
+/// 텍스트는 `Pre::text`로 넣으면 이스케이프만 하고, 강조 표시가 필요하면 `Code`나 `Span`을 자식으로 씁니다.
+///
+/// # Example
+/// ```rust
+/// let block = Pre::new(
+///     AttrBuilder::global().class(classes("code")),
+///     vec![Box::new(Code::new(AttrBuilder::global(), "fn main() {\n    println!(\"hi\");\n}"))],
+/// );
+/// let poem = Pre::text(AttrBuilder::global(), "  봄이 오면\n    산에 들에");
+/// ```
+#[derive(Clone)]
+pub struct Pre {
+    attrs: SharedAttrs,
+    childs: Vec<Element>,
+}
+
+impl Pre {
+    pub fn new(attrs: Attributes<Global>, childs: Vec<Box<dyn Phrasing>>) -> Self {
+        Pre {
+            attrs: SharedAttrs::from_map(attrs.table),
+            childs: childs.iter().map(|c| c.to_element()).collect(),
+        }
+    }
+
+    /// 텍스트 하나 (이스케이프만, 타이포그래피 규칙 없음).
+    pub fn text(attrs: Attributes<Global>, text: &str) -> Self {
+        Pre {
+            attrs: SharedAttrs::from_map(attrs.table),
+            childs: vec![Element::Text(verbatim(text))],
+        }
+    }
+}
+
+impl Node for Pre {
+    fn to_irnode(&self) -> IRNode {
+        IRNode::new(TagName::from_str("pre"), self.attrs.clone(), self.childs.clone())
+    }
+}
+
+impl FlowContent for Pre {}
+
+/// 이스케이프만 한 텍스트 (코드, 서식 있는 텍스트).
+fn verbatim(text: &str) -> Content {
+    Content::from_str(text, &rules::Default { rules: vec![] })
+}
+
+// ============================================================================
+// 임베디드 콘텐츠 (Embedded Content)
+// ============================================================================
+
+/// Img 요소. 이미지 삽입.
+///
+/// # HTML5 명세
+/// - Content model: (없음 - Void 요소)
+/// - Categories: Flow content, Phrasing content, Embedded content,
+///   Interactive content (usemap 속성 있을 때), Palpable content
+///
+/// # 필수 속성
+/// - `src`: 이미지 URL
+/// - `alt`: 대체 텍스트
+pub struct Img {
+    attrs: SharedAttrs,
+}
+
+impl Img {
+    /// 새 Img 생성
+    ///
+    /// # Arguments
+    /// - `attrs`: Image 타입 속성 (src, alt 포함)
+    ///
+    /// # Example
+    /// ```rust
+    /// let img = Img::new(
+    ///     AttrBuilder::image()
+    ///         .src(AttrValue::from_str("/logo.png", &rule))
+    ///         .alt(AttrValue::from_str("Company Logo", &rule))
+    /// );
+    /// ```
+    pub fn new(attrs: Attributes<Image>) -> Self {
+        Img {
+            attrs: SharedAttrs::from_map(attrs.table),
+        }
+    }
+}
+
+impl Node for Img {
+    fn to_irnode(&self) -> IRNode {
+        IRNode::void(TagName::from_str("img"), self.attrs.clone())  // Void: 자식 없음
+    }
+}
+
+impl FlowContent for Img {}
+impl Phrasing for Img {}
+impl Embedded for Img {}
+
+/// Video 요소. 동영상 재생.
+///
+/// # HTML5 명세
+/// - Content model: source 0개 이상 + track 0개 이상 + Transparent (미디어 요소 제외)
+/// - Categories: Flow content, Phrasing content, Embedded content,
+///   Interactive content (controls 속성 있을 때), Palpable content
+///
+/// 자식은 `MediaContent`: `Source`, `Track`, 재생할 수 없는 브라우저에 보이는 대체 `Phrasing` 콘텐츠.
+///
+/// # Example
+/// ```rust
+/// let video = Video::new(
+///     AttrBuilder::media().controls(true).muted(true).poster(url("/intro.jpg")),
+///     vec![
+///         Box::new(Source::new(AttrBuilder::media().src(url("/intro.webm")).r#type(attr("video/webm")))),
+///         Box::new(Track::new(AttrBuilder::media().kind(attr("captions")).srclang(attr("ko")).src(url("/intro.vtt")))),
+///         Box::new(text("동영상을 재생할 수 없습니다.")),
+///     ],
+/// );
+/// ```
+#[derive(Clone)]
+pub struct Video {
+    attrs: SharedAttrs,
+    childs: Vec<Element>,
+}
+
+impl Video {
+    pub fn new(attrs: Attributes<Media>, childs: Vec<Box<dyn MediaContent>>) -> Self {
+        Video {
+            attrs: SharedAttrs::from_map(attrs.table),
+            childs: childs.iter().map(|c| c.to_element()).collect(),
+        }
+    }
+}
+
+impl Node for Video {
+    fn to_irnode(&self) -> IRNode {
+        IRNode::new(TagName::from_str("video"), self.attrs.clone(), self.childs.clone())
+    }
+}
+
+impl FlowContent for Video {}
+impl Phrasing for Video {}
+impl Embedded for Video {}
+impl Interactive for Video {}
+
+/// Audio 요소. 소리 재생.
+///
+/// # HTML5 명세
+/// - Content model: source 0개 이상 + track 0개 이상 + Transparent (미디어 요소 제외)
+/// - Categories: Flow content, Phrasing content, Embedded content,
+///   Interactive content (controls 속성 있을 때), Palpable content (controls 속성 있을 때)
+#[derive(Clone)]
+pub struct Audio {
+    attrs: SharedAttrs,
+    childs: Vec<Element>,
+}
+
+impl Audio {
+    pub fn new(attrs: Attributes<Media>, childs: Vec<Box<dyn MediaContent>>) -> Self {
+        Audio {
+            attrs: SharedAttrs::from_map(attrs.table),
+            childs: childs.iter().map(|c| c.to_element()).collect(),
+        }
+    }
+}
+
+impl Node for Audio {
+    fn to_irnode(&self) -> IRNode {
+        IRNode::new(TagName::from_str("audio"), self.attrs.clone(), self.childs.clone())
+    }
+}
+
+impl FlowContent for Audio {}
+impl Phrasing for Audio {}
+impl Embedded for Audio {}
+impl Interactive for Audio {}
+
+/// Source 요소. 미디어 후보 리소스.
+///
+/// # HTML5 명세
+/// - Content model: (없음 - Void 요소)
+/// - Categories: 없음 (video, audio, picture의 자식)
+///
+/// video, audio 안에서는 `src`와 `type`, picture 안에서는 `srcset`, `sizes`, `media`, `type`을 씁니다.
+#[derive(Clone)]
+pub struct Source {
+    attrs: SharedAttrs,
+}
+
+impl Source {
+    pub fn new(attrs: Attributes<Media>) -> Self {
+        Source {
+            attrs: SharedAttrs::from_map(attrs.table),
+        }
+    }
+}
+
+impl Node for Source {
+    fn to_irnode(&self) -> IRNode {
+        IRNode::void(TagName::from_str("source"), self.attrs.clone())
+    }
+}
+
+impl MediaContent for Source {}
+
+/// Track 요소. 자막, 챕터 등 시간 지정 텍스트.
+///
+/// # HTML5 명세
+/// - Content model: (없음 - Void 요소)
+/// - Categories: 없음 (video, audio의 자식)
+///
+/// # 필수 속성
+/// - `src`: WebVTT 파일 URL
+/// - `srclang`: `kind`가 `subtitles`일 때
+#[derive(Clone)]
+pub struct Track {
+    attrs: SharedAttrs,
+}
+
+impl Track {
+    pub fn new(attrs: Attributes<Media>) -> Self {
+        Track {
+            attrs: SharedAttrs::from_map(attrs.table),
+        }
+    }
+}
+
+impl Node for Track {
+    fn to_irnode(&self) -> IRNode {
+        IRNode::void(TagName::from_str("track"), self.attrs.clone())
+    }
+}
+
+impl MediaContent for Track {}
+
+/// Picture 요소. 조건별 이미지 후보.
+///
+/// # HTML5 명세
+/// - Content model: source 0개 이상 + img 1개
+/// - Categories: Flow content, Phrasing content, Embedded content, Palpable content
+///
+/// 내용 순서를 인자로 강제합니다: `sources`가 먼저, 마지막에 `img` 하나.
+///
+/// # Example
+/// ```rust
+/// let picture = Picture::new(
+///     AttrBuilder::global(),
+///     vec![Source::new(AttrBuilder::media().srcset(srcset("/hero.avif")).r#type(attr("image/avif")))],
+///     Img::new(AttrBuilder::image().src(url("/hero.jpg")).alt(attr("풍경"))),
+/// );
+/// ```
+#[derive(Clone)]
+pub struct Picture {
+    attrs: SharedAttrs,
+    childs: Vec<Element>,
+}
+
+impl Picture {
+    pub fn new(attrs: Attributes<Global>, sources: Vec<Source>, img: Img) -> Self {
+        let mut childs: Vec<Element> = sources.iter().map(|s| s.to_element()).collect();
+        childs.push(img.to_element());
+        Picture {
+            attrs: SharedAttrs::from_map(attrs.table),
+            childs,
+        }
+    }
+}
+
+impl Node for Picture {
+    fn to_irnode(&self) -> IRNode {
+        IRNode::new(TagName::from_str("picture"), self.attrs.clone(), self.childs.clone())
+    }
+}
+
+impl FlowContent for Picture {}
+impl Phrasing for Picture {}
+impl Embedded for Picture {}
+
+// ============================================================================
+// 표 (Tabular Data)
+// ============================================================================
+
+/// Table 요소. 표.
+///
+/// # HTML5 명세
+/// - Content model: caption?, colgroup*, thead?, (tbody* | tr+), tfoot?
+/// - Categories: Flow content, Palpable content
+///
+/// 자식 종류는 `TableContent`로 제한합니다. 순서(caption이 맨 앞 등)는 생성자 호출 순서를 따릅니다.
+///
+/// # Example
+/// ```rust
+/// let table = Table::new(
+///     AttrBuilder::global(),
+///     vec![
+///         Box::new(Caption::new(AttrBuilder::global(), vec![Box::new(P::new(AttrBuilder::global(), vec![Box::new(text("매출"))]))])),
+///         Box::new(Thead::new(AttrBuilder::global(), vec![
+///             Tr::new(AttrBuilder::global(), vec![Box::new(Th::new(AttrBuilder::table_cell().colspan(attr(2)), vec![]))]),
+///         ])),
+///         Box::new(Tbody::new(AttrBuilder::global(), rows)),
+///     ],
+/// );
+/// // → <table><caption>...</caption><thead><tr><th colspan="2"></th></tr></thead><tbody>...</tbody></table>
+/// ```
+#[derive(Clone)]
+pub struct Table {
+    attrs: SharedAttrs,
+    childs: Vec<Element>,
+}
+
+impl Table {
+    pub fn new(attrs: Attributes<Global>, childs: Vec<Box<dyn TableContent>>) -> Self {
+        Table {
+            attrs: SharedAttrs::from_map(attrs.table),
+            childs: childs.iter().map(|c| c.to_element()).collect(),
+        }
+    }
+}
+
+impl Node for Table {
+    fn to_irnode(&self) -> IRNode {
+        IRNode::new(TagName::from_str("table"), self.attrs.clone(), self.childs.clone())
+    }
+}
+
+impl FlowContent for Table {}
+
+/// Caption 요소. 표 제목.
+///
+/// # HTML5 명세
+/// - Content model: Flow content (table 제외)
+/// - Categories: 없음 (table의 첫 자식)
+#[derive(Clone)]
+pub struct Caption {
+    attrs: SharedAttrs,
+    childs: Vec<Element>,
+}
+
+impl Caption {
+    pub fn new(attrs: Attributes<Global>, childs: Vec<Box<dyn FlowContent>>) -> Self {
+        Caption {
+            attrs: SharedAttrs::from_map(attrs.table),
+            childs: childs.iter().map(|c| c.to_element()).collect(),
+        }
+    }
+}
+
+impl Node for Caption {
+    fn to_irnode(&self) -> IRNode {
+        IRNode::new(TagName::from_str("caption"), self.attrs.clone(), self.childs.clone())
+    }
+}
+
+impl TableContent for Caption {}
+
+/// Colgroup 요소. 열 묶음.
+///
+/// # HTML5 명세
+/// - Content model: `span` 속성이 있으면 없음, 없으면 col 0개 이상
+/// - Categories: 없음 (table의 자식)
+#[derive(Clone)]
+pub struct Colgroup {
+    attrs: SharedAttrs,
+    childs: Vec<Element>,
+}
+
+impl Colgroup {
+    pub fn new(attrs: Attributes<Column>, cols: Vec<Col>) -> Self {
+        Colgroup {
+            attrs: SharedAttrs::from_map(attrs.table),
+            childs: cols.iter().map(|c| c.to_element()).collect(),
+        }
+    }
+}
+
+impl Node for Colgroup {
+    fn to_irnode(&self) -> IRNode {
+        IRNode::new(TagName::from_str("colgroup"), self.attrs.clone(), self.childs.clone())
+    }
+}
+
+impl TableContent for Colgroup {}
+
+/// Col 요소. 열 하나 (또는 `span`개).
+///
+/// # HTML5 명세
+/// - Content model: (없음 - Void 요소)
+/// - Categories: 없음 (colgroup의 자식)
+#[derive(Clone)]
+pub struct Col {
+    attrs: SharedAttrs,
+}
+
+impl Col {
+    pub fn new(attrs: Attributes<Column>) -> Self {
+        Col {
+            attrs: SharedAttrs::from_map(attrs.table),
+        }
+    }
+}
+
+impl Node for Col {
+    fn to_irnode(&self) -> IRNode {
+        IRNode::void(TagName::from_str("col"), self.attrs.clone())
+    }
+}
+
+/// Thead 요소. 머리글 행 묶음.
+///
+/// # HTML5 명세
+/// - Content model: tr 0개 이상
+/// - Categories: 없음 (table의 자식)
+#[derive(Clone)]
+pub struct Thead {
+    attrs: SharedAttrs,
+    childs: Vec<Element>,
+}
+
+impl Thead {
+    pub fn new(attrs: Attributes<Global>, rows: Vec<Tr>) -> Self {
+        Thead {
+            attrs: SharedAttrs::from_map(attrs.table),
+            childs: rows.iter().map(|r| r.to_element()).collect(),
+        }
+    }
+}
+
+impl Node for Thead {
+    fn to_irnode(&self) -> IRNode {
+        IRNode::new(TagName::from_str("thead"), self.attrs.clone(), self.childs.clone())
+    }
+}
+
+impl TableContent for Thead {}
+
+/// Tbody 요소. 본문 행 묶음.
+///
+/// # HTML5 명세
+/// - Content model: tr 0개 이상
+/// - Categories: 없음 (table의 자식)
+#[derive(Clone)]
+pub struct Tbody {
+    attrs: SharedAttrs,
+    childs: Vec<Element>,
+}
+
+impl Tbody {
+    pub fn new(attrs: Attributes<Global>, rows: Vec<Tr>) -> Self {
+        Tbody {
+            attrs: SharedAttrs::from_map(attrs.table),
+            childs: rows.iter().map(|r| r.to_element()).collect(),
+        }
+    }
+}
+
+impl Node for Tbody {
+    fn to_irnode(&self) -> IRNode {
+        IRNode::new(TagName::from_str("tbody"), self.attrs.clone(), self.childs.clone())
+    }
+}
+
+impl TableContent for Tbody {}
+
+/// Tfoot 요소. 바닥글 행 묶음.
+///
+/// # HTML5 명세
+/// - Content model: tr 0개 이상
+/// - Categories: 없음 (table의 자식)
+#[derive(Clone)]
+pub struct Tfoot {
+    attrs: SharedAttrs,
+    childs: Vec<Element>,
+}
+
+impl Tfoot {
+    pub fn new(attrs: Attributes<Global>, rows: Vec<Tr>) -> Self {
+        Tfoot {
+            attrs: SharedAttrs::from_map(attrs.table),
+            childs: rows.iter().map(|r| r.to_element()).collect(),
+        }
+    }
+}
+
+impl Node for Tfoot {
+    fn to_irnode(&self) -> IRNode {
+        IRNode::new(TagName::from_str("tfoot"), self.attrs.clone(), self.childs.clone())
+    }
+}
+
+impl TableContent for Tfoot {}
+
+/// Tr 요소. 행.
+///
+/// # HTML5 명세
+/// - Content model: th, td 0개 이상
+/// - Categories: 없음 (thead, tbody, tfoot, table의 자식)
+///
+/// 행 묶음(`Thead`, `Tbody`, `Tfoot`)은 `Tr`만 받으므로 셀을 행 없이 넣을 수 없습니다.
+#[derive(Clone)]
+pub struct Tr {
+    attrs: SharedAttrs,
+    childs: Vec<Element>,
+}
+
+impl Tr {
+    pub fn new(attrs: Attributes<Global>, cells: Vec<Box<dyn TrContent>>) -> Self {
+        Tr {
+            attrs: SharedAttrs::from_map(attrs.table),
+            childs: cells.iter().map(|c| c.to_element()).collect(),
+        }
+    }
+}
+
+impl Node for Tr {
+    fn to_irnode(&self) -> IRNode {
+        IRNode::new(TagName::from_str("tr"), self.attrs.clone(), self.childs.clone())
+    }
+}
+
+impl TableContent for Tr {}
+
+/// Th 요소. 머리글 셀.
+///
+/// # HTML5 명세
+/// - Content model: Flow content (header, footer, sectioning, heading 제외)
+/// - Categories: 없음 (tr의 자식)
+#[derive(Clone)]
+pub struct Th {
+    attrs: SharedAttrs,
+    childs: Vec<Element>,
+}
+
+impl Th {
+    pub fn new(attrs: Attributes<TableCell>, childs: Vec<Box<dyn FlowContent>>) -> Self {
+        Th {
+            attrs: SharedAttrs::from_map(attrs.table),
+            childs: childs.iter().map(|c| c.to_element()).collect(),
+        }
+    }
+}
+
+impl Node for Th {
+    fn to_irnode(&self) -> IRNode {
+        IRNode::new(TagName::from_str("th"), self.attrs.clone(), self.childs.clone())
+    }
+}
+
+impl TrContent for Th {}
+
+/// Td 요소. 데이터 셀.
+///
+/// # HTML5 명세
+/// - Content model: Flow content
+/// - Categories: 없음 (tr의 자식)
+#[derive(Clone)]
+pub struct Td {
+    attrs: SharedAttrs,
+    childs: Vec<Element>,
+}
+
+impl Td {
+    pub fn new(attrs: Attributes<TableCell>, childs: Vec<Box<dyn FlowContent>>) -> Self {
+        Td {
+            attrs: SharedAttrs::from_map(attrs.table),
+            childs: childs.iter().map(|c| c.to_element()).collect(),
+        }
+    }
+}
+
+impl Node for Td {
+    fn to_irnode(&self) -> IRNode {
+        IRNode::new(TagName::from_str("td"), self.attrs.clone(), self.childs.clone())
+    }
+}
+
+impl TrContent for Td {}
+
+// ============================================================================
+// 폼 (Forms)
+// ============================================================================
+
+/// Form 요소. 사용자 입력 제출.
+///
+/// # HTML5 명세
+/// - Content model: Flow content (form 제외)
+/// - Categories: Flow content, Palpable content
+///
+/// # Example
+/// ```rust
+/// let form = Form::new(
+///     AttrBuilder::form().action(url("https://buttondown.email/api/subscribe")).method(attr("post")),
+///     vec![
+///         Box::new(Label::new(AttrBuilder::global(), vec![
+///             Box::new(text("이메일 ")),
+///             Box::new(Input::new(AttrBuilder::form().r#type(attr("email")).name(attr("email")).required(true))),
+///         ])),
+///         Box::new(Button::new(AttrBuilder::form().r#type(attr("submit")), vec![Box::new(text("구독"))])),
+///     ],
+/// );
+/// ```
+#[derive(Clone)]
+pub struct Form {
+    attrs: SharedAttrs,
+    childs: Vec<Element>,
+}
+
+impl Form {
+    pub fn new(attrs: Attributes<attributes::Form>, childs: Vec<Box<dyn FlowContent>>) -> Self {
+        Form {
+            attrs: SharedAttrs::from_map(attrs.table),
+            childs: childs.iter().map(|c| c.to_element()).collect(),
+        }
+    }
+}
+
+impl Node for Form {
+    fn to_irnode(&self) -> IRNode {
+        IRNode::new(TagName::from_str("form"), self.attrs.clone(), self.childs.clone())
+    }
+}
+
+impl FlowContent for Form {}
+
+/// Input 요소. 입력 컨트롤.
+///
+/// # HTML5 명세
+/// - Content model: (없음 - Void 요소)
+/// - Categories: Flow content, Phrasing content, Interactive content (type이 hidden이 아닐 때),
+///   Form-associated element, Palpable content
+#[derive(Clone)]
+pub struct Input {
+    attrs: SharedAttrs,
+}
+
+impl Input {
+    pub fn new(attrs: Attributes<attributes::Form>) -> Self {
+        Input {
+            attrs: SharedAttrs::from_map(attrs.table),
+        }
+    }
+}
+
+impl Node for Input {
+    fn to_irnode(&self) -> IRNode {
+        IRNode::void(TagName::from_str("input"), self.attrs.clone())
+    }
+}
+
+impl FlowContent for Input {}
+impl Phrasing for Input {}
+impl Interactive for Input {}
+impl Formassociated for Input {}
+
+/// Button 요소. 버튼.
+///
+/// # HTML5 명세
+/// - Content model: Phrasing content (Interactive content 제외)
+/// - Categories: Flow content, Phrasing content, Interactive content, Form-associated element, Palpable content
+///
+/// `type`을 지정하지 않은 form 안의 버튼은 제출 버튼입니다.
+#[derive(Clone)]
+pub struct Button {
+    attrs: SharedAttrs,
+    childs: Vec<Element>,
+}
+
+impl Button {
+    pub fn new(attrs: Attributes<attributes::Form>, childs: Vec<Box<dyn Phrasing>>) -> Self {
+        Button {
+            attrs: SharedAttrs::from_map(attrs.table),
+            childs: childs.iter().map(|c| c.to_element()).collect(),
+        }
+    }
+}
+
+impl Node for Button {
+    fn to_irnode(&self) -> IRNode {
+        IRNode::new(TagName::from_str("button"), self.attrs.clone(), self.childs.clone())
+    }
+}
+
+impl FlowContent for Button {}
+impl Phrasing for Button {}
+impl Interactive for Button {}
+impl Formassociated for Button {}
+
+/// Label 요소. 폼 컨트롤의 이름.
+///
+/// # HTML5 명세
+/// - Content model: Phrasing content (label 제외, 레이블 대상 컨트롤 하나)
+/// - Categories: Flow content, Phrasing content, Interactive content, Palpable content
+///
+/// 컨트롤을 자식으로 넣으면 `for` 없이 연결됩니다.
+#[derive(Clone)]
+pub struct Label {
+    attrs: SharedAttrs,
+    childs: Vec<Element>,
+}
+
+impl Label {
+    pub fn new(attrs: Attributes<Global>, childs: Vec<Box<dyn Phrasing>>) -> Self {
+        Label {
+            attrs: SharedAttrs::from_map(attrs.table),
+            childs: childs.iter().map(|c| c.to_element()).collect(),
+        }
+    }
+}
+
+impl Node for Label {
+    fn to_irnode(&self) -> IRNode {
+        IRNode::new(TagName::from_str("label"), self.attrs.clone(), self.childs.clone())
+    }
+}
+
+impl FlowContent for Label {}
+impl Phrasing for Label {}
+impl Interactive for Label {}
+
+/// Select 요소. 선택 목록.
+///
+/// # HTML5 명세
+/// - Content model: option, optgroup 0개 이상
+/// - Categories: Flow content, Phrasing content, Interactive content, Form-associated element, Palpable content
+#[derive(Clone)]
+pub struct Select {
+    attrs: SharedAttrs,
+    childs: Vec<Element>,
+}
+
+impl Select {
+    pub fn new(attrs: Attributes<attributes::Form>, options: Vec<Box<dyn SelectContent>>) -> Self {
+        Select {
+            attrs: SharedAttrs::from_map(attrs.table),
+            childs: options.iter().map(|o| o.to_element()).collect(),
+        }
+    }
+}
+
+impl Node for Select {
+    fn to_irnode(&self) -> IRNode {
+        IRNode::new(TagName::from_str("select"), self.attrs.clone(), self.childs.clone())
+    }
+}
+
+impl FlowContent for Select {}
+impl Phrasing for Select {}
+impl Interactive for Select {}
+impl Formassociated for Select {}
+
+/// Option 요소. 선택 목록의 항목.
+///
+/// # HTML5 명세
+/// - Content model: Text
+/// - Categories: 없음 (select, optgroup, datalist의 자식)
+///
+/// 이름이 `std::option::Option`과 같으므로 `elements::Option`처럼 경로로 씁니다.
+#[derive(Clone)]
+pub struct Option {
+    attrs: SharedAttrs,
+    content: Content,
+}
+
+impl Option {
+    pub fn new(attrs: Attributes<attributes::Form>, content: Content) -> Self {
+        Option {
+            attrs: SharedAttrs::from_map(attrs.table),
+            content,
+        }
+    }
+}
+
+impl Node for Option {
+    fn to_irnode(&self) -> IRNode {
+        IRNode::new(
+            TagName::from_str("option"),
+            self.attrs.clone(),
+            vec![Element::Text(self.content.clone())],
+        )
+    }
+}
+
+impl SelectContent for Option {}
+
+/// Textarea 요소. 여러 줄 입력.
+///
+/// # HTML5 명세
+/// - Content model: Text (초기값, 공백 보존)
+/// - Categories: Flow content, Phrasing content, Interactive content, Form-associated element, Palpable content
+#[derive(Clone)]
+pub struct Textarea {
+    attrs: SharedAttrs,
+    content: Content,
+}
+
+impl Textarea {
+    pub fn new(attrs: Attributes<attributes::Form>, content: Content) -> Self {
+        Textarea {
+            attrs: SharedAttrs::from_map(attrs.table),
+            content,
+        }
+    }
+}
+
+impl Node for Textarea {
+    fn to_irnode(&self) -> IRNode {
+        IRNode::new(
+            TagName::from_str("textarea"),
+            self.attrs.clone(),
+            vec![Element::Text(self.content.clone())],
+        )
+    }
+}
+
+impl FlowContent for Textarea {}
+impl Phrasing for Textarea {}
+impl Interactive for Textarea {}
+impl Formassociated for Textarea {}
+
+/// Fieldset 요소. 컨트롤 묶음.
+///
+/// # HTML5 명세
+/// - Content model: legend (선택, 첫 자식) + Flow content
+/// - Categories: Flow content, Form-associated element, Palpable content
+///
+/// 자식은 `FieldsetContent`: `Legend`와 모든 `FlowContent`. `disabled`면 안의 컨트롤이 모두 비활성화됩니다.
+#[derive(Clone)]
+pub struct Fieldset {
+    attrs: SharedAttrs,
+    childs: Vec<Element>,
+}
+
+impl Fieldset {
+    pub fn new(attrs: Attributes<attributes::Form>, childs: Vec<Box<dyn FieldsetContent>>) -> Self {
+        Fieldset {
+            attrs: SharedAttrs::from_map(attrs.table),
+            childs: childs.iter().map(|c| c.to_element()).collect(),
+        }
+    }
+}
+
+impl Node for Fieldset {
+    fn to_irnode(&self) -> IRNode {
+        IRNode::new(TagName::from_str("fieldset"), self.attrs.clone(), self.childs.clone())
+    }
+}
+
+impl FlowContent for Fieldset {}
+impl Formassociated for Fieldset {}
+
+/// Legend 요소. fieldset 제목.
+///
+/// # HTML5 명세
+/// - Content model: Phrasing content (heading 허용)
+/// - Categories: 없음 (fieldset의 첫 자식)
+#[derive(Clone)]
+pub struct Legend {
+    attrs: SharedAttrs,
+    childs: Vec<Element>,
+}
+
+impl Legend {
+    pub fn new(attrs: Attributes<Global>, childs: Vec<Box<dyn Phrasing>>) -> Self {
+        Legend {
+            attrs: SharedAttrs::from_map(attrs.table),
+            childs: childs.iter().map(|c| c.to_element()).collect(),
+        }
+    }
+}
+
+impl Node for Legend {
+    fn to_irnode(&self) -> IRNode {
+        IRNode::new(TagName::from_str("legend"), self.attrs.clone(), self.childs.clone())
+    }
+}
+
+impl FieldsetContent for Legend {}
+
+// ============================================================================
+// 대화형 요소 (Interactive Elements)
+// ============================================================================
+
+/// Details 요소. 요약을 누르면 펼쳐지는 접이식 콘텐츠 (아코디언).
+///
+/// # HTML5 명세
+/// - Content model: summary 하나 + Flow content
+/// - Categories: Flow content, Sectioning root, Interactive content, Palpable content
+///
+/// # 특징
+/// - `open(true)`: 처음부터 펼친 상태
+/// - 같은 `name`을 가진 details는 한 번에 하나만 열림 (스크립트 없는 아코디언)
+///
+/// # Example
+/// ```rust
+/// Details::new(
+///     AttrBuilder::disclosure().name(attr("faq")).open(true),
+///     Summary::new(AttrBuilder::global(), vec![Box::new(text("배송은 얼마나 걸리나요?"))]),
+///     vec![Box::new(P::new(AttrBuilder::global(), vec![Box::new(text("2~3일"))]))],
+/// );
+/// // → <details name="faq" open><summary>배송은 얼마나 걸리나요?</summary><p>2~3일</p></details>
+/// ```
+#[derive(Clone)]
+pub struct Details {
+    attrs: SharedAttrs,
+    summary: Summary,
+    childs: Vec<Element>,
+}
+
+impl Details {
+    pub fn new(
+        attrs: Attributes<Disclosure>,
+        summary: Summary,
+        childs: Vec<Box<dyn FlowContent>>,
+    ) -> Self {
+        Details {
+            attrs: SharedAttrs::from_map(attrs.table),
+            summary,
+            childs: childs.iter().map(|c| c.to_element()).collect(),
+        }
+    }
+}
+
+impl Node for Details {
+    fn to_irnode(&self) -> IRNode {
+        let mut childs = vec![self.summary.to_element()];
+        childs.extend(self.childs.iter().cloned());
+        IRNode::new(TagName::from_str("details"), self.attrs.clone(), childs)
+    }
+}
+
+impl FlowContent for Details {}
+impl Interactive for Details {}
+
+/// Summary 요소. Details의 요약 (항상 보이는 부분). `Details::new`로만 넣습니다.
+///
+/// # HTML5 명세
+/// - Content model: Phrasing content (또는 제목 하나)
+/// - Categories: 없음 (details의 첫 자식)
+#[derive(Clone)]
+pub struct Summary {
+    attrs: SharedAttrs,
+    childs: Vec<Element>,
+}
+
+impl Summary {
+    pub fn new(attrs: Attributes<Global>, childs: Vec<Box<dyn Phrasing>>) -> Self {
+        Summary {
+            attrs: SharedAttrs::from_map(attrs.table),
+            childs: childs.iter().map(|c| c.to_element()).collect(),
+        }
+    }
+}
+
+impl Node for Summary {
+    fn to_irnode(&self) -> IRNode {
+        IRNode::new(TagName::from_str("summary"), self.attrs.clone(), self.childs.clone())
+    }
+}
+
+/// Dialog 요소. 대화 상자.
+///
+/// # HTML5 명세
+/// - Content model: Flow content
+/// - Categories: Flow content, Sectioning root
+///
+/// # 특징
+/// - 기본은 닫힌 상태. `open(true)`는 모달 없이 열린 상태이고, 모달로 열려면 스크립트의 `showModal()`을 씀
+#[derive(Clone)]
+pub struct Dialog {
+    attrs: SharedAttrs,
+    childs: Vec<Element>,
+}
+
+impl Dialog {
+    pub fn new(attrs: Attributes<Disclosure>, childs: Vec<Box<dyn FlowContent>>) -> Self {
+        Dialog {
+            attrs: SharedAttrs::from_map(attrs.table),
+            childs: childs.iter().map(|c| c.to_element()).collect(),
+        }
+    }
+}
+
+impl Node for Dialog {
+    fn to_irnode(&self) -> IRNode {
+        IRNode::new(TagName::from_str("dialog"), self.attrs.clone(), self.childs.clone())
+    }
+}
+
+impl FlowContent for Dialog {}
+
+// ============================================================================
+// 스크립트, 스타일 (Raw text elements)
+// ============================================================================
+
+/// `pattern`(ASCII 대소문자 무시)이 나올 때마다 `at` 위치에 `\`를 넣어 끊습니다.
+fn break_sequences(text: &str, patterns: &[(&str, usize)]) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    'scan: while !rest.is_empty() {
+        for (pattern, at) in patterns {
+            let matched = rest
+                .get(..pattern.len())
+                .is_some_and(|head| head.eq_ignore_ascii_case(pattern));
+            if matched {
+                out.push_str(&rest[..*at]);
+                out.push('\\');
+                out.push_str(&rest[*at..pattern.len()]);
+                rest = &rest[pattern.len()..];
+                continue 'scan;
+            }
+        }
+        let ch = rest.chars().next().unwrap_or_default();
+        out.push(ch);
+        rest = &rest[ch.len_utf8()..];
+    }
+    out
+}
+
+/// 인라인 스크립트 내용 이스케이프. `</script`와 `<!--`(이중 이스케이프 상태 진입)를 끊습니다.
+fn escape_script(js: &str, cdata: bool) -> String {
+    if cdata {
+        let body = break_sequences(js, &[("</script", 1), ("<!--", 1), ("]]>", 2)]);
+        format!("//<![CDATA[\n{}\n//]]>", body)
+    } else {
+        break_sequences(js, &[("</script", 1), ("<!--", 1)])
+    }
+}
+
+/// 인라인 스타일 내용 이스케이프. `style`은 RAWTEXT이므로 `</style`만 끊습니다.
+fn escape_style(css: &str, cdata: bool) -> String {
+    if cdata {
+        let body = break_sequences(css, &[("</style", 1), ("]]>", 2)]);
+        format!("/*<![CDATA[*/\n{}\n/*]]>*/", body)
+    } else {
+        break_sequences(css, &[("</style", 1)])
+    }
+}
+
+/// Script 요소. 인라인 스크립트.
+///
+/// # HTML5 명세
+/// - Content model: 스크립트 (텍스트, HTML 이스케이프 없음)
+/// - Categories: Metadata content, Flow content, Phrasing content, Script-supporting element
+///
+/// # Example
+/// ```rust
+/// let script = Script::inline("document.documentElement.dataset.js = '1';");
+/// // → <script>document.documentElement.dataset.js = '1';</script>
+/// ```
+#[derive(Clone)]
+pub struct Script {
+    attrs: SharedAttrs,
+    code: String,
+    cdata: bool,
+}
+
+impl Script {
+    /// 인라인 스크립트. 내용은 `to_irnode`에서 스크립트 규칙으로 이스케이프됩니다.
+    pub fn inline(js: &str) -> Self {
+        Script {
+            attrs: SharedAttrs::new(),
+            code: js.to_string(),
+            cdata: false,
+        }
+    }
+
+    /// 외부 스크립트 (`src`, `defer`, `async`, `type="module"` 등). 내용은 비어 있습니다.
+    ///
+    /// ```rust
+    /// let script = Script::external(AttrBuilder::metadata().src(url("/js/app.js")).defer(true));
+    /// // → <script defer src="/js/app.js"></script>
+    /// ```
+    pub fn external(attrs: Attributes<attributes::Metadata>) -> Self {
+        Script {
+            attrs: SharedAttrs::from_map(attrs.table),
+            code: String::new(),
+            cdata: false,
+        }
+    }
+
+    /// Global 속성 (id, nonce용 data 속성 등).
+    pub fn attrs(mut self, attrs: Attributes<Global>) -> Self {
+        self.attrs = SharedAttrs::from_map(attrs.table);
+        self
+    }
+
+    /// CDATA 구획으로 감싸기 (XHTML 호환). 기본: false
+    pub fn cdata(mut self, cdata: bool) -> Self {
+        self.cdata = cdata;
+        self
+    }
+}
+
+impl Node for Script {
+    fn to_irnode(&self) -> IRNode {
+        if self.code.is_empty() {
+            return IRNode::new(TagName::from_str("script"), self.attrs.clone(), vec![]);
+        }
+        let code = escape_script(&self.code, self.cdata);
+        IRNode::new(
+            TagName::from_str("script"),
+            self.attrs.clone(),
+            vec![Element::Raw(HtmlBlock::from_str(&code))],
+        )
+    }
+}
+
+impl MetadataContent for Script {}
+impl FlowContent for Script {}
+impl Phrasing for Script {}
+impl node::Script for Script {}
+
+/// Style 요소. 인라인 스타일시트.
+///
+/// # HTML5 명세
+/// - Content model: 스타일시트 (텍스트, HTML 이스케이프 없음)
+/// - Categories: Metadata content
+///
+/// # Example
+/// ```rust
+/// let style = Style::inline(".note > p { margin: 0 }");
+/// // → <style>.note > p { margin: 0 }</style>
+/// ```
+#[derive(Clone)]
+pub struct Style {
+    attrs: SharedAttrs,
+    css: String,
+    cdata: bool,
+}
+
+impl Style {
+    /// 인라인 스타일. 내용은 `to_irnode`에서 스타일 규칙으로 이스케이프됩니다.
+    pub fn inline(css: &str) -> Self {
+        Style {
+            attrs: SharedAttrs::new(),
+            css: css.to_string(),
+            cdata: false,
+        }
+    }
+
+    /// Global 속성.
+    pub fn attrs(mut self, attrs: Attributes<Global>) -> Self {
+        self.attrs = SharedAttrs::from_map(attrs.table);
+        self
+    }
+
+    /// CDATA 구획으로 감싸기 (XHTML 호환). 기본: false
+    pub fn cdata(mut self, cdata: bool) -> Self {
+        self.cdata = cdata;
+        self
+    }
+}
+
+impl Node for Style {
+    fn to_irnode(&self) -> IRNode {
+        let css = escape_style(&self.css, self.cdata);
+        IRNode::new(
+            TagName::from_str("style"),
+            self.attrs.clone(),
+            vec![Element::Raw(HtmlBlock::from_str(&css))],
+        )
+    }
+}
+
+impl MetadataContent for Style {}
+
+// ============================================================================
+// 문서 (Document metadata)
+// ============================================================================
+
+/// Html 요소. 문서의 루트.
+///
+/// # HTML5 명세
+/// - Content model: head 하나 + body 하나
+/// - Categories: 없음 (문서 루트)
+///
+/// 내용 순서를 인자로 강제합니다. `HtmlRenderer`는 문서 맨 앞의 `html` 앞에 `<!DOCTYPE html>`을 붙입니다.
+///
+/// # Example
+/// ```rust
+/// let page = Html::new(
+///     AttrBuilder::global().lang(attr("ko")),
+///     Head::new(AttrBuilder::global(), vec![
+///         Box::new(Meta::new(AttrBuilder::metadata().charset(attr("UTF-8")))),
+///         Box::new(Title::new(AttrBuilder::global(), text("소개"))),
+///     ]),
+///     Body::new(AttrBuilder::global(), vec![Box::new(P::new(AttrBuilder::global(), vec![]))]),
+/// );
+/// // → <!DOCTYPE html><html lang="ko"><head><meta charset="UTF-8" ><title>소개</title></head><body><p></p></body></html>
+/// ```
+#[derive(Clone)]
+pub struct Html {
+    attrs: SharedAttrs,
+    childs: Vec<Element>,
+}
+
+impl Html {
+    pub fn new(attrs: Attributes<Global>, head: Head, body: Body) -> Self {
+        Html {
+            attrs: SharedAttrs::from_map(attrs.table),
+            childs: vec![head.to_element(), body.to_element()],
+        }
+    }
+}
+
+impl Node for Html {
+    fn to_irnode(&self) -> IRNode {
+        IRNode::new(TagName::from_str("html"), self.attrs.clone(), self.childs.clone())
+    }
+}
+
+/// Head 요소. 문서 메타데이터 모음.
+///
+/// # HTML5 명세
+/// - Content model: Metadata content (title 하나 포함)
+/// - Categories: 없음 (html의 첫 자식)
+///
+/// title이 하나인지는 타입으로 막지 않습니다.
+#[derive(Clone)]
+pub struct Head {
+    attrs: SharedAttrs,
+    childs: Vec<Element>,
+}
+
+impl Head {
+    pub fn new(attrs: Attributes<Global>, childs: Vec<Box<dyn MetadataContent>>) -> Self {
+        Head {
+            attrs: SharedAttrs::from_map(attrs.table),
+            childs: childs.iter().map(|c| c.to_element()).collect(),
+        }
+    }
+}
+
+impl Node for Head {
+    fn to_irnode(&self) -> IRNode {
+        IRNode::new(TagName::from_str("head"), self.attrs.clone(), self.childs.clone())
+    }
+}
+
+/// Body 요소. 문서 본문.
+///
+/// # HTML5 명세
+/// - Content model: Flow content
+/// - Categories: Sectioning root
+#[derive(Clone)]
+pub struct Body {
+    attrs: SharedAttrs,
+    childs: Vec<Element>,
+}
+
+impl Body {
+    pub fn new(attrs: Attributes<Global>, childs: Vec<Box<dyn FlowContent>>) -> Self {
+        Body {
+            attrs: SharedAttrs::from_map(attrs.table),
+            childs: childs.iter().map(|c| c.to_element()).collect(),
+        }
+    }
+}
+
+impl Node for Body {
+    fn to_irnode(&self) -> IRNode {
+        IRNode::new(TagName::from_str("body"), self.attrs.clone(), self.childs.clone())
+    }
+}
+
+/// Title 요소. 문서 제목 (탭, 검색 결과에 표시).
+///
+/// # HTML5 명세
+/// - Content model: 텍스트 (공백 외 문자 포함)
+/// - Categories: Metadata content
+#[derive(Clone)]
+pub struct Title {
+    attrs: SharedAttrs,
+    content: Content,
+}
+
+impl Title {
+    pub fn new(attrs: Attributes<Global>, content: Content) -> Self {
+        Title {
+            attrs: SharedAttrs::from_map(attrs.table),
+            content,
+        }
+    }
+}
+
+impl Node for Title {
+    fn to_irnode(&self) -> IRNode {
+        IRNode::new(
+            TagName::from_str("title"),
+            self.attrs.clone(),
+            vec![Element::Text(self.content.clone())],
+        )
+    }
+}
+
+impl MetadataContent for Title {}
+
+/// Meta 요소. 다른 요소로 나타낼 수 없는 메타데이터.
+///
+/// # HTML5 명세
+/// - Content model: Nothing (void)
+/// - Categories: Metadata content
+///
+/// # Example
+/// ```rust
+/// Meta::new(AttrBuilder::metadata().name(attr("description")).content(attr("소개 페이지")));
+/// // → <meta content="소개 페이지" name="description" >
+/// ```
+#[derive(Clone)]
+pub struct Meta {
+    attrs: SharedAttrs,
+}
+
+impl Meta {
+    pub fn new(attrs: Attributes<attributes::Metadata>) -> Self {
+        Meta {
+            attrs: SharedAttrs::from_map(attrs.table),
+        }
+    }
+}
+
+impl Node for Meta {
+    fn to_irnode(&self) -> IRNode {
+        IRNode::void(TagName::from_str("meta"), self.attrs.clone())
+    }
+}
+
+impl MetadataContent for Meta {}
+
+/// Link 요소. 외부 리소스와의 관계 (스타일시트, 아이콘, 대체 문서 등).
+///
+/// # HTML5 명세
+/// - Content model: Nothing (void)
+/// - Categories: Metadata content
+///
+/// # Example
+/// ```rust
+/// Link::new(AttrBuilder::metadata().rel(attr("stylesheet")).href(url("/css/site.css")));
+/// // → <link href="/css/site.css" rel="stylesheet" >
+/// ```
+#[derive(Clone)]
+pub struct Link {
+    attrs: SharedAttrs,
+}
+
+impl Link {
+    pub fn new(attrs: Attributes<attributes::Metadata>) -> Self {
+        Link {
+            attrs: SharedAttrs::from_map(attrs.table),
+        }
+    }
+}
+
+impl Node for Link {
+    fn to_irnode(&self) -> IRNode {
+        IRNode::void(TagName::from_str("link"), self.attrs.clone())
+    }
+}
+
+impl MetadataContent for Link {}
 
 // TODO: 다음 요소들 구현
-// - P: 문단
-// - Span: 인라인 컨테이너
-// - A: 링크
 // - Strong, Em: 강조
-// - Code, Pre: 코드
 // - Ul, Ol, Li: 리스트
 // - Article, Section, Nav, Header, Footer, Aside: 의미론적 요소
-// - Table, Thead, Tbody, Tr, Th, Td: 테이블
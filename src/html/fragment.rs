@@ -0,0 +1,172 @@
+//! # fragment.rs - 공유 조각 캐시
+//!
+//! ## 목적
+//! 사이트의 모든 페이지는 같은 헤더, 내비게이션, 푸터를 가집니다. 페이지마다 IRNode는 새로 만들지만
+//! HTML 문자열로 바꾸는 일은 한 번이면 충분합니다. `HtmlRenderer`에 `FragmentCache`를 주면
+//! 공유 조각 요소(`FRAGMENT_TAGS`)를 만날 때 하위 트리의 지문으로 캐시를 찾고, 있으면 렌더링한
+//! 문자열을 그대로 이어 붙입니다.
+//!
+//! ## 사용 예시
+//! ```rust
+//! let cache = Arc::new(FragmentCache::new());
+//! for page in pages {
+//!     let html = document_ir(page, &base, &assets)
+//!         .accept(HtmlRenderer::new().with_fragments(cache.clone()));
+//! }
+//! println!("{:?}", cache.stats());
+//! // FragmentStats { lookups: 300, hits: 297, bytes_reused: 412_800 }
+//! ```
+//!
+//! ## 구현 상태
+//! - [x] header, footer, nav 하위 트리 캐시 (지문 = 태그, 속성, 자식 전체)
+//! - [x] 출력 형식, 들여쓰기 단계별로 따로 저장 (`HtmlFormat::Pretty`에서도 같은 출력)
+//! - [x] 빌드 단위 캐시, 통계는 `BuildReport::fragments`
+//! - [ ] TODO: Block이 직접 캐시 대상 표시 (aside 등)
+//!
+//! ## 설계 결정
+//! - **지문으로 식별**: 현재 페이지 링크에 `aria-current`를 붙이는 내비게이션처럼 페이지마다 조금씩
+//!   다른 조각도 있으므로, Layout이 아닌 렌더링 결과(IRNode) 전체로 같은지 판단합니다.
+//!   같은 IRNode면 같은 문자열이 나오므로 캐시를 써도 출력이 바뀌지 않습니다.
+//! - **조각 요소만**: 모든 노드의 지문을 구하면 렌더링보다 비쌀 수 있으므로 반복될 가능성이 큰
+//!   `FRAGMENT_TAGS`만 찾습니다. 캐시한 조각 안의 조각은 따로 찾지 않습니다.
+//! - **빌드 단위**: `Site`가 빌드마다 새 캐시를 만들고 모든 렌더링 스레드가 나눠 씁니다.
+//!   캐시는 빌드가 끝나면 버려지므로 지문은 프로세스 안에서만 같으면 됩니다.
+
+use std::{
+    collections::{HashMap, hash_map::DefaultHasher},
+    hash::{Hash, Hasher},
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicU64, Ordering},
+    },
+};
+
+use serde::Serialize;
+
+use crate::html::node::{Element, ElementType, IRNode};
+use crate::html::renderer::HtmlFormat;
+use crate::html::trust::SafeString;
+
+/// 캐시를 찾는 요소. 사이트 골격에서 페이지마다 반복되는 요소입니다.
+pub const FRAGMENT_TAGS: &[&str] = &["header", "footer", "nav"];
+
+/// 캐시 사용 통계.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct FragmentStats {
+    /// 캐시를 찾은 조각 수
+    pub lookups: u64,
+    /// 그중 캐시에 있던 조각 수
+    pub hits: u64,
+    /// 다시 렌더링하지 않고 이어 붙인 바이트 수
+    pub bytes_reused: u64,
+}
+
+/// 렌더링 상태까지 포함한 조각 식별자. 같은 키면 같은 문자열이 나옵니다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FragmentKey {
+    /// 하위 트리 지문
+    fingerprint: u64,
+    /// 하위 트리 노드 수 (지문 충돌 방지)
+    nodes: usize,
+    format: HtmlFormat,
+    /// 들여쓰기 단계 (`HtmlFormat::Pretty`)
+    depth: usize,
+    /// 공백을 보존하는 요소 안인지
+    preserve: bool,
+    /// 문서 맨 앞인지 (`Pretty`는 맨 앞에서 줄을 바꾸지 않음)
+    leading: bool,
+}
+
+impl FragmentKey {
+    pub fn new(node: &IRNode, format: HtmlFormat, depth: usize, preserve: bool, leading: bool) -> Self {
+        let mut hasher = DefaultHasher::new();
+        let nodes = fingerprint(node, &mut hasher);
+        FragmentKey {
+            fingerprint: hasher.finish(),
+            nodes,
+            format,
+            depth,
+            preserve,
+            leading,
+        }
+    }
+}
+
+/// 빌드 단위 조각 캐시.
+#[derive(Debug, Default)]
+pub struct FragmentCache {
+    fragments: Mutex<HashMap<FragmentKey, Arc<str>>>,
+    lookups: AtomicU64,
+    hits: AtomicU64,
+    bytes_reused: AtomicU64,
+}
+
+impl FragmentCache {
+    pub fn new() -> Self {
+        FragmentCache::default()
+    }
+
+    /// 캐시가 필요한 요소인지.
+    pub fn is_fragment(node: &IRNode) -> bool {
+        FRAGMENT_TAGS.contains(&node.get_tag().as_str())
+    }
+
+    /// 렌더링한 조각 찾기.
+    pub fn get(&self, key: &FragmentKey) -> Option<Arc<str>> {
+        self.lookups.fetch_add(1, Ordering::Relaxed);
+        let fragments = self.fragments.lock().unwrap_or_else(|e| e.into_inner());
+        let html = fragments.get(key)?.clone();
+        self.hits.fetch_add(1, Ordering::Relaxed);
+        self.bytes_reused.fetch_add(html.len() as u64, Ordering::Relaxed);
+        Some(html)
+    }
+
+    /// 렌더링한 조각 저장. 다른 스레드가 먼저 저장했으면 그대로 둡니다 (같은 문자열).
+    pub fn insert(&self, key: FragmentKey, html: &str) {
+        let mut fragments = self.fragments.lock().unwrap_or_else(|e| e.into_inner());
+        fragments.entry(key).or_insert_with(|| Arc::from(html));
+    }
+
+    pub fn len(&self) -> usize {
+        self.fragments.lock().unwrap_or_else(|e| e.into_inner()).len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn stats(&self) -> FragmentStats {
+        FragmentStats {
+            lookups: self.lookups.load(Ordering::Relaxed),
+            hits: self.hits.load(Ordering::Relaxed),
+            bytes_reused: self.bytes_reused.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// 하위 트리 전체를 해시하고 노드 수 반환.
+fn fingerprint(node: &IRNode, hasher: &mut DefaultHasher) -> usize {
+    node.get_tag().as_str().hash(hasher);
+    node.get_attrs().into_string().hash(hasher);
+    matches!(node.get_type(), ElementType::Void).hash(hasher);
+    node.get_whitespace().hash(hasher);
+    node.get_childs().len().hash(hasher);
+    let mut nodes = 1;
+    for child in node.get_childs() {
+        match child {
+            Element::Text(content) => {
+                0u8.hash(hasher);
+                content.as_str().hash(hasher);
+            }
+            Element::Node(child) => {
+                1u8.hash(hasher);
+                nodes += fingerprint(child, hasher);
+            }
+            Element::Raw(html) => {
+                2u8.hash(hasher);
+                html.as_str().hash(hasher);
+            }
+        }
+    }
+    nodes
+}
@@ -0,0 +1,140 @@
+//! # intern.rs - 문자열 공유 풀
+//!
+//! ## 목적
+//! 큰 사이트는 같은 class 이름, 속성값, 짧은 텍스트를 수백만 번 만듭니다.
+//! `Content`와 `AttrValue`는 이스케이프한 문자열을 `Arc<str>`로 저장하고, 빌드 동안 활성화된
+//! `InternPool`이 있으면 같은 문자열은 한 번만 할당해 공유합니다.
+//!
+//! ## 사용 예시
+//! ```rust
+//! let pool = Arc::new(InternPool::new());
+//! {
+//!     let _scope = pool.enter();
+//!     let a = AttrValue::from_str("btn", &rule);
+//!     let b = AttrValue::from_str("btn", &rule);  // a와 같은 할당
+//! }
+//! println!("{:?}", pool.stats());
+//! // InternStats { lookups: 2, unique: 1, bytes_saved: 3 }
+//! ```
+//!
+//! ## 설계 결정
+//! - **빌드 단위 풀**: `Site::build`/`check`가 빌드마다 새 풀을 활성화하고 끝나면 해제합니다.
+//!   빌드가 끝난 뒤 문자열이 계속 남지 않고, 통계는 `BuildReport::interning`으로 보고됩니다.
+//! - **스레드 지역 활성 슬롯**: Block은 렌더링 컨텍스트 없이 `from_str`로 값을 만들므로 풀을 인자로
+//!   넘기지 않고 스레드마다 활성 풀을 둡니다. 빌드는 자신의 작업 스레드 풀을 만들 때 시작 훅에서
+//!   같은 풀을 활성화하므로(`enter_thread`), 동시에 여러 빌드를 돌려도 풀과 통계가 섞이지 않습니다.
+//!   풀이 없으면 공유 없이 할당만 합니다.
+//! - **샤드로 나눈 집합**: 작업 스레드가 한 잠금에 줄 서지 않도록 문자열 해시로 `SHARDS`개 집합 중
+//!   하나를 고릅니다.
+//! - **긴 문자열은 공유 안 함**: 본문 문단처럼 긴 텍스트는 반복될 가능성이 낮고 해시 비용만 들므로
+//!   `MAX_INTERNED_LEN`보다 길면 바로 할당합니다.
+
+use std::{
+    cell::RefCell,
+    collections::HashSet,
+    hash::{BuildHasher, RandomState},
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicU64, Ordering},
+    },
+};
+
+use serde::Serialize;
+
+/// 공유 대상 최대 길이 (바이트).
+pub const MAX_INTERNED_LEN: usize = 256;
+
+/// 문자열 집합 샤드 수.
+pub const SHARDS: usize = 16;
+
+thread_local! {
+    static ACTIVE: RefCell<Option<Arc<InternPool>>> = const { RefCell::new(None) };
+}
+
+/// 풀 사용 통계.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct InternStats {
+    /// 풀을 거친 문자열 수
+    pub lookups: u64,
+    /// 그중 서로 다른 문자열 수
+    pub unique: u64,
+    /// 공유로 아낀 바이트 수 (중복 문자열 길이 합)
+    pub bytes_saved: u64,
+}
+
+/// 빌드 단위 문자열 풀.
+#[derive(Debug, Default)]
+pub struct InternPool {
+    shards: [Mutex<HashSet<Arc<str>>>; SHARDS],
+    hasher: RandomState,
+    lookups: AtomicU64,
+    bytes_saved: AtomicU64,
+}
+
+impl InternPool {
+    pub fn new() -> Self {
+        InternPool::default()
+    }
+
+    /// 같은 문자열이 이미 있으면 그 할당을, 없으면 새로 넣고 반환.
+    pub fn intern(&self, s: &str) -> Arc<str> {
+        self.lookups.fetch_add(1, Ordering::Relaxed);
+        let shard = &self.shards[self.hasher.hash_one(s) as usize % SHARDS];
+        let mut strings = shard.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(existing) = strings.get(s) {
+            self.bytes_saved.fetch_add(s.len() as u64, Ordering::Relaxed);
+            return existing.clone();
+        }
+        let shared: Arc<str> = Arc::from(s);
+        strings.insert(shared.clone());
+        shared
+    }
+
+    pub fn stats(&self) -> InternStats {
+        let unique: usize = self
+            .shards
+            .iter()
+            .map(|shard| shard.lock().unwrap_or_else(|e| e.into_inner()).len())
+            .sum();
+        InternStats {
+            lookups: self.lookups.load(Ordering::Relaxed),
+            unique: unique as u64,
+            bytes_saved: self.bytes_saved.load(Ordering::Relaxed),
+        }
+    }
+
+    /// 현재 스레드에서 이 풀을 활성화. 반환값을 버리면 이전 풀로 돌아갑니다.
+    pub fn enter(self: &Arc<Self>) -> InternScope {
+        let previous = ACTIVE.with(|active| active.borrow_mut().replace(self.clone()));
+        InternScope { previous }
+    }
+
+    /// 현재 스레드가 끝날 때까지 이 풀을 활성화. 빌드 작업 스레드의 시작 훅에서 호출합니다.
+    pub fn enter_thread(self: &Arc<Self>) {
+        ACTIVE.with(|active| *active.borrow_mut() = Some(self.clone()));
+    }
+}
+
+/// `InternPool::enter`의 활성화 범위.
+#[must_use = "풀은 이 값이 살아 있는 동안만 활성화됩니다"]
+pub struct InternScope {
+    previous: Option<Arc<InternPool>>,
+}
+
+impl Drop for InternScope {
+    fn drop(&mut self) {
+        let previous = self.previous.take();
+        ACTIVE.with(|active| *active.borrow_mut() = previous);
+    }
+}
+
+/// 현재 스레드의 활성 풀로 공유한 문자열. 풀이 없거나 길면 새로 할당.
+pub(crate) fn intern(s: &str) -> Arc<str> {
+    if s.len() > MAX_INTERNED_LEN {
+        return Arc::from(s);
+    }
+    ACTIVE.with(|active| match active.borrow().as_ref() {
+        Some(pool) => pool.intern(s),
+        None => Arc::from(s),
+    })
+}
@@ -0,0 +1,108 @@
+//! # macros.rs - `html!` 보조 함수
+//!
+//! ## 목적
+//! `html!`(`quo-macros`)이 펼친 코드가 부르는 변환 함수입니다. 매크로 안의 리터럴과 `{식}`을
+//! 신뢰 경계 타입(`Content`, `AttrValue`)으로 바꿉니다. 직접 요소를 만들 때도 쓸 수 있습니다.
+//!
+//! ## 사용 예시
+//! ```rust
+//! H1::new(AttrBuilder::global().class(classes("title main")), text(&title))
+//! ```
+//!
+//! ## 설계 결정
+//! - **텍스트는 타이포그래피 규칙 적용, 속성과 코드는 이스케이프만**: Block 계층과 같은 규칙입니다.
+//!   URL 같은 속성값과 코드(`verbatim`)는 스마트 쿼트 등으로 바뀌면 안 됩니다.
+//! - **URL 속성은 `url`로**: `html!`은 `href`, `src`, `action` 값에 `url`, `srcset` 값에 `srcset`을 씁니다.
+//!   쿼리 값처럼 구분자를 값으로 담아야 하면 `encode_url_component`로 먼저 인코딩합니다.
+//! - **`datetime`은 `Datetime`으로**: 문자열은 `Datetime::parse`로 검사하고, 형식이 틀리면 패닉합니다.
+//!   잘못된 값이 조용히 출력되면 검색 엔진과 피드 리더가 날짜를 잃습니다.
+
+use std::{collections::BTreeSet, fmt::Display};
+
+use crate::html::attributes::Datetime;
+use crate::html::rules::{self, RuleList};
+use crate::html::trust::{AttrValue, Content, SafeString};
+
+/// 텍스트 노드 (`Display` 값, 타이포그래피 규칙 적용).
+pub fn text<T: Display + ?Sized>(value: &T) -> Content {
+    let rule = rules::Default {
+        rules: vec![RuleList::All],
+    };
+    Content::from_str(&value.to_string(), &rule)
+}
+
+/// 코드 텍스트 노드 (이스케이프만). `html!`은 `<pre>`, `<code>` 안의 텍스트에 씁니다.
+pub fn verbatim<T: Display + ?Sized>(value: &T) -> Content {
+    Content::from_str(&value.to_string(), &verbatim_rule())
+}
+
+/// 단일 속성값 (이스케이프만).
+pub fn attr<T: Display + ?Sized>(value: &T) -> AttrValue {
+    AttrValue::from_str(&value.to_string(), &verbatim_rule())
+}
+
+/// URL 속성값 (`href`, `src`). 이스케이프 전에 URL에 쓸 수 없는 문자를 퍼센트 인코딩.
+pub fn url<T: Display + ?Sized>(value: &T) -> AttrValue {
+    AttrValue::url(&value.to_string())
+}
+
+/// `srcset` 속성값. 후보마다 URL을 퍼센트 인코딩.
+pub fn srcset<T: Display + ?Sized>(value: &T) -> AttrValue {
+    AttrValue::srcset(&value.to_string())
+}
+
+/// `datetime` 속성값. 문자열이면 형식을 검사합니다.
+///
+/// # Panics
+/// 문자열이 HTML의 날짜, 시각 형식이 아니면 패닉합니다.
+pub fn datetime<T: IntoDatetime + ?Sized>(value: &T) -> Datetime {
+    value.to_datetime()
+}
+
+/// `datetime`에 넣을 수 있는 값 (`Datetime`, 문자열).
+pub trait IntoDatetime {
+    fn to_datetime(&self) -> Datetime;
+}
+
+impl IntoDatetime for Datetime {
+    fn to_datetime(&self) -> Datetime {
+        self.clone()
+    }
+}
+
+impl IntoDatetime for str {
+    fn to_datetime(&self) -> Datetime {
+        Datetime::parse(self).unwrap_or_else(|| panic!("invalid datetime value: {:?}", self))
+    }
+}
+
+impl IntoDatetime for String {
+    fn to_datetime(&self) -> Datetime {
+        self.as_str().to_datetime()
+    }
+}
+
+impl<T: IntoDatetime + ?Sized> IntoDatetime for &T {
+    fn to_datetime(&self) -> Datetime {
+        (**self).to_datetime()
+    }
+}
+
+/// 불리언 속성값 (`required`, `disabled`).
+pub fn flag(value: &bool) -> bool {
+    *value
+}
+
+/// 공백으로 구분한 class 목록.
+pub fn classes<T: Display + ?Sized>(value: &T) -> BTreeSet<AttrValue> {
+    let rule = verbatim_rule();
+    value
+        .to_string()
+        .split_whitespace()
+        .map(|class| AttrValue::from_str(class, &rule))
+        .collect()
+}
+
+fn verbatim_rule() -> rules::Default {
+    rules::Default { rules: vec![] }
+}
@@ -16,7 +16,10 @@
 //! 보안 도구의 책임:
 //!   ✅ XSS 취약점 검사
 //!   ✅ CSP 정책 검증
-//!   ✅ HTML 구조 검증
+//!   ✅ HTML 구조 전체 검증
+//!
+//! 빌드 후 검증 (validate.rs, `Site::validate_html`):
+//!   ✅ 자주 틀리는 중첩, 중복 ID, 랜드마크 구조
 //! ```
 //!
 //! ### 2. Block 계층을 위한 프리미티브 제공
@@ -39,11 +42,16 @@
 //! ```text
 //! html/
 //! ├─ trust.rs          - 신뢰 경계 타입 시스템 (Content, HtmlBlock 등)
+//! ├─ intern.rs         - 빌드 단위 문자열 공유 풀 (Content, AttrValue)
+//! ├─ fragment.rs       - 빌드 단위 공유 조각 캐시 (header, footer, nav의 렌더링 결과)
 //! ├─ rules.rs          - 타이포그래피 정규화 (모호한 문자, 스마트 쿼트)
 //! ├─ attributes.rs     - 타입 안전 HTML 속성 관리
 //! ├─ node.rs           - IRNode 중간 표현
 //! ├─ renderer.rs       - IRNode → HTML 문자열 변환
 //! ├─ elements.rs       - 타입 안전 HTML 요소 (H1, Div, Img 등)
+//! ├─ macros.rs         - `html!` 매크로가 펼친 코드의 보조 함수
+//! ├─ validate.rs       - 렌더링 결과 구조 검사 (중첩, ID, 랜드마크)
+//! ├─ tailwind.rs       - Tailwind 유틸리티 class 조합과 검사 (`tailwind` 기능)
 //! └─ mod.rs            - 모듈 진입점 (이 파일)
 //! ```
 //!
@@ -179,6 +187,18 @@
 //! - 단방향 의존성 (상위 → 하위)
 //! - HTML 계층 재사용 가능
 //!
+//! ### 대상 플랫폼 (WASM)
+//! HTML 계층은 파일 시스템, 스레드, 시계를 쓰지 않습니다. 규칙 데이터도 바이너리에 포함하므로
+//! `wasm32-unknown-unknown`에서 Block/IRNode 렌더링이 그대로 동작합니다 (`examples/wasm_preview.rs`).
+//! `Site`도 `build_to(MemoryFs)`와 `clock(FixedClock(..))`을 쓰면 빌드됩니다. 스레드가 없어 페이지를
+//! 순서대로 처리하고, 시계가 없어 단계별 시간(`BuildTimings`)은 0입니다. 디스크 출력(`build_output`)과
+//! 시스템 시계(`SystemClock`)는 네이티브 전용입니다. CI는 이 대상으로 라이브러리와 예제를 검사합니다.
+//! ```text
+//! cargo check --target wasm32-unknown-unknown --lib --example wasm_preview
+//! cargo build --target wasm32-unknown-unknown --example wasm_preview
+//! wasm-bindgen --target web target/wasm32-unknown-unknown/debug/examples/wasm_preview.wasm --out-dir pkg
+//! ```
+//!
 //! ## 구현 상태
 //!
 //! ### 완성된 모듈
@@ -188,6 +208,8 @@
 //! - [x] node: IRNode 중간 표현, Visitor 패턴
 //! - [x] renderer: 불변 렌더러
 //! - [x] elements: 기본 요소 (H1, H2, Div, Img)
+//! - [x] `wasm32-unknown-unknown` 빌드 (브라우저 미리보기)
+//! - [x] tailwind: 타입 유틸리티 class (`Tw`), class 검사 (`check_class`)
 //!
 //! ### 진행 중
 //! - ⏳ elements: 나머지 HTML5 요소
@@ -216,7 +238,7 @@
 //! ### 성능 vs 단순성
 //! **선택:** 단순성 우선 (현재)
 //! - 불변 렌더러 (디버깅 용이)
-//! - 문자열 복사 허용
+//! - 문자열 복사 허용 (단, 반복되는 속성값/텍스트는 빌드 단위 풀로 공유)
 //! - 실제 병목 확인 후 최적화
 //!
 //! ### 완전성 vs 실용성
@@ -299,8 +321,14 @@
 //! - [Character References](https://html.spec.whatwg.org/multipage/syntax.html#character-references)
 
 pub mod trust;
+pub mod intern;
+pub mod fragment;
 pub mod rules;
 pub mod attributes;
 pub mod renderer;
 pub mod node;
 pub mod elements;
+pub mod macros;
+pub mod validate;
+#[cfg(feature = "tailwind")]
+pub mod tailwind;
@@ -38,8 +38,14 @@
 //!
 //! ### ElementType
 //! HTML5 요소 분류:
-//! - `Void`: 자식을 가질 수 없는 요소 (`<img>`, `<br>`)
-//! - `Normal`: 자식을 가질 수 있는 요소 (`<div>`, `<p>`)
+//! - `Void`: 자식을 가질 수 없는 요소 (`<img>`, `<br>`). `IRNode::void`로만 만듭니다
+//! - `Normal`: 자식을 가질 수 있는 요소 (`<div>`, `<p>`). `IRNode::new`로 만듭니다
+//! - `Fragment`: 태그 없이 자식만 출력 (렌더링할 것이 없는 Block 등). `IRNode::fragment`로 만듭니다
+//!
+//! ### Whitespace
+//! 렌더러가 공백을 바꿔도 되는지 여부:
+//! - `Collapse`: 들여쓰기(`HtmlFormat::Pretty`), 공백 축약(`HtmlFormat::Minified`) 허용
+//! - `Preserve`: 자식까지 원문 그대로 (`pre`, `code`, `textarea`, `script`, `style`의 기본값)
 //!
 //! ## 사용 예시
 //! ```rust
@@ -49,12 +55,14 @@
 //!         IRNode::new(
 //!             TagName::from_str("h1"),
 //!             self.attrs.clone(),
-//!             ElementType::Normal,
 //!             vec![Element::Text(self.content.clone())]
 //!         )
 //!     }
 //! }
 //!
+//! // Void 요소는 자식 인자가 없음
+//! IRNode::void(TagName::from_str("img"), attrs);
+//!
 //! // IRNode를 렌더러로 처리
 //! let renderer = HtmlRenderer::new();
 //! let final_renderer = irnode.accept(renderer);
@@ -64,7 +72,7 @@
 //! ## 구현 상태
 //! - [x] IRNode 코어 구조
 //! - [x] Element enum (Text, Node, Raw)
-//! - [x] ElementType enum (Void, Normal)
+//! - [x] ElementType enum (Void, Normal), Void 노드는 자식 없이만 생성 (`IRNode::void`)
 //! - [x] Visitor 패턴 (`accept` 메서드)
 //! - [x] Content category 트레이트 정의
 //! - [ ] TODO: 모든 Content category 트레이트 구현체 추가
@@ -76,15 +84,18 @@
 //! - `PhrasingContent`: 텍스트 레벨 요소 (span, strong, em, ...)
 //! - `Heading`: 제목 요소 (h1~h6)
 //! - `Sectioning`: 섹션 요소 (article, section, nav, ...)
+//! - `TableContent`, `TrContent`: 표 구조 (table 안의 행 그룹, tr 안의 셀)
+//! - `SelectContent`, `FieldsetContent`: 폼 구조 (select 안의 option, fieldset 안의 legend와 Flow content)
+//! - `MediaContent`: video, audio 안의 source, track과 대체 Phrasing content
 //! - 기타: Embedded, Interactive, MetadataContent 등
 //!
 //! 목적: 타입 시스템으로 HTML 구조 규칙 강제
 //! ```rust
-//! // ✅ p 요소는 FlowContent를 자식으로 가질 수 있음
-//! fn P::new(children: Vec<Box<dyn PhrasingContent>>)
+//! // ✅ p 요소는 Phrasing(텍스트, span, img 등)을 자식으로 가질 수 있음
+//! fn P::new(attrs: Attributes<Global>, childs: Vec<Box<dyn Phrasing>>)
 //!
 //! // ❌ p 요소 안에 div 불가 (컴파일 에러)
-//! // div는 FlowContent지만 PhrasingContent 아님
+//! // div는 FlowContent지만 Phrasing 아님
 //! ```
 //!
 //! ## Visitor 패턴
@@ -157,6 +168,11 @@ use crate::html::trust::TagName;
 /// 모든 HTML 요소와 사용자 정의 Block이 구현해야 합니다.
 pub trait Node {
     fn to_irnode(&self) -> IRNode;
+
+    /// 부모의 자식으로 넣을 때의 형태. 기본: `Element::Node`. 텍스트(`Content`)는 `Element::Text`.
+    fn to_element(&self) -> Element {
+        Element::Node(self.to_irnode())
+    }
 }
 
 /// IRNode의 자식이 될 수 있는 타입.
@@ -167,11 +183,22 @@ pub enum Element {
     Raw(HtmlBlock),     // 신뢰된 HTML (이스케이프 없음)
 }
 
+/// 요소 안 공백 처리. 기본값은 태그명으로 정해지고(`TagName::whitespace`), Block이 바꿀 수 있습니다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Whitespace {
+    /// 렌더러가 공백을 정리해도 내용이 바뀌지 않음
+    #[default]
+    Collapse,
+    /// 공백이 내용 (`white-space: pre` 등). 렌더러가 자식까지 손대지 않음
+    Preserve,
+}
+
 /// HTML 요소 타입. HTML5 명세에 따른 분류.
 #[derive(Debug, Clone)]
 pub enum ElementType {
     Void,    // 자식 불가능: <img>, <br>, <hr>, <input> 등
     Normal,  // 자식 가능: <div>, <p>, <span> 등
+    Fragment, // 태그 없음: 자식만 출력
 }
 
 /// HTML 요소의 중간 표현. 모든 Block은 최종적으로 IRNode로 변환됩니다.
@@ -181,23 +208,65 @@ pub struct IRNode {
     attrs: SharedAttrs,
     tagtype: ElementType,
     childs: Vec<Element>,
+    whitespace: Whitespace,
 }
 
 impl IRNode {
-    pub fn new(
-        tag: TagName,
-        attrs: SharedAttrs,
-        tagtype: ElementType,
-        childs: Vec<Element>,
-    ) -> Self {
+    /// 자식을 가질 수 있는 요소 (`ElementType::Normal`).
+    pub fn new(tag: TagName, attrs: SharedAttrs, childs: Vec<Element>) -> Self {
         IRNode {
+            whitespace: tag.whitespace(),
             tag,
             attrs,
-            tagtype,
+            tagtype: ElementType::Normal,
+            childs,
+        }
+    }
+
+    /// Void 요소 (`ElementType::Void`). 자식을 받는 생성자가 없으므로 자식이 있는 Void 노드는 만들 수 없습니다.
+    pub fn void(tag: TagName, attrs: SharedAttrs) -> Self {
+        IRNode {
+            whitespace: tag.whitespace(),
+            tag,
+            attrs,
+            tagtype: ElementType::Void,
+            childs: Vec::new(),
+        }
+    }
+
+    /// 태그 없이 자식만 출력하는 조각 (`ElementType::Fragment`). 자식이 없으면 아무것도 출력하지 않습니다.
+    pub fn fragment(childs: Vec<Element>) -> Self {
+        IRNode {
+            tag: TagName::from_str(""),
+            attrs: SharedAttrs::new(),
+            tagtype: ElementType::Fragment,
+            childs,
+            whitespace: Whitespace::Collapse,
+        }
+    }
+
+    /// 태그 없는 조각인지.
+    pub fn is_fragment(&self) -> bool {
+        matches!(self.tagtype, ElementType::Fragment)
+    }
+
+    /// 태그, 속성, 종류, 공백 처리는 그대로 두고 자식만 바꾼 노드. IR 트리를 고쳐 쓰는 단계용.
+    pub fn with_childs(&self, childs: Vec<Element>) -> Self {
+        IRNode {
+            tag: self.tag.clone(),
+            attrs: self.attrs.clone(),
+            tagtype: self.tagtype.clone(),
             childs,
+            whitespace: self.whitespace,
         }
     }
 
+    /// 공백 처리 지정. `white-space: pre`를 적용한 요소처럼 태그만으로 알 수 없을 때 사용.
+    pub fn with_whitespace(mut self, whitespace: Whitespace) -> Self {
+        self.whitespace = whitespace;
+        self
+    }
+
     pub fn get_tag(&self) -> &TagName {
         &self.tag
     }
@@ -210,24 +279,42 @@ impl IRNode {
         &self.tagtype
     }
 
+    pub fn get_childs(&self) -> &[Element] {
+        &self.childs
+    }
+
+    pub fn get_whitespace(&self) -> Whitespace {
+        self.whitespace
+    }
+
     /// Visitor 패턴: 렌더러가 이 노드와 자식들을 순회하도록 합니다.
     ///
     /// 순회 순서:
     /// 1. visit_node_begin (여는 태그)
     /// 2. 자식들 재귀 순회
     /// 3. visit_node_end (닫는 태그)
+    ///
+    /// 렌더러가 `visit_subtree`로 하위 트리를 한 번에 처리하면 순회하지 않습니다.
+    /// 조각(`ElementType::Fragment`)은 1, 3 없이 자식만 순회합니다.
     pub fn accept<R: Renderer>(&self, renderer: R) -> R {
+        if self.is_fragment() {
+            return self.accept_childs(renderer);
+        }
+        if let Some(renderer) = renderer.visit_subtree(self) {
+            return renderer;
+        }
         let renderer_after_begin = renderer.visit_node_begin(self);
-        let renderer_after_children = self.childs.iter().fold(
-            renderer_after_begin,
-            |current_renderer, child| match child {
-                Element::Text(content) => current_renderer.visit_text(&content),
+        self.accept_childs(renderer_after_begin).visit_node_end(self)
+    }
+
+    fn accept_childs<R: Renderer>(&self, renderer: R) -> R {
+        self.childs
+            .iter()
+            .fold(renderer, |current_renderer, child| match child {
+                Element::Text(content) => current_renderer.visit_text(content),
                 Element::Node(irnode) => irnode.accept(current_renderer),
-                Element::Raw(html_block) => current_renderer.visit_raw(&html_block),
-            },
-        );
-        let final_renderer = renderer_after_children.visit_node_end(self);
-        final_renderer
+                Element::Raw(html_block) => current_renderer.visit_raw(html_block),
+            })
     }
 }
 
@@ -284,9 +371,31 @@ pub trait Transparentcontent: Node {}
 /// li (ordered list 컨텍스트)
 pub trait OlContent: Node {}
 
+/// Table content: table의 자식으로만 사용 가능
+/// caption, colgroup, thead, tbody, tfoot, tr
+pub trait TableContent: Node {}
+
+/// Tr content: tr의 자식으로만 사용 가능
+/// th, td
+pub trait TrContent: Node {}
+
+/// Select content: select의 자식으로만 사용 가능
+/// option
+pub trait SelectContent: Node {}
+
+/// Fieldset content: fieldset의 자식
+/// legend + 모든 Flow content
+pub trait FieldsetContent: Node {}
+
+impl<T: FlowContent> FieldsetContent for T {}
+
+/// Media content: video, audio의 자식
+/// source, track + 대체 콘텐츠 (Phrasing)
+pub trait MediaContent: Node {}
+
+impl<T: Phrasing> MediaContent for T {}
+
 // TODO: 추가 카테고리
-// - TableContent (thead, tbody, tfoot, tr)
-// - TrContent (th, td)
 // - UlContent (li in unordered list)
 // - DlContent (dt, dd)
-// - SelectContent (option, optgroup)
+// - SelectContent: optgroup
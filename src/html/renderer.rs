@@ -2,6 +2,10 @@
 //!
 //! ## 목적
 //! IRNode 트리를 순회하며 최종 HTML 문자열을 생성합니다.
+//! - `HtmlRenderer`: HTML 문자열 (`HtmlFormat`: 그대로, 들여쓰기, 공백 축약)
+//! - `TextRenderer`: 태그를 뺀 일반 텍스트 (피드 요약, 검색 인덱스)
+//! - `JsonRenderer`: 노드 트리를 그대로 옮긴 JSON (콘텐츠 API, `page.json`)
+//! - `MarkdownRenderer`: Markdown 역변환 (LLM용 텍스트 미러, `page.md`)
 //!
 //! ## 핵심 개념
 //!
@@ -68,6 +72,7 @@
 //! - `visit_node_end`: 노드 종료 (닫는 태그)
 //! - `visit_text`: 텍스트 노드
 //! - `visit_raw`: 신뢰된 HTML 블록
+//! - `visit_subtree`: 하위 트리를 한 번에 처리 (선택, 기본은 `None` → 평소처럼 순회)
 //! - `finalize`: 최종 결과 반환
 //!
 //! ## HtmlRenderer 구현
@@ -108,6 +113,28 @@
 //! buffer += html_block.as_str();
 //! ```
 //!
+//! ### 출력 형식 (`HtmlFormat`)
+//! ```rust
+//! HtmlRenderer::new();                                  // Compact: 공백을 바꾸지 않음
+//! HtmlRenderer::with_format(HtmlFormat::Pretty);        // 블록 요소마다 줄바꿈 + 들여쓰기
+//! HtmlRenderer::with_format(HtmlFormat::Minified);      // 텍스트의 연속 공백을 하나로
+//! ```
+//! `pre`, `code`, `textarea`, `script`, `style`처럼 공백이 내용인 요소(`Whitespace::Preserve`)는
+//! 형식과 무관하게 자식까지 원문 그대로 출력합니다. 들여쓰기는 블록 요소 앞에만 넣고
+//! 인라인 요소 사이에는 넣지 않으므로 표시되는 공백이 달라지지 않습니다.
+//!
+//! ### 문서 형식 선언
+//! 출력 맨 앞에 오는 `html` 요소(문서 루트)에는 `<!DOCTYPE html>`을 먼저 씁니다 (`Pretty`면 뒤에 줄바꿈).
+//! `elements::Html`이나 `document_ir`의 결과를 렌더링하면 그대로 완전한 문서가 됩니다.
+//! 다른 요소 안의 `html`이나 조각에는 붙이지 않습니다.
+//!
+//! ### 공유 조각 캐시 (`FragmentCache`)
+//! ```rust
+//! HtmlRenderer::new().with_fragments(cache.clone());
+//! ```
+//! header, footer, nav는 하위 트리 지문으로 캐시를 찾아 이미 렌더링한 문자열을 이어 붙입니다
+//! (`visit_subtree`). 자세한 내용은 `fragment.rs`.
+//!
 //! ## 설계 결정
 //!
 //! ### 왜 불변 패턴인가?
@@ -166,22 +193,15 @@
 //!
 //! ## 확장 가능성
 //!
-//! ### 다른 렌더러 구현 예시
+//! ### 다른 렌더러
+//! `Renderer`만 구현하면 같은 IRNode 트리를 다른 형식으로 내보낼 수 있습니다.
+//! 페이지의 추가 출력 형식(`OutputFormats`)이 아래 렌더러를 씁니다.
 //! ```rust
-//! // JSON 렌더러 (디버깅용)
-//! pub struct JsonRenderer {
-//!     json: serde_json::Value,
-//! }
-//!
-//! impl Renderer for JsonRenderer {
-//!     type Output = String;
-//!     // IRNode 트리를 JSON으로 변환
-//! }
+//! let tree = irnode.accept(JsonRenderer::new()).finalize().clone();
+//! // → [{"tag": "div", "attrs": {"class": "container"}, "children": ["Hello ", {...}]}]
 //!
-//! // Markdown 렌더러 (HTML → Markdown 역변환)
-//! pub struct MarkdownRenderer {
-//!     markdown: String,
-//! }
+//! let markdown = irnode.accept(MarkdownRenderer::new()).finalize().clone();
+//! // → "Hello **World**"
 //! ```
 //!
 //! ## 성능 고려사항
@@ -198,13 +218,17 @@
 //! 2. 메모리 할당이 문제인가?
 //!    → 버퍼 재사용
 //! 3. 전체 빌드 시간이 느린가?
-//!    → 병렬 렌더링
+//!    → 병렬 렌더링 (구현됨: `Site::jobs`, 페이지 단위로 스레드에 분배)
 //!
 //! **목표:** 1000 페이지 사이트를 10초 이내 빌드
 //!
 
-use crate::html::node::{ElementType, IRNode};
-use crate::html::trust::{Content, HtmlBlock, SafeString};
+use std::sync::Arc;
+
+use crate::html::attributes::AttrValues;
+use crate::html::fragment::{FragmentCache, FragmentKey};
+use crate::html::node::{Element, ElementType, IRNode, Whitespace};
+use crate::html::trust::{AttrKey, Content, HtmlBlock, SafeString, unescape_html_chars};
 
 /// 렌더러 인터페이스. 모든 렌더러가 구현해야 합니다.
 ///
@@ -225,48 +249,140 @@ pub trait Renderer: Clone {
     /// 신뢰된 HTML 블록 방문 시 호출
     fn visit_raw(&self, html: &HtmlBlock) -> Self;
 
+    /// 노드 방문 전에 호출. 하위 트리 전체를 처리했으면 결과를 반환하고, `None`이면 평소처럼
+    /// begin → 자식 → end 순서로 순회합니다 (조각 캐시 등).
+    fn visit_subtree(&self, _node: &IRNode) -> Option<Self> {
+        None
+    }
+
     /// 최종 결과 반환
     fn finalize(&self) -> &Self::Output;
 }
 
+/// HTML 출력 형식. 공백을 바꾸는 형식도 `Whitespace::Preserve` 요소 안은 원문 그대로 둡니다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum HtmlFormat {
+    /// 공백을 더하거나 빼지 않음
+    #[default]
+    Compact,
+    /// 블록 요소마다 줄바꿈과 들여쓰기 (읽기, 디버깅용)
+    Pretty,
+    /// 텍스트의 연속 공백을 하나로 축약
+    Minified,
+}
+
+/// 문서 루트 `html` 앞에 붙이는 형식 선언.
+pub const DOCTYPE: &str = "<!DOCTYPE html>";
+
+/// 들여쓰기 단위 (`HtmlFormat::Pretty`).
+const INDENT: &str = "  ";
+
+/// 앞에서 줄을 바꿔도 표시가 바뀌지 않는 요소 (`HtmlFormat::Pretty`).
+const LAYOUT_TAGS: &[&str] = &[
+    "body", "caption", "fieldset", "form", "head", "html", "legend", "link", "meta", "script",
+    "style", "tbody", "td", "template", "tfoot", "th", "thead", "title",
+];
+
 /// HTML 문자열 렌더러. IRNode → HTML 변환.
 #[derive(Clone)]
 pub struct HtmlRenderer {
     buffer: HtmlBlock,
+    format: HtmlFormat,
+    /// 열린 블록 요소 수 (들여쓰기 단계)
+    depth: usize,
+    /// 열린 `Whitespace::Preserve` 요소 수. 0보다 크면 공백을 바꾸지 않음
+    preserve_depth: usize,
+    /// 공유 조각 캐시 (빌드 단위)
+    fragments: Option<Arc<FragmentCache>>,
 }
 
 impl HtmlRenderer {
     pub fn new() -> Self {
+        Self::with_format(HtmlFormat::Compact)
+    }
+
+    pub fn with_format(format: HtmlFormat) -> Self {
         HtmlRenderer {
-            buffer: HtmlBlock::from_str(&String::new()),
+            buffer: HtmlBlock::from_str(""),
+            format,
+            depth: 0,
+            preserve_depth: 0,
+            fragments: None,
+        }
+    }
+
+    /// 공유 조각(header, footer, nav)을 캐시에서 찾아 이어 붙이는 렌더러.
+    pub fn with_fragments(mut self, cache: Arc<FragmentCache>) -> Self {
+        self.fragments = Some(cache);
+        self
+    }
+
+    fn is_layout(node: &IRNode) -> bool {
+        let tag = node.get_tag().as_str();
+        BLOCK_TAGS.contains(&tag) || LAYOUT_TAGS.contains(&tag)
+    }
+
+    /// 공백을 보존하는 요소 밖에서 줄바꿈 + 들여쓰기 (`Pretty`만).
+    fn newline(&self, buffer: &mut String, depth: usize) {
+        if self.format == HtmlFormat::Pretty && self.preserve_depth == 0 && !buffer.is_empty() {
+            buffer.push('\n');
+            buffer.push_str(&INDENT.repeat(depth));
         }
     }
 }
 
+impl Default for HtmlRenderer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Renderer for HtmlRenderer {
     type Output = HtmlBlock;
 
     /// 여는 태그 생성
     /// Normal: `<tag attr="val">`
     /// Void: `<tag attr="val" >` (공백 추가)
+    /// 문서 맨 앞의 `html`: `<!DOCTYPE html><html>`
     fn visit_node_begin(&self, node: &IRNode) -> Self {
         let mut buffer = self.buffer.as_str().to_string();
+        if buffer.is_empty() && node.get_tag().as_str() == "html" {
+            buffer.push_str(DOCTYPE);
+        }
+        let layout = Self::is_layout(node);
+        if layout {
+            self.newline(&mut buffer, self.depth);
+        }
 
         buffer.push('<');
-        buffer.push_str(&node.get_tag().as_str());
+        buffer.push_str(node.get_tag().as_str());
         buffer.push_str(&node.get_attrs().into_string());
 
+        let mut depth = self.depth;
+        let mut preserve_depth = self.preserve_depth;
         match node.get_type() {
             ElementType::Void => {
                 buffer.push_str(" >");  // Void: 공백 추가
             }
+            ElementType::Fragment => {
+                // 조각은 태그 없음 (`IRNode::accept`가 호출하지 않음)
+            }
             ElementType::Normal => {
                 buffer.push('>');
+                if layout {
+                    depth += 1;
+                }
+                if preserve_depth > 0 || node.get_whitespace() == Whitespace::Preserve {
+                    preserve_depth += 1;
+                }
             }
         }
 
         HtmlRenderer {
             buffer: HtmlBlock::from_str(&buffer),
+            depth,
+            preserve_depth,
+            ..self.clone()
         }
     }
 
@@ -275,42 +391,103 @@ impl Renderer for HtmlRenderer {
     /// Void: (아무것도 하지 않음)
     fn visit_node_end(&self, node: &IRNode) -> Self {
         let mut buffer = self.buffer.as_str().to_string();
+        let mut depth = self.depth;
+        let mut preserve_depth = self.preserve_depth;
 
         match node.get_type() {
             ElementType::Normal => {
+                if Self::is_layout(node) {
+                    depth = depth.saturating_sub(1);
+                    // 블록 자식이 있을 때만 닫는 태그를 다음 줄로 (`<p>text</p>`는 한 줄)
+                    let block_child = node
+                        .get_childs()
+                        .iter()
+                        .any(|child| matches!(child, Element::Node(child) if Self::is_layout(child)));
+                    if block_child {
+                        self.newline(&mut buffer, depth);
+                    }
+                }
                 buffer.push_str("</");
-                buffer.push_str(&node.get_tag().as_str());
+                buffer.push_str(node.get_tag().as_str());
                 buffer.push('>');
+                preserve_depth = preserve_depth.saturating_sub(1);
             }
-            ElementType::Void => {
-                // Void 요소는 닫는 태그 없음
+            ElementType::Void | ElementType::Fragment => {
+                // Void 요소와 조각은 닫는 태그 없음
             }
         }
 
         HtmlRenderer {
             buffer: HtmlBlock::from_str(&buffer),
+            depth,
+            preserve_depth,
+            ..self.clone()
         }
     }
 
     /// 텍스트 노드 추가
-    /// Content는 이미 이스케이프되어 있음
+    /// Content는 이미 이스케이프되어 있음. `Minified`면 공백을 보존하는 요소 밖에서 연속 공백 축약
     fn visit_text(&self, content: &Content) -> Self {
         let mut buffer = self.buffer.as_str().to_string();
-        buffer.push_str(&content.as_str());
+        if self.format == HtmlFormat::Minified && self.preserve_depth == 0 {
+            buffer.push_str(&collapse_whitespace(content.as_str()));
+        } else {
+            buffer.push_str(content.as_str());
+        }
 
         HtmlRenderer {
             buffer: HtmlBlock::from_str(&buffer),
+            ..self.clone()
         }
     }
 
     /// 신뢰된 HTML 블록 추가
-    /// HtmlBlock은 이스케이프하지 않고 그대로 사용
+    /// HtmlBlock은 이스케이프하지 않고 형식과 무관하게 그대로 사용
     fn visit_raw(&self, html: &HtmlBlock) -> Self {
         let mut buffer = self.buffer.as_str().to_string();
-        buffer.push_str(&html.as_str());
+        buffer.push_str(html.as_str());
 
         HtmlRenderer {
             buffer: HtmlBlock::from_str(&buffer),
+            ..self.clone()
+        }
+    }
+
+    /// 공유 조각이면 캐시한 문자열을 이어 붙임. 캐시에 없으면 평소처럼 렌더링하고 그 부분을 저장
+    fn visit_subtree(&self, node: &IRNode) -> Option<Self> {
+        let cache = self.fragments.as_ref()?;
+        if !FragmentCache::is_fragment(node) {
+            return None;
+        }
+        let key = FragmentKey::new(
+            node,
+            self.format,
+            self.depth,
+            self.preserve_depth > 0,
+            self.buffer.as_str().is_empty(),
+        );
+        match cache.get(&key) {
+            Some(html) => {
+                let mut buffer = self.buffer.as_str().to_string();
+                buffer.push_str(&html);
+                Some(HtmlRenderer {
+                    buffer: HtmlBlock::from_str(&buffer),
+                    ..self.clone()
+                })
+            }
+            None => {
+                // 조각 안의 조각은 따로 찾지 않음
+                let start = self.buffer.as_str().len();
+                let rendered = node.accept(HtmlRenderer {
+                    fragments: None,
+                    ..self.clone()
+                });
+                cache.insert(key, &rendered.buffer.as_str()[start..]);
+                Some(HtmlRenderer {
+                    fragments: self.fragments.clone(),
+                    ..rendered
+                })
+            }
         }
     }
 
@@ -319,3 +496,537 @@ impl Renderer for HtmlRenderer {
         &self.buffer
     }
 }
+
+/// 연속된 공백 문자를 공백 하나로.
+fn collapse_whitespace(text: &str) -> String {
+    let mut collapsed = String::with_capacity(text.len());
+    let mut in_space = false;
+    for c in text.chars() {
+        if c.is_ascii_whitespace() {
+            if !in_space {
+                collapsed.push(' ');
+            }
+            in_space = true;
+        } else {
+            collapsed.push(c);
+            in_space = false;
+        }
+    }
+    collapsed
+}
+
+/// 내용을 건너뛰는 요소 (텍스트로 읽을 내용이 아님).
+const SKIPPED_TAGS: &[&str] = &["script", "style", "template", "noscript"];
+
+/// 앞뒤로 줄을 바꾸는 블록 요소.
+const BLOCK_TAGS: &[&str] = &[
+    "address", "article", "aside", "blockquote", "dd", "details", "dialog", "div", "dl",
+    "dt", "figcaption", "figure", "footer", "h1", "h2", "h3", "h4", "h5", "h6", "header", "hr",
+    "li", "main", "nav", "ol", "p", "pre", "section", "summary", "table", "tr", "ul",
+];
+
+/// 일반 텍스트 렌더러. IRNode → 태그와 엔티티를 뺀 텍스트 (피드 요약, 검색 인덱스 등).
+/// 블록 요소 경계는 줄바꿈으로 바꾸고, `script`/`style` 안의 내용은 건너뜁니다.
+#[derive(Clone, Default)]
+pub struct TextRenderer {
+    buffer: String,
+    skip_depth: usize,
+}
+
+impl TextRenderer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn line_break(mut self) -> Self {
+        if !self.buffer.is_empty() && !self.buffer.ends_with('\n') {
+            self.buffer.push('\n');
+        }
+        self
+    }
+}
+
+impl Renderer for TextRenderer {
+    type Output = String;
+
+    fn visit_node_begin(&self, node: &IRNode) -> Self {
+        let tag = node.get_tag().as_str();
+        let next = self.clone();
+        if SKIPPED_TAGS.contains(&tag) {
+            return TextRenderer {
+                skip_depth: next.skip_depth + 1,
+                ..next
+            };
+        }
+        if next.skip_depth == 0 && (tag == "br" || BLOCK_TAGS.contains(&tag)) {
+            return next.line_break();
+        }
+        next
+    }
+
+    fn visit_node_end(&self, node: &IRNode) -> Self {
+        let tag = node.get_tag().as_str();
+        let next = self.clone();
+        if SKIPPED_TAGS.contains(&tag) {
+            return TextRenderer {
+                skip_depth: next.skip_depth.saturating_sub(1),
+                ..next
+            };
+        }
+        if next.skip_depth == 0 && BLOCK_TAGS.contains(&tag) {
+            return next.line_break();
+        }
+        next
+    }
+
+    fn visit_text(&self, content: &Content) -> Self {
+        let mut next = self.clone();
+        if next.skip_depth == 0 {
+            next.buffer.push_str(&unescape_html_chars(content.as_str()));
+        }
+        next
+    }
+
+    /// 신뢰된 HTML은 태그를 걷어내고 텍스트만 추가
+    fn visit_raw(&self, html: &HtmlBlock) -> Self {
+        let mut next = self.clone();
+        if next.skip_depth == 0 {
+            next.buffer.push_str(&unescape_html_chars(&strip_tags(html.as_str())));
+        }
+        next
+    }
+
+    fn finalize(&self) -> &Self::Output {
+        &self.buffer
+    }
+}
+
+/// `<...>` 구간 제거.
+fn strip_tags(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text
+}
+
+/// JSON 트리 렌더러. IRNode → `[{"tag", "attrs", "children"}]` (콘텐츠 API, 디버깅).
+/// 텍스트는 엔티티를 푼 문자열, 신뢰된 HTML은 `{"html": "..."}`로 담습니다.
+#[derive(Clone)]
+pub struct JsonRenderer {
+    /// 열려 있는 노드 (가장 안쪽이 마지막)
+    open: Vec<serde_json::Value>,
+    output: serde_json::Value,
+}
+
+impl JsonRenderer {
+    pub fn new() -> Self {
+        JsonRenderer {
+            open: Vec::new(),
+            output: serde_json::Value::Array(Vec::new()),
+        }
+    }
+
+    /// 열린 노드의 자식, 열린 노드가 없으면 최상위 목록에 추가.
+    fn push(mut self, value: serde_json::Value) -> Self {
+        let target = match self.open.last_mut() {
+            Some(node) => &mut node["children"],
+            None => &mut self.output,
+        };
+        if let serde_json::Value::Array(items) = target {
+            items.push(value);
+        }
+        self
+    }
+}
+
+impl Default for JsonRenderer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Renderer for JsonRenderer {
+    type Output = serde_json::Value;
+
+    fn visit_node_begin(&self, node: &IRNode) -> Self {
+        let attrs: serde_json::Map<String, serde_json::Value> = node
+            .get_attrs()
+            .get()
+            .all()
+            .into_iter()
+            .filter_map(|(key, value)| {
+                let value = match value {
+                    AttrValues::Token(v) => serde_json::Value::String(unescape_html_chars(&v.to_string())),
+                    AttrValues::Bool(true) => serde_json::Value::Bool(true),
+                    AttrValues::Bool(false) => return None,
+                    AttrValues::Set(values) => serde_json::Value::String(
+                        values.iter().map(|v| unescape_html_chars(&v.to_string())).collect::<Vec<_>>().join(" "),
+                    ),
+                    AttrValues::List(values) => serde_json::Value::Array(
+                        values.iter().map(|v| unescape_html_chars(&v.to_string()).into()).collect(),
+                    ),
+                };
+                Some((key.as_str().to_string(), value))
+            })
+            .collect();
+        let mut next = self.clone();
+        next.open.push(serde_json::json!({
+            "tag": node.get_tag().as_str(),
+            "attrs": attrs,
+            "children": [],
+        }));
+        next
+    }
+
+    fn visit_node_end(&self, _node: &IRNode) -> Self {
+        let mut next = self.clone();
+        match next.open.pop() {
+            Some(node) => next.push(node),
+            None => next,
+        }
+    }
+
+    fn visit_text(&self, content: &Content) -> Self {
+        self.clone().push(unescape_html_chars(content.as_str()).into())
+    }
+
+    fn visit_raw(&self, html: &HtmlBlock) -> Self {
+        self.clone().push(serde_json::json!({ "html": html.as_str() }))
+    }
+
+    fn finalize(&self) -> &Self::Output {
+        &self.output
+    }
+}
+
+/// 내용을 건너뛰는 요소 (Markdown). 아이콘 SVG도 읽을 내용이 아님.
+const MARKDOWN_SKIPPED_TAGS: &[&str] = &["script", "style", "template", "noscript", "svg"];
+
+/// Markdown 렌더러. IRNode → CommonMark (LLM용 텍스트 미러, `page.md`).
+/// 제목, 문단, 강조, 링크, 이미지, 목록, 인용, 코드, 표를 옮기고 그 밖의 요소는 내용만 남깁니다.
+#[derive(Clone, Default)]
+pub struct MarkdownRenderer {
+    buffer: String,
+    /// 줄 앞에 붙는 접두사 (인용 `> `, 목록 항목 들여쓰기)
+    prefixes: Vec<String>,
+    /// 열린 목록. 번호 목록이면 다음 번호
+    lists: Vec<Option<usize>>,
+    /// 열린 링크의 주소 (주소 없는 `a`는 `None`)
+    links: Vec<Option<String>>,
+    /// 목록 표시(`- `) 바로 뒤: 다음 블록이 같은 줄에서 시작
+    item_start: bool,
+    /// 표의 행 수, 현재 행의 셀 수
+    table_rows: usize,
+    row_cells: usize,
+    pre_depth: usize,
+    code_depth: usize,
+    skip_depth: usize,
+}
+
+impl MarkdownRenderer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn prefix(&self) -> String {
+        self.prefixes.concat()
+    }
+
+    fn at_line_start(&self) -> bool {
+        self.buffer.is_empty() || self.buffer.ends_with('\n')
+    }
+
+    /// 줄 시작이면 접두사를 붙여 추가.
+    fn write(&mut self, text: &str) {
+        for c in text.chars() {
+            if c != '\n' && self.at_line_start() {
+                let prefix = self.prefix();
+                self.buffer.push_str(&prefix);
+            }
+            self.buffer.push(c);
+        }
+        if !text.is_empty() {
+            self.item_start = false;
+        }
+    }
+
+    fn line_break(&mut self) {
+        if !self.item_start && !self.at_line_start() {
+            self.buffer.push('\n');
+        }
+    }
+
+    /// 블록 사이 빈 줄. 인용 안에서는 빈 줄에도 `>`를 붙여 인용이 끊기지 않게 합니다.
+    fn block_break(&mut self) {
+        if self.item_start || self.buffer.is_empty() {
+            return;
+        }
+        self.line_break();
+        let blank = self.prefix().trim_end().to_string();
+        let last_line = self.buffer[..self.buffer.len() - 1]
+            .rsplit('\n')
+            .next()
+            .unwrap_or("");
+        if last_line.trim_end() != blank && self.buffer.len() > 1 {
+            self.buffer.push_str(&blank);
+            self.buffer.push('\n');
+        }
+    }
+
+    fn heading_level(tag: &str) -> Option<usize> {
+        match tag {
+            "h1" => Some(1),
+            "h2" => Some(2),
+            "h3" => Some(3),
+            "h4" => Some(4),
+            "h5" => Some(5),
+            "h6" => Some(6),
+            _ => None,
+        }
+    }
+
+    /// `pre > code.language-x`의 언어.
+    fn code_language(node: &IRNode) -> Option<String> {
+        node.get_childs().iter().find_map(|child| match child {
+            Element::Node(code) if code.get_tag().as_str() == "code" => {
+                match code.get_attrs().get().get(&AttrKey::from_str("class"))? {
+                    AttrValues::Set(classes) => classes
+                        .iter()
+                        .find_map(|c| c.to_string().strip_prefix("language-").map(str::to_string)),
+                    AttrValues::Token(class) => class
+                        .to_string()
+                        .split_whitespace()
+                        .find_map(|c| c.strip_prefix("language-").map(str::to_string)),
+                    _ => None,
+                }
+            }
+            _ => None,
+        })
+    }
+
+    fn attr(node: &IRNode, key: &str) -> Option<String> {
+        match node.get_attrs().get().get(&AttrKey::from_str(key))? {
+            AttrValues::Token(value) => Some(unescape_html_chars(&value.to_string())),
+            _ => None,
+        }
+    }
+}
+
+/// Markdown 문법 문자 이스케이프.
+fn escape_markdown(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(c, '\\' | '*' | '_' | '`' | '[' | ']' | '<' | '|') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+impl Renderer for MarkdownRenderer {
+    type Output = String;
+
+    fn visit_node_begin(&self, node: &IRNode) -> Self {
+        let tag = node.get_tag().as_str();
+        let mut next = self.clone();
+        if MARKDOWN_SKIPPED_TAGS.contains(&tag) || next.skip_depth > 0 {
+            if MARKDOWN_SKIPPED_TAGS.contains(&tag) {
+                next.skip_depth += 1;
+            }
+            return next;
+        }
+        if let Some(level) = Self::heading_level(tag) {
+            next.block_break();
+            next.write(&format!("{} ", "#".repeat(level)));
+            return next;
+        }
+        match tag {
+            "p" | "div" | "section" | "article" | "header" | "footer" | "main" | "nav" | "aside"
+            | "figure" | "figcaption" | "details" | "summary" | "dl" | "dt" | "address" => next.block_break(),
+            "dd" => {
+                next.line_break();
+                next.write(": ");
+            }
+            "blockquote" => {
+                next.block_break();
+                next.prefixes.push("> ".to_string());
+            }
+            "ul" | "ol" => {
+                if next.lists.is_empty() {
+                    next.block_break();
+                } else {
+                    next.line_break();
+                }
+                let start = Self::attr(node, "start").and_then(|s| s.parse().ok()).unwrap_or(1);
+                next.lists.push((tag == "ol").then_some(start));
+            }
+            "li" => {
+                next.line_break();
+                let marker = match next.lists.last_mut() {
+                    Some(Some(n)) => {
+                        *n += 1;
+                        format!("{}. ", *n - 1)
+                    }
+                    _ => "- ".to_string(),
+                };
+                next.write(&marker);
+                next.prefixes.push(" ".repeat(marker.len()));
+                next.item_start = true;
+            }
+            "pre" => {
+                next.block_break();
+                let language = Self::code_language(node).unwrap_or_default();
+                next.write(&format!("```{}\n", language));
+                next.pre_depth += 1;
+            }
+            "code" if next.pre_depth == 0 => {
+                next.write("`");
+                next.code_depth += 1;
+            }
+            "strong" | "b" => next.write("**"),
+            "em" | "i" => next.write("*"),
+            "del" | "s" => next.write("~~"),
+            "a" => {
+                let href = Self::attr(node, "href");
+                if href.is_some() {
+                    next.write("[");
+                }
+                next.links.push(href);
+            }
+            "img" => {
+                let alt = Self::attr(node, "alt").unwrap_or_default();
+                let src = Self::attr(node, "src").unwrap_or_default();
+                next.write(&format!("![{}]({})", escape_markdown(&alt), src));
+            }
+            "br" => {
+                next.write("  \n");
+            }
+            "hr" => {
+                next.block_break();
+                next.write("---\n");
+            }
+            "table" => {
+                next.block_break();
+                next.table_rows = 0;
+            }
+            "tr" => {
+                next.line_break();
+                next.row_cells = 0;
+                next.write("|");
+            }
+            "th" | "td" => {
+                next.row_cells += 1;
+                next.write(" ");
+            }
+            _ => {}
+        }
+        next
+    }
+
+    fn visit_node_end(&self, node: &IRNode) -> Self {
+        let tag = node.get_tag().as_str();
+        let mut next = self.clone();
+        if next.skip_depth > 0 {
+            if MARKDOWN_SKIPPED_TAGS.contains(&tag) {
+                next.skip_depth -= 1;
+            }
+            return next;
+        }
+        if Self::heading_level(tag).is_some() {
+            next.line_break();
+            return next;
+        }
+        match tag {
+            "p" | "div" | "section" | "article" | "header" | "footer" | "main" | "nav" | "aside"
+            | "figure" | "figcaption" | "details" | "summary" | "dl" | "dt" | "dd" | "address" => {
+                next.line_break()
+            }
+            "blockquote" => {
+                next.line_break();
+                next.prefixes.pop();
+            }
+            "ul" | "ol" => {
+                next.line_break();
+                next.lists.pop();
+            }
+            "li" => {
+                next.item_start = false;
+                next.line_break();
+                next.prefixes.pop();
+            }
+            "pre" => {
+                next.pre_depth = next.pre_depth.saturating_sub(1);
+                next.line_break();
+                next.write("```\n");
+            }
+            "code" if next.pre_depth == 0 => {
+                next.code_depth = next.code_depth.saturating_sub(1);
+                next.write("`");
+            }
+            "strong" | "b" => next.write("**"),
+            "em" | "i" => next.write("*"),
+            "del" | "s" => next.write("~~"),
+            "a" => {
+                if let Some(Some(href)) = next.links.pop() {
+                    next.write(&format!("]({})", href));
+                }
+            }
+            "th" | "td" => next.write(" |"),
+            "tr" => {
+                next.line_break();
+                next.table_rows += 1;
+                if next.table_rows == 1 {
+                    let separator = format!("|{}\n", " --- |".repeat(next.row_cells));
+                    next.write(&separator);
+                }
+            }
+            _ => {}
+        }
+        next
+    }
+
+    fn visit_text(&self, content: &Content) -> Self {
+        let mut next = self.clone();
+        if next.skip_depth > 0 {
+            return next;
+        }
+        let text = unescape_html_chars(content.as_str());
+        if next.pre_depth > 0 {
+            next.write(&text);
+            return next;
+        }
+        let mut collapsed = text.split_whitespace().collect::<Vec<_>>().join(" ");
+        if text.starts_with(char::is_whitespace) && !next.at_line_start() && !next.item_start {
+            collapsed.insert(0, ' ');
+        }
+        if text.ends_with(char::is_whitespace) && !collapsed.is_empty() && !collapsed.ends_with(' ') {
+            collapsed.push(' ');
+        }
+        if next.code_depth == 0 {
+            collapsed = escape_markdown(&collapsed);
+        }
+        next.write(&collapsed);
+        next
+    }
+
+    /// 신뢰된 HTML은 Markdown 안의 HTML로 그대로 둡니다 (수식 등).
+    fn visit_raw(&self, html: &HtmlBlock) -> Self {
+        let mut next = self.clone();
+        if next.skip_depth == 0 {
+            next.write(html.as_str());
+        }
+        next
+    }
+
+    fn finalize(&self) -> &Self::Output {
+        &self.buffer
+    }
+}
@@ -16,7 +16,9 @@
 //! ## 데이터 소스
 //! - `ambiguous.json`: 로케일별 모호한 문자 매핑 (예: ja, ko, zh-hans)
 //! - `invisibleCharacters.json`: 로케일별 제거할 보이지 않는 문자
-//! - 빌드 시점에 lazy_static으로 로드
+//! - `include_str!`로 바이너리에 포함하고 처음 사용할 때 lazy_static으로 해석
+//!   (파일 시스템을 읽지 않으므로 `wasm32-unknown-unknown`에서도 동작)
+//! - 해석에 실패하면 규칙 없이 동작하고, `Site::build`가 `BuildError::RuleData`로 보고
 //!
 //! ## 사용 예시
 //! ```rust
@@ -39,12 +41,14 @@
 //! - [x] `replace_ambiguous_chars` 구현
 //! - [x] `remove_invisible_chars` 구현
 //! - [x] 스마트 쿼트 변환 (아포스트로피 감지)
+//! - [x] 규칙 데이터 오류를 panic 대신 값으로 보고 (`check_rule_data`)
 //! - [ ] TODO: Punctuation 트레이트 완성 (ellipsis, em-dash)
 //! - [ ] TODO: build.rs로 JSON → Rust 코드 생성 (컴파일 타임 검증)
 //!
 //! ## 설계 결정
-//! - **런타임 JSON 로드**: 현재는 lazy_static으로 파일 읽기. 향후 build.rs로
-//!   컴파일 타임에 Rust 코드로 변환하면 "컴파일 성공 = 안전" 철학에 더 부합.
+//! - **런타임 JSON 해석**: 파일은 컴파일 시 포함하므로 작업 디렉토리와 무관하지만, 해석은
+//!   처음 사용할 때 합니다. 향후 build.rs로 컴파일 타임에 Rust 코드로 변환하면
+//!   "컴파일 성공 = 안전" 철학에 더 부합.
 //! - **로케일 우선순위**: locale → lang-code → _default → _common 순서로 폴백.
 //! - **아포스트로피 감지**: 전후 문자가 알파벳이면 따옴표가 아닌 아포스트로피로 처리.
 //!
@@ -59,9 +63,7 @@
 use lazy_static::lazy_static;
 use std::{
     collections::{HashMap, HashSet},
-    fs,
-    path::Path,
-    str::FromStr,
+    fmt::Display,
 };
 
 use serde::Deserialize;
@@ -69,6 +71,26 @@ use serde::Deserialize;
 #[derive(Deserialize, Debug)]
 struct CodepointData(HashMap<String, Vec<u32>>);
 
+const INVISIBLE_FILE: &str = "invisibleCharacters.json";
+const AMBIGUOUS_FILE: &str = "ambiguous.json";
+
+/// 규칙 데이터 해석 오류.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuleDataError {
+    /// 데이터 파일 이름
+    pub file: &'static str,
+    pub message: String,
+}
+
+impl Display for RuleDataError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "failed to load sanitization rules from {}: {}", self.file, self.message)
+    }
+}
+
+impl std::error::Error for RuleDataError {}
+
+#[derive(Default)]
 pub struct SanitizationRules {
     invisible_chars: HashMap<String, HashSet<char>>,
     ambiguous_map: HashMap<String, HashMap<char, char>>,
@@ -77,15 +99,15 @@ pub struct SanitizationRules {
 /// 정규화 규칙을 관리하는 핵심 구조체.
 /// lazy_static으로 전역 싱글톤 인스턴스 생성.
 impl SanitizationRules {
-    fn from_files<P: AsRef<Path>>(
-        invisible_path: P,
-        ambiguous_path: P,
-    ) -> Result<Self, Box<dyn std::error::Error>> {
-        let ambiguous_json = fs::read_to_string(ambiguous_path)?;
-        let ambiguous_data: CodepointData = serde_json::from_str(&ambiguous_json)?;
-
-        let invisible_json = fs::read_to_string(invisible_path)?;
-        let invisible_data: CodepointData = serde_json::from_str(&invisible_json)?;
+    fn from_json(invisible_json: &str, ambiguous_json: &str) -> Result<Self, RuleDataError> {
+        let parse = |file: &'static str, json: &str| {
+            serde_json::from_str::<CodepointData>(json).map_err(|err| RuleDataError {
+                file,
+                message: err.to_string(),
+            })
+        };
+        let ambiguous_data = parse(AMBIGUOUS_FILE, ambiguous_json)?;
+        let invisible_data = parse(INVISIBLE_FILE, invisible_json)?;
 
         let mut ambiguous_map: HashMap<String, HashMap<char, char>> = HashMap::new();
 
@@ -140,10 +162,12 @@ impl SanitizationRules {
             result.extend(locale_chars);
         } else if locale.contains('-') {
             // "zh-hans" → "zh"로 폴백
-            if let Some(lang_code) = locale.split('-').next() {
-                if let Some(lang_chars) = self.invisible_chars.get(lang_code) {
-                    result.extend(lang_chars);
-                }
+            if let Some(lang_chars) = locale
+                .split('-')
+                .next()
+                .and_then(|lang_code| self.invisible_chars.get(lang_code))
+            {
+                result.extend(lang_chars);
             }
         }
 
@@ -157,20 +181,21 @@ impl SanitizationRules {
             return locale_map;
         }
 
-        if locale.contains('-') {
-            if let Some(lang_code) = locale.split('-').next() {
-                if let Some(lang_map) = self.ambiguous_map.get(lang_code) {
-                    return &lang_map;
-                }
-            }
+        if locale.contains('-')
+            && let Some(lang_map) = locale
+                .split('-')
+                .next()
+                .and_then(|lang_code| self.ambiguous_map.get(lang_code))
+        {
+            return lang_map;
         }
 
         if let Some(default_map) = self.ambiguous_map.get("_default") {
-            return &default_map;
+            return default_map;
         }
 
         if let Some(common_map) = self.ambiguous_map.get("_common") {
-            return &common_map;
+            return common_map;
         }
 
         &EMPTY_AMBIGUOUS_MAP
@@ -178,16 +203,24 @@ impl SanitizationRules {
 }
 
 lazy_static! {
-    static ref RULES: SanitizationRules = {
-        SanitizationRules::from_files(
-            "src/html/invisibleCharacters.json",
-            "src/html/ambiguous.json",
-        )
-        .expect("Failed to load sanitization rule files")
-    };
+    static ref RULES: Result<SanitizationRules, RuleDataError> = SanitizationRules::from_json(
+        include_str!("invisibleCharacters.json"),
+        include_str!("ambiguous.json"),
+    );
+    static ref EMPTY_RULES: SanitizationRules = SanitizationRules::default();
     static ref EMPTY_AMBIGUOUS_MAP: HashMap<char, char> = HashMap::new();
 }
 
+/// 전역 규칙. 데이터 해석에 실패했으면 빈 규칙 (문자를 바꾸지 않음).
+fn rules() -> &'static SanitizationRules {
+    RULES.as_ref().unwrap_or(&EMPTY_RULES)
+}
+
+/// 규칙 데이터를 해석할 수 있는지 확인.
+pub fn check_rule_data() -> Result<(), &'static RuleDataError> {
+    RULES.as_ref().map(|_| ())
+}
+
 /// 적용할 규칙 목록
 pub enum RuleList {
     All,
@@ -239,7 +272,7 @@ impl Rules for Default {
 
     /// 등록된 규칙을 순서대로 적용
     fn apply(&self, input: &str) -> String {
-        let mut result = String::from_str(input).unwrap();
+        let mut result = input.to_string();
         for rule in self.rules.iter() {
             match rule {
                 RuleList::All => {
@@ -253,14 +286,16 @@ impl Rules for Default {
                 RuleList::InvisibleCharacters => {
                     result = self.remove_invisible_chars(&result);
                 }
-                RuleList::Punctuation => todo!(),
+                RuleList::Punctuation => {
+                    result = self.punctuation_rule(&result);
+                }
             }
         }
         result
     }
 
     fn replace_ambiguous_chars(&self, input: &str) -> String {
-        let ambiguous_pair = RULES.get_ambiguous_pairs("_default");
+        let ambiguous_pair = rules().get_ambiguous_pairs("_default");
         input
             .chars()
             .map(|c| ambiguous_pair.get(&c).copied().unwrap_or(c))
@@ -268,7 +303,7 @@ impl Rules for Default {
     }
 
     fn remove_invisible_chars(&self, input: &str) -> String {
-        let invisible_set = RULES.get_invisible_chars("_default");
+        let invisible_set = rules().get_invisible_chars("_default");
         input
             .chars()
             .filter(|c| !invisible_set.contains(c))
@@ -0,0 +1,896 @@
+//! # tailwind.rs - Tailwind 유틸리티 class 보조
+//!
+//! ## 목적
+//! Tailwind CSS 유틸리티 class를 타입으로 조합합니다. 간격 단계, 글자 크기, 중단점,
+//! 상태 변형을 enum으로 받으므로 `text-2x1` 같은 오타는 컴파일되지 않습니다.
+//! 문자열로 받은 class는 `check_class`로 검사하고, 사이트에서 쓴 class 목록은
+//! `TailwindManifest` 방문자(cite)가 파일로 기록해 Tailwind JIT의 `content` 입력으로 씁니다.
+//!
+//! ## 사용 예시
+//! ```toml
+//! quo = { version = "0.1", features = ["tailwind"] }
+//! ```
+//!
+//! ```rust
+//! let card = Tw::new()
+//!     .p(Space::S4)
+//!     .text(TextSize::Xl2)
+//!     .bg(Color::Slate, Shade::S100)
+//!     .at(Breakpoint::Md, |tw| tw.p(Space::S8))
+//!     .on(Variant::Hover, |tw| tw.bg(Color::Slate, Shade::S200))
+//!     .raw("card")?;
+//!
+//! Div::new(AttrBuilder::global().class(card.classes()), childs);
+//! // class="bg-slate-100 card hover:bg-slate-200 md:p-8 p-4 text-2xl"
+//!
+//! check_class("text-2x1");  // Err(TailwindError::UnknownValue { .. })
+//! ```
+//!
+//! ## 검사 범위
+//! - 변형 접두사(`md:`, `hover:`, `dark:` 등)는 모두 알려진 것이어야 합니다.
+//! - 알려진 유틸리티 접두사(`p-`, `m-`, `text-`, `w-` 등)로 시작하면 값도 검사합니다.
+//! - 그 외 class(`card`, `page` 등 사이트 고유 class)는 통과합니다.
+//! - 임의 값(`p-[3px]`)은 대괄호만 맞으면 통과합니다.
+//!
+//! ## 설계 결정
+//! - **선택 기능**: Tailwind를 쓰지 않는 사이트에는 필요 없으므로 `tailwind` 기능을 켤 때만 포함합니다.
+//! - **기본 테마만**: 단계와 색상은 Tailwind 기본 테마(v3/v4 공통)입니다. 테마를 확장했다면
+//!   `raw`/`arbitrary`로 넣습니다.
+//! - **class는 이스케이프만**: 다른 속성값처럼 타이포그래피 규칙을 적용하지 않습니다.
+
+use std::{collections::BTreeSet, fmt::Display};
+
+use crate::html::rules;
+use crate::html::trust::{AttrValue, SafeString};
+
+/// Tailwind 기본 간격 단계 (`p-4`, `gap-0.5`, `m-px`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Space {
+    S0,
+    Px,
+    S0_5,
+    S1,
+    S1_5,
+    S2,
+    S2_5,
+    S3,
+    S3_5,
+    S4,
+    S5,
+    S6,
+    S7,
+    S8,
+    S9,
+    S10,
+    S11,
+    S12,
+    S14,
+    S16,
+    S20,
+    S24,
+    S28,
+    S32,
+    S36,
+    S40,
+    S44,
+    S48,
+    S52,
+    S56,
+    S60,
+    S64,
+    S72,
+    S80,
+    S96,
+}
+
+impl Space {
+    pub const ALL: [Space; 35] = [
+        Space::S0,
+        Space::Px,
+        Space::S0_5,
+        Space::S1,
+        Space::S1_5,
+        Space::S2,
+        Space::S2_5,
+        Space::S3,
+        Space::S3_5,
+        Space::S4,
+        Space::S5,
+        Space::S6,
+        Space::S7,
+        Space::S8,
+        Space::S9,
+        Space::S10,
+        Space::S11,
+        Space::S12,
+        Space::S14,
+        Space::S16,
+        Space::S20,
+        Space::S24,
+        Space::S28,
+        Space::S32,
+        Space::S36,
+        Space::S40,
+        Space::S44,
+        Space::S48,
+        Space::S52,
+        Space::S56,
+        Space::S60,
+        Space::S64,
+        Space::S72,
+        Space::S80,
+        Space::S96,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Space::S0 => "0",
+            Space::Px => "px",
+            Space::S0_5 => "0.5",
+            Space::S1 => "1",
+            Space::S1_5 => "1.5",
+            Space::S2 => "2",
+            Space::S2_5 => "2.5",
+            Space::S3 => "3",
+            Space::S3_5 => "3.5",
+            Space::S4 => "4",
+            Space::S5 => "5",
+            Space::S6 => "6",
+            Space::S7 => "7",
+            Space::S8 => "8",
+            Space::S9 => "9",
+            Space::S10 => "10",
+            Space::S11 => "11",
+            Space::S12 => "12",
+            Space::S14 => "14",
+            Space::S16 => "16",
+            Space::S20 => "20",
+            Space::S24 => "24",
+            Space::S28 => "28",
+            Space::S32 => "32",
+            Space::S36 => "36",
+            Space::S40 => "40",
+            Space::S44 => "44",
+            Space::S48 => "48",
+            Space::S52 => "52",
+            Space::S56 => "56",
+            Space::S60 => "60",
+            Space::S64 => "64",
+            Space::S72 => "72",
+            Space::S80 => "80",
+            Space::S96 => "96",
+        }
+    }
+
+    fn parse(value: &str) -> Option<Space> {
+        Space::ALL.into_iter().find(|s| s.as_str() == value)
+    }
+}
+
+/// 글자 크기 (`text-sm`, `text-2xl`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TextSize {
+    Xs,
+    Sm,
+    Base,
+    Lg,
+    Xl,
+    Xl2,
+    Xl3,
+    Xl4,
+    Xl5,
+    Xl6,
+    Xl7,
+    Xl8,
+    Xl9,
+}
+
+impl TextSize {
+    pub const ALL: [TextSize; 13] = [
+        TextSize::Xs,
+        TextSize::Sm,
+        TextSize::Base,
+        TextSize::Lg,
+        TextSize::Xl,
+        TextSize::Xl2,
+        TextSize::Xl3,
+        TextSize::Xl4,
+        TextSize::Xl5,
+        TextSize::Xl6,
+        TextSize::Xl7,
+        TextSize::Xl8,
+        TextSize::Xl9,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TextSize::Xs => "xs",
+            TextSize::Sm => "sm",
+            TextSize::Base => "base",
+            TextSize::Lg => "lg",
+            TextSize::Xl => "xl",
+            TextSize::Xl2 => "2xl",
+            TextSize::Xl3 => "3xl",
+            TextSize::Xl4 => "4xl",
+            TextSize::Xl5 => "5xl",
+            TextSize::Xl6 => "6xl",
+            TextSize::Xl7 => "7xl",
+            TextSize::Xl8 => "8xl",
+            TextSize::Xl9 => "9xl",
+        }
+    }
+}
+
+/// 글자 굵기 (`font-bold`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum FontWeight {
+    Thin,
+    ExtraLight,
+    Light,
+    Normal,
+    Medium,
+    Semibold,
+    Bold,
+    ExtraBold,
+    Black,
+}
+
+impl FontWeight {
+    pub const ALL: [FontWeight; 9] = [
+        FontWeight::Thin,
+        FontWeight::ExtraLight,
+        FontWeight::Light,
+        FontWeight::Normal,
+        FontWeight::Medium,
+        FontWeight::Semibold,
+        FontWeight::Bold,
+        FontWeight::ExtraBold,
+        FontWeight::Black,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FontWeight::Thin => "thin",
+            FontWeight::ExtraLight => "extralight",
+            FontWeight::Light => "light",
+            FontWeight::Normal => "normal",
+            FontWeight::Medium => "medium",
+            FontWeight::Semibold => "semibold",
+            FontWeight::Bold => "bold",
+            FontWeight::ExtraBold => "extrabold",
+            FontWeight::Black => "black",
+        }
+    }
+}
+
+/// 기본 색상 팔레트.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Color {
+    Slate,
+    Gray,
+    Zinc,
+    Neutral,
+    Stone,
+    Red,
+    Orange,
+    Amber,
+    Yellow,
+    Lime,
+    Green,
+    Emerald,
+    Teal,
+    Cyan,
+    Sky,
+    Blue,
+    Indigo,
+    Violet,
+    Purple,
+    Fuchsia,
+    Pink,
+    Rose,
+}
+
+impl Color {
+    pub const ALL: [Color; 22] = [
+        Color::Slate,
+        Color::Gray,
+        Color::Zinc,
+        Color::Neutral,
+        Color::Stone,
+        Color::Red,
+        Color::Orange,
+        Color::Amber,
+        Color::Yellow,
+        Color::Lime,
+        Color::Green,
+        Color::Emerald,
+        Color::Teal,
+        Color::Cyan,
+        Color::Sky,
+        Color::Blue,
+        Color::Indigo,
+        Color::Violet,
+        Color::Purple,
+        Color::Fuchsia,
+        Color::Pink,
+        Color::Rose,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Color::Slate => "slate",
+            Color::Gray => "gray",
+            Color::Zinc => "zinc",
+            Color::Neutral => "neutral",
+            Color::Stone => "stone",
+            Color::Red => "red",
+            Color::Orange => "orange",
+            Color::Amber => "amber",
+            Color::Yellow => "yellow",
+            Color::Lime => "lime",
+            Color::Green => "green",
+            Color::Emerald => "emerald",
+            Color::Teal => "teal",
+            Color::Cyan => "cyan",
+            Color::Sky => "sky",
+            Color::Blue => "blue",
+            Color::Indigo => "indigo",
+            Color::Violet => "violet",
+            Color::Purple => "purple",
+            Color::Fuchsia => "fuchsia",
+            Color::Pink => "pink",
+            Color::Rose => "rose",
+        }
+    }
+}
+
+/// 색상 명도 (`-50` ~ `-950`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Shade {
+    S50,
+    S100,
+    S200,
+    S300,
+    S400,
+    S500,
+    S600,
+    S700,
+    S800,
+    S900,
+    S950,
+}
+
+impl Shade {
+    pub const ALL: [Shade; 11] = [
+        Shade::S50,
+        Shade::S100,
+        Shade::S200,
+        Shade::S300,
+        Shade::S400,
+        Shade::S500,
+        Shade::S600,
+        Shade::S700,
+        Shade::S800,
+        Shade::S900,
+        Shade::S950,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Shade::S50 => "50",
+            Shade::S100 => "100",
+            Shade::S200 => "200",
+            Shade::S300 => "300",
+            Shade::S400 => "400",
+            Shade::S500 => "500",
+            Shade::S600 => "600",
+            Shade::S700 => "700",
+            Shade::S800 => "800",
+            Shade::S900 => "900",
+            Shade::S950 => "950",
+        }
+    }
+}
+
+/// 반응형 중단점 (`md:`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Breakpoint {
+    Sm,
+    Md,
+    Lg,
+    Xl,
+    Xl2,
+}
+
+impl Breakpoint {
+    pub const ALL: [Breakpoint; 5] = [
+        Breakpoint::Sm,
+        Breakpoint::Md,
+        Breakpoint::Lg,
+        Breakpoint::Xl,
+        Breakpoint::Xl2,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Breakpoint::Sm => "sm",
+            Breakpoint::Md => "md",
+            Breakpoint::Lg => "lg",
+            Breakpoint::Xl => "xl",
+            Breakpoint::Xl2 => "2xl",
+        }
+    }
+}
+
+/// 상태/환경 변형 (`hover:`, `dark:`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Variant {
+    Hover,
+    Focus,
+    FocusVisible,
+    FocusWithin,
+    Active,
+    Visited,
+    Disabled,
+    First,
+    Last,
+    Odd,
+    Even,
+    GroupHover,
+    PeerFocus,
+    Dark,
+    Print,
+    MotionReduce,
+    Rtl,
+    Ltr,
+}
+
+impl Variant {
+    pub const ALL: [Variant; 18] = [
+        Variant::Hover,
+        Variant::Focus,
+        Variant::FocusVisible,
+        Variant::FocusWithin,
+        Variant::Active,
+        Variant::Visited,
+        Variant::Disabled,
+        Variant::First,
+        Variant::Last,
+        Variant::Odd,
+        Variant::Even,
+        Variant::GroupHover,
+        Variant::PeerFocus,
+        Variant::Dark,
+        Variant::Print,
+        Variant::MotionReduce,
+        Variant::Rtl,
+        Variant::Ltr,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Variant::Hover => "hover",
+            Variant::Focus => "focus",
+            Variant::FocusVisible => "focus-visible",
+            Variant::FocusWithin => "focus-within",
+            Variant::Active => "active",
+            Variant::Visited => "visited",
+            Variant::Disabled => "disabled",
+            Variant::First => "first",
+            Variant::Last => "last",
+            Variant::Odd => "odd",
+            Variant::Even => "even",
+            Variant::GroupHover => "group-hover",
+            Variant::PeerFocus => "peer-focus",
+            Variant::Dark => "dark",
+            Variant::Print => "print",
+            Variant::MotionReduce => "motion-reduce",
+            Variant::Rtl => "rtl",
+            Variant::Ltr => "ltr",
+        }
+    }
+}
+
+// ============================================================================
+// 검사
+// ============================================================================
+
+/// 잘못된 유틸리티 class.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TailwindError {
+    /// 알 수 없는 변형 접두사 (`md:`, `hover:` 자리)
+    UnknownVariant { class: String, variant: String },
+    /// 알려진 유틸리티 접두사에 맞지 않는 값 (`text-2x1`)
+    UnknownValue { class: String, utility: String },
+    /// 비어 있거나 공백이 들어간 class
+    Malformed { class: String },
+}
+
+impl Display for TailwindError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TailwindError::UnknownVariant { class, variant } => {
+                write!(f, "unknown variant '{}:' in class '{}'", variant, class)
+            }
+            TailwindError::UnknownValue { class, utility } => {
+                write!(f, "unknown value for '{}-' in class '{}'", utility, class)
+            }
+            TailwindError::Malformed { class } => write!(f, "malformed class '{}'", class),
+        }
+    }
+}
+
+impl std::error::Error for TailwindError {}
+
+/// 간격 단계를 값으로 받는 유틸리티.
+const SPACING_UTILITIES: [&str; 24] = [
+    "p", "px", "py", "pt", "pr", "pb", "pl", "ps", "pe", "m", "mx", "my", "mt", "mr", "mb", "ml",
+    "ms", "me", "gap", "gap-x", "gap-y", "space-x", "space-y", "inset",
+];
+
+/// 크기 유틸리티 (간격 단계 + 비율/키워드).
+const SIZE_UTILITIES: [&str; 7] = ["w", "h", "size", "min-w", "min-h", "max-w", "max-h"];
+
+const SIZE_KEYWORDS: [&str; 10] = [
+    "auto", "full", "screen", "min", "max", "fit", "svh", "dvh", "none", "prose",
+];
+
+/// 색상을 값으로 받는 유틸리티.
+const COLOR_UTILITIES: [&str; 7] = ["bg", "text", "border", "ring", "outline", "fill", "stroke"];
+
+const COLOR_KEYWORDS: [&str; 5] = ["inherit", "current", "transparent", "black", "white"];
+
+const TEXT_KEYWORDS: [&str; 11] = [
+    "left", "center", "right", "justify", "start", "end", "wrap", "nowrap", "balance", "pretty",
+    "ellipsis",
+];
+
+/// class 하나 검사. 알려진 유틸리티 접두사가 아니면 사이트 고유 class로 보고 통과합니다.
+pub fn check_class(class: &str) -> Result<(), TailwindError> {
+    if class.is_empty() || class.chars().any(char::is_whitespace) {
+        return Err(TailwindError::Malformed {
+            class: class.to_string(),
+        });
+    }
+
+    let mut parts: Vec<&str> = split_variants(class);
+    let utility = parts.pop().unwrap_or_default();
+    for variant in parts {
+        if !is_variant(variant) {
+            return Err(TailwindError::UnknownVariant {
+                class: class.to_string(),
+                variant: variant.to_string(),
+            });
+        }
+    }
+
+    // `!p-4` (important), `-mt-2` (음수)
+    let utility = utility.strip_prefix('!').unwrap_or(utility);
+    let negative = utility.starts_with('-');
+    let utility = utility.trim_start_matches('-');
+    match check_utility(utility, negative) {
+        Some(name) => Err(TailwindError::UnknownValue {
+            class: class.to_string(),
+            utility: name.to_string(),
+        }),
+        None => Ok(()),
+    }
+}
+
+/// 공백으로 구분한 class 목록 검사. 잘못된 class마다 오류 하나.
+pub fn check_classes(classes: &str) -> Vec<TailwindError> {
+    classes
+        .split_whitespace()
+        .filter_map(|class| check_class(class).err())
+        .collect()
+}
+
+/// `md:hover:p-4` → `["md", "hover", "p-4"]`. 임의 값 안의 `:`(`bg-[url(a:b)]`)는 나누지 않습니다.
+fn split_variants(class: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (i, c) in class.char_indices() {
+        match c {
+            '[' => depth += 1,
+            ']' => depth = depth.saturating_sub(1),
+            ':' if depth == 0 => {
+                parts.push(&class[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&class[start..]);
+    parts
+}
+
+fn is_variant(variant: &str) -> bool {
+    Breakpoint::ALL.iter().any(|b| b.as_str() == variant)
+        || Variant::ALL.iter().any(|v| v.as_str() == variant)
+        // `max-md:`, `group-hover/item:`, `[&>p]:`, `data-[open]:`
+        || variant
+            .strip_prefix("max-")
+            .is_some_and(|bp| Breakpoint::ALL.iter().any(|b| b.as_str() == bp))
+        || variant.split_once('/').is_some_and(|(v, _)| is_variant(v))
+        || is_arbitrary(variant)
+        || variant.ends_with(']') && (variant.starts_with("data-[") || variant.starts_with("aria-["))
+}
+
+fn is_arbitrary(value: &str) -> bool {
+    value.len() > 2 && value.starts_with('[') && value.ends_with(']')
+}
+
+/// 유틸리티 값 검사. 잘못됐으면 유틸리티 이름.
+fn check_utility(utility: &str, negative: bool) -> Option<&'static str> {
+    // 긴 접두사부터 (`gap-x`가 `gap`보다 먼저)
+    let mut known: Vec<&'static str> = SPACING_UTILITIES
+        .iter()
+        .chain(SIZE_UTILITIES.iter())
+        .chain(COLOR_UTILITIES.iter())
+        .chain(["font"].iter())
+        .copied()
+        .collect();
+    known.sort_by_key(|name| std::cmp::Reverse(name.len()));
+
+    let (name, value) = known.into_iter().find_map(|name| {
+        let value = utility.strip_prefix(name)?.strip_prefix('-')?;
+        Some((name, value))
+    })?;
+    if is_arbitrary(value) {
+        return None;
+    }
+
+    let valid = if SPACING_UTILITIES.contains(&name) {
+        Space::parse(value).is_some()
+            || (value == "auto" && (name.starts_with('m') || name == "inset"))
+            || (name.starts_with("space-") && value == "reverse")
+    } else if SIZE_UTILITIES.contains(&name) {
+        !negative
+            && (Space::parse(value).is_some()
+                || SIZE_KEYWORDS.contains(&value)
+                || is_fraction(value)
+                || (name.starts_with("max-w")
+                    && (TextSize::ALL.iter().any(|s| s.as_str() == value)
+                        || value.starts_with("screen-"))))
+    } else if name == "font" {
+        // `font-sans` 같은 글꼴 가족은 테마에 따라 다르므로 굵기만 검사
+        !negative
+            && (FontWeight::ALL.iter().any(|w| w.as_str() == value)
+                || ["sans", "serif", "mono"].contains(&value))
+    } else {
+        // 투명도 수식어: `bg-blue-500/50`
+        let value = value.split_once('/').map_or(value, |(v, _)| v);
+        !negative && is_color_value(name, value)
+    };
+    if valid { None } else { Some(name) }
+}
+
+fn is_fraction(value: &str) -> bool {
+    value.split_once('/').is_some_and(|(a, b)| {
+        !a.is_empty() && !b.is_empty() && a.chars().chain(b.chars()).all(|c| c.is_ascii_digit())
+    })
+}
+
+fn is_color_value(name: &str, value: &str) -> bool {
+    if COLOR_KEYWORDS.contains(&value) {
+        return true;
+    }
+    let is_color = match value.rsplit_once('-') {
+        Some((color, shade)) => {
+            Color::ALL.iter().any(|c| c.as_str() == color)
+                && Shade::ALL.iter().any(|s| s.as_str() == shade)
+        }
+        None => false,
+    };
+    if is_color {
+        return true;
+    }
+    match name {
+        "text" => {
+            TextSize::ALL.iter().any(|s| s.as_str() == value) || TEXT_KEYWORDS.contains(&value)
+        }
+        // `border-2`, `border-t-2`, `border-dashed`, `ring-offset-2`
+        "border" | "ring" | "outline" => value.split('-').all(|part| {
+            part.chars().all(|c| c.is_ascii_digit())
+                || ["x", "y", "t", "r", "b", "l", "s", "e"].contains(&part)
+                || ["solid", "dashed", "dotted", "double", "none", "inset", "offset"]
+                    .contains(&part)
+        }),
+        "bg" => {
+            value.starts_with("gradient-to-")
+                || [
+                    "fixed", "local", "scroll", "clip", "origin", "center", "top", "bottom",
+                    "left", "right", "cover", "contain", "repeat", "no-repeat", "none",
+                ]
+                .contains(&value)
+        }
+        _ => false,
+    }
+}
+
+// ============================================================================
+// 조합
+// ============================================================================
+
+/// 유틸리티 class 조합기. 같은 class는 한 번만 들어갑니다.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Tw {
+    classes: BTreeSet<String>,
+    prefix: String,
+}
+
+impl Tw {
+    pub fn new() -> Self {
+        Tw::default()
+    }
+
+    fn add(mut self, class: String) -> Self {
+        self.classes.insert(format!("{}{}", self.prefix, class));
+        self
+    }
+
+    fn spacing(self, utility: &str, space: Space) -> Self {
+        self.add(format!("{}-{}", utility, space.as_str()))
+    }
+
+    /// 패딩 (`p-4`).
+    pub fn p(self, space: Space) -> Self {
+        self.spacing("p", space)
+    }
+
+    /// 가로 패딩 (`px-4`).
+    pub fn px(self, space: Space) -> Self {
+        self.spacing("px", space)
+    }
+
+    /// 세로 패딩 (`py-4`).
+    pub fn py(self, space: Space) -> Self {
+        self.spacing("py", space)
+    }
+
+    /// 마진 (`m-4`).
+    pub fn m(self, space: Space) -> Self {
+        self.spacing("m", space)
+    }
+
+    /// 가로 마진 (`mx-4`).
+    pub fn mx(self, space: Space) -> Self {
+        self.spacing("mx", space)
+    }
+
+    /// 세로 마진 (`my-4`).
+    pub fn my(self, space: Space) -> Self {
+        self.spacing("my", space)
+    }
+
+    /// 가로 가운데 정렬 (`mx-auto`).
+    pub fn mx_auto(self) -> Self {
+        self.add("mx-auto".to_string())
+    }
+
+    /// flex/grid 간격 (`gap-4`).
+    pub fn gap(self, space: Space) -> Self {
+        self.spacing("gap", space)
+    }
+
+    /// 너비 (`w-4`).
+    pub fn w(self, space: Space) -> Self {
+        self.spacing("w", space)
+    }
+
+    /// 높이 (`h-4`).
+    pub fn h(self, space: Space) -> Self {
+        self.spacing("h", space)
+    }
+
+    /// 너비 100% (`w-full`).
+    pub fn w_full(self) -> Self {
+        self.add("w-full".to_string())
+    }
+
+    /// 글자 크기 (`text-2xl`).
+    pub fn text(self, size: TextSize) -> Self {
+        self.add(format!("text-{}", size.as_str()))
+    }
+
+    /// 글자 색 (`text-slate-700`).
+    pub fn text_color(self, color: Color, shade: Shade) -> Self {
+        self.add(format!("text-{}-{}", color.as_str(), shade.as_str()))
+    }
+
+    /// 배경색 (`bg-slate-100`).
+    pub fn bg(self, color: Color, shade: Shade) -> Self {
+        self.add(format!("bg-{}-{}", color.as_str(), shade.as_str()))
+    }
+
+    /// 테두리 색 (`border-slate-200`).
+    pub fn border_color(self, color: Color, shade: Shade) -> Self {
+        self.add(format!("border-{}-{}", color.as_str(), shade.as_str()))
+    }
+
+    /// 글자 굵기 (`font-bold`).
+    pub fn font(self, weight: FontWeight) -> Self {
+        self.add(format!("font-{}", weight.as_str()))
+    }
+
+    /// `display: flex`.
+    pub fn flex(self) -> Self {
+        self.add("flex".to_string())
+    }
+
+    /// `display: grid`.
+    pub fn grid(self) -> Self {
+        self.add("grid".to_string())
+    }
+
+    /// `display: none`.
+    pub fn hidden(self) -> Self {
+        self.add("hidden".to_string())
+    }
+
+    /// `display: block`.
+    pub fn block(self) -> Self {
+        self.add("block".to_string())
+    }
+
+    /// 중단점 이상에서만 적용 (`md:p-8`).
+    pub fn at<F: FnOnce(Tw) -> Tw>(self, breakpoint: Breakpoint, f: F) -> Self {
+        self.scoped(breakpoint.as_str(), f)
+    }
+
+    /// 상태/환경 변형에서만 적용 (`hover:bg-slate-200`).
+    pub fn on<F: FnOnce(Tw) -> Tw>(self, variant: Variant, f: F) -> Self {
+        self.scoped(variant.as_str(), f)
+    }
+
+    fn scoped<F: FnOnce(Tw) -> Tw>(self, variant: &str, f: F) -> Self {
+        let inner = f(Tw {
+            classes: BTreeSet::new(),
+            prefix: format!("{}{}:", self.prefix, variant),
+        });
+        let mut classes = self.classes;
+        classes.extend(inner.classes);
+        Tw {
+            classes,
+            prefix: self.prefix,
+        }
+    }
+
+    /// 임의 값 (`p-[3px]`). `value`는 대괄호 없이 넘깁니다.
+    pub fn arbitrary(self, utility: &str, value: &str) -> Self {
+        self.add(format!("{}-[{}]", utility, value.replace(' ', "_")))
+    }
+
+    /// 문자열 class 추가. `check_class`를 통과해야 합니다.
+    pub fn raw(self, class: &str) -> Result<Self, TailwindError> {
+        let class = format!("{}{}", self.prefix, class);
+        check_class(&class)?;
+        let mut classes = self.classes;
+        classes.insert(class);
+        Ok(Tw {
+            classes,
+            prefix: self.prefix,
+        })
+    }
+
+    /// 조합한 class 이름 (정렬됨).
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.classes.iter().map(|c| c.as_str())
+    }
+
+    /// `Attributes::class`에 넘길 집합. 값에는 이스케이프만 적용합니다.
+    pub fn classes(&self) -> BTreeSet<AttrValue> {
+        let rule = rules::Default { rules: vec![] };
+        self.classes
+            .iter()
+            .map(|class| AttrValue::from_str(class, &rule))
+            .collect()
+    }
+}
+
+impl Display for Tw {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.names().collect::<Vec<_>>().join(" "))
+    }
+}
@@ -8,6 +8,7 @@
 //! - **문서 보존이 목표**: XSS 방어가 아닌, 사용자가 작성한 `<`, `>` 같은 문자가
 //!   HTML 태그로 오해되어 문서가 깨지는 것을 방지합니다.
 //! - **보안은 별도 도구의 책임**: 빌드 후 html-validate, security-scanner 등으로 검증합니다.
+//!   문서 구조의 흔한 오류는 `Site::validate_html`이 빌드 중에 경고로 보고합니다.
 //! - **타입으로 신뢰 표현**: 컴파일 타임에 잘못된 타입 사용을 방지합니다.
 //!
 //! ## 신뢰 모델
@@ -34,27 +35,50 @@
 //! ## 구현 상태
 //! - [x] 모든 타입 구현 완료
 //! - [x] escape_html_chars 함수
+//! - [x] Content/AttrValue 문자열 공유 (`Arc<str>`, 빌드 단위 `InternPool`)
+//! - [x] URL 속성값 퍼센트 인코딩 (`AttrValue::url`, `AttrValue::srcset`, `encode_url_component`)
 //! - [ ] TODO: 각 타입 독스트링 상세화
 //! - [ ] TODO: HtmlBlock 위험성 경고 강화
 //!
+//! ## URL 속성값
+//! `href`, `src`, `srcset`의 값은 두 번 이스케이프됩니다. 먼저 URL에 쓸 수 없는 문자를 퍼센트
+//! 인코딩하고(URL 계층), 그 결과를 다른 속성값처럼 HTML 이스케이프합니다(문서 계층).
+//! ```rust
+//! // 쿼리 값 안의 `&`는 구성 요소 인코딩으로 (구분자 `&`와 구분)
+//! let href = format!("/search?q={}&page=2", encode_url_component("rock & roll"));
+//! AttrValue::url(&href);
+//! // → href="/search?q=rock%20%26%20roll&amp;page=2"  (브라우저가 읽는 URL: /search?q=rock%20%26%20roll&page=2)
+//!
+//! AttrValue::url("/my notes/a\"b\".html");  // → /my%20notes/a%22b%22.html
+//! ```
+//!
 //! ## 설계 결정
+//! - **URL 인코딩은 필요한 만큼만**: `AttrValue::url`은 URL 구조(`/`, `?`, `&`, `#` 등)와 이미 인코딩된
+//!   `%XX`, ASCII 밖의 문자(IRI)는 그대로 두고 공백, `"`, `<` 같은 문자만 인코딩합니다. 같은 URL을 두 번
+//!   넣어도 결과가 같고, 사이트 안 링크 검사(`normalize_url`)는 디코딩한 경로로 비교합니다.
+//! - **구성 요소는 호출자가**: 쿼리 값 안의 `&`, `=`처럼 구분자와 같은 문자가 값인지 구분자인지는 URL만
+//!   보고 알 수 없으므로 값을 넣을 때 `encode_url_component`로 인코딩합니다.
 //! - `AttrKey`, `TagName`이 `pub(crate)`인 이유: 사용자가 임의의 속성/태그를
 //!   생성하지 못하도록. 라이브러리가 제공하는 안전한 API만 사용 가능.
 //! - `HtmlBlock`이 검증하지 않는 이유: 외부 도구(Mermaid, KaTeX) 출력을 신뢰.
 //!   불필요한 파싱/재직렬화 방지. 보안은 빌드 파이프라인의 별도 도구가 담당.
 
 use core::str;
-use std::fmt::Display;
+use std::{fmt::Display, sync::Arc};
 
+use crate::html::intern::intern;
+use crate::html::node::Whitespace;
 use crate::html::rules;
 
 /// 사용자가 작성한 텍스트 노드. HTML 특수문자를 이스케이프합니다.
+/// 같은 문자열은 빌드의 `InternPool`로 공유됩니다 (`intern.rs`).
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Content(String);
+pub struct Content(Arc<str>);
 
 /// 사용자가 제공한 HTML 속성값. HTML 특수문자를 이스케이프합니다.
+/// 같은 문자열은 빌드의 `InternPool`로 공유됩니다 (`intern.rs`).
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
-pub struct AttrValue(String);
+pub struct AttrValue(Arc<str>);
 
 /// HTML 속성 키. 라이브러리 내부에서만 생성 가능 (pub(crate)).
 /// 예: "id", "class", "src"
@@ -115,7 +139,19 @@ impl SafeString for Content {
         T: rules::Rules,
     {
         let typo = rule.apply(s);
-        Content(escape_html_chars(&typo))
+        Content(intern(&escape_html_chars(&typo)))
+    }
+}
+
+impl AttrValue {
+    /// URL 속성값 (`href`, `src` 등). 타이포그래피 규칙 없이 `encode_url` 후 HTML 이스케이프.
+    pub fn url(url: &str) -> Self {
+        AttrValue(intern(&escape_html_chars(&encode_url(url))))
+    }
+
+    /// `srcset` 속성값. `encode_srcset` 후 HTML 이스케이프.
+    pub fn srcset(srcset: &str) -> Self {
+        AttrValue(intern(&escape_html_chars(&encode_srcset(srcset))))
     }
 }
 
@@ -129,11 +165,12 @@ impl SafeString for AttrValue {
         T: rules::Rules,
     {
         let typo = rule.apply(s);
-        AttrValue(escape_html_chars(&typo))
+        AttrValue(intern(&escape_html_chars(&typo)))
     }
 }
 
 impl AttrKey {
+    #[allow(clippy::should_implement_trait)]
     pub fn from_str(key: &str) -> Self {
         AttrKey(key.to_string())
     }
@@ -143,6 +180,7 @@ impl AttrKey {
 }
 
 impl HtmlBlock {
+    #[allow(clippy::should_implement_trait)]
     pub fn from_str(block: &str) -> Self {
         HtmlBlock(block.to_string())
     }
@@ -158,6 +196,14 @@ impl TagName {
     pub fn as_str(&self) -> &str {
         &self.0
     }
+
+    /// 태그의 기본 공백 처리. 공백이 내용인 요소(`pre`, `code`, `textarea`, `script`, `style`)는 `Preserve`.
+    pub fn whitespace(&self) -> Whitespace {
+        match self.0.as_str() {
+            "pre" | "code" | "textarea" | "script" | "style" => Whitespace::Preserve,
+            _ => Whitespace::Collapse,
+        }
+    }
 }
 
 /// HTML 특수문자를 엔티티로 변환하여 문서 구조 손상을 방지합니다.
@@ -166,7 +212,7 @@ impl TagName {
 /// - `<`, `>`: HTML 태그로 오해 방지
 /// - `&`: HTML 엔티티 시작 문자로 오해 방지  
 /// - `"`, `'`: 속성값 종료로 오해 방지
-fn escape_html_chars(input: &str) -> String {
+pub(crate) fn escape_html_chars(input: &str) -> String {
     let mut output = String::with_capacity(input.len());
     for c in input.chars() {
         match c {
@@ -180,3 +226,100 @@ fn escape_html_chars(input: &str) -> String {
     }
     output
 }
+
+/// URL 구성 요소(쿼리 값, 경로 한 단계) 인코딩. RFC 3986 비예약 문자 외 모두 `%XX`.
+pub fn encode_url_component(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => (b as char).to_string(),
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+/// URL 전체 인코딩. URL에 그대로 쓸 수 없는 ASCII 문자(공백, 제어 문자, `"`, `<`, `>`, `\`, `^`, `` ` ``, `{`, `|`, `}`)와
+/// `%XX`가 아닌 `%`만 인코딩합니다. 구분자(`/?#&=` 등), `%XX`, ASCII 밖의 문자는 그대로 둡니다.
+pub fn encode_url(url: &str) -> String {
+    let bytes = url.as_bytes();
+    let mut encoded = String::with_capacity(url.len());
+    for (i, c) in url.char_indices() {
+        let escaped = bytes
+            .get(i + 1..i + 3)
+            .is_some_and(|hex| hex.iter().all(u8::is_ascii_hexdigit));
+        match c {
+            '%' if escaped => encoded.push('%'),
+            '%' | ' ' | '"' | '<' | '>' | '\\' | '^' | '`' | '{' | '|' | '}' => {
+                encoded.push_str(&format!("%{:02X}", c as u8))
+            }
+            c if c.is_ascii_control() => encoded.push_str(&format!("%{:02X}", c as u8)),
+            c => encoded.push(c),
+        }
+    }
+    encoded
+}
+
+/// `%XX` 디코딩. 잘못된 시퀀스는 그대로 둡니다.
+pub fn decode_url(url: &str) -> String {
+    let bytes = url.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let hex = bytes
+            .get(i + 1..i + 3)
+            .and_then(|h| std::str::from_utf8(h).ok())
+            .and_then(|h| u8::from_str_radix(h, 16).ok());
+        match (bytes[i], hex) {
+            (b'%', Some(byte)) => {
+                decoded.push(byte);
+                i += 3;
+            }
+            (byte, _) => {
+                decoded.push(byte);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+/// `srcset` 인코딩. 후보(`URL 설명자`)마다 URL을 `encode_url`하고, 후보 구분과 헷갈리는 URL 안의 `,`는
+/// `%2C`로 바꿉니다. 후보는 HTML 명세처럼 공백까지를 URL로 읽습니다.
+pub fn encode_srcset(srcset: &str) -> String {
+    let mut candidates = Vec::new();
+    let mut rest = srcset;
+    loop {
+        rest = rest.trim_start_matches(|c: char| c.is_ascii_whitespace() || c == ',');
+        if rest.is_empty() {
+            break;
+        }
+        let end = rest.find(|c: char| c.is_ascii_whitespace()).unwrap_or(rest.len());
+        let (url, after) = rest.split_at(end);
+        let (url, descriptor, after) = match url.strip_suffix(',') {
+            // `a.png,b.png 2x`: 설명자 없는 후보
+            Some(_) => (url.trim_end_matches(','), "", after),
+            None => {
+                let end = after.find(',').unwrap_or(after.len());
+                (url, after[..end].trim(), &after[end..])
+            }
+        };
+        let url = encode_url(url).replace(',', "%2C");
+        candidates.push(if descriptor.is_empty() {
+            url
+        } else {
+            format!("{} {}", url, descriptor)
+        });
+        rest = after;
+    }
+    candidates.join(", ")
+}
+
+/// `escape_html_chars`의 역변환. 렌더링 결과에서 원문 텍스트를 되찾을 때 사용합니다.
+pub(crate) fn unescape_html_chars(input: &str) -> String {
+    input
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
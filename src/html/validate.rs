@@ -0,0 +1,351 @@
+//! # validate.rs - 렌더링 결과 구조 검사
+//!
+//! ## 목적
+//! 완성된 문서 IR을 순회하며 브라우저가 조용히 고쳐 버리는 구조 오류를 찾습니다.
+//! 타입으로 막지 못하는 조합(Block 안의 Block, 외부 도구의 출력을 감싼 구조 등)을 빌드 후에 검증합니다.
+//! - 중첩: 구문 요소(`p`, `span`, `h1` 등) 안의 블록 요소, 링크/버튼 안의 대화형 요소,
+//!   목록/표 요소의 부모
+//! - ID: 문서 안에서 같은 `id`가 두 번 이상
+//! - 랜드마크: `<main>`이 둘 이상이거나 다른 랜드마크 안에 있음, `header`/`footer` 안의 `header`/`footer`
+//! - 속성: 요소에 적용되지 않는 속성 (HTML5 명세의 요소별 속성 목록 + 전역 속성, `data-*`, `aria-*`, `on*`)
+//!
+//! ## 사용 예시
+//! ```rust
+//! let violations = HtmlValidator::new().validate(&document_ir);
+//! for v in &violations {
+//!     println!("{}", v);  // "nesting: body > p > div: <div> is not allowed inside <p>"
+//! }
+//!
+//! // 사이트 전체: 경고로 BuildReport에 모임
+//! let site = Site::new().validate_html(true);
+//! ```
+//!
+//! ## 설계 결정
+//! - **IR 기준**: 렌더링한 문자열을 다시 해석하지 않고 문서 IR을 그대로 검사합니다.
+//!   `HtmlBlock`(Raw) 내용은 신뢰 경계 밖이므로 검사하지 않습니다.
+//! - **자주 틀리는 규칙만**: HTML 명세 전체가 아닌, 렌더링 결과를 바꾸는 규칙만 검사합니다.
+//!   전체 검증은 외부 도구(html-validate 등)의 몫입니다.
+//! - **속성은 허용 목록**: 속성 빌더의 타입 묶음(`Attributes<T>`)은 Block 계층의 `SharedAttrs`나 앞으로의
+//!   우회 경로로 붙인 속성을 막지 못하므로 렌더링 결과에서 다시 확인합니다. 목록에 없는 태그(사용자 정의 요소)와
+//!   `svg`/`math` 안의 요소, 외부 스크립트(`<script src>`: 위젯이 자기 설정을 속성으로 읽음)는 검사하지 않습니다.
+//! - **위치는 태그 경로로**: `body > main > p > div`처럼 조상 태그를 이어 붙여 보여 줍니다.
+
+use std::{collections::HashMap, fmt::Display};
+
+use serde::Serialize;
+
+use crate::block::block::attr_token;
+use crate::html::node::{Element, IRNode};
+
+/// 블록 요소 (구문 콘텐츠가 아님).
+const BLOCK_ELEMENTS: &[&str] = &[
+    "address", "article", "aside", "blockquote", "details", "dialog", "div", "dl", "dd", "dt",
+    "fieldset", "figcaption", "figure", "footer", "form", "h1", "h2", "h3", "h4", "h5", "h6",
+    "header", "hgroup", "hr", "li", "main", "menu", "nav", "ol", "p", "pre", "section", "table",
+    "tbody", "thead", "tfoot", "tr", "td", "th", "caption", "ul",
+];
+
+/// 구문 콘텐츠만 담을 수 있는 요소.
+const PHRASING_PARENTS: &[&str] = &[
+    "p", "h1", "h2", "h3", "h4", "h5", "h6", "span", "em", "strong", "b", "i", "u", "s", "small",
+    "code", "kbd", "samp", "var", "sub", "sup", "mark", "abbr", "cite", "q", "time", "label",
+    "pre", "button",
+];
+
+/// 대화형 요소. 링크/버튼 안에 둘 수 없습니다.
+const INTERACTIVE_ELEMENTS: &[&str] = &["a", "button", "input", "select", "textarea"];
+
+/// 다른 랜드마크 안에 둘 수 없는 `<main>`의 조상.
+const SECTIONING_LANDMARKS: &[&str] = &["article", "aside", "footer", "header", "nav"];
+
+/// 모든 HTML 요소에 쓸 수 있는 전역 속성.
+const GLOBAL_ATTRIBUTES: &[&str] = &[
+    "accesskey", "autocapitalize", "autofocus", "class", "contenteditable", "dir", "draggable",
+    "enterkeyhint", "hidden", "id", "inert", "inputmode", "is", "itemid", "itemprop", "itemref",
+    "itemscope", "itemtype", "lang", "nonce", "popover", "role", "slot", "spellcheck", "style",
+    "tabindex", "title", "translate", "writingsuggestions",
+];
+
+/// 요소별 속성 (전역 속성 제외). 목록에 없는 태그는 `None`: 검사하지 않음.
+fn element_attributes(tag: &str) -> Option<&'static [&'static str]> {
+    let attrs: &[&str] = match tag {
+        "a" => &["download", "href", "hreflang", "ping", "referrerpolicy", "rel", "target", "type"],
+        "area" => &[
+            "alt", "coords", "download", "href", "ping", "referrerpolicy", "rel", "shape", "target",
+        ],
+        "audio" => &["autoplay", "controls", "crossorigin", "loop", "muted", "preload", "src"],
+        "base" => &["href", "target"],
+        "blockquote" | "q" => &["cite"],
+        "button" => &[
+            "command", "commandfor", "disabled", "form", "formaction", "formenctype", "formmethod",
+            "formnovalidate", "formtarget", "name", "popovertarget", "popovertargetaction", "type",
+            "value",
+        ],
+        "canvas" => &["height", "width"],
+        "col" | "colgroup" => &["span"],
+        "data" => &["value"],
+        "del" | "ins" => &["cite", "datetime"],
+        "details" => &["name", "open"],
+        "dialog" => &["closedby", "open"],
+        "embed" => &["height", "src", "type", "width"],
+        "fieldset" => &["disabled", "form", "name"],
+        "form" => &[
+            "accept-charset", "action", "autocomplete", "enctype", "method", "name", "novalidate",
+            "rel", "target",
+        ],
+        "html" => &["manifest", "xmlns"],
+        "iframe" => &[
+            "allow", "allowfullscreen", "height", "loading", "name", "referrerpolicy", "sandbox",
+            "src", "srcdoc", "width",
+        ],
+        "img" => &[
+            "alt", "crossorigin", "decoding", "fetchpriority", "height", "ismap", "loading",
+            "referrerpolicy", "sizes", "src", "srcset", "usemap", "width",
+        ],
+        "input" => &[
+            "accept", "alt", "autocomplete", "checked", "dirname", "disabled", "form", "formaction",
+            "formenctype", "formmethod", "formnovalidate", "formtarget", "height", "list", "max",
+            "maxlength", "min", "minlength", "multiple", "name", "pattern", "placeholder",
+            "popovertarget", "popovertargetaction", "readonly", "required", "size", "src", "step",
+            "type", "value", "width",
+        ],
+        "label" => &["for"],
+        "li" => &["value"],
+        "link" => &[
+            "as", "blocking", "color", "crossorigin", "disabled", "fetchpriority", "href", "hreflang",
+            "imagesizes", "imagesrcset", "integrity", "media", "referrerpolicy", "rel", "sizes",
+            "type",
+        ],
+        "map" | "slot" => &["name"],
+        // `property`: Open Graph (RDFa)
+        "meta" => &["charset", "content", "http-equiv", "media", "name", "property"],
+        "meter" => &["high", "low", "max", "min", "optimum", "value"],
+        "object" => &["data", "form", "height", "name", "type", "width"],
+        "ol" => &["reversed", "start", "type"],
+        "optgroup" => &["disabled", "label"],
+        "option" => &["disabled", "label", "selected", "value"],
+        "output" => &["for", "form", "name"],
+        "progress" => &["max", "value"],
+        "script" => &[
+            "async", "blocking", "crossorigin", "defer", "fetchpriority", "integrity", "nomodule",
+            "referrerpolicy", "src", "type",
+        ],
+        "select" => &["autocomplete", "disabled", "form", "multiple", "name", "required", "size"],
+        "source" => &["height", "media", "sizes", "src", "srcset", "type", "width"],
+        "style" => &["blocking", "media"],
+        "td" => &["colspan", "headers", "rowspan"],
+        "th" => &["abbr", "colspan", "headers", "rowspan", "scope"],
+        "template" => &[
+            "shadowrootclonable", "shadowrootdelegatesfocus", "shadowrootmode",
+            "shadowrootserializable",
+        ],
+        "textarea" => &[
+            "autocomplete", "cols", "dirname", "disabled", "form", "maxlength", "minlength", "name",
+            "placeholder", "readonly", "required", "rows", "wrap",
+        ],
+        "time" => &["datetime"],
+        "track" => &["default", "kind", "label", "src", "srclang"],
+        "video" => &[
+            "autoplay", "controls", "crossorigin", "height", "loop", "muted", "playsinline", "poster",
+            "preload", "src", "width",
+        ],
+        "abbr" | "address" | "article" | "aside" | "b" | "bdi" | "bdo" | "body" | "br" | "caption"
+        | "cite" | "code" | "dd" | "dfn" | "div" | "dl" | "dt" | "em" | "figcaption" | "figure"
+        | "footer" | "h1" | "h2" | "h3" | "h4" | "h5" | "h6" | "head" | "header" | "hgroup" | "hr"
+        | "i" | "kbd" | "legend" | "main" | "mark" | "menu" | "nav" | "noscript" | "p" | "picture"
+        | "pre" | "rp" | "rt" | "ruby" | "s" | "samp" | "search" | "section" | "small" | "span"
+        | "strong" | "sub" | "summary" | "sup" | "table" | "tbody" | "tfoot" | "thead" | "title"
+        | "tr" | "u" | "ul" | "var" | "wbr" => &[],
+        _ => return None,
+    };
+    Some(attrs)
+}
+
+/// `attr`이 `tag` 요소에 적용되는 속성인지. 목록에 없는 태그는 항상 `true`.
+pub fn attribute_applies(tag: &str, attr: &str) -> bool {
+    let Some(attrs) = element_attributes(tag) else {
+        return true;
+    };
+    GLOBAL_ATTRIBUTES.contains(&attr)
+        || attrs.contains(&attr)
+        || attr.starts_with("data-")
+        || attr.starts_with("aria-")
+        || attr.starts_with("on")
+}
+
+/// 검사 규칙 종류.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ViolationKind {
+    Nesting,
+    DuplicateId,
+    Landmark,
+    Attribute,
+}
+
+impl ViolationKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ViolationKind::Nesting => "nesting",
+            ViolationKind::DuplicateId => "duplicate_id",
+            ViolationKind::Landmark => "landmark",
+            ViolationKind::Attribute => "attribute",
+        }
+    }
+}
+
+/// 위반 하나.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Violation {
+    pub rule: ViolationKind,
+    /// 조상 태그 경로. 예: `body > main > p > div`
+    pub element: String,
+    pub message: String,
+}
+
+impl Display for Violation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}: {}", self.rule.as_str(), self.element, self.message)
+    }
+}
+
+/// 문서 구조 검사기.
+#[derive(Debug, Clone, Default)]
+pub struct HtmlValidator {
+    violations: Vec<Violation>,
+    ids: HashMap<String, usize>,
+    mains: usize,
+}
+
+impl HtmlValidator {
+    pub fn new() -> Self {
+        HtmlValidator::default()
+    }
+
+    /// 문서(또는 조각) 하나 검사. 위반은 문서 순서입니다.
+    pub fn validate(mut self, root: &IRNode) -> Vec<Violation> {
+        self.visit(root, &mut Vec::new());
+        self.violations
+    }
+
+    fn visit<'a>(&mut self, node: &'a IRNode, ancestors: &mut Vec<&'a str>) {
+        if node.is_fragment() {
+            // 조각은 태그가 없으므로 자식이 부모 요소의 자식
+            for child in node.get_childs() {
+                if let Element::Node(child) = child {
+                    self.visit(child, ancestors);
+                }
+            }
+            return;
+        }
+        let tag = node.get_tag().as_str();
+        self.check_nesting(tag, ancestors);
+        self.check_landmark(tag, ancestors);
+        self.check_attributes(node, ancestors);
+        if let Some(id) = attr_token(node, "id") {
+            let seen = self.ids.entry(id.clone()).or_default();
+            *seen += 1;
+            if *seen == 2 {
+                self.report(
+                    ViolationKind::DuplicateId,
+                    ancestors,
+                    tag,
+                    format!("id \"{}\" is used more than once", id),
+                );
+            }
+        }
+
+        ancestors.push(tag);
+        for child in node.get_childs() {
+            if let Element::Node(child) = child {
+                self.visit(child, ancestors);
+            }
+        }
+        ancestors.pop();
+    }
+
+    fn check_nesting(&mut self, tag: &str, ancestors: &[&str]) {
+        let parent = ancestors.last().copied();
+        if BLOCK_ELEMENTS.contains(&tag)
+            && let Some(phrasing) = ancestors.iter().rev().find(|a| PHRASING_PARENTS.contains(a))
+        {
+            let message = format!("<{}> is not allowed inside <{}>", tag, phrasing);
+            self.report(ViolationKind::Nesting, ancestors, tag, message);
+        }
+        if INTERACTIVE_ELEMENTS.contains(&tag)
+            && let Some(interactive) = ancestors.iter().rev().find(|a| matches!(**a, "a" | "button"))
+        {
+            let message = format!("interactive <{}> is not allowed inside <{}>", tag, interactive);
+            self.report(ViolationKind::Nesting, ancestors, tag, message);
+        }
+        let required: Option<&[&str]> = match tag {
+            "li" => Some(&["ul", "ol", "menu"]),
+            "tr" => Some(&["table", "thead", "tbody", "tfoot"]),
+            "td" | "th" => Some(&["tr"]),
+            "dt" | "dd" => Some(&["dl", "div"]),
+            "figcaption" => Some(&["figure"]),
+            "summary" => Some(&["details"]),
+            _ => None,
+        };
+        if let Some(required) = required
+            && !parent.is_some_and(|p| required.contains(&p))
+        {
+            let message = format!("<{}> must be a child of <{}>", tag, required.join(">, <"));
+            self.report(ViolationKind::Nesting, ancestors, tag, message);
+        }
+        if let Some(list @ ("ul" | "ol")) = parent
+            && !matches!(tag, "li" | "script" | "template")
+        {
+            let message = format!("<{}> may only contain <li>, found <{}>", list, tag);
+            self.report(ViolationKind::Nesting, ancestors, tag, message);
+        }
+    }
+
+    fn check_attributes(&mut self, node: &IRNode, ancestors: &[&str]) {
+        let tag = node.get_tag().as_str();
+        let foreign = ancestors.iter().any(|a| matches!(*a, "svg" | "math"));
+        if foreign || (tag == "script" && attr_token(node, "src").is_some()) {
+            return;
+        }
+        for (key, _) in node.get_attrs().get().all() {
+            let key = key.as_str();
+            if !attribute_applies(tag, key) {
+                let message = format!("attribute \"{}\" does not apply to <{}>", key, tag);
+                self.report(ViolationKind::Attribute, ancestors, tag, message);
+            }
+        }
+    }
+
+    fn check_landmark(&mut self, tag: &str, ancestors: &[&str]) {
+        match tag {
+            "main" => {
+                self.mains += 1;
+                if self.mains == 2 {
+                    let message = "document has more than one <main>".to_string();
+                    self.report(ViolationKind::Landmark, ancestors, tag, message);
+                }
+                if let Some(landmark) = ancestors.iter().find(|a| SECTIONING_LANDMARKS.contains(a)) {
+                    let message = format!("<main> is not allowed inside <{}>", landmark);
+                    self.report(ViolationKind::Landmark, ancestors, tag, message);
+                }
+            }
+            "header" | "footer" => {
+                if let Some(landmark) = ancestors.iter().find(|a| matches!(**a, "header" | "footer")) {
+                    let message = format!("<{}> is not allowed inside <{}>", tag, landmark);
+                    self.report(ViolationKind::Landmark, ancestors, tag, message);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn report(&mut self, rule: ViolationKind, ancestors: &[&str], tag: &str, message: String) {
+        let mut path: Vec<&str> = ancestors.iter().copied().filter(|a| *a != "html").collect();
+        path.push(tag);
+        self.violations.push(Violation {
+            rule,
+            element: path.join(" > "),
+            message,
+        });
+    }
+}
@@ -1,4 +1,10 @@
 pub mod html;
+pub mod metadata;
 pub mod block;
 pub mod page;
 pub mod cite;
+pub mod cli;
+pub mod testing;
+
+/// JSX와 비슷한 문법의 타입 HTML 요소 (`quo-macros`). 예: `html! { <div class="box"><h1>{title}</h1></div> }`
+pub use quo_macros::html;
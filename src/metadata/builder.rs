@@ -0,0 +1,125 @@
+//! # builder.rs - Metadata 빌더
+//!
+//! ## 목적
+//! 사용자 친화적인 메타데이터 생성 인터페이스를 제공합니다.
+//! 내부적으로 `MetadataMap`을 감싸며, 불변 빌더 패턴(`self` 소비)을 따릅니다.
+//!
+//! ## 사용 예시
+//! ```rust
+//! let meta = Metadata::new()
+//!     .title("About")                 // 라이브러리 제공
+//!     .custom(SyntaxTheme::Dracula);  // 사용자 확장
+//!
+//! // 계층 병합: Site → Page
+//! let resolved = site_meta.map().merge(page_meta.map());
+//! ```
+
+use crate::metadata::{
+    map::MetadataMap,
+    merge::MergeableMetadata,
+    types::{
+        Assets, Categories, CriticalCss, Date, Description, Language, PublishDate, Tags,
+        TextDirection, Title,
+    },
+    value::MetadataValue,
+};
+
+/// 메타데이터 빌더. Block, Page, Site가 자신의 설정을 제공할 때 사용.
+#[derive(Clone, Default, Debug)]
+pub struct Metadata {
+    map: MetadataMap,
+}
+
+impl Metadata {
+    pub fn new() -> Self {
+        Metadata {
+            map: MetadataMap::new(),
+        }
+    }
+
+    /// 사용자 정의 값 추가. 같은 타입의 기존 값은 덮어씁니다.
+    pub fn custom<T: MetadataValue>(self, value: T) -> Self {
+        let mut map = self.map;
+        map.insert(value);
+        Metadata { map }
+    }
+
+    /// 부분 병합 가능한 값 추가. 상위 계층 값과 `merge_with`로 병합됩니다.
+    pub fn mergeable<T: MergeableMetadata>(self, value: T) -> Self {
+        let mut map = self.map;
+        map.insert_mergeable(value);
+        Metadata { map }
+    }
+
+    /// 제목 설정.
+    pub fn title(self, title: &str) -> Self {
+        self.custom(Title(title.to_string()))
+    }
+
+    /// 요약 설정.
+    pub fn description(self, description: &str) -> Self {
+        self.custom(Description(description.to_string()))
+    }
+
+    /// 태그 설정.
+    pub fn tags(self, tags: Vec<&str>) -> Self {
+        self.custom(Tags(tags.into_iter().map(String::from).collect()))
+    }
+
+    /// 카테고리 설정.
+    pub fn categories(self, categories: Vec<&str>) -> Self {
+        self.custom(Categories(
+            categories.into_iter().map(String::from).collect(),
+        ))
+    }
+
+    /// 발행일 설정.
+    pub fn date(self, date: Date) -> Self {
+        self.custom(PublishDate(date))
+    }
+
+    /// 문서 언어 설정 (BCP 47). 예: "en", "ko"
+    pub fn language(self, language: &str) -> Self {
+        self.custom(Language(language.to_string()))
+    }
+
+    /// 글 방향 설정. 지정하지 않으면 문서 언어로 정합니다.
+    pub fn direction(self, direction: TextDirection) -> Self {
+        self.custom(direction)
+    }
+
+    /// 필요한 CSS/JS 선언. 상위 계층 Assets와 합집합으로 병합됩니다.
+    pub fn assets(self, assets: Assets) -> Self {
+        self.mergeable(assets)
+    }
+
+    /// 핵심 CSS 선언. 상위 계층 CSS 뒤에 이어 붙습니다.
+    pub fn critical_css(self, css: &str) -> Self {
+        self.mergeable(CriticalCss(css.to_string()))
+    }
+
+    /// 다른 메타데이터를 위에 덮어 병합 (`other` 우선).
+    pub fn merge(self, other: &Metadata) -> Self {
+        Metadata {
+            map: self.map.merge(&other.map),
+        }
+    }
+
+    pub fn get<T: MetadataValue>(&self) -> Option<&T> {
+        self.map.get::<T>()
+    }
+
+    pub fn map(&self) -> &MetadataMap {
+        &self.map
+    }
+
+    pub fn into_map(self) -> MetadataMap {
+        self.map
+    }
+}
+
+impl From<MetadataMap> for Metadata {
+    fn from(map: MetadataMap) -> Self {
+        Metadata { map }
+    }
+}
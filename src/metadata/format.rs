@@ -0,0 +1,340 @@
+//! # format.rs - 언어별 날짜, 숫자, 파일 크기 형식
+//!
+//! ## 목적
+//! 날짜 목록, 아카이브 제목, 초안 배너처럼 사람이 읽는 값을 페이지 언어(`Language`, 기본값은
+//! `SiteConfig::language`)에 맞게 씁니다. Block마다 형식을 따로 만들지 않도록 `Locale` 하나로 모읍니다.
+//! 피드처럼 기계가 읽는 날짜(RFC 3339, RFC 822)도 여기 있습니다.
+//!
+//! ## 사용 예시
+//! ```rust
+//! // Block에서: 현재 페이지 언어와 사이트의 DateStyle
+//! let locale = Locale::from_metadata(&ctx.metadata);
+//! locale.date(date)          // "2024년 3월 1일" (ko, Long)
+//! locale.month(2024, 3)      // "2024년 3월"
+//! locale.number(1234567)     // "1,234,567"
+//! locale.file_size(246_272)  // "240.5 KB"
+//!
+//! // 날짜 표시 형식 바꾸기 (사이트 메타데이터)
+//! let site = Site::new().metadata(Metadata::new().custom(DateStyle::Medium));
+//!
+//! // 직접 만들기
+//! Locale::new("de").date(date)  // "1. März 2024"
+//! ```
+//!
+//! ## 형식 (2024-03-01, 1234567.5)
+//! | 언어 | Short | Medium | Long | 숫자 |
+//! |------|-------|--------|------|------|
+//! | en | 3/1/2024 | Mar 1, 2024 | March 1, 2024 | 1,234,567.5 |
+//! | ko | 2024. 3. 1. | 2024. 3. 1. | 2024년 3월 1일 | 1,234,567.5 |
+//! | ja | 2024/3/1 | 2024/03/01 | 2024年3月1日 | 1,234,567.5 |
+//! | zh | 2024/3/1 | 2024年3月1日 | 2024年3月1日 | 1,234,567.5 |
+//! | de | 01.03.2024 | 01.03.2024 | 1. März 2024 | 1.234.567,5 |
+//! | fr | 01/03/2024 | 1 mars 2024 | 1 mars 2024 | 1 234 567,5 |
+//! | es | 1/3/2024 | 1 mar 2024 | 1 de marzo de 2024 | 1.234.567,5 |
+//!
+//! ## 구현 상태
+//! - [x] 날짜 (`DateStyle`: Iso, Short, Medium, Long), 연월
+//! - [x] 정수/소수 자릿수 구분, 파일 크기 (1024 단위)
+//! - [x] 기계용 날짜 (`rfc3339`, `rfc822`)
+//! - [ ] TODO: 상대 시간 ("3일 전"), 사용자 정의 언어 표
+//!
+//! ## 설계 결정
+//! - **언어가 없으면 ISO**: 언어를 모르면 어느 나라 형식도 틀릴 수 있으므로 날짜는 `YYYY-MM-DD`,
+//!   숫자는 영어 형식으로 씁니다. 언어를 지정하지 않은 사이트의 출력은 바뀌지 않습니다.
+//! - **기본 스타일은 Long**: 언어가 있으면 월 이름을 쓰는 긴 형식이 가장 오해가 적습니다
+//!   (`3/1`이 3월 1일인지 1월 3일인지는 언어마다 다름).
+//! - **내장 표만**: 외부 로케일 데이터(CLDR) 없이 자주 쓰는 언어만 내장합니다. 모르는 언어는
+//!   주 언어 부분(`en-GB` → `en`)으로 다시 찾고, 그래도 없으면 영어 형식을 씁니다.
+//! - **`<time datetime>`은 그대로 ISO**: 이 모듈은 화면에 보이는 텍스트만 바꿉니다.
+
+use crate::metadata::map::ResolvedMetadata;
+use crate::metadata::types::{Date, Language};
+use crate::metadata::value::MetadataValue;
+
+/// 날짜 표시 형식. 사이트나 페이지 메타데이터로 지정합니다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DateStyle {
+    /// `2024-03-01` (언어 무관)
+    Iso,
+    /// 숫자만 (`3/1/2024`)
+    Short,
+    /// 줄인 월 이름 (`Mar 1, 2024`)
+    Medium,
+    /// 전체 월 이름 (`March 1, 2024`)
+    #[default]
+    Long,
+}
+impl MetadataValue for DateStyle {}
+
+/// 언어별 형식 표.
+///
+/// 날짜 패턴의 자리표시자: `{y}` 연도, `{m}`/`{mm}` 월 (두 자리), `{d}`/`{dd}` 일 (두 자리),
+/// `{month}` 월 이름, `{mon}` 줄인 월 이름.
+#[derive(Debug)]
+struct LocaleData {
+    language: &'static str,
+    months: [&'static str; 12],
+    months_short: [&'static str; 12],
+    short: &'static str,
+    medium: &'static str,
+    long: &'static str,
+    /// 연월 (아카이브 제목)
+    month: &'static str,
+    decimal: &'static str,
+    group: &'static str,
+    /// 자릿수 구분을 시작하는 정수 자릿수 (es: 10000부터)
+    min_grouping: usize,
+}
+
+const EN: LocaleData = LocaleData {
+    language: "en",
+    months: [
+        "January", "February", "March", "April", "May", "June", "July", "August", "September", "October",
+        "November", "December",
+    ],
+    months_short: ["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"],
+    short: "{m}/{d}/{y}",
+    medium: "{mon} {d}, {y}",
+    long: "{month} {d}, {y}",
+    month: "{month} {y}",
+    decimal: ".",
+    group: ",",
+    min_grouping: 4,
+};
+
+const LOCALES: &[LocaleData] = &[
+    EN,
+    LocaleData {
+        language: "ko",
+        months: ["1월", "2월", "3월", "4월", "5월", "6월", "7월", "8월", "9월", "10월", "11월", "12월"],
+        months_short: ["1월", "2월", "3월", "4월", "5월", "6월", "7월", "8월", "9월", "10월", "11월", "12월"],
+        short: "{y}. {m}. {d}.",
+        medium: "{y}. {m}. {d}.",
+        long: "{y}년 {month} {d}일",
+        month: "{y}년 {month}",
+        decimal: ".",
+        group: ",",
+        min_grouping: 4,
+    },
+    LocaleData {
+        language: "ja",
+        months: ["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"],
+        months_short: ["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"],
+        short: "{y}/{m}/{d}",
+        medium: "{y}/{mm}/{dd}",
+        long: "{y}年{month}{d}日",
+        month: "{y}年{month}",
+        decimal: ".",
+        group: ",",
+        min_grouping: 4,
+    },
+    LocaleData {
+        language: "zh",
+        months: ["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"],
+        months_short: ["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"],
+        short: "{y}/{m}/{d}",
+        medium: "{y}年{month}{d}日",
+        long: "{y}年{month}{d}日",
+        month: "{y}年{month}",
+        decimal: ".",
+        group: ",",
+        min_grouping: 4,
+    },
+    LocaleData {
+        language: "de",
+        months: [
+            "Januar", "Februar", "März", "April", "Mai", "Juni", "Juli", "August", "September", "Oktober",
+            "November", "Dezember",
+        ],
+        months_short: ["Jan.", "Feb.", "März", "Apr.", "Mai", "Juni", "Juli", "Aug.", "Sept.", "Okt.", "Nov.", "Dez."],
+        short: "{dd}.{mm}.{y}",
+        medium: "{dd}.{mm}.{y}",
+        long: "{d}. {month} {y}",
+        month: "{month} {y}",
+        decimal: ",",
+        group: ".",
+        min_grouping: 4,
+    },
+    LocaleData {
+        language: "fr",
+        months: [
+            "janvier", "février", "mars", "avril", "mai", "juin", "juillet", "août", "septembre", "octobre",
+            "novembre", "décembre",
+        ],
+        months_short: [
+            "janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août", "sept.", "oct.", "nov.", "déc.",
+        ],
+        short: "{dd}/{mm}/{y}",
+        medium: "{d} {mon} {y}",
+        long: "{d} {month} {y}",
+        month: "{month} {y}",
+        decimal: ",",
+        // 좁은 줄바꿈 없는 공백 (U+202F)
+        group: "\u{202f}",
+        min_grouping: 4,
+    },
+    LocaleData {
+        language: "es",
+        months: [
+            "enero", "febrero", "marzo", "abril", "mayo", "junio", "julio", "agosto", "septiembre", "octubre",
+            "noviembre", "diciembre",
+        ],
+        months_short: ["ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sept", "oct", "nov", "dic"],
+        short: "{d}/{m}/{y}",
+        medium: "{d} {mon} {y}",
+        long: "{d} de {month} de {y}",
+        month: "{month} de {y}",
+        decimal: ",",
+        group: ".",
+        min_grouping: 5,
+    },
+];
+
+/// 언어별 형식기.
+#[derive(Debug, Clone, Copy)]
+pub struct Locale {
+    data: &'static LocaleData,
+    style: DateStyle,
+}
+
+impl Default for Locale {
+    /// 언어 없음: 날짜는 ISO, 숫자는 영어 형식.
+    fn default() -> Self {
+        Locale {
+            data: &EN,
+            style: DateStyle::Iso,
+        }
+    }
+}
+
+impl Locale {
+    /// BCP 47 언어 태그 (`ko`, `en-US`). 내장하지 않은 언어는 영어 형식.
+    pub fn new(language: &str) -> Self {
+        let language = language.to_ascii_lowercase();
+        let primary = language.split(['-', '_']).next().unwrap_or_default();
+        let data = LOCALES.iter().find(|l| l.language == primary).unwrap_or(&EN);
+        Locale {
+            data,
+            style: DateStyle::default(),
+        }
+    }
+
+    /// 메타데이터의 `Language`와 `DateStyle`로 만들기. 언어가 없으면 `Locale::default()`.
+    pub fn from_metadata(metadata: &ResolvedMetadata) -> Self {
+        let locale = metadata
+            .get::<Language>()
+            .map_or_else(Locale::default, |l| Locale::new(&l.0));
+        match metadata.get::<DateStyle>() {
+            Some(style) => locale.style(*style),
+            None => locale,
+        }
+    }
+
+    /// 날짜 표시 형식.
+    pub fn style(mut self, style: DateStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// 날짜 (`DateStyle`에 따라).
+    pub fn date(&self, date: Date) -> String {
+        let pattern = match self.style {
+            DateStyle::Iso => return date.to_string(),
+            DateStyle::Short => self.data.short,
+            DateStyle::Medium => self.data.medium,
+            DateStyle::Long => self.data.long,
+        };
+        self.fill(pattern, date.year, date.month, date.day)
+    }
+
+    /// 연월 (아카이브 제목). `DateStyle::Iso`면 `2024-03`.
+    pub fn month(&self, year: i32, month: u8) -> String {
+        if self.style == DateStyle::Iso || !(1..=12).contains(&month) {
+            return format!("{:04}-{:02}", year, month);
+        }
+        self.fill(self.data.month, year, month, 1)
+    }
+
+    /// 자릿수를 구분한 정수 (`1,234,567`).
+    pub fn number(&self, value: i64) -> String {
+        let digits = self.group_digits(&value.unsigned_abs().to_string());
+        if value < 0 { format!("-{}", digits) } else { digits }
+    }
+
+    /// 소수점 아래 `precision` 자리까지 반올림한 수 (`1,234.5`).
+    pub fn decimal(&self, value: f64, precision: usize) -> String {
+        let formatted = format!("{:.*}", precision, value.abs());
+        let (int, frac) = formatted.split_once('.').unwrap_or((&formatted, ""));
+        let sign = if value < 0.0 && formatted.bytes().any(|b| b.is_ascii_digit() && b != b'0') {
+            "-"
+        } else {
+            ""
+        };
+        let int = self.group_digits(int);
+        if frac.is_empty() {
+            format!("{}{}", sign, int)
+        } else {
+            format!("{}{}{}{}", sign, int, self.data.decimal, frac)
+        }
+    }
+
+    /// 파일 크기 (`512 B`, `240.5 KB`, `1.4 MB`, 1024 단위).
+    pub fn file_size(&self, bytes: u64) -> String {
+        const UNITS: [&str; 3] = ["KB", "MB", "GB"];
+        if bytes < 1024 {
+            return format!("{} B", self.number(bytes as i64));
+        }
+        let mut value = bytes as f64 / 1024.0;
+        let mut unit = 0;
+        while value >= 1024.0 && unit < UNITS.len() - 1 {
+            value /= 1024.0;
+            unit += 1;
+        }
+        format!("{} {}", self.decimal(value, 1), UNITS[unit])
+    }
+
+    fn fill(&self, pattern: &str, year: i32, month: u8, day: u8) -> String {
+        let index = usize::from(month - 1);
+        pattern
+            .replace("{y}", &year.to_string())
+            .replace("{mm}", &format!("{:02}", month))
+            .replace("{m}", &month.to_string())
+            .replace("{dd}", &format!("{:02}", day))
+            .replace("{d}", &day.to_string())
+            .replace("{month}", self.data.months[index])
+            .replace("{mon}", self.data.months_short[index])
+    }
+
+    fn group_digits(&self, digits: &str) -> String {
+        if digits.len() < self.data.min_grouping {
+            return digits.to_string();
+        }
+        let mut out = String::new();
+        for (i, digit) in digits.chars().enumerate() {
+            if i > 0 && (digits.len() - i).is_multiple_of(3) {
+                out.push_str(self.data.group);
+            }
+            out.push(digit);
+        }
+        out
+    }
+}
+
+/// RFC 3339 날짜 (`2024-03-01T00:00:00Z`). Atom, JSON Feed.
+pub fn rfc3339(date: Date) -> String {
+    format!("{}T00:00:00Z", date)
+}
+
+/// RFC 822 날짜 (`Fri, 01 Mar 2024 00:00:00 +0000`). RSS. 언어와 관계없이 영어 이름을 씁니다.
+pub fn rfc822(date: Date) -> String {
+    const WEEKDAYS: [&str; 7] = ["Thu", "Fri", "Sat", "Sun", "Mon", "Tue", "Wed"];
+    // 1970-01-01은 목요일
+    let weekday = WEEKDAYS[date.to_unix_days().rem_euclid(7) as usize];
+    format!(
+        "{}, {:02} {} {:04} 00:00:00 +0000",
+        weekday,
+        date.day,
+        EN.months_short[usize::from(date.month - 1)],
+        date.year
+    )
+}
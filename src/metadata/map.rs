@@ -0,0 +1,145 @@
+//! # map.rs - MetadataMap 타입 안전 저장소
+//!
+//! ## 목적
+//! TypeId를 키로 사용하여 임의의 사용자 타입을 저장하고, 타입 안전하게 꺼내 씁니다.
+//!
+//! ## 구조
+//! ```text
+//! MetadataMap
+//! └─ data: HashMap<TypeId, Entry>
+//!    └─ Entry { value: Arc<dyn Any>, merge: Option<fn>, type_name }
+//! ```
+//!
+//! ## 설계 결정
+//! - **Arc<dyn Any>**: 문서의 `Box<dyn Any>` 대신 Arc 사용. `Box<dyn Any>`는 Clone이
+//!   불가능하여 병합 시 복사할 수 없습니다. Arc는 `SharedAttrs`와 같이 cheap clone.
+//! - **merge 함수 포인터**: `MergeableMetadata`로 삽입한 값은 타입 정보가 지워진 뒤에도
+//!   부분 병합이 가능하도록 삽입 시점에 병합 함수를 함께 저장합니다.
+//! - **type_name 보관**: 디버그 출력용. `type_names()`로 확인.
+
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    sync::Arc,
+};
+
+use crate::metadata::{merge::MergeableMetadata, value::MetadataValue};
+
+type AnyValue = Arc<dyn Any + Send + Sync>;
+
+/// 타입 정보가 지워진 부분 병합 함수. (가까운 값, 상위 값) → 병합 결과
+type MergeFn = fn(&AnyValue, &AnyValue) -> AnyValue;
+
+#[derive(Clone)]
+struct Entry {
+    value: AnyValue,
+    merge: Option<MergeFn>,
+    type_name: &'static str,
+}
+
+/// TypeId 기반 타입 안전 메타데이터 저장소.
+#[derive(Clone, Default)]
+pub struct MetadataMap {
+    data: HashMap<TypeId, Entry>,
+}
+
+/// 계층 병합(Site → Page → Block)이 끝난 메타데이터.
+pub type ResolvedMetadata = MetadataMap;
+
+impl MetadataMap {
+    pub fn new() -> Self {
+        MetadataMap {
+            data: HashMap::new(),
+        }
+    }
+
+    /// 값 삽입. 같은 타입의 기존 값은 덮어씁니다.
+    pub fn insert<T: MetadataValue>(&mut self, value: T) {
+        self.data.insert(
+            TypeId::of::<T>(),
+            Entry {
+                value: Arc::new(value),
+                merge: None,
+                type_name: std::any::type_name::<T>(),
+            },
+        );
+    }
+
+    /// 부분 병합 가능한 값 삽입. 병합 시 `merge_with`가 호출됩니다.
+    pub fn insert_mergeable<T: MergeableMetadata>(&mut self, value: T) {
+        fn merge<T: MergeableMetadata>(near: &AnyValue, base: &AnyValue) -> AnyValue {
+            match (near.downcast_ref::<T>(), base.downcast_ref::<T>()) {
+                (Some(near), Some(base)) => Arc::new(near.merge_with(base)),
+                _ => near.clone(),
+            }
+        }
+
+        self.data.insert(
+            TypeId::of::<T>(),
+            Entry {
+                value: Arc::new(value),
+                merge: Some(merge::<T>),
+                type_name: std::any::type_name::<T>(),
+            },
+        );
+    }
+
+    /// 타입 안전 접근. 없으면 `None`.
+    pub fn get<T: MetadataValue>(&self) -> Option<&T> {
+        self.data
+            .get(&TypeId::of::<T>())
+            .and_then(|entry| entry.value.downcast_ref::<T>())
+    }
+
+    pub fn contains<T: MetadataValue>(&self) -> bool {
+        self.data.contains_key(&TypeId::of::<T>())
+    }
+
+    pub fn remove<T: MetadataValue>(&mut self) -> Option<T> {
+        self.data
+            .remove(&TypeId::of::<T>())
+            .and_then(|entry| entry.value.downcast_ref::<T>().cloned())
+    }
+
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// 다른 맵을 위에 덮어 병합한 새 맵 반환 ("가까운 곳이 우선").
+    ///
+    /// # 병합 규칙
+    /// - 기본: `other`의 값이 `self`의 값을 완전히 덮어씀
+    /// - `insert_mergeable`로 삽입된 값: `other.merge_with(self)` 결과 사용
+    pub fn merge(&self, other: &MetadataMap) -> MetadataMap {
+        let mut data = self.data.clone();
+        for (type_id, near) in other.data.iter() {
+            let merged = match (near.merge, data.get(type_id)) {
+                (Some(merge), Some(base)) => Entry {
+                    value: merge(&near.value, &base.value),
+                    merge: near.merge,
+                    type_name: near.type_name,
+                },
+                _ => near.clone(),
+            };
+            data.insert(*type_id, merged);
+        }
+        MetadataMap { data }
+    }
+
+    /// 저장된 타입 이름 목록 (디버그용, 정렬됨).
+    pub fn type_names(&self) -> Vec<&'static str> {
+        let mut names: Vec<_> = self.data.values().map(|entry| entry.type_name).collect();
+        names.sort();
+        names
+    }
+}
+
+impl std::fmt::Debug for MetadataMap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.type_names()).finish()
+    }
+}
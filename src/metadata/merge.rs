@@ -0,0 +1,37 @@
+//! # merge.rs - 부분 병합 트레이트
+//!
+//! ## 목적
+//! 기본 병합 규칙(완전 덮어쓰기) 대신 부분 병합이 필요한 타입을 위한 확장점입니다.
+//!
+//! ## 병합 방향
+//! ```text
+//! base (Site)  ← 상위 계층 값
+//! self (Page)  ← 가까운 계층 값 (우선)
+//! 결과 = self.merge_with(base)
+//! ```
+//!
+//! ## 사용 예시
+//! ```rust
+//! #[derive(Clone)]
+//! pub struct CssVars(BTreeMap<String, String>);
+//! impl MetadataValue for CssVars {}
+//!
+//! impl MergeableMetadata for CssVars {
+//!     fn merge_with(&self, base: &Self) -> Self {
+//!         let mut vars = base.0.clone();
+//!         vars.extend(self.0.clone());  // 일부만 오버라이드
+//!         CssVars(vars)
+//!     }
+//! }
+//!
+//! let meta = Metadata::new().mergeable(CssVars(vars));
+//! ```
+
+use crate::metadata::value::MetadataValue;
+
+/// 부분 병합이 가능한 메타데이터.
+/// `Metadata::mergeable()`로 삽입한 값만 이 규칙으로 병합됩니다.
+pub trait MergeableMetadata: MetadataValue {
+    /// 상위 계층 값(`base`) 위에 자신을 병합한 새 값 반환.
+    fn merge_with(&self, base: &Self) -> Self;
+}
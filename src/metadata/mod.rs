@@ -207,21 +207,27 @@
 //! ├─ value.rs         - MetadataValue 트레이트
 //! ├─ builder.rs       - Metadata 빌더 패턴
 //! ├─ merge.rs         - 병합 로직, MergeableMetadata
+//! ├─ types.rs         - 라이브러리 기본 메타데이터 타입 (Title, Tags, PublishDate 등)
+//! ├─ format.rs        - 언어별 날짜, 숫자, 파일 크기 형식 (Locale)
 //! └─ prelude.rs       - 자주 쓰는 타입 재수출
 //! ```
 //!
 //! ## 구현 상태
 //!
 //! ### 우선순위: 높음 (필수)
-//! - [ ] MetadataMap 구조체 및 기본 메서드
-//! - [ ] MetadataValue 트레이트
-//! - [ ] Metadata 빌더
-//! - [ ] 기본 병합 로직
+//! - [x] MetadataMap 구조체 및 기본 메서드
+//! - [x] MetadataValue 트레이트
+//! - [x] Metadata 빌더
+//! - [x] 기본 병합 로직
 //!
 //! ### 우선순위: 중간 (중요)
-//! - [ ] MergeableMetadata 트레이트 (선택적 부분 병합)
-//! - [ ] 디버그 헬퍼 (debug_dump, type_names)
+//! - [x] MergeableMetadata 트레이트 (선택적 부분 병합)
+//! - [x] 디버그 헬퍼 (type_names)
 //! - [ ] 라이브러리 기본 메타데이터 타입 (Theme, Layout 등)
+//!   - [x] 페이지 기본 정보 (Title, Description, Tags, Categories, PublishDate)
+//!   - [x] 문서 언어, 페이지네이션, 필요 asset (Language, Paging, Assets, CriticalCss)
+//!   - [x] 발행 상태, 리다이렉트 (PageStatus, Redirect)
+//!   - [x] 언어별 표시 형식 (Locale, DateStyle)
 //!
 //! ### 우선순위: 낮음 (향상)
 //! - [ ] 메타데이터 검증
@@ -289,3 +295,11 @@
 //! - 저장: `HashMap<TypeId, Box<dyn Any>>`
 //! - 사용: 렌더링 방식 결정, 전역 기능
 //!
+
+pub mod value;
+pub mod merge;
+pub mod map;
+pub mod builder;
+pub mod types;
+pub mod format;
+pub mod prelude;
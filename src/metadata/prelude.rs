@@ -0,0 +1,15 @@
+//! # prelude.rs - 자주 쓰는 타입 재수출
+//!
+//! ```rust
+//! use quo::metadata::prelude::*;
+//! ```
+
+pub use crate::metadata::builder::Metadata;
+pub use crate::metadata::format::{DateStyle, Locale};
+pub use crate::metadata::map::{MetadataMap, ResolvedMetadata};
+pub use crate::metadata::merge::MergeableMetadata;
+pub use crate::metadata::types::{
+    Assets, Categories, CriticalCss, CurrentUrl, Date, Description, Language, LastModified, Numbered, NumberedKeys,
+    PageStatus, Paging, PublishDate, Redirect, RenderTarget, Series, SourceFiles, Tags, Title,
+};
+pub use crate::metadata::value::MetadataValue;
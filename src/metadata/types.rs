@@ -0,0 +1,505 @@
+//! # types.rs - 라이브러리 기본 메타데이터 타입
+//!
+//! ## 목적
+//! 여러 계층이 공통으로 사용하는 페이지 정보를 표준 타입으로 제공합니다.
+//! 컬렉션, 피드, 사이트맵 등 Cite 계층 기능이 이 타입들을 읽습니다.
+//!
+//! ## 제공 타입
+//! - `Title`: 페이지/문서 제목
+//! - `Description`: 요약 (meta description, 피드 요약)
+//! - `Tags`, `Categories`: 컬렉션 분류
+//! - `PublishDate`: 발행일 (`Date`)
+//! - `LastModified`: 마지막 수정일 (지정하지 않으면 Site가 `SourceFiles`로부터 주입)
+//! - `SourceFiles`: 페이지를 만든 원본 파일 (수정일 계산용)
+//! - `Language`: 문서 언어 (`<html lang>`)
+//! - `TextDirection`: 글 방향 (`<html dir>`, 지정하지 않으면 `Language`로 결정)
+//! - `Paging`: 페이지네이션 정보 (Cite 컬렉션 페이지네이터가 주입)
+//! - `Assets`: 필요한 CSS/JS (Block이 선언, Cite가 수집하여 head에 주입)
+//! - `CriticalCss`: head에 인라인할 핵심 CSS (나머지 스타일시트는 지연 로드)
+//! - `PageStatus`: 초안/발행/예약 상태 (Site 빌드가 출력 여부 결정)
+//! - `Redirect`: 리다이렉트 매핑 (호스트별 리다이렉트 파일 생성용)
+//! - `Unlisted`: sitemap, 피드, 검색 색인에서 뺄 페이지 (`Listing`별로 지정, 페이지는 그대로 출력)
+//! - `CurrentUrl`: 렌더링 중인 페이지의 URL 경로 (활성 링크 판정용, 렌더링 시 주입)
+//! - `RenderTarget`: 렌더링 대상 (화면/인쇄/대체 출력, 렌더링 시 주입)
+//! - `Numbered`: 자동 번호 대상 종류 (Block이 선언, Cite가 페이지별 번호 부여)
+//! - `NumberedKeys`: 키별 자동 번호 (같은 키는 페이지에서 번호 하나)
+//! - `Series`: 연재 소속과 순서 (Cite 컬렉션이 같은 연재의 페이지를 묶음)
+//! - `Weight`: 메뉴와 목록에서의 순서 (작을수록 앞, `SortKey::Weight`)
+//!
+//! ## 사용 예시
+//! ```rust
+//! let meta = Metadata::new()
+//!     .title("Hello Quo")
+//!     .tags(vec!["rust", "ssg"])
+//!     .date(Date::parse("2024-03-01").unwrap());
+//!
+//! let title = meta.get::<Title>().map(|t| t.0.as_str());
+//! ```
+
+use std::{collections::BTreeSet, fmt::Display, path::{Path, PathBuf}};
+
+use crate::metadata::merge::MergeableMetadata;
+use crate::metadata::value::MetadataValue;
+
+/// 페이지/문서 제목.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Title(pub String);
+impl MetadataValue for Title {}
+
+/// 페이지 요약. meta description, 피드 요약 등에 사용.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Description(pub String);
+impl MetadataValue for Description {}
+
+/// 태그 목록.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Tags(pub Vec<String>);
+impl MetadataValue for Tags {}
+
+/// 카테고리 목록.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Categories(pub Vec<String>);
+impl MetadataValue for Categories {}
+
+/// 발행일.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PublishDate(pub Date);
+impl MetadataValue for PublishDate {}
+
+/// 마지막 수정일. sitemap `<lastmod>`, 피드 갱신일에 쓰입니다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LastModified(pub Date);
+impl MetadataValue for LastModified {}
+
+/// 페이지를 만든 원본 파일 경로. Site가 수정일(`LastModified`)을 계산할 때 읽습니다.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Hash)]
+pub struct SourceFiles(pub Vec<PathBuf>);
+impl MetadataValue for SourceFiles {}
+
+impl SourceFiles {
+    pub fn new() -> Self {
+        SourceFiles::default()
+    }
+
+    pub fn file<P: AsRef<Path>>(mut self, path: P) -> Self {
+        self.0.push(path.as_ref().to_path_buf());
+        self
+    }
+}
+
+/// 문서 언어 (BCP 47). 예: "ko", "en-US"
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Language(pub String);
+impl MetadataValue for Language {}
+
+impl Language {
+    /// 언어의 글 방향.
+    pub fn direction(&self) -> TextDirection {
+        TextDirection::of(&self.0)
+    }
+}
+
+/// 오른쪽에서 왼쪽으로 쓰는 언어 (BCP 47 주 언어 하위 태그).
+const RTL_LANGUAGES: &[&str] = &[
+    "ar", "arc", "ckb", "dv", "fa", "he", "iw", "ji", "khw", "ks", "nqo", "ps", "sd", "syr", "ug",
+    "ur", "yi",
+];
+
+/// 오른쪽에서 왼쪽으로 쓰는 문자 체계 (ISO 15924 하위 태그, 소문자).
+const RTL_SCRIPTS: &[&str] = &["adlm", "arab", "hebr", "mand", "nkoo", "rohg", "samr", "syrc", "thaa"];
+
+/// 글 방향 (`dir`). 지정하지 않으면 문서 언어로 정합니다 (`RenderContext::direction`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum TextDirection {
+    #[default]
+    Ltr,
+    Rtl,
+}
+impl MetadataValue for TextDirection {}
+
+impl TextDirection {
+    /// BCP 47 언어 태그의 글 방향. 문자 체계 하위 태그가 있으면 그것으로 (`az-Arab`은 RTL, `ar-Latn`은 LTR),
+    /// 없으면 주 언어로 정합니다. 모르는 언어는 LTR.
+    pub fn of(language: &str) -> Self {
+        let mut subtags = language.split(['-', '_']).map(|s| s.to_ascii_lowercase());
+        let primary = subtags.next().unwrap_or_default();
+        let script = subtags.next().filter(|s| s.len() == 4 && s.chars().all(|c| c.is_ascii_alphabetic()));
+        let rtl = match script {
+            Some(script) => RTL_SCRIPTS.contains(&script.as_str()),
+            None => RTL_LANGUAGES.contains(&primary.as_str()),
+        };
+        match rtl {
+            true => TextDirection::Rtl,
+            false => TextDirection::Ltr,
+        }
+    }
+
+    /// `dir` 속성값.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TextDirection::Ltr => "ltr",
+            TextDirection::Rtl => "rtl",
+        }
+    }
+}
+
+/// 페이지네이션 정보. 여러 페이지로 나뉜 목록의 한 페이지에 주입됩니다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Paging {
+    /// 현재 페이지 번호 (1부터)
+    pub current: usize,
+    /// 페이지별 URL. `urls[0]`이 1페이지.
+    pub urls: Vec<String>,
+}
+impl MetadataValue for Paging {}
+
+impl Paging {
+    /// `current`가 범위를 벗어나면 `None`.
+    pub fn new(current: usize, urls: Vec<String>) -> Option<Self> {
+        if current == 0 || current > urls.len() {
+            return None;
+        }
+        Some(Paging { current, urls })
+    }
+
+    pub fn total(&self) -> usize {
+        self.urls.len()
+    }
+
+    /// n페이지(1부터)의 URL.
+    pub fn url(&self, n: usize) -> Option<&str> {
+        n.checked_sub(1)
+            .and_then(|i| self.urls.get(i))
+            .map(|u| u.as_str())
+    }
+
+    pub fn prev_url(&self) -> Option<&str> {
+        self.url(self.current - 1)
+    }
+
+    pub fn next_url(&self) -> Option<&str> {
+        self.url(self.current + 1)
+    }
+}
+
+/// Block/Page가 필요로 하는 CSS/JS 파일 (URL 경로). 병합 시 합집합 (순서 유지, 중복 제거).
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Assets {
+    pub stylesheets: Vec<String>,
+    pub scripts: Vec<String>,
+}
+impl MetadataValue for Assets {}
+
+impl MergeableMetadata for Assets {
+    fn merge_with(&self, base: &Self) -> Self {
+        base.union(self)
+    }
+}
+
+impl Assets {
+    pub fn new() -> Self {
+        Assets::default()
+    }
+
+    pub fn stylesheet(mut self, href: &str) -> Self {
+        if !self.stylesheets.iter().any(|s| s == href) {
+            self.stylesheets.push(href.to_string());
+        }
+        self
+    }
+
+    pub fn script(mut self, src: &str) -> Self {
+        if !self.scripts.iter().any(|s| s == src) {
+            self.scripts.push(src.to_string());
+        }
+        self
+    }
+
+    /// `self` 뒤에 `other`를 이어 붙인 합집합.
+    pub fn union(&self, other: &Assets) -> Assets {
+        let merged = other
+            .stylesheets
+            .iter()
+            .fold(self.clone(), |acc, href| acc.stylesheet(href));
+        other.scripts.iter().fold(merged, |acc, src| acc.script(src))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.stylesheets.is_empty() && self.scripts.is_empty()
+    }
+}
+
+/// 첫 화면 렌더링에 필요한 핵심 CSS. 지정되면 `<style>`로 인라인되고
+/// 나머지 스타일시트는 preload로 지연 로드됩니다. 병합 시 상위 계층 CSS 뒤에 이어 붙습니다.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct CriticalCss(pub String);
+impl MetadataValue for CriticalCss {}
+
+impl MergeableMetadata for CriticalCss {
+    fn merge_with(&self, base: &Self) -> Self {
+        if base.0.is_empty() {
+            return self.clone();
+        }
+        CriticalCss(format!("{}\n{}", base.0, self.0))
+    }
+}
+
+/// 페이지 발행 상태.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PageStatus {
+    /// 초안. `include_drafts` 빌드에서만 출력
+    Draft,
+    /// 발행됨 (기본)
+    #[default]
+    Published,
+    /// 지정한 날짜부터 발행
+    Scheduled(Date),
+}
+impl MetadataValue for PageStatus {}
+
+impl PageStatus {
+    /// `today` 기준으로 발행된 상태인지.
+    pub fn is_published_on(&self, today: Date) -> bool {
+        match self {
+            PageStatus::Draft => false,
+            PageStatus::Published => true,
+            PageStatus::Scheduled(date) => *date <= today,
+        }
+    }
+}
+
+/// 리다이렉트 매핑. `from`은 사이트 루트 기준 URL 경로, `to`는 경로 또는 절대 URL.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Redirect {
+    pub from: String,
+    pub to: String,
+    /// 영구 이동(301) 여부. false면 302
+    pub permanent: bool,
+}
+impl MetadataValue for Redirect {}
+
+/// 페이지 목록을 담는 전역 파일 종류. `Unlisted`가 페이지를 뺄 대상입니다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Listing {
+    /// `sitemap.xml` (`SitemapGenerator`)
+    Sitemap,
+    /// 모든 구독 피드 (`FeedGenerator`)
+    Feeds,
+    /// 검색 색인 `search.json` (`SearchIndexGenerator`)
+    Search,
+}
+
+impl Listing {
+    pub const ALL: [Listing; 3] = [Listing::Sitemap, Listing::Feeds, Listing::Search];
+
+    /// 머리말 이름 (`sitemap`, `feeds`/`feed`, `search`).
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "sitemap" => Some(Listing::Sitemap),
+            "feeds" | "feed" => Some(Listing::Feeds),
+            "search" => Some(Listing::Search),
+            _ => None,
+        }
+    }
+}
+
+/// 전역 파일에서 뺄 페이지. 랜딩 페이지 변형, 약관처럼 출력은 하되 목록에는 드러내지 않을 페이지에 씁니다.
+///
+/// 페이지 문서는 그대로 출력되고 링크로 찾아갈 수 있습니다. 검색 엔진 색인까지 막으려면
+/// `<meta name="robots" content="noindex">`를 따로 넣습니다.
+///
+/// ```rust
+/// Metadata::new().custom(Unlisted::all());                       // sitemap, 피드, 검색 모두
+/// Metadata::new().custom(Unlisted::new().from(Listing::Feeds));  // 피드에서만
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Unlisted(pub BTreeSet<Listing>);
+impl MetadataValue for Unlisted {}
+
+impl Unlisted {
+    /// 아무 곳에서도 빼지 않음. `from`으로 대상을 추가합니다.
+    pub fn new() -> Self {
+        Unlisted::default()
+    }
+
+    /// 모든 전역 파일에서 뺌.
+    pub fn all() -> Self {
+        Unlisted(Listing::ALL.into_iter().collect())
+    }
+
+    /// 뺄 대상 추가.
+    pub fn from(mut self, listing: Listing) -> Self {
+        self.0.insert(listing);
+        self
+    }
+
+    pub fn excludes(&self, listing: Listing) -> bool {
+        self.0.contains(&listing)
+    }
+}
+
+/// 렌더링 중인 페이지의 사이트 루트 기준 URL 경로. 예: `/blog/post.html`, `/blog/`
+///
+/// 문서 렌더링 시 출력 경로로부터 자동 주입되며, 네비게이션의 활성 링크 판정에 사용합니다.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CurrentUrl(pub String);
+impl MetadataValue for CurrentUrl {}
+
+/// 렌더링 대상. 같은 Block 트리를 대상별로 다르게 렌더링할 때 `ctx.target()`으로 읽습니다.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub enum RenderTarget {
+    /// 기본 화면용 문서
+    #[default]
+    Screen,
+    /// 인쇄용 문서 (네비게이션 등 화면 전용 요소 제외)
+    Print,
+    /// 사용자 정의 대체 출력. 이름이 파일 이름 접미사가 됩니다.
+    Alternate(String),
+}
+impl MetadataValue for RenderTarget {}
+
+impl RenderTarget {
+    /// 출력 파일 이름 접미사. 기본 대상이면 `None`.
+    pub fn suffix(&self) -> Option<&str> {
+        match self {
+            RenderTarget::Screen => None,
+            RenderTarget::Print => Some("print"),
+            RenderTarget::Alternate(name) => Some(name),
+        }
+    }
+}
+
+/// 자동 번호 대상 종류. 예: "figure", "table", "footnote"
+///
+/// Block이 메타데이터로 선언하고 `id()`를 제공하면 페이지 안에서 종류별로 1부터 번호가 매겨집니다.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Numbered(pub String);
+impl MetadataValue for Numbered {}
+
+/// 키로 번호를 받는 대상. `Numbered`와 함께 선언합니다.
+///
+/// `id()` 대신 키마다 번호가 매겨지고, 같은 페이지에서 다시 나온 키는 처음 받은 번호를 그대로 씁니다.
+/// 예: 인용(`Citation`)은 같은 문헌을 여러 번 인용해도 번호가 하나입니다.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NumberedKeys(pub Vec<String>);
+impl MetadataValue for NumberedKeys {}
+
+/// 연재(여러 편으로 나눈 글) 소속. 같은 이름의 페이지가 한 연재로 묶입니다.
+///
+/// 순서는 `part`(작은 것부터), 없으면 발행일(오래된 것부터)입니다. `part`는 정렬에만 쓰고
+/// 화면의 "몇 편"은 연재 안의 실제 위치입니다.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Series {
+    pub name: String,
+    pub part: Option<usize>,
+}
+impl MetadataValue for Series {}
+
+impl Series {
+    pub fn new(name: &str) -> Self {
+        Series {
+            name: name.to_string(),
+            part: None,
+        }
+    }
+
+    /// 연재 안의 순서 (1부터).
+    pub fn part(mut self, part: usize) -> Self {
+        self.part = Some(part);
+        self
+    }
+}
+
+/// 메뉴와 목록에서의 순서. 작을수록 앞에 오고, 없는 페이지는 뒤로 갑니다.
+///
+/// `Site::nav_links`가 같은 단계의 링크를 이 순서로 놓고, 컬렉션 질의는 `SortKey::Weight`로 씁니다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Weight(pub i32);
+impl MetadataValue for Weight {}
+
+/// 달력 날짜 (시간대 없음). `YYYY-MM-DD` 형식.
+///
+/// 외부 날짜 크레이트 없이 정렬/비교/출력만 지원합니다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    pub year: i32,
+    pub month: u8,
+    pub day: u8,
+}
+
+impl Date {
+    /// 유효한 날짜면 `Some` 반환.
+    pub fn new(year: i32, month: u8, day: u8) -> Option<Self> {
+        if !(1..=12).contains(&month) || day == 0 || day > days_in_month(year, month) {
+            return None;
+        }
+        Some(Date { year, month, day })
+    }
+
+    /// `YYYY-MM-DD` 파싱. 뒤에 시간(`T...`, ` ...`)이 붙어 있으면 무시합니다.
+    pub fn parse(s: &str) -> Option<Self> {
+        let date_part = s.trim().split(['T', ' ']).next()?;
+        let mut parts = date_part.splitn(3, '-');
+        let year = parts.next()?.parse().ok()?;
+        let month = parts.next()?.parse().ok()?;
+        let day = parts.next()?.parse().ok()?;
+        Date::new(year, month, day)
+    }
+
+    /// 1970-01-01부터의 일수로 날짜 계산 (UTC).
+    pub fn from_unix_days(days: i64) -> Self {
+        // civil_from_days (Howard Hinnant)
+        let z = days + 719_468;
+        let era = z.div_euclid(146_097);
+        let doe = z.rem_euclid(146_097);
+        let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = (doy - (153 * mp + 2) / 5 + 1) as u8;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 } as u8;
+        let year = (yoe + era * 400 + i64::from(month <= 2)) as i32;
+        Date { year, month, day }
+    }
+
+    /// 1970-01-01부터의 일수 (`from_unix_days`의 역변환).
+    pub fn to_unix_days(&self) -> i64 {
+        // days_from_civil (Howard Hinnant)
+        let year = i64::from(self.year) - i64::from(self.month <= 2);
+        let era = year.div_euclid(400);
+        let yoe = year.rem_euclid(400);
+        let month = i64::from(self.month);
+        let mp = if month > 2 { month - 3 } else { month + 9 };
+        let doy = (153 * mp + 2) / 5 + i64::from(self.day) - 1;
+        let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+        era * 146_097 + doe - 719_468
+    }
+
+    /// 오늘 날짜 (UTC, 시스템 시계 기준).
+    pub fn today() -> Self {
+        let secs = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs() as i64)
+            .unwrap_or(0);
+        Date::from_unix_days(secs.div_euclid(86_400))
+    }
+}
+
+impl Display for Date {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+fn is_leap_year(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+pub(crate) fn days_in_month(year: i32, month: u8) -> u8 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        _ => 0,
+    }
+}
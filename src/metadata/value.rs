@@ -0,0 +1,23 @@
+//! # value.rs - MetadataValue 트레이트
+//!
+//! ## 목적
+//! 메타데이터로 저장할 수 있는 타입을 표시하는 마커 트레이트입니다.
+//!
+//! ## 요구사항
+//! - `Clone`: 병합 시 값 복사
+//! - `Send + Sync`: 빌드 단계 간 공유 (향후 병렬 빌드)
+//! - `'static`: TypeId 사용
+//!
+//! ## 사용 예시
+//! ```rust
+//! #[derive(Clone)]
+//! pub struct SyntaxTheme(String);
+//!
+//! impl MetadataValue for SyntaxTheme {}
+//! ```
+
+use std::any::Any;
+
+/// 메타데이터로 사용 가능한 타입의 마커 트레이트.
+/// 타입 자체가 저장소의 키가 됩니다.
+pub trait MetadataValue: Any + Clone + Send + Sync {}
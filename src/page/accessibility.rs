@@ -0,0 +1,273 @@
+//! # accessibility.rs - 건너뛰기 링크와 랜드마크
+//!
+//! ## 목적
+//! 생성된 사이트가 기본 접근성 검사를 통과하도록 문서 조립 단계에서 본문을 보정합니다.
+//! - "본문으로 건너뛰기" 링크를 `<body>` 맨 앞에 추가
+//! - `<main>`이 정확히 하나가 되도록 보장 (없으면 본문을 감싸고, 여럿이면 첫 번째만 유지)
+//! - 이름 없는 `<nav>`, `<aside>`에 `aria-label` 부여
+//!
+//! ## 사용 예시
+//! ```rust
+//! // 기본값으로 모두 적용. 페이지/사이트 메타데이터로 조정
+//! Metadata::new().custom(Accessibility::new().skip_link("본문 바로가기"));
+//! Metadata::new().custom(Accessibility::disabled());
+//! ```
+//!
+//! ## 렌더링 결과
+//! ```html
+//! <body>
+//!   <a class="skip-link" href="#main-content">Skip to main content</a>
+//!   <div class="site">
+//!     <header class="site-header"><nav aria-label="Site" class="nav-menu">...</nav></header>
+//!     <main class="site-main" id="main-content">...</main>
+//!     <footer class="site-footer"><nav aria-label="Footer">...</nav></footer>
+//!   </div>
+//! </body>
+//! ```
+//!
+//! ## 설계 결정
+//! - **기본 적용**: 메타데이터가 없어도 적용됩니다. 끄려면 `Accessibility::disabled()`.
+//! - **기존 값 존중**: `<main>`에 이미 id가 있으면 그 id로 연결하고,
+//!   `aria-label`/`aria-labelledby`가 있는 랜드마크는 건드리지 않습니다.
+//! - **랜드마크 이름**: header 안의 nav는 "Site", footer 안은 "Footer", 그 외 "Navigation",
+//!   aside는 "Sidebar". 같은 이름이 겹치면 번호를 붙여 구분합니다.
+
+use std::collections::HashMap;
+
+use crate::block::block::{
+    attr_token, class_attrs, element, prose_rule, token_attrs, with_tokens,
+};
+use crate::html::attributes::SharedAttrs;
+use crate::html::node::{Element, ElementType, IRNode};
+use crate::html::trust::{Content, SafeString, TagName};
+use crate::metadata::value::MetadataValue;
+
+/// 접근성 보정 옵션. 페이지/사이트 메타데이터로 지정합니다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Accessibility {
+    /// 건너뛰기 링크 텍스트. `None`이면 생략
+    pub skip_link: Option<String>,
+    /// `<main>`에 id가 없을 때 부여할 id
+    pub main_id: String,
+    /// `<main>`을 정확히 하나로 보장
+    pub single_main: bool,
+    /// 이름 없는 nav/aside에 `aria-label` 부여
+    pub label_landmarks: bool,
+}
+impl MetadataValue for Accessibility {}
+
+impl Default for Accessibility {
+    fn default() -> Self {
+        Accessibility {
+            skip_link: Some("Skip to main content".to_string()),
+            main_id: "main-content".to_string(),
+            single_main: true,
+            label_landmarks: true,
+        }
+    }
+}
+
+impl Accessibility {
+    /// 건너뛰기 링크가 포커스를 받을 때만 보이도록 하는 CSS.
+    pub const CSS: &'static str = ".skip-link{position:absolute;inset-inline-start:-9999px;top:0}.skip-link:focus{inset-inline-start:1rem;top:1rem;z-index:1000}";
+
+    pub fn new() -> Self {
+        Accessibility::default()
+    }
+
+    /// 모든 보정을 끈 옵션.
+    pub fn disabled() -> Self {
+        Accessibility {
+            skip_link: None,
+            main_id: "main-content".to_string(),
+            single_main: false,
+            label_landmarks: false,
+        }
+    }
+
+    pub fn skip_link(mut self, label: &str) -> Self {
+        self.skip_link = Some(label.to_string());
+        self
+    }
+
+    pub fn no_skip_link(mut self) -> Self {
+        self.skip_link = None;
+        self
+    }
+
+    pub fn main_id(mut self, id: &str) -> Self {
+        self.main_id = id.to_string();
+        self
+    }
+
+    pub fn single_main(mut self, enabled: bool) -> Self {
+        self.single_main = enabled;
+        self
+    }
+
+    pub fn label_landmarks(mut self, enabled: bool) -> Self {
+        self.label_landmarks = enabled;
+        self
+    }
+
+    /// `<body>` 내용을 보정하여 `<body>`의 자식 목록으로 반환.
+    pub(crate) fn apply(&self, body: IRNode) -> Vec<Element> {
+        let mut body = body;
+        if self.single_main {
+            body = match count_tag(&body, "main") {
+                0 => element(
+                    "main",
+                    token_attrs(&[("id", &self.main_id)]),
+                    vec![Element::Node(body)],
+                ),
+                _ => keep_first_main(body, &mut false),
+            };
+        }
+        if self.label_landmarks {
+            let mut used = HashMap::new();
+            collect_labels(&body, &mut used);
+            body = label_landmarks(body, None, &mut used);
+        }
+
+        let main_id = self.ensure_main_id(&mut body);
+        let mut childs = Vec::new();
+        if let (Some(label), Some(id)) = (&self.skip_link, main_id) {
+            childs.push(Element::Node(element(
+                "a",
+                with_tokens(class_attrs(&["skip-link"]), &[("href", &format!("#{}", id))]),
+                vec![Element::Text(Content::from_str(label, &prose_rule()))],
+            )));
+        }
+        childs.push(Element::Node(body));
+        childs
+    }
+
+    /// 첫 `<main>`의 id (없으면 부여). `<main>`이 없으면 `None`.
+    fn ensure_main_id(&self, body: &mut IRNode) -> Option<String> {
+        let mut id = None;
+        *body = map_first(body.clone(), "main", &mut |main| {
+            let existing = attr_token(&main, "id");
+            id = Some(existing.clone().unwrap_or_else(|| self.main_id.clone()));
+            if existing.is_some() {
+                return main;
+            }
+            rebuild(
+                &main,
+                None,
+                Some(with_tokens(main.get_attrs().clone(), &[("id", &self.main_id)])),
+                main.get_childs().to_vec(),
+            )
+        });
+        id
+    }
+}
+
+fn rebuild(node: &IRNode, tag: Option<&str>, attrs: Option<SharedAttrs>, childs: Vec<Element>) -> IRNode {
+    let tag = tag.map(TagName::from_str).unwrap_or_else(|| node.get_tag().clone());
+    let attrs = attrs.unwrap_or_else(|| node.get_attrs().clone());
+    let rebuilt = match node.get_type() {
+        // Void 노드의 자식은 항상 비어 있음
+        ElementType::Void => IRNode::void(tag, attrs),
+        ElementType::Normal => IRNode::new(tag, attrs, childs),
+        ElementType::Fragment => return IRNode::fragment(childs),
+    };
+    rebuilt.with_whitespace(node.get_whitespace())
+}
+
+fn map_childs(node: &IRNode, mut f: impl FnMut(IRNode) -> IRNode) -> Vec<Element> {
+    node.get_childs()
+        .iter()
+        .map(|child| match child {
+            Element::Node(n) => Element::Node(f(n.clone())),
+            other => other.clone(),
+        })
+        .collect()
+}
+
+fn count_tag(node: &IRNode, tag: &str) -> usize {
+    let own = usize::from(node.get_tag().as_str() == tag);
+    own + node
+        .get_childs()
+        .iter()
+        .map(|child| match child {
+            Element::Node(n) => count_tag(n, tag),
+            _ => 0,
+        })
+        .sum::<usize>()
+}
+
+/// 첫 번째 이후의 `<main>`을 `<div>`로 바꿈.
+fn keep_first_main(node: IRNode, seen: &mut bool) -> IRNode {
+    let is_main = node.get_tag().as_str() == "main";
+    let tag = if is_main && *seen { Some("div") } else { None };
+    *seen |= is_main;
+    let childs = map_childs(&node, |child| keep_first_main(child, seen));
+    rebuild(&node, tag, None, childs)
+}
+
+/// 문서 순서상 첫 `tag` 요소에 `f` 적용.
+fn map_first(node: IRNode, tag: &str, f: &mut dyn FnMut(IRNode) -> IRNode) -> IRNode {
+    fn walk(node: IRNode, tag: &str, f: &mut dyn FnMut(IRNode) -> IRNode, done: &mut bool) -> IRNode {
+        if *done {
+            return node;
+        }
+        if node.get_tag().as_str() == tag {
+            *done = true;
+            return f(node);
+        }
+        let childs = map_childs(&node, |child| walk(child, tag, f, done));
+        rebuild(&node, None, None, childs)
+    }
+    walk(node, tag, f, &mut false)
+}
+
+fn is_landmark(node: &IRNode) -> bool {
+    matches!(node.get_tag().as_str(), "nav" | "aside")
+}
+
+fn has_label(node: &IRNode) -> bool {
+    attr_token(node, "aria-label").is_some() || attr_token(node, "aria-labelledby").is_some()
+}
+
+fn collect_labels(node: &IRNode, used: &mut HashMap<String, usize>) {
+    if is_landmark(node)
+        && let Some(label) = attr_token(node, "aria-label")
+    {
+        *used.entry(label).or_insert(0) += 1;
+    }
+    for child in node.get_childs() {
+        if let Element::Node(n) = child {
+            collect_labels(n, used);
+        }
+    }
+}
+
+/// 이름 없는 nav/aside에 위치에 따른 `aria-label` 부여. `region`은 가장 가까운 header/footer.
+fn label_landmarks(node: IRNode, region: Option<&str>, used: &mut HashMap<String, usize>) -> IRNode {
+    let tag = node.get_tag().as_str().to_string();
+    let attrs = if is_landmark(&node) && !has_label(&node) {
+        let base = match (tag.as_str(), region) {
+            ("aside", _) => "Sidebar",
+            (_, Some("header")) => "Site",
+            (_, Some("footer")) => "Footer",
+            _ => "Navigation",
+        };
+        let count = used.entry(base.to_string()).or_insert(0);
+        *count += 1;
+        let label = if *count == 1 {
+            base.to_string()
+        } else {
+            format!("{} {}", base, count)
+        };
+        Some(with_tokens(node.get_attrs().clone(), &[("aria-label", &label)]))
+    } else {
+        None
+    };
+
+    let region = match tag.as_str() {
+        "header" | "footer" => Some(tag.as_str()),
+        _ => region,
+    };
+    let childs = map_childs(&node, |child| label_landmarks(child, region, used));
+    rebuild(&node, None, attrs, childs)
+}
@@ -0,0 +1,86 @@
+//! # anchors.rs - 제목 anchor
+//!
+//! ## 목적
+//! 페이지 본문의 제목(h1~h6)에 제목 텍스트로 만든 `id`를 붙여 `page#제목` 링크와 목차가 가리킬 수 있게 합니다.
+//! `SiteConfig::heading_anchors`로 켜고, slug 문자(`SlugStyle`)를 고릅니다.
+//!
+//! ## 사용 예시
+//! ```rust
+//! let config = SiteConfig::new("My Blog", "https://example.com")
+//!     .heading_anchors(SlugStyle::Unicode);  // 한글/한자 유지
+//! // .heading_anchors(SlugStyle::Ascii)      // 한글은 로마자 표기, 음역할 수 없는 문자는 제거
+//! ```
+//!
+//! ## 렌더링 결과
+//! ```html
+//! <h2 id="설치">설치</h2>
+//! <h2 id="설치-1">설치</h2>          <!-- 같은 제목은 등장 순서대로 -1, -2 -->
+//! <h2 id="custom">직접 지정</h2>      <!-- 이미 id가 있으면 그대로 -->
+//! ```
+//!
+//! ## 구현 상태
+//! - [x] 페이지 본문의 제목에 anchor 부여 (`document_ir`)
+//! - [x] 본문에 이미 있는 id와 겹치지 않음
+//! - [x] 분석 단계의 anchor 목록에 포함 (`IdGenerator`, fragment 링크 검사)
+//! - [ ] TODO: 제목 옆 링크 기호 (`<a href="#id">#</a>`)
+//!
+//! ## 설계 결정
+//! - **문서 조립 단계에서 부여**: 충돌 접미사는 페이지 안의 모든 제목을 순서대로 봐야 정할 수 있으므로
+//!   `Heading` Block이 아니라 페이지 본문 IR 전체에 한 번 적용합니다.
+//! - **분석과 같은 함수**: `IdGenerator`도 같은 본문에 `anchor_headings`를 적용해 anchor를 모으므로
+//!   분석 단계의 anchor와 출력 문서의 anchor가 항상 같습니다.
+//! - **공유 Layout 제외**: 사이트 머리글의 제목은 모든 페이지에 같으므로 본문(`Page::layout`)만 봅니다.
+
+use crate::block::block::{attr_token, with_tokens};
+use crate::block::context::RenderContext;
+use crate::cite::analysis::collect_attr;
+use crate::cite::config::SiteConfig;
+use crate::html::node::{Element, ElementType, IRNode};
+use crate::html::renderer::{Renderer, TextRenderer};
+use crate::page::slug::{SlugStyle, Slugger};
+
+/// anchor를 붙이는 제목 요소.
+pub const HEADING_TAGS: &[&str] = &["h1", "h2", "h3", "h4", "h5", "h6"];
+
+/// 본문의 id 없는 제목마다 anchor 부여. 이미 있는 id는 예약해 피합니다.
+pub fn anchor_headings(content: IRNode, style: SlugStyle) -> IRNode {
+    let mut slugger = Slugger::new(style);
+    collect_attr(&content, "id", &mut |id| slugger.reserve(&id));
+    anchor_node(&content, &mut slugger)
+}
+
+/// `SiteConfig::heading_anchors`가 있으면 anchor 부여.
+pub(crate) fn heading_anchors(content: IRNode, ctx: &RenderContext) -> IRNode {
+    match ctx.get::<SiteConfig>().and_then(|config| config.heading_anchors) {
+        Some(style) => anchor_headings(content, style),
+        None => content,
+    }
+}
+
+fn anchor_node(node: &IRNode, slugger: &mut Slugger) -> IRNode {
+    if matches!(node.get_type(), ElementType::Void) {
+        return node.clone();
+    }
+    if HEADING_TAGS.contains(&node.get_tag().as_str()) {
+        if attr_token(node, "id").is_some() {
+            return node.clone();
+        }
+        let text = node.accept(TextRenderer::new()).finalize().to_string();
+        let anchor = slugger.slug(&text);
+        return IRNode::new(
+            node.get_tag().clone(),
+            with_tokens(node.get_attrs().clone(), &[("id", &anchor)]),
+            node.get_childs().to_vec(),
+        )
+        .with_whitespace(node.get_whitespace());
+    }
+    let childs = node
+        .get_childs()
+        .iter()
+        .map(|child| match child {
+            Element::Node(child) => Element::Node(anchor_node(child, slugger)),
+            other => other.clone(),
+        })
+        .collect();
+    node.with_childs(childs)
+}
@@ -0,0 +1,159 @@
+//! # assets.rs - 페이지별 asset 수집과 주입
+//!
+//! ## 목적
+//! Block이 필요한 CSS/JS를 메타데이터(`Assets`)로 선언하면,
+//! 페이지의 Block 트리 전체에서 모아 중복을 제거한 뒤 `<head>`에 자동 주입합니다.
+//! 사용자가 페이지마다 스타일시트를 직접 나열할 필요가 없습니다.
+//!
+//! ## 흐름
+//! ```text
+//! CodeBlock::new().theme_stylesheet("/css/code.css")
+//!   ↓ metadata() → Assets { stylesheets: ["/css/code.css"] }
+//! collect_assets(page)
+//!   페이지 메타데이터 → Layout 체인의 Block → 페이지 Block (깊이 우선, 자식 포함)
+//!   ↓ 합집합 (처음 등장 순서 유지)
+//! asset_url(ctx, url)   (SiteConfig::cache_busting가 Query면 ?v=<지문>)
+//!   ↓
+//! HeadElements::assets(&assets) → <link rel="stylesheet">, <script>
+//! ```
+//!
+//! ## 사용 예시
+//! ```rust
+//! impl Block for MathBlock {
+//!     fn metadata(&self) -> Metadata {
+//!         Metadata::new().assets(
+//!             Assets::new()
+//!                 .stylesheet("/css/katex.css")
+//!                 .script("/js/katex.js"),
+//!         )
+//!     }
+//! }
+//! ```
+//!
+//!
+//! ## 캐시 무효화 (`CacheBusting::Query`)
+//! ```rust
+//! let site = Site::new().config(config.cache_busting(CacheBusting::Query).asset_dir("static"));
+//!
+//! // Block에서 직접 참조하는 asset
+//! let src = asset_url(ctx, "/js/widget.js");  // "/js/widget.js?v=8c1d2e3f4a5b6c7d"
+//! ```
+//! ```html
+//! <link rel="stylesheet" href="/assets/blocks.css?v=3f9a1c0d5e7b2a64">
+//! ```
+//!
+//! ## 설계 결정
+//! - **선언 순서 유지**: CSS는 순서가 의미를 가지므로 집합이 아닌 순서 있는 목록으로 합칩니다.
+//! - **스크립트는 defer**: 주입되는 스크립트는 렌더링을 막지 않도록 `defer`로 출력합니다.
+//! - **아는 파일에만 버전**: 방문자가 만든 파일과 `SiteConfig::asset_dirs`의 파일만 지문을 알 수 있으므로
+//!   그 밖의 URL(외부 CDN 등)은 그대로 둡니다. 지문은 `Site::index`가 계산해 `AssetVersions`로 남깁니다.
+
+use std::collections::BTreeMap;
+
+use crate::block::block::Block;
+use crate::block::context::RenderContext;
+use crate::metadata::types::Assets;
+use crate::metadata::value::MetadataValue;
+use crate::page::head::{HeadElements, Script};
+use crate::page::layout::layout_chain;
+use crate::page::page::Page;
+
+/// `CacheBusting::Query`에서 asset URL별 내용 지문. Site가 렌더링 메타데이터로 주입합니다.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct AssetVersions(BTreeMap<String, String>);
+impl MetadataValue for AssetVersions {}
+
+impl AssetVersions {
+    pub fn new() -> Self {
+        AssetVersions::default()
+    }
+
+    /// 사이트 루트 기준 URL의 버전.
+    pub fn version(mut self, url: &str, version: &str) -> Self {
+        self.0.insert(url.to_string(), version.to_string());
+        self
+    }
+
+    pub fn get(&self, url: &str) -> Option<&str> {
+        self.0.get(url).map(String::as_str)
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// 버전을 붙인 URL. 버전을 모르는 URL은 그대로.
+    pub fn url(&self, url: &str) -> String {
+        let path = url.split(['?', '#']).next().unwrap_or(url);
+        let Some(version) = self.get(path) else {
+            return url.to_string();
+        };
+        let (base, fragment) = match url.find('#') {
+            Some(i) => url.split_at(i),
+            None => (url, ""),
+        };
+        let separator = if base.contains('?') { '&' } else { '?' };
+        format!("{}{}v={}{}", base, separator, version, fragment)
+    }
+
+    /// 모든 스타일시트와 스크립트에 버전을 붙인 asset 목록.
+    pub fn assets(&self, assets: &Assets) -> Assets {
+        Assets {
+            stylesheets: assets.stylesheets.iter().map(|href| self.url(href)).collect(),
+            scripts: assets.scripts.iter().map(|src| self.url(src)).collect(),
+        }
+    }
+}
+
+/// 캐시 무효화 방식에 맞춘 asset URL. `CacheBusting::Query`면 `?v=<지문>`을 붙이고,
+/// 그 밖에는(파일 이름에 지문이 있으므로) 그대로 돌려줍니다.
+pub fn asset_url(ctx: &RenderContext, url: &str) -> String {
+    match ctx.get::<AssetVersions>() {
+        Some(versions) => versions.url(url),
+        None => url.to_string(),
+    }
+}
+
+/// Block 하나와 그 자식 전체의 asset 수집 (깊이 우선).
+pub fn collect_block_assets(block: &dyn Block) -> Assets {
+    let own = block.metadata().get::<Assets>().cloned().unwrap_or_default();
+    block
+        .children()
+        .into_iter()
+        .fold(own, |acc, child| acc.union(&collect_block_assets(child)))
+}
+
+/// 페이지 하나가 필요로 하는 asset 전체 수집.
+/// 페이지 메타데이터 → Layout 체인(바깥부터)의 Block → 페이지 Block 순서.
+pub fn collect_assets(page: &dyn Page) -> Assets {
+    let own = page.metadata().get::<Assets>().cloned().unwrap_or_default();
+
+    let chain = page.layout_template().map(layout_chain).unwrap_or_default();
+    let from_layouts = chain.iter().rev().fold(own, |acc, layout| {
+        layout
+            .blocks()
+            .into_iter()
+            .fold(acc, |acc, block| acc.union(&collect_block_assets(block)))
+    });
+
+    page.blocks()
+        .into_iter()
+        .fold(from_layouts, |acc, block| acc.union(&collect_block_assets(block)))
+}
+
+impl HeadElements {
+    /// asset 주입. 이미 있는 스타일시트/스크립트는 건너뜁니다.
+    pub fn assets(self, assets: &Assets) -> Self {
+        let head = assets
+            .stylesheets
+            .iter()
+            .fold(self, |head, href| head.stylesheet(href));
+        assets.scripts.iter().fold(head, |head, src| {
+            head.add_script(Script::new(src).defer(true))
+        })
+    }
+}
@@ -0,0 +1,298 @@
+//! # comments.rs - Comments (댓글)
+//!
+//! ## 목적
+//! 외부 댓글 서비스(giscus, utterances, isso)의 삽입 코드를 렌더링하는 Block입니다.
+//! 서비스 설정(`CommentSystem`)은 사이트 전역 메타데이터에 한 번 넣고, Block은 렌더링 시점에
+//! `ctx.get::<CommentSystem>()`으로 읽습니다. 페이지 메타데이터로 덮어쓰면 그 페이지만 다른 설정을 씁니다.
+//!
+//! ## 사용 예시
+//! ```rust
+//! let comments = CommentSystem::Giscus(
+//!     Giscus::new("owner/blog", "R_kgDOxxxx", "Comments", "DIC_kwDOxxxx").theme("dark"),
+//! );
+//! let site = Site::new().metadata(Metadata::new().custom(comments));
+//!
+//! // 글 템플릿
+//! VBox::new(vec![Box::new(article), Box::new(Comments::new())])
+//! ```
+//!
+//! ## 렌더링 결과
+//! ```html
+//! <section aria-label="Comments" class="comments comments-giscus">
+//!   <script async crossorigin="anonymous" data-category="Comments" data-category-id="DIC_kwDOxxxx"
+//!           data-lang="ko" data-loading="lazy" data-mapping="pathname" data-reactions-enabled="1"
+//!           data-repo="owner/blog" data-repo-id="R_kgDOxxxx" data-theme="dark"
+//!           src="https://giscus.app/client.js"></script>
+//! </section>
+//!
+//! <!-- utterances -->
+//! <script async crossorigin="anonymous" issue-term="pathname" repo="owner/blog"
+//!         src="https://utteranc.es/client.js" theme="github-light"></script>
+//!
+//! <!-- isso -->
+//! <script data-isso="https://comments.example.com/" src="https://comments.example.com/js/embed.min.js"></script>
+//! <section data-title="글 제목" id="isso-thread"></section>
+//! ```
+//!
+//! ## 구현 상태
+//! - [x] giscus, utterances, isso
+//! - [x] 사이트 메타데이터 설정, 페이지별 덮어쓰기, Block별 지정 (`Comments::system`)
+//! - [x] 언어(`Language`)와 제목(`Title`)을 서비스 설정에 전달
+//! - [ ] TODO: 사용자 동의 후 로드 (클릭해서 댓글 열기)
+//!
+//! ## 설계 결정
+//! - **설정이 없으면 빈 section**: 댓글을 끈 사이트도 같은 템플릿을 쓰므로 오류 대신
+//!   내용 없는 `section`을 렌더링합니다.
+//! - **스크립트는 본문에**: 세 서비스 모두 스크립트 태그 위치에 댓글 창을 만들므로 head가 아닌
+//!   Block 자리에 둡니다. `async`로 본문 렌더링을 막지 않습니다.
+//! - **스레드 기준은 경로**: 기본 매핑(`pathname`)은 도메인이 바뀌어도 같은 스레드를 찾습니다.
+
+use crate::block::block::{Block, class_attrs, element, token_attrs, with_tokens};
+use crate::block::context::RenderContext;
+use crate::html::attributes::{AttrValues, SharedAttrs};
+use crate::html::node::{Element, IRNode};
+use crate::html::trust::AttrKey;
+use crate::metadata::types::{Language, Title};
+use crate::metadata::value::MetadataValue;
+
+/// giscus (GitHub Discussions).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Giscus {
+    pub repo: String,
+    pub repo_id: String,
+    pub category: String,
+    pub category_id: String,
+    /// 페이지 ↔ 토론 매핑. 기본: `pathname`
+    pub mapping: String,
+    /// 기본: `preferred_color_scheme`
+    pub theme: String,
+    pub reactions: bool,
+    /// 없으면 페이지 `Language`, 그것도 없으면 `en`
+    pub lang: Option<String>,
+}
+
+impl Giscus {
+    /// giscus.app에서 저장소를 고르면 나오는 네 값.
+    pub fn new(repo: &str, repo_id: &str, category: &str, category_id: &str) -> Self {
+        Giscus {
+            repo: repo.to_string(),
+            repo_id: repo_id.to_string(),
+            category: category.to_string(),
+            category_id: category_id.to_string(),
+            mapping: "pathname".to_string(),
+            theme: "preferred_color_scheme".to_string(),
+            reactions: true,
+            lang: None,
+        }
+    }
+
+    pub fn mapping(mut self, mapping: &str) -> Self {
+        self.mapping = mapping.to_string();
+        self
+    }
+
+    pub fn theme(mut self, theme: &str) -> Self {
+        self.theme = theme.to_string();
+        self
+    }
+
+    pub fn reactions(mut self, reactions: bool) -> Self {
+        self.reactions = reactions;
+        self
+    }
+
+    pub fn lang(mut self, lang: &str) -> Self {
+        self.lang = Some(lang.to_string());
+        self
+    }
+}
+
+/// utterances (GitHub Issues).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Utterances {
+    pub repo: String,
+    /// 페이지 ↔ 이슈 매핑. 기본: `pathname`
+    pub issue_term: String,
+    pub label: Option<String>,
+    /// 기본: `github-light`
+    pub theme: String,
+}
+
+impl Utterances {
+    pub fn new(repo: &str) -> Self {
+        Utterances {
+            repo: repo.to_string(),
+            issue_term: "pathname".to_string(),
+            label: None,
+            theme: "github-light".to_string(),
+        }
+    }
+
+    pub fn issue_term(mut self, issue_term: &str) -> Self {
+        self.issue_term = issue_term.to_string();
+        self
+    }
+
+    /// 새 이슈에 붙일 라벨.
+    pub fn label(mut self, label: &str) -> Self {
+        self.label = Some(label.to_string());
+        self
+    }
+
+    pub fn theme(mut self, theme: &str) -> Self {
+        self.theme = theme.to_string();
+        self
+    }
+}
+
+/// isso (직접 운영하는 댓글 서버).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Isso {
+    /// 서버 주소. 예: `https://comments.example.com/`
+    pub url: String,
+    /// 없으면 페이지 `Language`
+    pub lang: Option<String>,
+}
+
+impl Isso {
+    pub fn new(url: &str) -> Self {
+        let url = match url.ends_with('/') {
+            true => url.to_string(),
+            false => format!("{}/", url),
+        };
+        Isso { url, lang: None }
+    }
+
+    pub fn lang(mut self, lang: &str) -> Self {
+        self.lang = Some(lang.to_string());
+        self
+    }
+}
+
+/// 댓글 서비스 설정. 사이트 전역 또는 페이지 메타데이터로 넣습니다.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CommentSystem {
+    Giscus(Giscus),
+    Utterances(Utterances),
+    Isso(Isso),
+}
+impl MetadataValue for CommentSystem {}
+
+impl CommentSystem {
+    /// 서비스 이름 (class 접미사). 예: `giscus`
+    pub fn name(&self) -> &'static str {
+        match self {
+            CommentSystem::Giscus(_) => "giscus",
+            CommentSystem::Utterances(_) => "utterances",
+            CommentSystem::Isso(_) => "isso",
+        }
+    }
+
+    /// 삽입 코드. `lang`, `title`은 페이지 메타데이터 값입니다.
+    pub fn embed(&self, lang: Option<&str>, title: Option<&str>) -> Vec<Element> {
+        match self {
+            CommentSystem::Giscus(giscus) => {
+                let lang = giscus.lang.as_deref().or(lang).unwrap_or("en");
+                let attrs = token_attrs(&[
+                    ("src", "https://giscus.app/client.js"),
+                    ("data-repo", &giscus.repo),
+                    ("data-repo-id", &giscus.repo_id),
+                    ("data-category", &giscus.category),
+                    ("data-category-id", &giscus.category_id),
+                    ("data-mapping", &giscus.mapping),
+                    ("data-reactions-enabled", if giscus.reactions { "1" } else { "0" }),
+                    ("data-theme", &giscus.theme),
+                    ("data-lang", lang),
+                    ("data-loading", "lazy"),
+                    ("crossorigin", "anonymous"),
+                ]);
+                vec![script(attrs)]
+            }
+            CommentSystem::Utterances(utterances) => {
+                let mut pairs = vec![
+                    ("src", "https://utteranc.es/client.js"),
+                    ("repo", utterances.repo.as_str()),
+                    ("issue-term", utterances.issue_term.as_str()),
+                    ("theme", utterances.theme.as_str()),
+                    ("crossorigin", "anonymous"),
+                ];
+                if let Some(label) = &utterances.label {
+                    pairs.push(("label", label));
+                }
+                vec![script(token_attrs(&pairs))]
+            }
+            CommentSystem::Isso(isso) => {
+                let src = format!("{}js/embed.min.js", isso.url);
+                let mut pairs = vec![("src", src.as_str()), ("data-isso", isso.url.as_str())];
+                if let Some(lang) = isso.lang.as_deref().or(lang) {
+                    pairs.push(("data-isso-lang", lang));
+                }
+                let thread = match title {
+                    Some(title) => token_attrs(&[("id", "isso-thread"), ("data-title", title)]),
+                    None => token_attrs(&[("id", "isso-thread")]),
+                };
+                vec![
+                    Element::Node(element("script", token_attrs(&pairs), vec![])),
+                    Element::Node(element("section", thread, vec![])),
+                ]
+            }
+        }
+    }
+}
+
+/// 비동기 외부 스크립트.
+fn script(attrs: SharedAttrs) -> Element {
+    let attrs = attrs.with_added(AttrKey::from_str("async"), AttrValues::Bool(true));
+    Element::Node(element("script", attrs, vec![]))
+}
+
+/// 댓글 Block.
+#[derive(Debug, Clone, Default)]
+pub struct Comments {
+    system: Option<CommentSystem>,
+    label: Option<String>,
+}
+
+impl Comments {
+    pub fn new() -> Self {
+        Comments::default()
+    }
+
+    /// 메타데이터 대신 이 Block에서만 쓸 서비스.
+    pub fn system(mut self, system: CommentSystem) -> Self {
+        self.system = Some(system);
+        self
+    }
+
+    /// 영역 이름 (`aria-label`). 기본: `Comments`
+    pub fn label(mut self, label: &str) -> Self {
+        self.label = Some(label.to_string());
+        self
+    }
+}
+
+impl Block for Comments {
+    fn render_to_ir(&self, ctx: &RenderContext) -> IRNode {
+        let label = self.label.as_deref().unwrap_or("Comments");
+        let system = self.system.as_ref().or_else(|| ctx.get::<CommentSystem>());
+        let Some(system) = system else {
+            return element("section", comments_attrs(label, None), vec![]);
+        };
+
+        let lang = ctx.get::<Language>().map(|l| l.0.as_str());
+        let title = ctx.get::<Title>().map(|t| t.0.as_str());
+        element(
+            "section",
+            comments_attrs(label, Some(system.name())),
+            system.embed(lang, title),
+        )
+    }
+}
+
+/// 댓글 영역 `section`의 속성. `system`이 있으면 서비스별 class를 붙입니다.
+fn comments_attrs(label: &str, system: Option<&str>) -> SharedAttrs {
+    let system_class = system.map(|name| format!("comments-{}", name));
+    let mut classes = vec!["comments"];
+    classes.extend(system_class.as_deref());
+    with_tokens(class_attrs(&classes), &[("aria-label", label)])
+}
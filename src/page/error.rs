@@ -0,0 +1,161 @@
+//! # error.rs - 오류 페이지
+//!
+//! ## 목적
+//! 호스팅 서버가 보여줄 `404.html`, `50x.html`을 사이트의 공유 Layout으로 생성합니다.
+//!
+//! ## 사용 예시
+//! ```rust
+//! // 직접 등록
+//! site.register_page(Box::new(
+//!     ErrorPage::not_found()
+//!         .message("요청하신 페이지를 찾을 수 없습니다.")
+//!         .with_layout(chrome.clone()),
+//! ));
+//!
+//! // 또는 Site가 기본 Layout으로 생성
+//! let site = Site::new().default_layout(chrome).generate_not_found(true);
+//! ```
+//!
+//! ## 렌더링 결과
+//! ```html
+//! <div class="error-page error-404">
+//!   <h1>Page not found</h1>
+//!   <p>The page you requested does not exist.</p>
+//!   <p><a href="/">Home</a></p>
+//! </div>
+//! ```
+//!
+//! ## 설계 결정
+//! - **검색 제외**: 오류 페이지는 `<meta name="robots" content="noindex">`를 출력합니다.
+//! - **루트 기준 링크**: 오류 페이지는 임의 경로에서 보여지므로 홈 링크와 asset은
+//!   상대 경로가 아닌 `/`로 시작하는 경로를 사용해야 합니다.
+
+use std::{path::PathBuf, sync::Arc};
+
+use crate::block::block::{Block, class_attrs, element, render_block};
+use crate::block::context::RenderContext;
+use crate::block::inline::Inline;
+use crate::block::prose::{Heading, Paragraph};
+use crate::html::node::{Element, IRNode};
+use crate::metadata::builder::Metadata;
+use crate::page::head::HeadElements;
+use crate::page::layout::Layout;
+use crate::page::page::Page;
+
+/// HTTP 오류 페이지.
+pub struct ErrorPage {
+    status: u16,
+    title: String,
+    message: String,
+    home_href: Option<String>,
+    content: Vec<Box<dyn Block>>,
+    output_path: PathBuf,
+    template: Option<Arc<dyn Layout>>,
+}
+
+impl ErrorPage {
+    /// 임의 상태 코드 오류 페이지. 출력 경로는 `{status}.html`.
+    pub fn new(status: u16, title: &str, message: &str) -> Self {
+        ErrorPage {
+            status,
+            title: title.to_string(),
+            message: message.to_string(),
+            home_href: Some("/".to_string()),
+            content: Vec::new(),
+            output_path: PathBuf::from(format!("{}.html", status)),
+            template: None,
+        }
+    }
+
+    /// `404.html`
+    pub fn not_found() -> Self {
+        ErrorPage::new(404, "Page not found", "The page you requested does not exist.")
+    }
+
+    /// `50x.html` (nginx 관례: 500/502/503/504 공용)
+    pub fn server_error() -> Self {
+        ErrorPage::new(500, "Something went wrong", "Please try again later.")
+            .path("50x.html")
+    }
+
+    pub fn title(mut self, title: &str) -> Self {
+        self.title = title.to_string();
+        self
+    }
+
+    pub fn message(mut self, message: &str) -> Self {
+        self.message = message.to_string();
+        self
+    }
+
+    /// 홈 링크 주소. `None`이면 링크를 출력하지 않음.
+    pub fn home_href(mut self, href: Option<&str>) -> Self {
+        self.home_href = href.map(|h| h.to_string());
+        self
+    }
+
+    pub fn path(mut self, path: &str) -> Self {
+        self.output_path = PathBuf::from(path);
+        self
+    }
+
+    pub fn add_block(mut self, block: Box<dyn Block>) -> Self {
+        self.content.push(block);
+        self
+    }
+
+    pub fn with_layout(mut self, layout: Arc<dyn Layout>) -> Self {
+        self.template = Some(layout);
+        self
+    }
+
+    pub fn status(&self) -> u16 {
+        self.status
+    }
+}
+
+impl Page for ErrorPage {
+    fn layout(&self, ctx: &RenderContext) -> IRNode {
+        let mut childs = vec![
+            Element::Node(Heading::text(1, &self.title).render_to_ir(ctx)),
+            Element::Node(Paragraph::new(&self.message).render_to_ir(ctx)),
+        ];
+        childs.extend(
+            self.content
+                .iter()
+                .map(|block| Element::Node(render_block(block.as_ref(), ctx))),
+        );
+        if let Some(href) = &self.home_href {
+            let link = Paragraph::from_inlines(vec![Inline::Link {
+                href: href.clone(),
+                children: vec![Inline::Text("Home".to_string())],
+            }]);
+            childs.push(Element::Node(link.render_to_ir(ctx)));
+        }
+
+        let status_class = format!("error-{}", self.status);
+        element("div", class_attrs(&["error-page", &status_class]), childs)
+    }
+
+    fn metadata(&self) -> Metadata {
+        Metadata::new().title(&self.title)
+    }
+
+    fn path(&self) -> PathBuf {
+        self.output_path.clone()
+    }
+
+    fn head(&self, _ctx: &RenderContext) -> HeadElements {
+        HeadElements::new()
+            .title(&self.title)
+            .meta_name("robots", "noindex")
+    }
+
+    fn layout_template(&self) -> Option<Arc<dyn Layout>> {
+        self.template.clone()
+    }
+
+    fn blocks(&self) -> Vec<&dyn Block> {
+        self.content.iter().map(|b| b.as_ref()).collect()
+    }
+}
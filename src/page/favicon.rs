@@ -0,0 +1,265 @@
+//! # favicon.rs - 파비콘과 웹 매니페스트
+//!
+//! ## 목적
+//! 브라우저 탭, 홈 화면, 안드로이드 설치용 아이콘 link 전체와 `theme-color` meta,
+//! 그리고 `site.webmanifest` 파일 내용을 한 곳에서 생성합니다.
+//!
+//! ## 사용 예시
+//! ```rust
+//! // 1. 원본 이미지 하나 (SVG 권장: 모든 크기에 대응)
+//! let icons = Favicons::from_source("/favicon.svg")
+//!     .theme_color("#1e293b")
+//!     .app_name("My Blog");
+//!
+//! // 2. 미리 준비한 파일 세트 (realfavicongenerator 등의 출력 관례)
+//! let icons = Favicons::prepared("/icons").theme_color("#1e293b");
+//!
+//! HeadElements::new().favicons(&icons);
+//! icons.write_manifest(&DiskFs::new("dist"))?;  // dist/site.webmanifest
+//! ```
+//!
+//! ## 렌더링 결과 (`prepared`)
+//! ```html
+//! <link href="/icons/favicon.ico" rel="icon" sizes="any" >
+//! <link href="/icons/favicon-32x32.png" rel="icon" sizes="32x32" type="image/png" >
+//! <link href="/icons/favicon-16x16.png" rel="icon" sizes="16x16" type="image/png" >
+//! <link href="/icons/apple-touch-icon.png" rel="apple-touch-icon" sizes="180x180" >
+//! <link href="/site.webmanifest" rel="manifest" >
+//! <meta content="#1e293b" name="theme-color" >
+//! ```
+//!
+//! ## 구현 상태
+//! - [x] 아이콘 link, apple-touch-icon, manifest link, theme-color
+//! - [x] `site.webmanifest` JSON 생성 및 기록
+//! - [ ] TODO: 원본 이미지에서 크기별 PNG 생성 (이미지 처리 기능 구현 후)
+//! - [ ] TODO: Site 빌드 시 매니페스트 자동 기록
+//!
+//! ## 설계 결정
+//! - **리사이즈하지 않음**: 이미지 처리 의존성 없이 동작하도록, 원본 하나일 때는
+//!   그 파일을 모든 용도에 사용합니다. SVG는 `sizes="any"`로 선언됩니다.
+
+use std::{io, path::PathBuf};
+
+use serde_json::json;
+
+use crate::cite::vfs::SiteFs;
+use crate::page::head::{HeadElements, LinkTag, MetaTag};
+
+/// 아이콘 파일 하나.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Icon {
+    pub href: String,
+    /// 예: `"32x32"`, `"any"`
+    pub sizes: Option<String>,
+    /// MIME 타입. 예: `"image/png"`
+    pub mime: Option<String>,
+}
+
+impl Icon {
+    /// 확장자로 MIME 타입을 추정하여 생성.
+    pub fn new(href: &str, sizes: Option<&str>) -> Self {
+        Icon {
+            href: href.to_string(),
+            sizes: sizes.map(|s| s.to_string()),
+            mime: mime_for(href).map(|m| m.to_string()),
+        }
+    }
+
+    fn to_link(&self, rel: &str) -> LinkTag {
+        let mut link = LinkTag::new(rel, &self.href);
+        if let Some(sizes) = &self.sizes {
+            link = link.attr("sizes", sizes);
+        }
+        if let Some(mime) = &self.mime {
+            link = link.attr("type", mime);
+        }
+        link
+    }
+}
+
+fn mime_for(href: &str) -> Option<&'static str> {
+    let ext = href.rsplit('.').next()?.to_ascii_lowercase();
+    match ext.as_str() {
+        "png" => Some("image/png"),
+        "svg" => Some("image/svg+xml"),
+        "ico" => Some("image/x-icon"),
+        "webp" => Some("image/webp"),
+        "jpg" | "jpeg" => Some("image/jpeg"),
+        _ => None,
+    }
+}
+
+/// 파비콘 세트 + 웹 매니페스트 설정.
+#[derive(Debug, Clone)]
+pub struct Favicons {
+    /// `rel="icon"` 목록
+    pub icons: Vec<Icon>,
+    pub apple_touch_icon: Option<Icon>,
+    /// 매니페스트에 들어갈 아이콘 (192, 512 등)
+    pub manifest_icons: Vec<Icon>,
+    pub theme_color: Option<String>,
+    pub background_color: Option<String>,
+    pub app_name: Option<String>,
+    pub short_name: Option<String>,
+    /// 매니페스트 URL 경로. 기본: `/site.webmanifest`
+    pub manifest_path: String,
+}
+
+impl Favicons {
+    fn empty() -> Self {
+        Favicons {
+            icons: Vec::new(),
+            apple_touch_icon: None,
+            manifest_icons: Vec::new(),
+            theme_color: None,
+            background_color: None,
+            app_name: None,
+            short_name: None,
+            manifest_path: "/site.webmanifest".to_string(),
+        }
+    }
+
+    /// 원본 이미지 하나를 모든 용도에 사용.
+    pub fn from_source(href: &str) -> Self {
+        let sizes = if mime_for(href) == Some("image/svg+xml") {
+            Some("any")
+        } else {
+            None
+        };
+        let icon = Icon::new(href, sizes);
+        Favicons {
+            icons: vec![icon.clone()],
+            apple_touch_icon: Some(Icon { sizes: None, ..icon.clone() }),
+            manifest_icons: vec![icon],
+            ..Favicons::empty()
+        }
+    }
+
+    /// 관례적인 파일 이름으로 준비된 아이콘 세트. `dir`은 URL 경로. 예: `"/icons"`
+    ///
+    /// favicon.ico, favicon-32x32.png, favicon-16x16.png, apple-touch-icon.png,
+    /// android-chrome-192x192.png, android-chrome-512x512.png
+    pub fn prepared(dir: &str) -> Self {
+        let dir = dir.trim_end_matches('/');
+        let at = |name: &str| format!("{}/{}", dir, name);
+        Favicons {
+            icons: vec![
+                Icon {
+                    mime: None,
+                    ..Icon::new(&at("favicon.ico"), Some("any"))
+                },
+                Icon::new(&at("favicon-32x32.png"), Some("32x32")),
+                Icon::new(&at("favicon-16x16.png"), Some("16x16")),
+            ],
+            apple_touch_icon: Some(Icon {
+                mime: None,
+                ..Icon::new(&at("apple-touch-icon.png"), Some("180x180"))
+            }),
+            manifest_icons: vec![
+                Icon::new(&at("android-chrome-192x192.png"), Some("192x192")),
+                Icon::new(&at("android-chrome-512x512.png"), Some("512x512")),
+            ],
+            ..Favicons::empty()
+        }
+    }
+
+    pub fn theme_color(mut self, color: &str) -> Self {
+        self.theme_color = Some(color.to_string());
+        self
+    }
+
+    pub fn background_color(mut self, color: &str) -> Self {
+        self.background_color = Some(color.to_string());
+        self
+    }
+
+    pub fn app_name(mut self, name: &str) -> Self {
+        self.app_name = Some(name.to_string());
+        self
+    }
+
+    pub fn short_name(mut self, name: &str) -> Self {
+        self.short_name = Some(name.to_string());
+        self
+    }
+
+    pub fn manifest_path(mut self, path: &str) -> Self {
+        self.manifest_path = path.to_string();
+        self
+    }
+
+    /// `<head>`에 들어갈 link 목록.
+    pub fn links(&self) -> Vec<LinkTag> {
+        let mut links: Vec<LinkTag> = self.icons.iter().map(|i| i.to_link("icon")).collect();
+        if let Some(apple) = &self.apple_touch_icon {
+            links.push(apple.to_link("apple-touch-icon"));
+        }
+        links.push(LinkTag::new("manifest", &self.manifest_path));
+        links
+    }
+
+    /// `theme-color` meta. 색상을 지정하지 않으면 `None`.
+    pub fn meta_tag(&self) -> Option<MetaTag> {
+        self.theme_color.as_ref().map(|color| MetaTag::Name {
+            name: "theme-color".to_string(),
+            content: color.clone(),
+        })
+    }
+
+    /// `site.webmanifest` JSON 내용.
+    pub fn manifest_json(&self) -> String {
+        let icons: Vec<_> = self
+            .manifest_icons
+            .iter()
+            .map(|icon| {
+                let mut entry = json!({ "src": icon.href });
+                if let Some(sizes) = &icon.sizes {
+                    entry["sizes"] = json!(sizes);
+                }
+                if let Some(mime) = &icon.mime {
+                    entry["type"] = json!(mime);
+                }
+                entry
+            })
+            .collect();
+
+        let mut manifest = json!({
+            "icons": icons,
+            "display": "standalone",
+        });
+        let optional = [
+            ("name", &self.app_name),
+            ("short_name", &self.short_name),
+            ("theme_color", &self.theme_color),
+            ("background_color", &self.background_color),
+        ];
+        for (key, value) in optional {
+            if let Some(value) = value {
+                manifest[key] = json!(value);
+            }
+        }
+
+        serde_json::to_string_pretty(&manifest).unwrap_or_default()
+    }
+
+    /// 출력 파일 시스템에 매니페스트 기록. 기록한 파일 경로(출력 디렉토리 기준) 반환.
+    pub fn write_manifest(&self, fs: &dyn SiteFs) -> io::Result<PathBuf> {
+        let path = PathBuf::from(self.manifest_path.trim_start_matches('/'));
+        fs.write(&path, self.manifest_json().as_bytes())?;
+        Ok(path)
+    }
+}
+
+impl HeadElements {
+    /// 파비콘 link와 theme-color meta 추가.
+    pub fn favicons(self, favicons: &Favicons) -> Self {
+        let head = favicons
+            .links()
+            .into_iter()
+            .fold(self, |head, link| head.link(link));
+        match favicons.meta_tag() {
+            Some(tag) => head.meta(tag),
+            None => head,
+        }
+    }
+}
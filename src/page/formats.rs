@@ -0,0 +1,140 @@
+//! # formats.rs - 추가 출력 형식
+//!
+//! ## 목적
+//! 페이지를 HTML 문서 외에 JSON 트리, 일반 텍스트, Markdown으로도 출력하도록 선언합니다.
+//! 콘텐츠 API(`page.json`)나 LLM이 읽기 좋은 텍스트 미러(`page.md`, `page.txt`)를 HTML 옆에 둡니다.
+//! 선언은 메타데이터(`OutputFormats`)로 하고, 파일은 Cite의 `OutputFormatGenerator`가 씁니다.
+//!
+//! ## 사용 예시
+//! ```rust
+//! // 사이트 전체
+//! let site = Site::new().metadata(
+//!     Metadata::new().custom(OutputFormats::new().format(OutputFormat::Markdown)),
+//! );
+//!
+//! // 페이지별 (사이트 설정을 대체)
+//! SimplePage::new("API", "api/posts.html")
+//!     .with_metadata(Metadata::new().custom(OutputFormats::new().format(OutputFormat::Json)));
+//! ```
+//! ```text
+//! ---
+//! title: 첫 글
+//! formats: [json, md]
+//! ---
+//! ```
+//!
+//! ## 출력
+//! ```text
+//! blog/post.html   ← <link href="/blog/post.md" rel="alternate" type="text/markdown">
+//! blog/post.json   ← {"url", "title", ..., "content": [{"tag": "p", "attrs": {}, "children": [...]}]}
+//! blog/post.txt
+//! blog/post.md
+//! ```
+//!
+//! ## 구현 상태
+//! - [x] JSON 트리, 일반 텍스트, Markdown
+//! - [x] 화면용 문서에 `rel="alternate"` 링크
+//! - [x] 머리말 `formats`
+//! - [ ] TODO: 사이트 전체 텍스트 목록 (`llms.txt`)
+//!
+//! ## 설계 결정
+//! - **본문 Block만**: 피드, 검색 색인과 같이 페이지의 최상위 Block만 렌더링합니다.
+//!   공유 Layout의 헤더, 내비게이션은 콘텐츠가 아니므로 넣지 않습니다.
+//! - **확장자만 바꾼 경로**: `blog/post.html` → `blog/post.json`. 디렉토리 URL(`/blog/`)은 `index.json`.
+//! - **메타데이터로 선언**: `Series`, `SourceFiles`처럼 페이지 메타데이터에 두므로 사이트 기본값과
+//!   머리말 선언이 같은 병합 규칙을 따릅니다.
+
+use std::path::{Path, PathBuf};
+
+use crate::metadata::value::MetadataValue;
+use crate::page::head::{HeadElements, LinkTag};
+
+/// 추가 출력 형식.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum OutputFormat {
+    /// 메타데이터 + 본문 노드 트리 (`JsonRenderer`)
+    Json,
+    /// 태그를 뺀 본문 (`TextRenderer`)
+    Text,
+    /// 본문 Markdown (`MarkdownRenderer`)
+    Markdown,
+}
+
+impl OutputFormat {
+    /// 이름으로 찾기 (머리말용). `json`, `txt`/`text`, `md`/`markdown`
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "json" => Some(OutputFormat::Json),
+            "txt" | "text" => Some(OutputFormat::Text),
+            "md" | "markdown" => Some(OutputFormat::Markdown),
+            _ => None,
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            OutputFormat::Json => "json",
+            OutputFormat::Text => "txt",
+            OutputFormat::Markdown => "md",
+        }
+    }
+
+    pub fn mime_type(&self) -> &'static str {
+        match self {
+            OutputFormat::Json => "application/json",
+            OutputFormat::Text => "text/plain",
+            OutputFormat::Markdown => "text/markdown",
+        }
+    }
+}
+
+/// 페이지의 추가 출력 형식. 사이트 메타데이터에 두면 모든 페이지, 페이지 메타데이터에 두면 그 페이지만.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct OutputFormats(pub Vec<OutputFormat>);
+impl MetadataValue for OutputFormats {}
+
+impl OutputFormats {
+    pub fn new() -> Self {
+        OutputFormats::default()
+    }
+
+    /// 형식 추가. 중복은 무시.
+    pub fn format(mut self, format: OutputFormat) -> Self {
+        if !self.0.contains(&format) {
+            self.0.push(format);
+        }
+        self
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &OutputFormat> {
+        self.0.iter()
+    }
+}
+
+/// 형식별 출력 경로. `blog/post.html` → `blog/post.json`
+pub fn format_path(path: &Path, format: OutputFormat) -> PathBuf {
+    path.with_extension(format.extension())
+}
+
+/// 형식별 URL. `/blog/post.html` → `/blog/post.json`, `/blog/` → `/blog/index.json`
+pub fn format_url(url: &str, format: OutputFormat) -> String {
+    if url.ends_with('/') {
+        return format!("{}index.{}", url, format.extension());
+    }
+    match url.strip_suffix(".html") {
+        Some(stem) => format!("{}.{}", stem, format.extension()),
+        None => format!("{}.{}", url, format.extension()),
+    }
+}
+
+impl HeadElements {
+    /// 추가 출력 형식을 가리키는 `<link rel="alternate" type="...">` 추가.
+    pub fn output_formats(self, current_url: &str, formats: &OutputFormats) -> Self {
+        formats.iter().fold(self, |head, format| {
+            head.link(
+                LinkTag::new("alternate", &format_url(current_url, *format))
+                    .attr("type", format.mime_type()),
+            )
+        })
+    }
+}
@@ -0,0 +1,272 @@
+//! # frontmatter.rs - 콘텐츠 파일 머리말
+//!
+//! ## 목적
+//! Markdown 파일 앞부분의 `---`로 둘러싼 머리말을 파싱하여 페이지 메타데이터로 변환합니다.
+//!
+//! ## 지원 형식 (YAML 부분집합)
+//! ```text
+//! ---
+//! title: 첫 글
+//! date: 2024-03-01
+//! tags: [rust, ssg]
+//! categories:
+//!   - blog
+//! ---
+//! 본문...
+//! ```
+//! - `key: value`: 문자열 (앞뒤 따옴표 제거)
+//! - `key: [a, b]`: 목록
+//! - `key:` + `  - item` 줄들: 목록
+//! - `#`로 시작하는 줄: 주석
+//!
+//! ## 표준 키 → 메타데이터 타입
+//! | 키 | 타입 |
+//! |----|------|
+//! | `title` | `Title` |
+//! | `description` | `Description` |
+//! | `date` | `PublishDate` |
+//! | `updated` / `lastmod` | `LastModified` |
+//! | `tags` | `Tags` |
+//! | `categories` | `Categories` |
+//! | `draft: true` / `status` | `PageStatus` |
+//! | `series` (+ `series_part`) | `Series` |
+//! | `weight` | `Weight` |
+//! | `formats` | `OutputFormats` |
+//! | `unlisted: true` / `unlisted: [sitemap, feeds, search]` | `Unlisted` |
+//!
+//! 그 외 키는 `Frontmatter` 자체를 메타데이터로 저장하여 `get_str("key")`로 접근합니다.
+
+use std::collections::BTreeMap;
+
+use crate::metadata::builder::Metadata;
+use crate::metadata::types::{
+    Categories, Date, Description, LastModified, Listing, PageStatus, PublishDate, Series, Tags,
+    Title, Unlisted, Weight,
+};
+use crate::metadata::value::MetadataValue;
+use crate::page::formats::{OutputFormat, OutputFormats};
+
+/// 머리말 값.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FrontmatterValue {
+    Text(String),
+    List(Vec<String>),
+}
+
+/// 파싱된 머리말. 키 순서와 무관하게 정렬 저장 (결정적 출력).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Frontmatter {
+    fields: BTreeMap<String, FrontmatterValue>,
+}
+
+impl MetadataValue for Frontmatter {}
+
+impl Frontmatter {
+    /// 원문에서 머리말을 분리. (머리말, 본문) 반환.
+    /// 머리말이 없으면 빈 머리말과 원문 전체를 반환합니다.
+    pub fn split(source: &str) -> (Frontmatter, &str) {
+        let source = source.strip_prefix('\u{feff}').unwrap_or(source);
+        let Some(rest) = source
+            .strip_prefix("---\n")
+            .or_else(|| source.strip_prefix("---\r\n"))
+        else {
+            return (Frontmatter::default(), source);
+        };
+
+        let mut offset = 0;
+        for line in rest.split_inclusive('\n') {
+            if line.trim_end() == "---" {
+                let header = &rest[..offset];
+                let body = &rest[offset + line.len()..];
+                return (Frontmatter::parse(header), body);
+            }
+            offset += line.len();
+        }
+
+        // 닫는 구분자가 없으면 머리말로 보지 않음 (문서 보존)
+        (Frontmatter::default(), source)
+    }
+
+    /// 구분자(`---`) 없는 머리말 본문 파싱.
+    pub fn parse(header: &str) -> Frontmatter {
+        let mut fields = BTreeMap::new();
+        let mut current_list: Option<(String, Vec<String>)> = None;
+
+        for line in header.lines() {
+            let trimmed = line.trim();
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                continue;
+            }
+
+            if let Some(item) = trimmed.strip_prefix("- ") {
+                if let Some((_, items)) = current_list.as_mut() {
+                    items.push(unquote(item));
+                }
+                continue;
+            }
+
+            if let Some((key, items)) = current_list.take() {
+                fields.insert(key, FrontmatterValue::List(items));
+            }
+
+            let Some((key, value)) = trimmed.split_once(':') else {
+                continue;
+            };
+            let key = key.trim().to_string();
+            let value = value.trim();
+
+            if value.is_empty() {
+                current_list = Some((key, Vec::new()));
+            } else if let Some(inner) = value.strip_prefix('[').and_then(|v| v.strip_suffix(']')) {
+                let items = inner
+                    .split(',')
+                    .map(unquote)
+                    .filter(|item| !item.is_empty())
+                    .collect();
+                fields.insert(key, FrontmatterValue::List(items));
+            } else {
+                fields.insert(key, FrontmatterValue::Text(unquote(value)));
+            }
+        }
+
+        if let Some((key, items)) = current_list {
+            fields.insert(key, FrontmatterValue::List(items));
+        }
+
+        Frontmatter { fields }
+    }
+
+    pub fn get(&self, key: &str) -> Option<&FrontmatterValue> {
+        self.fields.get(key)
+    }
+
+    /// 문자열 값. 목록이면 `None`.
+    pub fn get_str(&self, key: &str) -> Option<&str> {
+        match self.fields.get(key)? {
+            FrontmatterValue::Text(text) => Some(text),
+            FrontmatterValue::List(_) => None,
+        }
+    }
+
+    /// 목록 값. 문자열이면 단일 항목 목록으로 취급.
+    pub fn get_list(&self, key: &str) -> Vec<String> {
+        match self.fields.get(key) {
+            Some(FrontmatterValue::List(items)) => items.clone(),
+            Some(FrontmatterValue::Text(text)) => vec![text.clone()],
+            None => vec![],
+        }
+    }
+
+    /// 불린 값 (`true`, `yes`, `on`).
+    pub fn get_bool(&self, key: &str) -> Option<bool> {
+        match self.get_str(key)?.to_ascii_lowercase().as_str() {
+            "true" | "yes" | "on" => Some(true),
+            "false" | "no" | "off" => Some(false),
+            _ => None,
+        }
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.fields.keys().map(String::as_str)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    /// 표준 키를 메타데이터 타입으로 변환. 머리말 전체도 함께 저장됩니다.
+    /// `draft: true` 또는 `status: draft | published | scheduled`.
+    /// `scheduled`는 `date`를 발행일로 사용합니다.
+    pub fn status(&self) -> Option<PageStatus> {
+        if self.get_bool("draft") == Some(true) {
+            return Some(PageStatus::Draft);
+        }
+        match self.get_str("status")?.to_ascii_lowercase().as_str() {
+            "draft" => Some(PageStatus::Draft),
+            "published" => Some(PageStatus::Published),
+            "scheduled" => self
+                .get_str("date")
+                .and_then(Date::parse)
+                .map(PageStatus::Scheduled),
+            _ => None,
+        }
+    }
+
+    /// `unlisted: true`(모든 전역 파일) 또는 `unlisted: [sitemap, feeds, search]`.
+    pub fn unlisted(&self) -> Option<Unlisted> {
+        match self.get_bool("unlisted") {
+            Some(true) => return Some(Unlisted::all()),
+            Some(false) => return None,
+            None => {}
+        }
+        self.get("unlisted")?;
+        Some(
+            self.get_list("unlisted")
+                .iter()
+                .filter_map(|name| Listing::parse(name))
+                .fold(Unlisted::new(), Unlisted::from),
+        )
+    }
+
+    pub fn to_metadata(&self) -> Metadata {
+        let mut meta = Metadata::new().custom(self.clone());
+
+        if let Some(title) = self.get_str("title") {
+            meta = meta.custom(Title(title.to_string()));
+        }
+        if let Some(description) = self.get_str("description") {
+            meta = meta.custom(Description(description.to_string()));
+        }
+        if let Some(date) = self.get_str("date").and_then(Date::parse) {
+            meta = meta.custom(PublishDate(date));
+        }
+        let updated = self.get_str("updated").or_else(|| self.get_str("lastmod"));
+        if let Some(date) = updated.and_then(Date::parse) {
+            meta = meta.custom(LastModified(date));
+        }
+        if self.get("tags").is_some() {
+            meta = meta.custom(Tags(self.get_list("tags")));
+        }
+        if self.get("categories").is_some() {
+            meta = meta.custom(Categories(self.get_list("categories")));
+        }
+        if let Some(status) = self.status() {
+            meta = meta.custom(status);
+        }
+        if let Some(name) = self.get_str("series") {
+            let series = Series::new(name);
+            meta = meta.custom(match self.get_str("series_part").and_then(|p| p.parse().ok()) {
+                Some(part) => series.part(part),
+                None => series,
+            });
+        }
+
+        if let Some(weight) = self.get_str("weight").and_then(|w| w.parse().ok()) {
+            meta = meta.custom(Weight(weight));
+        }
+        if let Some(unlisted) = self.unlisted() {
+            meta = meta.custom(unlisted);
+        }
+
+        if self.get("formats").is_some() {
+            let formats = self
+                .get_list("formats")
+                .iter()
+                .filter_map(|name| OutputFormat::parse(name))
+                .fold(OutputFormats::new(), OutputFormats::format);
+            meta = meta.custom(formats);
+        }
+
+        meta
+    }
+}
+
+fn unquote(value: &str) -> String {
+    let value = value.trim();
+    let unquoted = value
+        .strip_prefix('"')
+        .and_then(|v| v.strip_suffix('"'))
+        .or_else(|| value.strip_prefix('\'').and_then(|v| v.strip_suffix('\'')))
+        .unwrap_or(value);
+    unquoted.to_string()
+}
@@ -0,0 +1,342 @@
+//! # glossary.rs - 용어집
+//!
+//! ## 목적
+//! 사이트 전역 용어집(`Glossary`: 용어 → 정의, 정의 위치)을 등록하고, 전체 목록을 `GlossaryBlock`으로
+//! 렌더링합니다. `auto_link`를 켜면 각 페이지 본문에서 용어가 처음 나오는 곳을 정의로 링크합니다.
+//!
+//! ## 사용 예시
+//! ```rust
+//! let glossary = Glossary::new()
+//!     .term("SSG", "정적 사이트 생성기. 빌드할 때 HTML을 미리 만듭니다.")
+//!     .term_at("IR", "중간 표현", "/docs/architecture.html#ir")  // 정의가 다른 페이지에 있을 때
+//!     .auto_link(true);
+//! let site = Site::new().metadata(Metadata::new().custom(glossary));
+//!
+//! // 용어집 페이지
+//! SimplePage::new("용어집", "glossary.html").add_block(Box::new(GlossaryBlock::new()));
+//! ```
+//!
+//! ## 렌더링 결과
+//! ```html
+//! <!-- 용어집 페이지 -->
+//! <dl class="glossary">
+//!   <dt id="term-ir"><dfn>IR</dfn></dt><dd>중간 표현</dd>
+//!   <dt id="term-ssg"><dfn>SSG</dfn></dt><dd>정적 사이트 생성기. 빌드할 때 HTML을 미리 만듭니다.</dd>
+//! </dl>
+//!
+//! <!-- 다른 페이지 본문: 처음 나온 "SSG"만 -->
+//! <p>Quo는 <a class="glossary-term" href="/glossary.html#term-ssg" title="정적 사이트 생성기. ...">SSG</a>입니다.
+//!    SSG는 ...</p>
+//! ```
+//!
+//! ## 구현 상태
+//! - [x] 용어 등록, 정의 위치 지정 (`term_at`)
+//! - [x] 전체 목록 Block (`GlossaryBlock`, 용어순, 정의의 인라인 마크업)
+//! - [x] 페이지마다 처음 나온 용어 자동 링크 (`auto_link`, 분석 단계 `GlossaryLinker`)
+//! - [ ] TODO: 별칭(복수형, 풀어 쓴 이름), 용어별 사용 페이지 목록
+//!
+//! ## 설계 결정
+//! - **분석에서 찾고 조립에서 링크**: `GlossaryLinker`가 페이지마다 링크할 용어를 정하고(`GlossaryLinks`),
+//!   문서 조립 단계(`document_ir`)가 페이지 본문 IR의 텍스트를 나눠 링크를 넣습니다.
+//!   Layout(머리글, 바닥글)의 텍스트는 링크하지 않습니다.
+//! - **정의 위치**: `term_at`으로 지정하지 않은 용어는 `GlossaryBlock`이 있는 페이지의 anchor로 링크합니다.
+//!   용어집 페이지가 없으면 링크하지 않고, 용어집 페이지 자신도 링크하지 않습니다.
+//! - **링크하지 않는 곳**: 이미 링크인 곳, 코드(`code`, `pre`, `kbd`), 제목(h1~h6), `dfn`, 스크립트와 스타일.
+//! - **일치 규칙**: 대소문자를 구분하지 않습니다. 영문자/숫자로 시작하거나 끝나는 용어는 앞뒤가 영문자/숫자이면
+//!   (단어 중간이면) 일치로 보지 않습니다. 한글 용어는 조사가 붙어도 일치합니다.
+
+use std::collections::BTreeMap;
+
+use crate::block::block::{Block, class_attrs, element, prose_rule, token_attrs, verbatim_rule, with_tokens};
+use crate::block::context::RenderContext;
+use crate::block::inline::{parse_inline, render_inline};
+use crate::cite::analysis::normalize_url;
+use crate::html::attributes::SharedAttrs;
+use crate::html::node::{Element, ElementType, IRNode};
+use crate::html::trust::{Content, SafeString, unescape_html_chars};
+use crate::metadata::builder::Metadata;
+use crate::metadata::types::CurrentUrl;
+use crate::metadata::value::MetadataValue;
+use crate::page::slug::Slug;
+
+/// 용어 하나.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct GlossaryTerm {
+    pub term: String,
+    /// 정의 (인라인 마크업 가능)
+    pub definition: String,
+    /// 정의 위치. 없으면 용어집 페이지의 anchor
+    pub href: Option<String>,
+}
+
+/// 사이트 용어집. 전역 메타데이터로 넣습니다.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Glossary {
+    terms: BTreeMap<String, GlossaryTerm>,
+    auto_link: bool,
+}
+impl MetadataValue for Glossary {}
+
+impl Glossary {
+    pub fn new() -> Self {
+        Glossary::default()
+    }
+
+    pub fn term(mut self, term: &str, definition: &str) -> Self {
+        self.insert(term, definition, None);
+        self
+    }
+
+    /// 정의가 용어집 페이지가 아닌 곳에 있는 용어.
+    pub fn term_at(mut self, term: &str, definition: &str, href: &str) -> Self {
+        self.insert(term, definition, Some(href.to_string()));
+        self
+    }
+
+    /// 페이지마다 처음 나온 용어를 정의로 링크. 기본: 끔
+    pub fn auto_link(mut self, auto_link: bool) -> Self {
+        self.auto_link = auto_link;
+        self
+    }
+
+    fn insert(&mut self, term: &str, definition: &str, href: Option<String>) {
+        let term = term.trim();
+        if term.is_empty() {
+            return;
+        }
+        let entry = GlossaryTerm {
+            term: term.to_string(),
+            definition: definition.to_string(),
+            href,
+        };
+        self.terms.insert(term.to_string(), entry);
+    }
+
+    pub fn get(&self, term: &str) -> Option<&GlossaryTerm> {
+        self.terms.get(term)
+    }
+
+    /// 모든 용어 (용어순).
+    pub fn iter(&self) -> impl Iterator<Item = &GlossaryTerm> {
+        self.terms.values()
+    }
+
+    pub fn is_auto_link(&self) -> bool {
+        self.auto_link
+    }
+
+    /// 용어의 정의 위치. `glossary_page`는 `GlossaryBlock`이 있는 페이지 URL.
+    pub fn href(&self, term: &str, glossary_page: Option<&str>) -> Option<String> {
+        let entry = self.terms.get(term)?;
+        match &entry.href {
+            Some(href) => Some(href.clone()),
+            None => glossary_page.map(|page| format!("{}#{}", page, term_anchor(term))),
+        }
+    }
+}
+
+/// 용어집 목록의 용어 anchor. 예: `term-ssg`
+pub fn term_anchor(term: &str) -> String {
+    format!("term-{}", Slug::new(term))
+}
+
+/// `GlossaryBlock`이 있는 Block 표시. 분석 단계가 용어집 페이지를 찾는 데 씁니다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlossaryListing;
+impl MetadataValue for GlossaryListing {}
+
+/// 분석 결과: 용어집 페이지와 페이지마다 링크할 용어 (`GlossaryLinker`).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GlossaryLinks {
+    /// `GlossaryBlock`이 있는 첫 페이지 URL
+    pub page: Option<String>,
+    /// 정규화된 페이지 URL → 처음 나온 순서의 용어
+    pub terms: BTreeMap<String, Vec<String>>,
+}
+impl MetadataValue for GlossaryLinks {}
+
+/// 용어집 전체 목록 Block.
+#[derive(Debug, Clone, Default)]
+pub struct GlossaryBlock;
+
+impl GlossaryBlock {
+    pub fn new() -> Self {
+        GlossaryBlock
+    }
+}
+
+impl Block for GlossaryBlock {
+    fn metadata(&self) -> Metadata {
+        Metadata::new().custom(GlossaryListing)
+    }
+
+    fn render_to_ir(&self, ctx: &RenderContext) -> IRNode {
+        let Some(glossary) = ctx.get::<Glossary>() else {
+            return element("dl", class_attrs(&["glossary"]), vec![]);
+        };
+        let rule = prose_rule();
+        let childs = glossary
+            .iter()
+            .flat_map(|entry| {
+                let dfn = element(
+                    "dfn",
+                    SharedAttrs::new(),
+                    vec![Element::Text(Content::from_str(&entry.term, &rule))],
+                );
+                let id = term_anchor(&entry.term);
+                [
+                    Element::Node(element("dt", token_attrs(&[("id", &id)]), vec![Element::Node(dfn)])),
+                    Element::Node(element(
+                        "dd",
+                        SharedAttrs::new(),
+                        render_inline(&parse_inline(&entry.definition), ctx),
+                    )),
+                ]
+            })
+            .collect();
+        element("dl", class_attrs(&["glossary"]), childs)
+    }
+}
+
+// ============================================================================
+// 용어 찾기와 링크
+// ============================================================================
+
+/// 안의 텍스트를 링크하지 않는 요소.
+const SKIPPED_TAGS: &[&str] = &[
+    "a", "code", "pre", "kbd", "samp", "script", "style", "dfn", "h1", "h2", "h3", "h4", "h5", "h6", "button",
+    "label", "svg", "textarea",
+];
+
+/// IR의 텍스트에서 용어가 처음 나오는 순서대로 `found`에 추가 (이미 있는 용어는 건너뜀).
+pub(crate) fn collect_terms(node: &IRNode, glossary: &Glossary, found: &mut Vec<String>) {
+    if SKIPPED_TAGS.contains(&node.get_tag().as_str()) {
+        return;
+    }
+    for child in node.get_childs() {
+        match child {
+            Element::Text(content) => {
+                let text = fold_case(&unescape_html_chars(content.as_str()));
+                let mut hits: Vec<(usize, &str)> = glossary
+                    .iter()
+                    .filter(|entry| !found.contains(&entry.term))
+                    .filter_map(|entry| find_term(&text, &fold_case(&entry.term)).map(|at| (at, entry.term.as_str())))
+                    .collect();
+                hits.sort();
+                found.extend(hits.into_iter().map(|(_, term)| term.to_string()));
+            }
+            Element::Node(child) => collect_terms(child, glossary, found),
+            _ => {}
+        }
+    }
+}
+
+/// 페이지 본문 IR에 용어 링크 추가. 자동 링크가 꺼져 있거나 분석 결과가 없으면 그대로 반환합니다.
+pub(crate) fn link_glossary_terms(content: IRNode, ctx: &RenderContext) -> IRNode {
+    let (Some(glossary), Some(links), Some(url)) = (
+        ctx.get::<Glossary>().filter(|g| g.is_auto_link()),
+        ctx.index().and_then(|index| index.get::<GlossaryLinks>()),
+        ctx.get::<CurrentUrl>().map(|u| normalize_url(&u.0)),
+    ) else {
+        return content;
+    };
+    if links.page.as_deref().map(normalize_url) == Some(url.clone()) {
+        return content;
+    }
+    let mut targets: Vec<LinkTarget> = links
+        .terms
+        .get(&url)
+        .into_iter()
+        .flatten()
+        .filter_map(|term| {
+            let entry = glossary.get(term)?;
+            Some(LinkTarget {
+                folded: fold_case(term),
+                href: glossary.href(term, links.page.as_deref())?,
+                definition: entry.definition.clone(),
+            })
+        })
+        .collect();
+    // 같은 위치에서는 긴 용어 먼저 ("static site"가 "site"보다)
+    targets.sort_by_key(|target| std::cmp::Reverse(target.folded.len()));
+    link_node(&content, &mut targets)
+}
+
+struct LinkTarget {
+    folded: Vec<char>,
+    href: String,
+    definition: String,
+}
+
+fn link_node(node: &IRNode, targets: &mut Vec<LinkTarget>) -> IRNode {
+    if targets.is_empty()
+        || matches!(node.get_type(), ElementType::Void)
+        || SKIPPED_TAGS.contains(&node.get_tag().as_str())
+    {
+        return node.clone();
+    }
+    let mut childs = Vec::new();
+    for child in node.get_childs() {
+        match child {
+            Element::Text(content) if !targets.is_empty() => childs.extend(link_text(content, targets)),
+            Element::Node(child) => childs.push(Element::Node(link_node(child, targets))),
+            other => childs.push(other.clone()),
+        }
+    }
+    node.with_childs(childs)
+}
+
+/// 텍스트 하나에서 남은 용어의 첫 일치를 링크로 나눔. 링크한 용어는 `targets`에서 뺍니다.
+fn link_text(content: &Content, targets: &mut Vec<LinkTarget>) -> Vec<Element> {
+    let chars: Vec<char> = unescape_html_chars(content.as_str()).chars().collect();
+    let folded = fold_case(&chars.iter().collect::<String>());
+    let hit = targets
+        .iter()
+        .enumerate()
+        .filter_map(|(i, target)| find_term(&folded, &target.folded).map(|at| (at, i)))
+        .min_by_key(|(at, _)| *at);
+    let Some((at, i)) = hit else {
+        return vec![Element::Text(content.clone())];
+    };
+
+    let target = targets.remove(i);
+    let end = at + target.folded.len();
+    let rule = verbatim_rule();
+    let text = |chars: &[char]| Content::from_str(&chars.iter().collect::<String>(), &rule);
+    let link = element(
+        "a",
+        with_tokens(
+            class_attrs(&["glossary-term"]),
+            &[("href", &target.href), ("title", &target.definition)],
+        ),
+        vec![Element::Text(text(&chars[at..end]))],
+    );
+
+    let mut result = Vec::new();
+    if at > 0 {
+        result.push(Element::Text(text(&chars[..at])));
+    }
+    result.push(Element::Node(link));
+    if end < chars.len() {
+        result.extend(link_text(&text(&chars[end..]), targets));
+    }
+    result
+}
+
+/// 대소문자 무시 비교용 (문자 수 유지).
+fn fold_case(text: &str) -> Vec<char> {
+    text.chars().map(|c| c.to_lowercase().next().unwrap_or(c)).collect()
+}
+
+/// 단어 경계를 지키는 첫 일치 위치.
+fn find_term(text: &[char], term: &[char]) -> Option<usize> {
+    if term.is_empty() || term.len() > text.len() {
+        return None;
+    }
+    let ascii_word = |c: Option<&char>| c.is_some_and(|c| c.is_ascii_alphanumeric());
+    (0..=text.len() - term.len()).find(|&at| {
+        text[at..at + term.len()] == *term
+            && !(ascii_word(term.first()) && at > 0 && ascii_word(text.get(at - 1)))
+            && !(ascii_word(term.last()) && ascii_word(text.get(at + term.len())))
+    })
+}
@@ -0,0 +1,306 @@
+//! # hbox.rs - HBox (수평 배치)
+//!
+//! ## 목적
+//! 자식 Block들을 글 방향 순서로 나란히 배치하는 레이아웃 Block입니다 (LTR은 왼쪽부터, RTL은 오른쪽부터).
+//! 열 너비(`Width`)를 자식마다 지정할 수 있습니다.
+//!
+//! ## 렌더링 결과
+//! ```html
+//! <div class="hbox hbox-9490931d hbox-gap-large">
+//!   <div class="hbox-item" style="flex: 0 1 70%"><!-- child 1 --></div>
+//!   <div class="hbox-item" style="flex: 0 1 30%"><!-- child 2 --></div>
+//! </div>
+//! ```
+//!
+//! ## 사용 예시
+//! ```rust
+//! let columns = HBox::new(vec![main, aside])
+//!     .widths(vec![Width::Percent(70), Width::Percent(30)])
+//!     .gap(Spacing::Large)
+//!     .alignment(HorizontalAlignment::Center);
+//!
+//! columns.validate()?;  // 너비 개수/합계 검증
+//! columns.render_to_ir(ctx)
+//! ```
+//!
+//! ## 구현 상태
+//! - [x] 열 너비 (Percent, Fraction, Fixed, Auto)
+//! - [x] 간격 (Spacing), 정렬 (HorizontalAlignment)
+//! - [x] 너비 검증 (`validate()`, 빌드 시 `Block::check`로 자동 검사)
+//! - [x] 의존 CSS (`HBox::CSS`, `HBox::stylesheet()`)
+//! - [x] 의존 CSS를 페이지 asset으로 자동 주입 (`Block::css`, 범위 class `hbox-<해시>`)
+//! - [x] 글 방향 (`direction`: 문서와 다른 방향의 영역에 `dir`)
+//! - [ ] TODO: 좁은 화면에서 세로 배치로 전환 (반응형)
+//!
+//! ## 설계 결정
+//! - **`flex-shrink: 1`**: 문서 초안은 `flex: 0 0 70%`였지만, gap이 있으면 70% + 30%가
+//!   컨테이너를 넘칩니다. 줄어들 수 있게 하여 gap과 함께 써도 넘치지 않습니다.
+//! - **논리 방향만 사용**: `flex-direction:row`, `flex-start`/`flex-end`는 글 방향을 따르므로
+//!   `<html dir="rtl">` 문서에서는 첫 자식이 오른쪽에 옵니다. 정렬 이름도 Left/Right가 아닌 Start/End입니다.
+//! - **검증은 렌더링과 분리**: `render_to_ir()`는 실패할 수 없으므로, 너비가 부족하면
+//!   남은 자식은 `Auto`로 렌더링하고 잘못된 구성은 `validate()`로 확인합니다.
+
+use std::fmt::Display;
+
+use crate::block::block::{
+    Block, class_attrs, element, render_block, with_style, with_tokens,
+};
+use crate::block::context::RenderContext;
+use crate::block::css::ScopedCss;
+use crate::html::node::{Element, IRNode};
+use crate::metadata::types::TextDirection;
+use crate::page::spacing::{SPACING_SCALE, Spacing};
+
+// ============================================================================
+// 열 너비 / 정렬
+// ============================================================================
+
+/// HBox 열 너비.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Width {
+    /// 컨테이너 대비 비율 (0..=100)
+    Percent(u8),
+    /// 남은 공간을 비율로 나눔 (`flex-grow`)
+    Fraction(u32),
+    /// 고정 CSS 길이. 예: `"240px"`
+    Fixed(String),
+    /// 내용에 맞춤
+    Auto,
+}
+
+impl Width {
+    fn inline_style(&self) -> Option<String> {
+        match self {
+            Width::Percent(p) => Some(format!("flex: 0 1 {}%", p)),
+            Width::Fraction(n) => Some(format!("flex: {} 1 0", n)),
+            Width::Fixed(length) => Some(format!("flex: 0 0 {}", length)),
+            Width::Auto => None,
+        }
+    }
+}
+
+/// HBox 자식들의 가로 방향 분배 (flex `justify-content`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HorizontalAlignment {
+    #[default]
+    Start,
+    Center,
+    End,
+    SpaceBetween,
+}
+
+impl HorizontalAlignment {
+    fn class_suffix(&self) -> &'static str {
+        match self {
+            HorizontalAlignment::Start => "start",
+            HorizontalAlignment::Center => "center",
+            HorizontalAlignment::End => "end",
+            HorizontalAlignment::SpaceBetween => "between",
+        }
+    }
+
+    fn css_value(&self) -> &'static str {
+        match self {
+            HorizontalAlignment::Start => "flex-start",
+            HorizontalAlignment::Center => "center",
+            HorizontalAlignment::End => "flex-end",
+            HorizontalAlignment::SpaceBetween => "space-between",
+        }
+    }
+}
+
+/// HBox 구성 오류.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HBoxError {
+    /// 지정한 너비 개수가 자식 개수와 다름
+    WidthCountMismatch { widths: usize, children: usize },
+    /// Percent 너비의 합이 100을 넘음
+    PercentOverflow { total: u32 },
+}
+
+impl Display for HBoxError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HBoxError::WidthCountMismatch { widths, children } => write!(
+                f,
+                "HBox has {} widths but {} children",
+                widths, children
+            ),
+            HBoxError::PercentOverflow { total } => {
+                write!(f, "HBox percent widths add up to {}%", total)
+            }
+        }
+    }
+}
+
+impl std::error::Error for HBoxError {}
+
+// ============================================================================
+// HBox
+// ============================================================================
+
+/// 수평 배치 레이아웃 Block.
+pub struct HBox {
+    children: Vec<Box<dyn Block>>,
+    spacing: Option<Spacing>,
+    alignment: HorizontalAlignment,
+    widths: Vec<Width>,
+    direction: Option<TextDirection>,
+}
+
+impl HBox {
+    /// HBox가 의존하는 CSS.
+    pub const CSS: &'static str = ".hbox{display:flex;flex-direction:row}.hbox-item{min-width:0}";
+
+    pub fn new(children: Vec<Box<dyn Block>>) -> Self {
+        HBox {
+            children,
+            spacing: None,
+            alignment: HorizontalAlignment::default(),
+            widths: Vec::new(),
+            direction: None,
+        }
+    }
+
+    /// 자식별 열 너비. 순서대로 대응합니다.
+    pub fn widths(mut self, widths: Vec<Width>) -> Self {
+        self.widths = widths;
+        self
+    }
+
+    pub fn gap(mut self, spacing: Spacing) -> Self {
+        self.spacing = Some(spacing);
+        self
+    }
+
+    pub fn alignment(mut self, alignment: HorizontalAlignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
+    /// 글 방향. 문서와 방향이 다른 영역(영어 페이지 안의 아랍어 단락 등)에 `dir`을 붙이고
+    /// 자식 Block도 그 방향으로 렌더링합니다. 기본: 문서 방향을 따름
+    pub fn direction(mut self, direction: TextDirection) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    pub fn push(mut self, child: Box<dyn Block>) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    /// 너비 구성 검증. 너비를 지정하지 않은 경우(모두 Auto)는 항상 유효.
+    pub fn validate(&self) -> Result<(), HBoxError> {
+        if !self.widths.is_empty() && self.widths.len() != self.children.len() {
+            return Err(HBoxError::WidthCountMismatch {
+                widths: self.widths.len(),
+                children: self.children.len(),
+            });
+        }
+
+        let total: u32 = self
+            .widths
+            .iter()
+            .map(|w| match w {
+                Width::Percent(p) => *p as u32,
+                _ => 0,
+            })
+            .sum();
+        if total > 100 {
+            return Err(HBoxError::PercentOverflow { total });
+        }
+
+        Ok(())
+    }
+
+    /// 간격/정렬 class까지 포함한 전체 스타일시트 생성.
+    pub fn stylesheet() -> String {
+        format!("{}{}", Self::CSS, Self::modifier_rules(""))
+    }
+
+    /// 간격/정렬 class 규칙. 각 선택자 앞에 `scope`를 붙입니다.
+    fn modifier_rules(scope: &str) -> String {
+        let mut css = String::new();
+        for (name, value) in SPACING_SCALE {
+            css.push_str(&format!("{}.hbox-gap-{}{{gap:{}}}", scope, name, value));
+        }
+        for alignment in [
+            HorizontalAlignment::Start,
+            HorizontalAlignment::Center,
+            HorizontalAlignment::End,
+            HorizontalAlignment::SpaceBetween,
+        ] {
+            css.push_str(&format!(
+                "{}.hbox-align-{}{{justify-content:{}}}",
+                scope,
+                alignment.class_suffix(),
+                alignment.css_value()
+            ));
+        }
+        css
+    }
+}
+
+impl Block for HBox {
+    fn children(&self) -> Vec<&dyn Block> {
+        self.children.iter().map(|c| c.as_ref()).collect()
+    }
+
+    fn check(&self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        Ok(self.validate()?)
+    }
+
+    fn css(&self) -> Option<ScopedCss> {
+        let rules = format!(
+            "&{{display:flex;flex-direction:row}}&>.hbox-item{{min-width:0}}{}",
+            HBox::modifier_rules("&")
+        );
+        Some(ScopedCss::new("hbox", &rules))
+    }
+
+    fn render_to_ir(&self, ctx: &RenderContext) -> IRNode {
+        let mut classes = vec!["hbox".to_string()];
+        classes.extend(self.css().map(|css| css.class()));
+        if let Some(suffix) = self.spacing.as_ref().and_then(|s| s.class_suffix()) {
+            classes.push(format!("hbox-gap-{}", suffix));
+        }
+        if self.alignment != HorizontalAlignment::Start {
+            classes.push(format!("hbox-align-{}", self.alignment.class_suffix()));
+        }
+
+        let class_refs: Vec<&str> = classes.iter().map(|c| c.as_str()).collect();
+        let mut attrs = class_attrs(&class_refs);
+        if let Some(style) = self.spacing.as_ref().and_then(|s| s.inline_style()) {
+            attrs = with_style(attrs, &style);
+        }
+        let inner;
+        let ctx = match self.direction {
+            Some(direction) => {
+                attrs = with_tokens(attrs, &[("dir", direction.as_str())]);
+                inner = ctx.with_direction(direction);
+                &inner
+            }
+            None => ctx,
+        };
+
+        let items = self
+            .children
+            .iter()
+            .enumerate()
+            .map(|(i, child)| {
+                let mut item_attrs = class_attrs(&["hbox-item"]);
+                if let Some(style) = self.widths.get(i).and_then(|w| w.inline_style()) {
+                    item_attrs = with_style(item_attrs, &style);
+                }
+                Element::Node(element(
+                    "div",
+                    item_attrs,
+                    vec![Element::Node(render_block(child.as_ref(), ctx))],
+                ))
+            })
+            .collect();
+
+        element("div", attrs, items)
+    }
+}
@@ -0,0 +1,500 @@
+//! # head.rs - HTML `<head>` 구성
+//!
+//! ## 목적
+//! Page가 `head(ctx)`로 반환하는 `<head>` 내용을 표현합니다.
+//! 메타태그, 스타일시트, 스크립트를 빌더로 쌓은 뒤 `to_irnode()`로 변환합니다.
+//!
+//! ## 사용 예시
+//! ```rust
+//! HeadElements::new()
+//!     .title("My Post - My Blog")
+//!     .description("Post excerpt...")
+//!     .keywords(vec!["rust", "programming"])
+//!     .canonical_url("https://example.com/blog/my-post.html")
+//!     .stylesheet("/css/blog.css")
+//!     .add_script(Script::new("/js/highlight.js").defer(true))
+//!     .meta_name("author", "racakenon")
+//!     .open_graph(OpenGraph::new("My Post").og_type(OgType::Article))
+//! ```
+//!
+//! ## 렌더링 순서
+//! ```text
+//! charset → viewport → title → description → keywords → canonical
+//! → 임의 link → 임의 meta → og:* → twitter:* → 인라인 style → stylesheet → script
+//! ```
+//!
+//! ## 구현 상태
+//! - [x] charset, viewport, title, description, keywords, canonical
+//! - [x] 스타일시트 (media), 스크립트 (defer, async, module)
+//! - [x] 임의 meta (name, property, http-equiv), 임의 link
+//! - [x] 중복 제거 (같은 href/src, 같은 meta)
+//! - [x] Open Graph, Twitter Card (`social` 모듈)
+//! - [x] 핵심 CSS 인라인 + 스타일시트 지연 로드 (`critical_css`)
+//!
+//! ## 설계 결정
+//! - **중복은 먼저 온 것 유지**: 레이아웃과 Block이 같은 스타일시트를 각각 요청해도
+//!   한 번만 출력되며, 처음 추가된 위치(순서)가 유지됩니다.
+//! - **스크립트 옵션은 `Script`에 함께 지정**: `add_script(Script::new(src).defer(true))`처럼
+//!   추가하는 호출 자체에 옵션을 담습니다. 같은 src가 이미 있어 무시되는 경우에도
+//!   다른 스크립트의 옵션이 바뀌지 않습니다.
+
+use crate::block::block::{element, prose_rule, token_attrs, void_element};
+use crate::html::attributes::{AttrValues, SharedAttrs};
+use crate::html::elements::Style;
+use crate::html::node::{Element, IRNode, Node};
+use crate::html::trust::{AttrKey, Content, SafeString};
+use crate::page::social::{OpenGraph, SocialMetaError, TwitterCard};
+
+// ============================================================================
+// 구성 요소
+// ============================================================================
+
+/// `<link rel="stylesheet">` 항목.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Stylesheet {
+    pub href: String,
+    pub media: Option<String>,
+}
+
+/// 임의 `<link>` 항목. 예: icon, manifest, prev/next, alternate
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LinkTag {
+    pub rel: String,
+    pub href: String,
+    /// rel/href 외 속성. 예: `("sizes", "32x32")`, `("type", "image/png")`
+    pub attrs: Vec<(String, String)>,
+}
+
+impl LinkTag {
+    pub fn new(rel: &str, href: &str) -> Self {
+        LinkTag {
+            rel: rel.to_string(),
+            href: href.to_string(),
+            attrs: Vec::new(),
+        }
+    }
+
+    pub fn attr(mut self, key: &str, value: &str) -> Self {
+        self.attrs.push((key.to_string(), value.to_string()));
+        self
+    }
+
+    fn attr_value(&self, key: &str) -> Option<&str> {
+        self.attrs.iter().find(|(k, _)| k == key).map(|(_, v)| v.as_str())
+    }
+
+    fn to_irnode(&self) -> IRNode {
+        let mut pairs = vec![("rel", self.rel.as_str()), ("href", self.href.as_str())];
+        pairs.extend(self.attrs.iter().map(|(k, v)| (k.as_str(), v.as_str())));
+        void_element("link", token_attrs(&pairs))
+    }
+}
+
+/// `<script>` 항목.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Script {
+    pub src: String,
+    pub defer: bool,
+    pub async_: bool,
+    pub module: bool,
+}
+
+impl Script {
+    /// 옵션 없는 스크립트.
+    pub fn new(src: &str) -> Self {
+        Script {
+            src: src.to_string(),
+            defer: false,
+            async_: false,
+            module: false,
+        }
+    }
+
+    pub fn defer(mut self, defer: bool) -> Self {
+        self.defer = defer;
+        self
+    }
+
+    pub fn async_(mut self, async_: bool) -> Self {
+        self.async_ = async_;
+        self
+    }
+
+    /// `type="module"`로 지정.
+    pub fn module(mut self, module: bool) -> Self {
+        self.module = module;
+        self
+    }
+}
+
+/// 임의 `<meta>` 태그.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MetaTag {
+    /// `<meta name="..." content="...">`
+    Name { name: String, content: String },
+    /// `<meta property="..." content="...">` (Open Graph 등)
+    Property { property: String, content: String },
+    /// `<meta http-equiv="..." content="...">`
+    HttpEquiv { http_equiv: String, content: String },
+}
+
+impl MetaTag {
+    fn to_irnode(&self) -> IRNode {
+        let (key, value, content) = match self {
+            MetaTag::Name { name, content } => ("name", name, content),
+            MetaTag::Property { property, content } => ("property", property, content),
+            MetaTag::HttpEquiv { http_equiv, content } => ("http-equiv", http_equiv, content),
+        };
+        void_element("meta", token_attrs(&[(key, value), ("content", content)]))
+    }
+}
+
+// ============================================================================
+// HeadElements
+// ============================================================================
+
+/// `<head>` 요소 모음.
+#[derive(Debug, Clone)]
+pub struct HeadElements {
+    pub title: Option<String>,
+    pub charset: String,
+    pub viewport: String,
+    pub description: Option<String>,
+    pub keywords: Vec<String>,
+    pub canonical_url: Option<String>,
+    pub links: Vec<LinkTag>,
+    pub stylesheets: Vec<Stylesheet>,
+    pub scripts: Vec<Script>,
+    pub meta_tags: Vec<MetaTag>,
+    pub open_graph: Option<OpenGraph>,
+    pub twitter_card: Option<TwitterCard>,
+    /// `<style>`로 인라인할 CSS
+    pub inline_styles: Vec<String>,
+    /// true면 스타일시트를 preload + onload로 지연 로드
+    pub defer_stylesheets: bool,
+}
+
+impl Default for HeadElements {
+    fn default() -> Self {
+        HeadElements {
+            title: None,
+            charset: "UTF-8".to_string(),
+            viewport: "width=device-width, initial-scale=1.0".to_string(),
+            description: None,
+            keywords: Vec::new(),
+            canonical_url: None,
+            links: Vec::new(),
+            stylesheets: Vec::new(),
+            scripts: Vec::new(),
+            meta_tags: Vec::new(),
+            open_graph: None,
+            twitter_card: None,
+            inline_styles: Vec::new(),
+            defer_stylesheets: false,
+        }
+    }
+}
+
+impl HeadElements {
+    pub fn new() -> Self {
+        HeadElements::default()
+    }
+
+    pub fn title(mut self, title: &str) -> Self {
+        self.title = Some(title.to_string());
+        self
+    }
+
+    pub fn charset(mut self, charset: &str) -> Self {
+        self.charset = charset.to_string();
+        self
+    }
+
+    pub fn viewport(mut self, viewport: &str) -> Self {
+        self.viewport = viewport.to_string();
+        self
+    }
+
+    pub fn description(mut self, description: &str) -> Self {
+        self.description = Some(description.to_string());
+        self
+    }
+
+    /// 키워드 추가 (중복 제거).
+    pub fn keywords(mut self, keywords: Vec<&str>) -> Self {
+        for keyword in keywords {
+            if !self.keywords.iter().any(|k| k == keyword) {
+                self.keywords.push(keyword.to_string());
+            }
+        }
+        self
+    }
+
+    pub fn canonical_url(mut self, url: &str) -> Self {
+        self.canonical_url = Some(url.to_string());
+        self
+    }
+
+    /// 임의 link 추가. 같은 rel + href가 이미 있으면 무시.
+    /// hreflang 대체 링크는 언어마다 따로 봅니다 (`x-default`가 기본 언어와 같은 URL을 가리킴).
+    pub fn link(mut self, link: LinkTag) -> Self {
+        if !self.links.iter().any(|l| {
+            l.rel == link.rel && l.href == link.href && l.attr_value("hreflang") == link.attr_value("hreflang")
+        }) {
+            self.links.push(link);
+        }
+        self
+    }
+
+    /// 스타일시트 추가. 같은 href가 이미 있으면 무시.
+    pub fn stylesheet(self, href: &str) -> Self {
+        self.add_stylesheet(Stylesheet {
+            href: href.to_string(),
+            media: None,
+        })
+    }
+
+    /// media 조건이 있는 스타일시트 추가. 예: `"print"`
+    pub fn stylesheet_media(self, href: &str, media: &str) -> Self {
+        self.add_stylesheet(Stylesheet {
+            href: href.to_string(),
+            media: Some(media.to_string()),
+        })
+    }
+
+    pub fn add_stylesheet(mut self, stylesheet: Stylesheet) -> Self {
+        if !self.stylesheets.iter().any(|s| s.href == stylesheet.href) {
+            self.stylesheets.push(stylesheet);
+        }
+        self
+    }
+
+    /// 스크립트 추가. 같은 src가 이미 있으면 무시.
+    pub fn script(self, src: &str) -> Self {
+        self.add_script(Script::new(src))
+    }
+
+    /// 옵션을 지정한 스크립트 추가. 같은 src가 이미 있으면 먼저 온 것을 유지합니다.
+    pub fn add_script(mut self, script: Script) -> Self {
+        if !self.scripts.iter().any(|s| s.src == script.src) {
+            self.scripts.push(script);
+        }
+        self
+    }
+
+    /// `<meta name>` 추가.
+    pub fn meta_name(self, name: &str, content: &str) -> Self {
+        self.meta(MetaTag::Name {
+            name: name.to_string(),
+            content: content.to_string(),
+        })
+    }
+
+    /// `<meta property>` 추가.
+    pub fn meta_property(self, property: &str, content: &str) -> Self {
+        self.meta(MetaTag::Property {
+            property: property.to_string(),
+            content: content.to_string(),
+        })
+    }
+
+    /// 임의 meta 추가. 완전히 같은 태그가 이미 있으면 무시.
+    pub fn meta(mut self, tag: MetaTag) -> Self {
+        if !self.meta_tags.contains(&tag) {
+            self.meta_tags.push(tag);
+        }
+        self
+    }
+
+    /// `<style>` 인라인 CSS 추가. 같은 내용은 한 번만.
+    pub fn inline_style(mut self, css: &str) -> Self {
+        if !css.trim().is_empty() && !self.inline_styles.iter().any(|s| s == css) {
+            self.inline_styles.push(css.to_string());
+        }
+        self
+    }
+
+    /// 스타일시트 지연 로드 여부. 핵심 CSS를 인라인할 때 함께 사용.
+    pub fn defer_stylesheets(mut self, defer: bool) -> Self {
+        self.defer_stylesheets = defer;
+        self
+    }
+
+    /// 핵심 CSS 인라인 + 나머지 스타일시트 지연 로드.
+    pub fn critical_css(self, css: &str) -> Self {
+        self.inline_style(css).defer_stylesheets(true)
+    }
+
+    pub fn open_graph(mut self, open_graph: OpenGraph) -> Self {
+        self.open_graph = Some(open_graph);
+        self
+    }
+
+    pub fn twitter_card(mut self, twitter_card: TwitterCard) -> Self {
+        self.twitter_card = Some(twitter_card);
+        self
+    }
+
+    /// Open Graph / Twitter Card 구성 검증.
+    pub fn validate(&self) -> Result<(), SocialMetaError> {
+        if let Some(og) = &self.open_graph {
+            og.validate()?;
+        }
+        if let Some(card) = &self.twitter_card {
+            let og_image = self.open_graph.as_ref().and_then(|og| og.image.as_deref());
+            card.validate(og_image)?;
+        }
+        Ok(())
+    }
+
+    /// 다른 HeadElements의 항목을 뒤에 이어 붙임.
+    /// 단일 값(title, description, canonical, og, twitter)은 `other`에 있을 때만,
+    /// charset/viewport는 `other`가 기본값이 아닐 때만 덮어씁니다.
+    pub fn extend(mut self, other: HeadElements) -> Self {
+        let defaults = HeadElements::default();
+        if other.charset != defaults.charset {
+            self.charset = other.charset;
+        }
+        if other.viewport != defaults.viewport {
+            self.viewport = other.viewport;
+        }
+        if other.title.is_some() {
+            self.title = other.title;
+        }
+        if other.description.is_some() {
+            self.description = other.description;
+        }
+        if other.canonical_url.is_some() {
+            self.canonical_url = other.canonical_url;
+        }
+        if other.open_graph.is_some() {
+            self.open_graph = other.open_graph;
+        }
+        if other.twitter_card.is_some() {
+            self.twitter_card = other.twitter_card;
+        }
+        self = self.keywords(other.keywords.iter().map(|k| k.as_str()).collect());
+        for link in other.links {
+            self = self.link(link);
+        }
+        for stylesheet in other.stylesheets {
+            self = self.add_stylesheet(stylesheet);
+        }
+        for script in other.scripts {
+            self = self.add_script(script);
+        }
+        for tag in other.meta_tags {
+            self = self.meta(tag);
+        }
+        for css in other.inline_styles {
+            self = self.inline_style(&css);
+        }
+        self.defer_stylesheets |= other.defer_stylesheets;
+        self
+    }
+
+    /// 스타일시트 하나의 요소. 지연 로드면 preload link + noscript 대체.
+    fn stylesheet_elements(&self, stylesheet: &Stylesheet) -> Vec<Element> {
+        let mut pairs = vec![("rel", "stylesheet"), ("href", stylesheet.href.as_str())];
+        if let Some(media) = &stylesheet.media {
+            pairs.push(("media", media));
+        }
+        let link = void_element("link", token_attrs(&pairs));
+        if !self.defer_stylesheets {
+            return vec![Element::Node(link)];
+        }
+
+        let mut preload = vec![
+            ("rel", "preload"),
+            ("as", "style"),
+            ("href", stylesheet.href.as_str()),
+            ("onload", "this.onload=null;this.rel='stylesheet'"),
+        ];
+        if let Some(media) = &stylesheet.media {
+            preload.push(("media", media));
+        }
+        vec![
+            Element::Node(void_element("link", token_attrs(&preload))),
+            Element::Node(element("noscript", SharedAttrs::new(), vec![Element::Node(link)])),
+        ]
+    }
+
+    /// `<head>` IRNode로 변환.
+    pub fn to_irnode(&self) -> IRNode {
+        let mut childs = vec![
+            Element::Node(void_element("meta", token_attrs(&[("charset", &self.charset)]))),
+            Element::Node(void_element(
+                "meta",
+                token_attrs(&[("name", "viewport"), ("content", &self.viewport)]),
+            )),
+        ];
+
+        if let Some(title) = &self.title {
+            childs.push(Element::Node(element(
+                "title",
+                SharedAttrs::new(),
+                vec![Element::Text(Content::from_str(title, &prose_rule()))],
+            )));
+        }
+
+        if let Some(description) = &self.description {
+            childs.push(Element::Node(void_element(
+                "meta",
+                token_attrs(&[("name", "description"), ("content", description)]),
+            )));
+        }
+
+        if !self.keywords.is_empty() {
+            childs.push(Element::Node(void_element(
+                "meta",
+                token_attrs(&[("name", "keywords"), ("content", &self.keywords.join(", "))]),
+            )));
+        }
+
+        if let Some(url) = &self.canonical_url {
+            childs.push(Element::Node(void_element(
+                "link",
+                token_attrs(&[("rel", "canonical"), ("href", url)]),
+            )));
+        }
+
+        childs.extend(self.links.iter().map(|link| Element::Node(link.to_irnode())));
+
+        let social = self
+            .open_graph
+            .iter()
+            .flat_map(|og| og.meta_tags())
+            .chain(self.twitter_card.iter().flat_map(|card| card.meta_tags()));
+        childs.extend(
+            self.meta_tags
+                .iter()
+                .cloned()
+                .chain(social)
+                .map(|tag| Element::Node(tag.to_irnode())),
+        );
+
+        childs.extend(
+            self.inline_styles
+                .iter()
+                .map(|css| Element::Node(Style::inline(css).to_irnode())),
+        );
+
+        for stylesheet in &self.stylesheets {
+            childs.extend(self.stylesheet_elements(stylesheet));
+        }
+
+        childs.extend(self.scripts.iter().map(|script| {
+            let mut pairs = vec![("src", script.src.as_str())];
+            if script.module {
+                pairs.push(("type", "module"));
+            }
+            let attrs = token_attrs(&pairs)
+                .with_added(AttrKey::from_str("defer"), AttrValues::Bool(script.defer))
+                .with_added(AttrKey::from_str("async"), AttrValues::Bool(script.async_));
+            Element::Node(element("script", attrs, vec![]))
+        }));
+
+        element("head", SharedAttrs::new(), childs)
+    }
+}
+
@@ -0,0 +1,116 @@
+//! # heading_nav.rs - 목차 스크롤 추적, 제목 고정 링크
+//!
+//! ## 목적
+//! 긴 글을 읽을 때 필요한 두 가지를 작은 스크립트와 스타일시트 한 쌍으로 제공합니다.
+//! - 제목 고정 링크: `id`가 있는 h2~h6 옆에 `#` 링크를 붙이고, 누르면 그 절의 주소를 클립보드에 복사
+//! - 목차 스크롤 추적: `.toc` 안의 `#` 링크 중 지금 읽는 절의 링크에 `aria-current="location"`,
+//!   목차 안에서 위/아래 화살표, Home, End로 링크 사이를 이동
+//!
+//! `SiteConfig::heading_nav`를 켜면 Site가 모든 페이지에 두 파일을 `Assets`로 주입하고,
+//! `HeadingNavGenerator`가 출력 디렉토리에 기록합니다. 기본은 꺼져 있습니다.
+//!
+//! ## 사용 예시
+//! ```rust
+//! let config = SiteConfig::new("Blog", "https://blog.example.com")
+//!     .heading_anchors(SlugStyle::Ascii)     // 제목에 id가 있어야 링크가 붙음
+//!     .heading_nav(true);
+//! ```
+//!
+//! ## 렌더링 결과
+//! ```html
+//! <head>... <link href="/assets/heading-nav.css" rel="stylesheet">
+//!   <script defer src="/assets/heading-nav.js"></script></head>
+//! <!-- 스크립트 실행 후 -->
+//! <h2 id="install">Install <a aria-label="Copy link to this section" class="heading-permalink" href="#install">#</a></h2>
+//! <nav class="toc"><a aria-current="location" href="#install">Install</a> ...</nav>
+//! ```
+//!
+//! ## 구현 상태
+//! - [x] 제목 고정 링크, 클립보드 복사 (API가 없으면 일반 `#` 링크)
+//! - [x] 목차 현재 위치 표시 (`IntersectionObserver`), 키보드 이동
+//! - [x] 자체 호스팅 (`assets/heading-nav.js`, `assets/heading-nav.css`), 캐시 무효화 대상
+//! - [ ] TODO: 링크 라벨 다국어화
+//!
+//! ## 설계 결정
+//! - **점진적 향상**: 마크업은 바꾸지 않고 스크립트가 링크를 붙입니다. 스크립트가 없어도 본문과
+//!   목차 링크는 그대로 동작합니다.
+//! - **CDN 없음**: 외부 요청 없이 빌드할 때 파일을 씁니다. 다른 사이트 asset과 같이 `CacheBusting`을 따릅니다.
+//! - **목차는 클래스로 찾음**: 목차를 만드는 Block이 정해져 있지 않으므로 `class="toc"`인 요소 안의
+//!   같은 페이지 링크(`href="#..."`)를 목차로 봅니다.
+//! - **`id`는 만들지 않음**: 제목 `id`는 빌드 단계(`SiteConfig::heading_anchors` 또는 Block)가 정합니다.
+//!   스크립트가 만들면 링크 검사와 검색 색인이 그 주소를 모릅니다.
+
+use crate::metadata::types::Assets;
+
+/// 스크립트 출력 경로 (출력 디렉토리 기준).
+pub const HEADING_NAV_SCRIPT_PATH: &str = "assets/heading-nav.js";
+
+/// 스크립트 URL (`Assets`로 주입).
+pub const HEADING_NAV_SCRIPT_URL: &str = "/assets/heading-nav.js";
+
+/// 스타일시트 출력 경로 (출력 디렉토리 기준).
+pub const HEADING_NAV_STYLESHEET_PATH: &str = "assets/heading-nav.css";
+
+/// 스타일시트 URL (`Assets`로 주입).
+pub const HEADING_NAV_STYLESHEET_URL: &str = "/assets/heading-nav.css";
+
+/// 제목 고정 링크와 목차 스크롤 추적 스크립트.
+pub const HEADING_NAV_SCRIPT: &str = r##"(()=>{
+for(const h of document.querySelectorAll("h2[id],h3[id],h4[id],h5[id],h6[id]")){
+  const a=document.createElement("a");
+  a.className="heading-permalink";
+  a.href="#"+encodeURIComponent(h.id);
+  a.setAttribute("aria-label","Copy link to this section");
+  a.textContent="#";
+  a.addEventListener("click",e=>{
+    if(!navigator.clipboard)return;
+    e.preventDefault();
+    history.replaceState(null,"",a.hash);
+    navigator.clipboard.writeText(a.href).then(()=>{
+      a.dataset.copied="";
+      setTimeout(()=>delete a.dataset.copied,1500);
+    });
+  });
+  h.append(" ",a);
+}
+for(const toc of document.querySelectorAll(".toc")){
+  const links=[...toc.querySelectorAll('a[href^="#"]')];
+  const targets=new Map();
+  for(const a of links){
+    const target=document.getElementById(decodeURIComponent(a.hash.slice(1)));
+    if(target)targets.set(target,a);
+  }
+  toc.addEventListener("keydown",e=>{
+    const i=links.indexOf(document.activeElement);
+    const next={ArrowDown:i+1,ArrowUp:i-1,Home:0,End:links.length-1}[e.key];
+    if(i<0||next===undefined)return;
+    e.preventDefault();
+    links[Math.max(0,Math.min(links.length-1,next))].focus();
+  });
+  if(!targets.size||!("IntersectionObserver" in window))continue;
+  const visible=new Set();
+  const observer=new IntersectionObserver(entries=>{
+    for(const entry of entries)entry.isIntersecting?visible.add(entry.target):visible.delete(entry.target);
+    const current=[...targets.keys()].find(t=>visible.has(t));
+    if(!current)return;
+    for(const a of links)a.removeAttribute("aria-current");
+    targets.get(current).setAttribute("aria-current","location");
+  },{rootMargin:"0px 0px -70% 0px"});
+  for(const target of targets.keys())observer.observe(target);
+}
+})();
+"##;
+
+/// 고정 링크는 제목에 마우스나 포커스가 있을 때만 보이고, 목차의 현재 위치는 굵게 표시합니다.
+pub const HEADING_NAV_CSS: &str = ".heading-permalink{margin-inline-start:.25em;text-decoration:none;opacity:0;transition:opacity .15s}\
+:is(h2,h3,h4,h5,h6):hover>.heading-permalink,.heading-permalink:focus-visible{opacity:1}\
+.heading-permalink[data-copied]::after{content:\" \\2713\"}\
+@media (hover:none){.heading-permalink{opacity:1}}\
+.toc a[aria-current]{font-weight:600}\n";
+
+/// 모든 페이지에 주입하는 asset (`SiteConfig::heading_nav`).
+pub fn heading_nav_assets() -> Assets {
+    Assets::new()
+        .stylesheet(HEADING_NAV_STYLESHEET_URL)
+        .script(HEADING_NAV_SCRIPT_URL)
+}
@@ -0,0 +1,188 @@
+//! # i18n.rs - 다국어 지원
+//!
+//! ## 목적
+//! - 페이지의 언어별 번역을 하나의 번역 그룹(`TranslationKey`)으로 묶습니다.
+//!   Site는 기본 언어가 아닌 페이지를 `<언어>/` 아래에 출력하고, 같은 그룹의 번역을
+//!   `<link rel="alternate" hreflang>`로 서로 연결합니다.
+//! - UI 문자열(“더 보기”, “이전 글” 등)을 언어별 메시지 카탈로그(`MessageCatalog`)로 제공하고,
+//!   Block은 `t!` 매크로로 현재 페이지 언어의 문자열을 읽습니다.
+//!
+//! ## 사용 예시
+//! ```rust
+//! let catalog = MessageCatalog::new()
+//!     .message("ko", "read_more", "더 보기")
+//!     .message("en", "read_more", "Read more")
+//!     .message("ko", "posts", "글 {count}개")
+//!     .message("en", "posts", "{count} posts");
+//! let site = Site::new()
+//!     .config(SiteConfig::new("Blog", "https://example.com").language("ko"))
+//!     .metadata(Metadata::new().mergeable(catalog));
+//!
+//! // 같은 번역 그룹의 두 페이지
+//! SimplePage::new("소개", "about.html")
+//!     .with_metadata(Metadata::new().custom(TranslationKey::new("about")));
+//! SimplePage::new("About", "about.html")  // → en/about.html
+//!     .with_metadata(Metadata::new().language("en").custom(TranslationKey::new("about")));
+//!
+//! // Block에서
+//! t!(ctx, "read_more")           // "Read more" (en 페이지)
+//! t!(ctx, "posts", count = 3)    // "3 posts"
+//! ```
+//!
+//! ## 렌더링 결과
+//! ```html
+//! <link rel="alternate" href="https://example.com/en/about.html" hreflang="en">
+//! <link rel="alternate" href="https://example.com/about.html" hreflang="ko">
+//! <link rel="alternate" href="https://example.com/about.html" hreflang="x-default">
+//! ```
+//!
+//! ## 설계 결정
+//! - **기본 언어는 루트에**: `SiteConfig::language`와 같은 언어의 페이지는 경로를 바꾸지 않아
+//!   단일 언어 사이트의 URL이 그대로 유지됩니다. 경로가 이미 `<언어>/`로 시작하면 다시 붙이지 않습니다.
+//! - **없는 메시지는 키 그대로**: 빌드를 멈추지 않고 화면에서 누락을 바로 알아볼 수 있습니다.
+//! - **언어 대체 순서**: `en-US` → `en` → 카탈로그 기본 언어 → 키.
+
+use std::{collections::BTreeMap, fmt::Display};
+
+use crate::block::context::RenderContext;
+use crate::metadata::merge::MergeableMetadata;
+use crate::metadata::types::Language;
+use crate::metadata::value::MetadataValue;
+use crate::page::head::LinkTag;
+
+/// 번역 그룹. 같은 문서의 언어별 버전이 같은 키를 가집니다.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TranslationKey(pub String);
+impl MetadataValue for TranslationKey {}
+
+impl TranslationKey {
+    pub fn new(key: &str) -> Self {
+        TranslationKey(key.to_string())
+    }
+}
+
+/// 번역 하나: 언어(BCP 47 또는 `x-default`)와 절대 URL.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Alternate {
+    pub language: String,
+    pub href: String,
+}
+
+/// 현재 페이지가 속한 번역 그룹의 모든 번역 (자신 포함, 언어순). Site가 렌더링 시 주입합니다.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Translations(pub Vec<Alternate>);
+impl MetadataValue for Translations {}
+
+impl Translations {
+    /// hreflang 대체 링크들.
+    pub fn links(&self) -> Vec<LinkTag> {
+        self.0
+            .iter()
+            .map(|alt| LinkTag::new("alternate", &alt.href).attr("hreflang", &alt.language))
+            .collect()
+    }
+
+    /// 특정 언어 번역의 URL. 언어 전환 메뉴용.
+    pub fn href(&self, language: &str) -> Option<&str> {
+        self.0
+            .iter()
+            .find(|alt| alt.language == language)
+            .map(|alt| alt.href.as_str())
+    }
+}
+
+/// 언어별 UI 문자열. `mergeable`로 넣으면 하위 계층(페이지) 카탈로그가 같은 키를 덮어씁니다.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MessageCatalog {
+    /// 언어 → (키 → 문자열)
+    pub messages: BTreeMap<String, BTreeMap<String, String>>,
+    /// 어느 언어에도 없을 때 찾을 언어
+    pub fallback: Option<String>,
+}
+impl MetadataValue for MessageCatalog {}
+
+impl MergeableMetadata for MessageCatalog {
+    fn merge_with(&self, base: &Self) -> Self {
+        let mut messages = base.messages.clone();
+        for (language, entries) in &self.messages {
+            messages
+                .entry(language.clone())
+                .or_default()
+                .extend(entries.clone());
+        }
+        MessageCatalog {
+            messages,
+            fallback: self.fallback.clone().or_else(|| base.fallback.clone()),
+        }
+    }
+}
+
+impl MessageCatalog {
+    pub fn new() -> Self {
+        MessageCatalog::default()
+    }
+
+    /// 메시지 하나 추가. `{이름}`은 `t!`의 인자로 치환됩니다.
+    pub fn message(mut self, language: &str, key: &str, text: &str) -> Self {
+        self.messages
+            .entry(language.to_string())
+            .or_default()
+            .insert(key.to_string(), text.to_string());
+        self
+    }
+
+    /// 한 언어의 메시지 여러 개 추가.
+    pub fn messages(self, language: &str, entries: &[(&str, &str)]) -> Self {
+        entries
+            .iter()
+            .fold(self, |catalog, (key, text)| catalog.message(language, key, text))
+    }
+
+    /// 현재 언어에 없을 때 찾을 언어.
+    pub fn fallback(mut self, language: &str) -> Self {
+        self.fallback = Some(language.to_string());
+        self
+    }
+
+    /// `language` → 기본 하위 태그 → `fallback` 순으로 찾기.
+    pub fn get(&self, language: Option<&str>, key: &str) -> Option<&str> {
+        let primary = language.and_then(|l| l.split('-').next());
+        [language, primary, self.fallback.as_deref()]
+            .into_iter()
+            .flatten()
+            .find_map(|l| self.messages.get(l)?.get(key))
+            .map(String::as_str)
+    }
+}
+
+/// 현재 페이지 언어의 메시지. 없으면 키를 그대로 반환합니다. 보통 `t!` 매크로로 호출합니다.
+pub fn translate(ctx: &RenderContext, key: &str, args: &[(&str, &dyn Display)]) -> String {
+    let language = ctx.get::<Language>().map(|l| l.0.as_str());
+    let text = ctx
+        .get::<MessageCatalog>()
+        .and_then(|catalog| catalog.get(language, key))
+        .unwrap_or(key);
+    args.iter().fold(text.to_string(), |text, (name, value)| {
+        text.replace(&format!("{{{}}}", name), &value.to_string())
+    })
+}
+
+/// 메시지 카탈로그에서 현재 페이지 언어의 문자열 읽기.
+///
+/// ```rust
+/// t!(ctx, "read_more")
+/// t!(ctx, "posts", count = 3)
+/// ```
+#[macro_export]
+macro_rules! t {
+    ($ctx:expr, $key:expr $(,)?) => {
+        $crate::page::i18n::translate($ctx, $key, &[])
+    };
+    ($ctx:expr, $key:expr, $($name:ident = $value:expr),+ $(,)?) => {
+        $crate::page::i18n::translate(
+            $ctx,
+            $key,
+            &[$((stringify!($name), &$value as &dyn ::std::fmt::Display)),+],
+        )
+    };
+}
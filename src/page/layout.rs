@@ -0,0 +1,183 @@
+//! # layout.rs - 공유 레이아웃 (템플릿 상속)
+//!
+//! ## 목적
+//! 여러 페이지가 같은 사이트 골격(헤더, 내비게이션, 푸터)을 공유하도록 합니다.
+//! 각 Page는 자신의 본문만 `layout()`으로 정의하고, 골격은 `layout_template()`이
+//! 반환하는 Layout이 감쌉니다.
+//!
+//! ## 흐름
+//! ```text
+//! page.layout(ctx)           → 본문 IRNode
+//!   ↓ layout.wrap(body, ctx)
+//! 사이트 골격으로 감싼 IRNode
+//!   ↓ layout.parent()가 있으면 반복
+//! <body> 내용
+//! ```
+//!
+//! ## 사용 예시
+//! ```rust
+//! let chrome: Arc<dyn Layout> = Arc::new(
+//!     ChromeLayout::new()
+//!         .header(Box::new(Heading::text(1, "My Blog")))
+//!         .footer(Box::new(Paragraph::new("© 2024")))
+//!         .stylesheet("/css/site.css"),
+//! );
+//!
+//! impl Page for BlogPostPage {
+//!     fn layout_template(&self) -> Option<Arc<dyn Layout>> {
+//!         Some(self.chrome.clone())
+//!     }
+//!     // layout()은 본문만 정의
+//! }
+//! ```
+//!
+//! ## 구현 상태
+//! - [x] Layout 트레이트 (wrap, head, parent)
+//! - [x] ChromeLayout (header/main/footer 골격)
+//! - [x] 부모 Layout 체인 (템플릿 상속)
+//! - [x] 인쇄용 대상에서 header/footer 생략
+//!
+//! ## 설계 결정
+//! - **`Arc<dyn Layout>`**: 하나의 골격을 수백 페이지가 공유하므로 복제 없이 참조를 나눠 가집니다.
+//! - **head 병합 순서**: 부모 Layout → Layout → Page 순으로 `HeadElements::extend()`하여
+//!   페이지가 제목 등을 최종 결정합니다.
+
+use std::sync::Arc;
+
+use crate::block::block::{Block, class_attrs, element, render_block};
+use crate::block::context::RenderContext;
+use crate::html::node::{Element, IRNode};
+use crate::metadata::types::RenderTarget;
+use crate::page::head::HeadElements;
+
+/// 페이지 본문을 사이트 골격으로 감싸는 템플릿.
+pub trait Layout: Send + Sync {
+    /// 본문 IRNode를 감싼 IRNode 반환.
+    fn wrap(&self, body: IRNode, ctx: &RenderContext) -> IRNode;
+
+    /// 골격이 필요로 하는 `<head>` 항목 (사이트 공통 CSS 등).
+    fn head(&self, _ctx: &RenderContext) -> HeadElements {
+        HeadElements::default()
+    }
+
+    /// 이 Layout을 다시 감쌀 부모 Layout.
+    fn parent(&self) -> Option<Arc<dyn Layout>> {
+        None
+    }
+
+    /// 골격이 소유한 Block 목록 (asset 수집 등 트리 순회용).
+    fn blocks(&self) -> Vec<&dyn Block> {
+        Vec::new()
+    }
+}
+
+/// Layout 체인을 안쪽부터 순서대로 반환 (자기 자신 → 부모 → ...).
+pub fn layout_chain(layout: Arc<dyn Layout>) -> Vec<Arc<dyn Layout>> {
+    let mut chain = vec![layout];
+    while let Some(parent) = chain.last().and_then(|l| l.parent()) {
+        chain.push(parent);
+    }
+    chain
+}
+
+/// header / main / footer 골격 Layout.
+///
+/// ```html
+/// <div class="site">
+///   <header class="site-header"><!-- header blocks --></header>
+///   <main class="site-main"><!-- page body --></main>
+///   <footer class="site-footer"><!-- footer blocks --></footer>
+/// </div>
+/// ```
+/// 부모 Layout이 있으면 `<main>` 대신 `<div class="site-main">`을 사용합니다.
+/// 인쇄용 대상(`RenderTarget::Print`)에서는 header/footer를 생략합니다.
+#[derive(Default)]
+pub struct ChromeLayout {
+    header: Vec<Box<dyn Block>>,
+    footer: Vec<Box<dyn Block>>,
+    head: HeadElements,
+    parent: Option<Arc<dyn Layout>>,
+}
+
+impl ChromeLayout {
+    pub fn new() -> Self {
+        ChromeLayout::default()
+    }
+
+    pub fn header(mut self, block: Box<dyn Block>) -> Self {
+        self.header.push(block);
+        self
+    }
+
+    pub fn footer(mut self, block: Box<dyn Block>) -> Self {
+        self.footer.push(block);
+        self
+    }
+
+    /// 골격 공통 스타일시트.
+    pub fn stylesheet(mut self, href: &str) -> Self {
+        self.head = self.head.stylesheet(href);
+        self
+    }
+
+    /// 골격 공통 head 항목.
+    pub fn with_head(mut self, head: HeadElements) -> Self {
+        self.head = self.head.extend(head);
+        self
+    }
+
+    /// 이 골격을 다시 감쌀 부모 Layout 지정.
+    pub fn with_parent(mut self, parent: Arc<dyn Layout>) -> Self {
+        self.parent = Some(parent);
+        self
+    }
+
+    fn section(tag: &str, class: &str, blocks: &[Box<dyn Block>], ctx: &RenderContext) -> IRNode {
+        element(
+            tag,
+            class_attrs(&[class]),
+            blocks
+                .iter()
+                .map(|block| Element::Node(render_block(block.as_ref(), ctx)))
+                .collect(),
+        )
+    }
+}
+
+impl Layout for ChromeLayout {
+    fn wrap(&self, body: IRNode, ctx: &RenderContext) -> IRNode {
+        // 인쇄용 문서에는 header/footer(네비게이션 등)를 넣지 않음
+        let chrome = ctx.target() != RenderTarget::Print;
+        let mut childs = Vec::new();
+        if chrome && !self.header.is_empty() {
+            childs.push(Element::Node(Self::section("header", "site-header", &self.header, ctx)));
+        }
+        // 부모가 감싸는 경우 부모가 <main>을 가지므로 중첩을 피함
+        let main_tag = if self.parent.is_some() { "div" } else { "main" };
+        childs.push(Element::Node(element(
+            main_tag,
+            class_attrs(&["site-main"]),
+            vec![Element::Node(body)],
+        )));
+        if chrome && !self.footer.is_empty() {
+            childs.push(Element::Node(Self::section("footer", "site-footer", &self.footer, ctx)));
+        }
+        element("div", class_attrs(&["site"]), childs)
+    }
+
+    fn head(&self, _ctx: &RenderContext) -> HeadElements {
+        self.head.clone()
+    }
+
+    fn parent(&self) -> Option<Arc<dyn Layout>> {
+        self.parent.clone()
+    }
+
+    fn blocks(&self) -> Vec<&dyn Block> {
+        self.header
+            .iter()
+            .chain(self.footer.iter())
+            .map(|b| b.as_ref())
+            .collect()
+    }
+}
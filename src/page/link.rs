@@ -0,0 +1,260 @@
+//! # link.rs - 페이지 참조 링크
+//!
+//! ## 목적
+//! 다른 페이지를 출력 경로 문자열 대신 페이지 타입이나 페이지 ID로 가리킵니다. URL은 렌더링 시점에
+//! `SiteIndex`에서 찾으므로 대상 페이지의 출력 경로(슬러그, pretty URL, 언어 디렉토리)가 바뀌어도
+//! 링크를 고칠 필요가 없습니다.
+//!
+//! ## 사용 예시
+//! ```rust
+//! // 대상 페이지: 타입이 하나뿐이면 타입으로, 아니면 ID로
+//! site.register_page(Box::new(AboutPage::new()));
+//! site.register_page(Box::new(
+//!     SimplePage::new("Install", "docs/install.html")
+//!         .with_metadata(Metadata::new().custom(PageId::new("install"))),
+//! ));
+//!
+//! // 링크
+//! Link::to::<AboutPage>()                        // 텍스트: 대상 페이지 제목
+//! Link::id("install").text("설치 안내").anchor("linux")
+//! link!("install")                               // Link::id("install")
+//! link!("install", "설치 안내")
+//! Link::to::<AboutPage>().absolute()             // https://example.com/about.html
+//! ```
+//!
+//! ## 렌더링 결과 (현재 페이지: `/docs/guide.html`)
+//! ```html
+//! <a class="page-link" href="/about.html">About</a>
+//! <a class="page-link" href="/docs/install.html#linux">설치 안내</a>
+//! <a class="page-link" href="install.html">Install</a>   <!-- .relative() -->
+//! ```
+//!
+//! ## 설계 결정
+//! - **찾지 못하면 주석으로**: Include처럼 빌드를 멈추지 않고 `href` 없는 링크와
+//!   `<!-- unresolved page link: install -->`를 남기며, Site는 `WarningKind::UnresolvedLink` 경고를 냅니다.
+//!   타입으로 가리켰는데 그 타입의 페이지가 둘 이상이면 찾지 못한 것으로 봅니다.
+//! - **기본은 사이트 루트 기준 URL**: 다른 내부 링크와 같은 형태라 링크 검사, 백링크에 그대로 잡힙니다.
+//! - **의존성 기록**: 대상 페이지를 `Input::Page`로 기록하므로 대상의 경로나 제목이 바뀌면
+//!   `Site::rebuild_affected`가 링크한 페이지도 다시 렌더링합니다.
+
+use std::any::TypeId;
+
+use crate::block::block::{Block, class_attrs, element, prose_rule, with_tokens};
+use crate::block::context::RenderContext;
+use crate::cite::config::SiteConfig;
+use crate::cite::dependency::Input;
+use crate::cite::link::PageRefs;
+use crate::html::node::{Element, IRNode};
+use crate::html::trust::{Content, HtmlBlock, SafeString};
+use crate::metadata::types::CurrentUrl;
+use crate::metadata::value::MetadataValue;
+use crate::page::page::Page;
+
+/// 찾지 못한 링크가 남기는 주석의 앞부분.
+pub(crate) const UNRESOLVED_MARKER: &str = "<!-- unresolved page link: ";
+
+/// 페이지 ID. `Link::id`, `link!`로 가리킬 이름입니다.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct PageId(pub String);
+impl MetadataValue for PageId {}
+
+impl PageId {
+    pub fn new(id: &str) -> Self {
+        PageId(id.to_string())
+    }
+}
+
+/// 링크 대상 페이지.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PageRef {
+    /// Page 구현 타입. 사이트에 그 타입의 페이지가 하나일 때만 찾습니다
+    Type { id: TypeId, name: &'static str },
+    /// `PageId`
+    Id(String),
+}
+
+impl PageRef {
+    pub fn of<P: Page>() -> Self {
+        PageRef::Type {
+            id: TypeId::of::<P>(),
+            name: std::any::type_name::<P>(),
+        }
+    }
+
+    pub fn id(id: &str) -> Self {
+        PageRef::Id(id.to_string())
+    }
+
+    /// 사람이 읽는 이름 (타입은 마지막 경로만).
+    pub fn label(&self) -> &str {
+        match self {
+            PageRef::Type { name, .. } => name.rsplit("::").next().unwrap_or(name),
+            PageRef::Id(id) => id,
+        }
+    }
+}
+
+/// 링크 URL 형태.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LinkForm {
+    /// `/docs/install.html`
+    #[default]
+    RootRelative,
+    /// `install.html` (현재 페이지 기준)
+    Relative,
+    /// `https://example.com/docs/install.html`
+    Absolute,
+}
+
+/// 다른 페이지로의 링크 Block.
+#[derive(Debug, Clone)]
+pub struct Link {
+    target: PageRef,
+    text: Option<String>,
+    anchor: Option<String>,
+    form: LinkForm,
+}
+
+impl Link {
+    pub fn new(target: PageRef) -> Self {
+        Link {
+            target,
+            text: None,
+            anchor: None,
+            form: LinkForm::default(),
+        }
+    }
+
+    /// 타입 `P`의 페이지로.
+    pub fn to<P: Page>() -> Self {
+        Link::new(PageRef::of::<P>())
+    }
+
+    /// `PageId`가 `id`인 페이지로.
+    pub fn id(id: &str) -> Self {
+        Link::new(PageRef::id(id))
+    }
+
+    /// 링크 텍스트. 기본: 대상 페이지 제목 (없으면 URL)
+    pub fn text(mut self, text: &str) -> Self {
+        self.text = Some(text.to_string());
+        self
+    }
+
+    /// 대상 페이지 안의 위치 (`#anchor`).
+    pub fn anchor(mut self, anchor: &str) -> Self {
+        self.anchor = Some(anchor.to_string());
+        self
+    }
+
+    pub fn form(mut self, form: LinkForm) -> Self {
+        self.form = form;
+        self
+    }
+
+    /// 기본 URL이 붙은 절대 URL (`LinkForm::Absolute`).
+    pub fn absolute(self) -> Self {
+        self.form(LinkForm::Absolute)
+    }
+
+    /// 현재 페이지 기준 상대 URL (`LinkForm::Relative`).
+    pub fn relative(self) -> Self {
+        self.form(LinkForm::Relative)
+    }
+
+    pub fn target(&self) -> &PageRef {
+        &self.target
+    }
+
+    /// 렌더링 시점의 링크 URL과 대상 제목. 대상을 찾지 못하면 `None`.
+    pub fn resolve(&self, ctx: &RenderContext) -> Option<(String, Option<String>)> {
+        let index = ctx.index()?;
+        let target = index.get::<PageRefs>()?.resolve(&self.target)?;
+        ctx.depends_on(Input::page(&target.output_path));
+
+        let mut href = match self.form {
+            LinkForm::RootRelative => target.url.clone(),
+            LinkForm::Absolute => match ctx.get::<SiteConfig>() {
+                Some(config) => config.absolute_url(&target.url),
+                None => target.url.clone(),
+            },
+            LinkForm::Relative => match ctx.get::<CurrentUrl>() {
+                Some(current) => relative_url(&current.0, &target.url),
+                None => target.url.clone(),
+            },
+        };
+        if let Some(anchor) = &self.anchor {
+            href.push('#');
+            href.push_str(anchor);
+        }
+        let title = index.page(&target.url).and_then(|entry| entry.title.clone());
+        Some((href, title))
+    }
+}
+
+impl Block for Link {
+    fn render_to_ir(&self, ctx: &RenderContext) -> IRNode {
+        let rule = prose_rule();
+        let Some((href, title)) = self.resolve(ctx) else {
+            let label = self.text.as_deref().unwrap_or(self.target.label());
+            return element(
+                "a",
+                class_attrs(&["page-link", "unresolved"]),
+                vec![
+                    Element::Raw(HtmlBlock::from_str(&format!(
+                        "{}{} -->",
+                        UNRESOLVED_MARKER,
+                        self.target.label().replace("--", "")
+                    ))),
+                    Element::Text(Content::from_str(label, &rule)),
+                ],
+            );
+        };
+        let text = self.text.clone().or(title).unwrap_or_else(|| href.clone());
+        element(
+            "a",
+            with_tokens(class_attrs(&["page-link"]), &[("href", &href)]),
+            vec![Element::Text(Content::from_str(&text, &rule))],
+        )
+    }
+}
+
+/// 찾지 못한 링크 주석이면 대상 이름.
+pub(crate) fn unresolved_target(raw: &str) -> Option<&str> {
+    raw.strip_prefix(UNRESOLVED_MARKER)?.strip_suffix(" -->")
+}
+
+/// `from` 페이지에서 `to`로 가는 상대 URL. 두 값 모두 사이트 루트 기준 URL입니다.
+/// 예: `/docs/guide.html` → `/about.html` = `../about.html`
+pub fn relative_url(from: &str, to: &str) -> String {
+    let from_dir: Vec<&str> = match from.rfind('/') {
+        Some(i) => from[..i].split('/').filter(|s| !s.is_empty()).collect(),
+        None => Vec::new(),
+    };
+    let (to_dir, to_file) = match to.rfind('/') {
+        Some(i) => (&to[..i], &to[i + 1..]),
+        None => ("", to),
+    };
+    let to_dir: Vec<&str> = to_dir.split('/').filter(|s| !s.is_empty()).collect();
+
+    let common = from_dir.iter().zip(&to_dir).take_while(|(a, b)| a == b).count();
+    let mut parts: Vec<&str> = vec![".."; from_dir.len() - common];
+    parts.extend(&to_dir[common..]);
+    let mut url = parts.join("/");
+    if !url.is_empty() {
+        url.push('/');
+    }
+    url.push_str(to_file);
+    if url.is_empty() { "./".to_string() } else { url }
+}
+
+/// `PageId`로 페이지 링크 생성. `link!("about")`, `link!("about", "소개")`
+#[macro_export]
+macro_rules! link {
+    ($id:expr $(,)?) => {
+        $crate::page::link::Link::id($id)
+    };
+    ($id:expr, $text:expr $(,)?) => {
+        $crate::page::link::Link::id($id).text($text)
+    };
+}
@@ -0,0 +1,170 @@
+//! # loader.rs - 콘텐츠 디렉토리 로더
+//!
+//! ## 목적
+//! `content/` 폴더의 Markdown 파일들을 읽어 페이지로 만드는 관례적 블로그 작업 흐름을 지원합니다.
+//! 각 파일은 머리말(메타데이터)과 본문(Block 목록)으로 변환된 뒤,
+//! 사용자가 제공한 Page 템플릿 함수로 전달됩니다.
+//!
+//! ## 흐름
+//! ```text
+//! content/blog/first-post.md
+//!   ↓ Frontmatter::split()
+//! 머리말 → Metadata (Title, PublishDate, Tags ...)
+//! 본문   → parse_markdown() → Vec<Box<dyn Block>>
+//!   ↓ ContentFile
+//! template(ContentFile) → P: Page
+//! ```
+//!
+//! ## 사용 예시
+//! ```rust
+//! let posts: Vec<BlogPostPage> = ContentLoader::new("content/blog")
+//!     .load(|file| BlogPostPage {
+//!         slug: file.slug,
+//!         metadata: file.metadata,
+//!         content: file.blocks,
+//!     })?;
+//! ```
+//!
+//! ## 설계 결정
+//! - **템플릿은 함수**: 파일마다 같은 Page 타입을 인스턴스화하므로
+//!   "템플릿으로서의 Page" 원칙을 그대로 따릅니다.
+//! - **정렬된 순회**: 디렉토리 순서는 OS마다 다르므로 경로 순으로 정렬합니다 (결정적 빌드).
+//! - **원본 파일 선언**: 디렉토리에서 읽은 파일은 `SourceFiles`로 경로를 남겨 Site가 수정일을 계산합니다.
+//! - **숨김 파일 무시**: `.`이나 `_`로 시작하는 파일/디렉토리는 건너뜁니다.
+
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::block::block::Block;
+use crate::block::markdown::parse_markdown_with;
+use crate::block::math::MathDelimiters;
+use crate::metadata::builder::Metadata;
+use crate::metadata::types::SourceFiles;
+use crate::page::frontmatter::Frontmatter;
+use crate::page::page::Page;
+
+/// 로드된 콘텐츠 파일 하나. Page 템플릿 함수의 입력.
+pub struct ContentFile {
+    /// 원본 파일 경로
+    pub source: PathBuf,
+    /// 콘텐츠 루트 기준 상대 경로. 예: `blog/first-post.md`
+    pub relative: PathBuf,
+    /// 확장자를 뺀 `/` 구분 경로. 예: `blog/first-post`
+    pub slug: String,
+    pub frontmatter: Frontmatter,
+    /// 머리말에서 변환된 메타데이터
+    pub metadata: Metadata,
+    /// 본문 Block 목록
+    pub blocks: Vec<Box<dyn Block>>,
+}
+
+impl ContentFile {
+    /// 원문 문자열로부터 생성. (파일 시스템 없이 사용 가능)
+    pub fn from_source(relative: &Path, source: &str) -> Self {
+        ContentFile::from_source_with(relative, source, &MathDelimiters::default())
+    }
+
+    /// 수식 구분자를 지정해 원문 문자열로부터 생성.
+    pub fn from_source_with(relative: &Path, source: &str, math: &MathDelimiters) -> Self {
+        let (frontmatter, body) = Frontmatter::split(source);
+        let slug = relative
+            .with_extension("")
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy().into_owned())
+            .collect::<Vec<_>>()
+            .join("/");
+
+        ContentFile {
+            source: relative.to_path_buf(),
+            relative: relative.to_path_buf(),
+            slug,
+            metadata: frontmatter.to_metadata(),
+            frontmatter,
+            blocks: parse_markdown_with(body, math),
+        }
+    }
+}
+
+/// 콘텐츠 디렉토리 로더.
+pub struct ContentLoader {
+    root: PathBuf,
+    extensions: Vec<String>,
+    math: MathDelimiters,
+}
+
+impl ContentLoader {
+    /// `.md`, `.markdown` 파일을 읽는 로더 생성.
+    pub fn new<P: AsRef<Path>>(root: P) -> Self {
+        ContentLoader {
+            root: root.as_ref().to_path_buf(),
+            extensions: vec!["md".to_string(), "markdown".to_string()],
+            math: MathDelimiters::default(),
+        }
+    }
+
+    /// 읽을 확장자 추가.
+    pub fn extension(mut self, ext: &str) -> Self {
+        self.extensions.push(ext.trim_start_matches('.').to_string());
+        self
+    }
+
+    /// 본문의 수식 구분자. 사이트 메타데이터의 `MathDelimiters`를 넘깁니다. 기본: `$...$`, `$$...$$`
+    pub fn math_delimiters(mut self, math: MathDelimiters) -> Self {
+        self.math = math;
+        self
+    }
+
+    /// 디렉토리를 재귀 순회하여 모든 콘텐츠 파일을 로드 (경로 순 정렬).
+    pub fn files(&self) -> io::Result<Vec<ContentFile>> {
+        let mut paths = Vec::new();
+        self.collect_paths(&self.root, &mut paths)?;
+        paths.sort();
+
+        paths
+            .into_iter()
+            .map(|path| {
+                let source = fs::read_to_string(&path)?;
+                let relative = path.strip_prefix(&self.root).unwrap_or(&path);
+                let mut file = ContentFile::from_source_with(relative, &source, &self.math);
+                file.metadata = file.metadata.custom(SourceFiles::new().file(&path));
+                file.source = path;
+                Ok(file)
+            })
+            .collect()
+    }
+
+    /// 모든 콘텐츠 파일을 템플릿 함수로 Page 인스턴스화.
+    pub fn load<P, F>(&self, template: F) -> io::Result<Vec<P>>
+    where
+        P: Page,
+        F: Fn(ContentFile) -> P,
+    {
+        Ok(self.files()?.into_iter().map(template).collect())
+    }
+
+    fn collect_paths(&self, dir: &Path, paths: &mut Vec<PathBuf>) -> io::Result<()> {
+        for entry in fs::read_dir(dir)? {
+            let path = entry?.path();
+            let hidden = path
+                .file_name()
+                .and_then(|n| n.to_str())
+                .is_some_and(|n| n.starts_with('.') || n.starts_with('_'));
+            if hidden {
+                continue;
+            }
+
+            if path.is_dir() {
+                self.collect_paths(&path, paths)?;
+            } else if path
+                .extension()
+                .and_then(|e| e.to_str())
+                .is_some_and(|e| self.extensions.iter().any(|x| x == e))
+            {
+                paths.push(path);
+            }
+        }
+        Ok(())
+    }
+}
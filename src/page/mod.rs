@@ -25,13 +25,13 @@
 //! }
 //!
 //! impl Page for BlogPostPage {
-//!     fn layout(&self) -> IRNode {
+//!     fn layout(&self, ctx: &RenderContext) -> IRNode {
 //!         // 모든 인스턴스가 공유하는 레이아웃 구조
 //!         VBox::new(vec![
 //!             Box::new(Header { /* ... */ }),
 //!             Box::new(Main { /* ... */ }),
 //!             Box::new(Footer { /* ... */ }),
-//!         ]).to_irnode()
+//!         ]).render_to_ir(ctx)
 //!     }
 //! }
 //!
@@ -118,14 +118,14 @@
 //! **데이터 흐름:**
 //! ```rust
 //! impl Page for MyPage {
-//!     fn layout(&self) -> IRNode {
+//!     fn layout(&self, ctx: &RenderContext) -> IRNode {
 //!         // 1. 레이아웃 Block 생성
 //!         VBox::new(vec![
 //!             // 2. 사용자 Block들 배치
 //!             Box::new(self.header_block),
 //!             Box::new(self.content_block),
 //!             Box::new(self.footer_block),
-//!         ]).to_irnode()
+//!         ]).render_to_ir(ctx)
 //!     }
 //! }
 //! ```
@@ -134,10 +134,10 @@
 //!
 //! ### Page 트레이트
 //! ```rust
-//! pub trait Page {
+//! pub trait Page: Send + Sync {
 //!     /// 페이지의 레이아웃 트리 반환.
 //!     /// Block들을 레이아웃 Block으로 조합한 구조.
-//!     fn layout(&self) -> IRNode;
+//!     fn layout(&self, ctx: &RenderContext) -> IRNode;
 //!     
 //!     /// 페이지 수준 메타데이터 제공.
 //!     /// Cite 계층에서 수집하여 Site 메타데이터와 병합.
@@ -145,18 +145,38 @@
 //!         Metadata::new()  // 기본: 빈 메타데이터
 //!     }
 //!     
-//!     /// 출력 파일 경로.
+//!     /// 출력 파일 경로 (출력 디렉토리 기준 상대 경로).
 //!     /// 예: "blog/my-post.html", "index.html"
-//!     fn path(&self) -> &str;
+//!     fn path(&self) -> PathBuf;
 //!     
 //!     /// HTML <head> 내용 생성.
 //!     /// 메타태그, 스타일시트, 스크립트 등.
 //!     fn head(&self, ctx: &RenderContext) -> HeadElements {
 //!         HeadElements::default()  // 기본: 기본 메타태그
 //!     }
+//!
+//!     /// 본문을 감쌀 공유 Layout (사이트 골격).
+//!     fn layout_template(&self) -> Option<Arc<dyn Layout>> {
+//!         None  // 기본: 골격 없음
+//!     }
 //! }
 //! ```
 //!
+//! **문서 초안과 달라진 점:**
+//! - `layout()`이 `ctx`를 받습니다. 레이아웃 안의 Block들이 `render_to_ir(ctx)`로
+//!   메타데이터를 읽어야 하기 때문입니다.
+//! - `path()`가 소유한 `PathBuf`를 반환합니다. `&format!(...)`처럼 임시 문자열의
+//!   참조를 반환할 수 없는 생명주기 문제를 피합니다.
+//!
+//! ### 공유 Layout
+//! 헤더/내비게이션/푸터 같은 사이트 골격은 `Layout`으로 한 번 정의하고
+//! `layout_template()`으로 공유합니다. 자세한 내용은 `layout` 모듈을 참고하세요.
+//!
+//! ### 문서 조립
+//! `render_document(page, &site_metadata)`가 메타데이터를 병합(Site → Page)하고
+//! `<!DOCTYPE html>`부터 `</html>`까지 완전한 문서를 생성합니다.
+//! `SimplePage`는 제목 + Block 목록으로 구성된 참조 구현입니다.
+//!
 //! ## 레이아웃 시스템
 //!
 //! ### 레이아웃 Block
@@ -189,8 +209,8 @@
 //!
 //! // 렌더링 결과
 //! <div class="hbox">
-//!   <div class="hbox-item" style="flex: 0 0 70%"><!-- child 1 --></div>
-//!   <div class="hbox-item" style="flex: 0 0 30%"><!-- child 2 --></div>
+//!   <div class="hbox-item" style="flex: 0 1 70%"><!-- child 1 --></div>
+//!   <div class="hbox-item" style="flex: 0 1 30%"><!-- child 2 --></div>
 //! </div>
 //! ```
 //!
@@ -212,7 +232,7 @@
 //!
 //! ### 레이아웃 조합 예시
 //! ```rust
-//! fn layout(&self) -> IRNode {
+//! fn layout(&self, ctx: &RenderContext) -> IRNode {
 //!     VBox::new(vec![
 //!         // 헤더 (전체 너비)
 //!         Box::new(Header::new(/* ... */)),
@@ -237,7 +257,7 @@
 //!         Box::new(Footer::new(/* ... */)),
 //!     ])
 //!     .spacing(Spacing::Large)
-//!     .to_irnode()
+//!     .render_to_ir(ctx)
 //! }
 //! ```
 //!
@@ -249,7 +269,7 @@
 //! ### 구조
 //! ```rust
 //! pub struct HeadElements {
-//!     pub title: String,
+//!     pub title: Option<String>,
 //!     pub charset: String,
 //!     pub viewport: String,
 //!     pub description: Option<String>,
@@ -258,6 +278,8 @@
 //!     pub stylesheets: Vec<Stylesheet>,
 //!     pub scripts: Vec<Script>,
 //!     pub meta_tags: Vec<MetaTag>,
+//!     pub open_graph: Option<OpenGraph>,
+//!     pub twitter_card: Option<TwitterCard>,
 //! }
 //! ```
 //!
@@ -270,13 +292,12 @@
 //!             .description(&self.excerpt)
 //!             .keywords(vec!["rust", "programming"])
 //!             .stylesheet("/css/blog.css")
-//!             .script("/js/highlight.js")
-//!             .defer(true)
-//!             .open_graph(OpenGraph {
-//!                 title: self.title.clone(),
-//!                 type_: "article",
-//!                 image: self.cover_image.clone(),
-//!             })
+//!             .add_script(Script::new("/js/highlight.js").defer(true))
+//!             .open_graph(
+//!                 OpenGraph::new(&self.title)
+//!                     .og_type(OgType::Article)
+//!                     .image(&self.cover_image),
+//!             )
 //!     }
 //! }
 //! ```
@@ -374,7 +395,7 @@
 //! }
 //!
 //! impl Page for SimplePage {
-//!     fn layout(&self) -> IRNode {
+//!     fn layout(&self, ctx: &RenderContext) -> IRNode {
 //!         VBox::new(vec![
 //!             Box::new(H1::new(
 //!                 AttrBuilder::global(),
@@ -382,11 +403,11 @@
 //!             )),
 //!             // content blocks를 래핑
 //!         ] + self.content.clone())
-//!         .to_irnode()
+//!         .render_to_ir(ctx)
 //!     }
 //!     
-//!     fn path(&self) -> &str {
-//!         &self.output_path
+//!     fn path(&self) -> PathBuf {
+//!         self.output_path.clone()
 //!     }
 //!     
 //!     fn metadata(&self) -> Metadata {
@@ -406,7 +427,7 @@
 //! }
 //!
 //! impl Page for BlogPostPage {
-//!     fn layout(&self) -> IRNode {
+//!     fn layout(&self, ctx: &RenderContext) -> IRNode {
 //!         VBox::new(vec![
 //!             // 헤더
 //!             Box::new(Header::new(vec![
@@ -445,7 +466,7 @@
 //!                 Box::new(Comments::new()),
 //!             ])),
 //!         ])
-//!         .to_irnode()
+//!         .render_to_ir(ctx)
 //!     }
 //!     
 //!     fn head(&self, ctx: &RenderContext) -> HeadElements {
@@ -455,12 +476,11 @@
 //!             .canonical_url(&format!("/blog/{}", self.slug()))
 //!             .stylesheet("/css/blog.css")
 //!             .stylesheet("/css/syntax-highlight.css")
-//!             .script("/js/toc.js")
-//!             .defer(true)
+//!             .add_script(Script::new("/js/toc.js").defer(true))
 //!     }
 //!     
-//!     fn path(&self) -> &str {
-//!         &format!("blog/{}.html", self.slug())
+//!     fn path(&self) -> PathBuf {
+//!         PathBuf::from(format!("blog/{}.html", self.slug()))
 //!     }
 //! }
 //! ```
@@ -468,11 +488,11 @@
 //! ## 구현해야 할 컴포넌트
 //!
 //! ### 우선순위: 높음 (레이아웃 Block)
-//! - [ ] `VBox`: 수직 배치
-//! - [ ] `HBox`: 수평 배치
+//! - [x] `VBox`: 수직 배치
+//! - [x] `HBox`: 수평 배치
 //! - [ ] `Grid`: 그리드 배치
 //! - [ ] `Spacer`: 공백
-//! - [ ] `Divider`: 구분선
+//! - [x] `Divider`: 구분선 (`block::prose::Divider`)
 //!
 //! ### 우선순위: 높음 (의미론적 컨테이너)
 //! - [ ] `Header`: 페이지/섹션 헤더
@@ -481,18 +501,21 @@
 //! - [ ] `Aside`: 사이드바
 //! - [ ] `Article`: 독립적인 콘텐츠
 //! - [ ] `Section`: 주제별 섹션
-//! - [ ] `Nav`: 네비게이션
+//! - [x] `Nav`: 네비게이션 (`NavMenu`, 활성 링크 표시)
 //!
 //! ### 우선순위: 중간 (페이지 컴포넌트)
 //! - [ ] `TableOfContents`: 자동 목차
 //! - [ ] `Breadcrumb`: 경로 네비게이션
-//! - [ ] `Pagination`: 페이지네이션
+//! - [x] `Pagination`: 페이지네이션
+//! - [x] `SeriesNav`: 연재 위치와 이전/다음 편
 //! - [ ] `RelatedPosts`: 관련 글 목록
 //!
 //! ### 우선순위: 낮음 (특수 기능)
-//! - [ ] `Comments`: 댓글 시스템
-//! - [ ] `ShareButtons`: 공유 버튼
-//! - [ ] `SearchBox`: 검색창
+//! - [x] `Comments`: 댓글 시스템 (giscus, utterances, isso)
+//! - [x] `ShareButtons`: 공유 버튼 (메일, X, Mastodon, LinkedIn, 링크 복사)
+//! - [x] `SearchBox`: 검색창 (`search.json` 연동)
+//! - [x] `GlossaryBlock`: 용어집 (처음 나온 용어 자동 링크)
+//! - [x] `DraftBanner`: 미리보기 빌드의 초안 배너 (noindex)
 //!
 //! ## 설계 결정
 //!
@@ -515,7 +538,7 @@
 //!
 //! **현재 방식: IRNode 반환**
 //! ```rust
-//! fn layout(&self) -> IRNode
+//! fn layout(&self, ctx: &RenderContext) -> IRNode
 //! ```
 //! 장점:
 //! - 레이아웃이 이미 IRNode로 구성됨
@@ -563,18 +586,18 @@
 //! }
 //!
 //! impl Page for AboutPage {
-//!     fn layout(&self) -> IRNode {
+//!     fn layout(&self, ctx: &RenderContext) -> IRNode {
 //!         VBox::new(vec![
 //!             Box::new(H1::new(
 //!                 AttrBuilder::global(),
 //!                 Content::from_str("About Us", &rule)
 //!             )),
 //!             Box::new(Paragraph::new(&self.content)),
-//!         ]).to_irnode()
+//!         ]).render_to_ir(ctx)
 //!     }
 //!     
-//!     fn path(&self) -> &str {
-//!         "about.html"
+//!     fn path(&self) -> PathBuf {
+//!         PathBuf::from("about.html")
 //!     }
 //! }
 //! ```
@@ -587,7 +610,7 @@
 //! }
 //!
 //! impl Page for DocsPage {
-//!     fn layout(&self) -> IRNode {
+//!     fn layout(&self, ctx: &RenderContext) -> IRNode {
 //!         VBox::new(vec![
 //!             // 상단 네비게이션
 //!             Box::new(Nav::new(/* ... */)),
@@ -618,7 +641,7 @@
 //!                 Width::Percent(60),
 //!                 Width::Percent(20),
 //!             ])),
-//!         ]).to_irnode()
+//!         ]).render_to_ir(ctx)
 //!     }
 //!     
 //!     fn head(&self, ctx: &RenderContext) -> HeadElements {
@@ -628,12 +651,32 @@
 //!             .script("/js/search.js")
 //!     }
 //!     
-//!     fn path(&self) -> &str {
-//!         "docs/index.html"
+//!     fn path(&self) -> PathBuf {
+//!         PathBuf::from("docs/index.html")
 //!     }
 //! }
 //! ```
 //!
+//! ## 콘텐츠 디렉토리 로더
+//! Markdown 파일(머리말 + 본문)로부터 Page를 만드는 작업 흐름은 `loader` 모듈을 참고하세요.
+//! ```rust
+//! let posts = ContentLoader::new("content/blog").load(|file| BlogPostPage::from(file))?;
+//! ```
+//!
+//! ## 다국어
+//! 번역 그룹(`TranslationKey`), hreflang 대체 링크, UI 문자열 카탈로그(`t!`)는 `i18n` 모듈을 참고하세요.
+//! ```rust
+//! let meta = Metadata::new().language("en").custom(TranslationKey::new("about"));
+//! t!(ctx, "read_more")
+//! ```
+//!
+//! ## 사이트 변수
+//! 여러 페이지에 반복되는 문자열(버전, 제품 이름)은 `vars` 모듈의 `SiteVars`에 두고 `var!`로 가리킵니다.
+//! ```rust
+//! let meta = Metadata::new().mergeable(SiteVars::new().var("version", "0.4.2"));
+//! Paragraph::from_inlines(vec![Inline::Text("현재 버전: ".to_string()), var!("version")])
+//! ```
+//!
 //! ## 향후 방향
 //!
 //! ### 단기 (Phase 1)
@@ -656,4 +699,37 @@
 //! - [CSS Flexbox](https://css-tricks.com/snippets/css/a-guide-to-flexbox/)
 //! - [CSS Grid](https://css-tricks.com/snippets/css/complete-guide-grid/)
 
+#[allow(clippy::module_inception)]
 pub mod page;
+pub mod accessibility;
+pub mod anchors;
+pub mod frontmatter;
+pub mod glossary;
+pub mod heading_nav;
+pub mod loader;
+pub mod assets;
+pub mod comments;
+pub mod error;
+pub mod head;
+pub mod i18n;
+pub mod layout;
+pub mod link;
+pub mod nav;
+pub mod social;
+pub mod favicon;
+pub mod formats;
+pub mod pagination;
+pub mod partial;
+pub mod preview;
+pub mod redirect;
+pub mod search;
+pub mod series;
+pub mod share;
+pub mod simple;
+pub mod slug;
+pub mod spacing;
+pub mod target;
+pub mod theme;
+pub mod vars;
+pub mod vbox;
+pub mod hbox;
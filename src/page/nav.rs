@@ -0,0 +1,208 @@
+//! # nav.rs - NavMenu
+//!
+//! ## 목적
+//! 링크 트리로 사이트 네비게이션을 렌더링하고, 현재 페이지와 현재 섹션을 표시합니다.
+//! 현재 위치는 렌더링 시점에 `ctx.get::<CurrentUrl>()`으로 판정하므로
+//! 같은 NavMenu를 공유 Layout에 한 번만 두면 모든 페이지에서 올바르게 표시됩니다.
+//!
+//! ## 렌더링 결과 (현재 페이지: `/blog/first-post.html`)
+//! ```html
+//! <nav aria-label="Main" class="nav-menu">
+//!   <ul>
+//!     <li class="nav-item"><a href="/">Home</a></li>
+//!     <li class="active-section nav-item">
+//!       <a href="/blog/">Blog</a>
+//!       <ul>
+//!         <li class="active nav-item"><a aria-current="page" href="/blog/first-post.html">First Post</a></li>
+//!       </ul>
+//!     </li>
+//!   </ul>
+//! </nav>
+//! ```
+//!
+//! ## 사용 예시
+//! ```rust
+//! let nav = NavMenu::new(vec![
+//!     NavLink::new("Home", "/"),
+//!     NavLink::new("Blog", "/blog/").child(NavLink::new("Archive", "/blog/archive.html")),
+//! ]);
+//!
+//! // 페이지 등록 정보에서 자동 생성
+//! let nav = NavMenu::new(site.nav_links()).label("Site");
+//! ```
+//!
+//! ## 구현 상태
+//! - [x] 중첩 링크 트리
+//! - [x] 현재 페이지 `aria-current="page"` + `active`, 상위 섹션 `active-section`
+//! - [x] 페이지 등록 정보에서 자동 생성 (`Site::nav_links`, 같은 단계는 `Weight` 순)
+//! - [ ] TODO: 펼침/접힘 (모바일 메뉴)
+//!
+//! ## 설계 결정
+//! - **URL 비교는 정규화 후**: `/blog/index.html`, `/blog/`, `/blog`는 같은 페이지로,
+//!   쿼리와 fragment는 무시합니다.
+//! - **섹션 판정**: 현재 URL이 링크 경로 아래(`/blog` → `/blog/...`)에 있으면 활성 섹션입니다.
+//!   루트(`/`)는 모든 페이지의 상위이므로 섹션으로 취급하지 않습니다.
+
+use crate::block::block::{Block, class_attrs, element, prose_rule, token_attrs, with_tokens};
+use crate::block::context::RenderContext;
+use crate::html::attributes::SharedAttrs;
+use crate::html::node::{Element, IRNode};
+use crate::html::trust::{Content, SafeString};
+use crate::metadata::types::CurrentUrl;
+
+/// 네비게이션 링크 하나. 하위 링크를 가질 수 있습니다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NavLink {
+    pub label: String,
+    pub href: String,
+    pub children: Vec<NavLink>,
+}
+
+impl NavLink {
+    pub fn new(label: &str, href: &str) -> Self {
+        NavLink {
+            label: label.to_string(),
+            href: href.to_string(),
+            children: Vec::new(),
+        }
+    }
+
+    /// 하위 링크 추가.
+    pub fn child(mut self, link: NavLink) -> Self {
+        self.children.push(link);
+        self
+    }
+
+    /// 이 링크가 현재 페이지를 가리키는지.
+    pub fn is_current(&self, current: &str) -> bool {
+        normalize_url(&self.href) == normalize_url(current)
+    }
+
+    /// 현재 페이지가 이 링크의 하위(섹션 또는 하위 링크)에 있는지. 현재 페이지 자체는 제외.
+    pub fn contains_current(&self, current: &str) -> bool {
+        is_under(normalize_url(current), normalize_url(&self.href))
+            || self
+                .children
+                .iter()
+                .any(|c| c.is_current(current) || c.contains_current(current))
+    }
+}
+
+/// URL 순으로 정렬된 링크 목록을 디렉토리 구조에 따라 트리로 묶습니다.
+/// `/blog/` 링크 아래에 `/blog/...` 링크들이 들어갑니다. (루트 `/` 제외)
+pub fn nest_links(links: Vec<NavLink>) -> Vec<NavLink> {
+    let mut roots: Vec<NavLink> = Vec::new();
+    for link in links {
+        insert_link(&mut roots, link);
+    }
+    roots
+}
+
+fn insert_link(nodes: &mut Vec<NavLink>, link: NavLink) {
+    let href = normalize_url(&link.href).to_string();
+    let parent = nodes
+        .iter_mut()
+        .rev()
+        .find(|node| is_under(&href, normalize_url(&node.href)));
+    match parent {
+        Some(parent) => insert_link(&mut parent.children, link),
+        None => nodes.push(link),
+    }
+}
+
+/// 쿼리/fragment, 끝의 `index.html`과 `/`를 제거한 URL 경로. 루트는 빈 문자열.
+fn normalize_url(url: &str) -> &str {
+    let url = url.split(['?', '#']).next().unwrap_or("");
+    let url = match url.strip_suffix("index.html") {
+        Some(dir) if dir.ends_with('/') => dir,
+        _ => url,
+    };
+    url.trim_end_matches('/')
+}
+
+/// 정규화된 `url`이 정규화된 `section` 아래에 있는지. 루트는 섹션이 아님.
+fn is_under(url: &str, section: &str) -> bool {
+    !section.is_empty()
+        && url.len() > section.len() + 1
+        && url.starts_with(section)
+        && url[section.len()..].starts_with('/')
+}
+
+/// 네비게이션 메뉴 Block.
+#[derive(Debug, Clone)]
+pub struct NavMenu {
+    links: Vec<NavLink>,
+    label: String,
+}
+
+impl NavMenu {
+    pub fn new(links: Vec<NavLink>) -> Self {
+        NavMenu {
+            links,
+            label: "Main".to_string(),
+        }
+    }
+
+    /// `aria-label`. 한 페이지에 nav가 여럿이면 구분되도록 지정합니다. 기본: "Main"
+    pub fn label(mut self, label: &str) -> Self {
+        self.label = label.to_string();
+        self
+    }
+
+    pub fn push(mut self, link: NavLink) -> Self {
+        self.links.push(link);
+        self
+    }
+
+    pub fn links(&self) -> &[NavLink] {
+        &self.links
+    }
+
+    fn list(links: &[NavLink], current: Option<&str>) -> IRNode {
+        let items = links
+            .iter()
+            .map(|link| Element::Node(Self::item(link, current)))
+            .collect();
+        element("ul", SharedAttrs::new(), items)
+    }
+
+    fn item(link: &NavLink, current: Option<&str>) -> IRNode {
+        let is_current = current.is_some_and(|c| link.is_current(c));
+        let in_section = current.is_some_and(|c| link.contains_current(c));
+
+        let mut classes = vec!["nav-item"];
+        if is_current {
+            classes.push("active");
+        } else if in_section {
+            classes.push("active-section");
+        }
+
+        let mut pairs = vec![("href", link.href.as_str())];
+        if is_current {
+            pairs.insert(0, ("aria-current", "page"));
+        }
+        let anchor = element(
+            "a",
+            token_attrs(&pairs),
+            vec![Element::Text(Content::from_str(&link.label, &prose_rule()))],
+        );
+
+        let mut children = vec![Element::Node(anchor)];
+        if !link.children.is_empty() {
+            children.push(Element::Node(Self::list(&link.children, current)));
+        }
+        element("li", class_attrs(&classes), children)
+    }
+}
+
+impl Block for NavMenu {
+    fn render_to_ir(&self, ctx: &RenderContext) -> IRNode {
+        let current = ctx.get::<CurrentUrl>().map(|url| url.0.as_str());
+        let nav_attrs = with_tokens(class_attrs(&["nav-menu"]), &[("aria-label", &self.label)]);
+        element(
+            "nav",
+            nav_attrs,
+            vec![Element::Node(Self::list(&self.links, current))],
+        )
+    }
+}
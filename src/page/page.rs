@@ -1,9 +1,205 @@
-/*
-* block 들을 수집해 온전한 html 파일을 만드는 계층
-* 한 파일이 그대로 한 html 파일이 된다
-*/
-pub trait Page {
-    fn build(&self);
-    fn accept(&self);
+//! # page.rs - Page 트레이트
+//!
+//! block 들을 수집해 온전한 html 파일을 만드는 계층.
+//! 한 Page가 그대로 한 html 파일이 된다.
+
+use std::{any::Any, path::PathBuf, sync::Arc};
+
+use crate::block::block::{Block, element, token_attrs};
+use crate::block::context::RenderContext;
+use crate::block::css::BlockStylesheet;
+use crate::cite::config::SiteConfig;
+use crate::html::attributes::SharedAttrs;
+use crate::html::fragment::FragmentCache;
+use crate::html::node::{Element, IRNode};
+use crate::html::renderer::{HtmlFormat, HtmlRenderer, Renderer};
+use crate::html::trust::HtmlBlock;
+use crate::metadata::builder::Metadata;
+use crate::metadata::map::ResolvedMetadata;
+use crate::metadata::types::{
+    Assets, CriticalCss, CurrentUrl, Language, RenderTarget, TextDirection, Title,
+};
+use crate::page::accessibility::Accessibility;
+use crate::page::anchors::heading_anchors;
+use crate::page::assets::{AssetVersions, asset_url, collect_assets};
+use crate::page::formats::OutputFormats;
+use crate::page::glossary::link_glossary_terms;
+use crate::page::head::HeadElements;
+use crate::page::heading_nav::heading_nav_assets;
+use crate::page::i18n::Translations;
+use crate::page::layout::{Layout, layout_chain};
+use crate::page::preview::{DraftBanner, PreviewStatus};
+use crate::page::slug::url_for_path;
+use crate::page::social::{OpenGraph, SocialImage};
+use crate::page::theme::Theme;
+
+/// 완전한 HTML 문서 하나를 정의하는 템플릿.
+pub trait Page: Any + Send + Sync {
+    /// 페이지의 레이아웃 트리 반환. (`<body>` 내용)
+    fn layout(&self, ctx: &RenderContext) -> IRNode;
+
+    /// 페이지 수준 메타데이터. Site 메타데이터와 병합됩니다.
+    fn metadata(&self) -> Metadata {
+        Metadata::new()
+    }
+
+    /// 출력 파일 경로 (출력 디렉토리 기준 상대 경로). 예: `blog/my-post.html`
+    fn path(&self) -> PathBuf;
+
+    /// `<head>` 내용. 기본: charset, viewport
+    fn head(&self, _ctx: &RenderContext) -> HeadElements {
+        HeadElements::default()
+    }
+
+    /// 본문을 감쌀 공유 Layout (사이트 골격). 기본: 없음
+    fn layout_template(&self) -> Option<Arc<dyn Layout>> {
+        None
+    }
+
+    /// 페이지가 소유한 최상위 Block들. Cite 계층이 Block 트리를 순회할 때 사용.
+    fn blocks(&self) -> Vec<&dyn Block> {
+        Vec::new()
+    }
+
+    /// 화면용 문서와 함께 출력할 대체 문서 (인쇄용 등). 기본: 없음
+    fn alternate_targets(&self) -> Vec<RenderTarget> {
+        Vec::new()
+    }
+}
+
+/// Site 메타데이터와 Page 메타데이터를 병합한 RenderContext 생성.
+/// `base`에 `CurrentUrl`이 없으면 `path()`로부터 채웁니다.
+pub fn page_context(page: &dyn Page, base: &ResolvedMetadata) -> RenderContext {
+    let defaults = Metadata::new()
+        .custom(CurrentUrl(url_for_path(&page.path())))
+        .into_map();
+    RenderContext::new(defaults.merge(base).merge(page.metadata().map()))
+}
+
+/// Page를 `<!DOCTYPE html>`부터 `</html>`까지 완전한 문서로 렌더링.
+/// Block들이 선언한 asset(`Assets`)을 수집하여 head에 주입합니다. `Theme`이 있으면 테마 스타일시트가 맨 앞입니다. `SiteConfig::heading_nav`가 켜져 있으면 그 뒤에 제목 탐색 asset이 옵니다.
+/// 메타데이터에 `CriticalCss`가 있으면 인라인하고 나머지 스타일시트는 지연 로드합니다.
+///
+/// `layout_template()`이 있으면 본문을 Layout 체인으로 감싸고 head를 병합합니다.
+/// `head()`가 제목을 지정하지 않으면 병합된 메타데이터의 `Title`을 사용합니다.
+/// 화면용 문서에는 `alternate_targets()`의 대체 문서와 `OutputFormats`의 추가 형식 link가 추가되고,
+/// 대체 문서는 화면용 문서를 canonical로 가리키며 색인에서 제외됩니다.
+/// 메타데이터에 `Translations`가 있으면 번역마다 hreflang 대체 link를 추가합니다.
+/// `<html>`에는 `Language`의 `lang`과, RTL 언어이거나 `TextDirection`을 지정했으면 `dir`이 붙습니다.
+/// `BlockStylesheet`가 있으면 Block 범위 CSS 스타일시트를 연결합니다.
+/// 본문에는 `Accessibility` 보정(건너뛰기 링크, 단일 `<main>`, 랜드마크 이름)이 적용됩니다.
+pub fn render_document(page: &dyn Page, base: &ResolvedMetadata) -> HtmlBlock {
+    let theme = base.get::<Theme>().map(Theme::assets).unwrap_or_default();
+    let mut assets = theme.union(base.get::<Assets>().unwrap_or(&Assets::new()));
+    if base.get::<SiteConfig>().is_some_and(|config| config.heading_nav) {
+        assets = assets.union(&heading_nav_assets());
+    }
+    render_document_with_assets(page, base, &assets.union(&collect_assets(page)))
 }
 
+/// 미리 수집한 asset으로 문서 렌더링. (Cite 계층이 사이트 단위로 수집한 경우)
+pub fn render_document_with_assets(
+    page: &dyn Page,
+    base: &ResolvedMetadata,
+    assets: &Assets,
+) -> HtmlBlock {
+    document_html(&document_ir(page, base, assets))
+}
+
+/// 문자열로 바꾸기 전의 문서 IR (`<html>` 요소). 렌더링 결과 검사(`HtmlValidator`)용.
+pub fn document_ir(page: &dyn Page, base: &ResolvedMetadata, assets: &Assets) -> IRNode {
+    let ctx = page_context(page, base);
+
+    let chain = page.layout_template().map(layout_chain).unwrap_or_default();
+    let content = link_glossary_terms(heading_anchors(page.layout(&ctx), &ctx), &ctx);
+    let body = chain.iter().fold(content, |body, layout| layout.wrap(body, &ctx));
+    let mut head = chain
+        .iter()
+        .rev()
+        .fold(HeadElements::default(), |head, layout| head.extend(layout.head(&ctx)))
+        .extend(page.head(&ctx))
+        .assets(&match ctx.get::<AssetVersions>() {
+            Some(versions) => versions.assets(assets),
+            None => assets.clone(),
+        });
+    if let Some(sheet) = ctx.get::<BlockStylesheet>() {
+        head = head.stylesheet(&asset_url(&ctx, &sheet.0));
+    }
+    if let Some(css) = ctx.get::<CriticalCss>() {
+        head = head.critical_css(&css.0);
+    }
+    if head.title.is_none() {
+        head.title = ctx.get::<Title>().map(|t| t.0.clone());
+    }
+    if let Some(image) = ctx.get::<SocialImage>() {
+        let og = head
+            .open_graph
+            .take()
+            .unwrap_or_else(|| OpenGraph::new(head.title.as_deref().unwrap_or_default()));
+        head.open_graph = Some(image.apply(og));
+    }
+    if let Some(url) = ctx.get::<CurrentUrl>() {
+        head = if ctx.target() == RenderTarget::Screen {
+            let head = head.alternate_targets(&url.0, &page.alternate_targets());
+            match ctx.get::<OutputFormats>() {
+                Some(formats) => head.output_formats(&url.0, formats),
+                None => head,
+            }
+        } else {
+            // 대체 문서는 검색 결과에 중복으로 나오지 않도록 화면용 문서를 정본으로 지정
+            let canonical = head.canonical_url.clone().unwrap_or_else(|| url.0.clone());
+            head.canonical_url(&canonical).meta_name("robots", "noindex")
+        };
+    }
+
+    if let Some(translations) = ctx.get::<Translations>() {
+        head = translations.links().into_iter().fold(head, |head, link| head.link(link));
+    }
+
+    // `dir`은 RTL이거나 직접 지정했을 때만 (LTR은 브라우저 기본)
+    let mut html_pairs: Vec<(&str, &str)> = Vec::new();
+    if let Some(lang) = ctx.get::<Language>() {
+        html_pairs.push(("lang", &lang.0));
+    }
+    let direction = ctx.direction();
+    if direction == TextDirection::Rtl || ctx.get::<TextDirection>().is_some() {
+        html_pairs.push(("dir", direction.as_str()));
+    }
+    let html_attrs = token_attrs(&html_pairs);
+
+    let a11y = ctx.get::<Accessibility>().cloned().unwrap_or_default();
+    if a11y.skip_link.is_some() {
+        head = head.inline_style(Accessibility::CSS);
+    }
+    let mut childs = a11y.apply(body);
+    if ctx.get::<PreviewStatus>().is_some() {
+        // 미리보기 빌드의 미발행 페이지: 색인 제외 + 건너뛰기 링크 다음에 배너
+        head = head.meta_name("robots", "noindex").inline_style(DraftBanner::CSS);
+        let banner = ctx.get::<DraftBanner>().cloned().unwrap_or_default();
+        let banner = banner.render_to_ir(&ctx);
+        childs.insert(childs.len().saturating_sub(1), Element::Node(banner));
+    }
+    let body = element("body", SharedAttrs::new(), childs);
+    element(
+        "html",
+        html_attrs,
+        vec![Element::Node(head.to_irnode()), Element::Node(body)],
+    )
+}
+
+/// 문서 IR을 `<!DOCTYPE html>`이 붙은 HTML로.
+pub fn document_html(html: &IRNode) -> HtmlBlock {
+    document_html_with(html, HtmlFormat::Compact)
+}
+
+/// 출력 형식(`HtmlFormat`)을 지정해 문서 IR을 HTML로.
+pub fn document_html_with(html: &IRNode, format: HtmlFormat) -> HtmlBlock {
+    html.accept(HtmlRenderer::with_format(format)).finalize().clone()
+}
+
+/// 공유 조각 캐시(`FragmentCache`)를 쓰며 문서 IR을 HTML로. 결과는 `document_html_with`와 같습니다.
+pub fn document_html_cached(html: &IRNode, format: HtmlFormat, fragments: Arc<FragmentCache>) -> HtmlBlock {
+    html.accept(HtmlRenderer::with_format(format).with_fragments(fragments))
+        .finalize()
+        .clone()
+}
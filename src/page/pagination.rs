@@ -0,0 +1,256 @@
+//! # pagination.rs - Pagination
+//!
+//! ## 목적
+//! 여러 페이지로 나뉜 목록(블로그 글 목록, 태그 페이지 등)의 페이지 이동 UI를 렌더링합니다.
+//! 페이지 정보(`Paging`)는 Cite 계층의 컬렉션 페이지네이터가 메타데이터로 주입하며,
+//! Block은 렌더링 시점에 `ctx.get::<Paging>()`으로 읽습니다.
+//! 라벨은 메시지 카탈로그(`MessageCatalog`)의 `pagination.*` 키에서 현재 페이지 언어로 읽습니다.
+//!
+//! ## 렌더링 결과 (5페이지 중 3페이지, window 1)
+//! ```html
+//! <nav aria-label="Pagination" class="pagination">
+//!   <ul>
+//!     <li><a href="/blog/">First</a></li>
+//!     <li><a href="/blog/page/2/" rel="prev">Previous</a></li>
+//!     <li><span class="pagination-ellipsis">…</span></li>
+//!     <li><a href="/blog/page/2/">2</a></li>
+//!     <li><a aria-current="page" href="/blog/page/3/">3</a></li>
+//!     <li><a href="/blog/page/4/">4</a></li>
+//!     <li><span class="pagination-ellipsis">…</span></li>
+//!     <li><a href="/blog/page/4/" rel="next">Next</a></li>
+//!     <li><a href="/blog/page/5/">Last</a></li>
+//!   </ul>
+//! </nav>
+//! ```
+//!
+//! ## 사용 예시
+//! ```rust
+//! fn layout(&self, ctx: &RenderContext) -> IRNode {
+//!     VBox::new(vec![
+//!         Box::new(self.post_list),
+//!         Box::new(Pagination::new().window(2)),
+//!     ])
+//!     .render_to_ir(ctx)
+//! }
+//!
+//! fn head(&self, ctx: &RenderContext) -> HeadElements {
+//!     HeadElements::new().paging(ctx.get::<Paging>())  // rel=prev/next
+//! }
+//!
+//! // 라벨 번역 (없는 키는 영어 기본값)
+//! MessageCatalog::new().messages("ko", &[
+//!     ("pagination.nav", "페이지 이동"),
+//!     ("pagination.first", "처음"),
+//!     ("pagination.prev", "이전"),
+//!     ("pagination.next", "다음"),
+//!     ("pagination.last", "마지막"),
+//! ]);
+//! ```
+//!
+//! ## 구현 상태
+//! - [x] 이전/다음, 처음/마지막, 번호 창(window) + 생략 표시
+//! - [x] `aria-current="page"`, `rel="prev"/"next"`
+//! - [x] head의 `<link rel="prev/next">`
+//! - [x] 라벨 다국어화 (메시지 카탈로그 `pagination.nav`, `.first`, `.prev`, `.next`, `.last`)
+//!
+//! ## 설계 결정
+//! - **페이지가 하나뿐이면 출력 없음**: 한 페이지뿐인 목록도 같은 템플릿을 쓰므로 오류 대신
+//!   빈 조각(`IRNode::fragment`)을 렌더링합니다. 이름 붙은 빈 `nav`는 스크린 리더가 랜드마크로 읽습니다.
+//! - **직접 지정한 라벨이 우선**: `labels`로 지정하면 카탈로그를 보지 않습니다.
+
+use crate::block::block::{Block, class_attrs, element, prose_rule, token_attrs, with_tokens};
+use crate::block::context::RenderContext;
+use crate::cite::dependency::Input;
+use crate::html::attributes::SharedAttrs;
+use crate::html::node::{Element, IRNode};
+use crate::html::trust::{Content, SafeString};
+use crate::metadata::types::{Language, Paging};
+use crate::page::head::{HeadElements, LinkTag};
+use crate::page::i18n::MessageCatalog;
+
+/// 페이지 이동 링크 라벨.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PaginationLabels {
+    pub nav: String,
+    pub first: String,
+    pub prev: String,
+    pub next: String,
+    pub last: String,
+}
+
+impl Default for PaginationLabels {
+    fn default() -> Self {
+        PaginationLabels {
+            nav: "Pagination".to_string(),
+            first: "First".to_string(),
+            prev: "Previous".to_string(),
+            next: "Next".to_string(),
+            last: "Last".to_string(),
+        }
+    }
+}
+
+impl PaginationLabels {
+    /// 메시지 카탈로그의 `pagination.*` 키로 현재 페이지 언어의 라벨. 없는 키는 기본 라벨.
+    pub fn from_context(ctx: &RenderContext) -> Self {
+        ctx.depends_on(Input::metadata::<MessageCatalog>());
+        let language = ctx.get::<Language>().map(|l| l.0.as_str());
+        let catalog = ctx.get::<MessageCatalog>();
+        let label = |key: &str, default: String| {
+            catalog
+                .and_then(|catalog| catalog.get(language, &format!("pagination.{}", key)))
+                .map_or(default, str::to_string)
+        };
+        let defaults = PaginationLabels::default();
+        PaginationLabels {
+            nav: label("nav", defaults.nav),
+            first: label("first", defaults.first),
+            prev: label("prev", defaults.prev),
+            next: label("next", defaults.next),
+            last: label("last", defaults.last),
+        }
+    }
+}
+
+/// 페이지네이션 Block.
+#[derive(Debug, Clone)]
+pub struct Pagination {
+    window: usize,
+    first_last: bool,
+    labels: Option<PaginationLabels>,
+}
+
+impl Default for Pagination {
+    fn default() -> Self {
+        Pagination {
+            window: 2,
+            first_last: true,
+            labels: None,
+        }
+    }
+}
+
+impl Pagination {
+    pub fn new() -> Self {
+        Pagination::default()
+    }
+
+    /// 현재 페이지 앞뒤로 보여줄 번호 개수. 기본: 2
+    pub fn window(mut self, window: usize) -> Self {
+        self.window = window;
+        self
+    }
+
+    /// 처음/마지막 링크 표시 여부. 기본: true
+    pub fn first_last(mut self, show: bool) -> Self {
+        self.first_last = show;
+        self
+    }
+
+    /// 라벨 직접 지정. 기본: 메시지 카탈로그 (`PaginationLabels::from_context`)
+    pub fn labels(mut self, labels: PaginationLabels) -> Self {
+        self.labels = Some(labels);
+        self
+    }
+
+    fn item(child: IRNode) -> Element {
+        Element::Node(element("li", SharedAttrs::new(), vec![Element::Node(child)]))
+    }
+
+    fn link(href: &str, text: &str, extra: &[(&str, &str)]) -> IRNode {
+        let mut pairs = vec![("href", href)];
+        pairs.extend_from_slice(extra);
+        element(
+            "a",
+            token_attrs(&pairs),
+            vec![Element::Text(Content::from_str(text, &prose_rule()))],
+        )
+    }
+
+    fn ellipsis() -> IRNode {
+        element(
+            "span",
+            class_attrs(&["pagination-ellipsis"]),
+            vec![Element::Text(Content::from_str("…", &prose_rule()))],
+        )
+    }
+
+    fn items(&self, paging: &Paging, labels: &PaginationLabels) -> Vec<Element> {
+        let current = paging.current;
+        let total = paging.total();
+        let start = current.saturating_sub(self.window).max(1);
+        let end = (current + self.window).min(total);
+        let mut items = Vec::new();
+
+        if self.first_last && current > 1 {
+            items.push(Self::item(Self::link(&paging.urls[0], &labels.first, &[])));
+        }
+        if let Some(prev) = paging.prev_url() {
+            items.push(Self::item(Self::link(prev, &labels.prev, &[("rel", "prev")])));
+        }
+        if start > 1 {
+            items.push(Self::item(Self::ellipsis()));
+        }
+        for n in start..=end {
+            let url = &paging.urls[n - 1];
+            let extra: &[(&str, &str)] = if n == current {
+                &[("aria-current", "page")]
+            } else {
+                &[]
+            };
+            items.push(Self::item(Self::link(url, &n.to_string(), extra)));
+        }
+        if end < total {
+            items.push(Self::item(Self::ellipsis()));
+        }
+        if let Some(next) = paging.next_url() {
+            items.push(Self::item(Self::link(next, &labels.next, &[("rel", "next")])));
+        }
+        if self.first_last && current < total {
+            items.push(Self::item(Self::link(
+                &paging.urls[total - 1],
+                &labels.last,
+                &[],
+            )));
+        }
+
+        items
+    }
+}
+
+impl Block for Pagination {
+    fn render_to_ir(&self, ctx: &RenderContext) -> IRNode {
+        let Some(paging) = ctx.get::<Paging>().filter(|paging| paging.total() > 1) else {
+            return IRNode::fragment(Vec::new());
+        };
+        let labels = self
+            .labels
+            .clone()
+            .unwrap_or_else(|| PaginationLabels::from_context(ctx));
+        let items = self.items(paging, &labels);
+
+        let nav_attrs = with_tokens(class_attrs(&["pagination"]), &[("aria-label", &labels.nav)]);
+        element(
+            "nav",
+            nav_attrs,
+            vec![Element::Node(element("ul", SharedAttrs::new(), items))],
+        )
+    }
+}
+
+impl HeadElements {
+    /// `<link rel="prev">`, `<link rel="next">` 추가. 페이지 정보가 없으면 그대로.
+    pub fn paging(self, paging: Option<&Paging>) -> Self {
+        let Some(paging) = paging else {
+            return self;
+        };
+        let head = match paging.prev_url() {
+            Some(prev) => self.link(LinkTag::new("prev", prev)),
+            None => self,
+        };
+        match paging.next_url() {
+            Some(next) => head.link(LinkTag::new("next", next)),
+            None => head,
+        }
+    }
+}
@@ -0,0 +1,163 @@
+//! # partial.rs - 재사용 조각 (Partial)
+//!
+//! ## 목적
+//! 뉴스레터 구독 상자처럼 여러 페이지에 반복되는 Block 묶음을 이름으로 한 번 등록하고,
+//! 페이지에서는 이름과 매개변수로 참조합니다. 정의를 고치면 사이트 전체에 반영됩니다.
+//!
+//! ## 사용 예시
+//! ```rust
+//! site.register_partial("newsletter", |params: &PartialParams, _ctx: &RenderContext| {
+//!     Box::new(QuoteBlock::new(vec![
+//!         Box::new(Paragraph::new(params.get_or("title", "뉴스레터 구독"))),
+//!     ])) as Box<dyn Block>
+//! });
+//!
+//! // 페이지에서 참조
+//! SimplePage::new("Post", "post.html")
+//!     .add_block(Box::new(Include::new("newsletter").param("title", "새 글 알림 받기")));
+//! ```
+//!
+//! ## 흐름
+//! ```text
+//! Site::register_partial(name, partial)
+//!   ↓ PartialRegistry를 사이트 메타데이터로 주입
+//! RenderContext
+//!   ↓ Include::render_to_ir(ctx) → ctx.get::<PartialRegistry>()
+//! partial.render(params, ctx) → Block → IRNode
+//! ```
+//!
+//! ## 설계 결정
+//! - **렌더링 시점 해석**: Include는 이름만 가지므로 Partial을 나중에 등록하거나 교체해도 됩니다.
+//! - **없는 이름은 주석으로**: 빌드를 멈추지 않고 `<!-- missing partial: name -->`을 남깁니다.
+//! - **asset 수집 제외**: Partial의 Block은 렌더링 시점에 만들어지므로 `collect_assets`에
+//!   포함되지 않습니다. Partial이 필요로 하는 asset은 사이트 메타데이터에 선언하세요.
+
+use std::collections::BTreeMap;
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use crate::block::block::{Block, class_attrs, element, render_block};
+use crate::block::context::RenderContext;
+use crate::cite::dependency::Input;
+use crate::html::node::{Element, IRNode};
+use crate::html::trust::HtmlBlock;
+use crate::metadata::value::MetadataValue;
+
+/// Include가 Partial에 넘기는 매개변수.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PartialParams {
+    values: BTreeMap<String, String>,
+}
+
+impl PartialParams {
+    pub fn new() -> Self {
+        PartialParams::default()
+    }
+
+    pub fn set(mut self, key: &str, value: &str) -> Self {
+        self.values.insert(key.to_string(), value.to_string());
+        self
+    }
+
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.values.get(key).map(|v| v.as_str())
+    }
+
+    pub fn get_or<'a>(&'a self, key: &str, default: &'a str) -> &'a str {
+        self.get(key).unwrap_or(default)
+    }
+}
+
+/// 이름으로 등록되는 재사용 조각.
+pub trait Partial: Send + Sync {
+    fn render(&self, params: &PartialParams, ctx: &RenderContext) -> Box<dyn Block>;
+}
+
+impl<F> Partial for F
+where
+    F: Fn(&PartialParams, &RenderContext) -> Box<dyn Block> + Send + Sync,
+{
+    fn render(&self, params: &PartialParams, ctx: &RenderContext) -> Box<dyn Block> {
+        self(params, ctx)
+    }
+}
+
+/// 이름 → Partial 표. 사이트 메타데이터로 주입됩니다.
+#[derive(Clone, Default)]
+pub struct PartialRegistry {
+    partials: BTreeMap<String, Arc<dyn Partial>>,
+}
+
+impl MetadataValue for PartialRegistry {}
+
+impl Debug for PartialRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.partials.keys()).finish()
+    }
+}
+
+impl PartialRegistry {
+    pub fn new() -> Self {
+        PartialRegistry::default()
+    }
+
+    /// 등록. 같은 이름이 있으면 교체합니다.
+    pub fn register<P: Partial + 'static>(&mut self, name: &str, partial: P) {
+        self.partials.insert(name.to_string(), Arc::new(partial));
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Arc<dyn Partial>> {
+        self.partials.get(name)
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.partials.keys().map(|k| k.as_str())
+    }
+}
+
+/// 등록된 Partial을 이름으로 참조하는 Block.
+///
+/// ```html
+/// <div class="partial partial-newsletter"><!-- partial 내용 --></div>
+/// ```
+#[derive(Debug, Clone)]
+pub struct Include {
+    name: String,
+    params: PartialParams,
+}
+
+impl Include {
+    pub fn new(name: &str) -> Self {
+        Include {
+            name: name.to_string(),
+            params: PartialParams::new(),
+        }
+    }
+
+    pub fn param(mut self, key: &str, value: &str) -> Self {
+        self.params = self.params.set(key, value);
+        self
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl Block for Include {
+    fn render_to_ir(&self, ctx: &RenderContext) -> IRNode {
+        let name_class = format!("partial-{}", self.name);
+        let attrs = class_attrs(&["partial", &name_class]);
+
+        ctx.depends_on(Input::partial(&self.name));
+        let child = match ctx.get::<PartialRegistry>().and_then(|r| r.get(&self.name)) {
+            Some(partial) => Element::Node(render_block(partial.render(&self.params, ctx).as_ref(), ctx)),
+            None => Element::Raw(HtmlBlock::from_str(&format!(
+                "<!-- missing partial: {} -->",
+                self.name.replace("--", "")
+            ))),
+        };
+
+        element("div", attrs, vec![child])
+    }
+}
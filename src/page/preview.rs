@@ -0,0 +1,112 @@
+//! # preview.rs - 초안 미리보기
+//!
+//! ## 목적
+//! 초안과 예약 페이지를 스테이징 서버에서 함께 볼 수 있도록 미리보기 빌드(`Site::preview`)를 지원합니다.
+//! 미리보기 빌드는 발행되지 않은 페이지도 출력하되, 그 페이지에만 `PreviewStatus`를 주입합니다.
+//! 문서 조립 단계는 이 표시가 있는 문서에 `<meta name="robots" content="noindex">`를 넣고
+//! 본문 맨 앞에 초안 배너(`DraftBanner`)를 붙입니다.
+//!
+//! ## 사용 예시
+//! ```rust
+//! let site = Site::new().preview(true);  // quo build --preview
+//!
+//! // 배너 문구 바꾸기 (사이트 메타데이터)
+//! let site = Site::new()
+//!     .preview(true)
+//!     .metadata(Metadata::new().custom(DraftBanner::new().draft("초안: 아직 발행되지 않은 글입니다")));
+//! ```
+//!
+//! ## 렌더링 결과 (초안 페이지)
+//! ```html
+//! <head>... <meta content="noindex" name="robots"> <style>.draft-banner{...}</style></head>
+//! <body>
+//!   <div class="draft-banner" role="status">Draft preview: this page is not published</div>
+//!   ...
+//! </body>
+//! ```
+//!
+//! ## 구현 상태
+//! - [x] 초안/예약 페이지 출력 + noindex + 배너
+//! - [x] 예약 페이지는 발행 예정일 표시
+//! - [x] sitemap, 피드에서 제외
+//! - [ ] TODO: 미리보기 빌드 전체 noindex (`X-Robots-Tag` 헤더 파일)
+//!
+//! ## 설계 결정
+//! - **발행된 페이지는 그대로**: 미리보기 빌드라도 발행된 페이지는 일반 빌드와 같은 문서를 만듭니다.
+//!   배포 전 검토에서 실제 모습을 그대로 볼 수 있습니다.
+//! - **`include_drafts`와 구분**: `include_drafts`는 초안을 일반 페이지처럼 출력하고(로컬 작성용),
+//!   `preview`는 공유해도 색인되지 않도록 표시합니다.
+//! - **배너는 Site가 삽입**: Layout을 고치지 않아도 모든 초안 문서에 배너가 붙습니다. 문구와 모양은
+//!   사이트 메타데이터의 `DraftBanner`와 `.draft-banner` 스타일로 바꿉니다.
+
+use crate::block::block::{Block, class_attrs, element, prose_rule, with_tokens};
+use crate::block::context::RenderContext;
+use crate::html::node::{Element, IRNode};
+use crate::html::trust::{Content, SafeString};
+use crate::metadata::format::Locale;
+use crate::metadata::types::PageStatus;
+use crate::metadata::value::MetadataValue;
+
+/// 미리보기 빌드에서 발행되지 않은 페이지의 상태. Site가 주입합니다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PreviewStatus(pub PageStatus);
+impl MetadataValue for PreviewStatus {}
+
+/// 초안 배너 Block. 사이트 메타데이터로 넣으면 Site가 삽입하는 배너의 문구가 됩니다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DraftBanner {
+    draft: String,
+    scheduled: String,
+}
+impl MetadataValue for DraftBanner {}
+
+impl Default for DraftBanner {
+    fn default() -> Self {
+        DraftBanner {
+            draft: "Draft preview: this page is not published".to_string(),
+            scheduled: "Scheduled preview: this page will be published on {date}".to_string(),
+        }
+    }
+}
+
+impl DraftBanner {
+    /// 배너가 화면 위에 고정되도록 하는 CSS.
+    pub const CSS: &'static str = ".draft-banner{position:sticky;top:0;z-index:1000;padding:.5rem 1rem;background:#b91c1c;color:#fff;text-align:center;font-weight:600}";
+
+    pub fn new() -> Self {
+        DraftBanner::default()
+    }
+
+    /// 초안 문구.
+    pub fn draft(mut self, text: &str) -> Self {
+        self.draft = text.to_string();
+        self
+    }
+
+    /// 예약 페이지 문구. `{date}`는 발행 예정일로 바뀝니다 (페이지 언어의 날짜 형식, `Locale`).
+    pub fn scheduled(mut self, text: &str) -> Self {
+        self.scheduled = text.to_string();
+        self
+    }
+
+    /// 상태별 문구. 발행된 페이지면 `None`.
+    pub fn text(&self, status: PageStatus, locale: &Locale) -> Option<String> {
+        match status {
+            PageStatus::Draft => Some(self.draft.clone()),
+            PageStatus::Scheduled(date) => Some(self.scheduled.replace("{date}", &locale.date(date))),
+            PageStatus::Published => None,
+        }
+    }
+}
+
+impl Block for DraftBanner {
+    fn render_to_ir(&self, ctx: &RenderContext) -> IRNode {
+        let status = ctx.get::<PreviewStatus>().map_or(PageStatus::Draft, |s| s.0);
+        let text = self.text(status, &Locale::from_metadata(&ctx.metadata)).unwrap_or_default();
+        element(
+            "div",
+            with_tokens(class_attrs(&["draft-banner"]), &[("role", "status")]),
+            vec![Element::Text(Content::from_str(&text, &prose_rule()))],
+        )
+    }
+}
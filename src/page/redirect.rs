@@ -0,0 +1,107 @@
+//! # redirect.rs - RedirectPage
+//!
+//! ## 목적
+//! 옮겨진 페이지의 옛 경로에 새 주소로 이동시키는 HTML 문서를 남깁니다.
+//! 서버 설정 없이 동작하는 meta refresh + canonical link를 출력하고,
+//! 매핑(`Redirect`)을 메타데이터로 제공하여 Cite 계층이 호스트별 리다이렉트 파일
+//! (`_redirects`, `vercel.json` 등)도 생성할 수 있게 합니다.
+//!
+//! ## 사용 예시
+//! ```rust
+//! site.register_page(Box::new(RedirectPage::new("/old-post/", "/blog/new-post/")));
+//! site.register_page(Box::new(RedirectPage::new("/legacy.html", "https://example.com/")));
+//! ```
+//!
+//! ## 렌더링 결과 (`old-post/index.html`)
+//! ```html
+//! <head>
+//!   <title>Redirecting…</title>
+//!   <link href="/blog/new-post/" rel="canonical" >
+//!   <meta content="0; url=/blog/new-post/" http-equiv="refresh" >
+//!   <meta content="noindex" name="robots" >
+//! </head>
+//! <body><p>Redirecting to <a href="/blog/new-post/">/blog/new-post/</a></p></body>
+//! ```
+//!
+//! ## 출력 경로 규칙
+//! - `/old/` 또는 `/old` → `old/index.html`
+//! - `/old.html` → `old.html`
+
+use std::path::PathBuf;
+
+use crate::block::block::Block;
+use crate::block::context::RenderContext;
+use crate::block::inline::Inline;
+use crate::block::prose::Paragraph;
+use crate::html::node::IRNode;
+use crate::metadata::builder::Metadata;
+use crate::metadata::types::Redirect;
+use crate::page::head::{HeadElements, MetaTag};
+use crate::page::page::Page;
+
+/// 옛 경로 → 새 주소 리다이렉트 문서.
+#[derive(Debug, Clone)]
+pub struct RedirectPage {
+    redirect: Redirect,
+}
+
+impl RedirectPage {
+    /// 영구 이동(301) 리다이렉트. `from`은 사이트 루트 기준 URL 경로.
+    pub fn new(from: &str, to: &str) -> Self {
+        let from = format!("/{}", from.trim_start_matches('/'));
+        RedirectPage {
+            redirect: Redirect {
+                from,
+                to: to.to_string(),
+                permanent: true,
+            },
+        }
+    }
+
+    /// 임시 이동(302)으로 지정.
+    pub fn temporary(mut self) -> Self {
+        self.redirect.permanent = false;
+        self
+    }
+
+    pub fn redirect(&self) -> &Redirect {
+        &self.redirect
+    }
+}
+
+impl Page for RedirectPage {
+    fn layout(&self, ctx: &RenderContext) -> IRNode {
+        Paragraph::from_inlines(vec![
+            Inline::Text("Redirecting to ".to_string()),
+            Inline::Link {
+                href: self.redirect.to.clone(),
+                children: vec![Inline::Text(self.redirect.to.clone())],
+            },
+        ])
+        .render_to_ir(ctx)
+    }
+
+    fn metadata(&self) -> Metadata {
+        Metadata::new().custom(self.redirect.clone())
+    }
+
+    fn path(&self) -> PathBuf {
+        let from = self.redirect.from.trim_matches('/');
+        if from.ends_with(".html") {
+            PathBuf::from(from)
+        } else {
+            PathBuf::from(from).join("index.html")
+        }
+    }
+
+    fn head(&self, _ctx: &RenderContext) -> HeadElements {
+        HeadElements::new()
+            .title("Redirecting…")
+            .meta(MetaTag::HttpEquiv {
+                http_equiv: "refresh".to_string(),
+                content: format!("0; url={}", self.redirect.to),
+            })
+            .meta_name("robots", "noindex")
+            .canonical_url(&self.redirect.to)
+    }
+}
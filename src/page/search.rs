@@ -0,0 +1,214 @@
+//! # search.rs - SearchBox
+//!
+//! ## 목적
+//! `SearchIndexGenerator`가 만든 `search.json`으로 검색하는 입력창 Block입니다.
+//! 마크업은 일반 `<form>`이고, 스크립트(`/assets/search.js`)가 있으면 입력할 때마다 결과 목록을 채웁니다.
+//! 스크립트는 Block 메타데이터(`Assets`)로 선언하므로 검색창이 있는 페이지에만 자동으로 들어갑니다.
+//!
+//! ## 사용 예시
+//! ```rust
+//! site.register_visitor(SearchIndexGenerator::new());  // search.json + assets/search.js
+//!
+//! // 공유 Layout의 머리글
+//! SearchBox::new()
+//!     .placeholder("글 검색")
+//!     .limit(8)
+//!     .fallback("https://duckduckgo.com/?sites=blog.example.com");  // 스크립트 없이 제출했을 때
+//! ```
+//!
+//! ## 렌더링 결과
+//! ```html
+//! <form action="https://duckduckgo.com/" class="search-box" method="get" role="search">
+//!   <label class="search-label" for="search">Search</label>
+//!   <input aria-controls="search-results" autocomplete="off" data-search-index="/search.json"
+//!          data-search-limit="8" id="search" name="q" placeholder="글 검색" type="search">
+//!   <input name="sites" type="hidden" value="blog.example.com">
+//!   <ul aria-live="polite" class="search-results" hidden id="search-results"></ul>
+//! </form>
+//! ```
+//!
+//! ## 구현 상태
+//! - [x] 입력창 마크업, 결과 목록 (`aria-live`)
+//! - [x] 검색 스크립트 자동 주입 (`Assets`), 색인은 처음 입력할 때 한 번만 받음
+//! - [x] `search.json`의 필드 가중치로 점수 계산 (모든 검색어가 어딘가에 있어야 결과)
+//! - [x] 스크립트 없을 때 외부 검색으로 제출 (`fallback`)
+//! - [ ] TODO: 결과 목록 키보드 이동 (위/아래 화살표)
+//!
+//! ## 설계 결정
+//! - **점진적 향상**: 스크립트가 없거나 실패해도 `<form>`은 동작합니다 (`fallback`이 없으면 같은 페이지로 제출).
+//! - **스크립트 파일은 색인과 함께 기록**: `SearchIndexGenerator`가 검색창을 쓰는 페이지가 있을 때만
+//!   `assets/search.js`를 기록합니다. 검색창만 두고 색인 방문자를 등록하지 않으면 결과가 나오지 않습니다.
+//! - **외부 라이브러리 없음**: 부분 문자열 일치와 필드 가중치 합만 씁니다. 형태소 분석이나 오타 허용이
+//!   필요하면 `search.json`을 lunr, Fuse.js로 직접 읽습니다.
+
+use crate::block::block::{Block, class_attrs, element, prose_rule, token_attrs, void_element, with_tokens};
+use crate::block::context::RenderContext;
+use crate::html::attributes::AttrValues;
+use crate::html::node::{Element, IRNode};
+use crate::html::trust::{AttrKey, Content, SafeString};
+use crate::metadata::builder::Metadata;
+use crate::metadata::types::Assets;
+
+/// 검색 스크립트 출력 경로 (출력 디렉토리 기준).
+pub const SEARCH_SCRIPT_PATH: &str = "assets/search.js";
+
+/// 검색 스크립트 URL (`Assets`로 주입).
+pub const SEARCH_SCRIPT_URL: &str = "/assets/search.js";
+
+/// 검색 스크립트. `data-search-index`가 있는 입력창마다 연결됩니다.
+pub const SEARCH_SCRIPT: &str = r#"(()=>{
+for(const input of document.querySelectorAll("input[data-search-index]")){
+  const list=document.getElementById(input.getAttribute("aria-controls"));
+  const limit=Number(input.dataset.searchLimit)||10;
+  let index=null;
+  const load=()=>index||(index=fetch(input.dataset.searchIndex).then(r=>r.json()));
+  const text=v=>(Array.isArray(v)?v.join(" "):v||"").toLowerCase();
+  const score=(doc,fields,terms)=>{
+    let total=0;
+    for(const term of terms){
+      const hit=fields.reduce((s,f)=>s+(text(doc[f.name]).includes(term)?f.weight:0),0);
+      if(!hit)return 0;
+      total+=hit;
+    }
+    return total;
+  };
+  input.addEventListener("focus",load,{once:true});
+  input.addEventListener("input",async()=>{
+    const terms=input.value.toLowerCase().split(/\s+/).filter(Boolean);
+    const {fields,documents}=await load();
+    const hits=terms.length?documents.map(d=>[score(d,fields,terms),d]).filter(([s])=>s>0)
+      .sort((a,b)=>b[0]-a[0]).slice(0,limit):[];
+    list.replaceChildren(...hits.map(([,d])=>{
+      const li=document.createElement("li"),a=document.createElement("a");
+      a.href=d.url;a.textContent=d.title||d.url;li.append(a);return li;
+    }));
+    list.hidden=!hits.length;
+  });
+  input.form?.addEventListener("submit",e=>{
+    const first=list.querySelector("a");
+    if(first){e.preventDefault();location.href=first.href;}
+  });
+}
+})();
+"#;
+
+/// 검색창 Block.
+#[derive(Debug, Clone)]
+pub struct SearchBox {
+    id: String,
+    index: String,
+    label: String,
+    placeholder: Option<String>,
+    limit: usize,
+    fallback: Option<String>,
+}
+
+impl Default for SearchBox {
+    fn default() -> Self {
+        SearchBox {
+            id: "search".to_string(),
+            index: "/search.json".to_string(),
+            label: "Search".to_string(),
+            placeholder: None,
+            limit: 10,
+            fallback: None,
+        }
+    }
+}
+
+impl SearchBox {
+    pub fn new() -> Self {
+        SearchBox::default()
+    }
+
+    /// 입력창 `id`. 한 페이지에 검색창이 둘 이상이면 서로 다르게 지정합니다. 기본: `search`
+    pub fn id(mut self, id: &str) -> Self {
+        self.id = id.to_string();
+        self
+    }
+
+    /// 색인 URL. `SearchIndexGenerator::path`를 바꿨으면 같이 바꿉니다. 기본: `/search.json`
+    pub fn index(mut self, url: &str) -> Self {
+        self.index = url.to_string();
+        self
+    }
+
+    /// 입력창 라벨. 기본: `Search`
+    pub fn label(mut self, label: &str) -> Self {
+        self.label = label.to_string();
+        self
+    }
+
+    pub fn placeholder(mut self, placeholder: &str) -> Self {
+        self.placeholder = Some(placeholder.to_string());
+        self
+    }
+
+    /// 결과 최대 개수. 기본: 10
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    /// 스크립트 없이 제출했을 때 이동할 검색 주소. 쿼리 문자열은 숨은 입력으로 함께 보냅니다.
+    /// 검색어는 `q`로 전달됩니다.
+    pub fn fallback(mut self, url: &str) -> Self {
+        self.fallback = Some(url.to_string());
+        self
+    }
+}
+
+impl Block for SearchBox {
+    fn metadata(&self) -> Metadata {
+        Metadata::new().assets(Assets::new().script(SEARCH_SCRIPT_URL))
+    }
+
+    fn render_to_ir(&self, _ctx: &RenderContext) -> IRNode {
+        let results_id = format!("{}-results", self.id);
+        let limit = self.limit.to_string();
+
+        let label = element(
+            "label",
+            with_tokens(class_attrs(&["search-label"]), &[("for", &self.id)]),
+            vec![Element::Text(Content::from_str(&self.label, &prose_rule()))],
+        );
+        let mut input = vec![
+            ("type", "search"),
+            ("id", self.id.as_str()),
+            ("name", "q"),
+            ("autocomplete", "off"),
+            ("aria-controls", results_id.as_str()),
+            ("data-search-index", self.index.as_str()),
+            ("data-search-limit", limit.as_str()),
+        ];
+        if let Some(placeholder) = &self.placeholder {
+            input.push(("placeholder", placeholder));
+        }
+        let mut childs = vec![
+            Element::Node(label),
+            Element::Node(void_element("input", token_attrs(&input))),
+        ];
+
+        let mut form = vec![("method", "get"), ("role", "search")];
+        let (action, query) = match &self.fallback {
+            Some(url) => url.split_once('?').unwrap_or((url.as_str(), "")),
+            None => ("", ""),
+        };
+        if !action.is_empty() {
+            form.push(("action", action));
+        }
+        for (name, value) in query.split('&').filter_map(|pair| pair.split_once('=')) {
+            let hidden = token_attrs(&[("type", "hidden"), ("name", name), ("value", value)]);
+            childs.push(Element::Node(void_element("input", hidden)));
+        }
+
+        let results = with_tokens(
+            class_attrs(&["search-results"]),
+            &[("id", &results_id), ("aria-live", "polite")],
+        )
+        .with_added(AttrKey::from_str("hidden"), AttrValues::Bool(true));
+        childs.push(Element::Node(element("ul", results, vec![])));
+
+        element("form", with_tokens(class_attrs(&["search-box"]), &form), childs)
+    }
+}
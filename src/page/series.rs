@@ -0,0 +1,185 @@
+//! # series.rs - SeriesNav
+//!
+//! ## 목적
+//! 연재(여러 편으로 나눈 글)의 현재 위치("Part 3 of 5")와 이전/다음 편 링크를 렌더링하는 Block입니다.
+//! 연재 소속은 각 페이지의 메타데이터(`Series`, 머리말 `series`)에만 선언하고, 순서와 목록은
+//! `CollectionBuilder`가 만든 컬렉션(`SiteIndex::pages_in_series`)에서 읽습니다.
+//!
+//! ## 사용 예시
+//! ```text
+//! ---
+//! title: 소유권
+//! series: Rust 입문
+//! series_part: 3
+//! ---
+//! ```
+//! ```rust
+//! // 공유 Layout에 한 번
+//! SeriesNav::new();
+//! SeriesNav::new().list(true);  // 연재 전체 목차도 표시
+//! ```
+//!
+//! ## 렌더링 결과 (5편 중 3편)
+//! ```html
+//! <nav aria-label="Series" class="series-nav">
+//!   <p class="series-title"><strong>Rust 입문</strong> Part 3 of 5</p>
+//!   <ol class="series-parts">  <!-- list(true)일 때 -->
+//!     <li><a href="/rust/1.html">설치</a></li>
+//!     ...
+//!     <li><a aria-current="page" href="/rust/3.html">소유권</a></li>
+//!   </ol>
+//!   <ul>
+//!     <li><a href="/rust/2.html" rel="prev"><span class="series-nav-label">Previous</span> 변수</a></li>
+//!     <li><a href="/rust/4.html" rel="next"><span class="series-nav-label">Next</span> 트레이트</a></li>
+//!   </ul>
+//! </nav>
+//! ```
+//!
+//! ## 구현 상태
+//! - [x] 현재 위치, 이전/다음 편 링크 (`rel="prev"/"next"`)
+//! - [x] 연재 전체 목차 (`list`)
+//! - [x] 머리말 `series`, `series_part`
+//! - [ ] TODO: 연재 소개 페이지 링크, 라벨 다국어화
+//!
+//! ## 설계 결정
+//! - **연재가 아니면 숨긴 빈 nav**: 공유 Layout에 두어도 되도록 연재에 속하지 않은 페이지에서는
+//!   오류 대신 항목 없는 `<nav class="series-nav" hidden>`을 렌더링합니다 (랜드마크로 노출되지 않음).
+//! - **"몇 편"은 실제 위치**: `series_part`는 정렬에만 쓰므로 중간 편이 빠져도 번호가 이어집니다.
+//! - **초안 제외**: 컬렉션은 출력 대상 페이지만 담으므로 발행되지 않은 편은 목록과 링크에서 빠집니다.
+
+use crate::block::block::{Block, class_attrs, element, prose_rule, token_attrs, with_tokens};
+use crate::block::context::RenderContext;
+use crate::cite::analysis::normalize_url;
+use crate::cite::index::PageEntry;
+use crate::html::attributes::{AttrValues, SharedAttrs};
+use crate::html::node::{Element, IRNode};
+use crate::html::trust::{AttrKey, Content, SafeString};
+use crate::metadata::types::{CurrentUrl, Series};
+
+/// 연재 이동 라벨. `part`의 `{part}`, `{total}`은 현재 위치와 전체 편 수로 바뀝니다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SeriesLabels {
+    pub nav: String,
+    pub part: String,
+    pub prev: String,
+    pub next: String,
+}
+
+impl Default for SeriesLabels {
+    fn default() -> Self {
+        SeriesLabels {
+            nav: "Series".to_string(),
+            part: "Part {part} of {total}".to_string(),
+            prev: "Previous".to_string(),
+            next: "Next".to_string(),
+        }
+    }
+}
+
+/// 연재 이동 Block.
+#[derive(Debug, Clone, Default)]
+pub struct SeriesNav {
+    list: bool,
+    labels: SeriesLabels,
+}
+
+impl SeriesNav {
+    pub fn new() -> Self {
+        SeriesNav::default()
+    }
+
+    /// 연재 전체 목차 표시 여부. 기본: false
+    pub fn list(mut self, show: bool) -> Self {
+        self.list = show;
+        self
+    }
+
+    pub fn labels(mut self, labels: SeriesLabels) -> Self {
+        self.labels = labels;
+        self
+    }
+
+    fn text(text: &str) -> Element {
+        Element::Text(Content::from_str(text, &prose_rule()))
+    }
+
+    fn title(page: &PageEntry) -> &str {
+        page.title.as_deref().unwrap_or(&page.url)
+    }
+
+    fn step(page: &PageEntry, label: &str, rel: &str) -> Element {
+        let label = element("span", class_attrs(&["series-nav-label"]), vec![Self::text(label)]);
+        let link = element(
+            "a",
+            token_attrs(&[("href", &page.url), ("rel", rel)]),
+            vec![
+                Element::Node(label),
+                Self::text(&format!(" {}", Self::title(page))),
+            ],
+        );
+        Element::Node(element("li", SharedAttrs::new(), vec![Element::Node(link)]))
+    }
+}
+
+impl Block for SeriesNav {
+    fn render_to_ir(&self, ctx: &RenderContext) -> IRNode {
+        let attrs = with_tokens(class_attrs(&["series-nav"]), &[("aria-label", &self.labels.nav)]);
+        let empty = element(
+            "nav",
+            class_attrs(&["series-nav"]).with_added(AttrKey::from_str("hidden"), AttrValues::Bool(true)),
+            vec![],
+        );
+        let (Some(series), Some(url), Some(index)) = (
+            ctx.get::<Series>(),
+            ctx.get::<CurrentUrl>().map(|u| normalize_url(&u.0)),
+            ctx.index(),
+        ) else {
+            return empty;
+        };
+        let parts = index.pages_in_series(&series.name);
+        let Some(current) = parts.iter().position(|p| normalize_url(&p.url) == url) else {
+            return empty;
+        };
+
+        let part = self
+            .labels
+            .part
+            .replace("{part}", &(current + 1).to_string())
+            .replace("{total}", &parts.len().to_string());
+        let name = element("strong", SharedAttrs::new(), vec![Self::text(&series.name)]);
+        let mut childs = vec![Element::Node(element(
+            "p",
+            class_attrs(&["series-title"]),
+            vec![Element::Node(name), Self::text(&format!(" {}", part))],
+        ))];
+
+        if self.list {
+            let items = parts
+                .iter()
+                .enumerate()
+                .map(|(i, page)| {
+                    let mut attrs = vec![("href", page.url.as_str())];
+                    if i == current {
+                        attrs.push(("aria-current", "page"));
+                    }
+                    let link = element("a", token_attrs(&attrs), vec![Self::text(Self::title(page))]);
+                    Element::Node(element("li", SharedAttrs::new(), vec![Element::Node(link)]))
+                })
+                .collect();
+            childs.push(Element::Node(element("ol", class_attrs(&["series-parts"]), items)));
+        }
+
+        let mut steps = Vec::new();
+        if let Some(prev) = current.checked_sub(1).map(|i| parts[i]) {
+            steps.push(Self::step(prev, &self.labels.prev, "prev"));
+        }
+        if let Some(next) = parts.get(current + 1) {
+            steps.push(Self::step(next, &self.labels.next, "next"));
+        }
+        if !steps.is_empty() {
+            childs.push(Element::Node(element("ul", SharedAttrs::new(), steps)));
+        }
+
+        element("nav", attrs, childs)
+    }
+}
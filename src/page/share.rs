@@ -0,0 +1,209 @@
+//! # share.rs - ShareButtons
+//!
+//! ## 목적
+//! 현재 페이지의 공유 링크(메일, X, Mastodon, LinkedIn, 링크 복사)를 렌더링하는 Block입니다.
+//! 주소와 제목은 렌더링 시점에 `ctx`에서 읽으므로(`SiteConfig` + `CurrentUrl`, `Title`) 같은 Block을
+//! 공유 Layout에 한 번만 두면 됩니다. 외부 스크립트 없이 각 서비스의 공유 URL만 씁니다.
+//!
+//! ## 렌더링 결과 (`/blog/hello.html`, 제목 "Hello")
+//! ```html
+//! <nav aria-label="Share" class="share-buttons">
+//!   <ul>
+//!     <li><a class="share-email" href="mailto:?subject=Hello&amp;body=https%3A%2F%2Fblog.example.com%2Fblog%2Fhello.html">Email</a></li>
+//!     <li><a class="share-x" href="https://x.com/intent/tweet?text=Hello&amp;url=https%3A%2F%2F..." rel="noopener" target="_blank">X</a></li>
+//!     <li><a class="share-mastodon" href="https://mastodon.social/share?text=Hello%20https%3A%2F%2F..." rel="noopener" target="_blank">Mastodon</a></li>
+//!     <li><a class="share-linkedin" href="https://www.linkedin.com/sharing/share-offsite/?url=https%3A%2F%2F..." rel="noopener" target="_blank">LinkedIn</a></li>
+//!     <li><button class="share-copy" data-url="https://blog.example.com/blog/hello.html" hidden
+//!                 onclick="navigator.clipboard.writeText(this.dataset.url)" type="button">Copy link</button></li>
+//!   </ul>
+//!   <script>...</script>
+//! </nav>
+//! ```
+//!
+//! ## 사용 예시
+//! ```rust
+//! ShareButtons::new();  // 모든 대상
+//! ShareButtons::new()
+//!     .targets(&[ShareTarget::Mastodon, ShareTarget::CopyLink])
+//!     .mastodon_instance("hachyderm.io")
+//!     .label("이 글 공유하기");
+//! ```
+//!
+//! ## 구현 상태
+//! - [x] 메일, X, Mastodon, LinkedIn, 링크 복사
+//! - [x] 사이트 기본 URL 기준 절대 주소, 페이지 제목
+//! - [ ] TODO: 라벨 다국어화
+//!
+//! ## 설계 결정
+//! - **외부 스크립트 없음**: 공유 위젯 스크립트는 방문자를 추적하므로 링크만 씁니다.
+//! - **Mastodon 인스턴스는 지정**: 서버마다 주소가 달라 공통 공유 URL이 없습니다. 기본은 `mastodon.social`.
+//! - **복사 버튼은 점진적 향상**: 클립보드 API가 있을 때만 보이도록 `hidden`으로 렌더링하고,
+//!   짧은 인라인 스크립트가 켭니다. 스크립트가 없으면 나머지 링크만 보입니다.
+
+use crate::block::block::{Block, class_attrs, element, prose_rule, with_tokens};
+use crate::block::context::RenderContext;
+use crate::cite::config::SiteConfig;
+use crate::html::attributes::{AttrValues, SharedAttrs};
+use crate::html::elements::Script;
+use crate::html::node::{Element, IRNode, Node};
+use crate::html::trust::{AttrKey, Content, SafeString, encode_url_component};
+use crate::metadata::types::{CurrentUrl, Title};
+
+/// 복사 버튼을 클립보드 API가 있을 때만 보이게 하는 스크립트.
+const COPY_SCRIPT: &str = "if(navigator.clipboard){for(const b of document.querySelectorAll('.share-copy'))b.hidden=false}";
+
+/// 공유 대상.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ShareTarget {
+    Email,
+    X,
+    Mastodon,
+    LinkedIn,
+    CopyLink,
+}
+
+impl ShareTarget {
+    /// 전체 대상 (기본 순서).
+    pub const ALL: [ShareTarget; 5] = [
+        ShareTarget::Email,
+        ShareTarget::X,
+        ShareTarget::Mastodon,
+        ShareTarget::LinkedIn,
+        ShareTarget::CopyLink,
+    ];
+
+    fn class(&self) -> &'static str {
+        match self {
+            ShareTarget::Email => "share-email",
+            ShareTarget::X => "share-x",
+            ShareTarget::Mastodon => "share-mastodon",
+            ShareTarget::LinkedIn => "share-linkedin",
+            ShareTarget::CopyLink => "share-copy",
+        }
+    }
+
+    fn label(&self) -> &'static str {
+        match self {
+            ShareTarget::Email => "Email",
+            ShareTarget::X => "X",
+            ShareTarget::Mastodon => "Mastodon",
+            ShareTarget::LinkedIn => "LinkedIn",
+            ShareTarget::CopyLink => "Copy link",
+        }
+    }
+}
+
+/// 공유 버튼 Block.
+#[derive(Debug, Clone)]
+pub struct ShareButtons {
+    targets: Vec<ShareTarget>,
+    mastodon_instance: String,
+    label: String,
+}
+
+impl Default for ShareButtons {
+    fn default() -> Self {
+        ShareButtons {
+            targets: ShareTarget::ALL.to_vec(),
+            mastodon_instance: "mastodon.social".to_string(),
+            label: "Share".to_string(),
+        }
+    }
+}
+
+impl ShareButtons {
+    pub fn new() -> Self {
+        ShareButtons::default()
+    }
+
+    /// 보여 줄 대상과 순서.
+    pub fn targets(mut self, targets: &[ShareTarget]) -> Self {
+        self.targets = targets.to_vec();
+        self
+    }
+
+    /// Mastodon 서버 호스트. 예: `hachyderm.io`
+    pub fn mastodon_instance(mut self, host: &str) -> Self {
+        let host = host.split_once("://").map(|(_, rest)| rest).unwrap_or(host);
+        self.mastodon_instance = host.trim_end_matches('/').to_string();
+        self
+    }
+
+    /// `nav`의 `aria-label`. 기본: `Share`
+    pub fn label(mut self, label: &str) -> Self {
+        self.label = label.to_string();
+        self
+    }
+
+    /// 대상별 공유 URL. 링크 복사는 페이지 주소 그대로입니다.
+    pub fn share_url(&self, target: ShareTarget, url: &str, title: &str) -> String {
+        let (u, t) = (encode_url_component(url), encode_url_component(title));
+        match target {
+            ShareTarget::Email => format!("mailto:?subject={}&body={}", t, u),
+            ShareTarget::X => format!("https://x.com/intent/tweet?text={}&url={}", t, u),
+            ShareTarget::Mastodon => format!(
+                "https://{}/share?text={}",
+                self.mastodon_instance,
+                encode_url_component(format!("{} {}", title, url).trim_start())
+            ),
+            ShareTarget::LinkedIn => format!("https://www.linkedin.com/sharing/share-offsite/?url={}", u),
+            ShareTarget::CopyLink => url.to_string(),
+        }
+    }
+}
+
+impl Block for ShareButtons {
+    fn render_to_ir(&self, ctx: &RenderContext) -> IRNode {
+        let path = ctx.get::<CurrentUrl>().map(|u| u.0.as_str()).unwrap_or("/");
+        let url = match ctx.get::<SiteConfig>() {
+            Some(config) => config.absolute_url(path),
+            None => path.to_string(),
+        };
+        let title = ctx.get::<Title>().map(|t| t.0.as_str()).unwrap_or_default();
+
+        let rule = prose_rule();
+        let items = self
+            .targets
+            .iter()
+            .map(|target| {
+                let href = self.share_url(*target, &url, title);
+                let text = vec![Element::Text(Content::from_str(target.label(), &rule))];
+                let control = match target {
+                    ShareTarget::CopyLink => {
+                        let attrs = with_tokens(
+                            class_attrs(&[target.class()]),
+                            &[
+                                ("type", "button"),
+                                ("data-url", &href),
+                                ("onclick", "navigator.clipboard.writeText(this.dataset.url)"),
+                            ],
+                        )
+                        .with_added(AttrKey::from_str("hidden"), AttrValues::Bool(true));
+                        element("button", attrs, text)
+                    }
+                    ShareTarget::Email => {
+                        element("a", with_tokens(class_attrs(&[target.class()]), &[("href", &href)]), text)
+                    }
+                    _ => {
+                        let attrs = with_tokens(
+                            class_attrs(&[target.class()]),
+                            &[("href", &href), ("target", "_blank"), ("rel", "noopener")],
+                        );
+                        element("a", attrs, text)
+                    }
+                };
+                Element::Node(element("li", SharedAttrs::new(), vec![Element::Node(control)]))
+            })
+            .collect();
+
+        let mut childs = vec![Element::Node(element("ul", SharedAttrs::new(), items))];
+        if self.targets.contains(&ShareTarget::CopyLink) {
+            childs.push(Element::Node(Script::inline(COPY_SCRIPT).to_irnode()));
+        }
+        element(
+            "nav",
+            with_tokens(class_attrs(&["share-buttons"]), &[("aria-label", &self.label)]),
+            childs,
+        )
+    }
+}
@@ -0,0 +1,132 @@
+//! # simple.rs - SimplePage
+//!
+//! ## 목적
+//! 제목과 Block 목록만으로 구성된 Page 참조 구현입니다.
+//! 직접 Page를 구현하기 전, 또는 `ContentLoader` 템플릿으로 바로 사용할 수 있습니다.
+//!
+//! ## 렌더링 결과
+//! ```html
+//! <div class="page">
+//!   <h1>제목</h1>
+//!   <!-- Block들 -->
+//! </div>
+//! ```
+//!
+//! ## 사용 예시
+//! ```rust
+//! let page = SimplePage::new("About", "about.html")
+//!     .add_block(Box::new(Paragraph::new("안녕하세요.")));
+//!
+//! let pages = ContentLoader::new("content")
+//!     .load(|file| SimplePage::from_content(file).with_layout(chrome.clone()))?;
+//! ```
+
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use crate::block::block::{Block, class_attrs, element, render_block};
+use crate::block::context::RenderContext;
+use crate::block::prose::Heading;
+use crate::html::node::{Element, IRNode};
+use crate::metadata::builder::Metadata;
+use crate::metadata::types::Title;
+use crate::page::head::HeadElements;
+use crate::page::layout::Layout;
+use crate::page::loader::ContentFile;
+use crate::page::page::Page;
+
+/// 제목 + Block 목록으로 구성된 기본 Page.
+pub struct SimplePage {
+    title: String,
+    content: Vec<Box<dyn Block>>,
+    metadata: Metadata,
+    output_path: PathBuf,
+    template: Option<Arc<dyn Layout>>,
+}
+
+impl SimplePage {
+    pub fn new<P: AsRef<Path>>(title: &str, output_path: P) -> Self {
+        SimplePage {
+            title: title.to_string(),
+            content: Vec::new(),
+            metadata: Metadata::new(),
+            output_path: output_path.as_ref().to_path_buf(),
+            template: None,
+        }
+    }
+
+    pub fn add_block(mut self, block: Box<dyn Block>) -> Self {
+        self.content.push(block);
+        self
+    }
+
+    /// 페이지 메타데이터 지정. 제목은 항상 `title`로 덮어씁니다.
+    pub fn with_metadata(mut self, metadata: Metadata) -> Self {
+        self.metadata = metadata;
+        self
+    }
+
+    /// ContentFile로부터 생성. 제목은 머리말 `title`, 없으면 slug.
+    /// 경로는 `<slug>.html`.
+    pub fn from_content(file: ContentFile) -> Self {
+        let title = file
+            .metadata
+            .get::<Title>()
+            .map(|t| t.0.clone())
+            .unwrap_or_else(|| file.slug.clone());
+
+        SimplePage {
+            title,
+            content: file.blocks,
+            metadata: file.metadata,
+            output_path: PathBuf::from(format!("{}.html", file.slug)),
+            template: None,
+        }
+    }
+
+    /// 공유 Layout 지정.
+    pub fn with_layout(mut self, layout: Arc<dyn Layout>) -> Self {
+        self.template = Some(layout);
+        self
+    }
+
+    pub fn get_title(&self) -> &str {
+        &self.title
+    }
+}
+
+impl Page for SimplePage {
+    fn layout(&self, ctx: &RenderContext) -> IRNode {
+        let mut childs = vec![Element::Node(
+            Heading::text(1, &self.title).render_to_ir(ctx),
+        )];
+        childs.extend(
+            self.content
+                .iter()
+                .map(|block| Element::Node(render_block(block.as_ref(), ctx))),
+        );
+        element("div", class_attrs(&["page"]), childs)
+    }
+
+    fn metadata(&self) -> Metadata {
+        self.metadata.clone().title(&self.title)
+    }
+
+    fn path(&self) -> PathBuf {
+        self.output_path.clone()
+    }
+
+    fn head(&self, _ctx: &RenderContext) -> HeadElements {
+        HeadElements::new().title(&self.title)
+    }
+
+    fn layout_template(&self) -> Option<Arc<dyn Layout>> {
+        self.template.clone()
+    }
+
+    fn blocks(&self) -> Vec<&dyn Block> {
+        self.content.iter().map(|b| b.as_ref()).collect()
+    }
+}
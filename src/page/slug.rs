@@ -0,0 +1,290 @@
+//! # slug.rs - Slug와 출력 경로
+//!
+//! ## 목적
+//! 제목에서 URL에 쓸 수 있는 깔끔한 slug를 만들고, 출력 경로를 결정합니다.
+//!
+//! ## 사용 예시
+//! ```rust
+//! Slug::new("Hello, World!")          // "hello-world"
+//! Slug::new("Rust로 만드는 SSG")       // "rust로-만드는-ssg" (한글 유지)
+//! Slug::ascii("Rust로 만드는 SSG")     // "rustro-mandeuneun-ssg" (로마자 표기)
+//! Slug::ascii("Crème brûlée")        // "creme-brulee"
+//!
+//! let slug = Slug::new("My Post");
+//! slug.output_path("blog", false)    // blog/my-post.html
+//! slug.output_path("blog", true)     // blog/my-post/index.html (pretty URL)
+//! slug.url_path("blog", true)        // /blog/my-post/
+//!
+//! // 한 문서 안의 제목 anchor (겹치면 등장 순서대로 -1, -2)
+//! let mut slugger = Slugger::new(SlugStyle::Unicode);
+//! slugger.slug("개요")      // "개요"
+//! slugger.slug("개요")      // "개요-1"
+//! slugger.slug("!!!")       // "section" (비면 대체 이름)
+//! ```
+//!
+//! ## 규칙
+//! - 소문자화, 공백/구두점 → `-`, 연속 `-` 축약, 앞뒤 `-` 제거, 아포스트로피 제거
+//! - `new`: 유니코드 문자/숫자 유지 (한글 URL은 브라우저가 그대로 표시)
+//! - `ascii`: 라틴 확장 문자는 기본 라틴으로, 한글은 국어의 로마자 표기법(음절 단위)으로 변환,
+//!   그 외 문자는 제거
+//!
+//! ## 구현 상태
+//! - [x] 유니코드 유지 slug, ASCII 음역 slug
+//! - [x] 한글 로마자 표기 (음절 단위, 음운 변화 미적용)
+//! - [x] pretty URL 경로 변환 (`pretty_path`)
+//! - [x] 출력 경로 → URL 경로 (`url_for_path`)
+//! - [x] 문서 단위 anchor 생성 (`Slugger`: 이미 있는 id 예약, 결정적인 충돌 접미사)
+//! - [ ] TODO: 음운 변화 규칙 (신라 → silla)
+
+use std::collections::BTreeSet;
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+
+/// URL에 쓸 수 있는 정규화된 경로 조각.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Slug(String);
+
+impl Slug {
+    /// 유니코드 문자/숫자를 유지하는 slug.
+    pub fn new(text: &str) -> Self {
+        Slug(hyphenate(lowercase_chars(text).map(|c| {
+            if c.is_alphanumeric() {
+                Some(c.to_string())
+            } else {
+                None
+            }
+        })))
+    }
+
+    /// ASCII만 사용하는 slug (라틴 음역, 한글 로마자 표기).
+    pub fn ascii(text: &str) -> Self {
+        Slug(hyphenate(
+            lowercase_chars(text)
+                .map(transliterate)
+                .filter(|t| t.as_deref() != Some("")),
+        ))
+    }
+
+    /// 이미 정규화된 문자열로 생성 (검사 없음).
+    pub fn from_raw(slug: &str) -> Self {
+        Slug(slug.to_string())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// 출력 파일 경로. `pretty`면 `dir/slug/index.html`, 아니면 `dir/slug.html`.
+    pub fn output_path(&self, dir: &str, pretty: bool) -> PathBuf {
+        let base = Path::new(dir.trim_matches('/'));
+        if pretty {
+            base.join(&self.0).join("index.html")
+        } else {
+            base.join(format!("{}.html", self.0))
+        }
+    }
+
+    /// 사이트 루트 기준 URL 경로. `pretty`면 `/dir/slug/`, 아니면 `/dir/slug.html`.
+    pub fn url_path(&self, dir: &str, pretty: bool) -> String {
+        let dir = dir.trim_matches('/');
+        let prefix = if dir.is_empty() {
+            String::from("/")
+        } else {
+            format!("/{}/", dir)
+        };
+        if pretty {
+            format!("{}{}/", prefix, self.0)
+        } else {
+            format!("{}{}.html", prefix, self.0)
+        }
+    }
+}
+
+impl Display for Slug {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// `blog/my-post.html` → `blog/my-post/index.html`.
+/// 이미 `index.html`인 경로, `404.html`/`50x.html` 같은 오류 페이지, `.html`이 아닌 파일은 그대로 둡니다.
+pub fn pretty_path(path: &Path) -> PathBuf {
+    let is_html = path.extension().is_some_and(|e| e == "html");
+    let stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or("");
+    if !is_html || stem == "index" || is_error_page_path(path) {
+        return path.to_path_buf();
+    }
+    path.with_extension("").join("index.html")
+}
+
+/// `404.html`, `50x.html`처럼 상태 코드 이름을 가진 오류 페이지 경로인지.
+pub(crate) fn is_error_page_path(path: &Path) -> bool {
+    let is_html = path.extension().is_some_and(|e| e == "html");
+    let stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or("");
+    is_html
+        && stem.len() == 3
+        && stem.starts_with(|c: char| c.is_ascii_digit())
+        && stem.chars().all(|c| c.is_ascii_digit() || c == 'x')
+}
+
+/// 출력 경로를 사이트 루트 기준 URL 경로로 변환.
+/// `blog/post.html` → `/blog/post.html`, `blog/index.html` → `/blog/`, `index.html` → `/`
+pub fn url_for_path(path: &Path) -> String {
+    let parts: Vec<String> = path
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy().into_owned())
+        .collect();
+    let url = format!("/{}", parts.join("/"));
+    match url.strip_suffix("index.html") {
+        Some(dir) if dir.ends_with('/') => dir.to_string(),
+        _ => url,
+    }
+}
+
+/// slug에 쓸 문자.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum SlugStyle {
+    /// 유니코드 문자/숫자 유지 (`Slug::new`)
+    #[default]
+    Unicode,
+    /// ASCII만 (`Slug::ascii`). 음역할 수 없는 문자(한자, 가나 등)는 빠집니다
+    Ascii,
+}
+
+impl SlugStyle {
+    pub fn slug(&self, text: &str) -> Slug {
+        match self {
+            SlugStyle::Unicode => Slug::new(text),
+            SlugStyle::Ascii => Slug::ascii(text),
+        }
+    }
+}
+
+/// 한 문서 안에서 겹치지 않는 anchor를 만드는 생성기.
+/// 같은 slug가 다시 나오면 `-1`, `-2`를 붙이므로 같은 입력 순서면 항상 같은 결과입니다.
+#[derive(Debug, Clone, Default)]
+pub struct Slugger {
+    style: SlugStyle,
+    used: BTreeSet<String>,
+}
+
+impl Slugger {
+    /// slug가 비었을 때 쓰는 이름 (기호만 있는 제목, ASCII에서 모두 빠진 제목).
+    pub const FALLBACK: &'static str = "section";
+
+    pub fn new(style: SlugStyle) -> Self {
+        Slugger {
+            style,
+            used: BTreeSet::new(),
+        }
+    }
+
+    /// 이미 문서에 있는 id. 새 anchor가 이 값을 피합니다.
+    pub fn reserve(&mut self, id: &str) {
+        self.used.insert(id.to_string());
+    }
+
+    /// 텍스트의 anchor. 앞에서 만든(또는 예약한) anchor와 겹치지 않습니다.
+    pub fn slug(&mut self, text: &str) -> String {
+        let slug = self.style.slug(text);
+        let base = match slug.as_str() {
+            "" => Self::FALLBACK,
+            slug => slug,
+        };
+        let anchor = match self.used.contains(base) {
+            false => base.to_string(),
+            true => (1..)
+                .map(|n| format!("{}-{}", base, n))
+                .find(|candidate| !self.used.contains(candidate))
+                .expect("unbounded suffixes"),
+        };
+        self.used.insert(anchor.clone());
+        anchor
+    }
+}
+
+/// 소문자화한 문자열. 아포스트로피는 단어를 나누지 않도록 먼저 제거 (`don't` → `dont`).
+fn lowercase_chars(text: &str) -> impl Iterator<Item = char> + '_ {
+    text.chars()
+        .filter(|c| !matches!(c, '\'' | '’'))
+        .flat_map(|c| c.to_lowercase())
+}
+
+/// 조각들을 `-`로 연결. `None`은 구분자로 취급하고 연속 구분자는 하나로 축약.
+fn hyphenate(parts: impl Iterator<Item = Option<String>>) -> String {
+    let mut slug = String::new();
+    let mut pending_hyphen = false;
+    for part in parts {
+        match part {
+            Some(text) => {
+                if pending_hyphen && !slug.is_empty() {
+                    slug.push('-');
+                }
+                pending_hyphen = false;
+                slug.push_str(&text);
+            }
+            None => pending_hyphen = true,
+        }
+    }
+    slug
+}
+
+/// 소문자 한 글자의 ASCII 표기. 구분자면 `None`, 표기할 수 없는 문자는 `Some("")` (제거).
+fn transliterate(c: char) -> Option<String> {
+    if c.is_ascii_alphanumeric() {
+        return Some(c.to_string());
+    }
+    if let Some(romanized) = romanize_hangul(c) {
+        return Some(romanized);
+    }
+    let latin = match c {
+        'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' | 'ā' | 'ă' | 'ą' => "a",
+        'æ' => "ae",
+        'ç' | 'ć' | 'č' => "c",
+        'ď' | 'đ' | 'ð' => "d",
+        'è' | 'é' | 'ê' | 'ë' | 'ē' | 'ė' | 'ę' | 'ě' => "e",
+        'ğ' => "g",
+        'ì' | 'í' | 'î' | 'ï' | 'ī' | 'į' | 'ı' => "i",
+        'ł' | 'ľ' => "l",
+        'ñ' | 'ń' | 'ň' => "n",
+        'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' | 'ō' | 'ő' => "o",
+        'œ' => "oe",
+        'ř' => "r",
+        'ß' => "ss",
+        'ś' | 'š' | 'ş' => "s",
+        'ť' | 'ţ' => "t",
+        'þ' => "th",
+        'ù' | 'ú' | 'û' | 'ü' | 'ū' | 'ů' | 'ű' | 'ų' => "u",
+        'ý' | 'ÿ' => "y",
+        'ź' | 'ż' | 'ž' => "z",
+        _ if c.is_alphanumeric() => "",
+        _ => return None,
+    };
+    Some(latin.to_string())
+}
+
+const INITIALS: [&str; 19] = [
+    "g", "kk", "n", "d", "tt", "r", "m", "b", "pp", "s", "ss", "", "j", "jj", "ch", "k", "t", "p",
+    "h",
+];
+const MEDIALS: [&str; 21] = [
+    "a", "ae", "ya", "yae", "eo", "e", "yeo", "ye", "o", "wa", "wae", "oe", "yo", "u", "wo", "we",
+    "wi", "yu", "eu", "ui", "i",
+];
+const FINALS: [&str; 28] = [
+    "", "k", "k", "k", "n", "n", "n", "t", "l", "k", "m", "l", "l", "l", "p", "l", "m", "p", "p",
+    "t", "t", "ng", "t", "t", "k", "t", "p", "t",
+];
+
+/// 한글 완성형 음절의 로마자 표기 (국어의 로마자 표기법, 음절 단위).
+fn romanize_hangul(c: char) -> Option<String> {
+    let code = c as u32;
+    if !(0xAC00..=0xD7A3).contains(&code) {
+        return None;
+    }
+    let index = (code - 0xAC00) as usize;
+    let initial = index / (21 * 28);
+    let medial = (index % (21 * 28)) / 28;
+    let last = index % 28;
+    Some(format!("{}{}{}", INITIALS[initial], MEDIALS[medial], FINALS[last]))
+}
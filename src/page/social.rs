@@ -0,0 +1,420 @@
+//! # social.rs - Open Graph / Twitter Card
+//!
+//! ## 목적
+//! 링크 공유 시 미리보기(제목, 요약, 이미지)가 올바르게 표시되도록
+//! `og:*`, `twitter:*` 메타태그를 타입으로 구성합니다.
+//!
+//! ## 사용 예시
+//! ```rust
+//! fn head(&self, ctx: &RenderContext) -> HeadElements {
+//!     HeadElements::new()
+//!         .title(&self.title)
+//!         .open_graph(
+//!             OpenGraph::new(&self.title)
+//!                 .og_type(OgType::Article)
+//!                 .url("https://example.com/blog/my-post.html")
+//!                 .image("https://example.com/img/cover.png")
+//!                 .locale("ko_KR"),
+//!         )
+//!         .twitter_card(
+//!             TwitterCard::new(TwitterCardType::SummaryLargeImage)
+//!                 .site("@quo")
+//!                 .image("https://example.com/img/cover.png"),
+//!         )
+//! }
+//! ```
+//!
+//! ## 렌더링 결과
+//! ```html
+//! <meta content="My Post" property="og:title" >
+//! <meta content="article" property="og:type" >
+//! <meta content="https://example.com/img/cover.png" property="og:image" >
+//! <meta content="summary_large_image" name="twitter:card" >
+//! ```
+//!
+//! ## 구현 상태
+//! - [x] OpenGraph (title, type, url, description, image, image:alt, site_name, locale)
+//! - [x] TwitterCard (card, site, creator, title, description, image, image:alt)
+//! - [x] 검증 (절대 URL 이미지, 로케일 형식, `@` 핸들, 큰 이미지 카드의 이미지 필수)
+//! - [x] 빌드 시 생성한 공유 이미지 자동 연결 (`SocialImage`, `OgImageGenerator`)
+//! - [ ] TODO: article:published_time 등 타입별 추가 속성
+//!
+//! ## 설계 결정
+//! - **타입은 enum**: `og:type`은 정해진 값만 허용되므로 enum으로 만들어 잘못된 값을
+//!   컴파일 타임에 막습니다.
+//! - **검증은 명시적 호출**: 빌더는 실패하지 않고, `validate()`로 오류를 확인합니다.
+//!   (HeadElements::validate()가 함께 검사합니다.)
+
+use std::fmt::Display;
+
+use crate::metadata::value::MetadataValue;
+use crate::page::head::MetaTag;
+
+// ============================================================================
+// 오류
+// ============================================================================
+
+/// 소셜 메타태그 검증 오류.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SocialMetaError {
+    /// 이미지 URL이 `http(s)://`로 시작하지 않음 (크롤러는 상대 경로를 해석하지 않음)
+    RelativeImageUrl(String),
+    /// 로케일이 `language_TERRITORY` 형식이 아님. 예: `ko_KR`
+    InvalidLocale(String),
+    /// Twitter 핸들이 `@`로 시작하지 않음
+    InvalidHandle(String),
+    /// 이미지가 필요한 카드에 이미지가 없음
+    MissingImage,
+}
+
+impl Display for SocialMetaError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SocialMetaError::RelativeImageUrl(url) => {
+                write!(f, "social image must be an absolute URL: {}", url)
+            }
+            SocialMetaError::InvalidLocale(locale) => {
+                write!(f, "invalid Open Graph locale (expected ll_TT): {}", locale)
+            }
+            SocialMetaError::InvalidHandle(handle) => {
+                write!(f, "Twitter handle must start with '@': {}", handle)
+            }
+            SocialMetaError::MissingImage => write!(f, "summary_large_image card requires an image"),
+        }
+    }
+}
+
+impl std::error::Error for SocialMetaError {}
+
+fn check_image_url(url: &str) -> Result<(), SocialMetaError> {
+    if url.starts_with("https://") || url.starts_with("http://") {
+        Ok(())
+    } else {
+        Err(SocialMetaError::RelativeImageUrl(url.to_string()))
+    }
+}
+
+fn check_locale(locale: &str) -> Result<(), SocialMetaError> {
+    let valid = match locale.split_once('_') {
+        Some((lang, territory)) => {
+            (2..=3).contains(&lang.len())
+                && lang.chars().all(|c| c.is_ascii_lowercase())
+                && territory.len() == 2
+                && territory.chars().all(|c| c.is_ascii_uppercase())
+        }
+        None => false,
+    };
+    if valid {
+        Ok(())
+    } else {
+        Err(SocialMetaError::InvalidLocale(locale.to_string()))
+    }
+}
+
+fn check_handle(handle: &str) -> Result<(), SocialMetaError> {
+    if handle.len() > 1 && handle.starts_with('@') {
+        Ok(())
+    } else {
+        Err(SocialMetaError::InvalidHandle(handle.to_string()))
+    }
+}
+
+fn property(property: &str, content: &str) -> MetaTag {
+    MetaTag::Property {
+        property: property.to_string(),
+        content: content.to_string(),
+    }
+}
+
+fn name(name: &str, content: &str) -> MetaTag {
+    MetaTag::Name {
+        name: name.to_string(),
+        content: content.to_string(),
+    }
+}
+
+// ============================================================================
+// Open Graph
+// ============================================================================
+
+/// `og:type` 값.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OgType {
+    #[default]
+    Website,
+    Article,
+    Book,
+    Profile,
+    VideoMovie,
+    VideoEpisode,
+    MusicSong,
+    MusicAlbum,
+}
+
+impl OgType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OgType::Website => "website",
+            OgType::Article => "article",
+            OgType::Book => "book",
+            OgType::Profile => "profile",
+            OgType::VideoMovie => "video.movie",
+            OgType::VideoEpisode => "video.episode",
+            OgType::MusicSong => "music.song",
+            OgType::MusicAlbum => "music.album",
+        }
+    }
+}
+
+/// Open Graph 메타데이터.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OpenGraph {
+    pub title: String,
+    pub og_type: OgType,
+    pub url: Option<String>,
+    pub description: Option<String>,
+    pub image: Option<String>,
+    pub image_alt: Option<String>,
+    pub site_name: Option<String>,
+    pub locale: Option<String>,
+}
+
+impl OpenGraph {
+    pub fn new(title: &str) -> Self {
+        OpenGraph {
+            title: title.to_string(),
+            og_type: OgType::default(),
+            url: None,
+            description: None,
+            image: None,
+            image_alt: None,
+            site_name: None,
+            locale: None,
+        }
+    }
+
+    pub fn og_type(mut self, og_type: OgType) -> Self {
+        self.og_type = og_type;
+        self
+    }
+
+    pub fn url(mut self, url: &str) -> Self {
+        self.url = Some(url.to_string());
+        self
+    }
+
+    pub fn description(mut self, description: &str) -> Self {
+        self.description = Some(description.to_string());
+        self
+    }
+
+    pub fn image(mut self, url: &str) -> Self {
+        self.image = Some(url.to_string());
+        self
+    }
+
+    pub fn image_alt(mut self, alt: &str) -> Self {
+        self.image_alt = Some(alt.to_string());
+        self
+    }
+
+    pub fn site_name(mut self, site_name: &str) -> Self {
+        self.site_name = Some(site_name.to_string());
+        self
+    }
+
+    /// 로케일. 예: `"ko_KR"`, `"en_US"`
+    pub fn locale(mut self, locale: &str) -> Self {
+        self.locale = Some(locale.to_string());
+        self
+    }
+
+    pub fn validate(&self) -> Result<(), SocialMetaError> {
+        if let Some(image) = &self.image {
+            check_image_url(image)?;
+        }
+        if let Some(locale) = &self.locale {
+            check_locale(locale)?;
+        }
+        Ok(())
+    }
+
+    /// `og:*` 메타태그 목록으로 펼침.
+    pub fn meta_tags(&self) -> Vec<MetaTag> {
+        let mut tags = vec![
+            property("og:title", &self.title),
+            property("og:type", self.og_type.as_str()),
+        ];
+        let optional = [
+            ("og:url", &self.url),
+            ("og:description", &self.description),
+            ("og:image", &self.image),
+            ("og:image:alt", &self.image_alt),
+            ("og:site_name", &self.site_name),
+            ("og:locale", &self.locale),
+        ];
+        tags.extend(
+            optional
+                .iter()
+                .filter_map(|(key, value)| value.as_ref().map(|v| property(key, v))),
+        );
+        tags
+    }
+}
+
+/// 페이지의 기본 공유 이미지. Site가 `OgImageGenerator`의 결과를 렌더링 시 주입합니다.
+/// head의 Open Graph에 이미지가 없을 때만 쓰이고, Open Graph가 없으면 페이지 제목으로 새로 만듭니다.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SocialImage {
+    /// 절대 URL
+    pub url: String,
+    /// `og:image:alt`
+    pub alt: Option<String>,
+}
+impl MetadataValue for SocialImage {}
+
+impl SocialImage {
+    pub fn new(url: &str) -> Self {
+        SocialImage {
+            url: url.to_string(),
+            alt: None,
+        }
+    }
+
+    pub fn alt(mut self, alt: &str) -> Self {
+        self.alt = Some(alt.to_string());
+        self
+    }
+
+    /// `og`에 이미지가 없으면 채움. 직접 지정한 이미지(와 대체 텍스트)는 그대로 둡니다.
+    pub fn apply(&self, og: OpenGraph) -> OpenGraph {
+        if og.image.is_some() {
+            return og;
+        }
+        let og = og.image(&self.url);
+        match (&og.image_alt, &self.alt) {
+            (None, Some(alt)) => og.image_alt(alt),
+            _ => og,
+        }
+    }
+}
+
+// ============================================================================
+// Twitter Card
+// ============================================================================
+
+/// `twitter:card` 값.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TwitterCardType {
+    #[default]
+    Summary,
+    SummaryLargeImage,
+    App,
+    Player,
+}
+
+impl TwitterCardType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TwitterCardType::Summary => "summary",
+            TwitterCardType::SummaryLargeImage => "summary_large_image",
+            TwitterCardType::App => "app",
+            TwitterCardType::Player => "player",
+        }
+    }
+}
+
+/// Twitter Card 메타데이터.
+/// title/description/image가 없으면 Twitter는 `og:*` 값을 사용합니다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TwitterCard {
+    pub card: TwitterCardType,
+    pub site: Option<String>,
+    pub creator: Option<String>,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub image: Option<String>,
+    pub image_alt: Option<String>,
+}
+
+impl TwitterCard {
+    pub fn new(card: TwitterCardType) -> Self {
+        TwitterCard {
+            card,
+            site: None,
+            creator: None,
+            title: None,
+            description: None,
+            image: None,
+            image_alt: None,
+        }
+    }
+
+    /// 사이트 계정. 예: `"@quo"`
+    pub fn site(mut self, handle: &str) -> Self {
+        self.site = Some(handle.to_string());
+        self
+    }
+
+    /// 작성자 계정. 예: `"@racakenon"`
+    pub fn creator(mut self, handle: &str) -> Self {
+        self.creator = Some(handle.to_string());
+        self
+    }
+
+    pub fn title(mut self, title: &str) -> Self {
+        self.title = Some(title.to_string());
+        self
+    }
+
+    pub fn description(mut self, description: &str) -> Self {
+        self.description = Some(description.to_string());
+        self
+    }
+
+    pub fn image(mut self, url: &str) -> Self {
+        self.image = Some(url.to_string());
+        self
+    }
+
+    pub fn image_alt(mut self, alt: &str) -> Self {
+        self.image_alt = Some(alt.to_string());
+        self
+    }
+
+    /// 검증. `og_image`는 함께 출력되는 Open Graph 이미지 (대체 이미지로 인정).
+    pub fn validate(&self, og_image: Option<&str>) -> Result<(), SocialMetaError> {
+        for handle in [&self.site, &self.creator].into_iter().flatten() {
+            check_handle(handle)?;
+        }
+        if let Some(image) = &self.image {
+            check_image_url(image)?;
+        }
+        if self.card == TwitterCardType::SummaryLargeImage
+            && self.image.is_none()
+            && og_image.is_none()
+        {
+            return Err(SocialMetaError::MissingImage);
+        }
+        Ok(())
+    }
+
+    /// `twitter:*` 메타태그 목록으로 펼침.
+    pub fn meta_tags(&self) -> Vec<MetaTag> {
+        let mut tags = vec![name("twitter:card", self.card.as_str())];
+        let optional = [
+            ("twitter:site", &self.site),
+            ("twitter:creator", &self.creator),
+            ("twitter:title", &self.title),
+            ("twitter:description", &self.description),
+            ("twitter:image", &self.image),
+            ("twitter:image:alt", &self.image_alt),
+        ];
+        tags.extend(
+            optional
+                .iter()
+                .filter_map(|(key, value)| value.as_ref().map(|v| name(key, v))),
+        );
+        tags
+    }
+}
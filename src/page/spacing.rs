@@ -0,0 +1,49 @@
+//! # spacing.rs - 레이아웃 간격
+//!
+//! ## 목적
+//! VBox, HBox 등 레이아웃 Block이 공유하는 간격 단위를 정의합니다.
+//!
+//! ## 설계 결정
+//! - **이름 있는 단계는 class로**: `Small/Medium/Large`는 `{prefix}-gap-{name}` class가 되어
+//!   스타일시트에서 일괄 조정할 수 있습니다.
+//! - **Custom은 인라인 style로**: 임의 CSS 길이는 class로 표현할 수 없으므로 `gap: ...`을 직접 씁니다.
+
+/// 자식 사이 간격.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Spacing {
+    None,
+    Small,
+    Medium,
+    Large,
+    /// 임의 CSS 길이. 예: `"2.5rem"`
+    Custom(String),
+}
+
+impl Spacing {
+    /// 이름 있는 단계의 class 접미사. Custom이면 `None`.
+    pub(crate) fn class_suffix(&self) -> Option<&'static str> {
+        match self {
+            Spacing::None => Some("none"),
+            Spacing::Small => Some("small"),
+            Spacing::Medium => Some("medium"),
+            Spacing::Large => Some("large"),
+            Spacing::Custom(_) => None,
+        }
+    }
+
+    /// 인라인 `gap` 선언. Custom일 때만 `Some`.
+    pub(crate) fn inline_style(&self) -> Option<String> {
+        match self {
+            Spacing::Custom(length) => Some(format!("gap: {}", length)),
+            _ => None,
+        }
+    }
+}
+
+/// 간격 단계의 CSS 값 (스타일시트 생성용).
+pub(crate) const SPACING_SCALE: &[(&str, &str)] = &[
+    ("none", "0"),
+    ("small", "0.5rem"),
+    ("medium", "1rem"),
+    ("large", "2rem"),
+];
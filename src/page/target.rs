@@ -0,0 +1,79 @@
+//! # target.rs - 대체 렌더링 대상
+//!
+//! ## 목적
+//! 한 Page를 화면용 문서 외에 인쇄용 등 대체 문서로도 출력합니다.
+//! 같은 Block 트리를 다시 렌더링하되 `RenderTarget`을 메타데이터로 주입하여
+//! Layout과 Block이 `ctx.target()`을 보고 출력을 조정하도록 합니다.
+//!
+//! ## 사용 예시
+//! ```rust
+//! impl Page for BlogPostPage {
+//!     fn alternate_targets(&self) -> Vec<RenderTarget> {
+//!         vec![RenderTarget::Print]
+//!     }
+//! }
+//!
+//! // Block에서 대상별 분기
+//! fn render_to_ir(&self, ctx: &RenderContext) -> IRNode {
+//!     if ctx.target() == RenderTarget::Print { /* 인쇄용 */ }
+//! }
+//! ```
+//!
+//! ## 출력
+//! ```text
+//! blog/post.html          ← 화면용 (<link rel="alternate" media="print" href="/blog/post.print.html">)
+//! blog/post.print.html    ← 인쇄용 (ChromeLayout의 header/footer 생략, canonical → 화면용, noindex)
+//! ```
+//!
+//! ## 설계 결정
+//! - **별도 파일**: `@media print` CSS만으로는 Block 구조를 바꿀 수 없으므로
+//!   대상별 문서를 따로 출력하고 화면용 문서에서 `rel="alternate"`로 연결합니다.
+
+use std::path::{Path, PathBuf};
+
+use crate::metadata::types::RenderTarget;
+use crate::page::head::{HeadElements, LinkTag};
+
+/// 대상별 출력 경로. `blog/post.html` → `blog/post.print.html`
+pub fn target_path(path: &Path, target: &RenderTarget) -> PathBuf {
+    let Some(suffix) = target.suffix() else {
+        return path.to_path_buf();
+    };
+    let stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or("index");
+    let file_name = match path.extension().and_then(|e| e.to_str()) {
+        Some(ext) => format!("{}.{}.{}", stem, suffix, ext),
+        None => format!("{}.{}", stem, suffix),
+    };
+    path.with_file_name(file_name)
+}
+
+/// 대상별 URL. `/blog/post.html` → `/blog/post.print.html`, `/blog/post/` → `/blog/post/index.print.html`
+pub fn target_url(url: &str, target: &RenderTarget) -> String {
+    let Some(suffix) = target.suffix() else {
+        return url.to_string();
+    };
+    if url.ends_with('/') {
+        return format!("{}index.{}.html", url, suffix);
+    }
+    match url.strip_suffix(".html") {
+        Some(stem) => format!("{}.{}.html", stem, suffix),
+        None => format!("{}.{}", url, suffix),
+    }
+}
+
+impl HeadElements {
+    /// 대체 문서를 가리키는 `<link rel="alternate">` 추가. 인쇄용은 `media="print"`.
+    pub fn alternate_targets(self, current_url: &str, targets: &[RenderTarget]) -> Self {
+        targets
+            .iter()
+            .filter(|target| target.suffix().is_some())
+            .fold(self, |head, target| {
+                let link = LinkTag::new("alternate", &target_url(current_url, target));
+                let link = match target {
+                    RenderTarget::Print => link.attr("media", "print"),
+                    _ => link,
+                };
+                head.link(link)
+            })
+    }
+}
@@ -0,0 +1,352 @@
+//! # theme.rs - Theme
+//!
+//! ## 목적
+//! 사이트 전역 시각 설정(색상, 글꼴, 간격 단계)을 타입으로 정의하고
+//! CSS 사용자 정의 속성(`--color-*`, `--font-*`, `--space-*`)으로 구성된 스타일시트를 생성합니다.
+//! 손으로 관리하는 CSS 대신 Rust 코드 한 곳에서 사이트 색상을 바꿀 수 있습니다.
+//!
+//! ## 사용 예시
+//! ```rust
+//! let theme = Theme::new()
+//!     .light(Palette::light().primary("#2563eb"))
+//!     .dark(Palette::dark().primary("#60a5fa"))
+//!     .fonts(Fonts::default().heading("\"Noto Serif KR\", serif"));
+//!
+//! // Site 메타데이터에 넣으면 빌드 시 theme.css를 기록하고 모든 페이지 head에 연결
+//! let site = Site::new().metadata(Metadata::new().custom(theme));
+//!
+//! // 헤더에 색상 모드 전환 버튼
+//! ChromeLayout::new().header(Box::new(ThemeToggle::new()))
+//! ```
+//!
+//! ## 생성 결과
+//! ```css
+//! :root{color-scheme:light;--color-background:#ffffff;...;--font-body:system-ui, sans-serif;--space-small:0.5rem;...}
+//! @media (prefers-color-scheme: dark){:root:not([data-theme="light"]){color-scheme:dark;--color-background:#0f172a;...}}
+//! :root[data-theme="dark"]{color-scheme:dark;--color-background:#0f172a;...}
+//! body{background:var(--color-background);color:var(--color-text);font-family:var(--font-body)}
+//! ```
+//!
+//! ## 구현 상태
+//! - [x] 색상 팔레트 (밝은/어두운), 글꼴, 간격 단계 → CSS 사용자 정의 속성
+//! - [x] 시스템 설정(`prefers-color-scheme`) 추종 + `data-theme` 수동 지정
+//! - [x] 전환 버튼 (`ThemeToggle`, 선택을 localStorage에 저장)
+//! - [x] Site 빌드 시 스타일시트 기록 및 head 연결
+//!
+//! ## 설계 결정
+//! - **어두운 팔레트는 선택**: 지정하지 않으면 밝은 팔레트만 생성하고 `color-scheme:light`로 고정합니다.
+//! - **수동 선택이 우선**: `<html data-theme="...">`가 있으면 시스템 설정보다 우선합니다.
+//!   전환 버튼은 이 속성만 바꾸므로 CSS는 JS 없이도 동작합니다.
+
+use std::{io, path::PathBuf};
+
+use crate::block::block::{Block, class_attrs, element, prose_rule, with_tokens};
+use crate::block::context::RenderContext;
+use crate::cite::vfs::SiteFs;
+use crate::html::elements::Script;
+use crate::html::node::{Element, IRNode, Node};
+use crate::html::trust::{Content, SafeString};
+use crate::metadata::types::Assets;
+use crate::metadata::value::MetadataValue;
+use crate::page::spacing::SPACING_SCALE;
+
+/// 색상 팔레트. 각 필드는 CSS 색상 값이며 `--color-{name}` 속성이 됩니다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Palette {
+    pub background: String,
+    pub surface: String,
+    pub text: String,
+    pub muted: String,
+    pub primary: String,
+    pub accent: String,
+    pub border: String,
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        Palette::light()
+    }
+}
+
+impl Palette {
+    /// 기본 밝은 팔레트.
+    pub fn light() -> Self {
+        Palette {
+            background: "#ffffff".to_string(),
+            surface: "#f8fafc".to_string(),
+            text: "#0f172a".to_string(),
+            muted: "#64748b".to_string(),
+            primary: "#2563eb".to_string(),
+            accent: "#db2777".to_string(),
+            border: "#e2e8f0".to_string(),
+        }
+    }
+
+    /// 기본 어두운 팔레트.
+    pub fn dark() -> Self {
+        Palette {
+            background: "#0f172a".to_string(),
+            surface: "#1e293b".to_string(),
+            text: "#e2e8f0".to_string(),
+            muted: "#94a3b8".to_string(),
+            primary: "#60a5fa".to_string(),
+            accent: "#f472b6".to_string(),
+            border: "#334155".to_string(),
+        }
+    }
+
+    pub fn background(mut self, color: &str) -> Self {
+        self.background = color.to_string();
+        self
+    }
+
+    pub fn surface(mut self, color: &str) -> Self {
+        self.surface = color.to_string();
+        self
+    }
+
+    pub fn text(mut self, color: &str) -> Self {
+        self.text = color.to_string();
+        self
+    }
+
+    pub fn muted(mut self, color: &str) -> Self {
+        self.muted = color.to_string();
+        self
+    }
+
+    pub fn primary(mut self, color: &str) -> Self {
+        self.primary = color.to_string();
+        self
+    }
+
+    pub fn accent(mut self, color: &str) -> Self {
+        self.accent = color.to_string();
+        self
+    }
+
+    pub fn border(mut self, color: &str) -> Self {
+        self.border = color.to_string();
+        self
+    }
+
+    fn declarations(&self) -> String {
+        [
+            ("background", &self.background),
+            ("surface", &self.surface),
+            ("text", &self.text),
+            ("muted", &self.muted),
+            ("primary", &self.primary),
+            ("accent", &self.accent),
+            ("border", &self.border),
+        ]
+        .iter()
+        .map(|(name, value)| format!("--color-{}:{};", name, value))
+        .collect()
+    }
+}
+
+/// 글꼴 스택. 각 필드는 CSS `font-family` 값이며 `--font-{name}` 속성이 됩니다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fonts {
+    pub body: String,
+    pub heading: String,
+    pub mono: String,
+}
+
+impl Default for Fonts {
+    fn default() -> Self {
+        Fonts {
+            body: "system-ui, sans-serif".to_string(),
+            heading: "system-ui, sans-serif".to_string(),
+            mono: "ui-monospace, monospace".to_string(),
+        }
+    }
+}
+
+impl Fonts {
+    pub fn body(mut self, family: &str) -> Self {
+        self.body = family.to_string();
+        self
+    }
+
+    pub fn heading(mut self, family: &str) -> Self {
+        self.heading = family.to_string();
+        self
+    }
+
+    pub fn mono(mut self, family: &str) -> Self {
+        self.mono = family.to_string();
+        self
+    }
+}
+
+/// 사이트 테마. Site 메타데이터에 넣으면 빌드 시 스타일시트가 기록되고 모든 페이지에 연결됩니다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Theme {
+    pub light: Palette,
+    pub dark: Option<Palette>,
+    pub fonts: Fonts,
+    /// 간격 단계 (`--space-{name}`). 기본: 레이아웃 Block과 같은 단계
+    pub spacing: Vec<(String, String)>,
+    /// 스타일시트 URL 경로. 기본: `/theme.css`
+    pub href: String,
+}
+impl MetadataValue for Theme {}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Theme {
+            light: Palette::light(),
+            dark: None,
+            fonts: Fonts::default(),
+            spacing: SPACING_SCALE
+                .iter()
+                .map(|(name, value)| (name.to_string(), value.to_string()))
+                .collect(),
+            href: "/theme.css".to_string(),
+        }
+    }
+}
+
+impl Theme {
+    pub fn new() -> Self {
+        Theme::default()
+    }
+
+    pub fn light(mut self, palette: Palette) -> Self {
+        self.light = palette;
+        self
+    }
+
+    /// 어두운 색상 모드 팔레트. 지정하면 시스템 설정과 전환 버튼을 따릅니다.
+    pub fn dark(mut self, palette: Palette) -> Self {
+        self.dark = Some(palette);
+        self
+    }
+
+    pub fn fonts(mut self, fonts: Fonts) -> Self {
+        self.fonts = fonts;
+        self
+    }
+
+    /// 간격 단계 값 지정. 같은 이름은 교체, 새 이름은 추가됩니다.
+    pub fn space(mut self, name: &str, value: &str) -> Self {
+        match self.spacing.iter_mut().find(|(n, _)| n == name) {
+            Some(entry) => entry.1 = value.to_string(),
+            None => self.spacing.push((name.to_string(), value.to_string())),
+        }
+        self
+    }
+
+    pub fn href(mut self, href: &str) -> Self {
+        self.href = href.to_string();
+        self
+    }
+
+    /// 테마 스타일시트 link (페이지 asset으로 주입).
+    pub fn assets(&self) -> Assets {
+        Assets::new().stylesheet(&self.href)
+    }
+
+    /// CSS 사용자 정의 속성으로 구성된 스타일시트.
+    pub fn stylesheet(&self) -> String {
+        let fonts = format!(
+            "--font-body:{};--font-heading:{};--font-mono:{};",
+            self.fonts.body, self.fonts.heading, self.fonts.mono
+        );
+        let spacing: String = self
+            .spacing
+            .iter()
+            .map(|(name, value)| format!("--space-{}:{};", name, value))
+            .collect();
+
+        let mut css = format!(
+            ":root{{color-scheme:light;{}{}{}}}",
+            self.light.declarations(),
+            fonts,
+            spacing
+        );
+        if let Some(dark) = &self.dark {
+            let dark = format!("color-scheme:dark;{}", dark.declarations());
+            css.push_str(&format!(
+                "@media (prefers-color-scheme: dark){{:root:not([data-theme=\"light\"]){{{}}}}}",
+                dark
+            ));
+            css.push_str(&format!(":root[data-theme=\"dark\"]{{{}}}", dark));
+        }
+        css.push_str(
+            "body{background:var(--color-background);color:var(--color-text);font-family:var(--font-body)}\
+             h1,h2,h3,h4,h5,h6{font-family:var(--font-heading)}\
+             code,pre{font-family:var(--font-mono)}\
+             a{color:var(--color-primary)}",
+        );
+        css
+    }
+
+    /// 출력 디렉토리 기준 스타일시트 경로.
+    pub fn stylesheet_path(&self) -> PathBuf {
+        PathBuf::from(self.href.trim_start_matches('/'))
+    }
+
+    /// 출력 파일 시스템에 스타일시트 기록. 기록한 파일 경로(출력 디렉토리 기준) 반환.
+    pub fn write_stylesheet(&self, fs: &dyn SiteFs) -> io::Result<PathBuf> {
+        let path = self.stylesheet_path();
+        fs.write(&path, self.stylesheet().as_bytes())?;
+        Ok(path)
+    }
+}
+
+/// 밝은/어두운 색상 모드 전환 버튼. 선택은 localStorage에 저장되어 다음 방문에도 유지됩니다.
+#[derive(Debug, Clone)]
+pub struct ThemeToggle {
+    label: String,
+}
+
+impl Default for ThemeToggle {
+    fn default() -> Self {
+        ThemeToggle {
+            label: "Toggle color scheme".to_string(),
+        }
+    }
+}
+
+impl ThemeToggle {
+    /// 전환 스크립트. 저장된 선택을 즉시 적용하고 버튼 클릭 시 `data-theme`를 바꿉니다.
+    /// 버튼이 여러 개여도 한 번만 초기화됩니다.
+    pub const SCRIPT: &'static str = "(function(){if(window.quoThemeToggle)return;window.quoThemeToggle=1;\
+var r=document.documentElement,k=\"quo-theme\",s=localStorage.getItem(k);\
+if(s)r.dataset.theme=s;\
+document.addEventListener(\"click\",function(e){var b=e.target.closest(\"[data-theme-toggle]\");if(!b)return;\
+var d=r.dataset.theme||(matchMedia(\"(prefers-color-scheme: dark)\").matches?\"dark\":\"light\");\
+var n=d===\"dark\"?\"light\":\"dark\";r.dataset.theme=n;localStorage.setItem(k,n);});})();";
+
+    pub fn new() -> Self {
+        ThemeToggle::default()
+    }
+
+    /// 버튼 텍스트. 기본: "Toggle color scheme"
+    pub fn label(mut self, label: &str) -> Self {
+        self.label = label.to_string();
+        self
+    }
+}
+
+impl Block for ThemeToggle {
+    fn render_to_ir(&self, _ctx: &RenderContext) -> IRNode {
+        let button = element(
+            "button",
+            with_tokens(
+                class_attrs(&["theme-toggle"]),
+                &[("data-theme-toggle", ""), ("type", "button")],
+            ),
+            vec![Element::Text(Content::from_str(&self.label, &prose_rule()))],
+        );
+        let script = Script::inline(Self::SCRIPT).to_irnode();
+        element(
+            "span",
+            class_attrs(&["theme-toggle-wrapper"]),
+            vec![Element::Node(button), Element::Node(script)],
+        )
+    }
+}
@@ -0,0 +1,126 @@
+//! # vars.rs - 사이트 변수
+//!
+//! ## 목적
+//! 버전 번호, 제품 이름처럼 여러 페이지에 반복되는 문자열을 사이트 메타데이터 한 곳(`SiteVars`)에
+//! 두고, 본문에서는 `var!` 매크로(`Inline::Var`)로 이름만 가리킵니다. 값을 바꾸면 그 변수를 쓰는
+//! 모든 페이지에 반영됩니다.
+//!
+//! ## 사용 예시
+//! ```rust
+//! let site = Site::new().metadata(
+//!     Metadata::new().mergeable(SiteVars::new().var("product_name", "Quo").var("version", "0.4.2")),
+//! );
+//!
+//! Paragraph::from_inlines(vec![
+//!     var!("product_name"),
+//!     Inline::Text(" ".to_string()),
+//!     var!("version"),
+//!     Inline::Text(" 설치 안내".to_string()),
+//! ])
+//!
+//! // 한 페이지에서만 다른 값 (하위 계층이 같은 이름을 덮어씀)
+//! SimplePage::new("0.3 문서", "v0.3/install.html")
+//!     .with_metadata(Metadata::new().mergeable(SiteVars::new().var("version", "0.3.9")));
+//! ```
+//!
+//! ## 렌더링 결과
+//! ```html
+//! <p>Quo 0.4.2 설치 안내</p>
+//! <!-- 정의하지 않은 변수 -->
+//! <p><span class="undefined-var"><!-- undefined site var: edition -->edition</span></p>
+//! ```
+//!
+//! ## 구현 상태
+//! - [x] 사이트/페이지 변수 (`SiteVars`, 하위 계층이 덮어씀)
+//! - [x] 문단, 제목, 목록, 이미지 캡션 안의 변수 (`var!`, `Inline::Var`)
+//! - [x] 정의하지 않은 변수 경고 (`WarningKind::UndefinedVar`)
+//! - [ ] TODO: Markdown 본문 문법 (`{{ product_name }}`)
+//!
+//! ## 설계 결정
+//! - **렌더링 시점에 치환**: 변수는 병합된 메타데이터(`RenderContext`)에서 읽습니다. 페이지 검사
+//!   단계가 같은 렌더링 결과로 정의되지 않은 변수를 찾고, 문서 조립과 피드, 검색 색인도 같은 값을 씁니다.
+//! - **없는 변수는 이름 그대로**: `t!`의 없는 메시지처럼 빌드를 멈추지 않고 화면에서 누락을 알아볼 수
+//!   있게 이름을 남기며, Site는 `Link`의 찾지 못한 링크처럼 주석으로 표시된 곳을 경고로 옮깁니다.
+//! - **의존성 기록**: 변수를 쓴 페이지는 `Input::Metadata(SiteVars)`를 기록하므로 값이 바뀌면
+//!   `Site::rebuild_affected`가 다시 렌더링합니다.
+//! - **값은 텍스트**: 값은 문단 텍스트와 같은 타이포그래피 규칙으로 이스케이프합니다. 마크업은 넣을 수 없습니다.
+
+use std::collections::BTreeMap;
+
+use crate::block::block::{class_attrs, element, prose_rule};
+use crate::block::context::RenderContext;
+use crate::cite::dependency::Input;
+use crate::html::node::Element;
+use crate::html::trust::{Content, HtmlBlock, SafeString};
+use crate::metadata::merge::MergeableMetadata;
+use crate::metadata::value::MetadataValue;
+
+/// 정의하지 않은 변수가 남기는 주석의 앞부분.
+pub(crate) const UNDEFINED_MARKER: &str = "<!-- undefined site var: ";
+
+/// 이름 → 값. `mergeable`로 넣으면 하위 계층(페이지)이 같은 이름을 덮어씁니다.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SiteVars(pub BTreeMap<String, String>);
+impl MetadataValue for SiteVars {}
+
+impl MergeableMetadata for SiteVars {
+    fn merge_with(&self, base: &Self) -> Self {
+        let mut vars = base.0.clone();
+        vars.extend(self.0.clone());
+        SiteVars(vars)
+    }
+}
+
+impl SiteVars {
+    pub fn new() -> Self {
+        SiteVars::default()
+    }
+
+    /// 변수 하나 추가.
+    pub fn var(mut self, name: &str, value: &str) -> Self {
+        self.0.insert(name.to_string(), value.to_string());
+        self
+    }
+
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.0.get(name).map(String::as_str)
+    }
+}
+
+/// 변수 `name`의 렌더링 결과. 정의되지 않았으면 주석과 이름을 담은 `span.undefined-var`.
+pub fn render_var(name: &str, ctx: &RenderContext) -> Element {
+    ctx.depends_on(Input::metadata::<SiteVars>());
+    let rule = prose_rule();
+    match ctx.get::<SiteVars>().and_then(|vars| vars.get(name)) {
+        Some(value) => Element::Text(Content::from_str(value, &rule)),
+        None => Element::Node(element(
+            "span",
+            class_attrs(&["undefined-var"]),
+            vec![
+                Element::Raw(HtmlBlock::from_str(&format!(
+                    "{}{} -->",
+                    UNDEFINED_MARKER,
+                    name.replace("--", "")
+                ))),
+                Element::Text(Content::from_str(name, &rule)),
+            ],
+        )),
+    }
+}
+
+/// 정의하지 않은 변수 주석이면 변수 이름.
+pub(crate) fn undefined_var(raw: &str) -> Option<&str> {
+    raw.strip_prefix(UNDEFINED_MARKER)?.strip_suffix(" -->")
+}
+
+/// 사이트 변수 참조. `Inline::Var`을 만듭니다.
+///
+/// ```rust
+/// var!("product_name")
+/// ```
+#[macro_export]
+macro_rules! var {
+    ($name:expr $(,)?) => {
+        $crate::block::inline::Inline::Var(::std::string::ToString::to_string($name))
+    };
+}
@@ -0,0 +1,195 @@
+//! # vbox.rs - VBox (수직 배치)
+//!
+//! ## 목적
+//! 자식 Block들을 위에서 아래로 쌓는 레이아웃 Block입니다.
+//!
+//! ## 렌더링 결과
+//! ```html
+//! <div class="vbox vbox-d164b224 vbox-gap-large">
+//!   <div class="vbox-item"><!-- child 1 --></div>
+//!   <div class="vbox-item"><!-- child 2 --></div>
+//! </div>
+//! ```
+//!
+//! ## 사용 예시
+//! ```rust
+//! fn layout(&self, ctx: &RenderContext) -> IRNode {
+//!     VBox::new(vec![header, content, footer])
+//!         .spacing(Spacing::Large)
+//!         .alignment(VerticalAlignment::Center)
+//!         .render_to_ir(ctx)
+//! }
+//!
+//! // 간격/정렬 class를 포함한 CSS
+//! let css = VBox::stylesheet();
+//! ```
+//!
+//! ## 구현 상태
+//! - [x] 간격 (Spacing), 정렬 (VerticalAlignment)
+//! - [x] 의존 CSS (`VBox::CSS`, `VBox::stylesheet()`)
+//! - [x] 의존 CSS를 페이지 asset으로 자동 주입 (`Block::css`, 범위 class `vbox-<해시>`)
+//! - [x] 글 방향 (`direction`: 문서와 다른 방향의 영역에 `dir`, 자식도 그 방향으로 렌더링)
+
+use crate::block::block::{
+    Block, class_attrs, element, render_block, with_style, with_tokens,
+};
+use crate::block::context::RenderContext;
+use crate::block::css::ScopedCss;
+use crate::html::node::{Element, IRNode};
+use crate::metadata::types::TextDirection;
+use crate::page::spacing::{SPACING_SCALE, Spacing};
+
+/// VBox 자식들의 세로 방향 분배 (flex `justify-content`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum VerticalAlignment {
+    #[default]
+    Top,
+    Center,
+    Bottom,
+    SpaceBetween,
+}
+
+impl VerticalAlignment {
+    fn class_suffix(&self) -> &'static str {
+        match self {
+            VerticalAlignment::Top => "top",
+            VerticalAlignment::Center => "center",
+            VerticalAlignment::Bottom => "bottom",
+            VerticalAlignment::SpaceBetween => "between",
+        }
+    }
+
+    fn css_value(&self) -> &'static str {
+        match self {
+            VerticalAlignment::Top => "flex-start",
+            VerticalAlignment::Center => "center",
+            VerticalAlignment::Bottom => "flex-end",
+            VerticalAlignment::SpaceBetween => "space-between",
+        }
+    }
+}
+
+/// 수직 배치 레이아웃 Block.
+pub struct VBox {
+    children: Vec<Box<dyn Block>>,
+    spacing: Option<Spacing>,
+    alignment: VerticalAlignment,
+    direction: Option<TextDirection>,
+}
+
+impl VBox {
+    /// VBox가 의존하는 CSS.
+    pub const CSS: &'static str = ".vbox{display:flex;flex-direction:column}";
+
+    pub fn new(children: Vec<Box<dyn Block>>) -> Self {
+        VBox {
+            children,
+            spacing: None,
+            alignment: VerticalAlignment::default(),
+            direction: None,
+        }
+    }
+
+    pub fn spacing(mut self, spacing: Spacing) -> Self {
+        self.spacing = Some(spacing);
+        self
+    }
+
+    pub fn alignment(mut self, alignment: VerticalAlignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
+    /// 글 방향. 문서와 방향이 다른 영역(영어 페이지 안의 아랍어 단락 등)에 `dir`을 붙이고
+    /// 자식 Block도 그 방향으로 렌더링합니다. 기본: 문서 방향을 따름
+    pub fn direction(mut self, direction: TextDirection) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    pub fn push(mut self, child: Box<dyn Block>) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    /// 간격/정렬 class까지 포함한 전체 스타일시트 생성.
+    pub fn stylesheet() -> String {
+        format!("{}{}", Self::CSS, Self::modifier_rules(""))
+    }
+
+    /// 간격/정렬 class 규칙. 각 선택자 앞에 `scope`를 붙입니다.
+    fn modifier_rules(scope: &str) -> String {
+        let mut css = String::new();
+        for (name, value) in SPACING_SCALE {
+            css.push_str(&format!("{}.vbox-gap-{}{{gap:{}}}", scope, name, value));
+        }
+        for alignment in [
+            VerticalAlignment::Top,
+            VerticalAlignment::Center,
+            VerticalAlignment::Bottom,
+            VerticalAlignment::SpaceBetween,
+        ] {
+            css.push_str(&format!(
+                "{}.vbox-align-{}{{justify-content:{}}}",
+                scope,
+                alignment.class_suffix(),
+                alignment.css_value()
+            ));
+        }
+        css
+    }
+}
+
+impl Block for VBox {
+    fn children(&self) -> Vec<&dyn Block> {
+        self.children.iter().map(|c| c.as_ref()).collect()
+    }
+
+    fn css(&self) -> Option<ScopedCss> {
+        let rules = format!(
+            "&{{display:flex;flex-direction:column}}{}",
+            VBox::modifier_rules("&")
+        );
+        Some(ScopedCss::new("vbox", &rules))
+    }
+
+    fn render_to_ir(&self, ctx: &RenderContext) -> IRNode {
+        let mut classes = vec!["vbox".to_string()];
+        classes.extend(self.css().map(|css| css.class()));
+        if let Some(suffix) = self.spacing.as_ref().and_then(|s| s.class_suffix()) {
+            classes.push(format!("vbox-gap-{}", suffix));
+        }
+        if self.alignment != VerticalAlignment::Top {
+            classes.push(format!("vbox-align-{}", self.alignment.class_suffix()));
+        }
+
+        let class_refs: Vec<&str> = classes.iter().map(|c| c.as_str()).collect();
+        let mut attrs = class_attrs(&class_refs);
+        if let Some(style) = self.spacing.as_ref().and_then(|s| s.inline_style()) {
+            attrs = with_style(attrs, &style);
+        }
+        let inner;
+        let ctx = match self.direction {
+            Some(direction) => {
+                attrs = with_tokens(attrs, &[("dir", direction.as_str())]);
+                inner = ctx.with_direction(direction);
+                &inner
+            }
+            None => ctx,
+        };
+
+        let items = self
+            .children
+            .iter()
+            .map(|child| {
+                Element::Node(element(
+                    "div",
+                    class_attrs(&["vbox-item"]),
+                    vec![Element::Node(render_block(child.as_ref(), ctx))],
+                ))
+            })
+            .collect();
+
+        element("div", attrs, items)
+    }
+}
@@ -0,0 +1,458 @@
+//! # testing - 사이트 회귀 테스트 도구
+//!
+//! ## 목적
+//! 사이트 정의(`Site`를 만드는 코드)를 테스트하는 사용자를 위한 도구입니다. `SiteHarness`는 사이트를
+//! 메모리(`MemoryFs`)에 빌드하고, 생성된 파일, 내부 링크, `SiteIndex` 내용을 검사하는 assert 메서드를
+//! 제공합니다. 출력 전체를 저장해 둔 기준 디렉토리(golden)와 비교할 수도 있습니다.
+//!
+//! ## 사용 예시
+//! ```rust
+//! // tests/site.rs 의 테스트 함수
+//! let site = my_blog::site();                 // 사용자의 Site 정의
+//! let harness = SiteHarness::build(site).unwrap();
+//!
+//! harness
+//!     .assert_file("index.html")
+//!     .assert_file("sitemap.xml")
+//!     .assert_contains("blog/hello/index.html", "<h1>Hello</h1>")
+//!     .assert_indexed("/blog/hello/")
+//!     .assert_links_resolve()
+//!     .assert_no_warnings();
+//! assert_eq!(harness.index().pages_by_tag("rust").len(), 2);
+//!
+//! // 출력 전체 비교. QUO_UPDATE_GOLDEN=1 cargo test 로 기준 파일 갱신
+//! harness.assert_golden("tests/golden/blog");
+//! ```
+//!
+//! ## 링크 검사 규칙 (`broken_links`)
+//! - HTML 파일의 `href`, `src` 속성만 봅니다.
+//! - 사이트 루트 기준(`/blog/`), 상대(`../about.html`), 기본 URL로 시작하는 절대 URL을 검사합니다.
+//!   다른 호스트, `mailto:` 등 스킴이 있는 링크는 건너뜁니다.
+//! - `/blog/` → `blog/index.html`, `/about` → `about`, `about.html`, `about/index.html` 순으로 찾습니다.
+//! - `#조각`이 있으면 대상 HTML 파일에 그 `id`가 있어야 합니다.
+//!
+//! ## 설계 결정
+//! - **메모리에 빌드**: `Site::build_to`로 `Site::build`와 같은 과정(기록기, 플러그인, 생성 파일)을 거치므로
+//!   테스트가 배포 결과와 어긋나지 않으면서 디스크를 건드리지 않습니다. golden 비교만 디스크를 읽습니다.
+//! - **assert는 panic**: `#[track_caller]`로 테스트 코드 위치를 보고하고, `&Self`를 반환해 이어 쓸 수 있습니다.
+//!   조건만 필요하면 `read`, `files`, `broken_links`, `index`를 직접 씁니다.
+//! - **golden 비교는 빌드 상태 파일 제외**: 지문, 의존성 그래프, 기록 목록(`.quo-*`)은 비교하지 않습니다.
+
+use std::{
+    collections::BTreeSet,
+    env, fs, io,
+    path::{Component, Path, PathBuf},
+    sync::Arc,
+};
+
+use crate::cite::cite::Site;
+use crate::cite::error::BuildError;
+use crate::cite::index::SiteIndex;
+use crate::cite::report::BuildReport;
+use crate::cite::vfs::{MemoryFs, SiteFs};
+
+/// 설정하면 `assert_golden`이 비교 대신 기준 디렉토리를 다시 씁니다.
+pub const UPDATE_GOLDEN_ENV: &str = "QUO_UPDATE_GOLDEN";
+
+/// 메모리에 빌드한 사이트.
+pub struct SiteHarness {
+    site: Site,
+    fs: Arc<MemoryFs>,
+    report: BuildReport,
+    index: SiteIndex,
+}
+
+/// 내부 링크 검사에서 찾지 못한 대상.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BrokenLink {
+    /// 링크가 있는 파일 (출력 디렉토리 기준)
+    pub file: PathBuf,
+    /// 속성값 그대로
+    pub href: String,
+}
+
+impl std::fmt::Display for BrokenLink {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.file.display(), self.href)
+    }
+}
+
+impl SiteHarness {
+    /// `site`를 새 `MemoryFs`에 빌드. `SiteConfig::output_dir`는 쓰지 않습니다.
+    pub fn build(site: Site) -> Result<Self, BuildError> {
+        let fs = Arc::new(MemoryFs::new());
+        let report = site.build_to(fs.clone())?;
+        let index = site.index();
+        Ok(SiteHarness {
+            site,
+            fs,
+            report,
+            index,
+        })
+    }
+
+    pub fn site(&self) -> &Site {
+        &self.site
+    }
+
+    /// 빌드 결과가 담긴 파일 시스템.
+    pub fn fs(&self) -> &MemoryFs {
+        &self.fs
+    }
+
+    pub fn report(&self) -> &BuildReport {
+        &self.report
+    }
+
+    pub fn index(&self) -> &SiteIndex {
+        &self.index
+    }
+
+    /// 출력 파일 (출력 디렉토리 기준, 경로순). 빌드 상태 파일(`.quo-*`)은 제외합니다.
+    pub fn files(&self) -> Vec<PathBuf> {
+        self.fs
+            .files()
+            .into_keys()
+            .filter(|path| !is_build_state(path))
+            .collect()
+    }
+
+    /// 출력 파일 내용. 없거나 UTF-8이 아니면 `None`.
+    pub fn read<P: AsRef<Path>>(&self, path: P) -> Option<String> {
+        String::from_utf8(self.fs.get(path.as_ref())?).ok()
+    }
+
+    /// HTML 파일의 `href`, `src` 속성값 (문서 순서).
+    pub fn links<P: AsRef<Path>>(&self, path: P) -> Vec<String> {
+        self.read(path).map(|html| extract_links(&html)).unwrap_or_default()
+    }
+
+    /// 모든 HTML 파일에서 대상이 출력에 없는 내부 링크.
+    pub fn broken_links(&self) -> Vec<BrokenLink> {
+        let base = self.site.site_config().base_url.trim_end_matches('/').to_string();
+        let mut broken = Vec::new();
+        for file in self.files() {
+            if file.extension().is_none_or(|e| e != "html") {
+                continue;
+            }
+            for href in self.links(&file) {
+                if !self.resolves(&file, &href, &base) {
+                    broken.push(BrokenLink { file: file.clone(), href });
+                }
+            }
+        }
+        broken
+    }
+
+    fn resolves(&self, file: &Path, href: &str, base: &str) -> bool {
+        let href = match href.strip_prefix(base) {
+            Some(rest) if rest.is_empty() || rest.starts_with(['/', '?', '#']) => rest,
+            _ => href,
+        };
+        if has_scheme(href) || href.starts_with("//") {
+            return true;
+        }
+        let (path, fragment) = match href.split_once('#') {
+            Some((path, fragment)) => (path, Some(fragment)),
+            None => (href, None),
+        };
+        let path = path.split('?').next().unwrap_or("");
+        let target = if path.is_empty() {
+            Some(file.to_path_buf())
+        } else {
+            let relative = match path.strip_prefix('/') {
+                Some(rooted) => PathBuf::from(rooted),
+                None => file.parent().unwrap_or(Path::new("")).join(path),
+            };
+            normalize(&relative)
+                .and_then(|candidate| self.find_target(&candidate, path.ends_with('/')))
+        };
+        match (target, fragment) {
+            (None, _) => false,
+            (Some(_), None | Some("")) => true,
+            (Some(target), Some(fragment)) => self
+                .read(&target)
+                .is_some_and(|html| has_id(&html, fragment)),
+        }
+    }
+
+    fn find_target(&self, candidate: &Path, directory: bool) -> Option<PathBuf> {
+        let mut options = Vec::new();
+        if !directory && !candidate.as_os_str().is_empty() {
+            options.push(candidate.to_path_buf());
+            options.push(candidate.with_extension("html"));
+        }
+        options.push(candidate.join("index.html"));
+        options.into_iter().find(|p| self.fs.is_file(p))
+    }
+
+    // ------------------------------------------------------------------------
+    // assert
+    // ------------------------------------------------------------------------
+
+    /// 파일이 출력에 있어야 함.
+    #[track_caller]
+    pub fn assert_file<P: AsRef<Path>>(&self, path: P) -> &Self {
+        let path = path.as_ref();
+        if !self.fs.is_file(path) {
+            panic!(
+                "expected output file {}\noutput files:\n{}",
+                path.display(),
+                self.listing()
+            );
+        }
+        self
+    }
+
+    /// 파일이 출력에 없어야 함.
+    #[track_caller]
+    pub fn assert_no_file<P: AsRef<Path>>(&self, path: P) -> &Self {
+        let path = path.as_ref();
+        if self.fs.is_file(path) {
+            panic!("unexpected output file {}", path.display());
+        }
+        self
+    }
+
+    /// 출력 파일 목록이 정확히 `expected`여야 함 (빌드 상태 파일 제외, 순서 무관).
+    #[track_caller]
+    pub fn assert_files<P: AsRef<Path>>(&self, expected: &[P]) -> &Self {
+        let expected: BTreeSet<PathBuf> = expected.iter().map(|p| p.as_ref().to_path_buf()).collect();
+        let actual: BTreeSet<PathBuf> = self.files().into_iter().collect();
+        if expected != actual {
+            let missing: Vec<String> = expected.difference(&actual).map(|p| p.display().to_string()).collect();
+            let extra: Vec<String> = actual.difference(&expected).map(|p| p.display().to_string()).collect();
+            panic!(
+                "output files differ\nmissing: {}\nunexpected: {}",
+                missing.join(", "),
+                extra.join(", ")
+            );
+        }
+        self
+    }
+
+    /// 파일 내용에 `needle`이 있어야 함.
+    #[track_caller]
+    pub fn assert_contains<P: AsRef<Path>>(&self, path: P, needle: &str) -> &Self {
+        let contents = self.expect_read(path.as_ref());
+        if !contents.contains(needle) {
+            panic!(
+                "{} does not contain {:?}\ncontents:\n{}",
+                path.as_ref().display(),
+                needle,
+                contents
+            );
+        }
+        self
+    }
+
+    /// 파일 내용에 `needle`이 없어야 함.
+    #[track_caller]
+    pub fn assert_not_contains<P: AsRef<Path>>(&self, path: P, needle: &str) -> &Self {
+        let contents = self.expect_read(path.as_ref());
+        if contents.contains(needle) {
+            panic!("{} unexpectedly contains {:?}", path.as_ref().display(), needle);
+        }
+        self
+    }
+
+    /// 모든 내부 링크의 대상이 출력에 있어야 함 (`broken_links`).
+    #[track_caller]
+    pub fn assert_links_resolve(&self) -> &Self {
+        let broken = self.broken_links();
+        if !broken.is_empty() {
+            let lines: Vec<String> = broken.iter().map(|b| format!("  {}", b)).collect();
+            panic!("{} broken links:\n{}", broken.len(), lines.join("\n"));
+        }
+        self
+    }
+
+    /// `SiteIndex`에 페이지가 있어야 함.
+    #[track_caller]
+    pub fn assert_indexed(&self, url: &str) -> &Self {
+        if !self.index.has_page(url) {
+            let urls: Vec<String> = self.index.pages_by_date().iter().map(|p| p.url.clone()).collect();
+            panic!("page {} is not in the site index\nindexed pages: {}", url, urls.join(", "));
+        }
+        self
+    }
+
+    /// 빌드 경고가 없어야 함.
+    #[track_caller]
+    pub fn assert_no_warnings(&self) -> &Self {
+        let warnings = &self.report.warnings;
+        if !warnings.is_empty() {
+            let lines: Vec<String> = warnings.iter().map(|w| format!("  {}", w)).collect();
+            panic!("{} build warnings:\n{}", warnings.len(), lines.join("\n"));
+        }
+        self
+    }
+
+    /// 출력이 기준 디렉토리 `golden`과 같아야 함 (파일 목록과 바이트).
+    /// `QUO_UPDATE_GOLDEN`이 설정되어 있으면 비교하지 않고 `golden`을 현재 출력으로 바꿉니다.
+    #[track_caller]
+    pub fn assert_golden<P: AsRef<Path>>(&self, golden: P) -> &Self {
+        let golden = golden.as_ref();
+        if env::var_os(UPDATE_GOLDEN_ENV).is_some() {
+            if let Err(err) = self.write_golden(golden) {
+                panic!("could not update golden directory {}: {}", golden.display(), err);
+            }
+            return self;
+        }
+
+        let expected: BTreeSet<PathBuf> = list_files(golden).unwrap_or_default().into_iter().collect();
+        let actual: BTreeSet<PathBuf> = self.files().into_iter().collect();
+        let mut differences = Vec::new();
+        for path in expected.union(&actual) {
+            match (expected.contains(path), actual.contains(path)) {
+                (true, false) => differences.push(format!("  missing: {}", path.display())),
+                (false, true) => differences.push(format!("  new: {}", path.display())),
+                _ => {
+                    let before = fs::read(golden.join(path)).unwrap_or_default();
+                    let after = self.fs.get(path).unwrap_or_default();
+                    if before != after {
+                        differences.push(format!("  changed: {}", path.display()));
+                    }
+                }
+            }
+        }
+        if !differences.is_empty() {
+            panic!(
+                "output differs from golden directory {}:\n{}\nrun with {}=1 to accept the new output",
+                golden.display(),
+                differences.join("\n"),
+                UPDATE_GOLDEN_ENV
+            );
+        }
+        self
+    }
+
+    fn write_golden(&self, golden: &Path) -> io::Result<()> {
+        if golden.exists() {
+            fs::remove_dir_all(golden)?;
+        }
+        for (path, contents) in self.fs.files() {
+            if is_build_state(&path) {
+                continue;
+            }
+            let target = golden.join(&path);
+            if let Some(parent) = target.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::write(target, contents)?;
+        }
+        Ok(())
+    }
+
+    #[track_caller]
+    fn expect_read(&self, path: &Path) -> String {
+        match self.read(path) {
+            Some(contents) => contents,
+            None => panic!(
+                "expected text output file {}\noutput files:\n{}",
+                path.display(),
+                self.listing()
+            ),
+        }
+    }
+
+    fn listing(&self) -> String {
+        self.files()
+            .iter()
+            .map(|p| format!("  {}", p.display()))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// 디렉토리의 파일 (상대 경로, 경로순). `.quo-`로 시작하는 빌드 상태 파일은 제외합니다.
+fn list_files(root: &Path) -> io::Result<Vec<PathBuf>> {
+    fn walk(root: &Path, dir: &Path, files: &mut Vec<PathBuf>) -> io::Result<()> {
+        for entry in fs::read_dir(dir)? {
+            let path = entry?.path();
+            if path.is_dir() {
+                walk(root, &path, files)?;
+            } else if let Ok(relative) = path.strip_prefix(root)
+                && !is_build_state(relative)
+            {
+                files.push(relative.to_path_buf());
+            }
+        }
+        Ok(())
+    }
+    let mut files = Vec::new();
+    walk(root, root, &mut files)?;
+    files.sort();
+    Ok(files)
+}
+
+/// 지문, 의존성 그래프, 기록 목록 (`.quo-*`).
+fn is_build_state(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|n| n.to_str())
+        .is_some_and(|n| n.starts_with(".quo-"))
+}
+
+/// `..`, `.`을 정리한 상대 경로. 출력 디렉토리 밖을 가리키면 `None`.
+fn normalize(path: &Path) -> Option<PathBuf> {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::Normal(part) => normalized.push(part),
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if !normalized.pop() {
+                    return None;
+                }
+            }
+            Component::RootDir | Component::Prefix(_) => return None,
+        }
+    }
+    Some(normalized)
+}
+
+fn has_scheme(href: &str) -> bool {
+    match href.split_once(':') {
+        Some((scheme, _)) => {
+            !scheme.is_empty()
+                && !scheme.contains(['/', '?', '#'])
+                && scheme.chars().all(|c| c.is_ascii_alphanumeric() || "+-.".contains(c))
+        }
+        None => false,
+    }
+}
+
+/// `href="..."`, `src="..."` 값 (문서 순서, HTML 엔티티 `&amp;` 등 복원).
+fn extract_links(html: &str) -> Vec<String> {
+    let mut links = Vec::new();
+    let mut rest = html;
+    loop {
+        let next = [" href=\"", " src=\""]
+            .into_iter()
+            .filter_map(|attr| rest.find(attr).map(|start| start + attr.len()))
+            .min();
+        let Some(start) = next else {
+            break;
+        };
+        let value = &rest[start..];
+        let Some(end) = value.find('"') else {
+            break;
+        };
+        links.push(unescape(&value[..end]));
+        rest = &value[end..];
+    }
+    links
+}
+
+fn has_id(html: &str, id: &str) -> bool {
+    html.contains(&format!(" id=\"{}\"", id)) || html.contains(&format!(" name=\"{}\"", id))
+}
+
+fn unescape(value: &str) -> String {
+    value
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&amp;", "&")
+}
@@ -0,0 +1,68 @@
+//! 직접 구현한 gzip 출력이 표준 해제기(flate2)로 원본과 같게 풀리는지 확인합니다.
+
+use std::io::Read;
+
+use flate2::read::GzDecoder;
+use quo::cite::compress::{Compressor, Gzip};
+
+fn round_trip(data: &[u8]) {
+    let compressed = Gzip::new().compress(data).unwrap();
+    let mut decoded = Vec::new();
+    GzDecoder::new(&compressed[..]).read_to_end(&mut decoded).unwrap();
+    assert!(decoded == data, "round trip of {} bytes differs", data.len());
+}
+
+/// 재현 가능한 의사 난수 바이트 (xorshift).
+fn noise(len: usize, mut state: u32) -> Vec<u8> {
+    (0..len)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            state as u8
+        })
+        .collect()
+}
+
+#[test]
+fn empty_input() {
+    round_trip(b"");
+}
+
+#[test]
+fn short_and_literal_only_input() {
+    round_trip(b"a");
+    round_trip(b"ab");
+    round_trip(&noise(1000, 7));
+}
+
+#[test]
+fn longest_match_is_258_bytes() {
+    round_trip(&vec![b'x'; 258]);
+    round_trip(&vec![b'x'; 259]);
+    round_trip(&vec![b'x'; 258 * 10 + 3]);
+    let mut data = noise(258, 1);
+    data.extend_from_within(..);
+    round_trip(&data);
+}
+
+#[test]
+fn matches_at_the_edge_of_the_32k_window() {
+    for gap in [32 * 1024 - 300, 32 * 1024 - 258, 32 * 1024 - 1, 32 * 1024, 32 * 1024 + 1] {
+        let block = noise(300, 3);
+        let mut data = block.clone();
+        data.extend(noise(gap - block.len().min(gap), 11));
+        data.extend(&block);
+        round_trip(&data);
+    }
+}
+
+#[test]
+fn larger_than_the_window() {
+    let page = b"<p class=\"post\">Hello, <a href=\"/blog/\">blog</a>.</p>\n";
+    let mut data: Vec<u8> = page.iter().copied().cycle().take(200 * 1024).collect();
+    data.extend(noise(40 * 1024, 5));
+    data.extend(page);
+    round_trip(&data);
+    assert!(Gzip::new().compress(&data).unwrap().len() < data.len());
+}
@@ -0,0 +1,38 @@
+//! `HeadElements`의 스크립트 옵션이 추가한 스크립트에만 적용되는지 확인합니다.
+
+use quo::html::node::IRNode;
+use quo::html::renderer::{HtmlRenderer, Renderer};
+use quo::page::head::{HeadElements, Script};
+
+fn render(node: &IRNode) -> String {
+    node.accept(HtmlRenderer::new()).finalize().as_str().to_string()
+}
+
+fn script<'a>(head: &'a HeadElements, src: &str) -> &'a Script {
+    head.scripts.iter().find(|s| s.src == src).unwrap()
+}
+
+#[test]
+fn options_apply_to_added_script() {
+    let head = HeadElements::new()
+        .add_script(Script::new("/js/app.js").module(true))
+        .add_script(Script::new("/js/stats.js").async_(true));
+    assert!(script(&head, "/js/app.js").module);
+    assert!(!script(&head, "/js/app.js").async_);
+    assert!(script(&head, "/js/stats.js").async_);
+    let html = render(&head.to_irnode());
+    assert!(html.contains("type=\"module\""));
+    assert!(html.contains("async"));
+}
+
+#[test]
+fn duplicate_script_keeps_first_options_and_leaves_others_alone() {
+    let head = HeadElements::new()
+        .script("/js/a.js")
+        .add_script(Script::new("/js/b.js"))
+        .add_script(Script::new("/js/a.js").defer(true).module(true));
+    assert_eq!(head.scripts.len(), 2);
+    assert_eq!(script(&head, "/js/a.js"), &Script::new("/js/a.js"));
+    assert_eq!(script(&head, "/js/b.js"), &Script::new("/js/b.js"));
+    assert!(!render(&head.to_irnode()).contains("defer"));
+}
@@ -0,0 +1,70 @@
+//! `ImageProcessor`가 원본마다 한 번만 해석하고, 모든 변형을 그 이미지에서 만드는지 확인합니다.
+
+use std::io;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use quo::block::image::ImageBlock;
+use quo::cite::cite::Site;
+use quo::cite::image::{DecodedImage, ImageEncoder, ImageFormat, ImageProcessor};
+use quo::cite::vfs::MemoryFs;
+use quo::page::simple::SimplePage;
+
+/// 해석 횟수를 세고, 변형은 `너비.확장자` 텍스트로 만드는 인코더.
+#[derive(Clone)]
+struct CountingEncoder(Arc<AtomicUsize>);
+
+struct FakeImage;
+
+impl ImageEncoder for CountingEncoder {
+    fn decode(&self, _source: &[u8]) -> io::Result<Box<dyn DecodedImage>> {
+        self.0.fetch_add(1, Ordering::SeqCst);
+        Ok(Box::new(FakeImage))
+    }
+}
+
+impl DecodedImage for FakeImage {
+    fn dimensions(&self) -> (u32, u32) {
+        (1200, 800)
+    }
+
+    fn encode(&self, width: u32, format: ImageFormat) -> io::Result<Vec<u8>> {
+        Ok(format!("{}.{}", width, format.extension()).into_bytes())
+    }
+}
+
+fn source_dir() -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("quo-image-{}", std::process::id()));
+    std::fs::create_dir_all(dir.join("images")).unwrap();
+    std::fs::write(dir.join("images/photo.jpg"), b"not really a jpeg").unwrap();
+    dir
+}
+
+#[test]
+fn source_is_decoded_once_for_all_variants() {
+    let decodes = Arc::new(AtomicUsize::new(0));
+    let dir = source_dir();
+    let mut site = Site::new();
+    site.register_visitor(
+        ImageProcessor::new(&dir, CountingEncoder(decodes.clone()))
+            .widths(&[480, 960])
+            .formats(&[ImageFormat::Avif, ImageFormat::WebP]),
+    );
+    for page in ["a.html", "b.html"] {
+        site.register_page(Box::new(
+            SimplePage::new("Photo", page).add_block(Box::new(ImageBlock::new("/images/photo.jpg", "Photo"))),
+        ));
+    }
+    let fs = Arc::new(MemoryFs::new());
+    site.build_to(fs.clone()).unwrap();
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    assert_eq!(decodes.load(Ordering::SeqCst), 1);
+    let files = fs.files();
+    let variant = |name: &str| files.get(&PathBuf::from("images").join(name)).cloned();
+    assert_eq!(variant("photo-480w.jpg"), Some(b"480.jpg".to_vec()));
+    assert_eq!(variant("photo-960w.avif"), Some(b"960.avif".to_vec()));
+    assert_eq!(variant("photo-1200w.webp"), Some(b"1200.webp".to_vec()));
+    assert_eq!(variant("photo-1200w.jpg"), None);
+}
@@ -0,0 +1,66 @@
+//! 증분 빌드가 입력이 같은 페이지를 렌더링하지 않는지, 바뀐 페이지만 다시 렌더링하는지 확인합니다.
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use quo::block::block::Block;
+use quo::block::context::RenderContext;
+use quo::block::prose::Paragraph;
+use quo::cite::cite::Site;
+use quo::cite::vfs::MemoryFs;
+use quo::html::node::IRNode;
+use quo::page::simple::SimplePage;
+
+/// 페이지로 렌더링될 때마다 (`SiteIndex`가 있는 컨텍스트) 횟수를 세는 Block.
+/// 인덱스를 만드는 분석 단계의 렌더링은 세지 않습니다.
+struct Counting(Arc<AtomicUsize>);
+
+impl Block for Counting {
+    fn render_to_ir(&self, ctx: &RenderContext) -> IRNode {
+        if ctx.index().is_some() {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+        Paragraph::new("counted").render_to_ir(ctx)
+    }
+}
+
+fn site(renders: &Arc<AtomicUsize>, other: &str) -> Site {
+    let mut site = Site::new().incremental(true);
+    site.register_page(Box::new(
+        SimplePage::new("Counted", "counted.html").add_block(Box::new(Counting(renders.clone()))),
+    ));
+    site.register_page(Box::new(
+        SimplePage::new("Other", "other.html").add_block(Box::new(Paragraph::new(other))),
+    ));
+    site
+}
+
+#[test]
+fn unchanged_page_is_not_rendered() {
+    let renders = Arc::new(AtomicUsize::new(0));
+    let fs = Arc::new(MemoryFs::new());
+
+    let first = site(&renders, "First.").build_to(fs.clone()).unwrap();
+    assert_eq!(first.unchanged_pages, 0);
+    let after_first = renders.load(Ordering::SeqCst);
+    assert!(after_first > 0);
+
+    let second = site(&renders, "First.").build_to(fs.clone()).unwrap();
+    assert_eq!(second.unchanged_pages, 2);
+    assert_eq!(renders.load(Ordering::SeqCst), after_first);
+}
+
+#[test]
+fn changed_body_rerenders_only_that_page() {
+    let renders = Arc::new(AtomicUsize::new(0));
+    let fs = Arc::new(MemoryFs::new());
+
+    site(&renders, "First.").build_to(fs.clone()).unwrap();
+    let after_first = renders.load(Ordering::SeqCst);
+
+    let second = site(&renders, "Second.").build_to(fs.clone()).unwrap();
+    assert_eq!(second.unchanged_pages, 1);
+    assert_eq!(renders.load(Ordering::SeqCst), after_first);
+    let other = fs.files().into_iter().find(|(path, _)| path.ends_with("other.html")).unwrap().1;
+    assert!(String::from_utf8(other).unwrap().contains("Second."));
+}
@@ -0,0 +1,58 @@
+//! 동시에 돌린 빌드가 각자의 문자열 풀을 쓰는지 (통계가 섞이지 않는지) 확인합니다.
+
+use std::sync::{Arc, Barrier};
+use std::thread;
+
+use quo::block::prose::{Heading, Paragraph};
+use quo::cite::cite::Site;
+use quo::cite::vfs::MemoryFs;
+use quo::html::intern::InternStats;
+use quo::page::simple::SimplePage;
+
+fn site(pages: usize, jobs: usize) -> Site {
+    let mut site = Site::new().jobs(jobs);
+    for i in 0..pages {
+        site.register_page(Box::new(
+            SimplePage::new(&format!("Page {}", i), format!("page-{}.html", i))
+                .add_block(Box::new(Heading::text(2, "Shared heading")))
+                .add_block(Box::new(Paragraph::new(&format!("Body {} with **shared** words.", i)))),
+        ));
+    }
+    site
+}
+
+fn stats(pages: usize, jobs: usize) -> InternStats {
+    site(pages, jobs).build_to(Arc::new(MemoryFs::new())).unwrap().interning
+}
+
+#[test]
+fn workers_use_the_build_pool() {
+    let sequential = stats(20, 1);
+    assert!(sequential.lookups > 0);
+    assert!(sequential.bytes_saved > 0);
+    assert_eq!(stats(20, 4), sequential);
+}
+
+#[test]
+fn overlapping_builds_keep_separate_pools() {
+    let small = stats(5, 2);
+    let large = stats(40, 4);
+    assert_ne!(small, large);
+
+    for _ in 0..8 {
+        let start = Arc::new(Barrier::new(2));
+        let builds: Vec<_> = [(5, 2), (40, 4)]
+            .into_iter()
+            .map(|(pages, jobs)| {
+                let start = start.clone();
+                thread::spawn(move || {
+                    let site = site(pages, jobs);
+                    start.wait();
+                    site.build_to(Arc::new(MemoryFs::new())).unwrap().interning
+                })
+            })
+            .collect();
+        let results: Vec<InternStats> = builds.into_iter().map(|b| b.join().unwrap()).collect();
+        assert_eq!(results, vec![small, large]);
+    }
+}
@@ -0,0 +1,111 @@
+//! 같은 입력과 시계면 스레드 수와 무관하게 같은 바이트를 기록하는지, 출력 경로가 겹치면 빌드하지 않는지 확인합니다.
+
+use std::sync::Arc;
+
+use quo::block::prose::Paragraph;
+use quo::cite::cite::Site;
+use quo::cite::clock::FixedClock;
+use quo::cite::compress::Gzip;
+use quo::cite::config::SiteConfig;
+use quo::cite::error::BuildError;
+use quo::cite::feed::FeedConfig;
+use quo::cite::search::SearchIndexGenerator;
+use quo::cite::sitemap::SitemapGenerator;
+use quo::cite::taxonomy::{Taxonomy, TaxonomyList, TaxonomyListing};
+use quo::cite::vfs::MemoryFs;
+use quo::metadata::builder::Metadata;
+use quo::metadata::types::Date;
+use quo::page::page::Page;
+use quo::page::pagination::Pagination;
+use quo::page::simple::SimplePage;
+use quo::testing::SiteHarness;
+
+/// 날짜, 태그, 피드, sitemap, 검색 색인, 태그 목록 페이지, gzip 출력이 있는 예제 사이트.
+fn sample_site(jobs: usize) -> Site {
+    let mut site = Site::new()
+        .config(SiteConfig::new("Sample", "https://example.com").feed(FeedConfig::new()))
+        .clock(FixedClock(Date::new(2024, 6, 1).unwrap()))
+        .jobs(jobs)
+        .precompress(Gzip::new())
+        .taxonomy(
+            Taxonomy::tags(|listing: &TaxonomyListing| {
+                Box::new(
+                    SimplePage::new(&format!("#{}", listing.term), &listing.path)
+                        .add_block(Box::new(TaxonomyList::new()))
+                        .add_block(Box::new(Pagination::new())),
+                ) as Box<dyn Page>
+            })
+            .dir("tags")
+            .per_page(3),
+        );
+    site.register_visitor(SitemapGenerator::new());
+    site.register_visitor(SearchIndexGenerator::new());
+    site.register_page(Box::new(
+        SimplePage::new("Home", "index.html").add_block(Box::new(Paragraph::new("Welcome."))),
+    ));
+    for i in 1..=12 {
+        let tags = match i % 3 {
+            0 => vec!["rust", "web"],
+            1 => vec!["rust"],
+            _ => vec!["notes"],
+        };
+        site.register_page(Box::new(
+            SimplePage::new(&format!("Post {}", i), format!("blog/post-{}.html", i))
+                .with_metadata(
+                    Metadata::new()
+                        .date(Date::new(2024, 1 + (i % 5) as u8, i as u8).unwrap())
+                        .tags(tags),
+                )
+                .add_block(Box::new(Paragraph::new(&format!("Body of post {}.", i)))),
+        ));
+    }
+    site
+}
+
+fn build(site: Site) -> MemoryFs {
+    let fs = Arc::new(MemoryFs::new());
+    site.build_to(fs.clone()).unwrap();
+    Arc::try_unwrap(fs).unwrap_or_else(|_| panic!("output file system is still shared"))
+}
+
+#[test]
+fn same_input_writes_same_bytes() {
+    let first = build(sample_site(8)).files();
+    let second = build(sample_site(8)).files();
+    let sequential = build(sample_site(1)).files();
+
+    assert!(first.keys().any(|path| path.ends_with("sitemap.xml")));
+    assert!(first.keys().any(|path| path.starts_with("tags")));
+    assert_eq!(first.keys().collect::<Vec<_>>(), second.keys().collect::<Vec<_>>());
+    for (path, bytes) in &first {
+        assert!(second[path] == *bytes, "{} differs between builds", path.display());
+        assert!(sequential[path] == *bytes, "{} differs with jobs(1)", path.display());
+    }
+    assert_eq!(first.len(), sequential.len());
+}
+
+#[test]
+fn duplicate_output_paths_fail_before_writing() {
+    let mut site = Site::new().jobs(8);
+    for i in 0..8 {
+        site.register_page(Box::new(SimplePage::new(&format!("Page {}", i), "dup.html")));
+    }
+    let fs = Arc::new(MemoryFs::new());
+    let err = site.build_to(fs.clone()).expect_err("duplicate outputs must fail");
+    assert!(
+        err.errors()
+            .iter()
+            .any(|err| matches!(err, BuildError::DuplicateOutput { path } if path.ends_with("dup.html")))
+    );
+    assert!(fs.files().is_empty());
+}
+
+#[test]
+fn harness_sees_every_page() {
+    SiteHarness::build(sample_site(4))
+        .unwrap()
+        .assert_file("index.html")
+        .assert_file("blog/post-12.html")
+        .assert_file("index.html.gz")
+        .assert_links_resolve();
+}
@@ -0,0 +1,59 @@
+//! `SimplePage`가 `Page` 트레이트를 구현한 결과(경로, 레이아웃, 메타데이터, head)를 확인합니다.
+
+use std::path::PathBuf;
+
+use quo::block::context::RenderContext;
+use quo::block::prose::Paragraph;
+use quo::html::node::IRNode;
+use quo::html::renderer::{HtmlRenderer, Renderer};
+use quo::metadata::builder::Metadata;
+use quo::metadata::types::{Description, Title};
+use quo::page::page::{Page, page_context};
+use quo::page::simple::SimplePage;
+
+fn render(node: &IRNode) -> String {
+    node.accept(HtmlRenderer::new()).finalize().as_str().to_string()
+}
+
+fn about() -> SimplePage {
+    SimplePage::new("About", "about/index.html")
+        .add_block(Box::new(Paragraph::new("Hello.")))
+}
+
+#[test]
+fn path_is_owned_output_path() {
+    let page = about();
+    let path = page.path();
+    drop(page);
+    assert_eq!(path, PathBuf::from("about/index.html"));
+}
+
+#[test]
+fn layout_renders_title_then_blocks() {
+    let page = about();
+    let ctx = RenderContext::new(Metadata::new().into_map());
+    assert_eq!(
+        render(&page.layout(&ctx)),
+        "<div class=\"page\"><h1>About</h1><p>Hello.</p></div>"
+    );
+}
+
+#[test]
+fn metadata_keeps_page_values_and_overrides_title() {
+    let page = about().with_metadata(Metadata::new().title("Old").description("Who we are"));
+    let metadata = page.metadata();
+    assert_eq!(metadata.get::<Title>().map(|t| t.0.as_str()), Some("About"));
+    assert_eq!(
+        metadata.get::<Description>().map(|d| d.0.as_str()),
+        Some("Who we are")
+    );
+}
+
+#[test]
+fn head_uses_page_title() {
+    let page = about();
+    let ctx = page_context(&page, &Metadata::new().into_map());
+    let head = page.head(&ctx);
+    assert_eq!(head.title.as_deref(), Some("About"));
+    assert!(render(&head.to_irnode()).contains("<title>About</title>"));
+}
@@ -0,0 +1,53 @@
+//! 출력 형식(`HtmlFormat`)이 공백이 내용인 요소(`pre`, `code`)의 공백을 바꾸지 않는지 확인합니다.
+
+use quo::block::code::CodeBlock;
+use quo::block::inline::Inline;
+use quo::block::prose::Paragraph;
+use quo::cite::cite::Site;
+use quo::html::renderer::HtmlFormat;
+use quo::page::simple::SimplePage;
+use quo::testing::SiteHarness;
+
+const CODE: &str = "fn main() {\n    let  x =   1;\n\n\tx + 1\n}";
+
+fn site(format: HtmlFormat) -> Site {
+    let mut site = Site::new().html_format(format);
+    site.register_page(Box::new(
+        SimplePage::new("Whitespace", "index.html")
+            .add_block(Box::new(Paragraph::from_inlines(vec![
+                Inline::Text("spaced   out\n  text ".to_string()),
+                Inline::Code("a  =  b".to_string()),
+            ])))
+            .add_block(Box::new(CodeBlock::new().language("rust").content(CODE))),
+    ));
+    site
+}
+
+fn code_block() -> String {
+    format!("<pre class=\"code-block\"><code class=\"language-rust\">{}</code></pre>", CODE)
+}
+
+#[test]
+fn compact_keeps_all_whitespace() {
+    SiteHarness::build(site(HtmlFormat::Compact))
+        .unwrap()
+        .assert_contains("index.html", "<p>spaced   out\n  text <code>a  =  b</code></p>")
+        .assert_contains("index.html", &code_block());
+}
+
+#[test]
+fn pretty_indents_blocks_but_not_preserved_content() {
+    SiteHarness::build(site(HtmlFormat::Pretty))
+        .unwrap()
+        .assert_contains("index.html", "<!DOCTYPE html>\n<html>\n  <head>")
+        .assert_contains("index.html", "\n  <body>")
+        .assert_contains("index.html", &code_block());
+}
+
+#[test]
+fn minified_collapses_text_outside_preserved_elements() {
+    SiteHarness::build(site(HtmlFormat::Minified))
+        .unwrap()
+        .assert_contains("index.html", "<p>spaced out text <code>a  =  b</code></p>")
+        .assert_contains("index.html", &code_block());
+}